@@ -0,0 +1,13 @@
+use std::{env, path::PathBuf};
+
+fn main() {
+    let idl_path =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("awesome_sails_client.idl");
+    let client_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("awesome_sails_client.rs");
+
+    println!("cargo:rerun-if-changed={}", idl_path.display());
+
+    sails_rs::ClientGenerator::from_idl_path(&idl_path)
+        .generate_to(client_path)
+        .expect("failed to generate client");
+}
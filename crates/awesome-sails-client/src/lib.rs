@@ -0,0 +1,48 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Sails client SDK.
+//!
+//! Typed clients of the shipped services, generated from `awesome_sails_client.idl`,
+//! so backends talk to programs built with Awesome Sails without running codegen
+//! themselves. Services are expected under their default routes, e.g. `Vft`.
+//!
+//! Clients are generic over [`GearEnv`](sails_rs::client::GearEnv); enable the
+//! `gtest` or `gclient` feature for the corresponding environment.
+//!
+//! Event streams of the services, resubscribing on disconnects, are in `listeners`.
+//! Conversions of amounts by decimals of the VFT are in [`units`].
+//!
+//! ```rust,ignore
+//! use awesome_sails_client::{AwesomeSailsClient, AwesomeSailsClientProgram, vft::Vft};
+//! use sails_rs::client::Actor;
+//!
+//! let program = Actor::<AwesomeSailsClientProgram, _>::new(env, program_id);
+//!
+//! let balance = program.vft().balance_of(account).await?;
+//! ```
+
+#![no_std]
+
+#[cfg(any(feature = "gclient", feature = "gtest"))]
+pub mod listeners;
+
+pub mod units;
+
+// Incorporate code generated based on the IDL file
+include!(concat!(env!("OUT_DIR"), "/awesome_sails_client.rs"));
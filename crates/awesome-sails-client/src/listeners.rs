@@ -0,0 +1,80 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Typed event streams of the services.
+//!
+//! Streams returned by [`resubscribing`] subscribe again once the subscription ends,
+//! e.g. when the connection to the node drops, so long-running backends keep
+//! receiving events. Events emitted while resubscribing aren't replayed, as
+//! listeners follow new blocks only.
+
+use crate::{
+    access_control::events::AccessControlEvents, name_registry::events::NameRegistryEvents,
+    vft::events::VftEvents, vft_admin::events::VftAdminEvents,
+    vft_native_exchange_admin::events::VftNativeExchangeAdminEvents,
+};
+use futures::{Stream, StreamExt, stream};
+use sails_rs::{
+    client::{Event, GearEnv, Listener, ServiceListener},
+    prelude::*,
+};
+
+/// Attempts to subscribe before a stream gives up and ends.
+pub const SUBSCRIBE_ATTEMPTS: usize = 3;
+
+pub type AccessControlListener<E> = ServiceListener<AccessControlEvents, E>;
+pub type NameRegistryListener<E> = ServiceListener<NameRegistryEvents, E>;
+pub type VftListener<E> = ServiceListener<VftEvents, E>;
+pub type VftAdminListener<E> = ServiceListener<VftAdminEvents, E>;
+pub type VftNativeExchangeAdminListener<E> = ServiceListener<VftNativeExchangeAdminEvents, E>;
+
+/// Subscribes to events the listener receives, returning their stream,
+/// which subscribes again whenever the subscription ends.
+///
+/// The stream ends once [`SUBSCRIBE_ATTEMPTS`] subscriptions in a row,
+/// counting the ended one, fail or end without events.
+pub async fn resubscribing<'a, D, E>(
+    listener: &'a ServiceListener<D, E>,
+) -> Result<impl Stream<Item = (ActorId, D)> + 'a, <E as GearEnv>::Error>
+where
+    D: Event + 'a,
+    E: GearEnv + Listener<Error = <E as GearEnv>::Error> + 'a,
+{
+    let events = listener.listen().await?;
+
+    Ok(stream::unfold(
+        (listener, Some(events)),
+        |(listener, mut events)| async move {
+            for _ in 0..SUBSCRIBE_ATTEMPTS {
+                let mut stream = match events.take() {
+                    Some(stream) => stream,
+                    None => match listener.listen().await {
+                        Ok(stream) => stream,
+                        Err(_) => continue,
+                    },
+                };
+
+                if let Some(event) = stream.next().await {
+                    return Some((event, (listener, Some(stream))));
+                }
+            }
+
+            None
+        },
+    ))
+}
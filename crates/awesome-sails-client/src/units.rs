@@ -0,0 +1,147 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversions of VFT amounts between decimal strings and base units.
+//!
+//! Services take and report amounts in base units, i.e. scaled by `10^decimals`,
+//! where `decimals` is reported by `VftMetadata::decimals`. [`Units`] fetches
+//! them once, so amounts are converted without off-by-`10^n` mistakes.
+//!
+//! ```rust,ignore
+//! use awesome_sails_client::{units::Units, vft::Vft};
+//!
+//! let units = Units::fetch(&program.vft_metadata()).await?;
+//!
+//! program.vft().transfer(to, units.parse("1.5")?).await?;
+//! ```
+
+use crate::vft_metadata::{VftMetadata, io::Decimals};
+use core::future::IntoFuture;
+use sails_rs::{
+    client::{GearEnv, PendingCall},
+    prelude::*,
+};
+
+/// Error of parsing a decimal amount.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum UnitsError {
+    #[error("amount has no digits")]
+    Empty,
+    #[error("amount is not a decimal number")]
+    InvalidDigit,
+    #[error("amount has more than {0} decimals")]
+    TooManyDecimals(u8),
+    #[error("amount overflows 256 bits")]
+    Overflow,
+}
+
+/// Converts the decimal `amount`, e.g. `"1.5"`, to base units of a VFT of `decimals`.
+///
+/// Trailing zeros of the fraction are ignored, while other fraction digits beyond
+/// `decimals` fail instead of being rounded off.
+pub fn to_base_units(amount: &str, decimals: u8) -> Result<U256, UnitsError> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(UnitsError::Empty);
+    }
+
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(UnitsError::InvalidDigit);
+    }
+
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.len() > decimals as usize {
+        return Err(UnitsError::TooManyDecimals(decimals));
+    }
+
+    let padding = decimals as usize - fraction.len();
+
+    integer
+        .bytes()
+        .chain(fraction.bytes())
+        .chain(core::iter::repeat_n(b'0', padding))
+        .try_fold(U256::zero(), |value, digit| {
+            value
+                .checked_mul(10.into())
+                .and_then(|value| value.checked_add((digit - b'0').into()))
+                .ok_or(UnitsError::Overflow)
+        })
+}
+
+/// Formats `value` in base units of a VFT of `decimals` as a decimal amount,
+/// e.g. `"1.5"`, without trailing zeros of the fraction.
+pub fn format_units(value: U256, decimals: u8) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+
+    if decimals == 0 {
+        return digits;
+    }
+
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.into()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Decimals of a VFT, converting its amounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Units {
+    decimals: u8,
+}
+
+impl Units {
+    /// Constructor for [`Self`].
+    pub const fn new(decimals: u8) -> Self {
+        Self { decimals }
+    }
+
+    /// Queries decimals of the VFT from its `VftMetadata` service.
+    pub async fn fetch<S>(metadata: &S) -> Result<Self, <S::Env as GearEnv>::Error>
+    where
+        S: VftMetadata,
+        PendingCall<Decimals, S::Env>: IntoFuture<Output = Result<u8, <S::Env as GearEnv>::Error>>,
+    {
+        Ok(Self::new(metadata.decimals().await?))
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Converts the decimal `amount` to base units, see [`to_base_units`].
+    pub fn parse(&self, amount: &str) -> Result<U256, UnitsError> {
+        to_base_units(amount, self.decimals)
+    }
+
+    /// Formats `value` in base units as a decimal amount, see [`format_units`].
+    pub fn format(&self, value: U256) -> String {
+        format_units(value, self.decimals)
+    }
+}
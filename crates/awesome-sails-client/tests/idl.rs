@@ -0,0 +1,57 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks services and types of the SDK IDL against the golden IDL of the test program,
+//! which mounts every shipped service, so the SDK doesn't drift from them.
+
+use std::{fs, path::Path};
+
+#[test]
+fn idl_matches_golden() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let golden = root.join("../../tests/awesome-sails-test/client/idl");
+
+    let idl = fs::read_to_string(root.join("awesome_sails_client.idl")).unwrap();
+    let program = fs::read_to_string(golden.join("program.idl")).unwrap();
+    let mut services = 0;
+
+    for block in idl.split_inclusive("\n};\n") {
+        let block = block.trim_start_matches('\n');
+
+        let Some(name) = block
+            .lines()
+            .find_map(|line| line.strip_prefix("service "))
+            .and_then(|line| line.split_whitespace().next())
+        else {
+            assert!(
+                program.contains(block),
+                "type drifted from golden:\n{block}"
+            );
+            continue;
+        };
+
+        let expected = fs::read_to_string(golden.join(format!("{name}.idl")))
+            .unwrap_or_else(|_| panic!("service `{name}` is not mounted by the test program"));
+
+        assert_eq!(block, expected, "service `{name}` drifted from golden");
+
+        services += 1;
+    }
+
+    assert_eq!(services, 8);
+}
@@ -0,0 +1,79 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_client::units::{Units, UnitsError, format_units, to_base_units};
+use sails_rs::U256;
+
+#[test]
+fn parses_amounts() {
+    assert_eq!(
+        to_base_units("1.5", 12),
+        Ok(U256::from(1_500_000_000_000u64))
+    );
+    assert_eq!(to_base_units("0.000001", 6), Ok(U256::one()));
+    assert_eq!(to_base_units("42", 0), Ok(U256::from(42)));
+    assert_eq!(to_base_units(".5", 1), Ok(U256::from(5)));
+    assert_eq!(to_base_units("7.", 2), Ok(U256::from(700)));
+    assert_eq!(to_base_units("1.500", 1), Ok(U256::from(15)));
+    assert_eq!(to_base_units("0", 18), Ok(U256::zero()));
+}
+
+#[test]
+fn rejects_malformed_amounts() {
+    assert_eq!(to_base_units("", 12), Err(UnitsError::Empty));
+    assert_eq!(to_base_units(".", 12), Err(UnitsError::Empty));
+    assert_eq!(to_base_units("-1", 12), Err(UnitsError::InvalidDigit));
+    assert_eq!(to_base_units("1,5", 12), Err(UnitsError::InvalidDigit));
+    assert_eq!(to_base_units("1.2.3", 12), Err(UnitsError::InvalidDigit));
+    assert_eq!(to_base_units(" 1", 12), Err(UnitsError::InvalidDigit));
+    assert_eq!(
+        to_base_units("0.0000001", 6),
+        Err(UnitsError::TooManyDecimals(6))
+    );
+    assert_eq!(
+        to_base_units(&U256::MAX.to_string(), 1),
+        Err(UnitsError::Overflow)
+    );
+    assert_eq!(to_base_units(&U256::MAX.to_string(), 0), Ok(U256::MAX));
+}
+
+#[test]
+fn formats_amounts() {
+    assert_eq!(format_units(U256::from(1_500_000_000_000u64), 12), "1.5");
+    assert_eq!(format_units(U256::one(), 6), "0.000001");
+    assert_eq!(format_units(U256::from(42), 0), "42");
+    assert_eq!(format_units(U256::from(4200), 2), "42");
+    assert_eq!(format_units(U256::zero(), 18), "0");
+}
+
+#[test]
+fn roundtrips_amounts() {
+    let units = Units::new(18);
+
+    assert_eq!(units.decimals(), 18);
+
+    for amount in ["0", "1", "0.1", "123.456789", "1000000.000000000000000001"] {
+        let value = units.parse(amount).unwrap();
+
+        assert_eq!(units.format(value), amount);
+    }
+
+    let value = U256::MAX;
+
+    assert_eq!(units.parse(&units.format(value)), Ok(value));
+}
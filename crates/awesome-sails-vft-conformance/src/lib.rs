@@ -0,0 +1,557 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! VFT conformance test suite.
+//!
+//! Checks that a program serving the `Vft` service behaves as VFTs of Awesome Sails
+//! do: semantics of `approve`, `transfer` and `transfer_from`, minimum balance rules
+//! and pause behavior. Token programs claiming VFT compatibility run the suite against
+//! their own gtest deployment by implementing [`Target`]:
+//!
+//! ```rust,ignore
+//! use awesome_sails_vft_conformance::{Target, assert_conforms};
+//!
+//! struct MyToken;
+//!
+//! impl Target for MyToken {
+//!     fn deploy<'a>(&self, system: &'a System, balances: &[(ActorId, U256)]) -> Program<'a> {
+//!         // Deploys the program, crediting `balances`.
+//!     }
+//! }
+//!
+//! #[test]
+//! fn conforms() {
+//!     assert_conforms(&MyToken);
+//! }
+//! ```
+//!
+//! Calls are sent as raw messages under the [`ROUTE`], so the suite doesn't depend
+//! on clients of the program. Every check deploys the program anew.
+
+use sails_rs::{
+    ActorId, U256,
+    gtest::{Program, System},
+    scale_codec::{Decode, Encode},
+};
+use std::fmt::Debug;
+
+/// Route the `Vft` service is expected to be mounted under.
+pub const ROUTE: &str = "Vft";
+
+/// Balance accounts are funded with to send messages, 100_000 * 10**12.
+pub const DEFAULT_BALANCE: u128 = 100_000_000_000_000_000;
+
+const fn actor_id(id: u8) -> ActorId {
+    let mut bytes = [0; 32];
+    bytes[12] = id;
+    ActorId::new(bytes)
+}
+
+/// Accounts checks are made on behalf of, funded before deployment.
+pub const ALICE: ActorId = actor_id(42);
+pub const BOB: ActorId = actor_id(43);
+pub const CHARLIE: ActorId = actor_id(44);
+
+/// Token program the suite runs against.
+pub trait Target {
+    /// Deploys the program in `system`, crediting `balances` and nothing else,
+    /// so the total supply equals their sum.
+    fn deploy<'a>(&self, system: &'a System, balances: &[(ActorId, U256)]) -> Program<'a>;
+
+    /// Returns the minimum balance accounts of the deployed program must hold,
+    /// zero if it has none.
+    fn minimum_balance(&self) -> U256 {
+        U256::zero()
+    }
+
+    /// Pauses the deployed program, returning `false` if it can't be paused,
+    /// skipping checks of pause behavior.
+    fn pause(&self, _system: &System, _program: &Program<'_>) -> bool {
+        false
+    }
+}
+
+/// Named check of the suite.
+pub struct Check {
+    pub name: &'static str,
+    pub run: fn(&dyn Target) -> Result<(), String>,
+}
+
+/// All checks of the suite, in the order [`run`] makes them.
+pub const CHECKS: &[Check] = &[
+    Check {
+        name: "transfer_moves_balance",
+        run: transfer_moves_balance,
+    },
+    Check {
+        name: "transfer_above_balance_fails",
+        run: transfer_above_balance_fails,
+    },
+    Check {
+        name: "transfer_noop",
+        run: transfer_noop,
+    },
+    Check {
+        name: "transfer_whole_balance",
+        run: transfer_whole_balance,
+    },
+    Check {
+        name: "approve_sets_allowance",
+        run: approve_sets_allowance,
+    },
+    Check {
+        name: "transfer_from_spends_allowance",
+        run: transfer_from_spends_allowance,
+    },
+    Check {
+        name: "transfer_from_above_allowance_fails",
+        run: transfer_from_above_allowance_fails,
+    },
+    Check {
+        name: "minimum_balance",
+        run: minimum_balance,
+    },
+    Check {
+        name: "pause_blocks_mutations",
+        run: pause_blocks_mutations,
+    },
+];
+
+/// Makes all checks against the target, returning their results by name.
+pub fn run(target: &dyn Target) -> Vec<(&'static str, Result<(), String>)> {
+    CHECKS
+        .iter()
+        .map(|check| (check.name, (check.run)(target)))
+        .collect()
+}
+
+/// Makes all checks against the target, panicking with failures of them, if any.
+#[track_caller]
+pub fn assert_conforms(target: &dyn Target) {
+    let failures: Vec<_> = run(target)
+        .into_iter()
+        .filter_map(|(name, res)| res.err().map(|e| format!("{name}: {e}")))
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "VFT doesn't conform:\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Reply and events of a message sent to the program.
+struct Outcome {
+    ok: bool,
+    /// Reply payload without the route: the encoded result on success, the error otherwise.
+    reply: Vec<u8>,
+    events: Vec<Vec<u8>>,
+}
+
+impl Outcome {
+    /// Decodes the result of the successful call.
+    fn result<R: Decode>(&self, method: &str) -> Result<R, String> {
+        if !self.ok {
+            return Err(format!(
+                "`{method}` failed: {}",
+                String::from_utf8_lossy(&self.reply)
+            ));
+        }
+
+        R::decode(&mut self.reply.as_slice())
+            .map_err(|e| format!("`{method}` replied with undecodable result: {e}"))
+    }
+
+    fn emitted(&self, name: &str, data: impl Encode) -> bool {
+        let event = [(ROUTE, name).encode(), data.encode()].concat();
+
+        self.events.contains(&event)
+    }
+}
+
+/// Deployed program under check.
+struct Token<'a> {
+    system: &'a System,
+    program: Program<'a>,
+}
+
+impl Token<'_> {
+    fn send(&self, actor: ActorId, method: &str, args: impl Encode) -> Outcome {
+        let prefix = (ROUTE, method).encode();
+        let payload = [prefix.clone(), args.encode()].concat();
+
+        let message_id = self.program.send_bytes(actor, payload);
+        let result = self.system.run_next_block();
+
+        let mut reply = Vec::new();
+        let mut events = Vec::new();
+
+        for log in result.log() {
+            if log.source() != self.program.id() {
+                continue;
+            }
+
+            if log.reply_to() == Some(message_id) {
+                reply = log.payload().to_vec();
+            } else if log.reply_to().is_none() && log.payload().starts_with(&ROUTE.encode()) {
+                events.push(log.payload().to_vec());
+            }
+        }
+
+        let ok = result.succeed.contains(&message_id);
+
+        if ok {
+            reply = reply
+                .strip_prefix(prefix.as_slice())
+                .unwrap_or(&reply)
+                .to_vec();
+        }
+
+        Outcome { ok, reply, events }
+    }
+
+    fn call<R: Decode>(
+        &self,
+        actor: ActorId,
+        method: &str,
+        args: impl Encode,
+    ) -> Result<R, String> {
+        self.send(actor, method, args).result(method)
+    }
+
+    fn balance_of(&self, account: ActorId) -> Result<U256, String> {
+        self.call(ALICE, "BalanceOf", account)
+    }
+
+    fn allowance(&self, owner: ActorId, spender: ActorId) -> Result<U256, String> {
+        self.call(ALICE, "Allowance", (owner, spender))
+    }
+
+    fn total_supply(&self) -> Result<U256, String> {
+        self.call(ALICE, "TotalSupply", ())
+    }
+
+    fn balances(&self, accounts: &[ActorId]) -> Result<Vec<U256>, String> {
+        accounts.iter().map(|&a| self.balance_of(a)).collect()
+    }
+}
+
+/// Deploys the target with `balances` in units, returning the program and the unit:
+/// the minimum balance of the target, if any, so every balance made satisfies it.
+fn deploy<'a>(
+    target: &dyn Target,
+    system: &'a System,
+    balances: &[(ActorId, u64)],
+) -> (Token<'a>, U256) {
+    for account in [ALICE, BOB, CHARLIE] {
+        system.mint_to(account, DEFAULT_BALANCE);
+    }
+
+    let unit = target.minimum_balance().max(U256::one());
+
+    let balances: Vec<_> = balances
+        .iter()
+        .map(|&(account, units)| (account, unit * units))
+        .collect();
+
+    let program = target.deploy(system, &balances);
+
+    (Token { system, program }, unit)
+}
+
+fn ensure(condition: bool, message: impl FnOnce() -> String) -> Result<(), String> {
+    if condition { Ok(()) } else { Err(message()) }
+}
+
+fn ensure_eq<T: PartialEq + Debug>(what: &str, actual: T, expected: T) -> Result<(), String> {
+    ensure(actual == expected, || {
+        format!("{what}: expected {expected:?}, got {actual:?}")
+    })
+}
+
+fn transfer_moves_balance(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(ALICE, "Transfer", (BOB, unit * 3));
+    ensure_eq("`Transfer` result", outcome.result("Transfer")?, true)?;
+    ensure(outcome.emitted("Transfer", (ALICE, BOB, unit * 3)), || {
+        "`Transfer` event isn't emitted".into()
+    })?;
+
+    ensure_eq(
+        "balances after transfer",
+        token.balances(&[ALICE, BOB])?,
+        vec![unit * 7, unit * 3],
+    )?;
+    ensure_eq(
+        "total supply after transfer",
+        token.total_supply()?,
+        unit * 10,
+    )
+}
+
+fn transfer_above_balance_fails(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(ALICE, "Transfer", (BOB, unit * 10 + 1));
+    ensure(!outcome.ok, || {
+        "transfer above the balance succeeded".into()
+    })?;
+
+    let outcome = token.send(CHARLIE, "Transfer", (BOB, unit));
+    ensure(!outcome.ok, || "transfer without balance succeeded".into())?;
+
+    ensure_eq(
+        "balances after failed transfers",
+        token.balances(&[ALICE, BOB, CHARLIE])?,
+        vec![unit * 10, U256::zero(), U256::zero()],
+    )
+}
+
+fn transfer_noop(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(ALICE, "Transfer", (BOB, U256::zero()));
+    ensure_eq(
+        "`Transfer` of zero result",
+        outcome.result("Transfer")?,
+        false,
+    )?;
+    ensure(outcome.events.is_empty(), || {
+        "`Transfer` of zero emitted events".into()
+    })?;
+
+    let outcome = token.send(ALICE, "Transfer", (ALICE, unit));
+    ensure_eq(
+        "`Transfer` to self result",
+        outcome.result("Transfer")?,
+        false,
+    )?;
+    ensure(outcome.events.is_empty(), || {
+        "`Transfer` to self emitted events".into()
+    })?;
+
+    ensure_eq(
+        "balances after noop transfers",
+        token.balances(&[ALICE, BOB])?,
+        vec![unit * 10, U256::zero()],
+    )
+}
+
+fn transfer_whole_balance(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10), (BOB, 1)]);
+
+    let res = token.call(ALICE, "Transfer", (BOB, unit * 10))?;
+    ensure_eq("`Transfer` of whole balance result", res, true)?;
+
+    ensure_eq(
+        "balances after transfer of whole balance",
+        token.balances(&[ALICE, BOB])?,
+        vec![U256::zero(), unit * 11],
+    )?;
+
+    let res = token.call(BOB, "Transfer", (ALICE, unit * 11))?;
+    ensure_eq("`Transfer` back result", res, true)?;
+
+    ensure_eq(
+        "balances after transfer back",
+        token.balances(&[ALICE, BOB])?,
+        vec![unit * 11, U256::zero()],
+    )
+}
+
+fn approve_sets_allowance(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(ALICE, "Approve", (BOB, unit * 5));
+    ensure_eq("`Approve` result", outcome.result("Approve")?, true)?;
+    ensure(outcome.emitted("Approval", (ALICE, BOB, unit * 5)), || {
+        "`Approval` event isn't emitted".into()
+    })?;
+    ensure_eq(
+        "allowance after approve",
+        token.allowance(ALICE, BOB)?,
+        unit * 5,
+    )?;
+
+    let res = token.call(ALICE, "Approve", (BOB, unit * 5))?;
+    ensure_eq("`Approve` of the same value result", res, false)?;
+
+    let res = token.call(ALICE, "Approve", (BOB, unit * 20))?;
+    ensure_eq("`Approve` above the balance result", res, true)?;
+    ensure_eq(
+        "allowance above the balance",
+        token.allowance(ALICE, BOB)?,
+        unit * 20,
+    )?;
+
+    let outcome = token.send(ALICE, "Approve", (BOB, U256::zero()));
+    ensure_eq("`Approve` of zero result", outcome.result("Approve")?, true)?;
+    ensure(
+        outcome.emitted("Approval", (ALICE, BOB, U256::zero())),
+        || "`Approval` event of zero isn't emitted".into(),
+    )?;
+    ensure_eq(
+        "allowance after approve of zero",
+        token.allowance(ALICE, BOB)?,
+        U256::zero(),
+    )?;
+
+    let res = token.call(ALICE, "Approve", (ALICE, unit))?;
+    ensure_eq("`Approve` to self result", res, false)?;
+    ensure_eq(
+        "allowance to self",
+        token.allowance(ALICE, ALICE)?,
+        U256::zero(),
+    )
+}
+
+fn transfer_from_spends_allowance(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    token.call::<bool>(ALICE, "Approve", (BOB, unit * 5))?;
+
+    let outcome = token.send(BOB, "TransferFrom", (ALICE, CHARLIE, unit * 2));
+    ensure_eq(
+        "`TransferFrom` result",
+        outcome.result("TransferFrom")?,
+        true,
+    )?;
+    ensure(
+        outcome.emitted("Transfer", (ALICE, CHARLIE, unit * 2)),
+        || "`Transfer` event isn't emitted".into(),
+    )?;
+
+    ensure_eq(
+        "allowance after transfer from",
+        token.allowance(ALICE, BOB)?,
+        unit * 3,
+    )?;
+    ensure_eq(
+        "balances after transfer from",
+        token.balances(&[ALICE, BOB, CHARLIE])?,
+        vec![unit * 8, U256::zero(), unit * 2],
+    )?;
+
+    let res = token.call(BOB, "TransferFrom", (ALICE, CHARLIE, unit * 3))?;
+    ensure_eq("`TransferFrom` of whole allowance result", res, true)?;
+    ensure_eq(
+        "allowance after transfer from of whole allowance",
+        token.allowance(ALICE, BOB)?,
+        U256::zero(),
+    )
+}
+
+fn transfer_from_above_allowance_fails(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(BOB, "TransferFrom", (ALICE, CHARLIE, unit));
+    ensure(!outcome.ok, || {
+        "transfer from without allowance succeeded".into()
+    })?;
+
+    token.call::<bool>(ALICE, "Approve", (BOB, unit * 5))?;
+
+    let outcome = token.send(BOB, "TransferFrom", (ALICE, CHARLIE, unit * 5 + 1));
+    ensure(!outcome.ok, || {
+        "transfer from above the allowance succeeded".into()
+    })?;
+
+    token.call::<bool>(ALICE, "Approve", (BOB, unit * 20))?;
+
+    let outcome = token.send(BOB, "TransferFrom", (ALICE, CHARLIE, unit * 11));
+    ensure(!outcome.ok, || {
+        "transfer from above the balance succeeded".into()
+    })?;
+
+    ensure_eq(
+        "allowance after failed transfers from",
+        token.allowance(ALICE, BOB)?,
+        unit * 20,
+    )?;
+    ensure_eq(
+        "balances after failed transfers from",
+        token.balances(&[ALICE, CHARLIE])?,
+        vec![unit * 10, U256::zero()],
+    )
+}
+
+fn minimum_balance(target: &dyn Target) -> Result<(), String> {
+    let minimum_balance = target.minimum_balance();
+
+    if minimum_balance.is_zero() {
+        return Ok(());
+    }
+
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    let outcome = token.send(ALICE, "Transfer", (BOB, unit - 1));
+    ensure(!outcome.ok, || {
+        "transfer creating balance below the minimum succeeded".into()
+    })?;
+    ensure_eq(
+        "balance below the minimum",
+        token.balance_of(BOB)?,
+        U256::zero(),
+    )?;
+
+    let res = token.call(ALICE, "Transfer", (BOB, unit))?;
+    ensure_eq("`Transfer` of the minimum result", res, true)?;
+    ensure_eq("balance of the minimum", token.balance_of(BOB)?, unit)
+}
+
+fn pause_blocks_mutations(target: &dyn Target) -> Result<(), String> {
+    let system = System::new();
+    let (token, unit) = deploy(target, &system, &[(ALICE, 10)]);
+
+    token.call::<bool>(ALICE, "Approve", (BOB, unit * 5))?;
+
+    if !target.pause(&system, &token.program) {
+        return Ok(());
+    }
+
+    let outcome = token.send(ALICE, "Transfer", (BOB, unit));
+    ensure(!outcome.ok, || "transfer while paused succeeded".into())?;
+
+    let outcome = token.send(ALICE, "Approve", (CHARLIE, unit));
+    ensure(!outcome.ok, || "approve while paused succeeded".into())?;
+
+    let outcome = token.send(BOB, "TransferFrom", (ALICE, CHARLIE, unit));
+    ensure(!outcome.ok, || {
+        "transfer from while paused succeeded".into()
+    })?;
+
+    ensure_eq(
+        "balances while paused",
+        token.balances(&[ALICE, BOB, CHARLIE])?,
+        vec![unit * 10, U256::zero(), U256::zero()],
+    )?;
+    ensure_eq(
+        "allowance while paused",
+        token.allowance(ALICE, BOB)?,
+        unit * 5,
+    )
+}
@@ -0,0 +1,158 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_vft_conformance::{ALICE, Target, assert_conforms, run};
+use sails_rs::{
+    ActorId, U256,
+    gtest::{Program, System},
+    scale_codec::{Decode, Encode},
+};
+
+#[cfg(debug_assertions)]
+const WASM_PATH: &str = "../../target/wasm32-gear/debug/awesome_sails_test_app.opt.wasm";
+#[cfg(not(debug_assertions))]
+const WASM_PATH: &str = "../../target/wasm32-gear/release/awesome_sails_test_app.opt.wasm";
+
+/// The test program, admined by Alice.
+#[derive(Default)]
+struct TestApp {
+    minimum_balance: U256,
+}
+
+impl TestApp {
+    fn call<R: Decode>(
+        system: &System,
+        program: &Program<'_>,
+        service: &str,
+        method: &str,
+        args: impl Encode,
+    ) -> Option<R> {
+        let prefix = (service, method).encode();
+
+        let message_id = program.send_bytes(
+            ALICE,
+            [prefix.as_slice(), args.encode().as_slice()].concat(),
+        );
+        let result = system.run_next_block();
+
+        if !result.succeed.contains(&message_id) {
+            return None;
+        }
+
+        let reply = result
+            .log()
+            .iter()
+            .find(|log| log.reply_to() == Some(message_id))?
+            .payload();
+
+        R::decode(&mut reply.strip_prefix(prefix.as_slice())?).ok()
+    }
+}
+
+impl Target for TestApp {
+    fn deploy<'a>(&self, system: &'a System, balances: &[(ActorId, U256)]) -> Program<'a> {
+        let code = std::fs::read(WASM_PATH).unwrap();
+        let program = Program::from_binary_with_id(system, ActorId::from(1), &code);
+
+        program.send_bytes(ALICE, "New".encode());
+        system.run_next_block();
+
+        for method in ["AllocateNextBalancesShard", "AllocateNextAllowancesShard"] {
+            while Self::call::<bool>(system, &program, "VftExtension", method, ())
+                .expect("failed to allocate next shard")
+            {}
+        }
+
+        Self::call::<()>(
+            system,
+            &program,
+            "Test",
+            "Set",
+            (
+                Vec::<(ActorId, ActorId, U256, u32)>::new(),
+                balances.to_vec(),
+                100u32,
+            ),
+        )
+        .expect("failed to set balances");
+
+        if !self.minimum_balance.is_zero() {
+            Self::call::<()>(
+                system,
+                &program,
+                "VftAdmin",
+                "SetMinimumBalance",
+                self.minimum_balance,
+            )
+            .expect("failed to set minimum balance");
+        }
+
+        program
+    }
+
+    fn minimum_balance(&self) -> U256 {
+        self.minimum_balance
+    }
+
+    fn pause(&self, system: &System, program: &Program<'_>) -> bool {
+        Self::call::<()>(
+            system,
+            program,
+            "VftAdmin",
+            "Pause",
+            (None::<()>, None::<u32>),
+        )
+        .is_some()
+    }
+}
+
+/// The test program claiming the minimum balance it doesn't have.
+struct WrongMinimum;
+
+impl Target for WrongMinimum {
+    fn deploy<'a>(&self, system: &'a System, balances: &[(ActorId, U256)]) -> Program<'a> {
+        TestApp::default().deploy(system, balances)
+    }
+
+    fn minimum_balance(&self) -> U256 {
+        U256::exp10(3)
+    }
+}
+
+#[test]
+fn test_app_conforms() {
+    assert_conforms(&TestApp::default());
+}
+
+#[test]
+fn test_app_conforms_with_minimum_balance() {
+    assert_conforms(&TestApp {
+        minimum_balance: U256::exp10(3),
+    });
+}
+
+#[test]
+fn reports_failed_checks() {
+    let failed: Vec<_> = run(&WrongMinimum)
+        .into_iter()
+        .filter(|(_, res)| res.is_err())
+        .map(|(name, _)| name)
+        .collect();
+
+    assert_eq!(failed, ["minimum_balance"]);
+}
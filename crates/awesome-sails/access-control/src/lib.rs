@@ -21,13 +21,17 @@
 //! This service implements a role-based access control (RBAC) mechanism with support for
 //! role hierarchies, enumeration, and batch operations.
 //!
+//! The service uses deterministic storage (`BTreeMap`) and provides methods to enumerate
+//! all roles and their members, as well as perform bulk updates via batch functions.
+//!
 //! # Role Hierarchy
 //!
 //! * **Super Admin (`DEFAULT_ADMIN_ROLE`)**:
 //!     * Acts as a **Master Key**: an account with this role passes any `require_role` check,
 //!       regardless of the specific role requested.
 //!     * Is the default administrator for all new roles.
-//!     * Can grant/revoke any role and change any role's administrator.
+//!     * Can grant/revoke any role and change any role's administrator. Doing so without
+//!       holding the role's administrator role emits a `SuperAdminOverrideUsed` event.
 //!
 //! * **Role Admin**:
 //!     * Each role has an associated administrator role (by default, the Super Admin role).
@@ -35,18 +39,78 @@
 //!     * Administrator roles can be changed via `set_role_admin` to create complex
 //!       permission structures.
 //!
-//! The service uses deterministic storage (`BTreeMap`) and provides methods to enumerate
-//! all roles and their members, as well as perform bulk updates via batch functions.
+//! * **Parent Role**:
+//!     * A role may inherit from a parent role set via `set_role_parent`: holders of the
+//!       parent (or any of its ancestors) are considered to hold the child role as well.
+//!     * Inheritance only affects role checks; enumeration lists explicit members only.
+//!
+//! # Locked Roles
+//!
+//! A role admin may lock a role via `lock_role`, e.g. to make `MINTER_ROLE` provably closed
+//! after launch. Locking is irreversible: no new members may be granted, and neither the
+//! admin role nor the parent role of a locked role may change. Roles with a parent can't be
+//! locked, as they would gain holders via the parent. Members may still be revoked
+//! or renounce it.
+//!
+//! # Grant Delay
+//!
+//! A role may be configured with a grant delay via `set_role_grant_delay`. Granting such
+//! a role only schedules the grant: it becomes active once `execute_role_grant` is called
+//! after the delay has passed, and may be cancelled by the role admin until then.
+//!
+//! # Renouncing Super Admin
+//!
+//! Renouncing `DEFAULT_ADMIN_ROLE` instantly could brick the program, so `renounce_role`
+//! only schedules it for `get_admin_renounce_delay` blocks, set by the super admin via
+//! `set_admin_renounce_delay`. Once they pass, the account completes it via
+//! `execute_admin_renounce`, and until then may change its mind via `cancel_admin_renounce`.
+//!
+//! The last super admin can neither renounce the role nor have it revoked via `revoke_role`,
+//! unless the super admin explicitly allowed leaving the program admin-less via
+//! `set_allow_admin_less`, e.g. to make it immutable.
+//!
+//! # Recovery
+//!
+//! The super admin may appoint guardians via `set_recovery_guardians`. Once `threshold` of
+//! them approve the same candidate with `propose_recovery`, and `delay` blocks pass,
+//! `execute_recovery` transfers `DEFAULT_ADMIN_ROLE` to the candidate, revoking it from
+//! all other holders. Until then the super admin may reject the candidate via `cancel_recovery`.
+//!
+//! # Initialization
+//!
+//! Constructors grant `DEFAULT_ADMIN_ROLE` to the initial admin via `grant_initial_admin`,
+//! which can't emit events. Calling `init_access_control` once deployed emits the
+//! `RoleGranted` event of that grant, so indexers see the complete history of roles.
+//!
+//! # Pagination
+//!
+//! Enumeration queries reply with at most `get_max_page_size` items, set by the super admin
+//! via `set_max_page_size`. Queries requesting more fail with `PageTooLarge` upfront.
+//!
+//! # Viewers
+//!
+//! Heavy enumeration exports (`get_all_members`, `get_memberships`, `audit_log`) are public
+//! by default. Once the super admin enables the viewer gate via `set_viewer_gate`, they only
+//! reply to holders of `VIEWER_ROLE` and super admins, so public nodes aren't forced to serve
+//! expensive scans to everyone. Services built on top gate their exports via `require_viewer`.
 
 #![no_std]
 
 pub use awesome_sails_utils::ensure;
 
-use crate::error::{AccessDenied, EmitError, Error, NotAccountOwner};
-use awesome_sails_utils::storage::{InfallibleStorageMut, StorageRefCell};
+use crate::error::{
+    AccessDenied, AccessDeniedAny, BadInput, EmitError, Error, LastAdminProtection,
+    NotAccountOwner, NotGuardian, PendingGrantNotFound, PendingGrantNotReady, RecoveryNotFound,
+    RecoveryNotReady, RenounceNotFound, RenounceNotReady, RoleCycle, RoleLocked,
+};
+use awesome_sails_utils::{
+    audit::{AuditEntry, AuditLog},
+    page::{DEFAULT_MAX_PAGE_SIZE, PageTooLarge, ensure_page_size},
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
 use core::marker::PhantomData;
 use sails_rs::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, btree_map},
     prelude::*,
 };
 
@@ -54,15 +118,131 @@ pub type RoleId = [u8; 32];
 
 pub const DEFAULT_ADMIN_ROLE: RoleId = [0u8; 32];
 
-#[derive(Default, Debug)]
+/// Role allowed to call heavy enumeration exports once the viewer gate is enabled.
+pub const VIEWER_ROLE: RoleId = role_id("VIEWER_ROLE");
+
+/// Derives the ID of the role named `name` as keccak256 of the name, e.g.
+/// `role_id("MINTER_ROLE")`. `DEFAULT_ADMIN_ROLE` is not derived, being all zeros.
+pub const fn role_id(name: &str) -> RoleId {
+    keccak_const::Keccak256::new()
+        .update(name.as_bytes())
+        .finalize()
+}
+
+/// Default number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for,
+/// i.e. a day of 3 seconds blocks.
+pub const DEFAULT_ADMIN_RENOUNCE_DELAY: u32 = 28_800;
+
+#[derive(Debug)]
 pub struct RolesStorage {
     roles: BTreeMap<RoleId, RoleData>,
+    /// Secondary index of roles held by each member, kept in sync with `roles`.
+    member_roles: BTreeMap<ActorId, BTreeSet<RoleId>>,
+    pending_grants: BTreeMap<(RoleId, ActorId), PendingGrant>,
+    /// Blocks each pending renounce of `DEFAULT_ADMIN_ROLE` is ready at.
+    pending_admin_renounces: BTreeMap<ActorId, u32>,
+    admin_renounce_delay: u32,
+    allow_admin_less: bool,
+    recovery: RecoveryConfig,
+    recovery_proposals: BTreeMap<ActorId, RecoveryProposal>,
+    audit_log: AuditLog<AuditEntry>,
+    max_page_size: u32,
+    viewer_gate: bool,
+    /// Initial admin whose grant is yet to be announced by `init_access_control`.
+    unannounced_admin: Option<ActorId>,
+}
+
+impl Default for RolesStorage {
+    fn default() -> Self {
+        Self {
+            roles: Default::default(),
+            member_roles: Default::default(),
+            pending_grants: Default::default(),
+            pending_admin_renounces: Default::default(),
+            admin_renounce_delay: DEFAULT_ADMIN_RENOUNCE_DELAY,
+            allow_admin_less: false,
+            recovery: Default::default(),
+            recovery_proposals: Default::default(),
+            audit_log: Default::default(),
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+            viewer_gate: false,
+            unannounced_admin: None,
+        }
+    }
 }
 
 #[derive(Default, Debug)]
 pub struct RoleData {
     members: BTreeSet<ActorId>,
     admin_role_id: RoleId,
+    parent_role_id: Option<RoleId>,
+    grant_delay: u32,
+    locked: bool,
+}
+
+/// Grant of a role awaiting its delay to pass.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PendingGrant {
+    pub ready_at: u32,
+    pub sender: ActorId,
+    pub via_role: RoleId,
+}
+
+/// Guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+///
+/// Recovery is disabled while there are no guardians.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<ActorId>,
+    pub threshold: u32,
+    pub delay: u32,
+}
+
+/// Guardians' approvals of a candidate for `DEFAULT_ADMIN_ROLE`.
+///
+/// `ready_at` is set once the approvals reach the threshold.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryProposal {
+    pub approvals: Vec<ActorId>,
+    pub ready_at: Option<u32>,
+}
+
+/// Effects of `grant_role` previewed by `simulate_grant_role`.
+///
+/// The grant emits `SuperAdminOverrideUsed` event if `via_role` isn't the admin role
+/// of the role, and, if `changed`, `RoleGrantScheduled` event if `ready_at` is set
+/// or `RoleGranted` one otherwise.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GrantPreview {
+    /// Role authorizing the grant.
+    pub via_role: RoleId,
+    /// Block the grant is scheduled to, if the role has a grant delay.
+    pub ready_at: Option<u32>,
+    /// Whether the role isn't granted or pending yet.
+    pub changed: bool,
+}
+
+/// Permissions of an account, reported by `permissions_of`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Permissions {
+    /// Roles granted to the account explicitly.
+    pub roles: Vec<RoleId>,
+    /// Known roles the account may grant and revoke, with their grant delays.
+    pub administered: Vec<(RoleId, u32)>,
+    /// Grants to the account awaiting their delay.
+    pub pending_grants: Vec<(RoleId, PendingGrant)>,
+    /// Whether the account holds `DEFAULT_ADMIN_ROLE`, passing any role check.
+    pub is_super_admin: bool,
 }
 
 #[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy)]
@@ -74,12 +254,36 @@ pub struct Pagination {
 }
 
 impl RolesStorage {
+    /// Returns `true` if `account_id` holds `role_id` or any of its ancestors.
     pub fn has_role(&self, role_id: RoleId, account_id: ActorId) -> bool {
+        self.member_roles
+            .get(&account_id)
+            .is_some_and(|roles| self.role_lineage(role_id).any(|r| roles.contains(&r)))
+    }
+
+    /// Returns `true` if `role_id` admits no new members, see `lock_role`.
+    pub fn is_role_locked(&self, role_id: RoleId) -> bool {
+        self.roles.get(&role_id).is_some_and(|data| data.locked)
+    }
+
+    /// Returns `true` if `account_id` has been granted `role_id` explicitly.
+    pub fn is_member(&self, role_id: RoleId, account_id: ActorId) -> bool {
         self.roles
             .get(&role_id)
             .is_some_and(|data| data.members.contains(&account_id))
     }
 
+    pub fn get_role_parent(&self, role_id: RoleId) -> Option<RoleId> {
+        self.roles
+            .get(&role_id)
+            .and_then(|data| data.parent_role_id)
+    }
+
+    /// Iterates over `role_id` followed by its ancestors.
+    pub fn role_lineage(&self, role_id: RoleId) -> impl Iterator<Item = RoleId> + '_ {
+        core::iter::successors(Some(role_id), |&r| self.get_role_parent(r))
+    }
+
     pub fn get_role_admin(&self, role_id: RoleId) -> RoleId {
         self.roles
             .get(&role_id)
@@ -91,17 +295,8 @@ impl RolesStorage {
         self.roles.len() as u32
     }
 
-    pub fn get_roles(&self, query: Option<Pagination>) -> Vec<RoleId> {
-        let (offset, limit) = query
-            .map(|q| (q.offset as usize, q.limit as usize))
-            .unwrap_or((0, usize::MAX));
-
-        self.roles
-            .keys()
-            .skip(offset)
-            .take(limit)
-            .copied()
-            .collect()
+    pub fn get_roles(&self, query: Option<Pagination>) -> Result<Vec<RoleId>, PageTooLarge> {
+        self.paginate(self.roles.keys().copied(), query)
     }
 
     pub fn get_role_member_count(&self, role_id: RoleId) -> u32 {
@@ -111,51 +306,224 @@ impl RolesStorage {
             .unwrap_or_default()
     }
 
-    pub fn get_role_members(&self, role_id: RoleId, query: Option<Pagination>) -> Vec<ActorId> {
-        let (offset, limit) = query
-            .map(|q| (q.offset as usize, q.limit as usize))
-            .unwrap_or((0, usize::MAX));
+    pub fn get_role_members(
+        &self,
+        role_id: RoleId,
+        query: Option<Pagination>,
+    ) -> Result<Vec<ActorId>, PageTooLarge> {
+        let members = self.roles.get(&role_id).map(|data| &data.members);
+
+        self.paginate(members.into_iter().flatten().copied(), query)
+    }
+
+    pub fn has_any_role(&self, role_ids: &[RoleId], account_id: ActorId) -> bool {
+        self.member_roles.get(&account_id).is_some_and(|roles| {
+            role_ids
+                .iter()
+                .flat_map(|&role_id| self.role_lineage(role_id))
+                .any(|r| roles.contains(&r))
+        })
+    }
+
+    pub fn get_member_role_count(&self, member_id: ActorId) -> u32 {
+        self.member_roles
+            .get(&member_id)
+            .map(|roles| roles.len() as u32)
+            .unwrap_or_default()
+    }
+
+    pub fn get_member_roles(
+        &self,
+        member_id: ActorId,
+        query: Option<Pagination>,
+    ) -> Result<Vec<RoleId>, PageTooLarge> {
+        self.paginate(self.member_roles_iter(member_id), query)
+    }
+
+    pub fn get_all_member_count(&self) -> u32 {
+        self.member_roles.len() as u32
+    }
+
+    pub fn get_all_members(&self, query: Option<Pagination>) -> Result<Vec<ActorId>, PageTooLarge> {
+        self.paginate(self.member_roles.keys().copied(), query)
+    }
+
+    pub fn get_memberships(
+        &self,
+        query: Option<Pagination>,
+    ) -> Result<Vec<(RoleId, ActorId)>, PageTooLarge> {
+        let memberships = self
+            .roles
+            .iter()
+            .flat_map(|(&role_id, data)| data.members.iter().map(move |&member| (role_id, member)));
+
+        self.paginate(memberships, query)
+    }
+
+    /// Returns the max amount of items enumeration queries reply with at once.
+    pub fn get_max_page_size(&self) -> u32 {
+        self.max_page_size
+    }
+
+    /// Returns whether heavy enumeration exports are restricted to viewers.
+    pub fn get_viewer_gate(&self) -> bool {
+        self.viewer_gate
+    }
+
+    /// Collects the page of `items` requested by `query`, or all of them if none,
+    /// failing if it would exceed the max page size.
+    fn paginate<T>(
+        &self,
+        items: impl Iterator<Item = T>,
+        query: Option<Pagination>,
+    ) -> Result<Vec<T>, PageTooLarge> {
+        let max_page_size = self.max_page_size;
+
+        let Some(Pagination { offset, limit }) = query else {
+            // Visits at most one extra item to tell if all of them fit the page.
+            let items: Vec<_> = items.take(max_page_size as usize + 1).collect();
+            ensure_page_size(items.len() as u32, max_page_size)?;
+
+            return Ok(items);
+        };
+
+        ensure_page_size(limit, max_page_size)?;
+
+        Ok(items.skip(offset as usize).take(limit as usize).collect())
+    }
+
+    fn member_roles_iter(&self, member_id: ActorId) -> impl Iterator<Item = RoleId> + '_ {
+        self.member_roles
+            .get(&member_id)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
 
+    pub fn get_role_grant_delay(&self, role_id: RoleId) -> u32 {
         self.roles
             .get(&role_id)
-            .map(|data| {
-                data.members
-                    .iter()
-                    .skip(offset)
-                    .take(limit)
-                    .copied()
-                    .collect()
-            })
+            .map(|data| data.grant_delay)
             .unwrap_or_default()
     }
 
-    pub fn get_member_role_count(&self, member_id: ActorId) -> u32 {
-        self.roles
-            .values()
-            .filter(|data| data.members.contains(&member_id))
-            .count() as u32
+    pub fn get_pending_grant(&self, role_id: RoleId, account_id: ActorId) -> Option<PendingGrant> {
+        self.pending_grants.get(&(role_id, account_id)).copied()
+    }
+
+    /// Returns the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+    pub fn get_admin_renounce_delay(&self) -> u32 {
+        self.admin_renounce_delay
     }
 
-    pub fn get_member_roles(&self, member_id: ActorId, query: Option<Pagination>) -> Vec<RoleId> {
-        let (offset, limit) = query
-            .map(|q| (q.offset as usize, q.limit as usize))
-            .unwrap_or((0, usize::MAX));
+    /// Returns the block the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`
+    /// is ready at, if any.
+    pub fn get_pending_admin_renounce(&self, account_id: ActorId) -> Option<u32> {
+        self.pending_admin_renounces.get(&account_id).copied()
+    }
 
-        self.roles
-            .iter()
-            .filter(|(_, data)| data.members.contains(&member_id))
-            .map(|(&role_id, _)| role_id)
-            .skip(offset)
-            .take(limit)
-            .collect()
+    /// Returns whether the last super admin may give up `DEFAULT_ADMIN_ROLE`.
+    pub fn get_allow_admin_less(&self) -> bool {
+        self.allow_admin_less
     }
 
+    /// Reports roles held and administered by `account_id`, and grants pending to it.
+    pub fn permissions_of(&self, account_id: ActorId) -> Permissions {
+        let is_super_admin = self.has_role(DEFAULT_ADMIN_ROLE, account_id);
+
+        Permissions {
+            roles: self.member_roles_iter(account_id).collect(),
+            administered: self
+                .roles
+                .iter()
+                .filter(|(_, data)| is_super_admin || self.has_role(data.admin_role_id, account_id))
+                .map(|(&role_id, data)| (role_id, data.grant_delay))
+                .collect(),
+            pending_grants: self
+                .pending_grants
+                .iter()
+                .filter(|((_, target_account), _)| *target_account == account_id)
+                .map(|(&(role_id, _), &grant)| (role_id, grant))
+                .collect(),
+            is_super_admin,
+        }
+    }
+
+    pub fn get_recovery_config(&self) -> RecoveryConfig {
+        self.recovery.clone()
+    }
+
+    pub fn get_recovery_proposal(&self, new_admin: ActorId) -> Option<RecoveryProposal> {
+        self.recovery_proposals.get(&new_admin).cloned()
+    }
+
+    pub fn is_guardian(&self, account_id: ActorId) -> bool {
+        self.recovery.guardians.binary_search(&account_id).is_ok()
+    }
+
+    pub fn get_audit_log(
+        &self,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Vec<(u32, AuditEntry)>, PageTooLarge> {
+        ensure_page_size(len, self.max_page_size)?;
+
+        Ok(self.audit_log.entries(cursor, len))
+    }
+
+    /// Returns the index the next audit log entry will be assigned,
+    /// i.e. the amount of privileged calls made.
+    pub fn get_audit_seq(&self) -> u32 {
+        self.audit_log.next_index()
+    }
+
+    /// Grants `DEFAULT_ADMIN_ROLE` to `deployer` without emitting events, e.g. in
+    /// constructors, leaving the grant to be announced by `init_access_control`.
     pub fn grant_initial_admin(&mut self, deployer: ActorId) {
-        self.roles
-            .entry(DEFAULT_ADMIN_ROLE)
+        if self.insert_member(DEFAULT_ADMIN_ROLE, deployer) {
+            self.unannounced_admin = Some(deployer);
+        }
+    }
+
+    fn insert_member(&mut self, role_id: RoleId, account_id: ActorId) -> bool {
+        let inserted = self
+            .roles
+            .entry(role_id)
             .or_default()
             .members
-            .insert(deployer);
+            .insert(account_id);
+
+        if inserted {
+            self.member_roles
+                .entry(account_id)
+                .or_default()
+                .insert(role_id);
+        }
+
+        inserted
+    }
+
+    fn remove_member(&mut self, role_id: RoleId, account_id: ActorId) -> bool {
+        let removed = self
+            .roles
+            .get_mut(&role_id)
+            .is_some_and(|data| data.members.remove(&account_id));
+
+        if removed
+            && let btree_map::Entry::Occupied(mut entry) = self.member_roles.entry(account_id)
+        {
+            entry.get_mut().remove(&role_id);
+
+            if entry.get().is_empty() {
+                entry.remove();
+            }
+        }
+
+        if removed && role_id == DEFAULT_ADMIN_ROLE {
+            self.pending_admin_renounces.remove(&account_id);
+        }
+
+        removed
     }
 }
 
@@ -178,19 +546,100 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     fn grant_role_unchecked(&mut self, role_id: RoleId, target_account: ActorId) -> bool {
         self.storage
             .get_mut()
-            .roles
-            .entry(role_id)
-            .or_default()
-            .members
-            .insert(target_account)
+            .insert_member(role_id, target_account)
     }
 
     fn revoke_role_unchecked(&mut self, role_id: RoleId, target_account: ActorId) -> bool {
         self.storage
             .get_mut()
-            .roles
-            .get_mut(&role_id)
-            .is_some_and(|role_data| role_data.members.remove(&target_account))
+            .remove_member(role_id, target_account)
+    }
+
+    /// Ensures that `role_id` isn't locked, failing with `RoleLocked` otherwise.
+    fn ensure_unlocked(&self, role_id: RoleId) -> Result<(), Error> {
+        ensure!(
+            !self.storage.get().is_role_locked(role_id),
+            RoleLocked { role_id }
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that removing `DEFAULT_ADMIN_ROLE` from `account_id` leaves a super admin,
+    /// unless admin-less programs are allowed.
+    fn ensure_not_last_admin(&self, account_id: ActorId) -> Result<(), Error> {
+        let storage = self.storage.get();
+
+        ensure!(
+            storage.allow_admin_less
+                || !storage.is_member(DEFAULT_ADMIN_ROLE, account_id)
+                || storage.get_role_member_count(DEFAULT_ADMIN_ROLE) > 1,
+            LastAdminProtection { account_id }
+        );
+
+        Ok(())
+    }
+
+    fn set_role_grant_delay_unchecked(&mut self, role_id: RoleId, delay: u32) -> u32 {
+        core::mem::replace(
+            &mut self
+                .storage
+                .get_mut()
+                .roles
+                .entry(role_id)
+                .or_default()
+                .grant_delay,
+            delay,
+        )
+    }
+
+    /// Schedules grant of `role_id` to `target_account`, unless already granted or pending.
+    fn schedule_grant_unchecked(
+        &mut self,
+        role_id: RoleId,
+        target_account: ActorId,
+        grant: PendingGrant,
+    ) -> bool {
+        let mut storage = self.storage.get_mut();
+
+        if storage.is_member(role_id, target_account) {
+            return false;
+        }
+
+        match storage.pending_grants.entry((role_id, target_account)) {
+            btree_map::Entry::Occupied(_) => false,
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(grant);
+                true
+            }
+        }
+    }
+
+    fn set_role_parent_unchecked(
+        &mut self,
+        role_id: RoleId,
+        parent_role_id: Option<RoleId>,
+    ) -> Option<RoleId> {
+        core::mem::replace(
+            &mut self
+                .storage
+                .get_mut()
+                .roles
+                .entry(role_id)
+                .or_default()
+                .parent_role_id,
+            parent_role_id,
+        )
+    }
+
+    /// Records privileged call of the current message source in the audit log.
+    fn audit(&mut self, action: &str, params: impl Encode) {
+        self.storage.get_mut().audit_log.push(AuditEntry::new(
+            Syscall::block_height(),
+            Syscall::message_source(),
+            action,
+            &params,
+        ));
     }
 
     fn set_role_admin_unchecked(&mut self, role_id: RoleId, admin_role_id: RoleId) {
@@ -203,14 +652,28 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service(events = Event)]
 impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
-    /// Returns `true` if `account_id` has been granted `role_id`.
+    /// Returns `true` if `account_id` has been granted `role_id` or inherits it
+    /// from a parent role.
     #[export]
     pub fn has_role(&self, role_id: RoleId, account_id: ActorId) -> bool {
         self.storage.get().has_role(role_id, account_id)
     }
 
+    /// Returns the parent role `role_id` inherits from, if any.
+    #[export]
+    pub fn get_role_parent(&self, role_id: RoleId) -> Option<RoleId> {
+        self.storage.get().get_role_parent(role_id)
+    }
+
+    /// Returns `true` if `role_id` is locked, admitting no new members.
+    #[export]
+    pub fn is_role_locked(&self, role_id: RoleId) -> bool {
+        self.storage.get().is_role_locked(role_id)
+    }
+
     /// Returns the admin role ID that controls `role_id`.
     #[export]
     pub fn get_role_admin(&self, role_id: RoleId) -> RoleId {
@@ -224,9 +687,9 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     }
 
     /// Returns a list of role IDs with pagination.
-    #[export]
-    pub fn get_roles(&self, query: Option<Pagination>) -> Vec<RoleId> {
-        self.storage.get().get_roles(query)
+    #[export(unwrap_result)]
+    pub fn get_roles(&self, query: Option<Pagination>) -> Result<Vec<RoleId>, Error> {
+        Ok(self.storage.get().get_roles(query)?)
     }
 
     /// Returns the number of members in the specified role.
@@ -236,9 +699,13 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     }
 
     /// Returns a list of members in the specified role with pagination.
-    #[export]
-    pub fn get_role_members(&self, role_id: RoleId, query: Option<Pagination>) -> Vec<ActorId> {
-        self.storage.get().get_role_members(role_id, query)
+    #[export(unwrap_result)]
+    pub fn get_role_members(
+        &self,
+        role_id: RoleId,
+        query: Option<Pagination>,
+    ) -> Result<Vec<ActorId>, Error> {
+        Ok(self.storage.get().get_role_members(role_id, query)?)
     }
 
     /// Returns the number of roles assigned to the specified member.
@@ -248,19 +715,158 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     }
 
     /// Returns a list of roles assigned to the specified member with pagination.
+    #[export(unwrap_result)]
+    pub fn get_member_roles(
+        &self,
+        member_id: ActorId,
+        query: Option<Pagination>,
+    ) -> Result<Vec<RoleId>, Error> {
+        Ok(self.storage.get().get_member_roles(member_id, query)?)
+    }
+
+    /// Returns the number of distinct accounts holding any role.
+    #[export]
+    pub fn get_all_member_count(&self) -> u32 {
+        self.storage.get().get_all_member_count()
+    }
+
+    /// Returns a list of distinct accounts holding any role with pagination.
+    ///
+    /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+    #[export(unwrap_result)]
+    pub fn get_all_members(&self, query: Option<Pagination>) -> Result<Vec<ActorId>, Error> {
+        self.require_viewer(Syscall::message_source())?;
+
+        Ok(self.storage.get().get_all_members(query)?)
+    }
+
+    /// Returns a list of `(role_id, member_id)` pairs across all roles with pagination.
+    ///
+    /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+    #[export(unwrap_result)]
+    pub fn get_memberships(
+        &self,
+        query: Option<Pagination>,
+    ) -> Result<Vec<(RoleId, ActorId)>, Error> {
+        self.require_viewer(Syscall::message_source())?;
+
+        Ok(self.storage.get().get_memberships(query)?)
+    }
+
+    /// Returns the max amount of items enumeration queries reply with at once.
+    #[export]
+    pub fn get_max_page_size(&self) -> u32 {
+        self.storage.get().get_max_page_size()
+    }
+
+    /// Returns whether heavy enumeration exports are restricted to `VIEWER_ROLE` holders.
+    #[export]
+    pub fn get_viewer_gate(&self) -> bool {
+        self.storage.get().get_viewer_gate()
+    }
+
+    /// Returns the number of blocks a grant of `role_id` is delayed for.
+    #[export]
+    pub fn get_role_grant_delay(&self, role_id: RoleId) -> u32 {
+        self.storage.get().get_role_grant_delay(role_id)
+    }
+
+    /// Returns the pending grant of `role_id` to `account_id`, if any.
+    #[export]
+    pub fn get_pending_grant(&self, role_id: RoleId, account_id: ActorId) -> Option<PendingGrant> {
+        self.storage.get().get_pending_grant(role_id, account_id)
+    }
+
+    /// Returns the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+    #[export]
+    pub fn get_admin_renounce_delay(&self) -> u32 {
+        self.storage.get().get_admin_renounce_delay()
+    }
+
+    /// Returns the block the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`
+    /// is ready at, if any.
+    #[export]
+    pub fn get_pending_admin_renounce(&self, account_id: ActorId) -> Option<u32> {
+        self.storage.get().get_pending_admin_renounce(account_id)
+    }
+
+    /// Returns whether the last super admin may give up `DEFAULT_ADMIN_ROLE`.
+    #[export]
+    pub fn get_allow_admin_less(&self) -> bool {
+        self.storage.get().get_allow_admin_less()
+    }
+
+    /// Returns up to `len` audit log entries of privileged calls, starting from `cursor`
+    /// or the oldest entry kept.
+    ///
+    /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+    #[export(unwrap_result)]
+    pub fn audit_log(&self, cursor: u32, len: u32) -> Result<Vec<(u32, AuditEntry)>, Error> {
+        self.require_viewer(Syscall::message_source())?;
+
+        Ok(self.storage.get().get_audit_log(cursor, len)?)
+    }
+
+    /// Returns the guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+    #[export]
+    pub fn get_recovery_config(&self) -> RecoveryConfig {
+        self.storage.get().get_recovery_config()
+    }
+
+    /// Returns guardians' approvals of `new_admin` as the recovered super admin, if any.
     #[export]
-    pub fn get_member_roles(&self, member_id: ActorId, query: Option<Pagination>) -> Vec<RoleId> {
-        self.storage.get().get_member_roles(member_id, query)
+    pub fn get_recovery_proposal(&self, new_admin: ActorId) -> Option<RecoveryProposal> {
+        self.storage.get().get_recovery_proposal(new_admin)
+    }
+
+    /// Returns roles held and administered by `account_id`, and grants pending to it,
+    /// in a single query.
+    #[export]
+    pub fn permissions_of(&self, account_id: ActorId) -> Permissions {
+        self.storage.get().permissions_of(account_id)
+    }
+
+    /// Previews `grant_role` by the caller without mutating storage, e.g. for signers
+    /// of a multisig to review its effects, failing as `grant_role` would.
+    #[export(unwrap_result)]
+    pub fn simulate_grant_role(
+        &self,
+        role_id: RoleId,
+        target_account: ActorId,
+    ) -> Result<GrantPreview, Error> {
+        let via_role =
+            self.require_role(self.get_role_admin(role_id), Syscall::message_source())?;
+
+        self.ensure_unlocked(role_id)?;
+
+        let storage = self.storage.get();
+
+        let delay = storage.get_role_grant_delay(role_id);
+        let ready_at = (delay != 0).then(|| Syscall::block_height().saturating_add(delay));
+
+        let changed = !storage.is_member(role_id, target_account)
+            && (ready_at.is_none() || storage.get_pending_grant(role_id, target_account).is_none());
+
+        Ok(GrantPreview {
+            via_role,
+            ready_at,
+            changed,
+        })
     }
 
     /// Ensures that `account_id` has `role_id` or is a super admin.
     ///
+    /// Returns the role that satisfied the check: `role_id` if held, otherwise
+    /// `DEFAULT_ADMIN_ROLE`.
+    ///
     /// Requirements:
     ///
     /// - `account_id` must have `role_id` or `DEFAULT_ADMIN_ROLE`.
-    pub fn require_role(&self, role_id: RoleId, account_id: ActorId) -> Result<(), Error> {
-        if self.has_role(role_id, account_id) || self.has_role(DEFAULT_ADMIN_ROLE, account_id) {
-            Ok(())
+    pub fn require_role(&self, role_id: RoleId, account_id: ActorId) -> Result<RoleId, Error> {
+        if self.has_role(role_id, account_id) {
+            Ok(role_id)
+        } else if self.has_role(DEFAULT_ADMIN_ROLE, account_id) {
+            Ok(DEFAULT_ADMIN_ROLE)
         } else {
             Err(AccessDenied {
                 account_id,
@@ -270,27 +876,81 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
         }
     }
 
+    /// Ensures that `account_id` has any of `role_ids` or is a super admin.
+    ///
+    /// Requirements:
+    ///
+    /// - `account_id` must have one of `role_ids` or `DEFAULT_ADMIN_ROLE`.
+    pub fn require_any_role(&self, role_ids: &[RoleId], account_id: ActorId) -> Result<(), Error> {
+        let storage = self.storage.get();
+
+        if storage.has_any_role(role_ids, account_id)
+            || storage.has_role(DEFAULT_ADMIN_ROLE, account_id)
+        {
+            Ok(())
+        } else {
+            Err(AccessDeniedAny {
+                account_id,
+                role_ids: role_ids.to_vec(),
+            }
+            .into())
+        }
+    }
+
+    /// Ensures that `account_id` may call heavy enumeration exports.
+    ///
+    /// Requirements:
+    ///
+    /// - if the viewer gate is enabled, `account_id` must have `VIEWER_ROLE`
+    ///   or `DEFAULT_ADMIN_ROLE`.
+    pub fn require_viewer(&self, account_id: ActorId) -> Result<(), Error> {
+        if self.storage.get().get_viewer_gate() {
+            self.require_role(VIEWER_ROLE, account_id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Announces the grant of `DEFAULT_ADMIN_ROLE` made by `grant_initial_admin`,
+    /// emitting its `RoleGranted` event with the initial admin as the sender.
+    ///
+    /// Returns `false` if there is no grant to announce, e.g. it's already announced.
+    /// Anyone may call it, as it only reports the grant made.
+    #[export(unwrap_result)]
+    pub fn init_access_control(&mut self) -> Result<bool, Error> {
+        let Some(admin) = self.storage.get_mut().unannounced_admin.take() else {
+            return Ok(false);
+        };
+
+        self.emit_event(Event::RoleGranted {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: admin,
+            sender: admin,
+            via_role: DEFAULT_ADMIN_ROLE,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
     /// Grants `role_id` to `target_account`.
     ///
     /// If `target_account` had not been already granted `role_id`, emits a `RoleGranted`
-    /// event.
+    /// event. If `role_id` has a grant delay, the grant is scheduled instead and a
+    /// `RoleGrantScheduled` event is emitted.
     ///
     /// Requirements:
     ///
     /// - the caller must have `role_id`'s admin role.
+    /// - `role_id` must not be locked.
     #[export(unwrap_result)]
     pub fn grant_role(&mut self, role_id: RoleId, target_account: ActorId) -> Result<(), Error> {
         let message_source = Syscall::message_source();
-        self.require_role(self.get_role_admin(role_id), message_source)?;
+        let via_role = self.require_role_admin(role_id, message_source)?;
 
-        if self.grant_role_unchecked(role_id, target_account) {
-            self.emit_event(Event::RoleGranted {
-                role_id,
-                target_account,
-                sender: message_source,
-            })
-            .map_err(|_| EmitError)?;
-        }
+        self.grant_or_schedule(role_id, target_account, message_source, via_role)?;
+
+        self.audit("grant_role", (role_id, target_account));
 
         Ok(())
     }
@@ -298,11 +958,13 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     /// Grants `role_ids` to `target_account`.
     ///
     /// If `target_account` had not been already granted any of the `role_ids`,
-    /// emits a `RoleGranted` event for each newly granted role.
+    /// emits a `RoleGranted` event for each newly granted role. Roles with a grant
+    /// delay are scheduled as in `grant_role`.
     ///
     /// Requirements:
     ///
     /// - the caller must have the admin role for all specified `role_ids`.
+    /// - none of the `role_ids` may be locked.
     #[export(unwrap_result)]
     pub fn grant_roles_batch(
         &mut self,
@@ -310,21 +972,107 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
         target_account: ActorId,
     ) -> Result<(), Error> {
         let message_source = Syscall::message_source();
+        let mut via_roles = Vec::with_capacity(role_ids.len());
         for &role_id in &role_ids {
-            self.require_role(self.get_role_admin(role_id), message_source)?;
+            via_roles.push(self.require_role_admin(role_id, message_source)?);
         }
 
-        for role_id in role_ids {
-            if self.grant_role_unchecked(role_id, target_account) {
-                self.emit_event(Event::RoleGranted {
+        for (&role_id, via_role) in role_ids.iter().zip(via_roles) {
+            self.grant_or_schedule(role_id, target_account, message_source, via_role)?;
+        }
+
+        self.audit("grant_roles_batch", (role_ids, target_account));
+
+        Ok(())
+    }
+
+    /// Activates a scheduled grant of `role_id` to `target_account`.
+    ///
+    /// Emits a `RoleGranted` event on behalf of the account that scheduled it.
+    ///
+    /// Requirements:
+    ///
+    /// - the grant must be pending and its delay must have passed.
+    /// - `role_id` must not be locked.
+    #[export(unwrap_result)]
+    pub fn execute_role_grant(
+        &mut self,
+        role_id: RoleId,
+        target_account: ActorId,
+    ) -> Result<(), Error> {
+        let grant =
+            self.get_pending_grant(role_id, target_account)
+                .ok_or(PendingGrantNotFound {
                     role_id,
-                    target_account,
-                    sender: message_source,
-                })
-                .map_err(|_| EmitError)?;
+                    account_id: target_account,
+                })?;
+
+        self.ensure_unlocked(role_id)?;
+
+        ensure!(
+            Syscall::block_height() >= grant.ready_at,
+            PendingGrantNotReady {
+                role_id,
+                account_id: target_account,
+                ready_at: grant.ready_at,
             }
+        );
+
+        self.storage
+            .get_mut()
+            .pending_grants
+            .remove(&(role_id, target_account));
+
+        if self.grant_role_unchecked(role_id, target_account) {
+            self.emit_event(Event::RoleGranted {
+                role_id,
+                target_account,
+                sender: grant.sender,
+                via_role: grant.via_role,
+            })
+            .map_err(|_| EmitError)?;
         }
 
+        self.audit("execute_role_grant", (role_id, target_account));
+
+        Ok(())
+    }
+
+    /// Cancels a scheduled grant of `role_id` to `target_account`.
+    ///
+    /// Emits a `RoleGrantCancelled` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `role_id`'s admin role.
+    /// - the grant must be pending.
+    #[export(unwrap_result)]
+    pub fn cancel_role_grant(
+        &mut self,
+        role_id: RoleId,
+        target_account: ActorId,
+    ) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role_admin(role_id, message_source)?;
+
+        self.storage
+            .get_mut()
+            .pending_grants
+            .remove(&(role_id, target_account))
+            .ok_or(PendingGrantNotFound {
+                role_id,
+                account_id: target_account,
+            })?;
+
+        self.emit_event(Event::RoleGrantCancelled {
+            role_id,
+            target_account,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("cancel_role_grant", (role_id, target_account));
+
         Ok(())
     }
 
@@ -335,20 +1083,29 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     /// Requirements:
     ///
     /// - the caller must have `role_id`'s admin role.
+    /// - `target_account` must not be the last super admin if `role_id` is
+    ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
     #[export(unwrap_result)]
     pub fn revoke_role(&mut self, role_id: RoleId, target_account: ActorId) -> Result<(), Error> {
         let message_source = Syscall::message_source();
-        self.require_role(self.get_role_admin(role_id), message_source)?;
+        let via_role = self.require_role_admin(role_id, message_source)?;
+
+        if role_id == DEFAULT_ADMIN_ROLE {
+            self.ensure_not_last_admin(target_account)?;
+        }
 
         if self.revoke_role_unchecked(role_id, target_account) {
             self.emit_event(Event::RoleRevoked {
                 role_id,
                 target_account,
                 sender: message_source,
+                via_role,
             })
             .map_err(|_| EmitError)?;
         }
 
+        self.audit("revoke_role", (role_id, target_account));
+
         Ok(())
     }
 
@@ -360,6 +1117,8 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     /// Requirements:
     ///
     /// - the caller must have the admin role for all specified `role_ids`.
+    /// - `target_account` must not be the last super admin if `role_ids` contain
+    ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
     #[export(unwrap_result)]
     pub fn revoke_roles_batch(
         &mut self,
@@ -367,21 +1126,88 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
         target_account: ActorId,
     ) -> Result<(), Error> {
         let message_source = Syscall::message_source();
+        let mut via_roles = Vec::with_capacity(role_ids.len());
         for &role_id in &role_ids {
-            self.require_role(self.get_role_admin(role_id), message_source)?;
+            via_roles.push(self.require_role_admin(role_id, message_source)?);
+        }
+
+        if role_ids.contains(&DEFAULT_ADMIN_ROLE) {
+            self.ensure_not_last_admin(target_account)?;
         }
 
-        for role_id in role_ids {
+        for (&role_id, via_role) in role_ids.iter().zip(via_roles) {
             if self.revoke_role_unchecked(role_id, target_account) {
                 self.emit_event(Event::RoleRevoked {
                     role_id,
                     target_account,
                     sender: message_source,
+                    via_role,
                 })
                 .map_err(|_| EmitError)?;
             }
         }
 
+        self.audit("revoke_roles_batch", (role_ids, target_account));
+
+        Ok(())
+    }
+
+    /// Replaces `old_account` with `new_account` among members of `role_id` at once,
+    /// e.g. to rotate a key without a window of the role having no members.
+    ///
+    /// Emits a `RoleGranted` event for `new_account`, unless it's already a member,
+    /// followed by a `RoleRevoked` event for `old_account`.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `role_id`'s admin role.
+    /// - `old_account` must be a member of `role_id`, other than `new_account`.
+    /// - `role_id` must have no grant delay, as the replacement would bypass it.
+    /// - `role_id` must not be locked.
+    #[export(unwrap_result)]
+    pub fn replace_role_member(
+        &mut self,
+        role_id: RoleId,
+        old_account: ActorId,
+        new_account: ActorId,
+    ) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        let via_role = self.require_role_admin(role_id, message_source)?;
+
+        self.ensure_unlocked(role_id)?;
+
+        {
+            let storage = self.storage.get();
+
+            ensure!(
+                old_account != new_account && storage.is_member(role_id, old_account),
+                BadInput
+            );
+            ensure!(storage.get_role_grant_delay(role_id) == 0, BadInput);
+        }
+
+        if self.grant_role_unchecked(role_id, new_account) {
+            self.emit_event(Event::RoleGranted {
+                role_id,
+                target_account: new_account,
+                sender: message_source,
+                via_role,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        self.revoke_role_unchecked(role_id, old_account);
+
+        self.emit_event(Event::RoleRevoked {
+            role_id,
+            target_account: old_account,
+            sender: message_source,
+            via_role,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("replace_role_member", (role_id, old_account, new_account));
+
         Ok(())
     }
 
@@ -392,11 +1218,13 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     /// if they are compromised (such as when a trusted device is misplaced).
     ///
     /// If the calling account had been granted `role_id`, emits a `RoleRevoked`
-    /// event.
+    /// event. Renouncing `DEFAULT_ADMIN_ROLE` is scheduled instead and an
+    /// `AdminRenounceScheduled` event is emitted, see `execute_admin_renounce`.
     ///
     /// Requirements:
     ///
     /// - the caller must be `account_id`.
+    /// - the caller must not be the last super admin, unless admin-less programs are allowed.
     #[export(unwrap_result)]
     pub fn renounce_role(&mut self, role_id: RoleId, account_id: ActorId) -> Result<(), Error> {
         let message_source = Syscall::message_source();
@@ -408,11 +1236,69 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
             }
         );
 
+        if role_id == DEFAULT_ADMIN_ROLE {
+            return self.schedule_admin_renounce(account_id);
+        }
+
         if self.revoke_role_unchecked(role_id, account_id) {
             self.emit_event(Event::RoleRevoked {
                 role_id,
                 target_account: account_id,
                 sender: message_source,
+                via_role: role_id,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Completes the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+    ///
+    /// Emits a `RoleRevoked` event. The renounce is discarded once `account_id` loses
+    /// `DEFAULT_ADMIN_ROLE` otherwise, e.g. on recovery.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be `account_id`.
+    /// - the renounce must be pending and its delay must have passed.
+    /// - the caller must not be the last super admin, unless admin-less programs are allowed.
+    #[export(unwrap_result)]
+    pub fn execute_admin_renounce(&mut self, account_id: ActorId) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        ensure!(
+            account_id == message_source,
+            NotAccountOwner {
+                account_id,
+                message_source,
+            }
+        );
+
+        let ready_at = self
+            .get_pending_admin_renounce(account_id)
+            .ok_or(RenounceNotFound { account_id })?;
+
+        ensure!(
+            Syscall::block_height() >= ready_at,
+            RenounceNotReady {
+                account_id,
+                ready_at,
+            }
+        );
+
+        self.ensure_not_last_admin(account_id)?;
+
+        self.storage
+            .get_mut()
+            .pending_admin_renounces
+            .remove(&account_id);
+
+        if self.revoke_role_unchecked(DEFAULT_ADMIN_ROLE, account_id) {
+            self.emit_event(Event::RoleRevoked {
+                role_id: DEFAULT_ADMIN_ROLE,
+                target_account: account_id,
+                sender: message_source,
+                via_role: DEFAULT_ADMIN_ROLE,
             })
             .map_err(|_| EmitError)?;
         }
@@ -420,6 +1306,37 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
         Ok(())
     }
 
+    /// Cancels the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+    ///
+    /// Emits an `AdminRenounceCancelled` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be `account_id`.
+    /// - the renounce must be pending.
+    #[export(unwrap_result)]
+    pub fn cancel_admin_renounce(&mut self, account_id: ActorId) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        ensure!(
+            account_id == message_source,
+            NotAccountOwner {
+                account_id,
+                message_source,
+            }
+        );
+
+        self.storage
+            .get_mut()
+            .pending_admin_renounces
+            .remove(&account_id)
+            .ok_or(RenounceNotFound { account_id })?;
+
+        self.emit_event(Event::AdminRenounceCancelled { account_id })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
     /// Sets `new_admin_role_id` as the admin role for `role_id`.
     ///
     /// Emits a `RoleAdminChanged` event.
@@ -427,6 +1344,7 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     /// Requirements:
     ///
     /// - the caller must have `role_id`'s admin role.
+    /// - `role_id` must not be locked.
     #[export(unwrap_result)]
     pub fn set_role_admin(
         &mut self,
@@ -435,7 +1353,9 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
     ) -> Result<(), Error> {
         let message_source = Syscall::message_source();
         let current_admin_role_id = self.get_role_admin(role_id);
-        self.require_role(current_admin_role_id, message_source)?;
+        self.require_role_admin(role_id, message_source)?;
+
+        self.ensure_unlocked(role_id)?;
 
         self.set_role_admin_unchecked(role_id, new_admin_role_id);
 
@@ -447,52 +1367,719 @@ impl<'a, S: InfallibleStorageMut<Item = RolesStorage>> AccessControl<'a, S> {
         })
         .map_err(|_| EmitError)?;
 
+        self.audit("set_role_admin", (role_id, new_admin_role_id));
+
         Ok(())
     }
-}
 
-#[event]
-#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
-#[codec(crate = sails_rs::scale_codec)]
-#[scale_info(crate = sails_rs::scale_info)]
-pub enum Event {
-    RoleGranted {
-        role_id: RoleId,
-        target_account: ActorId,
-        sender: ActorId,
-    },
-    RoleRevoked {
-        role_id: RoleId,
-        target_account: ActorId,
-        sender: ActorId,
-    },
-    RoleAdminChanged {
+    /// Sets `parent_role_id` as the parent of `role_id`, or removes the parent if `None`.
+    ///
+    /// Holders of the parent role are considered to hold `role_id` as well.
+    ///
+    /// Emits a `RoleParentChanged` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so only accounts granted it are
+    ///   super admins.
+    /// - the new parent must not make `role_id` its own ancestor.
+    /// - `role_id` must not be locked.
+    #[export(unwrap_result)]
+    pub fn set_role_parent(
+        &mut self,
         role_id: RoleId,
-        previous_admin_role_id: RoleId,
-        new_admin_role_id: RoleId,
-        sender: ActorId,
-    },
-}
+        parent_role_id: Option<RoleId>,
+    ) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
 
-pub mod error {
-    use crate::RoleId;
-    pub use awesome_sails_utils::error::{BadOrigin, EmitError, Error};
-    use sails_rs::{
-        ActorId,
-        scale_codec::{Decode, Encode},
-        scale_info::TypeInfo,
-    };
+        ensure!(role_id != DEFAULT_ADMIN_ROLE, BadInput);
+        self.ensure_unlocked(role_id)?;
 
-    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
-    #[codec(crate = sails_rs::scale_codec)]
-    #[error("Access denied: account {account_id:?} does not have role {role_id:?}")]
-    #[scale_info(crate = sails_rs::scale_info)]
-    pub struct AccessDenied {
-        pub account_id: ActorId,
-        pub role_id: RoleId,
+        if let Some(parent_role_id) = parent_role_id {
+            ensure!(
+                !self
+                    .storage
+                    .get()
+                    .role_lineage(parent_role_id)
+                    .any(|r| r == role_id),
+                RoleCycle {
+                    role_id,
+                    parent_role_id,
+                }
+            );
+        }
+
+        let previous_parent_role_id = self.set_role_parent_unchecked(role_id, parent_role_id);
+
+        self.emit_event(Event::RoleParentChanged {
+            role_id,
+            previous_parent_role_id,
+            new_parent_role_id: parent_role_id,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_role_parent", (role_id, parent_role_id));
+
+        Ok(())
     }
 
-    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    /// Locks `role_id` irreversibly: no new members may be granted, and its admin role
+    /// and parent role may no longer change.
+    ///
+    /// Emits a `RoleLocked` event, unless the role is already locked.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `role_id`'s admin role.
+    /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so it stays recoverable.
+    /// - `role_id` must have no parent role, holders of which would hold it as well.
+    #[export(unwrap_result)]
+    pub fn lock_role(&mut self, role_id: RoleId) -> Result<bool, Error> {
+        let message_source = Syscall::message_source();
+        self.require_role_admin(role_id, message_source)?;
+
+        ensure!(role_id != DEFAULT_ADMIN_ROLE, BadInput);
+
+        {
+            let mut storage = self.storage.get_mut();
+            let data = storage.roles.entry(role_id).or_default();
+
+            if data.locked {
+                return Ok(false);
+            }
+
+            ensure!(data.parent_role_id.is_none(), BadInput);
+
+            data.locked = true;
+        }
+
+        self.emit_event(Event::RoleLocked {
+            role_id,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("lock_role", role_id);
+
+        Ok(true)
+    }
+
+    /// Sets the number of blocks grants of `role_id` are delayed for.
+    ///
+    /// Grants already scheduled keep their original delay.
+    ///
+    /// Emits a `RoleGrantDelayChanged` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    #[export(unwrap_result)]
+    pub fn set_role_grant_delay(&mut self, role_id: RoleId, delay: u32) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        let previous_delay = self.set_role_grant_delay_unchecked(role_id, delay);
+
+        self.emit_event(Event::RoleGrantDelayChanged {
+            role_id,
+            previous_delay,
+            new_delay: delay,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_role_grant_delay", (role_id, delay));
+
+        Ok(())
+    }
+
+    /// Sets the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+    ///
+    /// Renounces already scheduled keep their original delay.
+    ///
+    /// Emits an `AdminRenounceDelayChanged` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    #[export(unwrap_result)]
+    pub fn set_admin_renounce_delay(&mut self, delay: u32) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        let previous_delay =
+            core::mem::replace(&mut self.storage.get_mut().admin_renounce_delay, delay);
+
+        self.emit_event(Event::AdminRenounceDelayChanged {
+            previous_delay,
+            new_delay: delay,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_admin_renounce_delay", delay);
+
+        Ok(())
+    }
+
+    /// Allows or forbids the last super admin to renounce or be revoked `DEFAULT_ADMIN_ROLE`,
+    /// leaving the program without super admins.
+    ///
+    /// Emits an `AllowAdminLessChanged` event if the setting changed.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    #[export(unwrap_result)]
+    pub fn set_allow_admin_less(&mut self, allowed: bool) -> Result<bool, Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        let previous = core::mem::replace(&mut self.storage.get_mut().allow_admin_less, allowed);
+
+        if previous == allowed {
+            return Ok(false);
+        }
+
+        self.emit_event(Event::AllowAdminLessChanged {
+            allowed,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_allow_admin_less", allowed);
+
+        Ok(true)
+    }
+
+    /// Sets the max amount of items enumeration queries reply with at once.
+    ///
+    /// Queries requesting more items, or all items when there are more of them,
+    /// fail with `PageTooLarge` instead of running out of gas.
+    ///
+    /// Emits a `MaxPageSizeChanged` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    /// - `max_page_size` must be non-zero.
+    #[export(unwrap_result)]
+    pub fn set_max_page_size(&mut self, max_page_size: u32) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        ensure!(max_page_size != 0, BadInput);
+
+        let previous_max_page_size =
+            core::mem::replace(&mut self.storage.get_mut().max_page_size, max_page_size);
+
+        self.emit_event(Event::MaxPageSizeChanged {
+            previous_max_page_size,
+            new_max_page_size: max_page_size,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_max_page_size", max_page_size);
+
+        Ok(())
+    }
+
+    /// Restricts heavy enumeration exports to `VIEWER_ROLE` holders, or lifts the restriction.
+    ///
+    /// Emits a `ViewerGateChanged` event if the gate changed.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    #[export(unwrap_result)]
+    pub fn set_viewer_gate(&mut self, enabled: bool) -> Result<bool, Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        let previous = core::mem::replace(&mut self.storage.get_mut().viewer_gate, enabled);
+
+        if previous == enabled {
+            return Ok(false);
+        }
+
+        self.emit_event(Event::ViewerGateChanged {
+            enabled,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_viewer_gate", enabled);
+
+        Ok(true)
+    }
+
+    /// Replaces the recovery guardians, discarding all recovery proposals.
+    ///
+    /// Passing no guardians disables recovery.
+    ///
+    /// Emits a `RecoveryConfigChanged` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    /// - `threshold` must be within `1..=guardians.len()`, or zero if there are no guardians.
+    #[export(unwrap_result)]
+    pub fn set_recovery_guardians(
+        &mut self,
+        mut guardians: Vec<ActorId>,
+        threshold: u32,
+        delay: u32,
+    ) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        guardians.sort_unstable();
+        guardians.dedup();
+
+        ensure!(
+            (guardians.is_empty() && threshold == 0)
+                || (1..=guardians.len()).contains(&(threshold as usize)),
+            BadInput
+        );
+
+        self.audit("set_recovery_guardians", (&guardians, threshold, delay));
+
+        {
+            let mut storage = self.storage.get_mut();
+
+            storage.recovery = RecoveryConfig {
+                guardians,
+                threshold,
+                delay,
+            };
+            storage.recovery_proposals.clear();
+        }
+
+        self.emit_event(Event::RecoveryConfigChanged {
+            threshold,
+            delay,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Approves `new_admin` as the recovered super admin on behalf of the calling guardian.
+    ///
+    /// Emits a `RecoveryApproved` event, followed by a `RecoveryScheduled` event once the
+    /// approvals reach the threshold.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be a guardian.
+    #[export(unwrap_result)]
+    pub fn propose_recovery(&mut self, new_admin: ActorId) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+
+        let (approvals, ready_at) = {
+            let mut storage = self.storage.get_mut();
+
+            ensure!(
+                storage.is_guardian(message_source),
+                NotGuardian {
+                    account_id: message_source,
+                }
+            );
+
+            let RecoveryConfig {
+                threshold, delay, ..
+            } = storage.recovery;
+
+            let proposal = storage.recovery_proposals.entry(new_admin).or_default();
+
+            let Err(idx) = proposal.approvals.binary_search(&message_source) else {
+                return Ok(());
+            };
+            proposal.approvals.insert(idx, message_source);
+
+            let ready_at = (proposal.ready_at.is_none()
+                && proposal.approvals.len() >= threshold as usize)
+                .then(|| Syscall::block_height().saturating_add(delay));
+
+            if ready_at.is_some() {
+                proposal.ready_at = ready_at;
+            }
+
+            (proposal.approvals.len() as u32, ready_at)
+        };
+
+        self.emit_event(Event::RecoveryApproved {
+            new_admin,
+            guardian: message_source,
+            approvals,
+        })
+        .map_err(|_| EmitError)?;
+
+        if let Some(ready_at) = ready_at {
+            self.emit_event(Event::RecoveryScheduled {
+                new_admin,
+                ready_at,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        self.audit("propose_recovery", new_admin);
+
+        Ok(())
+    }
+
+    /// Discards the recovery proposal of `new_admin`.
+    ///
+    /// Emits a `RecoveryCancelled` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    /// - the proposal must exist.
+    #[export(unwrap_result)]
+    pub fn cancel_recovery(&mut self, new_admin: ActorId) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+        self.require_role(DEFAULT_ADMIN_ROLE, message_source)?;
+
+        self.storage
+            .get_mut()
+            .recovery_proposals
+            .remove(&new_admin)
+            .ok_or(RecoveryNotFound { new_admin })?;
+
+        self.emit_event(Event::RecoveryCancelled {
+            new_admin,
+            sender: message_source,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("cancel_recovery", new_admin);
+
+        Ok(())
+    }
+
+    /// Transfers `DEFAULT_ADMIN_ROLE` to `new_admin`, revoking it from all other holders
+    /// and discarding all recovery proposals.
+    ///
+    /// Emits a `RoleRevoked` event for each previous super admin, a `RoleGranted` event
+    /// and a `RecoveryExecuted` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the proposal must have reached the threshold and its delay must have passed.
+    #[export(unwrap_result)]
+    pub fn execute_recovery(&mut self, new_admin: ActorId) -> Result<(), Error> {
+        let message_source = Syscall::message_source();
+
+        let ready_at = self
+            .storage
+            .get()
+            .recovery_proposals
+            .get(&new_admin)
+            .ok_or(RecoveryNotFound { new_admin })?
+            .ready_at;
+
+        ensure!(
+            ready_at.is_some_and(|ready_at| Syscall::block_height() >= ready_at),
+            RecoveryNotReady {
+                new_admin,
+                ready_at,
+            }
+        );
+
+        let previous_admins: Vec<ActorId> = self
+            .storage
+            .get()
+            .roles
+            .get(&DEFAULT_ADMIN_ROLE)
+            .map(|data| {
+                data.members
+                    .iter()
+                    .copied()
+                    .filter(|&admin| admin != new_admin)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.storage.get_mut().recovery_proposals.clear();
+
+        for admin in previous_admins {
+            self.revoke_role_unchecked(DEFAULT_ADMIN_ROLE, admin);
+
+            self.emit_event(Event::RoleRevoked {
+                role_id: DEFAULT_ADMIN_ROLE,
+                target_account: admin,
+                sender: message_source,
+                via_role: DEFAULT_ADMIN_ROLE,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        if self.grant_role_unchecked(DEFAULT_ADMIN_ROLE, new_admin) {
+            self.emit_event(Event::RoleGranted {
+                role_id: DEFAULT_ADMIN_ROLE,
+                target_account: new_admin,
+                sender: message_source,
+                via_role: DEFAULT_ADMIN_ROLE,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        self.emit_event(Event::RecoveryExecuted { new_admin })
+            .map_err(|_| EmitError)?;
+
+        self.audit("execute_recovery", new_admin);
+
+        Ok(())
+    }
+
+    /// Ensures that `account_id` has `role_id`'s admin role or is a super admin.
+    ///
+    /// Returns the role that satisfied the check, emitting a `SuperAdminOverrideUsed`
+    /// event if it is `DEFAULT_ADMIN_ROLE` standing in for another admin role.
+    fn require_role_admin(
+        &mut self,
+        role_id: RoleId,
+        account_id: ActorId,
+    ) -> Result<RoleId, Error> {
+        let admin_role_id = self.get_role_admin(role_id);
+        let via_role = self.require_role(admin_role_id, account_id)?;
+
+        if via_role != admin_role_id {
+            self.emit_event(Event::SuperAdminOverrideUsed {
+                role_id,
+                admin_role_id,
+                sender: account_id,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(via_role)
+    }
+
+    /// Grants `role_id` to `target_account` immediately or schedules the grant,
+    /// depending on the role's grant delay.
+    fn grant_or_schedule(
+        &mut self,
+        role_id: RoleId,
+        target_account: ActorId,
+        sender: ActorId,
+        via_role: RoleId,
+    ) -> Result<(), Error> {
+        self.ensure_unlocked(role_id)?;
+
+        let delay = self.get_role_grant_delay(role_id);
+
+        if delay == 0 {
+            if self.grant_role_unchecked(role_id, target_account) {
+                self.emit_event(Event::RoleGranted {
+                    role_id,
+                    target_account,
+                    sender,
+                    via_role,
+                })
+                .map_err(|_| EmitError)?;
+            }
+
+            return Ok(());
+        }
+
+        let ready_at = Syscall::block_height().saturating_add(delay);
+
+        if self.schedule_grant_unchecked(
+            role_id,
+            target_account,
+            PendingGrant {
+                ready_at,
+                sender,
+                via_role,
+            },
+        ) {
+            self.emit_event(Event::RoleGrantScheduled {
+                role_id,
+                target_account,
+                sender,
+                ready_at,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Schedules renounce of `DEFAULT_ADMIN_ROLE` by `account_id`, unless it doesn't
+    /// hold the role or the renounce is already pending.
+    fn schedule_admin_renounce(&mut self, account_id: ActorId) -> Result<(), Error> {
+        self.ensure_not_last_admin(account_id)?;
+
+        let ready_at = {
+            let mut storage = self.storage.get_mut();
+
+            if !storage.is_member(DEFAULT_ADMIN_ROLE, account_id) {
+                return Ok(());
+            }
+
+            let ready_at = Syscall::block_height().saturating_add(storage.admin_renounce_delay);
+
+            match storage.pending_admin_renounces.entry(account_id) {
+                btree_map::Entry::Occupied(_) => return Ok(()),
+                btree_map::Entry::Vacant(entry) => *entry.insert(ready_at),
+            }
+        };
+
+        self.emit_event(Event::AdminRenounceScheduled {
+            account_id,
+            ready_at,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    /// `via_role` is the role that authorized the grant: the admin role of `role_id`,
+    /// or `DEFAULT_ADMIN_ROLE` on super admin override and recovery.
+    RoleGranted {
+        role_id: RoleId,
+        target_account: ActorId,
+        sender: ActorId,
+        via_role: RoleId,
+    },
+    /// `via_role` is the role that authorized the revocation: the admin role of `role_id`,
+    /// `DEFAULT_ADMIN_ROLE` on super admin override and recovery, or `role_id` itself
+    /// when renounced.
+    RoleRevoked {
+        role_id: RoleId,
+        target_account: ActorId,
+        sender: ActorId,
+        via_role: RoleId,
+    },
+    RoleAdminChanged {
+        role_id: RoleId,
+        previous_admin_role_id: RoleId,
+        new_admin_role_id: RoleId,
+        sender: ActorId,
+    },
+    RoleParentChanged {
+        role_id: RoleId,
+        previous_parent_role_id: Option<RoleId>,
+        new_parent_role_id: Option<RoleId>,
+        sender: ActorId,
+    },
+    RecoveryConfigChanged {
+        threshold: u32,
+        delay: u32,
+        sender: ActorId,
+    },
+    RecoveryApproved {
+        new_admin: ActorId,
+        guardian: ActorId,
+        approvals: u32,
+    },
+    RecoveryScheduled {
+        new_admin: ActorId,
+        ready_at: u32,
+    },
+    RecoveryCancelled {
+        new_admin: ActorId,
+        sender: ActorId,
+    },
+    RecoveryExecuted {
+        new_admin: ActorId,
+    },
+    RoleGrantScheduled {
+        role_id: RoleId,
+        target_account: ActorId,
+        sender: ActorId,
+        ready_at: u32,
+    },
+    RoleGrantCancelled {
+        role_id: RoleId,
+        target_account: ActorId,
+        sender: ActorId,
+    },
+    RoleGrantDelayChanged {
+        role_id: RoleId,
+        previous_delay: u32,
+        new_delay: u32,
+        sender: ActorId,
+    },
+    /// `sender` managed `role_id` as a super admin without holding its `admin_role_id`.
+    SuperAdminOverrideUsed {
+        role_id: RoleId,
+        admin_role_id: RoleId,
+        sender: ActorId,
+    },
+    MaxPageSizeChanged {
+        previous_max_page_size: u32,
+        new_max_page_size: u32,
+        sender: ActorId,
+    },
+    ViewerGateChanged {
+        enabled: bool,
+        sender: ActorId,
+    },
+    RoleLocked {
+        role_id: RoleId,
+        sender: ActorId,
+    },
+    AdminRenounceScheduled {
+        account_id: ActorId,
+        ready_at: u32,
+    },
+    AdminRenounceCancelled {
+        account_id: ActorId,
+    },
+    AdminRenounceDelayChanged {
+        previous_delay: u32,
+        new_delay: u32,
+        sender: ActorId,
+    },
+    AllowAdminLessChanged {
+        allowed: bool,
+        sender: ActorId,
+    },
+}
+
+pub mod error {
+    use crate::RoleId;
+    pub use awesome_sails_utils::error::{BadInput, BadOrigin, EmitError, Error};
+    use sails_rs::{
+        ActorId,
+        prelude::Vec,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Access denied: account {account_id:?} does not have role {role_id:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AccessDenied {
+        pub account_id: ActorId,
+        pub role_id: RoleId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Access denied: account {account_id:?} does not have any of roles {role_ids:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AccessDeniedAny {
+        pub account_id: ActorId,
+        pub role_ids: Vec<RoleId>,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
     #[codec(crate = sails_rs::scale_codec)]
     #[error("Not account owner: account {account_id:?}, message source {message_source:?}")]
     #[scale_info(crate = sails_rs::scale_info)]
@@ -500,4 +2087,108 @@ pub mod error {
         pub account_id: ActorId,
         pub message_source: ActorId,
     }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Pending grant not found: role {role_id:?} for account {account_id:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PendingGrantNotFound {
+        pub role_id: RoleId,
+        pub account_id: ActorId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error(
+        "Pending grant not ready: role {role_id:?} for account {account_id:?} is ready at block {ready_at}"
+    )]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PendingGrantNotReady {
+        pub role_id: RoleId,
+        pub account_id: ActorId,
+        pub ready_at: u32,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Role cycle: role {role_id:?} is already an ancestor of {parent_role_id:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoleCycle {
+        pub role_id: RoleId,
+        pub parent_role_id: RoleId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Not guardian: account {account_id:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotGuardian {
+        pub account_id: ActorId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Recovery not found: new admin {new_admin:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RecoveryNotFound {
+        pub new_admin: ActorId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Recovery not ready: new admin {new_admin:?}, ready at block {ready_at:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RecoveryNotReady {
+        pub new_admin: ActorId,
+        pub ready_at: Option<u32>,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Role locked: role {role_id:?} admits no new members")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoleLocked {
+        pub role_id: RoleId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Last admin protection: account {account_id:?} is the last super admin")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct LastAdminProtection {
+        pub account_id: ActorId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Renounce not found: account {account_id:?}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RenounceNotFound {
+        pub account_id: ActorId,
+    }
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Renounce not ready: account {account_id:?} is ready at block {ready_at}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RenounceNotReady {
+        pub account_id: ActorId,
+        pub ready_at: u32,
+    }
+
+    awesome_sails_utils::impl_error_code!(
+        AccessDenied => 500,
+        AccessDeniedAny => 501,
+        NotAccountOwner => 502,
+        PendingGrantNotFound => 503,
+        PendingGrantNotReady => 504,
+        RoleCycle => 505,
+        NotGuardian => 506,
+        RecoveryNotFound => 507,
+        RecoveryNotReady => 508,
+        RoleLocked => 509,
+        LastAdminProtection => 510,
+        RenounceNotFound => 511,
+        RenounceNotReady => 512,
+    );
 }
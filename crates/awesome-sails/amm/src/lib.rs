@@ -0,0 +1,772 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome AMM service.
+//!
+//! This service keeps a constant-product (`x * y = k`) pool of two remote VFTs,
+//! pulled from and pushed to accounts through the [`RemoteVft`] client. Liquidity
+//! providers are issued LP tokens through the local VFT, which must be dedicated to
+//! the pool: any other tokens of it would be burnt for the reserves of the pool.
+//!
+//! Swaps are charged the fee of the [`Pair`], which stays in the pool.
+//! The pool accumulates prices of both tokens every block, so consumers derive
+//! TWAP prices from the difference of [`Pool::price_cumulatives`] over a period.
+//!
+//! Remote transfers are asynchronous: tokens which can't be delivered, or were
+//! pulled for an operation that failed meanwhile, are credited to the account
+//! and delivered again by `claim`.
+
+#![no_std]
+
+use awesome_sails_access_control::{self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    error::{BadInput, EmitError, Error},
+    math::OverflowError,
+    ok_if,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    remote::RemoteVft,
+    utils::{Allowances, Balance, Balances},
+};
+use error::{InsufficientLiquidity, PairInUse, PairNotSet, SlippageExceeded, UnknownToken};
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+/// Scale of accumulated prices: a price of `PRICE_SCALE` is one token per token.
+pub const PRICE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Basis points of swapped amounts, used for fees.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Tokens of the pool with the swap fee.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Pair {
+    pub token0: ActorId,
+    pub token1: ActorId,
+    /// Fee charged of swapped amounts, in basis points.
+    pub fee_bps: u16,
+}
+
+/// Constant-product pool of [`Amm`].
+#[derive(Default, Debug)]
+pub struct Pool {
+    pair: Option<Pair>,
+    reserve0: U256,
+    reserve1: U256,
+    liquidity: U256,
+    price0_cumulative: U256,
+    price1_cumulative: U256,
+    updated_at: u32,
+    unclaimed: BTreeMap<(ActorId, ActorId), U256>,
+}
+
+impl Pool {
+    pub fn pair(&self) -> Option<&Pair> {
+        self.pair.as_ref()
+    }
+
+    /// Replaces the pair, failing if any liquidity is issued for the current one.
+    pub fn set_pair(&mut self, pair: Pair) -> Result<(), PairInUse> {
+        ensure!(self.liquidity.is_zero(), PairInUse);
+
+        *self = Self {
+            pair: Some(pair),
+            unclaimed: core::mem::take(&mut self.unclaimed),
+            ..Default::default()
+        };
+
+        Ok(())
+    }
+
+    pub fn reserves(&self) -> (U256, U256) {
+        (self.reserve0, self.reserve1)
+    }
+
+    /// LP tokens issued by the pool.
+    pub fn liquidity(&self) -> U256 {
+        self.liquidity
+    }
+
+    /// Returns prices of `token0` and `token1` accumulated per block up to the given block,
+    /// scaled by [`PRICE_SCALE`].
+    ///
+    /// Accumulators wrap on overflow, so the difference of two readings is always
+    /// the accumulated price between them.
+    pub fn price_cumulatives(&self, current_bn: u32) -> (U256, U256) {
+        let elapsed = U256::from(current_bn.saturating_sub(self.updated_at));
+
+        if elapsed.is_zero() || self.reserve0.is_zero() || self.reserve1.is_zero() {
+            return (self.price0_cumulative, self.price1_cumulative);
+        }
+
+        let scale = U256::from(PRICE_SCALE);
+
+        let price0 = self.reserve1.saturating_mul(scale) / self.reserve0;
+        let price1 = self.reserve0.saturating_mul(scale) / self.reserve1;
+
+        (
+            self.price0_cumulative
+                .overflowing_add(price0.overflowing_mul(elapsed).0)
+                .0,
+            self.price1_cumulative
+                .overflowing_add(price1.overflowing_mul(elapsed).0)
+                .0,
+        )
+    }
+
+    /// Returns the token paid out for `amount_in` of `token_in` with its amount.
+    pub fn quote_swap(&self, token_in: ActorId, amount_in: U256) -> Result<(ActorId, U256), Error> {
+        let pair = self.pair.ok_or(PairNotSet)?;
+
+        let (token_out, reserve_in, reserve_out) = if token_in == pair.token0 {
+            (pair.token1, self.reserve0, self.reserve1)
+        } else if token_in == pair.token1 {
+            (pair.token0, self.reserve1, self.reserve0)
+        } else {
+            return Err(UnknownToken.into());
+        };
+
+        ensure!(
+            !reserve_in.is_zero() && !reserve_out.is_zero(),
+            InsufficientLiquidity
+        );
+
+        let amount_in_with_fee = amount_in
+            .checked_mul((MAX_BPS - pair.fee_bps).into())
+            .ok_or(OverflowError)?;
+
+        let numerator = amount_in_with_fee
+            .checked_mul(reserve_out)
+            .ok_or(OverflowError)?;
+        let denominator = reserve_in
+            .checked_mul(MAX_BPS.into())
+            .and_then(|v| v.checked_add(amount_in_with_fee))
+            .ok_or(OverflowError)?;
+
+        Ok((token_out, numerator / denominator))
+    }
+
+    /// Applies the swap quoted by [`Self::quote_swap`].
+    pub fn swap(&mut self, token_in: ActorId, amount_in: U256, amount_out: U256, current_bn: u32) {
+        self.accumulate(current_bn);
+
+        if self.pair.is_some_and(|pair| pair.token0 == token_in) {
+            self.reserve0 += amount_in;
+            self.reserve1 -= amount_out;
+        } else {
+            self.reserve1 += amount_in;
+            self.reserve0 -= amount_out;
+        }
+    }
+
+    /// Returns amounts of the tokens to be deposited at the current price,
+    /// up to the desired ones.
+    pub fn quote_deposit(&self, amount0_desired: U256, amount1_desired: U256) -> (U256, U256) {
+        if self.reserve0.is_zero() || self.reserve1.is_zero() {
+            return (amount0_desired, amount1_desired);
+        }
+
+        let amount1 = amount0_desired.saturating_mul(self.reserve1) / self.reserve0;
+
+        if amount1 <= amount1_desired {
+            (amount0_desired, amount1)
+        } else {
+            (
+                amount1_desired.saturating_mul(self.reserve0) / self.reserve1,
+                amount1_desired,
+            )
+        }
+    }
+
+    /// Returns the liquidity issued for deposited amounts of the tokens.
+    pub fn quote_liquidity(&self, amount0: U256, amount1: U256) -> Result<U256, Error> {
+        self.reserve0.checked_add(amount0).ok_or(OverflowError)?;
+        self.reserve1.checked_add(amount1).ok_or(OverflowError)?;
+
+        let liquidity = if self.liquidity.is_zero() {
+            amount0
+                .checked_mul(amount1)
+                .ok_or(OverflowError)?
+                .integer_sqrt()
+        } else {
+            let liquidity0 =
+                amount0.checked_mul(self.liquidity).ok_or(OverflowError)? / self.reserve0;
+            let liquidity1 =
+                amount1.checked_mul(self.liquidity).ok_or(OverflowError)? / self.reserve1;
+
+            liquidity0.min(liquidity1)
+        };
+
+        ensure!(!liquidity.is_zero(), InsufficientLiquidity);
+
+        Ok(liquidity)
+    }
+
+    /// Applies the deposit quoted by [`Self::quote_liquidity`].
+    pub fn deposit(&mut self, amount0: U256, amount1: U256, liquidity: U256, current_bn: u32) {
+        self.accumulate(current_bn);
+
+        self.reserve0 += amount0;
+        self.reserve1 += amount1;
+        self.liquidity += liquidity;
+    }
+
+    /// Returns amounts of the tokens withdrawn for the liquidity.
+    pub fn quote_withdrawal(&self, liquidity: U256) -> Result<(U256, U256), Error> {
+        ensure!(liquidity <= self.liquidity, InsufficientLiquidity);
+
+        let amount0 = liquidity.checked_mul(self.reserve0).ok_or(OverflowError)? / self.liquidity;
+        let amount1 = liquidity.checked_mul(self.reserve1).ok_or(OverflowError)? / self.liquidity;
+
+        Ok((amount0, amount1))
+    }
+
+    /// Applies the withdrawal quoted by [`Self::quote_withdrawal`].
+    pub fn withdraw(&mut self, amount0: U256, amount1: U256, liquidity: U256, current_bn: u32) {
+        self.accumulate(current_bn);
+
+        self.reserve0 -= amount0;
+        self.reserve1 -= amount1;
+        self.liquidity -= liquidity;
+    }
+
+    /// Returns the amount of the token to be claimed by the account.
+    pub fn unclaimed(&self, token: ActorId, account: ActorId) -> U256 {
+        self.unclaimed
+            .get(&(token, account))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Credits the amount of the token to be claimed by the account.
+    pub fn credit(&mut self, token: ActorId, account: ActorId, amount: U256) {
+        let unclaimed = self.unclaimed.entry((token, account)).or_default();
+
+        *unclaimed = unclaimed.saturating_add(amount);
+    }
+
+    /// Removes and returns the amount of the token to be claimed by the account.
+    pub fn take_unclaimed(&mut self, token: ActorId, account: ActorId) -> U256 {
+        self.unclaimed.remove(&(token, account)).unwrap_or_default()
+    }
+
+    fn accumulate(&mut self, current_bn: u32) {
+        (self.price0_cumulative, self.price1_cumulative) = self.price_cumulatives(current_bn);
+        self.updated_at = current_bn;
+    }
+}
+
+/// Awesome AMM service itself.
+pub struct Amm<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    P: InfallibleStorageMut<Item = Pool> = StorageRefCell<'a, Pool>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    balances: B,
+    storage: P,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    P: InfallibleStorageMut<Item = Pool>,
+> Amm<'a, ACS, A, B, P>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        balances: B,
+        storage: P,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            balances,
+            storage,
+            vft,
+        }
+    }
+
+    /// Issues LP tokens to the provider for deposited amounts of the tokens,
+    /// returning the issued liquidity.
+    fn deposit(
+        &mut self,
+        provider: ActorId,
+        amount0: U256,
+        amount1: U256,
+        min_liquidity: U256,
+    ) -> Result<U256, Error> {
+        let liquidity = self.storage.get().quote_liquidity(amount0, amount1)?;
+
+        ensure!(liquidity >= min_liquidity, SlippageExceeded);
+
+        {
+            let mut balances = self.balances.get_mut()?;
+
+            balances.mint(
+                provider.try_into()?,
+                Balance::try_from(liquidity)?.try_into()?,
+            )?;
+            balances.checkpoint_supply(Syscall::block_height());
+        }
+
+        self.storage
+            .get_mut()
+            .deposit(amount0, amount1, liquidity, Syscall::block_height());
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from: ActorId::zero(),
+                to: provider,
+                value: liquidity,
+            })
+            .map_err(|_| EmitError)?;
+
+        Ok(liquidity)
+    }
+
+    /// Transfers the amount of the token to the account, crediting it
+    /// to be claimed if the transfer fails.
+    ///
+    /// Returns `true` if the amount was transferred.
+    async fn push(&mut self, token: ActorId, to: ActorId, amount: U256) -> bool {
+        if RemoteVft::new(token).transfer(to, amount).await.is_ok() {
+            return true;
+        }
+
+        self.storage.get_mut().credit(token, to, amount);
+
+        false
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    P: InfallibleStorageMut<Item = Pool>,
+> Amm<'a, ACS, A, B, P>
+{
+    /// Deposits the tokens at the current price, up to the desired amounts,
+    /// issuing LP tokens to the message source.
+    ///
+    /// Tokens are pulled from the message source, which must approve them to the program.
+    /// If the liquidity can't be issued once the tokens are pulled, e.g. as the price
+    /// moved meanwhile, the tokens are credited to be claimed and zero is returned.
+    #[export(unwrap_result)]
+    pub async fn add_liquidity(
+        &mut self,
+        amount0_desired: U256,
+        amount1_desired: U256,
+        min_liquidity: U256,
+    ) -> Result<U256, Error> {
+        let provider = Syscall::message_source();
+        let program_id = Syscall::program_id();
+
+        let pair = *self.storage.get().pair().ok_or(PairNotSet)?;
+
+        let (amount0, amount1) = self
+            .storage
+            .get()
+            .quote_deposit(amount0_desired, amount1_desired);
+
+        let liquidity = self.storage.get().quote_liquidity(amount0, amount1)?;
+
+        ensure!(liquidity >= min_liquidity, SlippageExceeded);
+
+        RemoteVft::new(pair.token0)
+            .transfer_from(provider, program_id, amount0)
+            .await?;
+
+        let mut pulled = vec![(pair.token0, amount0)];
+
+        let liquidity = if RemoteVft::new(pair.token1)
+            .transfer_from(provider, program_id, amount1)
+            .await
+            .is_ok()
+        {
+            pulled.push((pair.token1, amount1));
+
+            self.inner
+                .deposit(provider, amount0, amount1, min_liquidity)
+                .ok()
+        } else {
+            None
+        };
+
+        let Some(liquidity) = liquidity else {
+            for (token, amount) in pulled {
+                self.storage.get_mut().credit(token, provider, amount);
+
+                self.emit_event(Event::Credited {
+                    token,
+                    account: provider,
+                    amount,
+                })
+                .map_err(|_| EmitError)?;
+            }
+
+            return Ok(U256::zero());
+        };
+
+        self.emit_event(Event::LiquidityAdded {
+            provider,
+            amount0,
+            amount1,
+            liquidity,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(liquidity)
+    }
+
+    /// Transfers the amount of the token credited to the message source.
+    ///
+    /// Returns the transferred amount.
+    #[export(unwrap_result)]
+    pub async fn claim(&mut self, token: ActorId) -> Result<U256, Error> {
+        let account = Syscall::message_source();
+
+        let amount = self.storage.get_mut().take_unclaimed(token, account);
+
+        ok_if!(amount.is_zero(), amount);
+
+        ok_if!(!self.inner.push(token, account, amount).await, U256::zero());
+
+        self.emit_event(Event::Claimed {
+            token,
+            account,
+            amount,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(amount)
+    }
+
+    /// Burns LP tokens of the message source, transferring it the withdrawn tokens.
+    ///
+    /// Returns withdrawn amounts of `token0` and `token1`.
+    #[export(unwrap_result)]
+    pub async fn remove_liquidity(
+        &mut self,
+        liquidity: U256,
+        min_amount0: U256,
+        min_amount1: U256,
+    ) -> Result<(U256, U256), Error> {
+        ensure!(!liquidity.is_zero(), BadInput);
+
+        let provider = Syscall::message_source();
+
+        let pair = *self.storage.get().pair().ok_or(PairNotSet)?;
+
+        let (amount0, amount1) = self.storage.get().quote_withdrawal(liquidity)?;
+
+        ensure!(
+            amount0 >= min_amount0 && amount1 >= min_amount1,
+            SlippageExceeded
+        );
+
+        let reaped = {
+            let mut balances = self.balances.get_mut()?;
+
+            let reaped = balances.burn(
+                provider.try_into()?,
+                Balance::try_from(liquidity)?.try_into()?,
+            )?;
+            balances.checkpoint_supply(Syscall::block_height());
+
+            reaped
+        };
+
+        self.storage
+            .get_mut()
+            .withdraw(amount0, amount1, liquidity, Syscall::block_height());
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from: provider,
+                to: ActorId::zero(),
+                value: liquidity,
+            })
+            .map_err(|_| EmitError)?;
+
+        if let Some(dust) = reaped {
+            self.vft
+                .emit_event(vft::Event::AccountReaped {
+                    account: provider,
+                    dust: dust.into(),
+                })
+                .map_err(|_| EmitError)?;
+        }
+
+        self.emit_event(Event::LiquidityRemoved {
+            provider,
+            amount0,
+            amount1,
+            liquidity,
+        })
+        .map_err(|_| EmitError)?;
+
+        for (token, amount) in [(pair.token0, amount0), (pair.token1, amount1)] {
+            if !amount.is_zero() && !self.inner.push(token, provider, amount).await {
+                self.emit_event(Event::Credited {
+                    token,
+                    account: provider,
+                    amount,
+                })
+                .map_err(|_| EmitError)?;
+            }
+        }
+
+        Ok((amount0, amount1))
+    }
+
+    /// Sets the pair of the pool, while no liquidity is issued for the current one.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_pair(
+        &mut self,
+        token0: ActorId,
+        token1: ActorId,
+        fee_bps: u16,
+    ) -> Result<(), Error> {
+        ensure!(
+            !token0.is_zero() && !token1.is_zero() && token0 != token1 && fee_bps < MAX_BPS,
+            BadInput
+        );
+
+        let pair = Pair {
+            token0,
+            token1,
+            fee_bps,
+        };
+
+        self.storage.get_mut().set_pair(pair)?;
+
+        self.emit_event(Event::PairSet(pair))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Swaps `amount_in` of `token_in` for the other token of the pair.
+    ///
+    /// Input is pulled from the message source, which must approve it to the program.
+    /// If the output drops below `min_amount_out` once the input is pulled, the input
+    /// is credited to be claimed and zero is returned.
+    ///
+    /// Returns the output amount.
+    #[export(unwrap_result)]
+    pub async fn swap(
+        &mut self,
+        token_in: ActorId,
+        amount_in: U256,
+        min_amount_out: U256,
+    ) -> Result<U256, Error> {
+        ensure!(!amount_in.is_zero(), BadInput);
+
+        let trader = Syscall::message_source();
+
+        let (_, amount_out) = self.storage.get().quote_swap(token_in, amount_in)?;
+
+        ensure!(amount_out >= min_amount_out, SlippageExceeded);
+
+        RemoteVft::new(token_in)
+            .transfer_from(trader, Syscall::program_id(), amount_in)
+            .await?;
+
+        // Reserves might have changed while the input was pulled.
+        let quote = self
+            .storage
+            .get()
+            .quote_swap(token_in, amount_in)
+            .ok()
+            .filter(|(_, amount_out)| *amount_out >= min_amount_out);
+
+        let Some((token_out, amount_out)) = quote else {
+            self.storage.get_mut().credit(token_in, trader, amount_in);
+
+            self.emit_event(Event::Credited {
+                token: token_in,
+                account: trader,
+                amount: amount_in,
+            })
+            .map_err(|_| EmitError)?;
+
+            return Ok(U256::zero());
+        };
+
+        self.storage
+            .get_mut()
+            .swap(token_in, amount_in, amount_out, Syscall::block_height());
+
+        self.emit_event(Event::Swapped {
+            trader,
+            token_in,
+            amount_in,
+            amount_out,
+        })
+        .map_err(|_| EmitError)?;
+
+        if !self.inner.push(token_out, trader, amount_out).await {
+            self.emit_event(Event::Credited {
+                token: token_out,
+                account: trader,
+                amount: amount_out,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(amount_out)
+    }
+
+    #[export]
+    pub fn liquidity(&self) -> U256 {
+        self.storage.get().liquidity()
+    }
+
+    #[export]
+    pub fn pair(&self) -> Option<Pair> {
+        self.storage.get().pair().copied()
+    }
+
+    /// Returns accumulated prices of `token0` and `token1`, scaled by [`PRICE_SCALE`].
+    #[export]
+    pub fn price_cumulatives(&self) -> (U256, U256) {
+        self.storage
+            .get()
+            .price_cumulatives(Syscall::block_height())
+    }
+
+    /// Returns the token paid out for `amount_in` of `token_in` with its amount.
+    #[export]
+    pub fn quote_swap(&self, token_in: ActorId, amount_in: U256) -> Option<(ActorId, U256)> {
+        self.storage.get().quote_swap(token_in, amount_in).ok()
+    }
+
+    #[export]
+    pub fn reserves(&self) -> (U256, U256) {
+        self.storage.get().reserves()
+    }
+
+    #[export]
+    pub fn unclaimed(&self, token: ActorId, account: ActorId) -> U256 {
+        self.storage.get().unclaimed(token, account)
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    LiquidityAdded {
+        provider: ActorId,
+        amount0: U256,
+        amount1: U256,
+        liquidity: U256,
+    },
+    LiquidityRemoved {
+        provider: ActorId,
+        amount0: U256,
+        amount1: U256,
+        liquidity: U256,
+    },
+    Swapped {
+        trader: ActorId,
+        token_in: ActorId,
+        amount_in: U256,
+        amount_out: U256,
+    },
+    Credited {
+        token: ActorId,
+        account: ActorId,
+        amount: U256,
+    },
+    Claimed {
+        token: ActorId,
+        account: ActorId,
+        amount: U256,
+    },
+    PairSet(Pair),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error};
+    pub use awesome_sails_vft::remote::TransferFailed;
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Pair is in use")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PairInUse;
+
+    awesome_sails_utils::impl_error_code!(PairInUse => 800);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Pair is not set")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PairNotSet;
+
+    awesome_sails_utils::impl_error_code!(PairNotSet => 801);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Token is not in the pair")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct UnknownToken;
+
+    awesome_sails_utils::impl_error_code!(UnknownToken => 802);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Slippage exceeded")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct SlippageExceeded;
+
+    awesome_sails_utils::impl_error_code!(SlippageExceeded => 803);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Insufficient liquidity")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct InsufficientLiquidity;
+
+    awesome_sails_utils::impl_error_code!(InsufficientLiquidity => 804);
+}
@@ -0,0 +1,186 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Counter service.
+//!
+//! This service keeps named counters, usable as sequences or nonces by other services.
+//!
+//! Anyone may increment a counter, while decrementing, setting and resetting require
+//! [`COUNTER_ADMIN_ROLE`]. Counters are kept in any [`InfallibleStorageMut`], so programs
+//! choose how to persist them (e.g. `StorageRefCell` or `StorageStatic`).
+
+#![no_std]
+
+use awesome_sails_access_control::{self as access_control, RoleId, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    error::{EmitError, Error},
+    math::{OverflowError, UnderflowError},
+    ok_if,
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+pub const COUNTER_ADMIN_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"COUNTER_ADMIN_ROLE")
+    .finalize();
+
+/// Named counters, with zero counters not stored.
+#[derive(Default, Debug)]
+pub struct Counters(BTreeMap<String, u64>);
+
+impl Counters {
+    /// Returns the value of the counter.
+    pub fn get(&self, key: &str) -> u64 {
+        self.0.get(key).copied().unwrap_or_default()
+    }
+
+    /// Increases the counter by `by`, returning its new value.
+    pub fn add(&mut self, key: String, by: u64) -> Result<u64, OverflowError> {
+        let value = self.get(&key).checked_add(by).ok_or(OverflowError)?;
+
+        Ok(self.put(key, value))
+    }
+
+    /// Decreases the counter by `by`, returning its new value.
+    pub fn sub(&mut self, key: String, by: u64) -> Result<u64, UnderflowError> {
+        let value = self.get(&key).checked_sub(by).ok_or(UnderflowError)?;
+
+        Ok(self.put(key, value))
+    }
+
+    /// Increments the counter, returning its value before the increment.
+    ///
+    /// Useful for issuing sequential ids and nonces.
+    pub fn next(&mut self, key: String) -> Result<u64, OverflowError> {
+        self.add(key, 1).map(|value| value - 1)
+    }
+
+    /// Sets the counter to `value`, returning its previous value.
+    pub fn set(&mut self, key: String, value: u64) -> u64 {
+        if value == 0 {
+            self.0.remove(&key)
+        } else {
+            self.0.insert(key, value)
+        }
+        .unwrap_or_default()
+    }
+
+    fn put(&mut self, key: String, value: u64) -> u64 {
+        self.set(key, value);
+        value
+    }
+}
+
+/// Awesome Counter service itself.
+pub struct Counter<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
+    S: InfallibleStorageMut<Item = Counters> = StorageRefCell<'a, Counters>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: S,
+}
+
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Counters>>
+    Counter<'a, ACS, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: S,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Counters>>
+    Counter<'a, ACS, S>
+{
+    #[export(unwrap_result)]
+    #[require_role(COUNTER_ADMIN_ROLE)]
+    pub fn decrement(&mut self, key: String, by: u64) -> Result<u64, Error> {
+        ok_if!(by == 0, self.storage.get().get(&key));
+
+        let value = self.storage.get_mut().sub(key.clone(), by)?;
+
+        self.emit_event(Event::Decremented { key, value })
+            .map_err(|_| EmitError)?;
+
+        Ok(value)
+    }
+
+    #[export(unwrap_result)]
+    pub fn increment(&mut self, key: String, by: u64) -> Result<u64, Error> {
+        ok_if!(by == 0, self.storage.get().get(&key));
+
+        let value = self.storage.get_mut().add(key.clone(), by)?;
+
+        self.emit_event(Event::Incremented { key, value })
+            .map_err(|_| EmitError)?;
+
+        Ok(value)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(COUNTER_ADMIN_ROLE)]
+    pub fn reset(&mut self, key: String) -> Result<bool, Error> {
+        ok_if!(self.storage.get_mut().set(key.clone(), 0) == 0, false);
+
+        self.emit_event(Event::Reset(key)).map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(COUNTER_ADMIN_ROLE)]
+    pub fn set(&mut self, key: String, value: u64) -> Result<bool, Error> {
+        ok_if!(
+            self.storage.get_mut().set(key.clone(), value) == value,
+            false
+        );
+
+        self.emit_event(Event::Set { key, value })
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    #[export]
+    pub fn value(&self, key: String) -> u64 {
+        self.storage.get().get(&key)
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Incremented { key: String, value: u64 },
+    Decremented { key: String, value: u64 },
+    Set { key: String, value: u64 },
+    Reset(String),
+}
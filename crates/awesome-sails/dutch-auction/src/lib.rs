@@ -0,0 +1,468 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Dutch-Auction service.
+//!
+//! This service sells a fixed amount of VFTs for native value, at a price decreasing
+//! linearly over a range of blocks. Tokens are escrowed by the seller when the auction
+//! starts and delivered to buyers instantly, with the excess value refunded.
+//!
+//! Once the auction ends or sells out, anyone finalizes it: the proceeds are sent
+//! to the seller and unsold tokens are returned or burnt, as set by the [`UnsoldPolicy`].
+
+#![no_std]
+
+use crate::error::{
+    AuctionActive, AuctionNotActive, BadInput, BadValue, EmitError, Error, PurchaseCapExceeded,
+    SendError,
+};
+use awesome_sails_access_control::{self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    math::OverflowError,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balance, Balances},
+};
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+/// Scale of prices: a price of `PRICE_SCALE` is one unit of native value per token.
+pub const PRICE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Handling of tokens left unsold once the auction ends.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UnsoldPolicy {
+    /// Unsold tokens are returned to the seller.
+    #[default]
+    ReturnToSeller,
+    /// Unsold tokens are burnt.
+    Burn,
+}
+
+/// Parameters of the auction.
+///
+/// Prices are given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct AuctionParams {
+    /// Amount of tokens for sale.
+    pub amount: U256,
+    /// Price at the start block.
+    pub start_price: U256,
+    /// Price at the end block, not above the start price.
+    pub end_price: U256,
+    pub start_block: u32,
+    /// Block the auction ends at: no purchases are made from it on.
+    pub end_block: u32,
+    /// Amount of tokens an account may buy, zero for unbounded.
+    pub cap_per_account: U256,
+    pub unsold: UnsoldPolicy,
+}
+
+impl AuctionParams {
+    /// Returns `true` if the parameters describe a sale.
+    pub fn is_valid(&self) -> bool {
+        !self.amount.is_zero()
+            && self.end_price <= self.start_price
+            && self.start_block < self.end_block
+    }
+
+    /// Returns the price at the given block, scaled by [`PRICE_SCALE`].
+    pub fn price_at(&self, bn: u32) -> U256 {
+        let bn = bn.clamp(self.start_block, self.end_block);
+
+        let elapsed = U256::from(bn - self.start_block);
+        let duration = U256::from(self.end_block - self.start_block);
+
+        self.start_price - (self.start_price - self.end_price) * elapsed / duration
+    }
+}
+
+/// Auction along with its progress.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Auction {
+    /// Account the tokens are escrowed from and the proceeds are sent to.
+    pub seller: ActorId,
+    pub params: AuctionParams,
+    /// Amount of tokens sold.
+    pub sold: U256,
+    /// Native value paid for the sold tokens.
+    pub proceeds: u128,
+}
+
+impl Auction {
+    /// Amount of tokens left for sale.
+    pub fn remaining(&self) -> U256 {
+        self.params.amount - self.sold
+    }
+
+    /// Returns `true` if tokens are sold at the given block.
+    pub fn is_active(&self, bn: u32) -> bool {
+        (self.params.start_block..self.params.end_block).contains(&bn)
+            && !self.remaining().is_zero()
+    }
+
+    /// Returns native value paid for `amount` tokens at the given block, rounded up.
+    pub fn cost(&self, amount: U256, bn: u32) -> Option<u128> {
+        let (cost, rem) = self
+            .params
+            .price_at(bn)
+            .checked_mul(amount)?
+            .div_mod(PRICE_SCALE.into());
+
+        u128::try_from(cost + U256::from(!rem.is_zero() as u8)).ok()
+    }
+}
+
+/// State of [`DutchAuction`].
+#[derive(Default, Debug)]
+pub struct AuctionStorage {
+    auction: Option<Auction>,
+    purchased: BTreeMap<ActorId, U256>,
+}
+
+impl AuctionStorage {
+    pub fn auction(&self) -> Option<&Auction> {
+        self.auction.as_ref()
+    }
+
+    /// Amount of tokens the account bought in the current auction.
+    pub fn purchased(&self, account: ActorId) -> U256 {
+        self.purchased.get(&account).copied().unwrap_or_default()
+    }
+
+    /// Starts the auction, failing if the previous one isn't finalized.
+    pub fn start(&mut self, seller: ActorId, params: AuctionParams) -> Result<(), AuctionActive> {
+        ensure!(self.auction.is_none(), AuctionActive);
+
+        self.auction = Some(Auction {
+            seller,
+            params,
+            sold: U256::zero(),
+            proceeds: 0,
+        });
+        self.purchased.clear();
+
+        Ok(())
+    }
+
+    /// Sells `amount` tokens to the buyer at the given block, returning their cost.
+    pub fn buy(&mut self, buyer: ActorId, amount: U256, bn: u32) -> Result<u128, Error> {
+        let auction = self
+            .auction
+            .as_mut()
+            .filter(|auction| auction.is_active(bn))
+            .ok_or(AuctionNotActive)?;
+
+        let purchased = self.purchased.get(&buyer).copied().unwrap_or_default();
+
+        let mut remaining = auction.remaining();
+
+        if !auction.params.cap_per_account.is_zero() {
+            remaining = remaining.min(auction.params.cap_per_account.saturating_sub(purchased));
+        }
+
+        ensure!(amount <= remaining, PurchaseCapExceeded { remaining });
+
+        let cost = auction.cost(amount, bn).ok_or(OverflowError)?;
+
+        auction.proceeds = auction.proceeds.checked_add(cost).ok_or(OverflowError)?;
+        auction.sold += amount;
+
+        self.purchased.insert(buyer, purchased + amount);
+
+        Ok(cost)
+    }
+
+    /// Removes the auction once it ended or sold out.
+    pub fn finalize(&mut self, bn: u32) -> Result<Auction, Error> {
+        let auction = self.auction.ok_or(AuctionNotActive)?;
+
+        ensure!(
+            !auction.is_active(bn) && bn >= auction.params.start_block,
+            AuctionActive
+        );
+
+        self.auction = None;
+
+        Ok(auction)
+    }
+}
+
+/// Awesome Dutch-Auction service itself.
+pub struct DutchAuction<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AuctionStorage> = StorageRefCell<'a, AuctionStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    balances: B,
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AuctionStorage>,
+> DutchAuction<'a, ACS, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        balances: B,
+        storage: S,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            balances,
+            storage,
+            vft,
+        }
+    }
+
+    /// Burns VFTs of the program account.
+    fn burn(&mut self, amount: U256) -> Result<(), Error> {
+        let from = Syscall::program_id();
+
+        {
+            let mut balances = self.balances.get_mut()?;
+
+            balances.burn(from.try_into()?, Balance::try_from(amount)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+        }
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from,
+                to: ActorId::zero(),
+                value: amount,
+            })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AuctionStorage>,
+> DutchAuction<'a, ACS, A, B, S>
+{
+    /// Sells `amount` tokens to the message source at the current price.
+    ///
+    /// Message value must cover the cost, with the rest attached to the reply.
+    /// Returns the cost.
+    #[export(unwrap_result)]
+    pub fn buy(&mut self, amount: U256) -> Result<CommandReply<u128>, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let attached = Syscall::message_value();
+        let buyer = Syscall::message_source();
+
+        let cost = self
+            .storage
+            .get_mut()
+            .buy(buyer, amount, Syscall::block_height())?;
+
+        ensure!(attached >= cost, BadValue);
+
+        self.inner
+            .vft
+            .transfer_escrowed(Syscall::program_id(), buyer, amount)?;
+
+        self.emit_event(Event::Bought {
+            buyer,
+            amount,
+            cost,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(cost).with_value(attached - cost))
+    }
+
+    /// Finalizes the auction once it ended or sold out, sending the proceeds
+    /// to the seller and handling unsold tokens by the [`UnsoldPolicy`].
+    ///
+    /// May be called by anyone.
+    #[export(unwrap_result)]
+    pub fn finalize(&mut self) -> Result<(), Error> {
+        let auction = self.storage.get_mut().finalize(Syscall::block_height())?;
+        let unsold = auction.remaining();
+
+        if !unsold.is_zero() {
+            match auction.params.unsold {
+                UnsoldPolicy::ReturnToSeller => self.inner.vft.transfer_escrowed(
+                    Syscall::program_id(),
+                    auction.seller,
+                    unsold,
+                )?,
+                UnsoldPolicy::Burn => self.inner.burn(unsold)?,
+            }
+        }
+
+        if auction.proceeds != 0 {
+            gstd::msg::send_bytes(auction.seller, [], auction.proceeds)
+                .map_err(|_| SendError::Value)?;
+        }
+
+        self.emit_event(Event::Finalized {
+            sold: auction.sold,
+            unsold,
+            proceeds: auction.proceeds,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Starts the auction, escrowing its tokens from the message source,
+    /// who receives the proceeds.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn start(&mut self, params: AuctionParams) -> Result<(), Error> {
+        ensure!(
+            params.is_valid() && params.end_block > Syscall::block_height(),
+            BadInput
+        );
+
+        let seller = Syscall::message_source();
+
+        self.storage.get_mut().start(seller, params)?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(seller, Syscall::program_id(), params.amount)?;
+
+        self.emit_event(Event::Started { seller, params })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn auction(&self) -> Option<Auction> {
+        self.storage.get().auction().copied()
+    }
+
+    /// Returns the current price, scaled by [`PRICE_SCALE`], while the auction is active.
+    #[export]
+    pub fn current_price(&self) -> Option<U256> {
+        let bn = Syscall::block_height();
+
+        self.storage
+            .get()
+            .auction()
+            .filter(|auction| auction.is_active(bn))
+            .map(|auction| auction.params.price_at(bn))
+    }
+
+    #[export]
+    pub fn purchased(&self, account: ActorId) -> U256 {
+        self.storage.get().purchased(account)
+    }
+
+    /// Returns native value to be paid for `amount` tokens at the current price.
+    #[export]
+    pub fn quote(&self, amount: U256) -> Option<u128> {
+        let bn = Syscall::block_height();
+
+        self.storage
+            .get()
+            .auction()
+            .filter(|auction| auction.is_active(bn))
+            .and_then(|auction| auction.cost(amount, bn))
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Started {
+        seller: ActorId,
+        params: AuctionParams,
+    },
+    Bought {
+        buyer: ActorId,
+        amount: U256,
+        cost: u128,
+    },
+    Finalized {
+        sold: U256,
+        unsold: U256,
+        proceeds: u128,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadValue, EmitError, Error, SendError};
+    use sails_rs::{
+        U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Auction is active")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AuctionActive;
+
+    awesome_sails_utils::impl_error_code!(AuctionActive => 1800);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Auction is not active")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AuctionNotActive;
+
+    awesome_sails_utils::impl_error_code!(AuctionNotActive => 1801);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Purchase cap exceeded: at most {remaining} may be bought")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PurchaseCapExceeded {
+        pub remaining: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(PurchaseCapExceeded => 1802);
+}
@@ -0,0 +1,356 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Ethereum Binding service.
+//!
+//! This service binds Ethereum addresses to accounts one-to-one: an account binds
+//! an address by a `personal_sign` (EIP-191) signature of the binding message made
+//! by the address, see `bind_message`.
+//!
+//! Bound addresses approve VFTs of their accounts by signed permits, see
+//! `permit_message`, submitted by anyone, e.g. the spender or a relayer.
+//!
+//! Signed messages are bound to the program and a per-address nonce,
+//! so every signature is used only once.
+
+#![no_std]
+
+use crate::error::{AlreadyBound, EmitError, Error, NotBound, PermitExpired};
+use awesome_sails_utils::{
+    ensure,
+    eth::{self, EthSignature, InvalidSignature},
+    math::{Max, NonZero, Zero},
+    ok_if,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowance, Allowances, Balances},
+};
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+/// Tag of binding messages.
+pub const BIND_TAG: &[u8] = b"awesome-sails/eth-binding/bind";
+
+/// Tag of permit messages.
+pub const PERMIT_TAG: &[u8] = b"awesome-sails/eth-binding/permit";
+
+/// Bindings of Ethereum addresses to accounts, along with nonces of the addresses.
+#[derive(Default, Debug)]
+pub struct Bindings {
+    accounts: BTreeMap<H160, ActorId>,
+    addresses: BTreeMap<ActorId, H160>,
+    nonces: BTreeMap<H160, u64>,
+}
+
+impl Bindings {
+    pub fn account_of(&self, address: &H160) -> Option<ActorId> {
+        self.accounts.get(address).copied()
+    }
+
+    pub fn address_of(&self, account: &ActorId) -> Option<H160> {
+        self.addresses.get(account).copied()
+    }
+
+    /// Returns the nonce the next message signed by the address must use.
+    pub fn nonce(&self, address: &H160) -> u64 {
+        self.nonces.get(address).copied().unwrap_or_default()
+    }
+
+    /// Consumes the nonce of the address, returning it.
+    pub fn use_nonce(&mut self, address: H160) -> u64 {
+        let nonce = self.nonces.entry(address).or_default();
+
+        *nonce += 1;
+
+        *nonce - 1
+    }
+
+    /// Binds the address to the account, failing if either is already bound.
+    pub fn bind(&mut self, address: H160, account: ActorId) -> Result<(), AlreadyBound> {
+        ensure!(
+            !self.accounts.contains_key(&address) && !self.addresses.contains_key(&account),
+            AlreadyBound
+        );
+
+        self.accounts.insert(address, account);
+        self.addresses.insert(account, address);
+
+        Ok(())
+    }
+
+    /// Unbinds the account, returning its address if it was bound.
+    pub fn unbind(&mut self, account: ActorId) -> Option<H160> {
+        let address = self.addresses.remove(&account)?;
+
+        self.accounts.remove(&address);
+
+        Some(address)
+    }
+}
+
+/// Returns the message the address signs to bind to the account within the program.
+pub fn bind_message(program: ActorId, address: H160, account: ActorId, nonce: u64) -> Vec<u8> {
+    (BIND_TAG, program, address, account, nonce).encode()
+}
+
+/// Returns the message the owner signs to approve `value` to the spender
+/// within the program, valid until block `deadline`.
+pub fn permit_message(
+    program: ActorId,
+    owner: H160,
+    spender: ActorId,
+    value: U256,
+    nonce: u64,
+    deadline: u32,
+) -> Vec<u8> {
+    (PERMIT_TAG, program, owner, spender, value, nonce, deadline).encode()
+}
+
+/// Awesome Ethereum Binding service itself.
+pub struct EthBinding<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Bindings> = StorageRefCell<'a, Bindings>,
+> {
+    allowances: A,
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Bindings>,
+> EthBinding<'a, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(allowances: A, storage: S, vft: vft::VftExposure<vft::Vft<'a, A, B>>) -> Self {
+        Self {
+            allowances,
+            storage,
+            vft,
+        }
+    }
+
+    /// Consumes the nonce of the address if it signed the message built with it.
+    fn verify(
+        &mut self,
+        address: H160,
+        message: impl FnOnce(u64) -> Vec<u8>,
+        signature: &EthSignature,
+    ) -> Result<(), Error> {
+        let mut storage = self.storage.get_mut();
+
+        let signer = eth::recover_message(&message(storage.nonce(&address)), signature)?;
+
+        ensure!(signer == address, InvalidSignature);
+
+        storage.use_nonce(address);
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Bindings>,
+> EthBinding<'a, A, B, S>
+{
+    /// Binds the address to the message source by the signature
+    /// of the binding message made by the address.
+    #[export(unwrap_result)]
+    pub fn bind(&mut self, address: H160, signature: EthSignature) -> Result<(), Error> {
+        let account = Syscall::message_source();
+        let program = Syscall::program_id();
+
+        self.inner.verify(
+            address,
+            |nonce| bind_message(program, address, account, nonce),
+            &signature,
+        )?;
+
+        self.storage.get_mut().bind(address, account)?;
+
+        self.emit_event(Event::Bound { address, account })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Approves `value` of VFTs of the account bound to `owner` to the spender
+    /// by the signature of the permit message made by `owner`.
+    ///
+    /// Returns `true` if the allowance changed.
+    #[export(unwrap_result)]
+    pub fn permit(
+        &mut self,
+        owner: H160,
+        spender: ActorId,
+        value: U256,
+        deadline: u32,
+        signature: EthSignature,
+    ) -> Result<bool, Error> {
+        ensure!(Syscall::block_height() <= deadline, PermitExpired);
+
+        let account = self.storage.get().account_of(&owner).ok_or(NotBound)?;
+        let program = Syscall::program_id();
+
+        self.inner.verify(
+            owner,
+            |nonce| permit_message(program, owner, spender, value, nonce, deadline),
+            &signature,
+        )?;
+
+        ok_if!(account == spender, false);
+
+        let approval = Allowance::try_from(value).unwrap_or(Allowance::MAX);
+        let value = if approval.is_max() { U256::MAX } else { value };
+
+        let previous = self.allowances.get_mut()?.set(
+            account.try_into()?,
+            spender.try_into()?,
+            approval,
+            Syscall::block_height(),
+        )?;
+
+        let changed = previous.map(NonZero::cast).unwrap_or(U256::ZERO) != value;
+
+        if changed {
+            self.vft
+                .emit_event(vft::Event::Approval {
+                    owner: account,
+                    spender,
+                    value,
+                })
+                .map_err(|_| EmitError)?;
+        }
+
+        Ok(changed)
+    }
+
+    /// Unbinds the address of the message source.
+    ///
+    /// Returns the unbound address.
+    #[export(unwrap_result)]
+    pub fn unbind(&mut self) -> Result<H160, Error> {
+        let account = Syscall::message_source();
+
+        let address = self.storage.get_mut().unbind(account).ok_or(NotBound)?;
+
+        self.emit_event(Event::Unbound { address, account })
+            .map_err(|_| EmitError)?;
+
+        Ok(address)
+    }
+
+    #[export]
+    pub fn account_of(&self, address: H160) -> Option<ActorId> {
+        self.storage.get().account_of(&address)
+    }
+
+    #[export]
+    pub fn address_of(&self, account: ActorId) -> Option<H160> {
+        self.storage.get().address_of(&account)
+    }
+
+    /// Returns the message to be signed by the address to bind to the account.
+    #[export]
+    pub fn bind_message(&self, address: H160, account: ActorId) -> Vec<u8> {
+        bind_message(
+            Syscall::program_id(),
+            address,
+            account,
+            self.storage.get().nonce(&address),
+        )
+    }
+
+    #[export]
+    pub fn nonce(&self, address: H160) -> u64 {
+        self.storage.get().nonce(&address)
+    }
+
+    /// Returns the message to be signed by the owner to permit the approval.
+    #[export]
+    pub fn permit_message(
+        &self,
+        owner: H160,
+        spender: ActorId,
+        value: U256,
+        deadline: u32,
+    ) -> Vec<u8> {
+        permit_message(
+            Syscall::program_id(),
+            owner,
+            spender,
+            value,
+            self.storage.get().nonce(&owner),
+            deadline,
+        )
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Bound { address: H160, account: ActorId },
+    Unbound { address: H160, account: ActorId },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::{
+        error::{EmitError, Error},
+        eth::InvalidSignature,
+    };
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Address or account is already bound")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AlreadyBound;
+
+    awesome_sails_utils::impl_error_code!(AlreadyBound => 1100);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Address or account is not bound")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotBound;
+
+    awesome_sails_utils::impl_error_code!(NotBound => 1101);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Permit is expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct PermitExpired;
+
+    awesome_sails_utils::impl_error_code!(PermitExpired => 1102);
+}
@@ -0,0 +1,363 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Fee-Pool service.
+//!
+//! This service lets accounts pay for meta-transactions in VFTs: accounts deposit
+//! VFTs into the pool, while relayers holding [`RELAYER_ROLE`] submit permits signed
+//! by Ethereum addresses bound to the accounts (see `awesome-sails-eth-binding`)
+//! and are reimbursed from the deposits of the accounts.
+//!
+//! Reimbursements convert the gas spent on the meta-transaction into VFTs
+//! by the [`FeeRate`] set by the admin.
+
+#![no_std]
+
+use crate::error::{BadInput, EmitError, Error, InsufficientDeposit};
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage,
+};
+use awesome_sails_eth_binding::{self as eth_binding, error::NotBound};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    eth::EthSignature,
+    math::OverflowError,
+    ok_if,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+pub const RELAYER_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"RELAYER_ROLE")
+    .finalize();
+
+/// Scale of rates: a rate of `RATE_SCALE` is one token per unit of gas.
+pub const RATE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Conversion of gas spent by relayers into VFTs.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeRate {
+    /// Tokens per unit of gas, scaled by [`RATE_SCALE`].
+    pub per_gas: U256,
+    /// Gas added to the measured one, covering the rest of the message.
+    pub base_gas: u64,
+}
+
+impl FeeRate {
+    /// Returns tokens reimbursed for the gas, rounded down.
+    pub fn fee_of(&self, gas: u64) -> Option<U256> {
+        let gas = U256::from(gas).checked_add(self.base_gas.into())?;
+
+        Some(self.per_gas.checked_mul(gas)? / RATE_SCALE)
+    }
+}
+
+/// Accounting of a relayer.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RelayerStats {
+    /// Amount of meta-transactions relayed.
+    pub relayed: u64,
+    /// Gas reimbursed, the base gas included.
+    pub gas: u64,
+    /// Tokens reimbursed.
+    pub reimbursed: U256,
+}
+
+/// Deposits and relayers of [`FeePool`].
+#[derive(Default, Debug)]
+pub struct FeePoolStorage {
+    deposits: BTreeMap<ActorId, U256>,
+    relayers: BTreeMap<ActorId, RelayerStats>,
+    rate: FeeRate,
+}
+
+impl FeePoolStorage {
+    pub fn deposit_of(&self, account: ActorId) -> U256 {
+        self.deposits.get(&account).copied().unwrap_or_default()
+    }
+
+    pub fn relayer(&self, relayer: ActorId) -> RelayerStats {
+        self.relayers.get(&relayer).copied().unwrap_or_default()
+    }
+
+    pub fn rate(&self) -> FeeRate {
+        self.rate
+    }
+
+    pub fn set_rate(&mut self, rate: FeeRate) {
+        self.rate = rate;
+    }
+
+    pub fn deposit(&mut self, account: ActorId, amount: U256) -> Result<(), OverflowError> {
+        let deposit = self.deposits.entry(account).or_default();
+
+        *deposit = deposit.checked_add(amount).ok_or(OverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, account: ActorId, amount: U256) -> Result<(), InsufficientDeposit> {
+        let available = self.deposit_of(account);
+
+        ensure!(amount <= available, InsufficientDeposit { available });
+
+        if amount == available {
+            self.deposits.remove(&account);
+        } else {
+            self.deposits.insert(account, available - amount);
+        }
+
+        Ok(())
+    }
+
+    /// Charges the account the fee for the gas spent by the relayer,
+    /// returning the fee and the gas charged.
+    pub fn reimburse(
+        &mut self,
+        account: ActorId,
+        relayer: ActorId,
+        gas: u64,
+    ) -> Result<(U256, u64), Error> {
+        let fee = self.rate.fee_of(gas).ok_or(OverflowError)?;
+        let gas = gas.saturating_add(self.rate.base_gas);
+
+        self.withdraw(account, fee)?;
+
+        let stats = self.relayers.entry(relayer).or_default();
+
+        stats.relayed += 1;
+        stats.gas = stats.gas.saturating_add(gas);
+        stats.reimbursed = stats.reimbursed.saturating_add(fee);
+
+        Ok((fee, gas))
+    }
+}
+
+/// Awesome Fee-Pool service itself.
+pub struct FeePool<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = FeePoolStorage> = StorageRefCell<'a, FeePoolStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    eth_binding: eth_binding::EthBindingExposure<eth_binding::EthBinding<'a, A, B>>,
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = FeePoolStorage>,
+> FeePool<'a, ACS, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        eth_binding: eth_binding::EthBindingExposure<eth_binding::EthBinding<'a, A, B>>,
+        storage: S,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            eth_binding,
+            storage,
+            vft,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = FeePoolStorage>,
+> FeePool<'a, ACS, A, B, S>
+{
+    /// Deposits `amount` VFTs of the message source to pay for its meta-transactions.
+    #[export(unwrap_result)]
+    pub fn deposit(&mut self, amount: U256) -> Result<(), Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let account = Syscall::message_source();
+
+        self.storage.get_mut().deposit(account, amount)?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(account, Syscall::program_id(), amount)?;
+
+        self.emit_event(Event::Deposited { account, amount })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Submits the permit signed by `owner` as `EthBinding::permit` does,
+    /// reimbursing the message source from the deposit of the account bound to `owner`.
+    ///
+    /// Returns the fee reimbursed.
+    #[export(unwrap_result)]
+    #[require_role(RELAYER_ROLE)]
+    pub fn relay_permit(
+        &mut self,
+        owner: H160,
+        spender: ActorId,
+        value: U256,
+        deadline: u32,
+        signature: EthSignature,
+    ) -> Result<U256, Error> {
+        let gas_before = gstd::exec::gas_available();
+
+        let account = self.eth_binding.account_of(owner).ok_or(NotBound)?;
+        let relayer = Syscall::message_source();
+
+        self.eth_binding
+            .permit(owner, spender, value, deadline, signature)?;
+
+        let gas = gas_before.saturating_sub(gstd::exec::gas_available());
+
+        let (fee, gas) = self.storage.get_mut().reimburse(account, relayer, gas)?;
+
+        if !fee.is_zero() {
+            self.inner
+                .vft
+                .transfer_escrowed(Syscall::program_id(), relayer, fee)?;
+        }
+
+        self.emit_event(Event::Reimbursed {
+            relayer,
+            account,
+            gas,
+            fee,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(fee)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_fee_rate(&mut self, rate: FeeRate) -> Result<(), Error> {
+        self.storage.get_mut().set_rate(rate);
+
+        self.emit_event(Event::FeeRateChanged(rate))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Withdraws `amount` VFTs from the deposit of the message source.
+    #[export(unwrap_result)]
+    pub fn withdraw(&mut self, amount: U256) -> Result<(), Error> {
+        ok_if!(amount.is_zero(), ());
+
+        let account = Syscall::message_source();
+
+        self.storage.get_mut().withdraw(account, amount)?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(Syscall::program_id(), account, amount)?;
+
+        self.emit_event(Event::Withdrawn { account, amount })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn deposit_of(&self, account: ActorId) -> U256 {
+        self.storage.get().deposit_of(account)
+    }
+
+    #[export]
+    pub fn fee_rate(&self) -> FeeRate {
+        self.storage.get().rate()
+    }
+
+    /// Returns tokens reimbursed for the gas by the current rate, the base gas added.
+    #[export]
+    pub fn quote(&self, gas: u64) -> Option<U256> {
+        self.storage.get().rate().fee_of(gas)
+    }
+
+    #[export]
+    pub fn relayer_stats(&self, relayer: ActorId) -> RelayerStats {
+        self.storage.get().relayer(relayer)
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Deposited {
+        account: ActorId,
+        amount: U256,
+    },
+    Withdrawn {
+        account: ActorId,
+        amount: U256,
+    },
+    Reimbursed {
+        relayer: ActorId,
+        account: ActorId,
+        gas: u64,
+        fee: U256,
+    },
+    FeeRateChanged(FeeRate),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error};
+    use sails_rs::{
+        U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Insufficient deposit: {available} is available")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct InsufficientDeposit {
+        pub available: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(InsufficientDeposit => 2100);
+}
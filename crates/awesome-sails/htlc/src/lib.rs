@@ -0,0 +1,300 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome HTLC service.
+//!
+//! This service keeps hashed-timelock locks of VFTs and native value: funds locked
+//! under the keccak-256 hash of a secret are paid to the recipient by anyone revealing
+//! the secret before the timelock, or refunded to the sender once it expires.
+//!
+//! Hashes and timelocks follow Ethereum-style HTLCs, so the revealed secret,
+//! emitted in the `Claimed` event, completes the counterpart swap on the other chain.
+
+#![no_std]
+
+use crate::error::{
+    BadInput, EmitError, Error, LockExists, LockExpired, LockNotExpired, LockNotFound, SendError,
+};
+use awesome_sails_utils::{
+    ensure,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+/// Funds locked until the secret of the hashlock is revealed or the timelock expires.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Lock {
+    /// Account refunded once the lock expires.
+    pub sender: ActorId,
+    pub recipient: ActorId,
+    /// Block the lock expires at.
+    pub timelock: u32,
+    /// Locked VFTs.
+    pub value: U256,
+    /// Locked native value.
+    pub native: u128,
+}
+
+/// Active locks by their hashlock.
+#[derive(Default, Debug)]
+pub struct Locks(BTreeMap<H256, Lock>);
+
+impl Locks {
+    pub fn get(&self, hashlock: &H256) -> Option<&Lock> {
+        self.0.get(hashlock)
+    }
+
+    /// Adds the lock, failing if its hashlock is already in use.
+    pub fn insert(&mut self, hashlock: H256, lock: Lock) -> Result<(), LockExists> {
+        ensure!(!self.0.contains_key(&hashlock), LockExists);
+
+        self.0.insert(hashlock, lock);
+
+        Ok(())
+    }
+
+    /// Removes the lock of the revealed preimage, if not yet expired.
+    pub fn claim(&mut self, preimage: &[u8], current_bn: u32) -> Result<(H256, Lock), Error> {
+        let hashlock = hash(preimage);
+        let lock = self.0.get(&hashlock).ok_or(LockNotFound)?;
+
+        ensure!(current_bn < lock.timelock, LockExpired);
+
+        Ok((hashlock, self.0.remove(&hashlock).expect("checked above")))
+    }
+
+    /// Removes the expired lock.
+    pub fn refund(&mut self, hashlock: H256, current_bn: u32) -> Result<Lock, Error> {
+        let lock = self.0.get(&hashlock).ok_or(LockNotFound)?;
+
+        ensure!(current_bn >= lock.timelock, LockNotExpired);
+
+        Ok(self.0.remove(&hashlock).expect("checked above"))
+    }
+}
+
+/// Returns the keccak-256 hash of the preimage.
+pub fn hash(preimage: &[u8]) -> H256 {
+    keccak_const::Keccak256::new()
+        .update(preimage)
+        .finalize()
+        .into()
+}
+
+/// Awesome HTLC service itself.
+pub struct Htlc<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    L: InfallibleStorageMut<Item = Locks> = StorageRefCell<'a, Locks>,
+> {
+    storage: L,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    L: InfallibleStorageMut<Item = Locks>,
+> Htlc<'a, A, B, L>
+{
+    /// Constructor for [`Self`].
+    pub fn new(storage: L, vft: vft::VftExposure<vft::Vft<'a, A, B>>) -> Self {
+        Self { storage, vft }
+    }
+
+    /// Pays the funds of the lock from the program account to the beneficiary.
+    fn pay(&mut self, beneficiary: ActorId, lock: Lock) -> Result<(), Error> {
+        if !lock.value.is_zero() {
+            self.vft
+                .transfer_escrowed(Syscall::program_id(), beneficiary, lock.value)?;
+        }
+
+        if lock.native != 0 {
+            gstd::msg::send_bytes(beneficiary, [], lock.native).map_err(|_| SendError::Value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    L: InfallibleStorageMut<Item = Locks>,
+> Htlc<'a, A, B, L>
+{
+    /// Pays the lock of the revealed preimage to its recipient.
+    ///
+    /// Returns the hashlock of the claimed lock.
+    #[export(unwrap_result)]
+    pub fn claim(&mut self, preimage: Vec<u8>) -> Result<H256, Error> {
+        let (hashlock, lock) = self
+            .storage
+            .get_mut()
+            .claim(&preimage, Syscall::block_height())?;
+
+        self.inner.pay(lock.recipient, lock)?;
+
+        self.emit_event(Event::Claimed { hashlock, preimage })
+            .map_err(|_| EmitError)?;
+
+        Ok(hashlock)
+    }
+
+    /// Locks `value` of VFTs of the message source along with the message value
+    /// for `recipient` until block `timelock`, under the keccak-256 `hashlock`.
+    #[export(unwrap_result)]
+    pub fn lock(
+        &mut self,
+        hashlock: H256,
+        timelock: u32,
+        recipient: ActorId,
+        value: U256,
+    ) -> Result<(), Error> {
+        let sender = Syscall::message_source();
+        let native = Syscall::message_value();
+
+        ensure!(
+            !recipient.is_zero()
+                && timelock > Syscall::block_height()
+                && (!value.is_zero() || native != 0),
+            BadInput
+        );
+
+        self.storage.get_mut().insert(
+            hashlock,
+            Lock {
+                sender,
+                recipient,
+                timelock,
+                value,
+                native,
+            },
+        )?;
+
+        if !value.is_zero() {
+            self.inner
+                .vft
+                .transfer_escrowed(sender, Syscall::program_id(), value)?;
+        }
+
+        self.emit_event(Event::Locked {
+            hashlock,
+            sender,
+            recipient,
+            timelock,
+            value,
+            native,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Refunds the expired lock to its sender.
+    #[export(unwrap_result)]
+    pub fn refund(&mut self, hashlock: H256) -> Result<(), Error> {
+        let lock = self
+            .storage
+            .get_mut()
+            .refund(hashlock, Syscall::block_height())?;
+
+        self.inner.pay(lock.sender, lock)?;
+
+        self.emit_event(Event::Refunded(hashlock))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn lock_of(&self, hashlock: H256) -> Option<Lock> {
+        self.storage.get().get(&hashlock).copied()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Locked {
+        hashlock: H256,
+        sender: ActorId,
+        recipient: ActorId,
+        timelock: u32,
+        value: U256,
+        native: u128,
+    },
+    Claimed {
+        hashlock: H256,
+        preimage: Vec<u8>,
+    },
+    Refunded(H256),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error, SendError};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Lock already exists")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct LockExists;
+
+    awesome_sails_utils::impl_error_code!(LockExists => 1000);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Lock not found")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct LockNotFound;
+
+    awesome_sails_utils::impl_error_code!(LockNotFound => 1001);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Lock is expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct LockExpired;
+
+    awesome_sails_utils::impl_error_code!(LockExpired => 1002);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Lock is not expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct LockNotExpired;
+
+    awesome_sails_utils::impl_error_code!(LockNotExpired => 1003);
+}
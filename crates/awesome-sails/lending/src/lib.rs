@@ -0,0 +1,907 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Lending service.
+//!
+//! This service is a minimal collateralized lending market of two remote VFTs:
+//! accounts deposit the collateral token and borrow the debt token held by
+//! the program, up to the loan-to-value ratio of their collateral.
+//!
+//! Debt accrues interest every block through the borrow index, while positions
+//! exceeding the liquidation threshold are liquidated by anyone, who repays their debt
+//! for the collateral with a bonus.
+//!
+//! Price of the collateral is queried by anyone from the `Oracle` service of the
+//! program set in [`MarketParams`] through the [`oracle::RemoteOracle`] client, or pushed
+//! by accounts with [`ORACLE_ROLE`], and expires after `max_price_age` blocks.
+//! The market is kept in any [`StorageMut`], e.g. `PausableRef` to be paused along
+//! with the program.
+//!
+//! Tokens which can't be delivered, or were pulled for an operation that failed
+//! meanwhile, e.g. as the market was paused, are credited to the account in
+//! [`Claims`], kept apart from the market, and delivered again by `claim`.
+
+#![no_std]
+
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage,
+};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    error::{BadInput, EmitError, Error},
+    math::{OverflowError, UnderflowError},
+    ok_if,
+    storage::{InfallibleStorageMut, StorageMut},
+};
+use awesome_sails_vft::remote::RemoteVft;
+use error::{MarketInUse, MarketNotSet, NotLiquidatable, StalePrice, Undercollateralized};
+use oracle::RemoteOracle;
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+pub mod oracle;
+
+pub const ORACLE_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"ORACLE_ROLE")
+    .finalize();
+
+/// Scale of prices and interest: a value of `SCALE` is one.
+pub const SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Basis points of the collateral value, used for ratios and the bonus.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Parameters of the market.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MarketParams {
+    pub collateral: ActorId,
+    pub debt: ActorId,
+    /// Maximal debt of the position per its collateral value, in basis points.
+    pub ltv_bps: u16,
+    /// Debt per collateral value the position is liquidated at, in basis points.
+    pub liquidation_threshold_bps: u16,
+    /// Collateral paid to liquidators atop of the repaid value, in basis points.
+    pub liquidation_bonus_bps: u16,
+    /// Interest accrued by debt every block, scaled by [`SCALE`].
+    pub interest_per_block: U256,
+    /// Blocks the collateral price stays valid for.
+    pub max_price_age: u32,
+    /// Program with the `Oracle` service reporting the collateral price,
+    /// or zero if the price is only pushed by [`ORACLE_ROLE`].
+    pub oracle: ActorId,
+}
+
+/// Collateral and debt of an account, with accrued interest.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Position {
+    pub collateral: U256,
+    pub debt: U256,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Account {
+    collateral: U256,
+    /// Debt divided by the borrow index.
+    scaled_debt: U256,
+}
+
+/// Lending market of [`Lending`].
+#[derive(Default, Debug)]
+pub struct Market {
+    params: Option<MarketParams>,
+    price: U256,
+    priced_at: u32,
+    borrow_index: U256,
+    accrued_at: u32,
+    accounts: BTreeMap<ActorId, Account>,
+}
+
+impl Market {
+    pub fn params(&self) -> Option<&MarketParams> {
+        self.params.as_ref()
+    }
+
+    /// Replaces parameters of the market, failing if tokens change
+    /// while any positions are open.
+    pub fn set_params(&mut self, params: MarketParams, current_bn: u32) -> Result<(), MarketInUse> {
+        let tokens_changed = self.params.is_none_or(|current| {
+            (current.collateral, current.debt) != (params.collateral, params.debt)
+        });
+
+        ensure!(!tokens_changed || self.accounts.is_empty(), MarketInUse);
+
+        self.accrue(current_bn);
+        self.params = Some(params);
+
+        Ok(())
+    }
+
+    /// Returns the price of the collateral in the debt token with the block it was set at.
+    pub fn price(&self) -> (U256, u32) {
+        (self.price, self.priced_at)
+    }
+
+    pub fn set_price(&mut self, price: U256, current_bn: u32) {
+        self.price = price;
+        self.priced_at = current_bn;
+    }
+
+    /// Returns the borrow index at the given block, scaled by [`SCALE`].
+    pub fn borrow_index(&self, current_bn: u32) -> U256 {
+        let index = self.index();
+
+        let Some(params) = self.params else {
+            return index;
+        };
+
+        let elapsed = U256::from(current_bn.saturating_sub(self.accrued_at));
+
+        index.saturating_add(
+            index
+                .saturating_mul(params.interest_per_block)
+                .saturating_mul(elapsed)
+                / U256::from(SCALE),
+        )
+    }
+
+    /// Returns the position of the account at the given block.
+    pub fn position(&self, account: ActorId, current_bn: u32) -> Position {
+        let account = self.accounts.get(&account).copied().unwrap_or_default();
+
+        Position {
+            collateral: account.collateral,
+            debt: debt_of(account.scaled_debt, self.borrow_index(current_bn)),
+        }
+    }
+
+    /// Adds collateral to the position of the account.
+    pub fn deposit(&mut self, account: ActorId, amount: U256) -> Result<(), OverflowError> {
+        let position = self.accounts.entry(account).or_default();
+
+        position.collateral = position
+            .collateral
+            .checked_add(amount)
+            .ok_or(OverflowError)?;
+
+        Ok(())
+    }
+
+    /// Removes collateral from the position of the account, keeping it within the LTV.
+    pub fn withdraw(
+        &mut self,
+        account: ActorId,
+        amount: U256,
+        current_bn: u32,
+    ) -> Result<(), Error> {
+        let mut position = self.account(account, current_bn)?;
+
+        position.collateral = position
+            .collateral
+            .checked_sub(amount)
+            .ok_or(UnderflowError)?;
+
+        self.ensure_within_ltv(position, current_bn)?;
+        self.put(account, position);
+
+        Ok(())
+    }
+
+    /// Adds debt to the position of the account, keeping it within the LTV.
+    pub fn borrow(&mut self, account: ActorId, amount: U256, current_bn: u32) -> Result<(), Error> {
+        let mut position = self.account(account, current_bn)?;
+
+        let scaled = amount
+            .checked_mul(SCALE.into())
+            .ok_or(OverflowError)?
+            .div_mod(self.index());
+
+        position.scaled_debt = position
+            .scaled_debt
+            .checked_add(scaled.0 + U256::from(!scaled.1.is_zero() as u8))
+            .ok_or(OverflowError)?;
+
+        self.ensure_within_ltv(position, current_bn)?;
+        self.put(account, position);
+
+        Ok(())
+    }
+
+    /// Repays up to `amount` of the debt of the account, returning the repaid amount.
+    pub fn repay(
+        &mut self,
+        account: ActorId,
+        amount: U256,
+        current_bn: u32,
+    ) -> Result<U256, Error> {
+        let mut position = self.account(account, current_bn)?;
+
+        let debt = debt_of(position.scaled_debt, self.index());
+
+        let repaid = if amount >= debt {
+            position.scaled_debt = U256::zero();
+
+            debt
+        } else {
+            let scaled = amount.checked_mul(SCALE.into()).ok_or(OverflowError)? / self.index();
+
+            position.scaled_debt -= scaled.min(position.scaled_debt);
+
+            amount
+        };
+
+        self.put(account, position);
+
+        Ok(repaid)
+    }
+
+    /// Returns the price of the collateral if the position of the account
+    /// exceeds the liquidation threshold.
+    pub fn ensure_liquidatable(&self, account: ActorId, current_bn: u32) -> Result<U256, Error> {
+        let params = self.params.ok_or(MarketNotSet)?;
+        let position = self.position(account, current_bn);
+        let price = self.valid_price(current_bn)?;
+
+        let value = collateral_value(position.collateral, price)?;
+
+        ensure!(
+            position.debt.saturating_mul(MAX_BPS.into())
+                > value.saturating_mul(params.liquidation_threshold_bps.into()),
+            NotLiquidatable
+        );
+
+        Ok(price)
+    }
+
+    /// Repays up to `amount` of the debt of the liquidatable account,
+    /// returning the repaid amount and the seized collateral.
+    pub fn liquidate(
+        &mut self,
+        borrower: ActorId,
+        amount: U256,
+        current_bn: u32,
+    ) -> Result<(U256, U256), Error> {
+        let params = self.params.ok_or(MarketNotSet)?;
+        let price = self.ensure_liquidatable(borrower, current_bn)?;
+
+        let repaid = self.repay(borrower, amount, current_bn)?;
+
+        let seized = repaid
+            .checked_mul(U256::from(MAX_BPS) + U256::from(params.liquidation_bonus_bps))
+            .and_then(|v| v.checked_mul(SCALE.into()))
+            .ok_or(OverflowError)?
+            / U256::from(MAX_BPS)
+            / price;
+
+        let mut account = self.accounts.get(&borrower).copied().unwrap_or_default();
+
+        let seized = seized.min(account.collateral);
+        account.collateral -= seized;
+
+        self.put(borrower, account);
+
+        Ok((repaid, seized))
+    }
+
+    fn index(&self) -> U256 {
+        if self.borrow_index.is_zero() {
+            SCALE.into()
+        } else {
+            self.borrow_index
+        }
+    }
+
+    fn accrue(&mut self, current_bn: u32) {
+        self.borrow_index = self.borrow_index(current_bn);
+        self.accrued_at = current_bn;
+    }
+
+    /// Accrues interest, returning the account to be updated.
+    fn account(&mut self, account: ActorId, current_bn: u32) -> Result<Account, MarketNotSet> {
+        ensure!(self.params.is_some(), MarketNotSet);
+
+        self.accrue(current_bn);
+
+        Ok(self.accounts.get(&account).copied().unwrap_or_default())
+    }
+
+    fn put(&mut self, account: ActorId, position: Account) {
+        if position.collateral.is_zero() && position.scaled_debt.is_zero() {
+            self.accounts.remove(&account);
+        } else {
+            self.accounts.insert(account, position);
+        }
+    }
+
+    fn valid_price(&self, current_bn: u32) -> Result<U256, Error> {
+        let params = self.params.ok_or(MarketNotSet)?;
+
+        ensure!(
+            !self.price.is_zero()
+                && current_bn.saturating_sub(self.priced_at) <= params.max_price_age,
+            StalePrice
+        );
+
+        Ok(self.price)
+    }
+
+    fn ensure_within_ltv(&self, position: Account, current_bn: u32) -> Result<(), Error> {
+        ok_if!(position.scaled_debt.is_zero());
+
+        let params = self.params.ok_or(MarketNotSet)?;
+        let price = self.valid_price(current_bn)?;
+
+        let debt = debt_of(position.scaled_debt, self.index());
+        let value = collateral_value(position.collateral, price)?;
+
+        ensure!(
+            debt.saturating_mul(MAX_BPS.into()) <= value.saturating_mul(params.ltv_bps.into()),
+            Undercollateralized
+        );
+
+        Ok(())
+    }
+}
+
+/// Tokens to be claimed by accounts of [`Lending`].
+///
+/// Kept apart from the [`Market`], so tokens pulled for an operation are credited
+/// even if the market can't be updated once they arrive.
+#[derive(Default, Debug)]
+pub struct Claims(BTreeMap<(ActorId, ActorId), U256>);
+
+impl Claims {
+    /// Returns the amount of the token to be claimed by the account.
+    pub fn unclaimed(&self, token: ActorId, account: ActorId) -> U256 {
+        self.0.get(&(token, account)).copied().unwrap_or_default()
+    }
+
+    /// Credits the amount of the token to be claimed by the account.
+    pub fn credit(&mut self, token: ActorId, account: ActorId, amount: U256) {
+        let unclaimed = self.0.entry((token, account)).or_default();
+
+        *unclaimed = unclaimed.saturating_add(amount);
+    }
+
+    /// Removes and returns the amount of the token to be claimed by the account.
+    pub fn take(&mut self, token: ActorId, account: ActorId) -> U256 {
+        self.0.remove(&(token, account)).unwrap_or_default()
+    }
+}
+
+/// Returns the debt for the scaled debt and the borrow index, rounded up.
+fn debt_of(scaled_debt: U256, index: U256) -> U256 {
+    let (debt, rem) = scaled_debt.saturating_mul(index).div_mod(SCALE.into());
+
+    debt.saturating_add(U256::from(!rem.is_zero() as u8))
+}
+
+/// Returns the value of the collateral in the debt token.
+fn collateral_value(collateral: U256, price: U256) -> Result<U256, OverflowError> {
+    Ok(collateral.checked_mul(price).ok_or(OverflowError)? / U256::from(SCALE))
+}
+
+/// Awesome Lending service itself.
+pub struct Lending<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    C: InfallibleStorageMut<Item = Claims>,
+    M: StorageMut<Item = Market>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    claims: C,
+    storage: M,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    C: InfallibleStorageMut<Item = Claims>,
+    M: StorageMut<Item = Market>,
+> Lending<'a, ACS, C, M>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        claims: C,
+        storage: M,
+    ) -> Self {
+        Self {
+            access_control,
+            claims,
+            storage,
+        }
+    }
+
+    fn params(&self) -> Result<MarketParams, Error> {
+        Ok(*self.storage.get()?.params().ok_or(MarketNotSet)?)
+    }
+
+    /// Applies the update to the market, failing if it can't be accessed.
+    fn update_market<T, E: Into<Error>>(
+        &mut self,
+        update: impl FnOnce(&mut Market) -> Result<T, E>,
+    ) -> Result<T, Error> {
+        update(&mut *self.storage.get_mut()?).map_err(Into::into)
+    }
+
+    /// Transfers the amount of the token to the account, crediting it
+    /// to be claimed if the transfer fails.
+    ///
+    /// Returns `true` if the amount was transferred.
+    async fn push(&mut self, token: ActorId, to: ActorId, amount: U256) -> bool {
+        if RemoteVft::new(token).transfer(to, amount).await.is_ok() {
+            return true;
+        }
+
+        self.claims.get_mut().credit(token, to, amount);
+
+        false
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    C: InfallibleStorageMut<Item = Claims>,
+    M: StorageMut<Item = Market>,
+> Lending<'a, ACS, C, M>
+{
+    /// Borrows `amount` of the debt token to the message source,
+    /// within the LTV of its collateral.
+    #[export(unwrap_result)]
+    pub async fn borrow(&mut self, amount: U256) -> Result<(), Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let account = Syscall::message_source();
+        let params = self.inner.params()?;
+
+        self.storage
+            .get_mut()?
+            .borrow(account, amount, Syscall::block_height())?;
+
+        self.emit_event(Event::Borrowed { account, amount })
+            .map_err(|_| EmitError)?;
+
+        if !self.inner.push(params.debt, account, amount).await {
+            self.emit_event(Event::Credited {
+                token: params.debt,
+                account,
+                amount,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Transfers the amount of the token credited to the message source.
+    ///
+    /// Returns the transferred amount.
+    #[export(unwrap_result)]
+    pub async fn claim(&mut self, token: ActorId) -> Result<U256, Error> {
+        let account = Syscall::message_source();
+
+        let amount = self.claims.get_mut().take(token, account);
+
+        ok_if!(amount.is_zero(), amount);
+
+        ok_if!(!self.inner.push(token, account, amount).await, U256::zero());
+
+        self.emit_event(Event::Claimed {
+            token,
+            account,
+            amount,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(amount)
+    }
+
+    /// Deposits `amount` of the collateral token of the message source,
+    /// which must approve it to the program.
+    ///
+    /// If the collateral can't be deposited once it's pulled, e.g. as the market
+    /// was paused meanwhile, it's credited to be claimed and zero is returned.
+    ///
+    /// Returns the deposited amount.
+    #[export(unwrap_result)]
+    pub async fn deposit_collateral(&mut self, amount: U256) -> Result<U256, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let account = Syscall::message_source();
+        let params = self.inner.params()?;
+
+        RemoteVft::new(params.collateral)
+            .transfer_from(account, Syscall::program_id(), amount)
+            .await?;
+
+        if self
+            .inner
+            .update_market(|market| market.deposit(account, amount))
+            .is_err()
+        {
+            self.credit(params.collateral, account, amount)?;
+
+            return Ok(U256::zero());
+        }
+
+        self.emit_event(Event::CollateralDeposited { account, amount })
+            .map_err(|_| EmitError)?;
+
+        Ok(amount)
+    }
+
+    /// Repays up to `amount` of the debt of the liquidatable `borrower` from
+    /// the message source, which must approve it to the program, for the collateral
+    /// of the borrower with the bonus.
+    ///
+    /// If the borrower can't be liquidated once the debt token is pulled, e.g. as the
+    /// price changed or the market was paused meanwhile, the debt token is credited
+    /// to be claimed and zero is returned.
+    ///
+    /// Returns the seized collateral.
+    #[export(unwrap_result)]
+    pub async fn liquidate(&mut self, borrower: ActorId, amount: U256) -> Result<U256, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let liquidator = Syscall::message_source();
+        let params = self.inner.params()?;
+
+        let amount = {
+            let market = self.storage.get()?;
+
+            market.ensure_liquidatable(borrower, Syscall::block_height())?;
+
+            amount.min(market.position(borrower, Syscall::block_height()).debt)
+        };
+
+        RemoteVft::new(params.debt)
+            .transfer_from(liquidator, Syscall::program_id(), amount)
+            .await?;
+
+        let liquidated = self
+            .inner
+            .update_market(|market| market.liquidate(borrower, amount, Syscall::block_height()));
+
+        let Ok((repaid, seized)) = liquidated else {
+            self.credit(params.debt, liquidator, amount)?;
+
+            return Ok(U256::zero());
+        };
+
+        let refund = amount - repaid;
+
+        if !refund.is_zero() {
+            self.credit(params.debt, liquidator, refund)?;
+        }
+
+        self.emit_event(Event::Liquidated {
+            borrower,
+            liquidator,
+            repaid,
+            seized,
+        })
+        .map_err(|_| EmitError)?;
+
+        if !self.inner.push(params.collateral, liquidator, seized).await {
+            self.emit_event(Event::Credited {
+                token: params.collateral,
+                account: liquidator,
+                amount: seized,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(seized)
+    }
+
+    /// Repays up to `amount` of the debt of the message source,
+    /// which must approve it to the program.
+    ///
+    /// If the debt can't be repaid once the debt token is pulled, e.g. as the market
+    /// was paused meanwhile, the debt token is credited to be claimed and zero
+    /// is returned.
+    ///
+    /// Returns the repaid amount.
+    #[export(unwrap_result)]
+    pub async fn repay(&mut self, amount: U256) -> Result<U256, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let account = Syscall::message_source();
+        let params = self.inner.params()?;
+
+        let amount = amount.min(
+            self.storage
+                .get()?
+                .position(account, Syscall::block_height())
+                .debt,
+        );
+
+        ok_if!(amount.is_zero(), amount);
+
+        RemoteVft::new(params.debt)
+            .transfer_from(account, Syscall::program_id(), amount)
+            .await?;
+
+        let repaid = self
+            .inner
+            .update_market(|market| market.repay(account, amount, Syscall::block_height()));
+
+        let Ok(repaid) = repaid else {
+            self.credit(params.debt, account, amount)?;
+
+            return Ok(U256::zero());
+        };
+
+        let refund = amount - repaid;
+
+        if !refund.is_zero() {
+            self.credit(params.debt, account, refund)?;
+        }
+
+        self.emit_event(Event::Repaid {
+            account,
+            amount: repaid,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(repaid)
+    }
+
+    /// Queries the price of the collateral from the oracle of the market.
+    ///
+    /// Returns the price with the block it was reported at.
+    #[export(unwrap_result)]
+    pub async fn refresh_price(&mut self) -> Result<(U256, u32), Error> {
+        let params = self.inner.params()?;
+
+        ensure!(!params.oracle.is_zero(), BadInput);
+
+        let (price, reported_at) = RemoteOracle::new(params.oracle)
+            .price(params.collateral, params.debt)
+            .await?;
+
+        let reported_at = reported_at.min(Syscall::block_height());
+
+        {
+            let mut market = self.storage.get_mut()?;
+
+            ensure!(
+                !price.is_zero() && reported_at >= market.price().1,
+                StalePrice
+            );
+
+            market.set_price(price, reported_at);
+        }
+
+        self.emit_event(Event::PriceSet(price))
+            .map_err(|_| EmitError)?;
+
+        Ok((price, reported_at))
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_params(&mut self, params: MarketParams) -> Result<(), Error> {
+        ensure!(
+            !params.collateral.is_zero()
+                && !params.debt.is_zero()
+                && params.collateral != params.debt
+                && params.ltv_bps <= params.liquidation_threshold_bps
+                && params.liquidation_threshold_bps <= MAX_BPS
+                && params.liquidation_bonus_bps <= MAX_BPS,
+            BadInput
+        );
+
+        self.storage
+            .get_mut()?
+            .set_params(params, Syscall::block_height())?;
+
+        self.emit_event(Event::ParamsSet(params))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Sets the price of the collateral in the debt token, scaled by [`SCALE`].
+    #[export(unwrap_result)]
+    #[require_role(ORACLE_ROLE)]
+    pub fn set_price(&mut self, price: U256) -> Result<(), Error> {
+        self.storage
+            .get_mut()?
+            .set_price(price, Syscall::block_height());
+
+        self.emit_event(Event::PriceSet(price))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Withdraws `amount` of the collateral of the message source,
+    /// keeping its debt within the LTV.
+    #[export(unwrap_result)]
+    pub async fn withdraw_collateral(&mut self, amount: U256) -> Result<(), Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let account = Syscall::message_source();
+        let params = self.inner.params()?;
+
+        self.storage
+            .get_mut()?
+            .withdraw(account, amount, Syscall::block_height())?;
+
+        self.emit_event(Event::CollateralWithdrawn { account, amount })
+            .map_err(|_| EmitError)?;
+
+        if !self.inner.push(params.collateral, account, amount).await {
+            self.emit_event(Event::Credited {
+                token: params.collateral,
+                account,
+                amount,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(())
+    }
+
+    #[export(unwrap_result)]
+    pub fn params(&self) -> Result<Option<MarketParams>, Error> {
+        Ok(self.storage.get()?.params().copied())
+    }
+
+    #[export(unwrap_result)]
+    pub fn position(&self, account: ActorId) -> Result<Position, Error> {
+        Ok(self
+            .storage
+            .get()?
+            .position(account, Syscall::block_height()))
+    }
+
+    /// Returns the price of the collateral with the block it was set at.
+    #[export(unwrap_result)]
+    pub fn price(&self) -> Result<(U256, u32), Error> {
+        Ok(self.storage.get()?.price())
+    }
+
+    #[export]
+    pub fn unclaimed(&self, token: ActorId, account: ActorId) -> U256 {
+        self.claims.get().unclaimed(token, account)
+    }
+
+    /// Credits the amount of the token pulled for an operation,
+    /// which failed meanwhile, to be claimed by the account.
+    fn credit(&mut self, token: ActorId, account: ActorId, amount: U256) -> Result<(), Error> {
+        self.claims.get_mut().credit(token, account, amount);
+
+        self.emit_event(Event::Credited {
+            token,
+            account,
+            amount,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    CollateralDeposited {
+        account: ActorId,
+        amount: U256,
+    },
+    CollateralWithdrawn {
+        account: ActorId,
+        amount: U256,
+    },
+    Borrowed {
+        account: ActorId,
+        amount: U256,
+    },
+    Repaid {
+        account: ActorId,
+        amount: U256,
+    },
+    Liquidated {
+        borrower: ActorId,
+        liquidator: ActorId,
+        repaid: U256,
+        seized: U256,
+    },
+    Credited {
+        token: ActorId,
+        account: ActorId,
+        amount: U256,
+    },
+    Claimed {
+        token: ActorId,
+        account: ActorId,
+        amount: U256,
+    },
+    ParamsSet(MarketParams),
+    PriceSet(U256),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error};
+    pub use awesome_sails_vft::remote::TransferFailed;
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Market is not set")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct MarketNotSet;
+
+    awesome_sails_utils::impl_error_code!(MarketNotSet => 900);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Market is in use")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct MarketInUse;
+
+    awesome_sails_utils::impl_error_code!(MarketInUse => 901);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Price is stale")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct StalePrice;
+
+    awesome_sails_utils::impl_error_code!(StalePrice => 902);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Position is undercollateralized")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct Undercollateralized;
+
+    awesome_sails_utils::impl_error_code!(Undercollateralized => 903);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Position is not liquidatable")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotLiquidatable;
+
+    awesome_sails_utils::impl_error_code!(NotLiquidatable => 904);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Oracle query failed")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct OracleFailed;
+
+    awesome_sails_utils::impl_error_code!(OracleFailed => 905);
+}
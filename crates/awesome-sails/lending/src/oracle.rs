@@ -0,0 +1,51 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Minimal client of the `Oracle` service of remote programs.
+//!
+//! The service is expected to expose the `Price` query of the `base` token in
+//! the `quote` token, scaled by [`SCALE`](crate::SCALE), replying with the price
+//! and the block it was reported at.
+
+use crate::error::OracleFailed;
+use sails_rs::{gstd, prelude::*};
+
+/// Client of the `Oracle` service of a remote program.
+pub struct RemoteOracle(ActorId);
+
+impl RemoteOracle {
+    pub fn new(program: ActorId) -> Self {
+        Self(program)
+    }
+
+    /// Returns the price of `base` in `quote` with the block it was reported at.
+    pub async fn price(&self, base: ActorId, quote: ActorId) -> Result<(U256, u32), OracleFailed> {
+        let payload = ("Oracle", "Price", (base, quote)).encode();
+
+        let reply = gstd::msg::send_bytes_for_reply(self.0, payload, 0, 0)
+            .map_err(|_| OracleFailed)?
+            .await
+            .map_err(|_| OracleFailed)?;
+
+        // Reply is prefixed with the route of the call.
+        let (_, _, price) = <(String, String, (U256, u32))>::decode(&mut reply.as_slice())
+            .map_err(|_| OracleFailed)?;
+
+        Ok(price)
+    }
+}
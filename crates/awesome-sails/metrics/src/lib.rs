@@ -0,0 +1,120 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Metrics service.
+//!
+//! This service reports storage usage of the program, i.e. shards and entries
+//! of sharded maps with their estimated heap bytes, and roles of access control,
+//! so operators watch the program growing and append shards before inserts
+//! start failing.
+
+#![no_std]
+
+use awesome_sails_access_control::RolesStorage;
+use awesome_sails_utils::{
+    error::Error,
+    map::MapMetrics,
+    pause::PausableRef,
+    storage::{InfallibleStorage, Storage, StorageRefCell},
+};
+use awesome_sails_vft::utils::{Allowances, Balances};
+use core::marker::PhantomData;
+use sails_rs::prelude::*;
+
+/// Metrics of the program storage.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramMetrics {
+    /// Metrics of the balances map.
+    pub balances: MapMetrics,
+    /// Metrics of the allowances map.
+    pub allowances: MapMetrics,
+    /// Amount of roles known.
+    pub roles: u32,
+    /// Amount of distinct accounts holding any role.
+    pub members: u32,
+    /// Amount of privileged calls made, recorded by the audit log.
+    pub audit_seq: u32,
+}
+
+/// Awesome Metrics service itself.
+pub struct Metrics<
+    'a,
+    ACS: InfallibleStorage<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
+    A: Storage<Item = Allowances> = PausableRef<'a, Allowances>,
+    B: Storage<Item = Balances> = PausableRef<'a, Balances>,
+> {
+    roles: ACS,
+    allowances: A,
+    balances: B,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorage<Item = RolesStorage>,
+    A: Storage<Item = Allowances>,
+    B: Storage<Item = Balances>,
+> Metrics<'a, ACS, A, B>
+{
+    /// Constructor for [`Self`].
+    pub fn new(roles: ACS, allowances: A, balances: B) -> Self {
+        Self {
+            roles,
+            allowances,
+            balances,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service]
+impl<
+    ACS: InfallibleStorage<Item = RolesStorage>,
+    A: Storage<Item = Allowances>,
+    B: Storage<Item = Balances>,
+> Metrics<'_, ACS, A, B>
+{
+    /// Returns metrics of the allowances map.
+    #[export(unwrap_result)]
+    pub fn allowances_metrics(&self) -> Result<MapMetrics, Error> {
+        Ok(self.allowances.get()?.metrics())
+    }
+
+    /// Returns metrics of the balances map.
+    #[export(unwrap_result)]
+    pub fn balances_metrics(&self) -> Result<MapMetrics, Error> {
+        Ok(self.balances.get()?.metrics())
+    }
+
+    /// Returns metrics of the whole program storage.
+    #[export(unwrap_result)]
+    pub fn metrics(&self) -> Result<ProgramMetrics, Error> {
+        let roles = self.roles.get();
+
+        Ok(ProgramMetrics {
+            balances: self.balances.get()?.metrics(),
+            allowances: self.allowances.get()?.metrics(),
+            roles: roles.get_role_count(),
+            members: roles.get_all_member_count(),
+            audit_seq: roles.get_audit_seq(),
+        })
+    }
+}
@@ -0,0 +1,337 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Name Registry service.
+//!
+//! This service maps human-readable names to actors, e.g. recipients of transfers.
+//! Names are registered for a number of blocks by their owners, who renew them,
+//! point them to other actors and transfer them. Expired names resolve to nothing
+//! and are registered by anyone.
+//!
+//! Names are up to [`MAX_NAME_LEN`] bytes of lowercase ASCII letters, digits and `-`.
+
+#![no_std]
+
+use crate::error::{BadInput, BadOrigin, EmitError, Error, NameNotFound, NameTaken};
+use awesome_sails_utils::{
+    ensure,
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    prelude::*,
+};
+
+/// Maximal length of names, in bytes.
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Returns `true` if the name is valid.
+pub fn is_valid_name(name: &str) -> bool {
+    (1..=MAX_NAME_LEN).contains(&name.len())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Registered name.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct NameRecord {
+    pub owner: ActorId,
+    /// Actor the name resolves to.
+    pub target: ActorId,
+    /// Block the name expires at.
+    pub expires_at: u32,
+}
+
+impl NameRecord {
+    pub fn is_expired(&self, bn: u32) -> bool {
+        bn >= self.expires_at
+    }
+}
+
+/// Registered names, along with names of owners.
+#[derive(Default, Debug)]
+pub struct Names {
+    records: BTreeMap<String, NameRecord>,
+    owned: BTreeMap<ActorId, BTreeSet<String>>,
+}
+
+impl Names {
+    /// Returns the record of the name, even if expired.
+    pub fn record(&self, name: &str) -> Option<&NameRecord> {
+        self.records.get(name)
+    }
+
+    /// Returns the actor the name resolves to, unless it's expired.
+    pub fn resolve(&self, name: &str, bn: u32) -> Option<ActorId> {
+        self.records
+            .get(name)
+            .filter(|record| !record.is_expired(bn))
+            .map(|record| record.target)
+    }
+
+    /// Iterates over names of the owner, including expired ones, in ascending order.
+    pub fn names_of(&self, owner: &ActorId) -> impl Iterator<Item = &String> {
+        self.owned.get(owner).into_iter().flatten()
+    }
+
+    /// Registers the name, replacing its expired record.
+    pub fn register(&mut self, name: String, record: NameRecord, bn: u32) -> Result<(), NameTaken> {
+        if let Some(previous) = self.records.get(&name) {
+            ensure!(previous.is_expired(bn), NameTaken);
+
+            let owner = previous.owner;
+            self.unindex(owner, &name);
+        }
+
+        self.owned
+            .entry(record.owner)
+            .or_default()
+            .insert(name.clone());
+        self.records.insert(name, record);
+
+        Ok(())
+    }
+
+    /// Returns the record of the unexpired name owned by the owner.
+    pub fn owned_mut(
+        &mut self,
+        name: &str,
+        owner: ActorId,
+        bn: u32,
+    ) -> Result<&mut NameRecord, Error> {
+        let record = self
+            .records
+            .get_mut(name)
+            .filter(|record| !record.is_expired(bn))
+            .ok_or(NameNotFound)?;
+
+        ensure!(record.owner == owner, BadOrigin);
+
+        Ok(record)
+    }
+
+    /// Transfers the unexpired name of the owner to the new owner.
+    pub fn transfer(
+        &mut self,
+        name: &str,
+        owner: ActorId,
+        new_owner: ActorId,
+        bn: u32,
+    ) -> Result<(), Error> {
+        self.owned_mut(name, owner, bn)?.owner = new_owner;
+
+        self.unindex(owner, name);
+        self.owned.entry(new_owner).or_default().insert(name.into());
+
+        Ok(())
+    }
+
+    fn unindex(&mut self, owner: ActorId, name: &str) {
+        if let Some(names) = self.owned.get_mut(&owner) {
+            names.remove(name);
+
+            if names.is_empty() {
+                self.owned.remove(&owner);
+            }
+        }
+    }
+}
+
+/// Awesome Name Registry service itself.
+pub struct NameRegistry<'a, S: InfallibleStorageMut<Item = Names> = StorageRefCell<'a, Names>> {
+    storage: S,
+    _marker: core::marker::PhantomData<&'a ()>,
+}
+
+impl<S: InfallibleStorageMut<Item = Names>> NameRegistry<'_, S> {
+    /// Constructor for [`Self`].
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<S: InfallibleStorageMut<Item = Names>> NameRegistry<'_, S> {
+    /// Registers the name for `duration` blocks, owned by the message source
+    /// and resolving to the target.
+    ///
+    /// Returns the block the name expires at.
+    #[export(unwrap_result)]
+    pub fn register(&mut self, name: String, target: ActorId, duration: u32) -> Result<u32, Error> {
+        ensure!(is_valid_name(&name) && duration != 0, BadInput);
+
+        let owner = Syscall::message_source();
+        let bn = Syscall::block_height();
+
+        let record = NameRecord {
+            owner,
+            target,
+            expires_at: bn.saturating_add(duration),
+        };
+
+        self.storage.get_mut().register(name.clone(), record, bn)?;
+
+        self.emit_event(Event::Registered {
+            name,
+            owner,
+            target,
+            expires_at: record.expires_at,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(record.expires_at)
+    }
+
+    /// Extends the name of the message source by `duration` blocks.
+    ///
+    /// Returns the block the name expires at.
+    #[export(unwrap_result)]
+    pub fn renew(&mut self, name: String, duration: u32) -> Result<u32, Error> {
+        ensure!(duration != 0, BadInput);
+
+        let expires_at = {
+            let mut storage = self.storage.get_mut();
+
+            let record =
+                storage.owned_mut(&name, Syscall::message_source(), Syscall::block_height())?;
+
+            record.expires_at = record.expires_at.saturating_add(duration);
+
+            record.expires_at
+        };
+
+        self.emit_event(Event::Renewed { name, expires_at })
+            .map_err(|_| EmitError)?;
+
+        Ok(expires_at)
+    }
+
+    /// Points the name of the message source to the target.
+    #[export(unwrap_result)]
+    pub fn set_target(&mut self, name: String, target: ActorId) -> Result<(), Error> {
+        self.storage
+            .get_mut()
+            .owned_mut(&name, Syscall::message_source(), Syscall::block_height())?
+            .target = target;
+
+        self.emit_event(Event::TargetSet { name, target })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Transfers the name of the message source to the new owner.
+    #[export(unwrap_result)]
+    pub fn transfer(&mut self, name: String, new_owner: ActorId) -> Result<(), Error> {
+        let owner = Syscall::message_source();
+
+        self.storage
+            .get_mut()
+            .transfer(&name, owner, new_owner, Syscall::block_height())?;
+
+        self.emit_event(Event::Transferred {
+            name,
+            from: owner,
+            to: new_owner,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Returns up to `len` names of the owner starting from `cursor`,
+    /// including expired ones, in ascending order.
+    #[export]
+    pub fn names_of(&self, owner: ActorId, cursor: u32, len: u32) -> Vec<String> {
+        self.storage
+            .get()
+            .names_of(&owner)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .cloned()
+            .collect()
+    }
+
+    #[export]
+    pub fn record(&self, name: String) -> Option<NameRecord> {
+        self.storage.get().record(&name).copied()
+    }
+
+    /// Returns the actor the name resolves to, unless it's expired.
+    #[export]
+    pub fn resolve(&self, name: String) -> Option<ActorId> {
+        self.storage.get().resolve(&name, Syscall::block_height())
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Registered {
+        name: String,
+        owner: ActorId,
+        target: ActorId,
+        expires_at: u32,
+    },
+    Renewed {
+        name: String,
+        expires_at: u32,
+    },
+    TargetSet {
+        name: String,
+        target: ActorId,
+    },
+    Transferred {
+        name: String,
+        from: ActorId,
+        to: ActorId,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadOrigin, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Name is taken")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NameTaken;
+
+    awesome_sails_utils::impl_error_code!(NameTaken => 1700);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Name is not registered or expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NameNotFound;
+
+    awesome_sails_utils::impl_error_code!(NameNotFound => 1701);
+}
@@ -0,0 +1,817 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Order-Book service.
+//!
+//! This service keeps limit orders trading VFTs for native value. Makers escrow
+//! the asset they give when placing an order: tokens for sell orders and native
+//! value for buy orders. Takers fill orders partially or fully at the order price,
+//! with a fee taken from the native leg and sent to the treasury.
+//!
+//! Orders expire after their time to live: expired orders aren't filled and
+//! may be cancelled by anyone, returning the rest of the escrow to the maker.
+//!
+//! Besides, settlers match sell orders against buy orders off-chain and settle
+//! the matched fills in batches, applied as a whole or not at all. Makers of both
+//! orders sign each fill by their sr25519 keys, see `fill_message`, so settlers
+//! only apply trades makers agreed to. Signed messages are bound to the program
+//! and a per-maker nonce, so every signature is used only once.
+
+#![no_std]
+
+use crate::error::{
+    BadInput, BadOrigin, BadValue, EmitError, Error, FillExceedsOrder, OrderExpired, OrderNotFound,
+    OrdersNotCrossed, SendError,
+};
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage,
+};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    map::{ShardedMap, ShardedMapError},
+    math::OverflowError,
+    multicall::MAX_BATCH_LEN,
+    ok_if,
+    signature::sr25519::{self, Sr25519Signature},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+    unwrap_infallible,
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    gstd,
+    prelude::*,
+};
+
+pub const SETTLER_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"SETTLER_ROLE")
+    .finalize();
+
+/// Scale of prices: a price of `PRICE_SCALE` is one unit of native value per token.
+pub const PRICE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Fees are given in basis points of the native value traded.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Tag of fill messages.
+pub const FILL_TAG: &[u8] = b"awesome-sails/order-book/fill";
+
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Side {
+    /// Maker buys tokens, escrowing native value.
+    Buy,
+    /// Maker sells tokens, escrowing them.
+    Sell,
+}
+
+/// Limit order along with its unfilled part.
+///
+/// The price is given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Order {
+    pub maker: ActorId,
+    pub side: Side,
+    pub price: U256,
+    /// Amount of tokens left to fill.
+    pub remaining: U256,
+    /// Native value left in escrow, zero for sell orders.
+    pub escrow: u128,
+    /// Block the order expires at.
+    pub expires_at: u32,
+}
+
+impl Order {
+    pub fn is_expired(&self, bn: u32) -> bool {
+        bn >= self.expires_at
+    }
+
+    /// Returns native value of `amount` tokens at the order price,
+    /// rounded up or down.
+    pub fn value_of(&self, amount: U256, round_up: bool) -> Option<u128> {
+        value_of(self.price, amount, round_up)
+    }
+
+    /// Takes `amount` tokens traded for `value` off the order.
+    fn take(&mut self, amount: U256, value: u128) -> Result<(), Error> {
+        ensure!(
+            amount <= self.remaining,
+            FillExceedsOrder {
+                remaining: self.remaining
+            }
+        );
+
+        if self.side == Side::Buy {
+            self.escrow = self.escrow.checked_sub(value).ok_or(OverflowError)?;
+        }
+
+        self.remaining -= amount;
+
+        Ok(())
+    }
+}
+
+/// Returns native value of `amount` tokens at the price, rounded up or down.
+pub fn value_of(price: U256, amount: U256, round_up: bool) -> Option<u128> {
+    let (value, rem) = price.checked_mul(amount)?.div_mod(PRICE_SCALE.into());
+
+    u128::try_from(value + U256::from((round_up && !rem.is_zero()) as u8)).ok()
+}
+
+/// Sell order matched against a buy order off-chain.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Fill {
+    pub sell_id: u64,
+    pub buy_id: u64,
+    /// Amount of tokens traded.
+    pub amount: U256,
+    /// Price of the trade, between the prices of the orders.
+    pub price: U256,
+}
+
+/// Fill along with signatures of makers of its orders, see [`fill_message`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SignedFill {
+    pub fill: Fill,
+    pub sell_signature: Sr25519Signature,
+    pub buy_signature: Sr25519Signature,
+}
+
+/// Returns the message the maker signs to agree to the fill within the program.
+pub fn fill_message(program: ActorId, maker: ActorId, fill: &Fill, nonce: u64) -> Vec<u8> {
+    (FILL_TAG, program, maker, fill, nonce).encode()
+}
+
+/// Fee charged on fills.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeConfig {
+    /// Fee in basis points of the native value of the fill.
+    pub fee_bps: u16,
+    /// Account fees are sent to.
+    pub treasury: ActorId,
+}
+
+impl FeeConfig {
+    /// Returns `true` if the fee is bounded and has a recipient, if charged.
+    pub fn is_valid(&self) -> bool {
+        self.fee_bps <= MAX_BPS && (self.fee_bps == 0 || !self.treasury.is_zero())
+    }
+
+    /// Returns fee charged on the native value, rounded down.
+    pub fn fee_of(&self, value: u128) -> u128 {
+        (U256::from(value) * self.fee_bps / MAX_BPS).low_u128()
+    }
+}
+
+/// Native value of a trade along with its fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trade {
+    /// Native value traded for the tokens.
+    pub value: u128,
+    /// Part of the value charged as the fee.
+    pub fee: u128,
+}
+
+/// State of [`OrderBook`].
+///
+/// Orders are kept in the sharded map, which shards must be allocated
+/// (see [`OrderBookStorage::allocate_next_shard`]) before orders are placed.
+pub struct OrderBookStorage {
+    next_id: u64,
+    orders: ShardedMap<u64, Order>,
+    by_maker: BTreeMap<ActorId, BTreeSet<u64>>,
+    nonces: BTreeMap<ActorId, u64>,
+    fee_config: FeeConfig,
+}
+
+impl OrderBookStorage {
+    /// Default, recommended max shard capacity.
+    pub const DEFAULT_MAX_SHARD: usize = 0b111 << 14;
+
+    /// Tries to create a new [`Self`] instance with the given capacities.
+    pub fn try_new(capacities: Vec<usize>) -> Result<Self, ShardedMapError> {
+        Ok(Self {
+            next_id: 0,
+            orders: ShardedMap::try_new(capacities)?,
+            by_maker: Default::default(),
+            nonces: Default::default(),
+            fee_config: Default::default(),
+        })
+    }
+
+    /// Allocates next shard of underlying sharded map.
+    ///
+    /// Returns bool indicating if there're unallocated shards left.
+    pub fn allocate_next_shard(&mut self) -> bool {
+        self.orders.alloc_next_shard()
+    }
+
+    pub fn fee_config(&self) -> FeeConfig {
+        self.fee_config
+    }
+
+    pub fn set_fee_config(&mut self, fee_config: FeeConfig) {
+        self.fee_config = fee_config;
+    }
+
+    /// Returns the nonce the next fill signed by the maker must use.
+    pub fn nonce(&self, maker: &ActorId) -> u64 {
+        self.nonces.get(maker).copied().unwrap_or_default()
+    }
+
+    /// Consumes the nonce of the maker, returning it.
+    pub fn use_nonce(&mut self, maker: ActorId) -> u64 {
+        let nonce = self.nonces.entry(maker).or_default();
+
+        *nonce += 1;
+
+        *nonce - 1
+    }
+
+    pub fn order(&self, id: u64) -> Option<&Order> {
+        self.orders.get(&id).map(|(_, order)| order)
+    }
+
+    /// Iterates over orders in no particular order.
+    pub fn orders(&self) -> impl Iterator<Item = (u64, &Order)> {
+        self.orders.iter().map(|(&id, order)| (id, order))
+    }
+
+    /// Iterates over ids of orders of the maker, in ascending order.
+    pub fn orders_of(&self, maker: ActorId) -> impl Iterator<Item = u64> + '_ {
+        self.by_maker.get(&maker).into_iter().flatten().copied()
+    }
+
+    /// Places the order, returning its id.
+    pub fn place(&mut self, order: Order) -> Result<u64, ShardedMapError> {
+        let id = self.next_id;
+
+        self.orders.try_insert(id, order)?;
+        self.by_maker.entry(order.maker).or_default().insert(id);
+        self.next_id += 1;
+
+        Ok(id)
+    }
+
+    /// Fills `amount` tokens of the order at the given block, returning the order
+    /// after the fill, removed from the book if filled fully.
+    ///
+    /// Native value of the fill is rounded in favour of the maker:
+    /// up for sell orders and down for buy orders.
+    pub fn fill(&mut self, id: u64, amount: U256, bn: u32) -> Result<(Order, Trade), Error> {
+        let mut order = self.live_order(id, bn)?;
+
+        let value = order
+            .value_of(amount, order.side == Side::Sell)
+            .ok_or(OverflowError)?;
+
+        order.take(amount, value)?;
+
+        self.update(id, order);
+
+        Ok((order, self.trade(value)))
+    }
+
+    /// Settles the matched fill at the given block, returning the sell and buy
+    /// orders after it, removed from the book if filled fully.
+    ///
+    /// Native value of the fill is rounded down.
+    pub fn settle(&mut self, fill: &Fill, bn: u32) -> Result<(Order, Order, Trade), Error> {
+        let mut sell = self.live_order(fill.sell_id, bn)?;
+        let mut buy = self.live_order(fill.buy_id, bn)?;
+
+        ensure!(sell.side == Side::Sell && buy.side == Side::Buy, BadInput);
+        ensure!(
+            sell.price <= fill.price && fill.price <= buy.price,
+            OrdersNotCrossed {
+                sell_id: fill.sell_id,
+                buy_id: fill.buy_id,
+            }
+        );
+
+        let value = value_of(fill.price, fill.amount, false).ok_or(OverflowError)?;
+
+        sell.take(fill.amount, value)?;
+        buy.take(fill.amount, value)?;
+
+        self.update(fill.sell_id, sell);
+        self.update(fill.buy_id, buy);
+
+        Ok((sell, buy, self.trade(value)))
+    }
+
+    /// Removes the order on behalf of the caller: makers cancel their orders
+    /// at any time, while anyone cancels expired ones.
+    pub fn cancel(&mut self, id: u64, caller: ActorId, bn: u32) -> Result<Order, Error> {
+        let order = self.order(id).copied().ok_or(OrderNotFound(id))?;
+
+        ensure!(order.maker == caller || order.is_expired(bn), BadOrigin);
+
+        self.remove(id);
+
+        Ok(order)
+    }
+
+    /// Returns the order if it's fillable at the given block.
+    fn live_order(&self, id: u64, bn: u32) -> Result<Order, Error> {
+        let order = self.order(id).copied().ok_or(OrderNotFound(id))?;
+
+        ensure!(!order.is_expired(bn), OrderExpired(id));
+
+        Ok(order)
+    }
+
+    /// Stores the order, removing it once filled fully.
+    fn update(&mut self, id: u64, order: Order) {
+        if order.remaining.is_zero() {
+            self.remove(id);
+        } else if let Some((_, stored)) = self.orders.get_mut(&id) {
+            *stored = order;
+        }
+    }
+
+    fn trade(&self, value: u128) -> Trade {
+        Trade {
+            value,
+            fee: self.fee_config.fee_of(value),
+        }
+    }
+
+    fn remove(&mut self, id: u64) {
+        let Some((_, order)) = self.orders.remove(&id) else {
+            return;
+        };
+
+        if let Some(ids) = self.by_maker.get_mut(&order.maker) {
+            ids.remove(&id);
+
+            if ids.is_empty() {
+                self.by_maker.remove(&order.maker);
+            }
+        }
+    }
+}
+
+impl Default for OrderBookStorage {
+    fn default() -> Self {
+        unwrap_infallible!(
+            Self::try_new(vec![Self::DEFAULT_MAX_SHARD; 2]).map_err(|_| unreachable!())
+        )
+    }
+}
+
+/// Awesome Order-Book service itself.
+pub struct OrderBook<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = OrderBookStorage> = StorageRefCell<'a, OrderBookStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = OrderBookStorage>,
+> OrderBook<'a, ACS, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: S,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+            vft,
+        }
+    }
+
+    /// Sends native value to the account, if any.
+    fn send_value(&self, to: ActorId, value: u128) -> Result<(), Error> {
+        ok_if!(value == 0, ());
+
+        gstd::msg::send_bytes(to, [], value).map_err(|_| SendError::Value)?;
+
+        Ok(())
+    }
+
+    /// Consumes the nonce of the maker if it signed the fill with it.
+    fn verify(
+        &mut self,
+        maker: ActorId,
+        fill: &Fill,
+        signature: &Sr25519Signature,
+    ) -> Result<(), Error> {
+        let mut storage = self.storage.get_mut();
+
+        let message = fill_message(Syscall::program_id(), maker, fill, storage.nonce(&maker));
+
+        sr25519::verify(&maker.into_bytes(), &message, signature)?;
+
+        storage.use_nonce(maker);
+
+        Ok(())
+    }
+
+    /// Returns the rest of the order escrow to its maker.
+    fn release(&mut self, order: &Order) -> Result<(), Error> {
+        match order.side {
+            Side::Buy => self.send_value(order.maker, order.escrow),
+            Side::Sell if order.remaining.is_zero() => Ok(()),
+            Side::Sell => {
+                self.vft
+                    .transfer_escrowed(Syscall::program_id(), order.maker, order.remaining)
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = OrderBookStorage>,
+> OrderBook<'a, ACS, A, B, S>
+{
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn allocate_next_shard(&mut self) -> Result<bool, Error> {
+        Ok(self.storage.get_mut().allocate_next_shard())
+    }
+
+    /// Cancels the order, returning the rest of its escrow to the maker.
+    ///
+    /// Makers cancel their orders at any time, while expired orders
+    /// may be cancelled by anyone.
+    #[export(unwrap_result)]
+    pub fn cancel(&mut self, id: u64) -> Result<(), Error> {
+        let caller = Syscall::message_source();
+
+        let order = self
+            .storage
+            .get_mut()
+            .cancel(id, caller, Syscall::block_height())?;
+
+        self.inner.release(&order)?;
+
+        self.emit_event(Event::Cancelled { id, by: caller })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Fills `amount` tokens of the order by the message source.
+    ///
+    /// Takers of sell orders pay the native value by the message value, with the
+    /// rest attached to the reply. Takers of buy orders deliver the tokens and get
+    /// the native value attached to the reply. Returns the native value of the fill,
+    /// the fee included.
+    #[export(unwrap_result)]
+    pub fn fill(&mut self, id: u64, amount: U256) -> Result<CommandReply<u128>, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let attached = Syscall::message_value();
+        let taker = Syscall::message_source();
+
+        let (order, Trade { value, fee }) =
+            self.storage
+                .get_mut()
+                .fill(id, amount, Syscall::block_height())?;
+
+        let proceeds = value - fee;
+
+        let refund = match order.side {
+            Side::Buy => {
+                self.inner
+                    .vft
+                    .transfer_escrowed(taker, order.maker, amount)?;
+
+                attached.checked_add(proceeds).ok_or(OverflowError)?
+            }
+            Side::Sell => {
+                ensure!(attached >= value, BadValue);
+
+                self.inner
+                    .vft
+                    .transfer_escrowed(Syscall::program_id(), taker, amount)?;
+                self.inner.send_value(order.maker, proceeds)?;
+
+                attached - value
+            }
+        };
+
+        let treasury = self.storage.get().fee_config().treasury;
+
+        self.inner.send_value(treasury, fee)?;
+
+        if order.remaining.is_zero() {
+            self.inner.release(&order)?;
+        }
+
+        self.emit_event(Event::Filled {
+            id,
+            taker,
+            amount,
+            value,
+            fee,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(value).with_value(refund))
+    }
+
+    /// Places the order of the message source for `amount` tokens at the price,
+    /// expiring in `ttl_blocks` blocks. Returns its id.
+    ///
+    /// Sell orders escrow the tokens. Buy orders escrow their native value by the
+    /// message value, with the rest attached to the reply.
+    #[export(unwrap_result)]
+    pub fn place(
+        &mut self,
+        side: Side,
+        amount: U256,
+        price: U256,
+        ttl_blocks: u32,
+    ) -> Result<CommandReply<u64>, Error> {
+        ensure!(
+            !amount.is_zero() && !price.is_zero() && ttl_blocks != 0,
+            BadInput
+        );
+
+        let attached = Syscall::message_value();
+        let maker = Syscall::message_source();
+
+        let mut order = Order {
+            maker,
+            side,
+            price,
+            remaining: amount,
+            escrow: 0,
+            expires_at: Syscall::block_height().saturating_add(ttl_blocks),
+        };
+
+        let value = order.value_of(amount, true).ok_or(OverflowError)?;
+
+        let refund = match side {
+            Side::Buy => {
+                ensure!(value != 0 && attached >= value, BadValue);
+
+                order.escrow = value;
+
+                attached - value
+            }
+            Side::Sell => {
+                self.inner
+                    .vft
+                    .transfer_escrowed(maker, Syscall::program_id(), amount)?;
+
+                attached
+            }
+        };
+
+        let id = self.storage.get_mut().place(order)?;
+
+        self.emit_event(Event::Placed { id, order })
+            .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(id).with_value(refund))
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_fee_config(&mut self, fee_config: FeeConfig) -> Result<(), Error> {
+        ensure!(fee_config.is_valid(), BadInput);
+
+        self.storage.get_mut().set_fee_config(fee_config);
+
+        self.emit_event(Event::FeeConfigChanged(fee_config))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Settles the fills matched off-chain, returning their amount.
+    ///
+    /// Each fill trades tokens escrowed by the sell order for native value escrowed
+    /// by the buy order at the price of the fill, with the fee taken from the value
+    /// paid to the seller. The batch fails as a whole if any of the fills does.
+    ///
+    /// Requirements:
+    ///
+    /// - there must be at most `MAX_BATCH_LEN` fills;
+    /// - makers of the sell and buy orders must sign each fill, see `fill_message`,
+    ///   with their next nonces (the sell maker's first if they're the same).
+    #[export(unwrap_result)]
+    #[require_role(SETTLER_ROLE)]
+    pub fn settle_batch(&mut self, fills: Vec<SignedFill>) -> Result<u32, Error> {
+        ensure!(!fills.is_empty() && fills.len() <= MAX_BATCH_LEN, BadInput);
+
+        let bn = Syscall::block_height();
+        let len = fills.len() as u32;
+
+        let mut fees = 0u128;
+
+        for SignedFill {
+            fill,
+            sell_signature,
+            buy_signature,
+        } in fills
+        {
+            let (sell, buy, Trade { value, fee }) = self.storage.get_mut().settle(&fill, bn)?;
+
+            self.inner.verify(sell.maker, &fill, &sell_signature)?;
+            self.inner.verify(buy.maker, &fill, &buy_signature)?;
+
+            self.inner
+                .vft
+                .transfer_escrowed(Syscall::program_id(), buy.maker, fill.amount)?;
+            self.inner.send_value(sell.maker, value - fee)?;
+
+            if buy.remaining.is_zero() {
+                self.inner.release(&buy)?;
+            }
+
+            fees = fees.checked_add(fee).ok_or(OverflowError)?;
+
+            self.emit_event(Event::Settled { fill, value, fee })
+                .map_err(|_| EmitError)?;
+        }
+
+        let treasury = self.storage.get().fee_config().treasury;
+
+        self.inner.send_value(treasury, fees)?;
+
+        Ok(len)
+    }
+
+    #[export]
+    pub fn fee_config(&self) -> FeeConfig {
+        self.storage.get().fee_config()
+    }
+
+    /// Returns the message to be signed by the maker to agree to the fill.
+    #[export]
+    pub fn fill_message(&self, maker: ActorId, fill: Fill) -> Vec<u8> {
+        fill_message(
+            Syscall::program_id(),
+            maker,
+            &fill,
+            self.storage.get().nonce(&maker),
+        )
+    }
+
+    #[export]
+    pub fn nonce(&self, maker: ActorId) -> u64 {
+        self.storage.get().nonce(&maker)
+    }
+
+    #[export]
+    pub fn order(&self, id: u64) -> Option<Order> {
+        self.storage.get().order(id).copied()
+    }
+
+    /// Returns up to `len` orders starting from `cursor`, in no particular order.
+    #[export]
+    pub fn orders(&self, cursor: u32, len: u32) -> Vec<(u64, Order)> {
+        self.storage
+            .get()
+            .orders()
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(|(id, &order)| (id, order))
+            .collect()
+    }
+
+    /// Returns up to `len` orders of the maker starting from `cursor`,
+    /// in ascending order of ids.
+    #[export]
+    pub fn orders_of(&self, maker: ActorId, cursor: u32, len: u32) -> Vec<(u64, Order)> {
+        let storage = self.storage.get();
+
+        storage
+            .orders_of(maker)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .filter_map(|id| storage.order(id).map(|&order| (id, order)))
+            .collect()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Placed {
+        id: u64,
+        order: Order,
+    },
+    Filled {
+        id: u64,
+        taker: ActorId,
+        amount: U256,
+        value: u128,
+        fee: u128,
+    },
+    Cancelled {
+        id: u64,
+        by: ActorId,
+    },
+    Settled {
+        fill: Fill,
+        value: u128,
+        fee: u128,
+    },
+    FeeConfigChanged(FeeConfig),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::{
+        error::{BadInput, BadOrigin, BadValue, EmitError, Error, SendError},
+        signature::InvalidSignature,
+    };
+    use sails_rs::{
+        U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Order {0} not found")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct OrderNotFound(pub u64);
+
+    awesome_sails_utils::impl_error_code!(OrderNotFound => 2000);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Order {0} is expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct OrderExpired(pub u64);
+
+    awesome_sails_utils::impl_error_code!(OrderExpired => 2001);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Fill exceeds order: at most {remaining} may be filled")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct FillExceedsOrder {
+        pub remaining: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(FillExceedsOrder => 2002);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Orders {sell_id} and {buy_id} don't cross")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct OrdersNotCrossed {
+        pub sell_id: u64,
+        pub buy_id: u64,
+    }
+
+    awesome_sails_utils::impl_error_code!(OrdersNotCrossed => 2003);
+}
@@ -0,0 +1,205 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Proxy service.
+//!
+//! This service forwards calls to the implementation program, so the program id
+//! integrations know stays the same while the implementation is upgraded.
+//! The implementation receives the call from the program as [`Forwarded`], carrying
+//! the original caller along with the payload, with the value of the call, and its
+//! reply is returned to the caller. Implementations must accept [`Forwarded`] calls
+//! from the proxy only, and handle them on behalf of the caller, as the proxy is
+//! the source of every forwarded message.
+//!
+//! The implementation is set only by messages of the program itself, i.e. by tasks
+//! scheduled by super admins via `Scheduler::schedule_self` acting as the timelock,
+//! so upgrades are visible and cancellable before they apply.
+
+#![no_std]
+
+use crate::error::{BadInput, BadOrigin, EmitError, Error, ForwardFailed, NoImplementation};
+use awesome_sails_utils::{
+    ensure,
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{gstd, prelude::*};
+
+/// Call forwarded to the implementation on behalf of the caller of the proxy.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Forwarded {
+    /// Source of the message to the proxy.
+    pub caller: ActorId,
+    pub payload: Vec<u8>,
+}
+
+/// Implementation the calls are forwarded to.
+#[derive(Default, Debug)]
+pub struct ProxyStorage {
+    implementation: ActorId,
+    upgraded_at: u32,
+}
+
+impl ProxyStorage {
+    /// Creates the storage forwarding to the implementation.
+    pub fn new(implementation: ActorId) -> Self {
+        Self {
+            implementation,
+            upgraded_at: 0,
+        }
+    }
+
+    /// Returns the implementation, if set.
+    pub fn implementation(&self) -> Option<ActorId> {
+        (!self.implementation.is_zero()).then_some(self.implementation)
+    }
+
+    /// Block the implementation was last set at.
+    pub fn upgraded_at(&self) -> u32 {
+        self.upgraded_at
+    }
+
+    /// Replaces the implementation, returning the previous one.
+    pub fn set_implementation(&mut self, implementation: ActorId, bn: u32) -> ActorId {
+        self.upgraded_at = bn;
+
+        core::mem::replace(&mut self.implementation, implementation)
+    }
+}
+
+/// Awesome Proxy service itself.
+pub struct Proxy<
+    'a,
+    S: InfallibleStorageMut<Item = ProxyStorage> = StorageRefCell<'a, ProxyStorage>,
+> {
+    storage: S,
+    _marker: core::marker::PhantomData<&'a ()>,
+}
+
+impl<S: InfallibleStorageMut<Item = ProxyStorage>> Proxy<'_, S> {
+    /// Constructor for [`Self`].
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<S: InfallibleStorageMut<Item = ProxyStorage>> Proxy<'_, S> {
+    /// Forwards the payload with the message value to the implementation
+    /// as [`Forwarded`] by the message source, returning its reply.
+    #[export(unwrap_result)]
+    pub async fn forward(&mut self, payload: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let implementation = self
+            .storage
+            .get()
+            .implementation()
+            .ok_or(NoImplementation)?;
+
+        let call = Forwarded {
+            caller: Syscall::message_source(),
+            payload,
+        };
+
+        let reply = gstd::msg::send_bytes_for_reply(
+            implementation,
+            call.encode(),
+            Syscall::message_value(),
+            0,
+        )
+        .map_err(|_| ForwardFailed)?
+        .await
+        .map_err(|_| ForwardFailed)?;
+
+        Ok(reply)
+    }
+
+    /// Sets the implementation calls are forwarded to.
+    ///
+    /// Called only by the program itself, e.g. by a scheduled task.
+    #[export(unwrap_result)]
+    pub fn set_implementation(&mut self, implementation: ActorId) -> Result<(), Error> {
+        ensure!(
+            Syscall::message_source() == Syscall::program_id(),
+            BadOrigin
+        );
+        ensure!(!implementation.is_zero(), BadInput);
+
+        let previous = self
+            .storage
+            .get_mut()
+            .set_implementation(implementation, Syscall::block_height());
+
+        self.emit_event(Event::Upgraded {
+            previous,
+            implementation,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn implementation(&self) -> Option<ActorId> {
+        self.storage.get().implementation()
+    }
+
+    #[export]
+    pub fn upgraded_at(&self) -> u32 {
+        self.storage.get().upgraded_at()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Upgraded {
+        previous: ActorId,
+        implementation: ActorId,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadOrigin, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Implementation is not set")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NoImplementation;
+
+    awesome_sails_utils::impl_error_code!(NoImplementation => 1500);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Forwarded call failed")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct ForwardFailed;
+
+    awesome_sails_utils::impl_error_code!(ForwardFailed => 1501);
+}
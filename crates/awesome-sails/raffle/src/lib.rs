@@ -0,0 +1,668 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Raffle service.
+//!
+//! This service runs rounds of a raffle: users buy tickets with VFTs until the end
+//! block of the round, and the pot, minus the fee, is transferred to the holder
+//! of the winning ticket.
+//!
+//! Randomness follows the [commit-reveal](awesome_sails_utils::commit_reveal) scheme:
+//! the operator opening the round commits to the keccak-256 hash of a secret seed,
+//! which is revealed by `draw` once the round ends. The seed is mixed with entropy
+//! accumulated from block data of every purchase and with the randomness of the chain
+//! determinable only after the end of the round, mixed in by anyone calling `close`.
+//! So neither the operator, who may buy the closing tickets, nor buyers alone choose
+//! the winner.
+//!
+//! Rounds not drawn within [`REVEAL_BLOCKS`] after their end expire, and their
+//! tickets are refunded to holders along with the bond the operator escrowed on
+//! opening, slashing the operator withholding the seed. Tickets are kept by round,
+//! so refunds of expired rounds are claimed after newer rounds open.
+
+#![no_std]
+
+use crate::error::{
+    BadInput, EmitError, Error, RandomnessNotReady, RoundActive, RoundNotClosed, RoundNotExpired,
+    RoundNotOpen,
+};
+use awesome_sails_access_control::{self as access_control, RoleId, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    commit_reveal::Commitment,
+    ensure,
+    math::OverflowError,
+    ok_if,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+pub const OPERATOR_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"OPERATOR_ROLE")
+    .finalize();
+
+/// Blocks after the end of the round its seed must be revealed within.
+pub const REVEAL_BLOCKS: u32 = 100;
+
+/// Basis points of the pot, used for fees.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Parameters of the round.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RoundParams {
+    /// Price of a ticket in VFTs.
+    pub ticket_price: U256,
+    /// Share of the pot taken as the fee, in basis points.
+    pub fee_bps: u16,
+    pub fee_recipient: ActorId,
+    /// Block the round ends at: no tickets are sold from it on.
+    pub end_block: u32,
+    /// VFTs escrowed by the operator, returned on `draw` or slashed
+    /// to ticket holders if the round expires.
+    pub bond: U256,
+}
+
+/// Status of the round.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RoundStatus {
+    /// Tickets are sold until the end block, then the round is closed.
+    Open,
+    /// Randomness of the chain is mixed into the entropy, the seed is awaited.
+    Closed,
+    /// Winner is drawn, if any tickets were sold.
+    Drawn {
+        winner: Option<ActorId>,
+        prize: U256,
+        fee: U256,
+    },
+}
+
+/// Round of the raffle.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Round {
+    pub id: u32,
+    /// Operator who opened the round, escrowing the bond.
+    pub operator: ActorId,
+    pub params: RoundParams,
+    /// Commitment to the seed revealed by `draw` within [`REVEAL_BLOCKS`] after the end.
+    pub commitment: Commitment,
+    /// Amount of sold tickets.
+    pub tickets: u32,
+    /// Entropy accumulated from block data of purchases.
+    pub entropy: H256,
+    pub status: RoundStatus,
+}
+
+impl Round {
+    /// VFTs paid for the sold tickets.
+    pub fn pot(&self) -> U256 {
+        self.params.ticket_price * U256::from(self.tickets)
+    }
+
+    /// Returns `true` if the seed wasn't revealed in time, so tickets are refunded.
+    pub fn is_expired(&self, bn: u32) -> bool {
+        !matches!(self.status, RoundStatus::Drawn { .. }) && self.commitment.is_expired(bn)
+    }
+
+    /// VFTs refunded for the tickets of the expired round, with their share of the bond.
+    pub fn refund_of(&self, tickets: u32) -> U256 {
+        if self.tickets == 0 {
+            return U256::zero();
+        }
+
+        let share = self.params.bond * U256::from(tickets) / U256::from(self.tickets);
+
+        self.params.ticket_price * U256::from(tickets) + share
+    }
+}
+
+/// State of [`Raffle`].
+#[derive(Default, Debug)]
+pub struct RaffleStorage {
+    round: Option<Round>,
+    /// Ticket ranges of holders in the current round by the end of the range, exclusive.
+    ranges: Vec<(u32, ActorId)>,
+    /// Tickets of holders by round, kept for expired rounds until refunded.
+    tickets: BTreeMap<u32, BTreeMap<ActorId, u32>>,
+    /// Expired rounds replaced by newer ones, kept until their tickets are refunded.
+    expired: BTreeMap<u32, Round>,
+}
+
+impl RaffleStorage {
+    pub fn round(&self) -> Option<&Round> {
+        self.round.as_ref()
+    }
+
+    /// Amount of tickets the account holds in the round.
+    pub fn tickets_of(&self, round: u32, account: ActorId) -> u32 {
+        self.tickets
+            .get(&round)
+            .and_then(|tickets| tickets.get(&account))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the holder of the ticket in the current round.
+    pub fn holder_of(&self, ticket: u32) -> Option<ActorId> {
+        let i = self.ranges.partition_point(|&(end, _)| end <= ticket);
+
+        self.ranges.get(i).map(|&(_, holder)| holder)
+    }
+
+    /// Opens the round by the operator, failing if the current one
+    /// is neither drawn nor expired.
+    ///
+    /// Tickets of the expired round are kept to be refunded.
+    pub fn open(
+        &mut self,
+        operator: ActorId,
+        params: RoundParams,
+        commitment: H256,
+        bn: u32,
+    ) -> Result<u32, RoundActive> {
+        let id = match self.round {
+            None => 0,
+            Some(round) => {
+                let expired = round.is_expired(bn);
+
+                ensure!(
+                    expired || matches!(round.status, RoundStatus::Drawn { .. }),
+                    RoundActive
+                );
+
+                if expired && self.tickets.contains_key(&round.id) {
+                    self.expired.insert(round.id, round);
+                } else {
+                    self.tickets.remove(&round.id);
+                }
+
+                round.id + 1
+            }
+        };
+
+        self.round = Some(Round {
+            id,
+            operator,
+            params,
+            commitment: Commitment::new(
+                commitment,
+                params.end_block,
+                params.end_block.saturating_add(REVEAL_BLOCKS),
+            ),
+            tickets: 0,
+            entropy: commitment,
+            status: RoundStatus::Open,
+        });
+        self.ranges.clear();
+
+        Ok(id)
+    }
+
+    /// Sells `count` tickets to the buyer, mixing block data into the entropy.
+    ///
+    /// Returns the round and the price of the tickets.
+    pub fn buy(
+        &mut self,
+        buyer: ActorId,
+        count: u32,
+        bn: u32,
+        timestamp: u64,
+    ) -> Result<(u32, U256), Error> {
+        let round = self
+            .round
+            .as_mut()
+            .filter(|round| round.status == RoundStatus::Open && bn < round.params.end_block)
+            .ok_or(RoundNotOpen)?;
+
+        round.tickets = round.tickets.checked_add(count).ok_or(OverflowError)?;
+        round.entropy = keccak_const::Keccak256::new()
+            .update(round.entropy.as_bytes())
+            .update(buyer.as_ref())
+            .update(&count.to_le_bytes())
+            .update(&bn.to_le_bytes())
+            .update(&timestamp.to_le_bytes())
+            .finalize()
+            .into();
+
+        self.ranges.push((round.tickets, buyer));
+        *self
+            .tickets
+            .entry(round.id)
+            .or_default()
+            .entry(buyer)
+            .or_default() += count;
+
+        let price = round
+            .params
+            .ticket_price
+            .checked_mul(count.into())
+            .ok_or(OverflowError)?;
+
+        Ok((round.id, price))
+    }
+
+    /// Closes the ended round, mixing the randomness of the chain into the entropy.
+    ///
+    /// Randomness must be determinable only from the end block on, so it's unknown
+    /// while tickets are sold.
+    pub fn close(
+        &mut self,
+        randomness: [u8; 32],
+        determinable_at: u32,
+        bn: u32,
+    ) -> Result<Round, Error> {
+        let round = self
+            .round
+            .as_mut()
+            .filter(|round| round.status == RoundStatus::Open && !round.is_expired(bn))
+            .ok_or(RoundNotOpen)?;
+
+        ensure!(
+            bn >= round.params.end_block && determinable_at >= round.params.end_block,
+            RandomnessNotReady
+        );
+
+        round.entropy = keccak_const::Keccak256::new()
+            .update(round.entropy.as_bytes())
+            .update(&randomness)
+            .finalize()
+            .into();
+        round.status = RoundStatus::Closed;
+
+        Ok(*round)
+    }
+
+    /// Draws the winner of the closed round by the revealed seed.
+    pub fn draw(&mut self, seed: &[u8], bn: u32) -> Result<Round, Error> {
+        let round = self
+            .round
+            .filter(|round| round.status == RoundStatus::Closed && !round.is_expired(bn))
+            .ok_or(RoundNotClosed)?;
+
+        round.commitment.verify(seed, bn)?;
+
+        let winner = (round.tickets != 0)
+            .then(|| {
+                let randomness = keccak_const::Keccak256::new()
+                    .update(seed)
+                    .update(round.entropy.as_bytes())
+                    .finalize();
+
+                let ticket = U256::from_big_endian(&randomness) % U256::from(round.tickets);
+
+                self.holder_of(ticket.as_u32())
+            })
+            .flatten();
+
+        let pot = round.pot();
+        let fee = pot * U256::from(round.params.fee_bps) / U256::from(MAX_BPS);
+
+        let round = Round {
+            status: RoundStatus::Drawn {
+                winner,
+                prize: pot - fee,
+                fee,
+            },
+            ..round
+        };
+
+        self.round = Some(round);
+
+        Ok(round)
+    }
+
+    /// Removes tickets of the account in the expired round,
+    /// returning their price with the share of the bond.
+    pub fn refund(&mut self, round: u32, account: ActorId, bn: u32) -> Result<U256, Error> {
+        let expired = match self.round.filter(|current| current.id == round) {
+            Some(current) => Some(current).filter(|current| current.is_expired(bn)),
+            None => self.expired.get(&round).copied(),
+        }
+        .ok_or(RoundNotExpired)?;
+
+        let Some(holders) = self.tickets.get_mut(&round) else {
+            return Ok(U256::zero());
+        };
+
+        let tickets = holders.remove(&account).unwrap_or_default();
+
+        if holders.is_empty() {
+            self.tickets.remove(&round);
+            self.expired.remove(&round);
+        }
+
+        Ok(expired.refund_of(tickets))
+    }
+}
+
+/// Awesome Raffle service itself.
+pub struct Raffle<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = RaffleStorage> = StorageRefCell<'a, RaffleStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = RaffleStorage>,
+> Raffle<'a, ACS, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: S,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+            vft,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = RaffleStorage>,
+> Raffle<'a, ACS, A, B, S>
+{
+    /// Buys `count` tickets of the open round for VFTs of the message source.
+    ///
+    /// Returns the amount of tickets the message source holds.
+    #[export(unwrap_result)]
+    pub fn buy_tickets(&mut self, count: u32) -> Result<u32, Error> {
+        ensure!(count != 0, BadInput);
+
+        let buyer = Syscall::message_source();
+
+        let (round, price) = self.storage.get_mut().buy(
+            buyer,
+            count,
+            Syscall::block_height(),
+            Syscall::block_timestamp(),
+        )?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(buyer, Syscall::program_id(), price)?;
+
+        self.emit_event(Event::TicketsBought {
+            round,
+            buyer,
+            count,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(self.storage.get().tickets_of(round, buyer))
+    }
+
+    /// Closes the ended round, mixing the randomness of the chain into its entropy,
+    /// so the seed can be revealed by `draw`.
+    ///
+    /// Called by anyone, e.g. ticket holders, right at the end of the round.
+    #[export(unwrap_result)]
+    pub fn close(&mut self) -> Result<(), Error> {
+        let subject = self.storage.get().round().ok_or(RoundNotOpen)?.entropy;
+
+        let (randomness, determinable_at) =
+            gstd::exec::random(subject.into()).map_err(|_| RandomnessNotReady)?;
+
+        let round =
+            self.storage
+                .get_mut()
+                .close(randomness, determinable_at, Syscall::block_height())?;
+
+        self.emit_event(Event::Closed {
+            round: round.id,
+            entropy: round.entropy,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Draws the winner of the closed round by revealing the seed committed to,
+    /// transferring the prize to the winner, the fee to its recipient and
+    /// the bond back to the operator of the round.
+    ///
+    /// Returns the winner, if any tickets were sold.
+    #[export(unwrap_result)]
+    #[require_role(OPERATOR_ROLE)]
+    pub fn draw(&mut self, seed: Vec<u8>) -> Result<Option<ActorId>, Error> {
+        let round = self
+            .storage
+            .get_mut()
+            .draw(&seed, Syscall::block_height())?;
+
+        let RoundStatus::Drawn { winner, prize, fee } = round.status else {
+            unreachable!("round is drawn above");
+        };
+
+        let program_id = Syscall::program_id();
+
+        if let Some(winner) = winner {
+            self.inner
+                .vft
+                .transfer_escrowed(program_id, winner, prize)?;
+        }
+
+        self.inner
+            .vft
+            .transfer_escrowed(program_id, round.params.fee_recipient, fee)?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(program_id, round.operator, round.params.bond)?;
+
+        self.emit_event(Event::Drawn {
+            round: round.id,
+            seed,
+            winner,
+            prize,
+            fee,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(winner)
+    }
+
+    /// Opens the round, committing to the keccak-256 hash of the seed
+    /// revealed by `draw` once the round ends, and escrowing the bond
+    /// of the message source.
+    ///
+    /// Returns the id of the round.
+    #[export(unwrap_result)]
+    #[require_role(OPERATOR_ROLE)]
+    pub fn open(&mut self, params: RoundParams, commitment: H256) -> Result<u32, Error> {
+        let operator = Syscall::message_source();
+        let bn = Syscall::block_height();
+
+        ensure!(
+            !params.ticket_price.is_zero()
+                && params.fee_bps <= MAX_BPS
+                && !params.fee_recipient.is_zero()
+                && params.end_block > bn,
+            BadInput
+        );
+
+        let id = self
+            .storage
+            .get_mut()
+            .open(operator, params, commitment, bn)?;
+
+        self.inner
+            .vft
+            .transfer_escrowed(operator, Syscall::program_id(), params.bond)?;
+
+        self.emit_event(Event::RoundOpened {
+            round: id,
+            params,
+            commitment,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(id)
+    }
+
+    /// Refunds tickets of the message source in the round whose seed
+    /// wasn't revealed in time, along with their share of the bond.
+    ///
+    /// Returns the refunded value.
+    #[export(unwrap_result)]
+    pub fn refund(&mut self, round: u32) -> Result<U256, Error> {
+        let account = Syscall::message_source();
+
+        let value = self
+            .storage
+            .get_mut()
+            .refund(round, account, Syscall::block_height())?;
+
+        ok_if!(value.is_zero(), value);
+
+        self.inner
+            .vft
+            .transfer_escrowed(Syscall::program_id(), account, value)?;
+
+        self.emit_event(Event::Refunded {
+            round,
+            account,
+            value,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(value)
+    }
+
+    #[export]
+    pub fn round(&self) -> Option<Round> {
+        self.storage.get().round().copied()
+    }
+
+    /// Returns the amount of tickets the account holds in the round.
+    #[export]
+    pub fn tickets_of(&self, round: u32, account: ActorId) -> u32 {
+        self.storage.get().tickets_of(round, account)
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    RoundOpened {
+        round: u32,
+        params: RoundParams,
+        commitment: H256,
+    },
+    TicketsBought {
+        round: u32,
+        buyer: ActorId,
+        count: u32,
+    },
+    Closed {
+        round: u32,
+        entropy: H256,
+    },
+    Drawn {
+        round: u32,
+        seed: Vec<u8>,
+        winner: Option<ActorId>,
+        prize: U256,
+        fee: U256,
+    },
+    Refunded {
+        round: u32,
+        account: ActorId,
+        value: U256,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Round is active")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoundActive;
+
+    awesome_sails_utils::impl_error_code!(RoundActive => 1900);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Round is not open")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoundNotOpen;
+
+    awesome_sails_utils::impl_error_code!(RoundNotOpen => 1901);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Round is not expired")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoundNotExpired;
+
+    awesome_sails_utils::impl_error_code!(RoundNotExpired => 1902);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Round is not closed")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RoundNotClosed;
+
+    awesome_sails_utils::impl_error_code!(RoundNotClosed => 1903);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Randomness is not ready")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct RandomnessNotReady;
+
+    awesome_sails_utils::impl_error_code!(RandomnessNotReady => 1904);
+}
@@ -0,0 +1,492 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Scheduler service.
+//!
+//! This service keeps tasks to be executed at a given block: a message with `payload`
+//! and `value` sent by the program to `target`.
+//!
+//! Due tasks are executed by anyone calling `execute_due`, who is paid the bounties
+//! attached to the executed tasks along with the program-wide keeper [`Bounty`].
+//!
+//! Accounts with [`SCHEDULER_ROLE`] schedule tasks through the service, while other
+//! services of the program enqueue their own delayed actions directly into the shared
+//! [`Schedule`].
+//!
+//! Tasks are sent with the program as the source, so [`SCHEDULER_ROLE`] effectively acts
+//! as the program towards other contracts, e.g. spending tokens it holds or using roles
+//! granted to it there. It may not target the program itself though, as such tasks pass
+//! checks of calls allowed to the program only: these are scheduled by super admins
+//! at least the minimal delay ahead, so the scheduler acts as the timelock of the program.
+//!
+//! Tasks which can't be sent on execution are marked failed instead of blocking
+//! the queue, and are cancelled by their owners to be refunded.
+
+#![no_std]
+
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage,
+};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    error::{BadInput, BadOrigin, BadValue, EmitError, Error},
+    keeper::{Bounty, RewardAsset},
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    gstd,
+    prelude::*,
+};
+
+pub const SCHEDULER_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"SCHEDULER_ROLE")
+    .finalize();
+
+/// Maximal amount of tasks executed within a single `execute_due` call.
+pub const MAX_EXECUTIONS_PER_CALL: u32 = 32;
+
+/// Default minimal delay of tasks to the program itself: a day of 3-second blocks.
+pub const DEFAULT_MIN_SELF_DELAY: u32 = 28_800;
+
+/// Message to be sent by the program at the given block.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Task {
+    /// Account refunded on cancellation.
+    pub owner: ActorId,
+    pub execute_at: u32,
+    pub target: ActorId,
+    pub payload: Vec<u8>,
+    pub value: u128,
+    /// Value paid to the account executing the task.
+    pub bounty: u128,
+}
+
+/// Scheduled tasks, ordered by their execution block.
+#[derive(Debug)]
+pub struct Schedule {
+    next_id: u64,
+    tasks: BTreeMap<u64, Task>,
+    queue: BTreeSet<(u32, u64)>,
+    failed: BTreeSet<u64>,
+    keeper_bounty: Bounty,
+    min_self_delay: u32,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_SELF_DELAY)
+    }
+}
+
+impl Schedule {
+    /// Creates the schedule with the minimal delay of tasks to the program itself.
+    pub fn new(min_self_delay: u32) -> Self {
+        Self {
+            next_id: 0,
+            tasks: Default::default(),
+            queue: Default::default(),
+            failed: Default::default(),
+            keeper_bounty: Default::default(),
+            min_self_delay,
+        }
+    }
+
+    /// Enqueues the task, returning its id.
+    ///
+    /// Value and bounty of the task must be held by the program on execution.
+    pub fn enqueue(&mut self, task: Task) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.queue.insert((task.execute_at, id));
+        self.tasks.insert(id, task);
+
+        id
+    }
+
+    /// Removes the task, returning it if it existed.
+    pub fn cancel(&mut self, id: u64) -> Option<Task> {
+        let task = self.tasks.remove(&id)?;
+        self.queue.remove(&(task.execute_at, id));
+        self.failed.remove(&id);
+
+        Some(task)
+    }
+
+    /// Keeps the task popped by [`Self::pop_due`] as failed, to be cancelled by its owner.
+    pub fn fail(&mut self, id: u64, task: Task) {
+        self.tasks.insert(id, task);
+        self.failed.insert(id);
+    }
+
+    pub fn task(&self, id: u64) -> Option<&Task> {
+        self.tasks.get(&id)
+    }
+
+    /// Returns `true` if the task failed to be executed.
+    pub fn is_failed(&self, id: u64) -> bool {
+        self.failed.contains(&id)
+    }
+
+    /// Minimal delay of tasks to the program itself, in blocks.
+    pub fn min_self_delay(&self) -> u32 {
+        self.min_self_delay
+    }
+
+    pub fn set_min_self_delay(&mut self, min_self_delay: u32) {
+        self.min_self_delay = min_self_delay;
+    }
+
+    /// Native bounty paid per executed task, atop of the task's own bounty.
+    pub fn keeper_bounty(&self) -> &Bounty {
+        &self.keeper_bounty
+    }
+
+    pub fn keeper_bounty_mut(&mut self) -> &mut Bounty {
+        &mut self.keeper_bounty
+    }
+
+    /// Returns ids of up to `len` tasks due at the given block, earliest first.
+    pub fn due(&self, current_bn: u32, len: usize) -> Vec<u64> {
+        self.queue
+            .range(..=(current_bn, u64::MAX))
+            .take(len)
+            .map(|&(_, id)| id)
+            .collect()
+    }
+
+    /// Removes and returns the earliest task due at the given block.
+    pub fn pop_due(&mut self, current_bn: u32) -> Option<(u64, Task)> {
+        let &(execute_at, id) = self.queue.first()?;
+
+        if execute_at > current_bn {
+            return None;
+        }
+
+        self.cancel(id).map(|task| (id, task))
+    }
+}
+
+/// Awesome Scheduler service itself.
+pub struct Scheduler<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
+    S: InfallibleStorageMut<Item = Schedule> = StorageRefCell<'a, Schedule>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: S,
+}
+
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Schedule>>
+    Scheduler<'a, ACS, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: S,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Schedule>>
+    Scheduler<'a, ACS, S>
+{
+    /// Cancels the task of the message source, refunding its value and bounty.
+    #[export(unwrap_result)]
+    pub fn cancel(&mut self, id: u64) -> Result<CommandReply<bool>, Error> {
+        let owner = Syscall::message_source();
+
+        let Some(task) = self.storage.get().task(id).cloned() else {
+            return Ok(CommandReply::new(false));
+        };
+
+        ensure!(task.owner == owner, BadOrigin);
+
+        self.storage.get_mut().cancel(id);
+
+        self.emit_event(Event::Cancelled(id))
+            .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(true).with_value(task.value + task.bounty))
+    }
+
+    /// Executes up to `limit` due tasks, paying their bounties to the message source.
+    ///
+    /// Tasks which can't be sent are marked failed and skipped, with their value
+    /// and bounty kept to be refunded on cancellation.
+    ///
+    /// Returns the number of executed tasks.
+    #[export(unwrap_result)]
+    pub fn execute_due(&mut self, limit: u32) -> Result<CommandReply<u32>, Error> {
+        let current_bn = Syscall::block_height();
+
+        let mut executed = 0;
+        let mut bounty = 0u128;
+
+        let mut processed = 0;
+
+        while processed < limit.min(MAX_EXECUTIONS_PER_CALL) {
+            let Some((id, task)) = self.storage.get_mut().pop_due(current_bn) else {
+                break;
+            };
+
+            processed += 1;
+
+            if gstd::msg::send_bytes(task.target, &task.payload, task.value).is_err() {
+                let target = task.target;
+
+                self.storage.get_mut().fail(id, task);
+
+                self.emit_event(Event::Failed { id, target })
+                    .map_err(|_| EmitError)?;
+
+                continue;
+            }
+
+            self.emit_event(Event::Executed {
+                id,
+                target: task.target,
+            })
+            .map_err(|_| EmitError)?;
+
+            executed += 1;
+            bounty = bounty.saturating_add(task.bounty);
+        }
+
+        let keeper_bounty = self
+            .storage
+            .get_mut()
+            .keeper_bounty_mut()
+            .claim(executed, current_bn);
+
+        bounty = bounty.saturating_add(keeper_bounty);
+
+        Ok(CommandReply::new(executed).with_value(bounty))
+    }
+
+    /// Funds the keeper bounty with the message value.
+    #[export(unwrap_result)]
+    pub fn fund_keeper_bounty(&mut self) -> Result<(), Error> {
+        let value = Syscall::message_value();
+
+        self.storage.get_mut().keeper_bounty_mut().fund(value)?;
+
+        self.emit_event(Event::KeeperBountyFunded(value))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Schedules a message to `target` at block `execute_at`.
+    ///
+    /// Message value must cover `value`, with the rest paid as a bounty
+    /// to the account executing the task.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `SCHEDULER_ROLE`.
+    /// - `target` must not be the program itself, see `schedule_self`.
+    #[export(unwrap_result)]
+    #[require_role(SCHEDULER_ROLE)]
+    pub fn schedule(
+        &mut self,
+        execute_at: u32,
+        target: ActorId,
+        payload: Vec<u8>,
+        value: u128,
+    ) -> Result<u64, Error> {
+        ensure!(target != Syscall::program_id(), BadInput);
+
+        let attached = Syscall::message_value();
+
+        ensure!(attached >= value, BadValue);
+
+        self.enqueue(execute_at, target, payload, value, attached - value)
+    }
+
+    /// Schedules a message to the program itself at block `execute_at`, e.g. a call
+    /// allowed to the program only, with the message value paid as a bounty
+    /// to the account executing the task.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+    /// - `execute_at` must be at least `min_self_delay` blocks ahead.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn schedule_self(&mut self, execute_at: u32, payload: Vec<u8>) -> Result<u64, Error> {
+        let min_self_delay = self.storage.get().min_self_delay();
+
+        ensure!(
+            execute_at >= Syscall::block_height().saturating_add(min_self_delay),
+            BadInput
+        );
+
+        self.enqueue(
+            execute_at,
+            Syscall::program_id(),
+            payload,
+            0,
+            Syscall::message_value(),
+        )
+    }
+
+    /// Sets the minimal delay of tasks to the program itself.
+    ///
+    /// Called only by the program itself, i.e. by a task scheduled
+    /// the current minimal delay ahead.
+    #[export(unwrap_result)]
+    pub fn set_min_self_delay(&mut self, min_self_delay: u32) -> Result<(), Error> {
+        ensure!(
+            Syscall::message_source() == Syscall::program_id(),
+            BadOrigin
+        );
+
+        self.storage.get_mut().set_min_self_delay(min_self_delay);
+
+        self.emit_event(Event::MinSelfDelaySet(min_self_delay))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Sets parameters of the keeper bounty, keeping its budget.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_keeper_bounty(
+        &mut self,
+        reward_per_task: u128,
+        max_tasks_per_call: u32,
+        max_payout_per_block: u128,
+    ) -> Result<(), Error> {
+        self.storage
+            .get_mut()
+            .keeper_bounty_mut()
+            .reconfigure(Bounty::new(
+                RewardAsset::Native,
+                reward_per_task,
+                max_tasks_per_call,
+                max_payout_per_block,
+            ));
+
+        self.emit_event(Event::KeeperBountySet {
+            reward_per_task,
+            max_tasks_per_call,
+            max_payout_per_block,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Returns ids of up to `len` tasks due at the current block, earliest first.
+    #[export]
+    pub fn due_tasks(&self, len: u32) -> Vec<u64> {
+        self.storage
+            .get()
+            .due(Syscall::block_height(), len as usize)
+    }
+
+    /// Returns `true` if the task failed to be executed.
+    #[export]
+    pub fn is_failed(&self, id: u64) -> bool {
+        self.storage.get().is_failed(id)
+    }
+
+    #[export]
+    pub fn keeper_bounty(&self) -> Bounty {
+        *self.storage.get().keeper_bounty()
+    }
+
+    #[export]
+    pub fn min_self_delay(&self) -> u32 {
+        self.storage.get().min_self_delay()
+    }
+
+    #[export]
+    pub fn task(&self, id: u64) -> Option<Task> {
+        self.storage.get().task(id).cloned()
+    }
+
+    fn enqueue(
+        &mut self,
+        execute_at: u32,
+        target: ActorId,
+        payload: Vec<u8>,
+        value: u128,
+        bounty: u128,
+    ) -> Result<u64, Error> {
+        let id = self.storage.get_mut().enqueue(Task {
+            owner: Syscall::message_source(),
+            execute_at,
+            target,
+            payload,
+            value,
+            bounty,
+        });
+
+        self.emit_event(Event::Scheduled {
+            id,
+            execute_at,
+            target,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(id)
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Scheduled {
+        id: u64,
+        execute_at: u32,
+        target: ActorId,
+    },
+    Executed {
+        id: u64,
+        target: ActorId,
+    },
+    Failed {
+        id: u64,
+        target: ActorId,
+    },
+    Cancelled(u64),
+    KeeperBountySet {
+        reward_per_task: u128,
+        max_tasks_per_call: u32,
+        max_payout_per_block: u128,
+    },
+    KeeperBountyFunded(u128),
+    MinSelfDelaySet(u32),
+}
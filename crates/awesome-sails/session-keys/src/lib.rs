@@ -0,0 +1,430 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Session Keys service.
+//!
+//! This service lets an account register session keys: secondary actors allowed
+//! to act on its behalf within a block range and a scope of methods, e.g. hot
+//! wallets or game clients holding keys of short-lived sessions.
+//!
+//! Sessions proposed by the account take effect once accepted by the key, so
+//! an account can't bind keys of others. Expired sessions are pruned by anyone.
+//!
+//! Keys transfer and approve VFTs of their accounts by `transfer_as` and `approve_as`,
+//! scoped by [`TRANSFER`] and [`APPROVE`], which `Vft` applies as if the account
+//! was the message source. Other services consult the sessions by [`Sessions::resolve`]
+//! when the message source differs from the account.
+
+#![no_std]
+
+use crate::error::{BadInput, EmitError, Error, KeyInUse, NotProposed, Unauthorized};
+use awesome_sails_utils::{
+    ensure, ok_if,
+    page::{DEFAULT_MAX_PAGE_SIZE, ensure_page_size},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    prelude::*,
+};
+
+/// Scope of `transfer_as`.
+pub const TRANSFER: &str = "Transfer";
+
+/// Scope of `approve_as`.
+pub const APPROVE: &str = "Approve";
+
+/// Session of a key acting on behalf of the account.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Session {
+    pub account: ActorId,
+    /// First block the key is valid at.
+    pub valid_from: u32,
+    /// Last block the key is valid at.
+    pub valid_until: u32,
+    /// Methods the key is allowed to call, e.g. [`TRANSFER`].
+    pub scope: Vec<String>,
+}
+
+impl Session {
+    /// Returns `true` if the session allows the method at the block.
+    pub fn allows(&self, method: &str, bn: u32) -> bool {
+        (self.valid_from..=self.valid_until).contains(&bn)
+            && self.scope.iter().any(|scope| scope == method)
+    }
+
+    /// Returns `true` if the session is no longer valid at the block.
+    pub fn is_expired(&self, bn: u32) -> bool {
+        self.valid_until < bn
+    }
+}
+
+/// Sessions by their keys, along with keys of accounts and sessions
+/// proposed by accounts until accepted by keys.
+#[derive(Default, Debug)]
+pub struct Sessions {
+    sessions: BTreeMap<ActorId, Session>,
+    keys: BTreeMap<ActorId, BTreeSet<ActorId>>,
+    proposals: BTreeMap<(ActorId, ActorId), Session>,
+}
+
+impl Sessions {
+    pub fn session(&self, key: &ActorId) -> Option<&Session> {
+        self.sessions.get(key)
+    }
+
+    pub fn keys_of(&self, account: &ActorId) -> impl Iterator<Item = &ActorId> {
+        self.keys.get(account).into_iter().flatten()
+    }
+
+    /// Returns the account the key acts on behalf of, if the key is allowed
+    /// to call the method at the block.
+    pub fn resolve(&self, key: &ActorId, method: &str, bn: u32) -> Option<ActorId> {
+        self.sessions
+            .get(key)
+            .filter(|session| session.allows(method, bn))
+            .map(|session| session.account)
+    }
+
+    /// Returns the session the account proposed to the key, if any.
+    pub fn proposal(&self, key: &ActorId, account: &ActorId) -> Option<&Session> {
+        self.proposals.get(&(*key, *account))
+    }
+
+    /// Proposes the session to the key, replacing the previous proposal of the same account.
+    ///
+    /// Fails if the key is registered by another account.
+    pub fn propose(&mut self, key: ActorId, session: Session) -> Result<(), KeyInUse> {
+        self.ensure_available(&key, &session.account)?;
+
+        self.proposals.insert((key, session.account), session);
+
+        Ok(())
+    }
+
+    /// Registers the session the account proposed to the key, replacing
+    /// the previous session of the key.
+    ///
+    /// Fails if the session wasn't proposed, or the key is registered by another account.
+    pub fn accept(&mut self, key: ActorId, account: ActorId) -> Result<Session, Error> {
+        self.ensure_available(&key, &account)?;
+
+        let session = self.proposals.remove(&(key, account)).ok_or(NotProposed)?;
+
+        self.keys.entry(account).or_default().insert(key);
+        self.sessions.insert(key, session.clone());
+
+        Ok(session)
+    }
+
+    fn ensure_available(&self, key: &ActorId, account: &ActorId) -> Result<(), KeyInUse> {
+        if let Some(previous) = self.sessions.get(key) {
+            ensure!(previous.account == *account, KeyInUse);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the session of the key, along with sessions proposed to it,
+    /// if expired at the block.
+    ///
+    /// Returns the account of the removed session.
+    pub fn prune(&mut self, key: ActorId, bn: u32) -> Option<ActorId> {
+        self.proposals
+            .retain(|(k, _), session| *k != key || !session.is_expired(bn));
+
+        let account = self
+            .sessions
+            .get(&key)
+            .filter(|session| session.is_expired(bn))?
+            .account;
+
+        self.revoke(account, key).map(|_| account)
+    }
+
+    /// Revokes the key of the account, returning its session if it was registered.
+    pub fn revoke(&mut self, account: ActorId, key: ActorId) -> Option<Session> {
+        let keys = self.keys.get_mut(&account)?;
+
+        if !keys.remove(&key) {
+            return None;
+        }
+
+        if keys.is_empty() {
+            self.keys.remove(&account);
+        }
+
+        self.sessions.remove(&key)
+    }
+}
+
+/// Awesome Session Keys service itself.
+pub struct SessionKeys<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Sessions> = StorageRefCell<'a, Sessions>,
+> {
+    storage: S,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Sessions>,
+> SessionKeys<'a, A, B, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(storage: S, vft: vft::VftExposure<vft::Vft<'a, A, B>>) -> Self {
+        Self { storage, vft }
+    }
+
+    /// Ensures the message source is a key allowed to call the method on behalf of the account.
+    fn authorize(&self, account: ActorId, method: &str) -> Result<(), Unauthorized> {
+        let resolved =
+            self.storage
+                .get()
+                .resolve(&Syscall::message_source(), method, Syscall::block_height());
+
+        ensure!(resolved == Some(account), Unauthorized);
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = Sessions>,
+> SessionKeys<'a, A, B, S>
+{
+    /// Proposes the key to act on behalf of the message source
+    /// within blocks `valid_from..=valid_until` and the scope of methods.
+    ///
+    /// The session takes effect once accepted by the key, see [`Self::accept`].
+    /// Proposing the key again replaces its proposal, while accepting replaces its session.
+    #[export(unwrap_result)]
+    pub fn register(
+        &mut self,
+        key: ActorId,
+        valid_from: u32,
+        valid_until: u32,
+        scope: Vec<String>,
+    ) -> Result<(), Error> {
+        let account = Syscall::message_source();
+
+        ensure!(
+            !key.is_zero()
+                && key != account
+                && valid_from <= valid_until
+                && valid_until >= Syscall::block_height()
+                && !scope.is_empty(),
+            BadInput
+        );
+
+        self.storage.get_mut().propose(
+            key,
+            Session {
+                account,
+                valid_from,
+                valid_until,
+                scope,
+            },
+        )?;
+
+        self.emit_event(Event::SessionProposed {
+            account,
+            key,
+            valid_from,
+            valid_until,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Accepts the session the account proposed to the message source as its key.
+    #[export(unwrap_result)]
+    pub fn accept(&mut self, account: ActorId) -> Result<(), Error> {
+        let key = Syscall::message_source();
+
+        let session = self.storage.get_mut().accept(key, account)?;
+
+        self.emit_event(Event::SessionRegistered {
+            account,
+            key,
+            valid_from: session.valid_from,
+            valid_until: session.valid_until,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Revokes the key of the message source.
+    ///
+    /// Returns `true` if the key was registered.
+    #[export(unwrap_result)]
+    pub fn revoke(&mut self, key: ActorId) -> Result<bool, Error> {
+        let account = Syscall::message_source();
+
+        ok_if!(self.storage.get_mut().revoke(account, key).is_none(), false);
+
+        self.emit_event(Event::SessionRevoked { account, key })
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    /// Removes expired sessions of the keys, along with expired sessions
+    /// proposed to them, freeing the storage. Callable by anyone.
+    ///
+    /// Returns amount of removed sessions, emitting `SessionRevoked` for each.
+    #[export(unwrap_result)]
+    pub fn prune(&mut self, keys: Vec<ActorId>) -> Result<u32, Error> {
+        ensure_page_size(keys.len() as u32, DEFAULT_MAX_PAGE_SIZE)?;
+
+        let bn = Syscall::block_height();
+        let mut pruned = 0;
+
+        for key in keys {
+            let Some(account) = self.storage.get_mut().prune(key, bn) else {
+                continue;
+            };
+
+            self.emit_event(Event::SessionRevoked { account, key })
+                .map_err(|_| EmitError)?;
+
+            pruned += 1;
+        }
+
+        Ok(pruned)
+    }
+
+    /// Approves `value` of VFTs of the account to the spender by its key
+    /// allowed to [`APPROVE`], see `Vft::approve`.
+    ///
+    /// Returns `true` if the allowance changed.
+    #[export(unwrap_result)]
+    pub fn approve_as(
+        &mut self,
+        account: ActorId,
+        spender: ActorId,
+        value: U256,
+    ) -> Result<bool, Error> {
+        self.inner.authorize(account, APPROVE)?;
+
+        self.vft.approve_on_behalf(account, spender, value)
+    }
+
+    /// Transfers `value` of VFTs of the account to `to` by its key
+    /// allowed to [`TRANSFER`], see `Vft::transfer`.
+    #[export(unwrap_result)]
+    pub fn transfer_as(
+        &mut self,
+        account: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> Result<bool, Error> {
+        self.inner.authorize(account, TRANSFER)?;
+
+        self.vft.transfer_on_behalf(account, to, value)
+    }
+
+    #[export]
+    pub fn session(&self, key: ActorId) -> Option<Session> {
+        self.storage.get().session(&key).cloned()
+    }
+
+    #[export]
+    pub fn keys_of(&self, account: ActorId) -> Vec<ActorId> {
+        self.storage.get().keys_of(&account).copied().collect()
+    }
+
+    #[export]
+    pub fn proposal(&self, key: ActorId, account: ActorId) -> Option<Session> {
+        self.storage.get().proposal(&key, &account).cloned()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    /// Session was proposed to the key, taking effect once accepted, see `SessionKeys::accept`.
+    SessionProposed {
+        account: ActorId,
+        key: ActorId,
+        valid_from: u32,
+        valid_until: u32,
+    },
+    SessionRegistered {
+        account: ActorId,
+        key: ActorId,
+        valid_from: u32,
+        valid_until: u32,
+    },
+    SessionRevoked {
+        account: ActorId,
+        key: ActorId,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Message source is not a key allowed to act on behalf of the account")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct Unauthorized;
+
+    awesome_sails_utils::impl_error_code!(Unauthorized => 1300);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Key is registered by another account")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct KeyInUse;
+
+    awesome_sails_utils::impl_error_code!(KeyInUse => 1301);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Session wasn't proposed to the key by the account")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotProposed;
+
+    awesome_sails_utils::impl_error_code!(NotProposed => 1302);
+}
@@ -0,0 +1,426 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Smart Account service.
+//!
+//! This service turns the program into an account of its owner: the program holds
+//! native value and tokens of other programs, executing calls of the owner on its behalf.
+//! The owner is any actor, e.g. a user or a multisig program.
+//!
+//! Calls are also executed by session keys the owner registered with the [`EXECUTE`]
+//! scope in the shared [`Sessions`], see `SessionKeys::register` and `SessionKeys::accept`.
+//!
+//! Guardians appointed by the owner rotate its key, once `threshold` of them approve
+//! the same new owner; the owner cancels pending recoveries. Each guardian backs one
+//! new owner at a time, so a guardian proposing another one doesn't discard approvals
+//! of the others.
+
+#![no_std]
+
+use crate::error::{BadInput, BadOrigin, EmitError, Error, ExecutionFailed, NotGuardian};
+use awesome_sails_session_keys::Sessions;
+use awesome_sails_utils::{
+    ensure, ok_if,
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    gstd,
+    prelude::*,
+};
+
+/// Scope of session keys executing calls of the owner.
+pub const EXECUTE: &str = "Execute";
+
+/// Pending rotation of the owner key by guardians.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Recovery {
+    pub new_owner: ActorId,
+    pub approvals: Vec<ActorId>,
+    pub started_at: u32,
+}
+
+/// State of the smart account.
+#[derive(Default, Debug)]
+pub struct AccountStorage {
+    owner: ActorId,
+    guardians: BTreeSet<ActorId>,
+    threshold: u32,
+    recoveries: BTreeMap<ActorId, Recovery>,
+}
+
+impl AccountStorage {
+    /// Creates the account of the owner, without guardians.
+    pub fn new(owner: ActorId) -> Self {
+        Self {
+            owner,
+            ..Default::default()
+        }
+    }
+
+    pub fn owner(&self) -> ActorId {
+        self.owner
+    }
+
+    pub fn guardians(&self) -> &BTreeSet<ActorId> {
+        &self.guardians
+    }
+
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the pending recovery rotating the owner to `new_owner`, if any.
+    pub fn recovery(&self, new_owner: ActorId) -> Option<&Recovery> {
+        self.recoveries.get(&new_owner)
+    }
+
+    /// Iterates over pending recoveries, in ascending order of new owners.
+    pub fn recoveries(&self) -> impl Iterator<Item = &Recovery> {
+        self.recoveries.values()
+    }
+
+    /// Replaces the owner, cancelling pending recoveries.
+    pub fn set_owner(&mut self, owner: ActorId) {
+        self.owner = owner;
+        self.recoveries.clear();
+    }
+
+    /// Replaces guardians and the amount of approvals rotating the owner,
+    /// cancelling pending recoveries.
+    ///
+    /// Fails unless the threshold is within `1..=guardians.len()`, or both are zero.
+    pub fn set_guardians(
+        &mut self,
+        guardians: BTreeSet<ActorId>,
+        threshold: u32,
+    ) -> Result<(), BadInput> {
+        ensure!(
+            threshold as usize <= guardians.len() && (threshold == 0) == guardians.is_empty(),
+            BadInput
+        );
+
+        self.guardians = guardians;
+        self.threshold = threshold;
+        self.recoveries.clear();
+
+        Ok(())
+    }
+
+    /// Approves rotation of the owner to `new_owner` by the guardian, withdrawing
+    /// its approval of another new owner, if any.
+    ///
+    /// Returns `true` if the owner is rotated, as the threshold is reached.
+    pub fn approve_recovery(
+        &mut self,
+        guardian: ActorId,
+        new_owner: ActorId,
+        bn: u32,
+    ) -> Result<bool, NotGuardian> {
+        ensure!(self.guardians.contains(&guardian), NotGuardian);
+
+        self.recoveries.retain(|owner, recovery| {
+            if *owner != new_owner {
+                recovery.approvals.retain(|approval| *approval != guardian);
+            }
+
+            !recovery.approvals.is_empty()
+        });
+
+        let recovery = self
+            .recoveries
+            .entry(new_owner)
+            .or_insert_with(|| Recovery {
+                new_owner,
+                approvals: Vec::new(),
+                started_at: bn,
+            });
+
+        if !recovery.approvals.contains(&guardian) {
+            recovery.approvals.push(guardian);
+        }
+
+        ok_if!(recovery.approvals.len() < self.threshold as usize, false);
+
+        self.set_owner(new_owner);
+
+        Ok(true)
+    }
+
+    /// Cancels pending recoveries, returning them.
+    pub fn cancel_recoveries(&mut self) -> Vec<Recovery> {
+        core::mem::take(&mut self.recoveries)
+            .into_values()
+            .collect()
+    }
+}
+
+/// Awesome Smart Account service itself.
+pub struct SmartAccount<
+    'a,
+    S: InfallibleStorageMut<Item = AccountStorage> = StorageRefCell<'a, AccountStorage>,
+    K: InfallibleStorageMut<Item = Sessions> = StorageRefCell<'a, Sessions>,
+> {
+    sessions: K,
+    storage: S,
+    _marker: core::marker::PhantomData<&'a ()>,
+}
+
+impl<'a, S: InfallibleStorageMut<Item = AccountStorage>, K: InfallibleStorageMut<Item = Sessions>>
+    SmartAccount<'a, S, K>
+{
+    /// Constructor for [`Self`].
+    pub fn new(sessions: K, storage: S) -> Self {
+        Self {
+            sessions,
+            storage,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Ensures the message source is the owner.
+    fn ensure_owner(&self) -> Result<ActorId, BadOrigin> {
+        let owner = self.storage.get().owner();
+
+        ensure!(Syscall::message_source() == owner, BadOrigin);
+
+        Ok(owner)
+    }
+
+    /// Ensures the message source is the owner or its session key allowed to [`EXECUTE`].
+    fn ensure_executor(&self) -> Result<ActorId, BadOrigin> {
+        let owner = self.storage.get().owner();
+        let source = Syscall::message_source();
+
+        let resolved = self
+            .sessions
+            .get()
+            .resolve(&source, EXECUTE, Syscall::block_height());
+
+        ensure!(source == owner || resolved == Some(owner), BadOrigin);
+
+        Ok(owner)
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<'a, S: InfallibleStorageMut<Item = AccountStorage>, K: InfallibleStorageMut<Item = Sessions>>
+    SmartAccount<'a, S, K>
+{
+    /// Sends the payload with `value` of the account to the destination,
+    /// returning the reply.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be the owner or its session key allowed to [`EXECUTE`].
+    /// - `destination` must not be the program itself, as it acts on behalf
+    ///   of other services composed in it, e.g. holding their escrow.
+    #[export(unwrap_result)]
+    pub async fn execute(
+        &mut self,
+        destination: ActorId,
+        payload: Vec<u8>,
+        value: u128,
+    ) -> Result<Vec<u8>, Error> {
+        self.inner.ensure_executor()?;
+
+        ensure!(destination != Syscall::program_id(), BadInput);
+
+        let reply = gstd::msg::send_bytes_for_reply(destination, payload, value, 0)
+            .map_err(|_| ExecutionFailed)?
+            .await
+            .map_err(|_| ExecutionFailed)?;
+
+        self.emit_event(Event::Executed {
+            executor: Syscall::message_source(),
+            destination,
+            value,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(reply)
+    }
+
+    /// Transfers the account to the new owner.
+    #[export(unwrap_result)]
+    pub fn transfer_ownership(&mut self, new_owner: ActorId) -> Result<(), Error> {
+        let previous = self.inner.ensure_owner()?;
+
+        ensure!(!new_owner.is_zero(), BadInput);
+
+        self.storage.get_mut().set_owner(new_owner);
+
+        self.emit_event(Event::OwnerChanged {
+            previous,
+            new: new_owner,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Appoints guardians, `threshold` of which rotate the owner.
+    ///
+    /// Empty guardians with zero threshold disable recovery.
+    #[export(unwrap_result)]
+    pub fn set_guardians(&mut self, guardians: Vec<ActorId>, threshold: u32) -> Result<(), Error> {
+        self.inner.ensure_owner()?;
+
+        let guardians: BTreeSet<_> = guardians.into_iter().collect();
+
+        self.storage
+            .get_mut()
+            .set_guardians(guardians.clone(), threshold)?;
+
+        self.emit_event(Event::GuardiansSet {
+            guardians: guardians.into_iter().collect(),
+            threshold,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Approves rotation of the owner to `new_owner` by the guardian.
+    ///
+    /// Returns `true` if the owner is rotated.
+    #[export(unwrap_result)]
+    pub fn approve_recovery(&mut self, new_owner: ActorId) -> Result<bool, Error> {
+        let guardian = Syscall::message_source();
+
+        ensure!(!new_owner.is_zero(), BadInput);
+
+        let previous = self.storage.get().owner();
+
+        let rotated = self.storage.get_mut().approve_recovery(
+            guardian,
+            new_owner,
+            Syscall::block_height(),
+        )?;
+
+        self.emit_event(Event::RecoveryApproved {
+            guardian,
+            new_owner,
+        })
+        .map_err(|_| EmitError)?;
+
+        if rotated {
+            self.emit_event(Event::OwnerChanged {
+                previous,
+                new: new_owner,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        Ok(rotated)
+    }
+
+    /// Cancels pending recoveries.
+    ///
+    /// Returns `true` if any recovery was pending.
+    #[export(unwrap_result)]
+    pub fn cancel_recovery(&mut self) -> Result<bool, Error> {
+        self.inner.ensure_owner()?;
+
+        ok_if!(self.storage.get_mut().cancel_recoveries().is_empty(), false);
+
+        self.emit_event(Event::RecoveryCancelled)
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    #[export]
+    pub fn guardians(&self) -> Vec<ActorId> {
+        self.storage.get().guardians().iter().copied().collect()
+    }
+
+    #[export]
+    pub fn owner(&self) -> ActorId {
+        self.storage.get().owner()
+    }
+
+    /// Returns the pending recovery rotating the owner to `new_owner`, if any.
+    #[export]
+    pub fn recovery(&self, new_owner: ActorId) -> Option<Recovery> {
+        self.storage.get().recovery(new_owner).cloned()
+    }
+
+    /// Returns pending recoveries, in ascending order of new owners.
+    #[export]
+    pub fn recoveries(&self) -> Vec<Recovery> {
+        self.storage.get().recoveries().cloned().collect()
+    }
+
+    #[export]
+    pub fn threshold(&self) -> u32 {
+        self.storage.get().threshold()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Executed {
+        executor: ActorId,
+        destination: ActorId,
+        value: u128,
+    },
+    OwnerChanged {
+        previous: ActorId,
+        new: ActorId,
+    },
+    GuardiansSet {
+        guardians: Vec<ActorId>,
+        threshold: u32,
+    },
+    RecoveryApproved {
+        guardian: ActorId,
+        new_owner: ActorId,
+    },
+    RecoveryCancelled,
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadOrigin, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Call execution failed")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct ExecutionFailed;
+
+    awesome_sails_utils::impl_error_code!(ExecutionFailed => 1400);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Message source is not a guardian")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotGuardian;
+
+    awesome_sails_utils::impl_error_code!(NotGuardian => 1401);
+}
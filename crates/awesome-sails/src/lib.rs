@@ -9,6 +9,9 @@ pub use awesome_sails_vft_utils as vft_utils;
 #[cfg(feature = "vft-admin")]
 pub use awesome_sails_vft_admin as vft_admin;
 
+#[cfg(feature = "vft-batch")]
+pub use awesome_sails_vft_batch as vft_batch;
+
 #[cfg(feature = "vft-extension")]
 pub use awesome_sails_vft_extension as vft_extension;
 
@@ -23,3 +26,63 @@ pub use awesome_sails_vft_native_exchange_admin as vft_native_exchange_admin;
 
 #[cfg(feature = "access-control")]
 pub use awesome_sails_access_control as access_control;
+
+#[cfg(feature = "counter")]
+pub use awesome_sails_counter as counter;
+
+#[cfg(feature = "scheduler")]
+pub use awesome_sails_scheduler as scheduler;
+
+#[cfg(feature = "vft-inflation")]
+pub use awesome_sails_vft_inflation as vft_inflation;
+
+#[cfg(feature = "treasury")]
+pub use awesome_sails_treasury as treasury;
+
+#[cfg(feature = "vft-bonding-curve")]
+pub use awesome_sails_vft_bonding_curve as vft_bonding_curve;
+
+#[cfg(feature = "amm")]
+pub use awesome_sails_amm as amm;
+
+#[cfg(feature = "lending")]
+pub use awesome_sails_lending as lending;
+
+#[cfg(feature = "htlc")]
+pub use awesome_sails_htlc as htlc;
+
+#[cfg(feature = "eth-binding")]
+pub use awesome_sails_eth_binding as eth_binding;
+
+#[cfg(feature = "vft-bridge-adapter")]
+pub use awesome_sails_vft_bridge_adapter as vft_bridge_adapter;
+
+#[cfg(feature = "session-keys")]
+pub use awesome_sails_session_keys as session_keys;
+
+#[cfg(feature = "smart-account")]
+pub use awesome_sails_smart_account as smart_account;
+
+#[cfg(feature = "proxy")]
+pub use awesome_sails_proxy as proxy;
+
+#[cfg(feature = "token-registry")]
+pub use awesome_sails_token_registry as token_registry;
+
+#[cfg(feature = "name-registry")]
+pub use awesome_sails_name_registry as name_registry;
+
+#[cfg(feature = "dutch-auction")]
+pub use awesome_sails_dutch_auction as dutch_auction;
+
+#[cfg(feature = "raffle")]
+pub use awesome_sails_raffle as raffle;
+
+#[cfg(feature = "order-book")]
+pub use awesome_sails_order_book as order_book;
+
+#[cfg(feature = "fee-pool")]
+pub use awesome_sails_fee_pool as fee_pool;
+
+#[cfg(feature = "metrics")]
+pub use awesome_sails_metrics as metrics;
@@ -0,0 +1,306 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Token Registry service.
+//!
+//! This service keeps a registry of deployed token programs, so wallets and indexers
+//! discover them without scanning the chain.
+//!
+//! Deployers register their programs along with metadata, code id and version,
+//! and update them by registering newer versions. Accounts with [`CURATOR_ROLE`]
+//! verify registered tokens and remove spam, while updates drop the verification.
+
+#![no_std]
+
+use crate::error::{BadOrigin, EmitError, Error, NotRegistered, StaleVersion};
+use awesome_sails_access_control::{self as access_control, RoleId, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure, ok_if,
+    storage::{InfallibleStorageMut, StorageRefCell},
+};
+use sails_rs::{
+    collections::{BTreeMap, BTreeSet},
+    prelude::*,
+};
+
+pub const CURATOR_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"CURATOR_ROLE")
+    .finalize();
+
+/// Metadata of the token, as returned by `VftMetadata`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// Registered token program.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TokenInfo {
+    pub deployer: ActorId,
+    pub metadata: TokenMetadata,
+    pub code_id: CodeId,
+    pub version: u32,
+    pub registered_at: u32,
+    /// Whether a curator verified the token.
+    pub verified: bool,
+}
+
+/// Registered tokens, along with programs of deployers.
+#[derive(Default, Debug)]
+pub struct Registry {
+    tokens: BTreeMap<ActorId, TokenInfo>,
+    deployed: BTreeMap<ActorId, BTreeSet<ActorId>>,
+}
+
+impl Registry {
+    pub fn token(&self, program_id: &ActorId) -> Option<&TokenInfo> {
+        self.tokens.get(program_id)
+    }
+
+    /// Iterates over registered tokens, in ascending order of program ids.
+    pub fn tokens(&self) -> impl Iterator<Item = (&ActorId, &TokenInfo)> {
+        self.tokens.iter()
+    }
+
+    /// Iterates over programs registered by the deployer, in ascending order.
+    pub fn tokens_of(&self, deployer: &ActorId) -> impl Iterator<Item = &ActorId> {
+        self.deployed.get(deployer).into_iter().flatten()
+    }
+
+    /// Registers the token, replacing its previous version.
+    ///
+    /// Fails if the token is registered by another deployer or with a version
+    /// not older than the new one.
+    pub fn register(&mut self, program_id: ActorId, info: TokenInfo) -> Result<(), Error> {
+        if let Some(previous) = self.tokens.get(&program_id) {
+            ensure!(previous.deployer == info.deployer, BadOrigin);
+            ensure!(previous.version < info.version, StaleVersion);
+        }
+
+        self.deployed
+            .entry(info.deployer)
+            .or_default()
+            .insert(program_id);
+        self.tokens.insert(program_id, info);
+
+        Ok(())
+    }
+
+    /// Sets whether the token is verified, returning `true` if it changed.
+    pub fn verify(&mut self, program_id: &ActorId, verified: bool) -> Result<bool, NotRegistered> {
+        let info = self.tokens.get_mut(program_id).ok_or(NotRegistered)?;
+
+        Ok(core::mem::replace(&mut info.verified, verified) != verified)
+    }
+
+    /// Removes the token, returning it if it was registered.
+    pub fn remove(&mut self, program_id: &ActorId) -> Option<TokenInfo> {
+        let info = self.tokens.remove(program_id)?;
+
+        if let Some(programs) = self.deployed.get_mut(&info.deployer) {
+            programs.remove(program_id);
+
+            if programs.is_empty() {
+                self.deployed.remove(&info.deployer);
+            }
+        }
+
+        Some(info)
+    }
+}
+
+/// Awesome Token Registry service itself.
+pub struct TokenRegistry<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
+    S: InfallibleStorageMut<Item = Registry> = StorageRefCell<'a, Registry>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: S,
+}
+
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Registry>>
+    TokenRegistry<'a, ACS, S>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: S,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<'a, ACS: InfallibleStorageMut<Item = RolesStorage>, S: InfallibleStorageMut<Item = Registry>>
+    TokenRegistry<'a, ACS, S>
+{
+    /// Registers the token program deployed by the message source,
+    /// or its newer version.
+    #[export(unwrap_result)]
+    pub fn register(
+        &mut self,
+        program_id: ActorId,
+        metadata: TokenMetadata,
+        code_id: CodeId,
+        version: u32,
+    ) -> Result<(), Error> {
+        let deployer = Syscall::message_source();
+
+        self.storage.get_mut().register(
+            program_id,
+            TokenInfo {
+                deployer,
+                metadata,
+                code_id,
+                version,
+                registered_at: Syscall::block_height(),
+                verified: false,
+            },
+        )?;
+
+        self.emit_event(Event::Registered {
+            program_id,
+            deployer,
+            version,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Removes the token from the registry.
+    ///
+    /// Returns `true` if the token was registered.
+    #[export(unwrap_result)]
+    #[require_role(CURATOR_ROLE)]
+    pub fn remove(&mut self, program_id: ActorId) -> Result<bool, Error> {
+        ok_if!(self.storage.get_mut().remove(&program_id).is_none(), false);
+
+        self.emit_event(Event::Removed(program_id))
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    /// Sets whether the token is verified.
+    ///
+    /// Returns `true` if the verification changed.
+    #[export(unwrap_result)]
+    #[require_role(CURATOR_ROLE)]
+    pub fn verify(&mut self, program_id: ActorId, verified: bool) -> Result<bool, Error> {
+        ok_if!(
+            !self.storage.get_mut().verify(&program_id, verified)?,
+            false
+        );
+
+        self.emit_event(Event::Verified {
+            program_id,
+            verified,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    #[export]
+    pub fn token(&self, program_id: ActorId) -> Option<TokenInfo> {
+        self.storage.get().token(&program_id).cloned()
+    }
+
+    /// Returns up to `len` registered tokens starting from `cursor`,
+    /// only verified ones if `verified_only`, in ascending order of program ids.
+    #[export]
+    pub fn tokens(&self, cursor: u32, len: u32, verified_only: bool) -> Vec<(ActorId, TokenInfo)> {
+        self.storage
+            .get()
+            .tokens()
+            .filter(|(_, info)| !verified_only || info.verified)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(|(&program_id, info)| (program_id, info.clone()))
+            .collect()
+    }
+
+    /// Returns up to `len` programs registered by the deployer starting from `cursor`,
+    /// in ascending order.
+    #[export]
+    pub fn tokens_of(&self, deployer: ActorId, cursor: u32, len: u32) -> Vec<ActorId> {
+        self.storage
+            .get()
+            .tokens_of(&deployer)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .copied()
+            .collect()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Registered {
+        program_id: ActorId,
+        deployer: ActorId,
+        version: u32,
+    },
+    Verified {
+        program_id: ActorId,
+        verified: bool,
+    },
+    Removed(ActorId),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadOrigin, EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Token is not registered")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct NotRegistered;
+
+    awesome_sails_utils::impl_error_code!(NotRegistered => 1600);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Version is not newer than the registered one")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct StaleVersion;
+
+    awesome_sails_utils::impl_error_code!(StaleVersion => 1601);
+}
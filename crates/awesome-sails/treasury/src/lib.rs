@@ -0,0 +1,538 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Treasury service.
+//!
+//! This service holds VFTs and native value deposited to the program, spending them
+//! through proposals: accounts with [`SPENDER_ROLE`] propose spends, executed once approved
+//! by accounts with [`TREASURER_ROLE`] (e.g. a governance program).
+//!
+//! Funds of the treasury are accounted apart from the rest of the program account,
+//! e.g. held in escrow by other services, so only deposits to the treasury are spent.
+//!
+//! Spends of each asset may be capped per period of blocks, while proposals are
+//! kept along with their outcome.
+
+#![no_std]
+
+use crate::error::{
+    BadInput, BudgetExceeded, EmitError, Error, InsufficientFunds, ProposalNotPending, SendError,
+};
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage, ensure,
+};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    math::OverflowError,
+    page::ensure_page_size,
+    period::PeriodBudget,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+pub const SPENDER_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"SPENDER_ROLE")
+    .finalize();
+pub const TREASURER_ROLE: RoleId = keccak_const::Keccak256::new()
+    .update(b"TREASURER_ROLE")
+    .finalize();
+
+/// Asset held by the treasury.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Asset {
+    Native,
+    Vft,
+}
+
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Rejected,
+}
+
+/// Proposal to spend treasury funds.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Proposal {
+    pub proposer: ActorId,
+    pub beneficiary: ActorId,
+    pub asset: Asset,
+    pub amount: U256,
+    pub created_at: u32,
+    pub status: ProposalStatus,
+}
+
+/// Amount of an asset the treasury may spend within a period of blocks.
+pub type Budget = PeriodBudget;
+
+/// Funds, proposals and budgets of [`Treasury`].
+#[derive(Default, Debug)]
+pub struct TreasuryStorage {
+    next_id: u64,
+    proposals: BTreeMap<u64, Proposal>,
+    budgets: BTreeMap<Asset, Budget>,
+    funds: BTreeMap<Asset, U256>,
+}
+
+impl TreasuryStorage {
+    /// Returns the amount of the asset deposited to the treasury and not yet spent.
+    pub fn funds(&self, asset: Asset) -> U256 {
+        self.funds.get(&asset).copied().unwrap_or_default()
+    }
+
+    /// Adds the deposited amount of the asset to funds of the treasury.
+    pub fn credit(&mut self, asset: Asset, amount: U256) -> Result<(), OverflowError> {
+        let funds = self.funds.entry(asset).or_default();
+
+        *funds = funds.checked_add(amount).ok_or(OverflowError)?;
+
+        Ok(())
+    }
+
+    /// Subtracts the spent amount of the asset from funds of the treasury.
+    pub fn debit(&mut self, asset: Asset, amount: U256) -> Result<(), InsufficientFunds> {
+        let available = self.funds(asset);
+
+        ensure!(amount <= available, InsufficientFunds { asset, available });
+
+        self.funds.insert(asset, available - amount);
+
+        Ok(())
+    }
+
+    /// Adds the proposal, returning its id.
+    pub fn propose(&mut self, proposal: Proposal) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.proposals.insert(id, proposal);
+
+        id
+    }
+
+    pub fn proposal(&self, id: u64) -> Option<&Proposal> {
+        self.proposals.get(&id)
+    }
+
+    /// Returns up to `len` proposals, starting from the `cursor` id.
+    pub fn proposals(&self, cursor: u64, len: u32) -> Vec<(u64, Proposal)> {
+        self.proposals
+            .range(cursor..)
+            .take(len as usize)
+            .map(|(&id, proposal)| (id, proposal.clone()))
+            .collect()
+    }
+
+    /// Resolves the pending proposal, returning it.
+    pub fn resolve(
+        &mut self,
+        id: u64,
+        status: ProposalStatus,
+    ) -> Result<Proposal, ProposalNotPending> {
+        let proposal = self
+            .proposals
+            .get_mut(&id)
+            .filter(|proposal| proposal.status == ProposalStatus::Pending)
+            .ok_or(ProposalNotPending(id))?;
+
+        proposal.status = status;
+
+        Ok(proposal.clone())
+    }
+
+    pub fn budget(&self, asset: Asset) -> Option<&Budget> {
+        self.budgets.get(&asset)
+    }
+
+    pub fn set_budget(&mut self, asset: Asset, budget: Budget) {
+        self.budgets.insert(asset, budget);
+    }
+
+    pub fn remove_budget(&mut self, asset: Asset) -> bool {
+        self.budgets.remove(&asset).is_some()
+    }
+
+    /// Accounts `amount` against the budget of the asset, if any.
+    ///
+    /// Assets without a budget are unbounded.
+    pub fn consume_budget(
+        &mut self,
+        asset: Asset,
+        amount: U256,
+        current_bn: u32,
+    ) -> Result<(), BudgetExceeded> {
+        let Some(budget) = self.budgets.get_mut(&asset) else {
+            return Ok(());
+        };
+
+        budget
+            .try_spend(amount, current_bn)
+            .map_err(|remaining| BudgetExceeded { asset, remaining })
+    }
+}
+
+/// Awesome Treasury service itself.
+pub struct Treasury<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    T: InfallibleStorageMut<Item = TreasuryStorage> = StorageRefCell<'a, TreasuryStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: T,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    T: InfallibleStorageMut<Item = TreasuryStorage>,
+> Treasury<'a, ACS, A, B, T>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: T,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+            vft,
+        }
+    }
+
+    /// Pays the amount of the asset from funds of the treasury to the beneficiary.
+    fn pay(&mut self, beneficiary: ActorId, asset: Asset, amount: U256) -> Result<(), Error> {
+        self.storage.get_mut().debit(asset, amount)?;
+
+        match asset {
+            Asset::Native => {
+                gstd::msg::send_bytes(beneficiary, [], amount.as_u128())
+                    .map_err(|_| SendError::Value)?;
+            }
+            Asset::Vft => {
+                self.vft
+                    .transfer_escrowed(Syscall::program_id(), beneficiary, amount)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    T: InfallibleStorageMut<Item = TreasuryStorage>,
+> Treasury<'a, ACS, A, B, T>
+{
+    /// Adds the amount of the asset deposited by the message source to funds
+    /// of the treasury.
+    fn deposit_asset(&mut self, asset: Asset, amount: U256) -> Result<(), Error> {
+        self.storage.get_mut().credit(asset, amount)?;
+
+        self.emit_event(Event::Deposited {
+            from: Syscall::message_source(),
+            asset,
+            amount,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Approves the pending proposal, paying it out within the budget of its asset.
+    #[export(unwrap_result)]
+    #[require_role(TREASURER_ROLE)]
+    pub fn approve_spend(&mut self, id: u64) -> Result<(), Error> {
+        let current_bn = Syscall::block_height();
+
+        let proposal = self
+            .storage
+            .get()
+            .proposal(id)
+            .filter(|proposal| proposal.status == ProposalStatus::Pending)
+            .cloned()
+            .ok_or(ProposalNotPending(id))?;
+
+        self.storage
+            .get_mut()
+            .consume_budget(proposal.asset, proposal.amount, current_bn)?;
+
+        self.storage
+            .get_mut()
+            .resolve(id, ProposalStatus::Executed)?;
+
+        self.inner
+            .pay(proposal.beneficiary, proposal.asset, proposal.amount)?;
+
+        self.emit_event(Event::SpendExecuted {
+            id,
+            treasurer: Syscall::message_source(),
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Deposits the message value to the treasury.
+    #[export(unwrap_result)]
+    pub fn deposit(&mut self) -> Result<(), Error> {
+        let value = Syscall::message_value();
+
+        ensure!(value != 0, BadInput);
+
+        self.deposit_asset(Asset::Native, value.into())
+    }
+
+    /// Deposits `amount` of VFTs of the message source to the treasury.
+    #[export(unwrap_result)]
+    pub fn deposit_vft(&mut self, amount: U256) -> Result<(), Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        self.inner.vft.transfer_escrowed(
+            Syscall::message_source(),
+            Syscall::program_id(),
+            amount,
+        )?;
+
+        self.deposit_asset(Asset::Vft, amount)
+    }
+
+    /// Proposes to spend the amount of the asset to the beneficiary.
+    #[export(unwrap_result)]
+    #[require_role(SPENDER_ROLE)]
+    pub fn propose_spend(
+        &mut self,
+        beneficiary: ActorId,
+        asset: Asset,
+        amount: U256,
+    ) -> Result<u64, Error> {
+        ensure!(!beneficiary.is_zero() && !amount.is_zero(), BadInput);
+
+        if asset == Asset::Native {
+            ensure!(amount <= u128::MAX.into(), OverflowError);
+        }
+
+        let proposer = Syscall::message_source();
+
+        let id = self.storage.get_mut().propose(Proposal {
+            proposer,
+            beneficiary,
+            asset,
+            amount,
+            created_at: Syscall::block_height(),
+            status: ProposalStatus::Pending,
+        });
+
+        self.emit_event(Event::SpendProposed {
+            id,
+            proposer,
+            beneficiary,
+            asset,
+            amount,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(id)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(TREASURER_ROLE)]
+    pub fn reject_spend(&mut self, id: u64) -> Result<(), Error> {
+        self.storage
+            .get_mut()
+            .resolve(id, ProposalStatus::Rejected)?;
+
+        self.emit_event(Event::SpendRejected {
+            id,
+            treasurer: Syscall::message_source(),
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn remove_budget(&mut self, asset: Asset) -> Result<bool, Error> {
+        if !self.storage.get_mut().remove_budget(asset) {
+            return Ok(false);
+        }
+
+        self.emit_event(Event::BudgetRemoved(asset))
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    /// Limits the amount of the asset spent per `period_blocks` blocks.
+    ///
+    /// Replaces any existing budget, starting a fresh period.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_budget(
+        &mut self,
+        asset: Asset,
+        amount_per_period: U256,
+        period_blocks: u32,
+    ) -> Result<(), Error> {
+        let budget = Budget::new(amount_per_period, period_blocks, Syscall::block_height())
+            .ok_or(BadInput)?;
+
+        self.storage.get_mut().set_budget(asset, budget);
+
+        self.emit_event(Event::BudgetSet {
+            asset,
+            amount_per_period,
+            period_blocks,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Returns the amount of the asset deposited to the treasury and not yet spent.
+    #[export]
+    pub fn funds(&self, asset: Asset) -> U256 {
+        self.storage.get().funds(asset)
+    }
+
+    #[export]
+    pub fn budget(&self, asset: Asset) -> Option<Budget> {
+        self.storage.get().budget(asset).copied()
+    }
+
+    /// Returns the amount of the asset still available to spend in the current period,
+    /// or `None` if the asset is not budgeted.
+    #[export]
+    pub fn budget_remaining(&self, asset: Asset) -> Option<U256> {
+        self.storage
+            .get()
+            .budget(asset)
+            .map(|budget| budget.remaining(Syscall::block_height()))
+    }
+
+    #[export]
+    pub fn proposal(&self, id: u64) -> Option<Proposal> {
+        self.storage.get().proposal(id).cloned()
+    }
+
+    /// Returns up to `len` proposals, starting from the `cursor` id.
+    ///
+    /// Fails with `PageTooLarge` if `len` exceeds the max page size of the program.
+    #[export(unwrap_result)]
+    pub fn proposals(&self, cursor: u64, len: u32) -> Result<Vec<(u64, Proposal)>, Error> {
+        ensure_page_size(len, self.access_control.get_max_page_size())?;
+
+        Ok(self.storage.get().proposals(cursor, len))
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Deposited {
+        from: ActorId,
+        asset: Asset,
+        amount: U256,
+    },
+    SpendProposed {
+        id: u64,
+        proposer: ActorId,
+        beneficiary: ActorId,
+        asset: Asset,
+        amount: U256,
+    },
+    SpendExecuted {
+        id: u64,
+        treasurer: ActorId,
+    },
+    SpendRejected {
+        id: u64,
+        treasurer: ActorId,
+    },
+    BudgetSet {
+        asset: Asset,
+        amount_per_period: U256,
+        period_blocks: u32,
+    },
+    BudgetRemoved(Asset),
+}
+
+pub mod error {
+    use crate::Asset;
+    pub use awesome_sails_utils::error::{BadInput, EmitError, Error, SendError};
+    use sails_rs::{
+        U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Budget exceeded: at most {remaining} of {asset:?} may be spent in this period")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct BudgetExceeded {
+        pub asset: Asset,
+        pub remaining: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(BudgetExceeded => 600);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Proposal {0} is not pending")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct ProposalNotPending(pub u64);
+
+    awesome_sails_utils::impl_error_code!(ProposalNotPending => 601);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Insufficient funds: {available} of {asset:?} held by the treasury")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct InsufficientFunds {
+        pub asset: Asset,
+        pub available: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(InsufficientFunds => 602);
+}
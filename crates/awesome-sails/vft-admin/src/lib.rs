@@ -19,34 +19,218 @@
 //! Awesome VFT-Admin service.
 //!
 //! This service provides admin functionality to VFT using Role-Based Access Control.
+//!
+//! Its circuit breaker halts mints and transfers once volume minted or transferred
+//! within a rolling window of blocks exceeds thresholds set by the admin. Mints and
+//! transfers check it as they happen, see `Vft::check_circuit_breaker`, until pausers
+//! resume the program.
+//!
+//! Pausers may schedule the program to resume after some blocks, so pauses can't
+//! become permanent if pausers disappear. The resume is sent by the program to itself
+//! by a delayed message paid from a gas reservation, so the service must be mounted
+//! under [`AUTO_RESUME_ROUTE`].
+//!
+//! Admins set recurring mint rules minting an amount to a recipient every interval
+//! of blocks, a limited number of times. Due mints are executed lazily by `tick`,
+//! open to anyone, e.g. a keeper or a task of the scheduler.
 
 #![no_std]
 
+use crate::error::{
+    BadInput, BadOrigin, EmitError, Error, MintRulesFull, MinterCapExceeded, SendError,
+};
 use awesome_sails_access_control::{
-    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage, ensure,
-    error::{EmitError, Error},
+    self as access_control, DEFAULT_ADMIN_ROLE, RoleId, RolesStorage, ensure, role_id,
 };
+use awesome_sails_macros::require_role;
 use awesome_sails_utils::{
+    audit::{AuditEntry, AuditLog},
     math::{Max, NonZero, Zero},
     ok_if,
-    pause::{PausableRef, Pause, UnpausedError},
+    pause::{PausableRef, Pause, PauseInfo, PauseReason, PausedError, UnpausedError},
+    period::PeriodBudget,
     storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
 };
 use awesome_sails_vft::{
     self as vft,
-    utils::{Allowance, Allowances, Balance, Balances},
+    utils::{
+        Allowance, Allowances, Balance, Balances, CapacityAlert, CircuitBreakerConfig, DustPolicy,
+        StorageMap,
+    },
 };
-use sails_rs::prelude::*;
-
-pub const MINTER_ROLE: RoleId = keccak_const::Keccak256::new()
-    .update(b"MINTER_ROLE")
-    .finalize();
-pub const BURNER_ROLE: RoleId = keccak_const::Keccak256::new()
-    .update(b"BURNER_ROLE")
-    .finalize();
-pub const PAUSER_ROLE: RoleId = keccak_const::Keccak256::new()
-    .update(b"PAUSER_ROLE")
-    .finalize();
+use sails_rs::{collections::BTreeMap, gstd, prelude::*};
+
+pub const MINTER_ROLE: RoleId = role_id("MINTER_ROLE");
+pub const BURNER_ROLE: RoleId = role_id("BURNER_ROLE");
+pub const PAUSER_ROLE: RoleId = role_id("PAUSER_ROLE");
+
+/// Roles the service checks, by name, reported by `known_roles`.
+pub const KNOWN_ROLES: [(&str, RoleId); 4] = [
+    ("DEFAULT_ADMIN_ROLE", DEFAULT_ADMIN_ROLE),
+    ("MINTER_ROLE", MINTER_ROLE),
+    ("BURNER_ROLE", BURNER_ROLE),
+    ("PAUSER_ROLE", PAUSER_ROLE),
+];
+
+/// Route the service must be mounted under for pauses to be resumed automatically.
+pub const AUTO_RESUME_ROUTE: &str = "VftAdmin";
+
+/// Gas reserved for the automatic resume.
+pub const AUTO_RESUME_GAS: u64 = 10_000_000_000;
+
+/// Maximal amount of recurring mint rules kept at once.
+pub const MAX_MINT_RULES: usize = 32;
+
+/// Maximal amount of due mints executed within a single `tick` call.
+pub const MAX_MINTS_PER_TICK: u32 = 32;
+
+/// Admin-side state of [`VftAdmin`], kept apart from VFT storages.
+#[derive(Default, Debug)]
+pub struct AdminStorage {
+    minter_caps: BTreeMap<ActorId, MinterCap>,
+    audit_log: AuditLog<AuditEntry>,
+    auto_resume_at: Option<u32>,
+    mint_rules: BTreeMap<u64, MintRule>,
+    next_mint_rule_id: u64,
+}
+
+impl AdminStorage {
+    /// Returns the block the program is scheduled to resume at, if any.
+    pub fn auto_resume_at(&self) -> Option<u32> {
+        self.auto_resume_at
+    }
+
+    pub fn set_auto_resume_at(&mut self, block: Option<u32>) {
+        self.auto_resume_at = block;
+    }
+
+    pub fn minter_cap(&self, minter: ActorId) -> Option<&MinterCap> {
+        self.minter_caps.get(&minter)
+    }
+
+    pub fn set_minter_cap(&mut self, minter: ActorId, cap: MinterCap) {
+        self.minter_caps.insert(minter, cap);
+    }
+
+    pub fn remove_minter_cap(&mut self, minter: ActorId) -> bool {
+        self.minter_caps.remove(&minter).is_some()
+    }
+
+    pub fn mint_rule(&self, id: u64) -> Option<&MintRule> {
+        self.mint_rules.get(&id)
+    }
+
+    pub fn mint_rules(&self) -> impl Iterator<Item = (u64, &MintRule)> {
+        self.mint_rules.iter().map(|(&id, rule)| (id, rule))
+    }
+
+    /// Adds the recurring mint rule, returning its id.
+    pub fn add_mint_rule(&mut self, rule: MintRule) -> Result<u64, MintRulesFull> {
+        ensure!(self.mint_rules.len() < MAX_MINT_RULES, MintRulesFull);
+
+        let id = self.next_mint_rule_id;
+
+        self.next_mint_rule_id += 1;
+        self.mint_rules.insert(id, rule);
+
+        Ok(id)
+    }
+
+    pub fn remove_mint_rule(&mut self, id: u64) -> bool {
+        self.mint_rules.remove(&id).is_some()
+    }
+
+    /// Removes the next mint of the rule if it's due by the given block,
+    /// returning its recipient and amount, and removing exhausted rules.
+    pub fn pop_due_mint(&mut self, id: u64, current_bn: u32) -> Option<(ActorId, U256)> {
+        let rule = self.mint_rules.get_mut(&id)?;
+
+        if rule.next_at > current_bn {
+            return None;
+        }
+
+        let mint = (rule.recipient, rule.amount);
+
+        rule.remaining_count -= 1;
+        rule.next_at = rule.next_at.saturating_add(rule.interval_blocks);
+
+        if rule.remaining_count == 0 {
+            self.mint_rules.remove(&id);
+        }
+
+        Some(mint)
+    }
+
+    pub fn get_audit_log(&self, cursor: u32, len: u32) -> Vec<(u32, AuditEntry)> {
+        self.audit_log.entries(cursor, len)
+    }
+
+    /// Accounts `value` against the minter's cap, if any.
+    ///
+    /// Minters without a cap are unbounded.
+    pub fn consume_minter_cap(
+        &mut self,
+        minter: ActorId,
+        value: U256,
+        current_bn: u32,
+    ) -> Result<(), MinterCapExceeded> {
+        let Some(cap) = self.minter_caps.get_mut(&minter) else {
+            return Ok(());
+        };
+
+        cap.try_spend(value, current_bn)
+            .map_err(|remaining| MinterCapExceeded { minter, remaining })
+    }
+}
+
+/// Amount of VFTs a minter may mint within a period of blocks.
+pub type MinterCap = PeriodBudget;
+
+/// Recurring mint of an amount to a recipient every interval of blocks.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MintRule {
+    pub recipient: ActorId,
+    pub amount: U256,
+    pub interval_blocks: u32,
+    /// Mints left, the rule is removed once it reaches zero.
+    pub remaining_count: u32,
+    /// Block the next mint is due at.
+    pub next_at: u32,
+}
+
+impl MintRule {
+    /// Returns blocks of up to `len` remaining mints, starting from the next one.
+    pub fn schedule(&self, len: u32) -> Vec<u32> {
+        (0..self.remaining_count.min(len))
+            .map(|i| {
+                self.next_at
+                    .saturating_add(i.saturating_mul(self.interval_blocks))
+            })
+            .collect()
+    }
+
+    /// Total amount of remaining mints.
+    pub fn remaining_amount(&self) -> U256 {
+        self.amount.saturating_mul(self.remaining_count.into())
+    }
+}
+
+/// Effects of `burn` previewed by `simulate_burn`.
+///
+/// The burn emits VFT `Transfer` event and, if `reaped` is set, `AccountReaped` one.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct BurnPreview {
+    /// Balance of the account after the burn.
+    pub balance: U256,
+    /// Dust of the account if the burn removes it.
+    pub reaped: Option<U256>,
+    /// Total supply after the burn.
+    pub total_supply: U256,
+}
 
 /// Awesome VFT-Admin service itself.
 pub struct VftAdmin<
@@ -54,11 +238,13 @@ pub struct VftAdmin<
     ACS: InfallibleStorageMut<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
     A: StorageMut<Item = Allowances> = PausableRef<'a, Allowances>,
     B: StorageMut<Item = Balances> = PausableRef<'a, Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage> = StorageRefCell<'a, AdminStorage>,
 > {
     access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
     allowances: A,
     balances: B,
     pause: &'a Pause,
+    storage: S,
     vft: vft::VftExposure<vft::Vft<'a, A, B>>,
 }
 
@@ -67,7 +253,8 @@ impl<
     ACS: InfallibleStorageMut<Item = RolesStorage>,
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
-> VftAdmin<'a, ACS, A, B>
+    S: InfallibleStorageMut<Item = AdminStorage>,
+> VftAdmin<'a, ACS, A, B, S>
 {
     /// Constructor for [`Self`].
     pub fn new(
@@ -77,6 +264,7 @@ impl<
         allowances: A,
         balances: B,
         pause: &'a Pause,
+        storage: S,
         vft: vft::VftExposure<vft::Vft<'a, A, B>>,
     ) -> Self {
         Self {
@@ -84,6 +272,7 @@ impl<
             allowances,
             balances,
             pause,
+            storage,
             vft,
         }
     }
@@ -95,9 +284,15 @@ impl<
     unsafe fn do_mint(&mut self, to: ActorId, value: U256) -> Result<(), Error> {
         ok_if!(value.is_zero());
 
-        self.balances
-            .get_mut()?
-            .mint(to.try_into()?, Balance::try_from(value)?.try_into()?)?;
+        let check = {
+            let mut balances = self.balances.get_mut()?;
+            let len = balances.len();
+
+            balances.mint(to.try_into()?, Balance::try_from(value)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+
+            balances.check_capacity(len)
+        };
 
         self.vft
             .emit_event(vft::Event::Transfer {
@@ -107,17 +302,63 @@ impl<
             })
             .map_err(|_| EmitError)?;
 
+        self.vft.report_capacity(check)?;
+        self.vft.check_circuit_breaker()?;
+
+        Ok(())
+    }
+
+    /// Records privileged call of the current message source in the audit log.
+    fn audit(&mut self, action: &str, params: impl Encode) {
+        self.storage.get_mut().audit_log.push(AuditEntry::new(
+            Syscall::block_height(),
+            Syscall::message_source(),
+            action,
+            &params,
+        ));
+    }
+
+    /// Burns VFTs from the specified address, emitting VFT transfer event.
+    fn do_burn(&mut self, from: ActorId, value: U256) -> Result<(), Error> {
+        let reaped = {
+            let mut balances = self.balances.get_mut()?;
+
+            let reaped = balances.burn(from.try_into()?, Balance::try_from(value)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+
+            reaped
+        };
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from,
+                to: ActorId::zero(),
+                value,
+            })
+            .map_err(|_| EmitError)?;
+
+        if let Some(dust) = reaped {
+            self.vft
+                .emit_event(vft::Event::AccountReaped {
+                    account: from,
+                    dust: dust.into(),
+                })
+                .map_err(|_| EmitError)?;
+        }
+
         Ok(())
     }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service(events = Event)]
 impl<
     'a,
     ACS: InfallibleStorageMut<Item = RolesStorage>,
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
-> VftAdmin<'a, ACS, A, B>
+    S: InfallibleStorageMut<Item = AdminStorage>,
+> VftAdmin<'a, ACS, A, B, S>
 {
     /// Mints VFTs to the specified address.
     ///
@@ -127,40 +368,104 @@ impl<
         unsafe { self.inner.do_mint(to, value) }
     }
 
+    /// Adds the rule minting `amount` to `recipient` every `interval_blocks` blocks,
+    /// `count` times, starting an interval after the current block.
+    ///
+    /// Returns the id of the rule.
     #[export(unwrap_result)]
-    pub fn append_allowances_shard(&mut self, capacity: u32) -> Result<(), Error> {
-        self.access_control
-            .require_role(DEFAULT_ADMIN_ROLE, Syscall::message_source())?;
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn add_mint_rule(
+        &mut self,
+        recipient: ActorId,
+        amount: U256,
+        interval_blocks: u32,
+        count: u32,
+    ) -> Result<u64, Error> {
+        ensure!(
+            !recipient.is_zero() && !amount.is_zero() && interval_blocks != 0 && count != 0,
+            BadInput
+        );
+
+        Balance::try_from(amount)?;
+
+        let rule = MintRule {
+            recipient,
+            amount,
+            interval_blocks,
+            remaining_count: count,
+            next_at: Syscall::block_height().saturating_add(interval_blocks),
+        };
+
+        let id = self.storage.get_mut().add_mint_rule(rule)?;
+
+        self.emit_event(Event::MintRuleAdded { id, rule })
+            .map_err(|_| EmitError)?;
 
+        self.audit("add_mint_rule", (recipient, amount, interval_blocks, count));
+
+        Ok(id)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn append_allowances_shard(&mut self, capacity: u32) -> Result<(), Error> {
         self.allowances
             .get_mut()?
             .try_append_shard(capacity as usize)?;
 
+        self.audit("append_allowances_shard", capacity);
+
         Ok(())
     }
 
     #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
     pub fn append_balances_shard(&mut self, capacity: u32) -> Result<(), Error> {
-        self.access_control
-            .require_role(DEFAULT_ADMIN_ROLE, Syscall::message_source())?;
-
         self.balances
             .get_mut()?
             .try_append_shard(capacity as usize)?;
 
+        self.audit("append_balances_shard", capacity);
+
         Ok(())
     }
 
+    /// Removes the last allowances shard holding no entries, freeing its memory.
+    ///
+    /// Returns the max capacity of the removed shard, e.g. to append it
+    /// to balances by `append_balances_shard`.
     #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn remove_empty_allowances_shard(&mut self) -> Result<u32, Error> {
+        let capacity = self.allowances.get_mut()?.try_remove_empty_shard()? as u32;
+
+        self.audit("remove_empty_allowances_shard", capacity);
+
+        Ok(capacity)
+    }
+
+    /// Removes the last balances shard holding no entries, freeing its memory.
+    ///
+    /// Returns the max capacity of the removed shard, e.g. to append it
+    /// to allowances by `append_allowances_shard`.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn remove_empty_balances_shard(&mut self) -> Result<u32, Error> {
+        let capacity = self.balances.get_mut()?.try_remove_empty_shard()? as u32;
+
+        self.audit("remove_empty_balances_shard", capacity);
+
+        Ok(capacity)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
     pub fn approve_from(
         &mut self,
         owner: ActorId,
         spender: ActorId,
         value: U256,
     ) -> Result<bool, Error> {
-        self.access_control
-            .require_role(DEFAULT_ADMIN_ROLE, Syscall::message_source())?;
-
         ok_if!(owner == spender, false);
 
         let approval = Allowance::try_from(value).unwrap_or(Allowance::MAX);
@@ -185,36 +490,97 @@ impl<
                 .map_err(|_| EmitError)?;
         }
 
+        self.audit("approve_from", (owner, spender, value));
+
         Ok(changed)
     }
 
+    /// Resumes the program once the block scheduled by `pause` comes.
+    ///
+    /// Only the program itself calls it, by the delayed message sent by `pause`.
+    /// Returns `false` if the resume is no longer scheduled for this block,
+    /// e.g. the program was resumed and paused again in between.
     #[export(unwrap_result)]
-    pub fn burn(&mut self, from: ActorId, value: U256) -> Result<(), Error> {
-        self.access_control
-            .require_role(BURNER_ROLE, Syscall::message_source())?;
+    pub fn auto_resume(&mut self) -> Result<bool, Error> {
+        ensure!(
+            Syscall::message_source() == Syscall::program_id(),
+            BadOrigin
+        );
 
-        self.balances
-            .get_mut()?
-            .burn(from.try_into()?, Balance::try_from(value)?.try_into()?)?;
+        let scheduled = self
+            .storage
+            .get()
+            .auto_resume_at()
+            .is_some_and(|at| at <= Syscall::block_height());
+
+        ok_if!(!scheduled, false);
+
+        self.do_resume()?;
 
+        Ok(true)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(BURNER_ROLE)]
+    pub fn burn(&mut self, from: ActorId, value: U256) -> Result<(), Error> {
+        // Emitted before the `Transfer` event, being reverted along with it if the burn fails.
         self.emit_event(Event::BurnerTookPlace)
             .map_err(|_| EmitError)?;
 
-        self.vft
-            .emit_event(vft::Event::Transfer {
-                from,
-                to: ActorId::zero(),
-                value,
-            })
+        self.inner.do_burn(from, value)?;
+
+        self.emit_event(Event::Burned {
+            by: Syscall::message_source(),
+            from,
+            value,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("burn", (from, value));
+
+        Ok(())
+    }
+
+    /// Burns VFTs from each of the specified addresses.
+    ///
+    /// Either all burns succeed or the whole batch fails.
+    #[export(unwrap_result)]
+    #[require_role(BURNER_ROLE)]
+    pub fn burn_batch(&mut self, burns: Vec<(ActorId, U256)>) -> Result<(), Error> {
+        for &(from, value) in &burns {
+            self.inner.do_burn(from, value)?;
+        }
+
+        self.emit_event(Event::BatchBurnTookPlace)
             .map_err(|_| EmitError)?;
 
+        let by = Syscall::message_source();
+
+        for &(from, value) in &burns {
+            self.emit_event(Event::Burned { by, from, value })
+                .map_err(|_| EmitError)?;
+        }
+
+        self.audit("burn_batch", burns);
+
         Ok(())
     }
 
+    /// Checks volume minted and transferred within the rolling window against
+    /// the circuit breaker, halting mints and transfers if it exceeds thresholds.
+    ///
+    /// Mints and transfers check it themselves, so it's only needed for volume
+    /// moved by services not checking it. May be called by anyone.
+    ///
+    /// Returns `true` if the breaker tripped.
+    #[export(unwrap_result)]
+    pub fn check_circuit_breaker(&mut self) -> Result<bool, Error> {
+        self.vft.check_circuit_breaker()
+    }
+
     #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
     pub fn exit(&mut self, inheritor: ActorId) -> Result<(), Error> {
-        self.access_control
-            .require_role(DEFAULT_ADMIN_ROLE, Syscall::message_source())?;
         ensure!(self.is_paused(), UnpausedError);
 
         self.emit_event(Event::Exited(inheritor))
@@ -223,10 +589,65 @@ impl<
         Syscall::exit(inheritor)
     }
 
+    /// Transfers VFTs between arbitrary accounts, bypassing allowances.
+    ///
+    /// Intended for incident response only.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn force_transfer(
+        &mut self,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> Result<bool, Error> {
+        ok_if!(from == to || value.is_zero(), false);
+
+        let (reaped, check) = {
+            let mut balances = self.balances.get_mut()?;
+            let len = balances.len();
+
+            let reaped =
+                balances.transfer(from.try_into()?, to, Balance::try_from(value)?.try_into()?)?;
+
+            if to.is_zero() {
+                balances.checkpoint_supply(Syscall::block_height());
+            }
+
+            (reaped, balances.check_capacity(len))
+        };
+
+        self.emit_event(Event::ForceTransferTookPlace)
+            .map_err(|_| EmitError)?;
+
+        self.vft
+            .emit_event(vft::Event::Transfer { from, to, value })
+            .map_err(|_| EmitError)?;
+
+        if let Some(dust) = reaped {
+            self.vft
+                .emit_event(vft::Event::AccountReaped {
+                    account: from,
+                    dust: dust.into(),
+                })
+                .map_err(|_| EmitError)?;
+        }
+
+        self.vft.report_capacity(check)?;
+        self.vft.check_circuit_breaker()?;
+
+        self.audit("force_transfer", (from, to, value));
+
+        Ok(true)
+    }
+
     #[export(unwrap_result)]
+    #[require_role(MINTER_ROLE)]
     pub fn mint(&mut self, to: ActorId, value: U256) -> Result<(), Error> {
-        self.access_control
-            .require_role(MINTER_ROLE, Syscall::message_source())?;
+        let minter = Syscall::message_source();
+
+        self.storage
+            .get_mut()
+            .consume_minter_cap(minter, value, Syscall::block_height())?;
 
         unsafe {
             self.do_mint(to, value)?;
@@ -235,49 +656,447 @@ impl<
         self.emit_event(Event::MinterTookPlace)
             .map_err(|_| EmitError)?;
 
+        self.emit_event(Event::Minted {
+            by: minter,
+            to,
+            value,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("mint", (to, value));
+
         Ok(())
     }
 
     #[export(unwrap_result)]
-    pub fn pause(&mut self) -> Result<(), Error> {
-        self.access_control
-            .require_role(PAUSER_ROLE, Syscall::message_source())?;
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn remove_mint_rule(&mut self, id: u64) -> Result<bool, Error> {
+        ok_if!(!self.storage.get_mut().remove_mint_rule(id), false);
+
+        self.emit_event(Event::MintRuleRemoved(id))
+            .map_err(|_| EmitError)?;
+
+        self.audit("remove_mint_rule", id);
 
-        if self.pause.pause() {
-            self.emit_event(Event::Paused).map_err(|_| EmitError)?;
+        Ok(true)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn remove_minter_cap(&mut self, minter: ActorId) -> Result<bool, Error> {
+        ok_if!(!self.storage.get_mut().remove_minter_cap(minter), false);
+
+        self.emit_event(Event::MinterCapRemoved(minter))
+            .map_err(|_| EmitError)?;
+
+        self.audit("remove_minter_cap", minter);
+
+        Ok(true)
+    }
+
+    /// Pauses the program, recording the caller, the block and the reason,
+    /// so wallets can show users why their calls fail.
+    ///
+    /// If `resume_after` is set, the program resumes after that many blocks,
+    /// see [`AUTO_RESUME_ROUTE`]; otherwise the scheduled resume, if any, is cancelled.
+    #[export(unwrap_result)]
+    #[require_role(PAUSER_ROLE)]
+    pub fn pause(
+        &mut self,
+        reason: Option<PauseReason>,
+        resume_after: Option<u32>,
+    ) -> Result<(), Error> {
+        ensure!(resume_after != Some(0), BadInput);
+
+        let auto_resume_at = resume_after
+            .map(|delay| self.schedule_resume(delay))
+            .transpose()?;
+
+        self.storage.get_mut().set_auto_resume_at(auto_resume_at);
+
+        let info = PauseInfo {
+            paused_by: Syscall::message_source(),
+            paused_at: Syscall::block_height(),
+            reason,
+        };
+
+        if self.pause.pause_with(info.clone()) {
+            self.emit_event(Event::Paused(info.clone()))
+                .map_err(|_| EmitError)?;
         }
 
+        self.audit("pause", (info.reason, resume_after));
+
         Ok(())
     }
 
     #[export(unwrap_result)]
+    #[require_role(PAUSER_ROLE)]
     pub fn resume(&mut self) -> Result<(), Error> {
-        self.access_control
-            .require_role(PAUSER_ROLE, Syscall::message_source())?;
+        self.do_resume()
+    }
 
-        if self.pause.resume() {
-            self.emit_event(Event::Resumed).map_err(|_| EmitError)?;
+    /// Sets the alert of operators about occupancy of the map, or removes it.
+    ///
+    /// Once inserts make occupancy of allocated shards of the map cross
+    /// the threshold, `Vft` emits `CapacityWarning` and notifies the operator.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_capacity_alert(
+        &mut self,
+        map: StorageMap,
+        alert: Option<CapacityAlert>,
+    ) -> Result<(), Error> {
+        ensure!(
+            alert.is_none_or(|alert| (1..=10_000).contains(&alert.threshold_bps)),
+            BadInput
+        );
+
+        match map {
+            StorageMap::Allowances => self.allowances.get_mut()?.set_capacity_alert(alert),
+            StorageMap::Balances => self.balances.get_mut()?.set_capacity_alert(alert),
         }
 
+        self.emit_event(Event::CapacityAlertChanged { map, alert })
+            .map_err(|_| EmitError)?;
+
+        self.audit("set_capacity_alert", (map, alert));
+
         Ok(())
     }
 
+    /// Sets thresholds of the circuit breaker, starting a new window.
     #[export(unwrap_result)]
-    pub fn set_expiry_period(&mut self, period: u32) -> Result<(), Error> {
-        self.access_control
-            .require_role(DEFAULT_ADMIN_ROLE, Syscall::message_source())?;
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> Result<(), Error> {
+        self.balances.get_mut()?.set_circuit_breaker(config);
+
+        self.emit_event(Event::CircuitBreakerSet(config))
+            .map_err(|_| EmitError)?;
+
+        self.audit("set_circuit_breaker", config);
+
+        Ok(())
+    }
 
+    /// Sets the policy of handling dust left by balances falling below the minimum.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_dust_policy(&mut self, policy: DustPolicy) -> Result<(), Error> {
+        if let DustPolicy::TransferToTreasury(treasury) = policy {
+            ensure!(!treasury.is_zero(), BadInput);
+        }
+
+        self.balances.get_mut()?.set_dust_policy(policy);
+
+        self.emit_event(Event::DustPolicyChanged(policy))
+            .map_err(|_| EmitError)?;
+
+        self.audit("set_dust_policy", policy);
+
+        Ok(())
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_expiry_period(&mut self, period: u32) -> Result<(), Error> {
         self.allowances.get_mut()?.set_expiry_period(period);
 
         self.emit_event(Event::ExpiryPeriodChanged(period))
             .map_err(|_| EmitError)?;
 
+        self.audit("set_expiry_period", period);
+
+        Ok(())
+    }
+
+    /// Sets the minimum balance of an account: balances falling below it are
+    /// removed, with the remaining dust handled by the dust policy.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_minimum_balance(&mut self, value: U256) -> Result<(), Error> {
+        self.balances.get_mut()?.set_minimum_balance(value);
+
+        self.emit_event(Event::MinimumBalanceChanged(value))
+            .map_err(|_| EmitError)?;
+
+        self.audit("set_minimum_balance", value);
+
+        Ok(())
+    }
+
+    /// Sets the max amount of items enumeration exports of the VFT-Extension
+    /// service reply with at once.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_max_page_size(&mut self, value: u32) -> Result<(), Error> {
+        ensure!(value != 0, BadInput);
+
+        self.balances.get_mut()?.set_max_page_size(value);
+
+        self.emit_event(Event::MaxPageSizeChanged(value))
+            .map_err(|_| EmitError)?;
+
+        self.audit("set_max_page_size", value);
+
+        Ok(())
+    }
+
+    /// Limits the amount of VFTs the minter may mint per `period_blocks` blocks.
+    ///
+    /// Replaces any existing cap, starting a fresh period.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_minter_cap(
+        &mut self,
+        minter: ActorId,
+        amount_per_period: U256,
+        period_blocks: u32,
+    ) -> Result<(), Error> {
+        let cap = MinterCap::new(amount_per_period, period_blocks, Syscall::block_height())
+            .ok_or(BadInput)?;
+
+        self.storage.get_mut().set_minter_cap(minter, cap);
+
+        self.emit_event(Event::MinterCapSet {
+            minter,
+            amount_per_period,
+            period_blocks,
+        })
+        .map_err(|_| EmitError)?;
+
+        self.audit("set_minter_cap", (minter, amount_per_period, period_blocks));
+
         Ok(())
     }
 
+    /// Executes up to [`MAX_MINTS_PER_TICK`] due mints of recurring mint rules,
+    /// each checking the circuit breaker.
+    ///
+    /// May be called by anyone, e.g. keepers or tasks of the scheduler.
+    ///
+    /// Returns the amount of executed mints.
+    #[export(unwrap_result)]
+    pub fn tick(&mut self) -> Result<u32, Error> {
+        let current_bn = Syscall::block_height();
+        let ids: Vec<u64> = self.storage.get().mint_rules().map(|(id, _)| id).collect();
+
+        let mut executed = 0;
+
+        'rules: for id in ids {
+            loop {
+                if executed == MAX_MINTS_PER_TICK {
+                    break 'rules;
+                }
+
+                let Some((to, value)) = self.storage.get_mut().pop_due_mint(id, current_bn) else {
+                    break;
+                };
+
+                // Safety: rules are set by the super admin.
+                unsafe {
+                    self.do_mint(to, value)?;
+                }
+
+                let remaining_count = self
+                    .storage
+                    .get()
+                    .mint_rule(id)
+                    .map_or(0, |rule| rule.remaining_count);
+
+                self.emit_event(Event::RecurringMint {
+                    id,
+                    to,
+                    value,
+                    remaining_count,
+                })
+                .map_err(|_| EmitError)?;
+
+                executed += 1;
+            }
+        }
+
+        Ok(executed)
+    }
+
+    /// Returns amount of entries allocated allowances shards may still hold.
+    #[export(unwrap_result)]
+    pub fn allowances_unused_capacity(&self) -> Result<u32, Error> {
+        Ok(self.allowances.get()?.space() as u32)
+    }
+
+    /// Returns up to `len` audit log entries of privileged calls, starting from `cursor`
+    /// or the oldest entry kept.
+    ///
+    /// `len` is clamped to the max page size.
+    #[export(unwrap_result)]
+    pub fn audit_log(&self, cursor: u32, len: u32) -> Result<Vec<(u32, AuditEntry)>, Error> {
+        let len = len.min(self.balances.get()?.max_page_size());
+
+        Ok(self.storage.get().get_audit_log(cursor, len))
+    }
+
+    /// Returns the block the program is scheduled to resume at, if any.
+    #[export]
+    pub fn auto_resume_at(&self) -> Option<u32> {
+        self.storage.get().auto_resume_at()
+    }
+
+    /// Returns amount of entries allocated balances shards may still hold.
+    #[export(unwrap_result)]
+    pub fn balances_unused_capacity(&self) -> Result<u32, Error> {
+        Ok(self.balances.get()?.space() as u32)
+    }
+
+    /// Returns the alert of operators about occupancy of the map, if any.
+    #[export(unwrap_result)]
+    pub fn capacity_alert(&self, map: StorageMap) -> Result<Option<CapacityAlert>, Error> {
+        Ok(match map {
+            StorageMap::Allowances => self.allowances.get()?.capacity_alert(),
+            StorageMap::Balances => self.balances.get()?.capacity_alert(),
+        })
+    }
+
+    #[export(unwrap_result)]
+    pub fn circuit_breaker(&self) -> Result<CircuitBreakerConfig, Error> {
+        Ok(*self.balances.get()?.circuit_breaker().config())
+    }
+
+    /// Returns `true` if the program is paused, or its mints and transfers
+    /// are halted by the circuit breaker.
     #[export]
     pub fn is_paused(&self) -> bool {
         self.pause.is_paused()
+            || self
+                .balances
+                .get()
+                .is_ok_and(|balances| balances.circuit_breaker().is_tripped())
+    }
+
+    /// Returns names and IDs of the roles the service checks, so clients don't
+    /// need to derive them.
+    #[export]
+    pub fn known_roles(&self) -> Vec<(String, RoleId)> {
+        KNOWN_ROLES
+            .iter()
+            .map(|&(name, role_id)| (name.into(), role_id))
+            .collect()
+    }
+
+    #[export]
+    pub fn mint_rule(&self, id: u64) -> Option<MintRule> {
+        self.storage.get().mint_rule(id).copied()
+    }
+
+    /// Returns all recurring mint rules with their ids.
+    #[export]
+    pub fn mint_rules(&self) -> Vec<(u64, MintRule)> {
+        self.storage
+            .get()
+            .mint_rules()
+            .map(|(id, rule)| (id, *rule))
+            .collect()
+    }
+
+    /// Returns blocks of up to `len` remaining mints of the rule, with the total
+    /// amount still to be minted by it, or `None` if there's no such rule.
+    #[export]
+    pub fn mint_schedule(&self, id: u64, len: u32) -> Option<(Vec<u32>, U256)> {
+        self.storage
+            .get()
+            .mint_rule(id)
+            .map(|rule| (rule.schedule(len), rule.remaining_amount()))
+    }
+
+    #[export]
+    pub fn minter_cap(&self, minter: ActorId) -> Option<MinterCap> {
+        self.storage.get().minter_cap(minter).copied()
+    }
+
+    /// Returns the amount the minter may still mint in the current period,
+    /// or `None` if the minter is not capped.
+    #[export]
+    pub fn minter_remaining(&self, minter: ActorId) -> Option<U256> {
+        self.storage
+            .get()
+            .minter_cap(minter)
+            .map(|cap| cap.remaining(Syscall::block_height()))
+    }
+
+    /// Returns who paused the program, when and why, if it's paused.
+    #[export]
+    pub fn pause_info(&self) -> Option<PauseInfo> {
+        self.pause.info()
+    }
+
+    /// Derives the ID of the role named `name`, as `MINTER_ROLE` and others are derived.
+    #[export]
+    pub fn role_id(&self, name: String) -> RoleId {
+        role_id(&name)
+    }
+
+    /// Previews `burn` by the caller without mutating storage, e.g. for signers of
+    /// a multisig to review its effects, failing as `burn` would.
+    #[export(unwrap_result)]
+    #[require_role(BURNER_ROLE)]
+    pub fn simulate_burn(&self, from: ActorId, value: U256) -> Result<BurnPreview, Error> {
+        ensure!(!self.pause.is_paused(), PausedError);
+
+        let balances = self.balances.get()?;
+
+        let reaped = balances
+            .check_burn(from.try_into()?, Balance::try_from(value)?.try_into()?)?
+            .map(U256::from);
+
+        let balance = match reaped {
+            Some(_) => U256::ZERO,
+            None => U256::from(balances.get(from.try_into()?)) - value,
+        };
+
+        Ok(BurnPreview {
+            balance,
+            reaped,
+            total_supply: balances.total_supply() - value,
+        })
+    }
+
+    /// Resumes the program, cancelling the scheduled resume and resetting
+    /// the circuit breaker.
+    fn do_resume(&mut self) -> Result<(), Error> {
+        let resumed = self.pause.resume();
+
+        self.storage.get_mut().set_auto_resume_at(None);
+
+        // Volume which tripped the circuit breaker doesn't trip it again.
+        let reset = self.balances.get_mut()?.reset_circuit_breaker();
+
+        if resumed || reset {
+            self.emit_event(Event::Resumed).map_err(|_| EmitError)?;
+        }
+
+        self.audit("resume", ());
+
+        Ok(())
+    }
+
+    /// Sends the delayed `auto_resume` call to the program from a gas reservation,
+    /// returning the block it's scheduled at.
+    fn schedule_resume(&self, delay: u32) -> Result<u32, Error> {
+        let reservation_id = gstd::exec::reserve_gas(AUTO_RESUME_GAS, delay.saturating_add(1))
+            .map_err(|_| SendError::ReserveGas)?;
+
+        let payload = (AUTO_RESUME_ROUTE, "AutoResume").encode();
+
+        gstd::msg::send_bytes_delayed_from_reservation(
+            reservation_id,
+            Syscall::program_id(),
+            payload,
+            0,
+            delay,
+        )
+        .map_err(|_| SendError::DelayedMessage)?;
+
+        Ok(Syscall::block_height().saturating_add(delay))
     }
 }
 
@@ -286,10 +1105,81 @@ impl<
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum Event {
+    /// Kept for compatibility, followed by `Burned` carrying the details.
     BurnerTookPlace,
+    /// Kept for compatibility, followed by `Minted` carrying the details.
     MinterTookPlace,
+    /// Kept for compatibility, followed by `Burned` for each burn of the batch.
+    BatchBurnTookPlace,
+    ForceTransferTookPlace,
+    MinterCapSet {
+        minter: ActorId,
+        amount_per_period: U256,
+        period_blocks: u32,
+    },
+    MinterCapRemoved(ActorId),
+    MintRuleAdded {
+        id: u64,
+        rule: MintRule,
+    },
+    MintRuleRemoved(u64),
+    /// Due mint of the recurring mint rule was executed.
+    RecurringMint {
+        id: u64,
+        to: ActorId,
+        value: U256,
+        remaining_count: u32,
+    },
+    CircuitBreakerSet(CircuitBreakerConfig),
     ExpiryPeriodChanged(u32),
+    MinimumBalanceChanged(U256),
+    DustPolicyChanged(DustPolicy),
     Exited(ActorId),
-    Paused,
+    Paused(PauseInfo),
     Resumed,
+    MaxPageSizeChanged(u32),
+    CapacityAlertChanged {
+        map: StorageMap,
+        alert: Option<CapacityAlert>,
+    },
+    /// `by` minted `value` to `to` via `mint`.
+    Minted {
+        by: ActorId,
+        to: ActorId,
+        value: U256,
+    },
+    /// `by` burned `value` from `from` via `burn` or `burn_batch`.
+    Burned {
+        by: ActorId,
+        from: ActorId,
+        value: U256,
+    },
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadOrigin, EmitError, Error, SendError};
+    use sails_rs::{
+        ActorId, U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Minter cap exceeded: minter {minter:?} may mint at most {remaining} in this period")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct MinterCapExceeded {
+        pub minter: ActorId,
+        pub remaining: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(MinterCapExceeded => 300);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Too many recurring mint rules")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct MintRulesFull;
+
+    awesome_sails_utils::impl_error_code!(MintRulesFull => 301);
 }
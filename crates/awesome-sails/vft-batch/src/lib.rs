@@ -0,0 +1,146 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome VFT-Batch service.
+//!
+//! This service executes a sequence of VFT and VFT-Admin calls within a single message,
+//! so front-ends can bundle flows like approve + transfer_from.
+//!
+//! Calls are executed on behalf of the message source, with the same checks as if sent
+//! separately. The batch is all-or-nothing: the first failing call fails the whole message,
+//! reverting the changes of the calls executed before it.
+//!
+//! `batch` is excluded from `typed-errors`: it never replies with `Err` and fails by panicking
+//! instead, so the calls executed before the failing one are always reverted.
+
+#![no_std]
+
+use awesome_sails_access_control::RolesStorage;
+use awesome_sails_utils::{
+    ensure,
+    error::{BadInput, Error},
+    multicall::MAX_BATCH_LEN,
+    storage::{InfallibleStorageMut, StorageMut},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use awesome_sails_vft_admin::{self as vft_admin, AdminStorage};
+use sails_rs::prelude::*;
+
+/// Call of a VFT or VFT-Admin command.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Call {
+    Approve {
+        spender: ActorId,
+        value: U256,
+    },
+    Transfer {
+        to: ActorId,
+        value: U256,
+    },
+    TransferFrom {
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    },
+    Mint {
+        to: ActorId,
+        value: U256,
+    },
+    Burn {
+        from: ActorId,
+        value: U256,
+    },
+}
+
+/// Awesome VFT-Batch service itself.
+pub struct VftBatch<'a, ACS, A, B, S>
+where
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+{
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+}
+
+impl<'a, ACS, A, B, S> VftBatch<'a, ACS, A, B, S>
+where
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+        vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+    ) -> Self {
+        Self { vft, vft_admin }
+    }
+}
+
+#[cfg_attr(
+    feature = "typed-errors",
+    awesome_sails_macros::typed_errors(skip(batch))
+)]
+#[service]
+impl<'a, ACS, A, B, S> VftBatch<'a, ACS, A, B, S>
+where
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+{
+    /// Executes `calls` in order, failing on the first failing call.
+    ///
+    /// Returns the number of executed calls.
+    ///
+    /// Requirements:
+    ///
+    /// - there must be at most `MAX_BATCH_LEN` calls.
+    #[export(unwrap_result)]
+    pub fn batch(&mut self, calls: Vec<Call>) -> Result<u32, Error> {
+        ensure!(calls.len() <= MAX_BATCH_LEN, BadInput);
+
+        let len = calls.len() as u32;
+
+        for call in calls {
+            match call {
+                Call::Approve { spender, value } => {
+                    self.vft.approve(spender, value)?;
+                }
+                Call::Transfer { to, value } => {
+                    self.vft.transfer(to, value)?;
+                }
+                Call::TransferFrom { from, to, value } => {
+                    self.vft.transfer_from(from, to, value)?;
+                }
+                Call::Mint { to, value } => self.vft_admin.mint(to, value)?,
+                Call::Burn { from, value } => self.vft_admin.burn(from, value)?,
+            }
+        }
+
+        Ok(len)
+    }
+}
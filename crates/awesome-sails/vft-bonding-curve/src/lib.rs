@@ -0,0 +1,516 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome VFT-BondingCurve service.
+//!
+//! This service mints VFTs against native value and burns them back for a refund,
+//! priced along a [`Curve`] of the supply issued through the curve. Paid value is kept
+//! in the reserve, so every token issued by the curve may be sold back to it.
+//!
+//! Accounts sell back at most the amount they bought through the curve, so tokens
+//! issued otherwise, e.g. minted by admins, don't drain the reserve.
+//!
+//! The curve is set by the super admin while no tokens are issued through it.
+
+#![no_std]
+
+use awesome_sails_access_control::{self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure,
+    error::{BadInput, BadValue, EmitError, Error},
+    math::{OverflowError, UnderflowError},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balance, Balances},
+};
+use error::{CurveInUse, CurveNotSet, SellExceedsIssued, SlippageExceeded};
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+/// Scale of prices: a price of `PRICE_SCALE` is one unit of native value per token.
+pub const PRICE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Basis points of the price, used for growth.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Price of a token depending on the supply issued through the curve.
+///
+/// Prices are given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Curve {
+    /// Price increasing by `slope` every [`PRICE_SCALE`] tokens of supply.
+    Linear { base_price: U256, slope: U256 },
+    /// Price increasing by `growth_bps` every `step` tokens of supply, compounded.
+    Exponential {
+        base_price: U256,
+        growth_bps: u16,
+        step: U256,
+    },
+}
+
+impl Curve {
+    /// Returns `true` if the curve prices every supply.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Linear { .. } => true,
+            Self::Exponential { step, .. } => !step.is_zero(),
+        }
+    }
+
+    /// Returns the price of the next token at the given supply.
+    pub fn spot_price(&self, supply: U256) -> Option<U256> {
+        let scale = U256::from(PRICE_SCALE);
+
+        match *self {
+            Self::Linear { base_price, slope } => {
+                base_price.checked_add(slope.checked_mul(supply)? / scale)
+            }
+            Self::Exponential {
+                base_price,
+                growth_bps,
+                step,
+            } => Some(base_price.checked_mul(growth(growth_bps, supply / step)?)? / scale),
+        }
+    }
+
+    /// Returns native value paid for `amount` tokens issued atop of `supply`, rounded up.
+    pub fn buy_cost(&self, supply: U256, amount: U256) -> Option<u128> {
+        let cost = self
+            .integral(supply.checked_add(amount)?)?
+            .saturating_sub(self.integral(supply)?);
+
+        let (cost, rem) = cost.div_mod(PRICE_SCALE.into());
+
+        u128::try_from(cost + U256::from(!rem.is_zero() as u8)).ok()
+    }
+
+    /// Returns native value refunded for `amount` tokens taken from `supply`, rounded down.
+    pub fn sell_refund(&self, supply: U256, amount: U256) -> Option<u128> {
+        let refund = self
+            .integral(supply)?
+            .saturating_sub(self.integral(supply.checked_sub(amount)?)?);
+
+        u128::try_from(refund / U256::from(PRICE_SCALE)).ok()
+    }
+
+    /// Returns the total price of the first `supply` tokens, scaled by [`PRICE_SCALE`].
+    fn integral(&self, supply: U256) -> Option<U256> {
+        let scale = U256::from(PRICE_SCALE);
+
+        match *self {
+            Self::Linear { base_price, slope } => base_price
+                .checked_mul(supply)?
+                .checked_add(slope.checked_mul(supply)?.checked_mul(supply)? / (scale * 2)),
+            Self::Exponential {
+                base_price,
+                growth_bps,
+                step,
+            } => {
+                if growth_bps == 0 {
+                    return base_price.checked_mul(supply);
+                }
+
+                let (steps, rem) = supply.div_mod(step);
+                let growth = growth(growth_bps, steps)?;
+
+                // Sum of the growth over the passed steps: `(growth - 1) / (rate - 1)`.
+                let growth_sum = (growth - scale) * U256::from(MAX_BPS) / U256::from(growth_bps);
+
+                base_price
+                    .checked_mul(step)?
+                    .checked_mul(growth_sum)?
+                    .checked_div(scale)?
+                    .checked_add(base_price.checked_mul(rem)?.checked_mul(growth)? / scale)
+            }
+        }
+    }
+}
+
+/// Returns `(1 + growth_bps / MAX_BPS) ^ exp`, scaled by [`PRICE_SCALE`].
+fn growth(growth_bps: u16, mut exp: U256) -> Option<U256> {
+    let scale = U256::from(PRICE_SCALE);
+
+    let mut base = scale * (U256::from(MAX_BPS) + U256::from(growth_bps)) / U256::from(MAX_BPS);
+    let mut acc = scale;
+
+    while !exp.is_zero() {
+        if exp.bit(0) {
+            acc = acc.checked_mul(base)? / scale;
+        }
+
+        exp >>= 1;
+
+        if !exp.is_zero() {
+            base = base.checked_mul(base)? / scale;
+        }
+    }
+
+    Some(acc)
+}
+
+/// State of [`VftBondingCurve`].
+#[derive(Default, Debug)]
+pub struct BondingCurve {
+    curve: Option<Curve>,
+    supply: U256,
+    reserve: u128,
+    issued: BTreeMap<ActorId, U256>,
+}
+
+impl BondingCurve {
+    pub fn curve(&self) -> Option<&Curve> {
+        self.curve.as_ref()
+    }
+
+    /// Replaces the curve, failing if any tokens are issued through the current one.
+    pub fn set_curve(&mut self, curve: Curve) -> Result<(), CurveInUse> {
+        ensure!(self.supply.is_zero(), CurveInUse);
+
+        self.curve = Some(curve);
+
+        Ok(())
+    }
+
+    /// Supply issued through the curve.
+    pub fn supply(&self) -> U256 {
+        self.supply
+    }
+
+    /// Native value paid for the issued supply.
+    pub fn reserve(&self) -> u128 {
+        self.reserve
+    }
+
+    /// Amount bought through the curve by the account and not sold back yet.
+    pub fn issued_to(&self, account: &ActorId) -> U256 {
+        self.issued.get(account).copied().unwrap_or_default()
+    }
+
+    pub fn spot_price(&self) -> Option<U256> {
+        self.curve?.spot_price(self.supply)
+    }
+
+    pub fn quote_buy(&self, amount: U256) -> Option<u128> {
+        self.curve?.buy_cost(self.supply, amount)
+    }
+
+    pub fn quote_sell(&self, amount: U256) -> Option<u128> {
+        self.curve?.sell_refund(self.supply, amount)
+    }
+
+    /// Issues `amount` tokens to the buyer, returning their cost added to the reserve.
+    pub fn buy(&mut self, buyer: ActorId, amount: U256) -> Result<u128, Error> {
+        let curve = self.curve.ok_or(CurveNotSet)?;
+        let cost = curve.buy_cost(self.supply, amount).ok_or(OverflowError)?;
+
+        self.reserve = self.reserve.checked_add(cost).ok_or(OverflowError)?;
+        self.supply += amount;
+
+        *self.issued.entry(buyer).or_default() += amount;
+
+        Ok(cost)
+    }
+
+    /// Takes back `amount` tokens issued to the seller, returning their refund
+    /// taken from the reserve.
+    pub fn sell(&mut self, seller: ActorId, amount: U256) -> Result<u128, Error> {
+        let curve = self.curve.ok_or(CurveNotSet)?;
+
+        let issued = self.issued_to(&seller);
+
+        ensure!(amount <= issued, SellExceedsIssued { issued });
+        ensure!(amount <= self.supply, UnderflowError);
+
+        let refund = curve
+            .sell_refund(self.supply, amount)
+            .ok_or(OverflowError)?;
+
+        self.reserve = self.reserve.checked_sub(refund).ok_or(UnderflowError)?;
+        self.supply -= amount;
+
+        if issued == amount {
+            self.issued.remove(&seller);
+        } else {
+            self.issued.insert(seller, issued - amount);
+        }
+
+        Ok(refund)
+    }
+}
+
+/// Awesome VFT-BondingCurve service itself.
+pub struct VftBondingCurve<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    C: InfallibleStorageMut<Item = BondingCurve> = StorageRefCell<'a, BondingCurve>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    balances: B,
+    storage: C,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    C: InfallibleStorageMut<Item = BondingCurve>,
+> VftBondingCurve<'a, ACS, A, B, C>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        balances: B,
+        storage: C,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            access_control,
+            balances,
+            storage,
+            vft,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    C: InfallibleStorageMut<Item = BondingCurve>,
+> VftBondingCurve<'a, ACS, A, B, C>
+{
+    /// Mints `amount` tokens to the message source for their price along the curve.
+    ///
+    /// Message value must cover the price, with the rest attached to the reply.
+    #[export(unwrap_result)]
+    pub fn buy(&mut self, amount: U256) -> Result<CommandReply<()>, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let attached = Syscall::message_value();
+        let to = Syscall::message_source();
+
+        let cost = self.storage.get_mut().buy(to, amount)?;
+
+        ensure!(attached >= cost, BadValue);
+
+        {
+            let mut balances = self.balances.get_mut()?;
+
+            balances.mint(to.try_into()?, Balance::try_from(amount)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+        }
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from: ActorId::zero(),
+                to,
+                value: amount,
+            })
+            .map_err(|_| EmitError)?;
+
+        self.emit_event(Event::Bought {
+            buyer: to,
+            amount,
+            cost,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(()).with_value(attached - cost))
+    }
+
+    /// Burns `amount` tokens of the message source, attaching their refund to the reply.
+    ///
+    /// Fails if the refund is below `min_refund`, or if the message source bought
+    /// less than `amount` through the curve.
+    #[export(unwrap_result)]
+    pub fn sell(&mut self, amount: U256, min_refund: u128) -> Result<CommandReply<u128>, Error> {
+        ensure!(!amount.is_zero(), BadInput);
+
+        let from = Syscall::message_source();
+
+        let refund = self.storage.get_mut().sell(from, amount)?;
+
+        ensure!(refund >= min_refund, SlippageExceeded { refund });
+
+        let reaped = {
+            let mut balances = self.balances.get_mut()?;
+
+            let reaped = balances.burn(from.try_into()?, Balance::try_from(amount)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+
+            reaped
+        };
+
+        self.vft
+            .emit_event(vft::Event::Transfer {
+                from,
+                to: ActorId::zero(),
+                value: amount,
+            })
+            .map_err(|_| EmitError)?;
+
+        if let Some(dust) = reaped {
+            self.vft
+                .emit_event(vft::Event::AccountReaped {
+                    account: from,
+                    dust: dust.into(),
+                })
+                .map_err(|_| EmitError)?;
+        }
+
+        self.emit_event(Event::Sold {
+            seller: from,
+            amount,
+            refund,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(CommandReply::new(refund).with_value(refund))
+    }
+
+    /// Sets the curve, while no tokens are issued through the current one.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_curve(&mut self, curve: Curve) -> Result<(), Error> {
+        ensure!(curve.is_valid(), BadInput);
+
+        self.storage.get_mut().set_curve(curve)?;
+
+        self.emit_event(Event::CurveSet(curve))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn curve(&self) -> Option<Curve> {
+        self.storage.get().curve().copied()
+    }
+
+    /// Returns native value to be paid for `amount` tokens.
+    #[export]
+    pub fn quote_buy(&self, amount: U256) -> Option<u128> {
+        self.storage.get().quote_buy(amount)
+    }
+
+    /// Returns native value to be refunded for `amount` tokens.
+    #[export]
+    pub fn quote_sell(&self, amount: U256) -> Option<u128> {
+        self.storage.get().quote_sell(amount)
+    }
+
+    /// Returns amount bought through the curve by the account,
+    /// which it may sell back.
+    #[export]
+    pub fn issued_to(&self, account: ActorId) -> U256 {
+        self.storage.get().issued_to(&account)
+    }
+
+    #[export]
+    pub fn reserve(&self) -> u128 {
+        self.storage.get().reserve()
+    }
+
+    /// Returns the price of the next token, scaled by [`PRICE_SCALE`].
+    #[export]
+    pub fn spot_price(&self) -> Option<U256> {
+        self.storage.get().spot_price()
+    }
+
+    #[export]
+    pub fn supply(&self) -> U256 {
+        self.storage.get().supply()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    Bought {
+        buyer: ActorId,
+        amount: U256,
+        cost: u128,
+    },
+    Sold {
+        seller: ActorId,
+        amount: U256,
+        refund: u128,
+    },
+    CurveSet(Curve),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{BadInput, BadValue, EmitError, Error};
+    use sails_rs::{
+        U256,
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Slippage exceeded: refund is {refund}")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct SlippageExceeded {
+        pub refund: u128,
+    }
+
+    awesome_sails_utils::impl_error_code!(SlippageExceeded => 700);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Curve is in use")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct CurveInUse;
+
+    awesome_sails_utils::impl_error_code!(CurveInUse => 701);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Curve is not set")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct CurveNotSet;
+
+    awesome_sails_utils::impl_error_code!(CurveNotSet => 702);
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Sell exceeds issued: at most {issued} may be sold back")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct SellExceedsIssued {
+        pub issued: U256,
+    }
+
+    awesome_sails_utils::impl_error_code!(SellExceedsIssued => 703);
+}
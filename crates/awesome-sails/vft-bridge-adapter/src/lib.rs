@@ -0,0 +1,321 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome VFT-Bridge-Adapter service.
+//!
+//! This service exposes the entry points the VFT manager of the Vara ↔ Ethereum
+//! bridge calls on bridged tokens: `Burn(from, value) -> bool` when tokens are locked
+//! for Ethereum and `Mint(to, value) -> bool` when their lock on Ethereum is proven.
+//! Mount it under the route the VFT manager is configured with.
+//!
+//! Calls are executed through [`VftAdmin`](vft_admin::VftAdmin), so the manager must
+//! hold [`MINTER_ROLE`](vft_admin::MINTER_ROLE) and [`BURNER_ROLE`](vft_admin::BURNER_ROLE),
+//! with its minter cap applied.
+//!
+//! Minters processing remote events themselves use `mint_remote`, which mints
+//! every remote event once, keeping ids of processed events.
+//!
+//! Admins reconcile the local total supply with the amount locked on the remote
+//! chain by `reconcile`, reporting divergence beyond the threshold and, optionally,
+//! pausing the program.
+
+#![no_std]
+
+use crate::error::{AlreadyProcessed, EmitError, Error};
+use awesome_sails_access_control::{self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    ensure, ok_if,
+    pause::{Pause, PauseInfo, PauseReason},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::{
+    self as vft,
+    utils::{Allowances, Balances},
+};
+use awesome_sails_vft_admin::{self as vft_admin, AdminStorage};
+use sails_rs::{collections::BTreeSet, prelude::*};
+
+/// Parameters of supply reconciliation.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Reconciliation {
+    /// Divergence of the local supply from the remote locked amount tolerated.
+    pub threshold: U256,
+    /// Whether divergence beyond the threshold pauses the program.
+    pub auto_pause: bool,
+}
+
+/// Local total supply compared against the remote locked amount.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SupplyReport {
+    pub local_supply: U256,
+    pub remote_locked: U256,
+    pub divergence: U256,
+    pub reported_at: u32,
+}
+
+/// Bridge-side state of [`VftBridgeAdapter`].
+#[derive(Default, Debug)]
+pub struct BridgeStorage {
+    /// Ids of processed remote events, e.g. hashes of the transaction and log index.
+    processed: BTreeSet<H256>,
+    reconciliation: Reconciliation,
+    last_report: Option<SupplyReport>,
+}
+
+impl BridgeStorage {
+    pub fn is_processed(&self, event_id: &H256) -> bool {
+        self.processed.contains(event_id)
+    }
+
+    /// Marks the event as processed, failing if it already was.
+    pub fn process(&mut self, event_id: H256) -> Result<(), AlreadyProcessed> {
+        ensure!(self.processed.insert(event_id), AlreadyProcessed);
+
+        Ok(())
+    }
+
+    pub fn reconciliation(&self) -> &Reconciliation {
+        &self.reconciliation
+    }
+
+    pub fn set_reconciliation(&mut self, reconciliation: Reconciliation) {
+        self.reconciliation = reconciliation;
+    }
+
+    pub fn last_report(&self) -> Option<&SupplyReport> {
+        self.last_report.as_ref()
+    }
+
+    /// Records the report, returning `true` if its divergence exceeds the threshold.
+    pub fn report(&mut self, report: SupplyReport) -> bool {
+        self.last_report = Some(report);
+
+        report.divergence > self.reconciliation.threshold
+    }
+}
+
+/// Awesome VFT-Bridge-Adapter service itself.
+pub struct VftBridgeAdapter<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    P: InfallibleStorageMut<Item = BridgeStorage> = StorageRefCell<'a, BridgeStorage>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    pause: &'a Pause,
+    storage: P,
+    vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    P: InfallibleStorageMut<Item = BridgeStorage>,
+> VftBridgeAdapter<'a, ACS, A, B, S, P>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        pause: &'a Pause,
+        storage: P,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+        vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+    ) -> Self {
+        Self {
+            access_control,
+            pause,
+            storage,
+            vft,
+            vft_admin,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    P: InfallibleStorageMut<Item = BridgeStorage>,
+> VftBridgeAdapter<'a, ACS, A, B, S, P>
+{
+    /// Burns VFTs locked for the remote chain.
+    ///
+    /// Requires [`BURNER_ROLE`](vft_admin::BURNER_ROLE).
+    #[export(unwrap_result)]
+    pub fn burn(&mut self, from: ActorId, value: U256) -> Result<bool, Error> {
+        ok_if!(value.is_zero(), false);
+
+        self.vft_admin.burn(from, value)?;
+
+        Ok(true)
+    }
+
+    /// Mints VFTs whose lock on the remote chain is proven.
+    ///
+    /// Requires [`MINTER_ROLE`](vft_admin::MINTER_ROLE).
+    #[export(unwrap_result)]
+    pub fn mint(&mut self, to: ActorId, value: U256) -> Result<bool, Error> {
+        ok_if!(value.is_zero(), false);
+
+        self.vft_admin.mint(to, value)?;
+
+        Ok(true)
+    }
+
+    /// Mints VFTs for the remote event, failing if it was already processed.
+    ///
+    /// Requires [`MINTER_ROLE`](vft_admin::MINTER_ROLE).
+    #[export(unwrap_result)]
+    pub fn mint_remote(&mut self, event_id: H256, to: ActorId, value: U256) -> Result<(), Error> {
+        self.storage.get_mut().process(event_id)?;
+
+        self.vft_admin.mint(to, value)?;
+
+        self.emit_event(Event::RemoteEventProcessed {
+            event_id,
+            to,
+            value,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Compares the local total supply against the amount locked on the remote chain,
+    /// reporting divergence beyond the threshold and pausing the program if configured.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn reconcile(&mut self, remote_locked: U256) -> Result<SupplyReport, Error> {
+        let local_supply = self.vft.total_supply()?;
+
+        let report = SupplyReport {
+            local_supply,
+            remote_locked,
+            divergence: local_supply.abs_diff(remote_locked),
+            reported_at: Syscall::block_height(),
+        };
+
+        let mismatch = self.storage.get_mut().report(report);
+
+        ok_if!(!mismatch, report);
+
+        let info = PauseInfo {
+            paused_by: Syscall::program_id(),
+            paused_at: report.reported_at,
+            reason: Some(PauseReason::Message("supply mismatch".into())),
+        };
+
+        let paused =
+            self.storage.get().reconciliation().auto_pause && self.pause.pause_with(info.clone());
+
+        if paused {
+            self.vft_admin
+                .emit_event(vft_admin::Event::Paused(info))
+                .map_err(|_| EmitError)?;
+        }
+
+        self.emit_event(Event::SupplyMismatch {
+            local_supply,
+            remote_locked,
+            divergence: report.divergence,
+            paused,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(report)
+    }
+
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_reconciliation(&mut self, reconciliation: Reconciliation) -> Result<(), Error> {
+        self.storage.get_mut().set_reconciliation(reconciliation);
+
+        self.emit_event(Event::ReconciliationSet(reconciliation))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    #[export]
+    pub fn is_processed(&self, event_id: H256) -> bool {
+        self.storage.get().is_processed(&event_id)
+    }
+
+    #[export]
+    pub fn last_report(&self) -> Option<SupplyReport> {
+        self.storage.get().last_report().copied()
+    }
+
+    #[export]
+    pub fn reconciliation(&self) -> Reconciliation {
+        *self.storage.get().reconciliation()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    RemoteEventProcessed {
+        event_id: H256,
+        to: ActorId,
+        value: U256,
+    },
+    /// Local supply diverged from the remote locked amount beyond the threshold.
+    SupplyMismatch {
+        local_supply: U256,
+        remote_locked: U256,
+        divergence: U256,
+        paused: bool,
+    },
+    ReconciliationSet(Reconciliation),
+}
+
+pub mod error {
+    pub use awesome_sails_utils::error::{EmitError, Error};
+    use sails_rs::{
+        scale_codec::{Decode, Encode},
+        scale_info::TypeInfo,
+    };
+
+    #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+    #[codec(crate = sails_rs::scale_codec)]
+    #[error("Remote event is already processed")]
+    #[scale_info(crate = sails_rs::scale_info)]
+    pub struct AlreadyProcessed;
+
+    awesome_sails_utils::impl_error_code!(AlreadyProcessed => 1200);
+}
@@ -19,47 +19,142 @@
 //! Awesome VFT-Extension service.
 //!
 //! This service extends default VFT functionality with additional methods.
+//!
+//! Approvals made by `approve_and_notify` are pushed to spender programs by
+//! [`approval_notification`] messages, e.g. for vaults to deposit once approved.
+//!
+//! Transfers made by `transfer_claimable` are escrowed by the program until
+//! the recipient claims them, so value sent to a wrong address is reclaimed
+//! by the sender once the claim expires.
+//!
+//! Snapshots of holders are computed by `compute_snapshot` in chunks across
+//! messages, committing balances as of a block by the merkle root of them
+//! (see [`SnapshotRoot`]), e.g. to verify airdrops built off-chain against.
+//!
+//! Exports enumerating whole storages (`allowances*`, `balances`, `balances_compact`
+//! and `balances_from`) require `VIEWER_ROLE` once the viewer gate of the access
+//! control service is enabled.
 
 #![no_std]
 
+use awesome_sails_access_control::{self as access_control, RolesStorage};
 use awesome_sails_utils::{
     ensure,
-    error::{EmitError, Error},
+    error::{BadInput, BadOrigin, EmitError, Error, SendError},
+    map::MapCursor,
     math::{Max, NonZero, Zero},
+    multicall::multicall,
     ok_if,
+    page::{self, Page},
     pause::PausableRef,
-    storage::StorageMut,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
 };
 use awesome_sails_vft::{
     self as vft,
-    utils::{Allowances, Balance, Balances},
+    utils::{
+        Allowance, Allowances, Balance, Balances, Claim, DustPolicy, SnapshotRoot, SpendingCap,
+    },
 };
-use sails_rs::prelude::*;
+use sails_rs::{gstd, prelude::*};
+
+/// Allowance exported as `((owner, spender), (value, expiry))`.
+pub type AllowanceEntry<T = U256> = ((ActorId, ActorId), (T, u32));
+
+/// Allowance of an owner or to a spender exported as `(counterparty, (value, expiry))`.
+pub type CounterpartyAllowance = (ActorId, (U256, u32));
+
+/// Route of the notification of spenders about approvals.
+pub const APPROVAL_NOTIFICATION_ROUTE: (&str, &str) = ("VftSpender", "OnApproval");
+
+/// Returns the payload of the message notifying the spender that `owner`
+/// approved it `value` of VFTs of the program sending the message.
+pub fn approval_notification(owner: ActorId, value: U256) -> Vec<u8> {
+    let (service, method) = APPROVAL_NOTIFICATION_ROUTE;
+
+    (service, method, owner, value).encode()
+}
 
 /// Awesome VFT-Extension service itself.
 pub struct VftExtension<
     'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage> = StorageRefCell<'a, RolesStorage>,
     A: StorageMut<Item = Allowances> = PausableRef<'a, Allowances>,
     B: StorageMut<Item = Balances> = PausableRef<'a, Balances>,
 > {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
     allowances: A,
     balances: B,
     vft: vft::VftExposure<vft::Vft<'a, A, B>>,
 }
 
-impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtension<'a, A, B> {
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+> VftExtension<'a, ACS, A, B>
+{
     /// Constructor for [`Self`].
-    pub fn new(allowances: A, balances: B, vft: vft::VftExposure<vft::Vft<'a, A, B>>) -> Self {
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        allowances: A,
+        balances: B,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
         Self {
+            access_control,
             allowances,
             balances,
             vft,
         }
     }
+
+    /// Ensures that a page of `len` items doesn't exceed the max page size.
+    fn ensure_page_size(&self, len: u32) -> Result<(), Error> {
+        let max_page_size = self.balances.get()?.max_page_size();
+
+        Ok(page::ensure_page_size(len, max_page_size)?)
+    }
+
+    /// Ensures that the caller may export a page of `len` items of a whole storage.
+    fn ensure_export(&self, len: u32) -> Result<(), Error> {
+        self.access_control
+            .require_viewer(Syscall::message_source())?;
+
+        self.ensure_page_size(len)
+    }
+
+    /// Pays out the escrowed value of the claim to the account, removing the claim.
+    fn settle(&mut self, id: u64, to: ActorId) -> Result<(), Error> {
+        let value = self
+            .balances
+            .get_mut()?
+            .claims_mut()
+            .remove(id)
+            .ok_or(BadInput)?
+            .value;
+
+        self.vft
+            .transfer_escrowed(Syscall::program_id(), to, value)?;
+
+        self.vft
+            .emit_event(vft::Event::ClaimSettled { id, to, value })
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service]
-impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtension<'_, A, B> {
+impl<
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+> VftExtension<'_, ACS, A, B>
+{
     #[export(unwrap_result)]
     pub fn allocate_next_allowances_shard(&mut self) -> Result<bool, Error> {
         Ok(self.allowances.get_mut()?.allocate_next_shard())
@@ -70,6 +165,114 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
         Ok(self.balances.get_mut()?.allocate_next_shard())
     }
 
+    /// Approves `value` to the spender as `Vft::approve` does, notifying the spender
+    /// by the [`approval_notification`] message if the allowance changed.
+    #[export(unwrap_result)]
+    pub fn approve_and_notify(&mut self, spender: ActorId, value: U256) -> Result<bool, Error> {
+        let owner = Syscall::message_source();
+
+        let changed = self.vft.approve(spender, value)?;
+
+        ok_if!(!changed, false);
+
+        let value = self.vft.allowance(owner, spender)?;
+
+        gstd::msg::send_bytes(spender, approval_notification(owner, value), 0)
+            .map_err(|_| SendError::Notify)?;
+
+        Ok(true)
+    }
+
+    /// Gives the spender a cap of `amount_per_period` renewed every `period_blocks`,
+    /// spent by its transfers from the message source instead of the allowance.
+    /// Zero `amount_per_period` removes the cap.
+    ///
+    /// Returns `true` if the cap changed.
+    #[export(unwrap_result)]
+    pub fn approve_with_cap(
+        &mut self,
+        spender: ActorId,
+        amount_per_period: U256,
+        period_blocks: u32,
+    ) -> Result<bool, Error> {
+        let owner = Syscall::message_source();
+
+        ok_if!(owner == spender, false);
+
+        {
+            let (_owner, _spender) = (owner.try_into()?, spender.try_into()?);
+            let mut allowances = self.allowances.get_mut()?;
+
+            // Caps on the same terms are kept, not to renew the spent amount.
+            let terms = |cap: &SpendingCap| (cap.amount_per_period, cap.period_blocks);
+            let unchanged = allowances.cap(_owner, _spender).map(terms)
+                == (!amount_per_period.is_zero()).then_some((amount_per_period, period_blocks));
+
+            ok_if!(unchanged, false);
+
+            let cap = if amount_per_period.is_zero() {
+                None
+            } else {
+                let cap =
+                    SpendingCap::new(amount_per_period, period_blocks, Syscall::block_height())
+                        .ok_or(BadInput)?;
+
+                Some(cap)
+            };
+
+            allowances.set_cap(_owner, _spender, cap);
+        }
+
+        self.vft
+            .emit_event(vft::Event::SpendingCapSet {
+                owner,
+                spender,
+                amount_per_period,
+                period_blocks,
+            })
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    /// Claims the escrowed transfer sent to the message source.
+    ///
+    /// Returns `false` if the claim isn't pending.
+    #[export(unwrap_result)]
+    pub fn claim(&mut self, id: u64) -> Result<bool, Error> {
+        let recipient = Syscall::message_source();
+
+        let Some(claim) = self.balances.get()?.claims().get(id).cloned() else {
+            return Ok(false);
+        };
+
+        ensure!(claim.recipient == recipient, BadOrigin);
+
+        self.inner.settle(id, recipient)?;
+
+        Ok(true)
+    }
+
+    /// Reclaims the expired escrowed transfer sent by the message source.
+    ///
+    /// Returns `false` if the claim isn't pending or expired yet.
+    #[export(unwrap_result)]
+    pub fn reclaim(&mut self, id: u64) -> Result<bool, Error> {
+        let sender = Syscall::message_source();
+
+        let Some(claim) = self.balances.get()?.claims().get(id).cloned() else {
+            return Ok(false);
+        };
+
+        ensure!(claim.sender == sender, BadOrigin);
+
+        ok_if!(!claim.is_expired(Syscall::block_height()), false);
+
+        self.inner.settle(id, sender)?;
+
+        Ok(true)
+    }
+
     #[export(unwrap_result)]
     pub fn remove_expired_allowance(
         &mut self,
@@ -105,6 +308,67 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
         Ok(true)
     }
 
+    /// Hashes up to `len` more holders into the merkle root of balances being
+    /// snapshotted, starting the snapshot at the current block if there's none.
+    /// Balances changed meanwhile are hashed as of the block of the snapshot.
+    ///
+    /// Anyone may continue the snapshot, until it's done in as many messages
+    /// as needed. Once all holders are hashed, emits a `SnapshotTaken` event
+    /// and returns the snapshot, kept as the latest one.
+    #[export(unwrap_result)]
+    pub fn compute_snapshot(&mut self, len: u32) -> Result<Option<SnapshotRoot>, Error> {
+        self.ensure_page_size(len)?;
+
+        let snapshot = self
+            .balances
+            .get_mut()?
+            .continue_snapshot(len, Syscall::block_height());
+
+        if let Some(SnapshotRoot {
+            block,
+            root,
+            holders,
+        }) = snapshot
+        {
+            self.vft
+                .emit_event(vft::Event::SnapshotTaken {
+                    block,
+                    root,
+                    holders,
+                })
+                .map_err(|_| EmitError)?;
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Approves or revokes the operator of the message source, transferring
+    /// any value of it without allowances.
+    ///
+    /// Returns `true` if the operator changed.
+    #[export(unwrap_result)]
+    pub fn set_operator(&mut self, operator: ActorId, approved: bool) -> Result<bool, Error> {
+        let owner = Syscall::message_source();
+
+        let changed = self.allowances.get_mut()?.set_operator(
+            owner.try_into()?,
+            operator.try_into()?,
+            approved,
+        );
+
+        ok_if!(!changed, false);
+
+        self.vft
+            .emit_event(vft::Event::OperatorSet {
+                owner,
+                operator,
+                approved,
+            })
+            .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
     #[export(unwrap_result)]
     pub fn transfer_all(&mut self, to: ActorId) -> Result<bool, Error> {
         let from = Syscall::message_source();
@@ -123,6 +387,13 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
             .emit_event(vft::Event::Transfer { from, to, value })
             .map_err(|_| EmitError)?;
 
+        self.vft
+            .emit_event(vft::Event::AccountReaped {
+                account: from,
+                dust: U256::zero(),
+            })
+            .map_err(|_| EmitError)?;
+
         Ok(true)
     }
 
@@ -146,7 +417,7 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
 
         let _value = <NonZero<Balance>>::try_from(value)?;
 
-        self.allowances.get_mut()?.decrease(
+        self.allowances.get_mut()?.spend(
             _from,
             _spender,
             _value.non_zero_cast(),
@@ -161,9 +432,59 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
             })
             .map_err(|_| EmitError)?;
 
+        self.vft
+            .emit_event(vft::Event::AccountReaped {
+                account: from,
+                dust: U256::zero(),
+            })
+            .map_err(|_| EmitError)?;
+
         Ok(true)
     }
 
+    /// Escrows `value` of the message source for the recipient to claim,
+    /// reclaimable by the message source after `timeout_blocks`.
+    ///
+    /// Returns the id of the claim.
+    #[export(unwrap_result)]
+    pub fn transfer_claimable(
+        &mut self,
+        to: ActorId,
+        value: U256,
+        timeout_blocks: u32,
+    ) -> Result<u64, Error> {
+        let from = Syscall::message_source();
+        let program = Syscall::program_id();
+
+        ensure!(
+            !to.is_zero() && to != from && to != program && !value.is_zero() && timeout_blocks != 0,
+            BadInput
+        );
+
+        let expires_at = Syscall::block_height().saturating_add(timeout_blocks);
+
+        self.vft.transfer_escrowed(from, program, value)?;
+
+        let id = self.balances.get_mut()?.claims_mut().insert(Claim {
+            sender: from,
+            recipient: to,
+            value,
+            expires_at,
+        });
+
+        self.vft
+            .emit_event(vft::Event::ClaimCreated {
+                id,
+                from,
+                to,
+                value,
+                expires_at,
+            })
+            .map_err(|_| EmitError)?;
+
+        Ok(id)
+    }
+
     #[export(unwrap_result)]
     pub fn allowance_of(
         &self,
@@ -172,20 +493,51 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
     ) -> Result<Option<(U256, u32)>, Error> {
         Ok((**self.allowances.get()?)
             .get(&(owner.try_into()?, spender.try_into()?))
-            .map(|(_, &(v, b))| {
-                let approval = if v.is_max() { U256::MAX } else { (*v).into() };
+            .map(|(_, (v, b))| (approval(v), *b)))
+    }
+
+    /// Returns allowances of each of the `(owner, spender)` pairs, as `Vft::allowance` does.
+    #[export(unwrap_result)]
+    pub fn allowances_of(&self, pairs: Vec<(ActorId, ActorId)>) -> Result<Vec<U256>, Error> {
+        let allowances = self.allowances.get()?;
 
-                (approval, b)
-            }))
+        multicall(pairs, |(owner, spender)| {
+            let allowance = allowances.get(owner.try_into()?, spender.try_into()?);
+
+            Ok(if allowance.is_max() {
+                U256::MAX
+            } else {
+                allowance.into()
+            })
+        })
     }
 
-    #[allow(clippy::type_complexity)]
     #[export(unwrap_result)]
-    pub fn allowances(
+    pub fn allowances(&self, cursor: u32, len: u32) -> Result<Page<AllowanceEntry>, Error> {
+        self.ensure_export(len)?;
+
+        Ok(self
+            .allowances
+            .get()?
+            .iter()
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(|(&(owner, spender), &(allowance, b))| {
+                ((owner.into(), spender.into()), ((*allowance).into(), b))
+            })
+            .collect())
+    }
+
+    /// Returns up to `len` allowances as `allowances` does, encoding values
+    /// as 9-byte [`Allowance`]s instead of 32-byte `U256`s.
+    #[export(unwrap_result)]
+    pub fn allowances_compact(
         &self,
         cursor: u32,
         len: u32,
-    ) -> Result<Vec<((ActorId, ActorId), (U256, u32))>, Error> {
+    ) -> Result<Page<AllowanceEntry<Allowance>>, Error> {
+        self.ensure_export(len)?;
+
         Ok(self
             .allowances
             .get()?
@@ -193,8 +545,209 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
             .skip(cursor as usize)
             .take(len as usize)
             .map(|(&(owner, spender), &(allowance, b))| {
+                ((owner.into(), spender.into()), (*allowance, b))
+            })
+            .collect())
+    }
+
+    /// Returns up to `len` allowances starting from the `cursor`, with the cursor
+    /// to resume from, so deep pages cost as much as the first one.
+    #[export(unwrap_result)]
+    pub fn allowances_from(
+        &self,
+        cursor: MapCursor,
+        len: u32,
+    ) -> Result<(Page<AllowanceEntry>, MapCursor), Error> {
+        self.ensure_export(len)?;
+
+        let mut next = cursor;
+
+        let page = self
+            .allowances
+            .get()?
+            .iter_from(cursor)
+            .take(len as usize)
+            .map(|(cursor, &(owner, spender), &(allowance, b))| {
+                next = cursor;
                 ((owner.into(), spender.into()), ((*allowance).into(), b))
             })
+            .collect();
+
+        Ok((page, next))
+    }
+
+    /// Returns up to `len` allowances given by the owner as `(spender, (value, expiry))`,
+    /// starting from `cursor`, in ascending order of spenders.
+    #[export(unwrap_result)]
+    pub fn allowances_of_owner(
+        &self,
+        owner: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<CounterpartyAllowance>, Error> {
+        self.ensure_page_size(len)?;
+
+        let owner = owner.try_into()?;
+        let allowances = self.allowances.get()?;
+
+        Ok(allowances
+            .spenders_of(owner)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .filter_map(|spender| {
+                let (_, (allowance, expiry)) = (**allowances).get(&(owner, spender))?;
+
+                Some((spender.into_inner(), (approval(allowance), *expiry)))
+            })
+            .collect())
+    }
+
+    /// Returns up to `len` allowances given to the spender as `(owner, (value, expiry))`,
+    /// starting from `cursor`, in ascending order of owners.
+    #[export(unwrap_result)]
+    pub fn allowances_to_spender(
+        &self,
+        spender: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<CounterpartyAllowance>, Error> {
+        self.ensure_page_size(len)?;
+
+        let spender = spender.try_into()?;
+        let allowances = self.allowances.get()?;
+
+        Ok(allowances
+            .owners_of(spender)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .filter_map(|owner| {
+                let (_, (allowance, expiry)) = (**allowances).get(&(owner, spender))?;
+
+                Some((owner.into_inner(), (approval(allowance), *expiry)))
+            })
+            .collect())
+    }
+
+    /// Returns the spending cap given by the owner to the spender, if any.
+    #[export(unwrap_result)]
+    pub fn spending_cap(
+        &self,
+        owner: ActorId,
+        spender: ActorId,
+    ) -> Result<Option<SpendingCap>, Error> {
+        Ok(self
+            .allowances
+            .get()?
+            .cap(owner.try_into()?, spender.try_into()?)
+            .copied())
+    }
+
+    /// Returns value the spender may still spend from its cap within the current period.
+    #[export(unwrap_result)]
+    pub fn spending_cap_remaining(
+        &self,
+        owner: ActorId,
+        spender: ActorId,
+    ) -> Result<Option<U256>, Error> {
+        Ok(self
+            .allowances
+            .get()?
+            .cap(owner.try_into()?, spender.try_into()?)
+            .map(|cap| cap.remaining(Syscall::block_height())))
+    }
+
+    #[export(unwrap_result)]
+    pub fn is_operator(&self, owner: ActorId, operator: ActorId) -> Result<bool, Error> {
+        Ok(self
+            .allowances
+            .get()?
+            .is_operator(owner.try_into()?, operator.try_into()?))
+    }
+
+    /// Returns up to `len` operators approved by the owner, starting from `cursor`.
+    #[export(unwrap_result)]
+    pub fn operators_of(
+        &self,
+        owner: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<ActorId>, Error> {
+        self.ensure_page_size(len)?;
+
+        Ok(self
+            .allowances
+            .get()?
+            .operators_of(owner.try_into()?)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(NonZero::into_inner)
+            .collect())
+    }
+
+    /// Returns up to `len` owners that approved the operator, starting from `cursor`.
+    #[export(unwrap_result)]
+    pub fn operated_by(
+        &self,
+        operator: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<ActorId>, Error> {
+        self.ensure_page_size(len)?;
+
+        Ok(self
+            .allowances
+            .get()?
+            .operated_by(operator.try_into()?)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(NonZero::into_inner)
+            .collect())
+    }
+
+    #[export(unwrap_result)]
+    pub fn pending_claim(&self, id: u64) -> Result<Option<Claim>, Error> {
+        Ok(self.balances.get()?.claims().get(id).cloned())
+    }
+
+    /// Returns up to `len` pending claims sent by the sender, starting from `cursor`.
+    #[export(unwrap_result)]
+    pub fn pending_claims_from(
+        &self,
+        sender: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<(u64, Claim)>, Error> {
+        self.ensure_page_size(len)?;
+
+        let balances = self.balances.get()?;
+        let claims = balances.claims();
+
+        Ok(claims
+            .sent_by(sender)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .filter_map(|id| Some((id, claims.get(id)?.clone())))
+            .collect())
+    }
+
+    /// Returns up to `len` pending claims sent to the recipient, starting from `cursor`.
+    #[export(unwrap_result)]
+    pub fn pending_claims_to(
+        &self,
+        recipient: ActorId,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<(u64, Claim)>, Error> {
+        self.ensure_page_size(len)?;
+
+        let balances = self.balances.get()?;
+        let claims = balances.claims();
+
+        Ok(claims
+            .sent_to(recipient)
+            .skip(cursor as usize)
+            .take(len as usize)
+            .filter_map(|id| Some((id, claims.get(id)?.clone())))
             .collect())
     }
 
@@ -205,8 +758,20 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
             .map(|(_, &v)| (*v).into()))
     }
 
+    /// Returns balances of each of the `accounts`, as `Vft::balance_of` does.
+    #[export(unwrap_result)]
+    pub fn balances_of(&self, accounts: Vec<ActorId>) -> Result<Vec<U256>, Error> {
+        let balances = self.balances.get()?;
+
+        multicall(accounts, |account| {
+            Ok(balances.get(account.try_into()?).into())
+        })
+    }
+
     #[export(unwrap_result)]
-    pub fn balances(&self, cursor: u32, len: u32) -> Result<Vec<(ActorId, U256)>, Error> {
+    pub fn balances(&self, cursor: u32, len: u32) -> Result<Page<(ActorId, U256)>, Error> {
+        self.ensure_export(len)?;
+
         Ok(self
             .balances
             .get()?
@@ -217,17 +782,119 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
             .collect())
     }
 
+    /// Returns up to `len` balances as `balances` does, encoding values
+    /// as 10-byte [`Balance`]s instead of 32-byte `U256`s.
+    #[export(unwrap_result)]
+    pub fn balances_compact(
+        &self,
+        cursor: u32,
+        len: u32,
+    ) -> Result<Page<(ActorId, Balance)>, Error> {
+        self.ensure_export(len)?;
+
+        Ok(self
+            .balances
+            .get()?
+            .iter()
+            .skip(cursor as usize)
+            .take(len as usize)
+            .map(|(&account, &v)| (account.into(), *v))
+            .collect())
+    }
+
+    /// Returns up to `len` balances starting from the `cursor`, with the cursor
+    /// to resume from, so deep pages cost as much as the first one.
+    #[export(unwrap_result)]
+    pub fn balances_from(
+        &self,
+        cursor: MapCursor,
+        len: u32,
+    ) -> Result<(Page<(ActorId, U256)>, MapCursor), Error> {
+        self.ensure_export(len)?;
+
+        let mut next = cursor;
+
+        let page = self
+            .balances
+            .get()?
+            .iter_from(cursor)
+            .take(len as usize)
+            .map(|(cursor, &account, &v)| {
+                next = cursor;
+                (account.into(), (*v).into())
+            })
+            .collect();
+
+        Ok((page, next))
+    }
+
+    /// Returns balance of the actor the 20-byte `address` maps to,
+    /// as `ActorId::from(H160)` pads it, being an alias of `Vft::balance_of`.
+    ///
+    /// Holders keyed by Ethereum addresses themselves are kept by storages
+    /// instantiated with `H160` keys, e.g. `Balances<Balance, H160>`.
+    #[export(unwrap_result)]
+    pub fn balance_of_address(&self, address: H160) -> Result<U256, Error> {
+        let account = NonZero::try_new(ActorId::from(address))?;
+
+        Ok(self.balances.get()?.get(account).into())
+    }
+
+    /// Returns the latest snapshot of holders computed, see `compute_snapshot`.
+    #[export(unwrap_result)]
+    pub fn latest_snapshot(&self) -> Result<Option<SnapshotRoot>, Error> {
+        Ok(self.balances.get()?.snapshot().latest())
+    }
+
+    /// Returns block the snapshot being computed is taken at, if any.
+    #[export(unwrap_result)]
+    pub fn pending_snapshot(&self) -> Result<Option<u32>, Error> {
+        Ok(self.balances.get()?.snapshot().pending_block())
+    }
+
+    #[export(unwrap_result)]
+    pub fn dust_policy(&self) -> Result<DustPolicy, Error> {
+        Ok(self.balances.get()?.dust_policy())
+    }
+
     #[export(unwrap_result)]
     pub fn expiry_period(&self) -> Result<u32, Error> {
         Ok(self.allowances.get()?.expiry_period())
     }
 
+    #[export(unwrap_result)]
+    pub fn minimum_balance(&self) -> Result<U256, Error> {
+        Ok(self.balances.get()?.minimum_balance())
+    }
+
+    /// Returns the max amount of items enumeration exports reply with at once.
+    #[export(unwrap_result)]
+    pub fn max_page_size(&self) -> Result<u32, Error> {
+        Ok(self.balances.get()?.max_page_size())
+    }
+
+    /// Returns the total supply as of the end of the given block,
+    /// or `None` if the block precedes the kept supply history.
+    #[export(unwrap_result)]
+    pub fn total_supply_at(&self, block: u32) -> Result<Option<U256>, Error> {
+        Ok(self.balances.get()?.total_supply_at(block))
+    }
+
     #[export(unwrap_result)]
     pub fn unused_value(&self) -> Result<U256, Error> {
         Ok(self.balances.get()?.unused_value())
     }
 }
 
+/// Converts the stored allowance to the value reported by `Vft::allowance`.
+fn approval(allowance: &NonZero<Allowance>) -> U256 {
+    if allowance.is_max() {
+        U256::MAX
+    } else {
+        (**allowance).into()
+    }
+}
+
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, TypeInfo, thiserror::Error,
 )]
@@ -235,3 +902,5 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> VftExtens
 #[error("allowance is not expired")]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct AllowanceNotExpiredError;
+
+awesome_sails_utils::impl_error_code!(AllowanceNotExpiredError => 200);
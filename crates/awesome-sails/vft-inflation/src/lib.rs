@@ -0,0 +1,265 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome VFT-Inflation service.
+//!
+//! This service mints scheduled emission of VFTs to a recipient, e.g. treasury
+//! or staking pot: a fixed amount per era of blocks, optionally decaying each era
+//! and stopping at the end block.
+//!
+//! Anyone may mint elapsed eras, while the schedule is set by the super admin.
+
+#![no_std]
+
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage, ensure,
+};
+use awesome_sails_macros::require_role;
+use awesome_sails_utils::{
+    error::{BadInput, EmitError, Error},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
+};
+use awesome_sails_vft::utils::{Allowances, Balance, Balances};
+use awesome_sails_vft_admin::{self as vft_admin, AdminStorage};
+use sails_rs::prelude::*;
+
+/// Maximal amount of eras minted within a single `mint_emission` call.
+pub const MAX_ERAS_PER_CALL: u32 = 32;
+
+/// Basis points of the whole emission, used for decay.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Parameters of the emission.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InflationSchedule {
+    /// Account the emission is minted to.
+    pub recipient: ActorId,
+    /// Emission of the first era.
+    pub initial_emission: U256,
+    /// Length of an era in blocks: zero disables the emission.
+    pub era_blocks: u32,
+    /// Decrease of the emission every era, in basis points.
+    pub decay_bps: u16,
+    pub start_block: u32,
+    /// Block the emission stops at: eras ending after it aren't minted.
+    pub end_block: Option<u32>,
+}
+
+/// Emission state of [`VftInflation`].
+#[derive(Default, Debug)]
+pub struct Inflation {
+    schedule: InflationSchedule,
+    next_era: u32,
+    emission: U256,
+}
+
+impl Inflation {
+    pub fn schedule(&self) -> &InflationSchedule {
+        &self.schedule
+    }
+
+    /// Replaces the schedule, starting emission from its first era.
+    pub fn set_schedule(&mut self, schedule: InflationSchedule) {
+        self.schedule = schedule;
+        self.next_era = 0;
+        self.emission = schedule.initial_emission;
+    }
+
+    /// Returns the block the next era ends at, with its emission.
+    ///
+    /// Returns `None` if the emission is disabled or has ended.
+    pub fn next_emission(&self) -> Option<(u32, U256)> {
+        let schedule = &self.schedule;
+
+        if schedule.era_blocks == 0 {
+            return None;
+        }
+
+        let era_end = schedule.start_block.checked_add(
+            self.next_era
+                .checked_add(1)?
+                .checked_mul(schedule.era_blocks)?,
+        )?;
+
+        if schedule
+            .end_block
+            .is_some_and(|end_block| era_end > end_block)
+        {
+            return None;
+        }
+
+        Some((era_end, self.emission))
+    }
+
+    /// Removes the next era if it has elapsed by the given block,
+    /// returning its index and emission.
+    pub fn pop_elapsed(&mut self, current_bn: u32) -> Option<(u32, U256)> {
+        let (era_end, emission) = self.next_emission()?;
+
+        if era_end > current_bn {
+            return None;
+        }
+
+        let era = self.next_era;
+
+        self.next_era += 1;
+        self.emission = emission
+            - emission.saturating_mul(self.schedule.decay_bps.into()) / U256::from(MAX_BPS);
+
+        Some((era, emission))
+    }
+}
+
+/// Awesome VFT-Inflation service itself.
+pub struct VftInflation<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    I: InfallibleStorageMut<Item = Inflation> = StorageRefCell<'a, Inflation>,
+> {
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    storage: I,
+    vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+}
+
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    I: InfallibleStorageMut<Item = Inflation>,
+> VftInflation<'a, ACS, A, B, S, I>
+{
+    /// Constructor for [`Self`].
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        storage: I,
+        vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+    ) -> Self {
+        Self {
+            access_control,
+            storage,
+            vft_admin,
+        }
+    }
+}
+
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+#[service(events = Event)]
+impl<
+    'a,
+    ACS: InfallibleStorageMut<Item = RolesStorage>,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    I: InfallibleStorageMut<Item = Inflation>,
+> VftInflation<'a, ACS, A, B, S, I>
+{
+    /// Mints emission of up to [`MAX_ERAS_PER_CALL`] elapsed eras to the recipient.
+    ///
+    /// Returns the minted amount.
+    #[export(unwrap_result)]
+    pub fn mint_emission(&mut self) -> Result<U256, Error> {
+        let current_bn = Syscall::block_height();
+        let to = self.storage.get().schedule().recipient;
+
+        let mut minted = U256::zero();
+
+        for _ in 0..MAX_ERAS_PER_CALL {
+            let Some((era, value)) = self.storage.get_mut().pop_elapsed(current_bn) else {
+                break;
+            };
+
+            if value.is_zero() {
+                continue;
+            }
+
+            // Safety: emission is minted to the recipient configured by the super admin.
+            unsafe {
+                self.vft_admin.do_mint(to, value)?;
+            }
+
+            self.emit_event(Event::EmissionMinted { era, to, value })
+                .map_err(|_| EmitError)?;
+
+            minted += value;
+        }
+
+        Ok(minted)
+    }
+
+    /// Sets the emission schedule, starting its eras from the current block.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_schedule(
+        &mut self,
+        recipient: ActorId,
+        initial_emission: U256,
+        era_blocks: u32,
+        decay_bps: u16,
+        end_block: Option<u32>,
+    ) -> Result<(), Error> {
+        ensure!(!recipient.is_zero() && decay_bps <= MAX_BPS, BadInput);
+
+        Balance::try_from(initial_emission)?;
+
+        let schedule = InflationSchedule {
+            recipient,
+            initial_emission,
+            era_blocks,
+            decay_bps,
+            start_block: Syscall::block_height(),
+            end_block,
+        };
+
+        self.storage.get_mut().set_schedule(schedule);
+
+        self.emit_event(Event::ScheduleSet(schedule))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Returns the block the next era ends at, with its emission,
+    /// or `None` if the emission is disabled or has ended.
+    #[export]
+    pub fn next_emission(&self) -> Option<(u32, U256)> {
+        self.storage.get().next_emission()
+    }
+
+    #[export]
+    pub fn schedule(&self) -> InflationSchedule {
+        *self.storage.get().schedule()
+    }
+}
+
+#[event]
+#[derive(Clone, Debug, PartialEq, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Event {
+    EmissionMinted { era: u32, to: ActorId, value: U256 },
+    ScheduleSet(InflationSchedule),
+}
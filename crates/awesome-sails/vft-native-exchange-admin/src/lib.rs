@@ -18,50 +18,103 @@
 
 //! Awesome VFT-NativeExchangeAdmin service.
 //!
-//! This service provides admin functionality of exchanging native tokens to VFT's.
+//! This service provides admin functionality of exchanging native tokens to VFT's,
+//! including [`MintBounds`] of deposits.
+//!
+//! Payouts of `burn_from` expect replies tagged with [`REPLY_TAG`] in the given
+//! [`ReplyRouter`], so the program must dispatch them to `handle_reply` of this service.
+//! Payouts are tracked until replied, so ones left unreplied are found by `stale_payouts`.
 
 #![no_std]
 
-use awesome_sails_access_control::{RolesStorage, error::Error};
+use awesome_sails_access_control::{
+    self as access_control, DEFAULT_ADMIN_ROLE, RolesStorage,
+    error::{EmitError, Error},
+};
+use awesome_sails_macros::require_role;
 use awesome_sails_utils::{
+    error::SendError,
     ok_if,
-    storage::{InfallibleStorageMut, StorageMut},
+    reply::{OutboundMessage, ReplyRouter},
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
 };
 use awesome_sails_vft::utils::{Allowances, Balances};
-use awesome_sails_vft_admin::{self as vft_admin};
+use awesome_sails_vft_admin::{self as vft_admin, AdminStorage};
+use awesome_sails_vft_native_exchange::{ExchangeStorage, MintBounds};
 use sails_rs::{gstd, prelude::*};
 
+/// Tag of replies handled by [`VftNativeExchangeAdmin::handle_reply`].
+pub const REPLY_TAG: &str = "VftNativeExchangeAdmin";
+
 /// Awesome VFT-Native-Exchange-Admin service itself.
-pub struct VftNativeExchangeAdmin<'a, ACS, A, B>
-where
+pub struct VftNativeExchangeAdmin<
+    'a,
+    ACS,
+    A,
+    B,
+    S,
+    E = StorageRefCell<'a, ExchangeStorage>,
+    R = StorageRefCell<'a, ReplyRouter>,
+> where
     ACS: InfallibleStorageMut<Item = RolesStorage>,
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    E: InfallibleStorageMut<Item = ExchangeStorage>,
+    R: InfallibleStorageMut<Item = ReplyRouter>,
 {
-    vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B>>,
+    access_control: access_control::AccessControlExposure<access_control::AccessControl<'a, ACS>>,
+    replies: R,
+    storage: E,
+    vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
 }
 
-impl<'a, ACS, A, B> VftNativeExchangeAdmin<'a, ACS, A, B>
+impl<'a, ACS, A, B, S, E, R> VftNativeExchangeAdmin<'a, ACS, A, B, S, E, R>
 where
     ACS: InfallibleStorageMut<Item = RolesStorage>,
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    E: InfallibleStorageMut<Item = ExchangeStorage>,
+    R: InfallibleStorageMut<Item = ReplyRouter>,
 {
     /// Constructor for [`Self`].
-    pub fn new(vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B>>) -> Self {
-        Self { vft_admin }
+    pub fn new(
+        access_control: access_control::AccessControlExposure<
+            access_control::AccessControl<'a, ACS>,
+        >,
+        replies: R,
+        storage: E,
+        vft_admin: vft_admin::VftAdminExposure<vft_admin::VftAdmin<'a, ACS, A, B, S>>,
+    ) -> Self {
+        Self {
+            access_control,
+            replies,
+            storage,
+            vft_admin,
+        }
     }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service(events = Event)]
-impl<'a, ACS, A, B> VftNativeExchangeAdmin<'a, ACS, A, B>
+impl<'a, ACS, A, B, S, E, R> VftNativeExchangeAdmin<'a, ACS, A, B, S, E, R>
 where
     ACS: InfallibleStorageMut<Item = RolesStorage>,
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
+    S: InfallibleStorageMut<Item = AdminStorage>,
+    E: InfallibleStorageMut<Item = ExchangeStorage>,
+    R: InfallibleStorageMut<Item = ReplyRouter>,
 {
     /// Reply handler for failed token transfers.
+    ///
+    /// Must only be called for replies routed by [`REPLY_TAG`].
     pub fn handle_reply(&mut self) {
+        if let Ok(reply_to) = Syscall::reply_to() {
+            self.storage.get_mut().payouts_mut().resolve(reply_to);
+        }
+
         // TODO(sails): impl getters for reply details.
         let value = Syscall::message_value();
 
@@ -74,7 +127,11 @@ where
                 .do_mint(Syscall::message_source(), value.into())
         };
 
-        if mint_res.is_err() {
+        if mint_res.is_ok() {
+            self.storage
+                .get_mut()
+                .record_mint(Syscall::message_source(), value.into());
+        } else {
             self.emit_event(Event::FailedMint {
                 to: Syscall::message_source(),
                 value: value.into(),
@@ -89,15 +146,49 @@ where
 
         self.vft_admin.burn(from, value)?;
 
+        self.storage.get_mut().record_burn(from, value);
+
         // TODO(sails): impl sync Remoting.
-        let message_id = gstd::msg::send_bytes(from, [], value.as_u128())
-            .map_err(|_| Error::new("failed to send value"))?;
+        let message_id =
+            gstd::msg::send_bytes(from, [], value.as_u128()).map_err(|_| SendError::Value)?;
         // TODO: #6
         gstd::exec::reply_deposit(message_id, 5_000_000_000)
-            .map_err(|_| Error::new("failed to deposit gas for reply"))?;
+            .map_err(|_| SendError::ReplyDeposit)?;
+
+        self.replies.get_mut().expect(message_id, REPLY_TAG);
+        self.storage.get_mut().payouts_mut().record(
+            message_id,
+            from,
+            "payout",
+            Syscall::block_height(),
+        );
 
         Ok(())
     }
+
+    /// Sets bounds of values deposited to mint, failing if the minimum
+    /// exceeds the bounded maximum.
+    #[export(unwrap_result)]
+    #[require_role(DEFAULT_ADMIN_ROLE)]
+    pub fn set_mint_bounds(&mut self, bounds: MintBounds) -> Result<(), Error> {
+        self.storage.get_mut().set_mint_bounds(bounds)?;
+
+        self.emit_event(Event::MintBoundsSet(bounds))
+            .map_err(|_| EmitError)?;
+
+        Ok(())
+    }
+
+    /// Returns payouts left unreplied for at least `older_than` blocks.
+    #[export]
+    pub fn stale_payouts(&self, older_than: u32) -> Vec<(MessageId, OutboundMessage)> {
+        self.storage
+            .get()
+            .payouts()
+            .stale_messages(older_than, Syscall::block_height())
+            .map(|(message_id, message)| (*message_id, message.clone()))
+            .collect()
+    }
 }
 
 #[event]
@@ -106,4 +197,5 @@ where
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum Event {
     FailedMint { to: ActorId, value: U256 },
+    MintBoundsSet(MintBounds),
 }
@@ -19,45 +19,165 @@
 //! Awesome VFT-NativeExchange service.
 //!
 //! This service provides functionality of exchanging native tokens to VFT's.
+//!
+//! Mints are throttled per actor by the given [`RateLimiter`], which may be left
+//! disabled (default) to allow unlimited minting.
+//!
+//! Deposits out of the [`MintBounds`] set by admins aren't minted, but refunded.
+//!
+//! Values exchanged are accumulated into [`ExchangeStats`] per account and in total.
 
 #![no_std]
 
 use awesome_sails_utils::{
-    error::{EmitError, Error},
+    ensure,
+    error::{BadInput, EmitError, Error},
     math::Zero,
     ok_if,
-    storage::StorageMut,
+    rate_limit::RateLimiter,
+    reply::OutboundTracker,
+    storage::{InfallibleStorageMut, StorageMut, StorageRefCell},
 };
 use awesome_sails_vft::{
     self as vft,
     utils::{Allowances, Balance, Balances},
 };
-use sails_rs::prelude::*;
+use sails_rs::{collections::BTreeMap, prelude::*};
+
+/// Bounds of values deposited to [`VftNativeExchange::mint`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MintBounds {
+    /// Minimal value minted, e.g. against dust spam.
+    pub min_mint_value: U256,
+    /// Maximal value minted, zero for unbounded.
+    pub max_mint_value: U256,
+}
+
+impl MintBounds {
+    /// Returns `true` if the value is within the bounds.
+    pub fn contains(&self, value: U256) -> bool {
+        value >= self.min_mint_value
+            && (self.max_mint_value.is_zero() || value <= self.max_mint_value)
+    }
+}
+
+/// Cumulative values exchanged.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ExchangeStats {
+    /// Value deposited for minted VFTs.
+    pub minted: U256,
+    /// Value paid out for burnt VFTs.
+    pub burned: U256,
+}
+
+/// Exchange-side state of [`VftNativeExchange`].
+#[derive(Default, Debug)]
+pub struct ExchangeStorage {
+    mint_bounds: MintBounds,
+    payouts: OutboundTracker,
+    stats: BTreeMap<ActorId, ExchangeStats>,
+    totals: ExchangeStats,
+}
+
+impl ExchangeStorage {
+    /// Returns payouts sent to accounts, awaiting their replies.
+    pub fn payouts(&self) -> &OutboundTracker {
+        &self.payouts
+    }
+
+    pub fn payouts_mut(&mut self) -> &mut OutboundTracker {
+        &mut self.payouts
+    }
+
+    pub fn stats_of(&self, account: &ActorId) -> ExchangeStats {
+        self.stats.get(account).copied().unwrap_or_default()
+    }
+
+    pub fn totals(&self) -> &ExchangeStats {
+        &self.totals
+    }
+
+    pub fn record_mint(&mut self, account: ActorId, value: U256) {
+        let stats = self.stats.entry(account).or_default();
+
+        stats.minted = stats.minted.saturating_add(value);
+        self.totals.minted = self.totals.minted.saturating_add(value);
+    }
+
+    pub fn record_burn(&mut self, account: ActorId, value: U256) {
+        let stats = self.stats.entry(account).or_default();
+
+        stats.burned = stats.burned.saturating_add(value);
+        self.totals.burned = self.totals.burned.saturating_add(value);
+    }
+
+    pub fn mint_bounds(&self) -> &MintBounds {
+        &self.mint_bounds
+    }
+
+    /// Sets the bounds, failing if the minimum exceeds the bounded maximum.
+    pub fn set_mint_bounds(&mut self, bounds: MintBounds) -> Result<(), BadInput> {
+        ensure!(
+            bounds.max_mint_value.is_zero() || bounds.min_mint_value <= bounds.max_mint_value,
+            BadInput
+        );
+
+        self.mint_bounds = bounds;
+
+        Ok(())
+    }
+}
 
 /// Awesome VFT-Native-Exchange service itself.
-pub struct VftNativeExchange<'a, A, B>
+pub struct VftNativeExchange<'a, A, B, R, S = StorageRefCell<'a, ExchangeStorage>>
 where
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
+    R: InfallibleStorageMut<Item = RateLimiter<ActorId>>,
+    S: InfallibleStorageMut<Item = ExchangeStorage>,
 {
     balances: B,
+    limiter: R,
+    storage: S,
     vft: vft::VftExposure<vft::Vft<'a, A, B>>,
 }
 
-impl<'a, A, B> VftNativeExchange<'a, A, B>
+impl<'a, A, B, R, S> VftNativeExchange<'a, A, B, R, S>
 where
     A: StorageMut<Item = Allowances>,
     B: StorageMut<Item = Balances>,
+    R: InfallibleStorageMut<Item = RateLimiter<ActorId>>,
+    S: InfallibleStorageMut<Item = ExchangeStorage>,
 {
     /// Constructor for [`Self`].
-    pub fn new(balances: B, vft: vft::VftExposure<vft::Vft<'a, A, B>>) -> Self {
-        Self { balances, vft }
+    pub fn new(
+        balances: B,
+        limiter: R,
+        storage: S,
+        vft: vft::VftExposure<vft::Vft<'a, A, B>>,
+    ) -> Self {
+        Self {
+            balances,
+            limiter,
+            storage,
+            vft,
+        }
     }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service]
-impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
-    VftNativeExchange<'a, A, B>
+impl<
+    'a,
+    A: StorageMut<Item = Allowances>,
+    B: StorageMut<Item = Balances>,
+    R: InfallibleStorageMut<Item = RateLimiter<ActorId>>,
+    S: InfallibleStorageMut<Item = ExchangeStorage>,
+> VftNativeExchange<'a, A, B, R, S>
 {
     #[export(unwrap_result)]
     pub fn burn(&mut self, value: U256) -> Result<CommandReply<()>, Error> {
@@ -65,9 +185,16 @@ impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
 
         let from = Syscall::message_source();
 
-        self.balances
-            .get_mut()?
-            .burn(from.try_into()?, Balance::try_from(value)?.try_into()?)?;
+        let reaped = {
+            let mut balances = self.balances.get_mut()?;
+
+            let reaped = balances.burn(from.try_into()?, Balance::try_from(value)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+
+            reaped
+        };
+
+        self.storage.get_mut().record_burn(from, value);
 
         self.vft
             .emit_event(vft::Event::Transfer {
@@ -77,6 +204,15 @@ impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
             })
             .map_err(|_| EmitError)?;
 
+        if let Some(dust) = reaped {
+            self.vft
+                .emit_event(vft::Event::AccountReaped {
+                    account: from,
+                    dust: dust.into(),
+                })
+                .map_err(|_| EmitError)?;
+        }
+
         Ok(CommandReply::new(()).with_value(value.as_u128()))
     }
 
@@ -84,10 +220,19 @@ impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
     pub fn burn_all(&mut self) -> Result<CommandReply<()>, Error> {
         let from = Syscall::message_source();
 
-        let value = self.balances.get_mut()?.burn_all(from.try_into()?);
+        let value = {
+            let mut balances = self.balances.get_mut()?;
+
+            let value = balances.burn_all(from.try_into()?);
+            balances.checkpoint_supply(Syscall::block_height());
+
+            value
+        };
 
         ok_if!(value.is_zero());
 
+        self.storage.get_mut().record_burn(from, value.into());
+
         self.vft
             .emit_event(vft::Event::Transfer {
                 from,
@@ -96,20 +241,43 @@ impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
             })
             .map_err(|_| EmitError)?;
 
+        self.vft
+            .emit_event(vft::Event::AccountReaped {
+                account: from,
+                dust: U256::zero(),
+            })
+            .map_err(|_| EmitError)?;
+
         Ok(CommandReply::new(()).with_value(value.into()))
     }
 
+    /// Mints VFTs for the attached value.
+    ///
+    /// Returns `false` if the value is out of the [`MintBounds`], refunding it.
     #[export(unwrap_result)]
-    pub fn mint(&mut self) -> Result<(), Error> {
+    pub fn mint(&mut self) -> Result<CommandReply<bool>, Error> {
         let value = U256::from(Syscall::message_value());
 
-        ok_if!(value.is_zero());
+        ok_if!(value.is_zero(), CommandReply::new(false));
+
+        if !self.storage.get().mint_bounds().contains(value) {
+            return Ok(CommandReply::new(false).with_value(value.as_u128()));
+        }
 
         let to = Syscall::message_source();
 
-        self.balances
-            .get_mut()?
-            .mint(to.try_into()?, Balance::try_from(value)?.try_into()?)?;
+        self.limiter
+            .get_mut()
+            .try_consume(to, 1, Syscall::block_height())?;
+
+        {
+            let mut balances = self.balances.get_mut()?;
+
+            balances.mint(to.try_into()?, Balance::try_from(value)?.try_into()?)?;
+            balances.checkpoint_supply(Syscall::block_height());
+        }
+
+        self.storage.get_mut().record_mint(to, value);
 
         self.vft
             .emit_event(vft::Event::Transfer {
@@ -119,6 +287,23 @@ impl<'a, A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>>
             })
             .map_err(|_| EmitError)?;
 
-        Ok(())
+        Ok(CommandReply::new(true))
+    }
+
+    /// Returns values exchanged by the account.
+    #[export]
+    pub fn exchange_stats(&self, account: ActorId) -> ExchangeStats {
+        self.storage.get().stats_of(&account)
+    }
+
+    /// Returns values exchanged by all accounts.
+    #[export]
+    pub fn exchange_totals(&self) -> ExchangeStats {
+        *self.storage.get().totals()
+    }
+
+    #[export]
+    pub fn mint_bounds(&self) -> MintBounds {
+        *self.storage.get().mint_bounds()
     }
 }
@@ -19,6 +19,10 @@
 //! Awesome VFT (Vara Fungible Token) service.
 //!
 //! This standard is direct analog of ERC20 standard.
+//!
+//! Operators approved by owners, see `VftExtension::set_operator`,
+//! transfer from them without spending allowances, while spenders given
+//! spending caps, see `VftExtension::approve_with_cap`, spend the caps instead.
 
 #![no_std]
 
@@ -29,12 +33,27 @@ use awesome_sails_utils::{
     pause::PausableRef,
     storage::StorageMut,
 };
-use awesome_sails_vft_utils::{Allowance, Allowances, Balance, Balances};
-use sails_rs::prelude::*;
+use awesome_sails_vft_utils::{
+    Allowance, Allowances, Balance, Balances, CapacityCheck, CapacityWarning, StorageMap, Tripped,
+};
+use sails_rs::{gstd, prelude::*};
+
+pub mod remote;
 
 /// Re-exporting the utils module for easier access.
 pub use awesome_sails_vft_utils as utils;
 
+/// Route of the notification of operators about occupancy of storage.
+pub const CAPACITY_NOTIFICATION_ROUTE: (&str, &str) = ("VftOperator", "OnCapacityWarning");
+
+/// Returns the payload of the message notifying the operator that occupancy
+/// of the map of the program sending the message crossed the alert threshold.
+pub fn capacity_notification(warning: CapacityWarning) -> Vec<u8> {
+    let (service, method) = CAPACITY_NOTIFICATION_ROUTE;
+
+    (service, method, warning).encode()
+}
+
 /// Awesome VFT service itself.
 pub struct Vft<'a, A = PausableRef<'a, Allowances>, B = PausableRef<'a, Balances>> {
     // Allowances storage.
@@ -55,23 +74,133 @@ impl<A, B> Vft<'_, A, B> {
     }
 }
 
+#[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
 #[service(events = Event)]
 impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> Vft<'_, A, B> {
-    #[export(unwrap_result)]
-    pub fn approve(&mut self, spender: ActorId, value: U256) -> Result<bool, Error> {
-        let owner = Syscall::message_source();
+    /// Reports occupancy of the map grown by inserts to operators by
+    /// `StorageSaturated` and `CapacityWarning` events, notifying the operator
+    /// of the alert by the [`capacity_notification`] message as well.
+    pub fn report_capacity(&mut self, check: CapacityCheck) -> Result<(), Error> {
+        if let Some(full) = check.saturation {
+            self.emit_event(Event::StorageSaturated {
+                map: full.map,
+                shards: full.shards,
+                capacity: full.capacity,
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        if let Some((warning, operator)) = check.warning {
+            self.emit_event(Event::CapacityWarning {
+                map: warning.map,
+                entries: warning.entries,
+                capacity: warning.capacity,
+            })
+            .map_err(|_| EmitError)?;
+
+            // Failed notification mustn't fail the insert, as the event is emitted anyway.
+            if let Some(operator) = operator {
+                let _ = gstd::msg::send_bytes(operator, capacity_notification(warning), 0);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Accounts volume minted and transferred since the last check in the circuit
+    /// breaker of balances, halting mints and transfers once volume within its
+    /// rolling window exceeds thresholds, see `VftAdmin::set_circuit_breaker`.
+    ///
+    /// Emits `CircuitBreakerTripped` event and returns `true` if the breaker tripped.
+    pub fn check_circuit_breaker(&mut self) -> Result<bool, Error> {
+        let tripped = self
+            .balances
+            .get_mut()?
+            .check_circuit_breaker(Syscall::block_height());
+
+        let Some(Tripped {
+            minted,
+            transferred,
+        }) = tripped
+        else {
+            return Ok(false);
+        };
 
+        self.emit_event(Event::CircuitBreakerTripped {
+            minted,
+            transferred,
+        })
+        .map_err(|_| EmitError)?;
+
+        Ok(true)
+    }
+
+    /// Transfers VFTs between the account and the program account, holding them
+    /// on behalf of others, e.g. escrowed by other services of the program.
+    ///
+    /// The program account is exempt from the minimum balance, so it's neither
+    /// rejected as the receiver, nor removed as dust, see [`Balances::transfer_escrowed`].
+    ///
+    /// Emits `Transfer` event, followed by `AccountReaped` event if `from` was removed.
+    pub fn transfer_escrowed(
+        &mut self,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> Result<(), Error> {
+        ok_if!(value.is_zero());
+
+        let reaped = self.balances.get_mut()?.transfer_escrowed(
+            from.try_into()?,
+            to.try_into()?,
+            Balance::try_from(value)?.try_into()?,
+            Syscall::program_id().try_into()?,
+        )?;
+
+        self.emit_event(Event::Transfer { from, to, value })
+            .map_err(|_| EmitError)?;
+
+        if let Some(dust) = reaped {
+            self.emit_event(Event::AccountReaped {
+                account: from,
+                dust: dust.into(),
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        self.check_circuit_breaker()?;
+
+        Ok(())
+    }
+
+    /// Approves VFTs of the owner as if it was the message source, e.g. by
+    /// the session key of the owner, see `SessionKeys::approve_as`.
+    ///
+    /// Returns `true` if the allowance changed.
+    pub fn approve_on_behalf(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+    ) -> Result<bool, Error> {
         ok_if!(owner == spender, false);
 
         let approval = Allowance::try_from(value).unwrap_or(Allowance::MAX);
         let value = if approval.is_max() { U256::MAX } else { value };
 
-        let previous = self.allowances.get_mut()?.set(
-            owner.try_into()?,
-            spender.try_into()?,
-            approval,
-            Syscall::block_height(),
-        )?;
+        let (previous, check) = {
+            let mut allowances = self.allowances.get_mut()?;
+            let len = allowances.len();
+
+            let previous = allowances.set(
+                owner.try_into()?,
+                spender.try_into()?,
+                approval,
+                Syscall::block_height(),
+            )?;
+
+            (previous, allowances.check_capacity(len))
+        };
 
         let changed = previous.map(NonZero::cast).unwrap_or(U256::ZERO) != value;
 
@@ -84,27 +213,63 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> Vft<'_, A
             .map_err(|_| EmitError)?;
         }
 
+        self.report_capacity(check)?;
+
         Ok(changed)
     }
 
-    #[export(unwrap_result)]
-    pub fn transfer(&mut self, to: ActorId, value: U256) -> Result<bool, Error> {
-        let from = Syscall::message_source();
-
+    /// Transfers VFTs of `from` as if it was the message source, e.g. by
+    /// the session key of the account, see `SessionKeys::transfer_as`.
+    pub fn transfer_on_behalf(
+        &mut self,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> Result<bool, Error> {
         ok_if!(from == to || value.is_zero(), false);
 
-        self.balances.get_mut()?.transfer(
-            from.try_into()?,
-            to,
-            Balance::try_from(value)?.try_into()?,
-        )?;
+        let (reaped, check) = {
+            let mut balances = self.balances.get_mut()?;
+            let len = balances.len();
+
+            let reaped =
+                balances.transfer(from.try_into()?, to, Balance::try_from(value)?.try_into()?)?;
+
+            // Transfer to zero address burns the value.
+            if to.is_zero() {
+                balances.checkpoint_supply(Syscall::block_height());
+            }
+
+            (reaped, balances.check_capacity(len))
+        };
 
         self.emit_event(Event::Transfer { from, to, value })
             .map_err(|_| EmitError)?;
 
+        if let Some(dust) = reaped {
+            self.emit_event(Event::AccountReaped {
+                account: from,
+                dust: dust.into(),
+            })
+            .map_err(|_| EmitError)?;
+        }
+
+        self.report_capacity(check)?;
+        self.check_circuit_breaker()?;
+
         Ok(true)
     }
 
+    #[export(unwrap_result)]
+    pub fn approve(&mut self, spender: ActorId, value: U256) -> Result<bool, Error> {
+        self.approve_on_behalf(Syscall::message_source(), spender, value)
+    }
+
+    #[export(unwrap_result)]
+    pub fn transfer(&mut self, to: ActorId, value: U256) -> Result<bool, Error> {
+        self.transfer_on_behalf(Syscall::message_source(), to, value)
+    }
+
     #[export(unwrap_result)]
     pub fn transfer_from(
         &mut self,
@@ -115,7 +280,7 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> Vft<'_, A
         let spender = Syscall::message_source();
 
         if spender == from {
-            return self.transfer(to, value);
+            return self.transfer_on_behalf(from, to, value);
         }
 
         ok_if!(from == to || value.is_zero(), false);
@@ -124,19 +289,14 @@ impl<A: StorageMut<Item = Allowances>, B: StorageMut<Item = Balances>> Vft<'_, A
         let _spender = spender.try_into()?;
         let _value: NonZero<_> = Balance::try_from(value)?.try_into()?;
 
-        self.allowances.get_mut()?.decrease(
+        self.allowances.get_mut()?.spend(
             _from,
             _spender,
             _value.non_zero_cast(),
             Syscall::block_height(),
         )?;
 
-        self.balances.get_mut()?.transfer(_from, to, _value)?;
-
-        self.emit_event(Event::Transfer { from, to, value })
-            .map_err(|_| EmitError)?;
-
-        Ok(true)
+        self.transfer_on_behalf(from, to, value)
     }
 
     #[export(unwrap_result)]
@@ -182,4 +342,62 @@ pub enum Event {
         to: ActorId,
         value: U256,
     },
+
+    /// Account was removed from balances, as its balance hit zero
+    /// or fell below the minimum, leaving `dust`.
+    AccountReaped { account: ActorId, dust: U256 },
+
+    /// Spending cap of the spender was set, or removed if `amount_per_period` is zero.
+    SpendingCapSet {
+        owner: ActorId,
+        spender: ActorId,
+        amount_per_period: U256,
+        period_blocks: u32,
+    },
+
+    /// Operator of the owner was approved or revoked.
+    OperatorSet {
+        owner: ActorId,
+        operator: ActorId,
+        approved: bool,
+    },
+
+    /// Value was escrowed until claimed by the recipient, see `VftExtension::transfer_claimable`.
+    ClaimCreated {
+        id: u64,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+        expires_at: u32,
+    },
+
+    /// Escrowed value was paid out, to the recipient if claimed or to the sender if reclaimed.
+    ClaimSettled { id: u64, to: ActorId, value: U256 },
+
+    /// Snapshot of holders was computed, see `VftExtension::compute_snapshot`.
+    SnapshotTaken {
+        block: u32,
+        root: [u8; 32],
+        holders: u32,
+    },
+
+    /// Insert filled shards of the map up, so next inserts fail until shards
+    /// are appended or allocated, see `VftAdmin::append_balances_shard`.
+    StorageSaturated {
+        map: StorageMap,
+        shards: u32,
+        capacity: u32,
+    },
+
+    /// Insert made occupancy of the map cross the threshold of its alert,
+    /// see `VftAdmin::set_capacity_alert`.
+    CapacityWarning {
+        map: StorageMap,
+        entries: u32,
+        capacity: u32,
+    },
+
+    /// Volume within the rolling window exceeded thresholds of the circuit breaker,
+    /// so mints and transfers were halted until resumed, see `VftAdmin::resume`.
+    CircuitBreakerTripped { minted: U256, transferred: U256 },
 }
@@ -0,0 +1,72 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Minimal client of the `Vft` service of remote programs.
+
+use awesome_sails_utils::ensure;
+use sails_rs::{gstd, prelude::*};
+
+/// Client of the `Vft` service of a remote program.
+pub struct RemoteVft(ActorId);
+
+impl RemoteVft {
+    pub fn new(program: ActorId) -> Self {
+        Self(program)
+    }
+
+    /// Transfers `value` from the calling program to `to`.
+    pub async fn transfer(&self, to: ActorId, value: U256) -> Result<(), TransferFailed> {
+        self.call("Transfer", (to, value)).await
+    }
+
+    /// Transfers `value` from `from` to `to`, spending the allowance of the calling program.
+    pub async fn transfer_from(
+        &self,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> Result<(), TransferFailed> {
+        self.call("TransferFrom", (from, to, value)).await
+    }
+
+    async fn call(&self, method: &str, args: impl Encode) -> Result<(), TransferFailed> {
+        let payload = ("Vft", method, args).encode();
+
+        let reply = gstd::msg::send_bytes_for_reply(self.0, payload, 0, 0)
+            .map_err(|_| TransferFailed)?
+            .await
+            .map_err(|_| TransferFailed)?;
+
+        // Reply is prefixed with the route of the call.
+        let (_, _, transferred) =
+            <(String, String, bool)>::decode(&mut reply.as_slice()).map_err(|_| TransferFailed)?;
+
+        ensure!(transferred, TransferFailed);
+
+        Ok(())
+    }
+}
+
+/// Error type for failed transfers of remote VFTs.
+#[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+#[codec(crate = sails_rs::scale_codec)]
+#[error("Remote transfer failed")]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TransferFailed;
+
+awesome_sails_utils::impl_error_code!(TransferFailed => 120);
@@ -0,0 +1,50 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    error::ErrorCode,
+    math::{OverflowError, UnderflowError, ZeroError},
+};
+use awesome_sails_vft::{
+    remote::TransferFailed,
+    utils::{AllowancesError, BalancesError, ShardsFull, StorageMap},
+};
+use std::collections::BTreeSet;
+
+#[test]
+fn error_codes_are_unique() {
+    let shards_full = ShardsFull {
+        map: StorageMap::Balances,
+        shards: 0,
+        capacity: 0,
+    };
+
+    // Map errors forward codes of `awesome-sails-utils`.
+    let codes = [
+        AllowancesError::Insufficient(UnderflowError).code(),
+        BalancesError::BelowMinimum.code(),
+        BalancesError::Insufficient(UnderflowError).code(),
+        BalancesError::Overflow(OverflowError).code(),
+        BalancesError::Zero(ZeroError).code(),
+        shards_full.code(),
+        TransferFailed.code(),
+    ];
+
+    assert!(codes.iter().all(|code| (100..=199).contains(code)));
+    assert_eq!(codes.iter().collect::<BTreeSet<_>>().len(), codes.len());
+}
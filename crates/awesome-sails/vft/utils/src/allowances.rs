@@ -18,28 +18,53 @@
 
 //! Module for allowances-related VFT logic.
 
-use crate::Allowance;
+use crate::{AccountKey, Allowance, CapacityAlert, CapacityCheck, ShardsFull, StorageMap};
 use awesome_sails_utils::{
+    error::ErrorCode,
     map::{ShardedMap, ShardedMapError},
     math::{Math, MathError, NonZero, UnderflowError, Zero},
-    ok_if, unwrap_infallible,
+    ok_if,
+    period::PeriodBudget,
+    unwrap_infallible,
 };
 use core::ops::Deref;
-use sails_rs::{ActorId, Decode, Encode, TypeInfo, vec, vec::Vec};
+use sails_rs::{
+    ActorId, Decode, Encode, TypeInfo, U256,
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
 
-pub type AllowancesKey = (NonZero<ActorId>, NonZero<ActorId>);
+pub type AllowancesKey<K = ActorId> = (NonZero<K>, NonZero<K>);
 pub type AllowancesValue<T> = (NonZero<T>, u32);
 
 /// A sharded map for storing VFT allowances.
 ///
+/// Keys are additionally indexed by owner and by spender, so allowances
+/// may be enumerated for either side.
+///
+/// Owners may also approve operators, spending any value of the owner
+/// without allowances, ERC-777 style, and give spenders [`SpendingCap`]s,
+/// renewed every period, instead of allowances.
+///
+/// Accounts are keyed by `ActorId` by default, or by another [`AccountKey`].
+///
 /// All functions are transactional, meaning if err is returned,
 /// state hasn't been changed.
-pub struct Allowances<T = Allowance> {
+pub struct Allowances<T = Allowance, K = ActorId> {
     expiry_period: u32,
-    store: ShardedMap<AllowancesKey, AllowancesValue<T>>,
+    store: ShardedMap<AllowancesKey<K>, AllowancesValue<T>>,
+    by_owner: Index<K>,
+    by_spender: Index<K>,
+    operators: Index<K>,
+    operated: Index<K>,
+    caps: BTreeMap<AllowancesKey<K>, SpendingCap>,
+    capacity_alert: Option<CapacityAlert>,
 }
 
-impl<T> Allowances<T> {
+type Index<K> = BTreeMap<NonZero<K>, BTreeSet<NonZero<K>>>;
+
+impl<T, K: AccountKey> Allowances<T, K> {
     /// Default, recommended max shard capacity.
     pub const DEFAULT_MAX_SHARD: usize = 0b11100000000000000000000;
 
@@ -52,6 +77,12 @@ impl<T> Allowances<T> {
         Ok(Self {
             store,
             expiry_period,
+            by_owner: BTreeMap::new(),
+            by_spender: BTreeMap::new(),
+            operators: BTreeMap::new(),
+            operated: BTreeMap::new(),
+            caps: BTreeMap::new(),
+            capacity_alert: None,
         })
     }
 
@@ -77,13 +108,169 @@ impl<T> Allowances<T> {
         self.store.try_append_shard(capacity).map_err(Into::into)
     }
 
+    /// Returns occupancy of the underlying sharded map if its shards are full,
+    /// so next inserts fail with [`ShardsFull`].
+    pub fn saturation(&self) -> Option<ShardsFull> {
+        (!self.store.has_space()).then(|| ShardsFull::new(StorageMap::Allowances, &self.store))
+    }
+
+    /// Returns the alert of operators about occupancy of the underlying sharded map.
+    pub fn capacity_alert(&self) -> Option<CapacityAlert> {
+        self.capacity_alert
+    }
+
+    /// Sets the alert of operators about occupancy of the underlying sharded map.
+    pub fn set_capacity_alert(&mut self, alert: Option<CapacityAlert>) {
+        self.capacity_alert = alert;
+    }
+
+    /// Checks the underlying sharded map after inserts into it, given amount
+    /// of entries it held before, e.g. to report its occupancy to operators.
+    pub fn check_capacity(&self, len: usize) -> CapacityCheck {
+        CapacityCheck::new(
+            StorageMap::Allowances,
+            &self.store,
+            self.capacity_alert,
+            len,
+        )
+    }
+
+    /// Tries to remove the last empty shard of the underlying sharded map,
+    /// returning its max capacity.
+    ///
+    /// Reuses [`ShardedMap::try_remove_empty_shard`] under the hood.
+    pub fn try_remove_empty_shard(&mut self) -> Result<usize, AllowancesError> {
+        self.store.try_remove_empty_shard().map_err(Into::into)
+    }
+
+    /// Sets bloom filters of accounts in shards of the underlying sharded map.
+    ///
+    /// Reuses [`ShardedMap::set_filters`] under the hood.
+    pub fn set_filters(&mut self, bits_per_key: usize) {
+        self.store.set_filters(bits_per_key)
+    }
+
+    /// Returns spenders approved by the owner, in ascending order.
+    pub fn spenders_of(&self, owner: NonZero<K>) -> impl Iterator<Item = NonZero<K>> + '_ {
+        Self::range_of(&self.by_owner, owner)
+    }
+
+    /// Returns owners that approved the spender, in ascending order.
+    pub fn owners_of(&self, spender: NonZero<K>) -> impl Iterator<Item = NonZero<K>> + '_ {
+        Self::range_of(&self.by_spender, spender)
+    }
+
+    /// Returns `true` if the operator is approved by the owner.
+    pub fn is_operator(&self, owner: NonZero<K>, operator: NonZero<K>) -> bool {
+        self.operators
+            .get(&owner)
+            .is_some_and(|operators| operators.contains(&operator))
+    }
+
+    /// Returns operators approved by the owner, in ascending order.
+    pub fn operators_of(&self, owner: NonZero<K>) -> impl Iterator<Item = NonZero<K>> + '_ {
+        Self::range_of(&self.operators, owner)
+    }
+
+    /// Returns owners that approved the operator, in ascending order.
+    pub fn operated_by(&self, operator: NonZero<K>) -> impl Iterator<Item = NonZero<K>> + '_ {
+        Self::range_of(&self.operated, operator)
+    }
+
+    /// Approves or revokes the operator of the owner.
+    ///
+    /// Returns `false` if nothing changed, including the owner and operator being the same.
+    pub fn set_operator(
+        &mut self,
+        owner: NonZero<K>,
+        operator: NonZero<K>,
+        approved: bool,
+    ) -> bool {
+        if owner == operator || self.is_operator(owner, operator) == approved {
+            return false;
+        }
+
+        if approved {
+            self.operators.entry(owner).or_default().insert(operator);
+            self.operated.entry(operator).or_default().insert(owner);
+        } else {
+            remove_from(&mut self.operators, owner, operator);
+            remove_from(&mut self.operated, operator, owner);
+        }
+
+        true
+    }
+
+    /// Returns the spending cap given by the owner to the spender.
+    pub fn cap(&self, owner: NonZero<K>, spender: NonZero<K>) -> Option<&SpendingCap> {
+        self.caps.get(&(owner, spender))
+    }
+
+    /// Sets or, if `None`, removes the spending cap given by the owner to the spender,
+    /// returning the previous one.
+    ///
+    /// Noop if the owner and spender are the same.
+    pub fn set_cap(
+        &mut self,
+        owner: NonZero<K>,
+        spender: NonZero<K>,
+        cap: Option<SpendingCap>,
+    ) -> Option<SpendingCap> {
+        if owner == spender {
+            return None;
+        }
+
+        match cap {
+            Some(cap) => self.caps.insert((owner, spender), cap),
+            None => self.caps.remove(&(owner, spender)),
+        }
+    }
+
+    /// Rebuilds owner and spender indexes from the underlying map.
+    ///
+    /// Required after modifying the map directly.
+    pub fn rebuild_index(&mut self) {
+        self.by_owner.clear();
+        self.by_spender.clear();
+
+        let keys: Vec<_> = self.store.iter().map(|(&key, _)| key).collect();
+
+        for key in keys {
+            self.index(key);
+        }
+    }
+
+    fn range_of(index: &Index<K>, key: NonZero<K>) -> impl Iterator<Item = NonZero<K>> {
+        index.get(&key).into_iter().flatten().copied()
+    }
+
+    fn index(&mut self, (owner, spender): AllowancesKey<K>) {
+        self.by_owner.entry(owner).or_default().insert(spender);
+        self.by_spender.entry(spender).or_default().insert(owner);
+    }
+
+    fn unindex(&mut self, (owner, spender): AllowancesKey<K>) {
+        remove_from(&mut self.by_owner, owner, spender);
+        remove_from(&mut self.by_spender, spender, owner);
+    }
+
     /// Calculates the expiry since a given block number.
     const fn expiry(&self, current_bn: u32) -> u32 {
         self.expiry_period.saturating_add(current_bn)
     }
 }
 
-impl<T> Default for Allowances<T> {
+fn remove_from<K: AccountKey>(index: &mut Index<K>, key: NonZero<K>, value: NonZero<K>) {
+    if let Some(set) = index.get_mut(&key) {
+        set.remove(&value);
+
+        if set.is_empty() {
+            index.remove(&key);
+        }
+    }
+}
+
+impl<T, K: AccountKey> Default for Allowances<T, K> {
     fn default() -> Self {
         unwrap_infallible!(
             Self::try_new(vec![Self::DEFAULT_MAX_SHARD], u32::MAX).map_err(|_| unreachable!())
@@ -91,8 +278,8 @@ impl<T> Default for Allowances<T> {
     }
 }
 
-impl<T> Deref for Allowances<T> {
-    type Target = ShardedMap<AllowancesKey, AllowancesValue<T>>;
+impl<T, K> Deref for Allowances<T, K> {
+    type Target = ShardedMap<AllowancesKey<K>, AllowancesValue<T>>;
 
     fn deref(&self) -> &Self::Target {
         &self.store
@@ -106,11 +293,11 @@ impl core::ops::DerefMut for Allowances {
     }
 }
 
-impl<T: Clone + Math> Allowances<T> {
+impl<T: Clone + Math, K: AccountKey> Allowances<T, K> {
     /// Gets the allowance for a given owner and spender.
     ///
     /// Returns ZERO if the allowance is not found.
-    pub fn get(&self, owner: NonZero<ActorId>, spender: NonZero<ActorId>) -> T {
+    pub fn get(&self, owner: NonZero<K>, spender: NonZero<K>) -> T {
         self.deref()
             .get(&(owner, spender))
             .map(|(_, (v, _))| v.clone().into())
@@ -125,8 +312,8 @@ impl<T: Clone + Math> Allowances<T> {
     /// - allowance is insufficient.
     pub fn decrease(
         &mut self,
-        owner: NonZero<ActorId>,
-        spender: NonZero<ActorId>,
+        owner: NonZero<K>,
+        spender: NonZero<K>,
         value: NonZero<T>,
         current_bn: u32,
     ) -> Result<(), AllowancesError> {
@@ -150,6 +337,7 @@ impl<T: Clone + Math> Allowances<T> {
                 Err(MathError::Underflow(e)) => Err(e)?,
                 Err(MathError::Zero(_)) => {
                     self.store.remove_at(idx, &(owner, spender));
+                    self.unindex((owner, spender));
                 }
                 Err(MathError::Overflow(_)) => unreachable!(),
             };
@@ -158,13 +346,38 @@ impl<T: Clone + Math> Allowances<T> {
         Ok(())
     }
 
-    /// Removes the allowance for a given owner and spender and returns the value.
-    pub fn remove(
+    /// Spends `value` of the owner by the spender: free for operators,
+    /// from the spending cap if any, or from the allowance otherwise.
+    ///
+    /// Fails if:
+    /// - cap or allowance is insufficient.
+    pub fn spend(
         &mut self,
-        owner: NonZero<ActorId>,
-        spender: NonZero<ActorId>,
-    ) -> Option<AllowancesValue<T>> {
-        self.store.remove(&(owner, spender)).map(|(_, v)| v)
+        owner: NonZero<K>,
+        spender: NonZero<K>,
+        value: NonZero<T>,
+        current_bn: u32,
+    ) -> Result<(), AllowancesError>
+    where
+        T: Into<U256>,
+    {
+        ok_if!(owner == spender || self.is_operator(owner, spender));
+
+        match self.caps.get_mut(&(owner, spender)) {
+            Some(cap) => cap
+                .try_spend(value.into_inner().into(), current_bn)
+                .map_err(|_| AllowancesError::Insufficient(UnderflowError)),
+            None => self.decrease(owner, spender, value, current_bn),
+        }
+    }
+
+    /// Removes the allowance for a given owner and spender and returns the value.
+    pub fn remove(&mut self, owner: NonZero<K>, spender: NonZero<K>) -> Option<AllowancesValue<T>> {
+        let (_, value) = self.store.remove(&(owner, spender))?;
+
+        self.unindex((owner, spender));
+
+        Some(value)
     }
 
     /// Sets the allowance for a given owner and spender, returning the previous value.
@@ -177,8 +390,8 @@ impl<T: Clone + Math> Allowances<T> {
     /// - map capacity is exceeded.
     pub fn set(
         &mut self,
-        owner: NonZero<ActorId>,
-        spender: NonZero<ActorId>,
+        owner: NonZero<K>,
+        spender: NonZero<K>,
         value: T,
         current_bn: u32,
     ) -> Result<Option<NonZero<T>>, AllowancesError> {
@@ -187,7 +400,15 @@ impl<T: Clone + Math> Allowances<T> {
         let previous = if let Ok(value) = NonZero::try_new(value) {
             let (_, previous) = self
                 .store
-                .try_insert((owner, spender), (value, self.expiry(current_bn)))?;
+                .try_insert((owner, spender), (value, self.expiry(current_bn)))
+                .map_err(|err| match err {
+                    ShardedMapError::CapacityOverflow => {
+                        ShardsFull::new(StorageMap::Allowances, &self.store).into()
+                    }
+                    err => AllowancesError::from(err),
+                })?;
+
+            self.index((owner, spender));
 
             previous
         } else {
@@ -198,6 +419,9 @@ impl<T: Clone + Math> Allowances<T> {
     }
 }
 
+/// Allowance renewed every period of blocks, e.g. for subscriptions.
+pub type SpendingCap = PeriodBudget;
+
 #[derive(
     Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, thiserror::Error,
 )]
@@ -208,4 +432,16 @@ pub enum AllowancesError {
     Insufficient(#[from] UnderflowError),
     #[error("sharded map error: {0}")]
     Map(#[from] ShardedMapError),
+    #[error(transparent)]
+    ShardsFull(#[from] ShardsFull),
+}
+
+impl ErrorCode for AllowancesError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::Insufficient(_) => 100,
+            Self::Map(err) => err.code(),
+            Self::ShardsFull(err) => err.code(),
+        }
+    }
 }
@@ -18,29 +18,173 @@
 
 //! Module for balances-related VFT logic.
 
-use crate::Balance;
+use crate::{
+    AccountKey, Balance, CapacityAlert, CapacityCheck, CircuitBreaker, CircuitBreakerConfig,
+    ShardsFull, Snapshot, SnapshotRoot, StorageMap, Tripped,
+};
 use awesome_sails_utils::{
+    checkpoint::Checkpoints,
+    ensure,
+    error::ErrorCode,
     map::{ShardedMap, ShardedMapError},
     math::{CheckedMath, Math, MathError, NonZero, OverflowError, UnderflowError, Zero, ZeroError},
-    ok_if, unwrap_infallible,
+    ok_if,
+    page::DEFAULT_MAX_PAGE_SIZE,
+    unwrap_infallible,
 };
 use core::{mem, ops::Deref};
-use sails_rs::{ActorId, Decode, Encode, TypeInfo, U256, vec, vec::Vec};
+use sails_rs::{
+    ActorId, Decode, Encode, TypeInfo, U256,
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
 
-pub type BalancesKey = NonZero<ActorId>;
+pub type BalancesKey<K = ActorId> = NonZero<K>;
 pub type BalancesValue<T> = NonZero<T>;
 
+/// Policy of handling dust: the remaining balance of an account
+/// falling below the minimum balance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum DustPolicy {
+    /// Removes the account, moving dust to the unused value.
+    #[default]
+    BurnToUnused,
+    /// Removes the account, crediting dust to the treasury account.
+    ///
+    /// The treasury itself is exempt from the dust removal.
+    TransferToTreasury(ActorId),
+    /// Fails operations leaving dust.
+    Reject,
+}
+
+impl DustPolicy {
+    /// Returns the treasury account dust is credited to, if any.
+    pub fn treasury(&self) -> Option<NonZero<ActorId>> {
+        match self {
+            Self::TransferToTreasury(treasury) => NonZero::try_from(*treasury).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Transfer escrowed until claimed by the recipient,
+/// or reclaimed by the sender once expired.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Claim {
+    pub sender: ActorId,
+    pub recipient: ActorId,
+    pub value: U256,
+    /// Block the sender may reclaim the value from.
+    pub expires_at: u32,
+}
+
+impl Claim {
+    pub fn is_expired(&self, bn: u32) -> bool {
+        bn >= self.expires_at
+    }
+}
+
+/// Pending claims by their ids, indexed by senders and recipients.
+#[derive(Debug, Default)]
+pub struct Claims {
+    next_id: u64,
+    claims: BTreeMap<u64, Claim>,
+    by_sender: ClaimsIndex,
+    by_recipient: ClaimsIndex,
+}
+
+type ClaimsIndex = BTreeMap<ActorId, BTreeSet<u64>>;
+
+impl Claims {
+    pub fn get(&self, id: u64) -> Option<&Claim> {
+        self.claims.get(&id)
+    }
+
+    /// Iterates over ids of claims sent by the sender, in ascending order.
+    pub fn sent_by(&self, sender: ActorId) -> impl Iterator<Item = u64> + '_ {
+        self.by_sender.get(&sender).into_iter().flatten().copied()
+    }
+
+    /// Iterates over ids of claims sent to the recipient, in ascending order.
+    pub fn sent_to(&self, recipient: ActorId) -> impl Iterator<Item = u64> + '_ {
+        self.by_recipient
+            .get(&recipient)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Inserts the claim, returning its id.
+    pub fn insert(&mut self, claim: Claim) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.by_sender.entry(claim.sender).or_default().insert(id);
+        self.by_recipient
+            .entry(claim.recipient)
+            .or_default()
+            .insert(id);
+        self.claims.insert(id, claim);
+
+        id
+    }
+
+    /// Removes the claim, returning it if it was pending.
+    pub fn remove(&mut self, id: u64) -> Option<Claim> {
+        let claim = self.claims.remove(&id)?;
+
+        unindex(&mut self.by_sender, claim.sender, id);
+        unindex(&mut self.by_recipient, claim.recipient, id);
+
+        Some(claim)
+    }
+}
+
+fn unindex(index: &mut ClaimsIndex, account: ActorId, id: u64) {
+    if let Some(ids) = index.get_mut(&account) {
+        ids.remove(&id);
+
+        if ids.is_empty() {
+            index.remove(&account);
+        }
+    }
+}
+
 /// A sharded map for storing VFT balances.
 ///
+/// Accounts are keyed by `ActorId` by default, or by another [`AccountKey`],
+/// e.g. `H160` for tokens identifying holders by Ethereum addresses.
+///
+/// Value of pending [`Claims`] is escrowed on the balance of the program,
+/// exempt from the minimum balance, see [`Self::transfer_escrowed`].
+///
 /// All functions are transactional, meaning if err is returned,
 /// state hasn't been changed.
-pub struct Balances<T = Balance> {
-    store: ShardedMap<BalancesKey, BalancesValue<T>>,
+pub struct Balances<T = Balance, K = ActorId> {
+    store: ShardedMap<BalancesKey<K>, BalancesValue<T>>,
     total: U256,
     unused: U256,
+    minimum: U256,
+    dust_policy: DustPolicy,
+    supply_checkpoints: Checkpoints<U256>,
+    /// Cumulative value minted, saturating.
+    minted: U256,
+    /// Cumulative value transferred between accounts, saturating.
+    transferred: U256,
+    circuit_breaker: CircuitBreaker,
+    claims: Claims,
+    snapshot: Snapshot<K>,
+    /// Max amount of items enumeration exports reply with at once.
+    max_page_size: u32,
+    capacity_alert: Option<CapacityAlert>,
 }
 
-impl<T> Balances<T> {
+impl<T, K> Balances<T, K> {
     /// Default, recommended max shard capacity.
     pub const DEFAULT_MAX_SHARD: usize = 0b111000000000000000000000;
 
@@ -54,6 +198,16 @@ impl<T> Balances<T> {
             store,
             total: U256::zero(),
             unused: U256::zero(),
+            minimum: U256::zero(),
+            dust_policy: DustPolicy::BurnToUnused,
+            supply_checkpoints: Checkpoints::default(),
+            minted: U256::zero(),
+            transferred: U256::zero(),
+            circuit_breaker: CircuitBreaker::default(),
+            claims: Claims::default(),
+            snapshot: Snapshot::default(),
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+            capacity_alert: None,
         })
     }
 
@@ -62,12 +216,112 @@ impl<T> Balances<T> {
         self.total
     }
 
+    /// Returns the total supply as of the end of the given block,
+    /// or `None` if the block precedes the kept supply history.
+    pub fn total_supply_at(&self, block: u32) -> Option<U256> {
+        self.supply_checkpoints.at(block).copied()
+    }
+
+    /// Records the current total supply in the supply history.
+    ///
+    /// Must be called by services changing the total supply (minting or burning),
+    /// keeping [`Self::total_supply_at`] accurate.
+    pub fn checkpoint_supply(&mut self, current_bn: u32) {
+        self.supply_checkpoints.push(current_bn, self.total);
+    }
+
+    /// Returns the cumulative value transferred between accounts, saturating,
+    /// e.g. for monitors to measure the volume transferred between their checks.
+    pub fn transferred_volume(&self) -> U256 {
+        self.transferred
+    }
+
+    /// Returns the cumulative value minted, saturating.
+    pub fn minted_volume(&self) -> U256 {
+        self.minted
+    }
+
+    /// Returns the circuit breaker halting mints and transfers,
+    /// see [`Self::check_circuit_breaker`].
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.circuit_breaker
+    }
+
+    /// Sets thresholds of the circuit breaker, resetting it.
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) {
+        self.circuit_breaker
+            .configure(config, (self.minted, self.transferred));
+    }
+
+    /// Resets the circuit breaker, resuming mints and transfers, so volume
+    /// which tripped it doesn't trip it again.
+    ///
+    /// Returns `true` if the breaker was tripped.
+    pub fn reset_circuit_breaker(&mut self) -> bool {
+        self.circuit_breaker.reset((self.minted, self.transferred))
+    }
+
+    /// Accounts volume minted and transferred since the last check in the
+    /// circuit breaker, tripping it if volume within its window exceeds thresholds.
+    ///
+    /// Returns the volume if the breaker tripped.
+    pub fn check_circuit_breaker(&mut self, current_bn: u32) -> Option<Tripped> {
+        self.circuit_breaker
+            .check((self.minted, self.transferred), current_bn)
+    }
+
     /// Returns the unused value of the balances: nobody's balance,
     /// created during burns of dust (balances below minimum).
     pub fn unused_value(&self) -> U256 {
         self.unused
     }
 
+    /// Returns the minimum balance (existential deposit) of an account.
+    pub fn minimum_balance(&self) -> U256 {
+        self.minimum
+    }
+
+    /// Sets the minimum balance of an account.
+    ///
+    /// Existing balances below the new minimum are kept until they change.
+    pub fn set_minimum_balance(&mut self, minimum: U256) {
+        self.minimum = minimum;
+    }
+
+    /// Returns the snapshot of holders, see [`Self::continue_snapshot`].
+    pub fn snapshot(&self) -> &Snapshot<K> {
+        &self.snapshot
+    }
+
+    /// Returns the max amount of items enumeration exports reply with at once.
+    pub fn max_page_size(&self) -> u32 {
+        self.max_page_size
+    }
+
+    /// Sets the max amount of items enumeration exports reply with at once.
+    pub fn set_max_page_size(&mut self, max_page_size: u32) {
+        self.max_page_size = max_page_size;
+    }
+
+    /// Returns the policy of handling dust.
+    pub fn dust_policy(&self) -> DustPolicy {
+        self.dust_policy
+    }
+
+    /// Sets the policy of handling dust.
+    pub fn set_dust_policy(&mut self, policy: DustPolicy) {
+        self.dust_policy = policy;
+    }
+
+    /// Returns pending claims of escrowed transfers.
+    pub fn claims(&self) -> &Claims {
+        &self.claims
+    }
+
+    pub fn claims_mut(&mut self) -> &mut Claims {
+        &mut self.claims
+    }
+
     /// Allocates next shard of underlying sharded map.
     ///
     /// Returns bool indicating if there're unallocated shards left.
@@ -79,9 +333,49 @@ impl<T> Balances<T> {
     pub fn try_append_shard(&mut self, capacity: usize) -> Result<(), BalancesError> {
         self.store.try_append_shard(capacity).map_err(Into::into)
     }
+
+    /// Returns occupancy of the underlying sharded map if its shards are full,
+    /// so next inserts fail with [`ShardsFull`].
+    pub fn saturation(&self) -> Option<ShardsFull> {
+        (!self.store.has_space()).then(|| ShardsFull::new(StorageMap::Balances, &self.store))
+    }
+
+    /// Returns the alert of operators about occupancy of the underlying sharded map.
+    pub fn capacity_alert(&self) -> Option<CapacityAlert> {
+        self.capacity_alert
+    }
+
+    /// Sets the alert of operators about occupancy of the underlying sharded map.
+    pub fn set_capacity_alert(&mut self, alert: Option<CapacityAlert>) {
+        self.capacity_alert = alert;
+    }
+
+    /// Checks the underlying sharded map after inserts into it, given amount
+    /// of entries it held before, e.g. to report its occupancy to operators.
+    pub fn check_capacity(&self, len: usize) -> CapacityCheck {
+        CapacityCheck::new(StorageMap::Balances, &self.store, self.capacity_alert, len)
+    }
+
+    /// Tries to remove the last empty shard of the underlying sharded map,
+    /// returning its max capacity.
+    ///
+    /// Reuses [`ShardedMap::try_remove_empty_shard`] under the hood.
+    pub fn try_remove_empty_shard(&mut self) -> Result<usize, BalancesError> {
+        self.store.try_remove_empty_shard().map_err(Into::into)
+    }
+
+    /// Sets bloom filters of accounts in shards of the underlying sharded map.
+    ///
+    /// Reuses [`ShardedMap::set_filters`] under the hood.
+    pub fn set_filters(&mut self, bits_per_key: usize)
+    where
+        K: AccountKey,
+    {
+        self.store.set_filters(bits_per_key)
+    }
 }
 
-impl<T: Zero> Default for Balances<T> {
+impl<T: Zero, K> Default for Balances<T, K> {
     fn default() -> Self {
         unwrap_infallible!(
             Self::try_new(vec![Self::DEFAULT_MAX_SHARD; 2]).map_err(|_| unreachable!())
@@ -89,8 +383,8 @@ impl<T: Zero> Default for Balances<T> {
     }
 }
 
-impl<T> Deref for Balances<T> {
-    type Target = ShardedMap<NonZero<ActorId>, NonZero<T>>;
+impl<T, K> Deref for Balances<T, K> {
+    type Target = ShardedMap<NonZero<K>, NonZero<T>>;
 
     fn deref(&self) -> &Self::Target {
         &self.store
@@ -117,42 +411,107 @@ impl<T> Balances<T> {
     }
 }
 
-impl<T: Clone + Math + PartialOrd> Balances<T>
+impl<T: Clone + Math + PartialOrd, K: AccountKey> Balances<T, K>
 where
     U256: From<T>,
 {
     /// Gets the balance for a given account.
     ///
     /// Returns ZERO if the balance is not found.
-    pub fn get(&self, account: NonZero<ActorId>) -> T {
+    pub fn get(&self, account: NonZero<K>) -> T {
         self.store
             .get(&account)
             .map(|(_, v)| v.clone().into())
             .unwrap_or(Zero::ZERO)
     }
 
+    /// Hashes up to `len` more holders into the snapshot being computed,
+    /// starting it at the current block if there's none.
+    ///
+    /// Balances changed while computing are hashed as of the block of the snapshot,
+    /// so it's only restarted once holders move within the map, i.e. its shards
+    /// are removed, see [`ShardedMap::layout`].
+    ///
+    /// Returns the snapshot once all holders are hashed, kept as the latest one.
+    pub fn continue_snapshot(&mut self, len: u32, current_bn: u32) -> Option<SnapshotRoot>
+    where
+        K: Encode,
+    {
+        let pending = self.snapshot.resume(self.store.layout(), current_bn);
+
+        let mut holders = self.store.iter_from(pending.cursor);
+
+        for (cursor, account, balance) in holders.by_ref().take(len as usize) {
+            pending.visit(account.into_inner(), balance.clone().cast::<U256>());
+            pending.cursor = cursor;
+        }
+
+        if holders.next().is_some() {
+            return None;
+        }
+
+        self.snapshot.finish()
+    }
+
+    /// Preserves the balance of the account for the snapshot being computed,
+    /// if any, before it's changed.
+    fn preserve(&mut self, account: NonZero<K>) {
+        if self.snapshot.pending_block().is_none() {
+            return;
+        }
+
+        let position = self.store.cursor_of(&account);
+        let balance = self.get(account).into();
+
+        self.snapshot
+            .preserve(account.into_inner(), position, balance);
+    }
+
     /// Burns a specified amount of value from the balance of a given account,
     /// reducing the total supply.
     ///
     /// If the balance after burning is below the minimum balance, the account
-    /// is removed from the store and unused value increased accordingly.
+    /// is removed from the store and its dust handled by the dust policy.
+    ///
+    /// Returns the dust of the account if it was removed.
     ///
     /// Fails if:
     /// - balance is insufficient;
-    /// - unused balance overflows.
+    /// - dust is left, while rejected by the dust policy;
+    /// - treasury balance overflows or map capacity exceed.
     pub fn burn(
         &mut self,
-        account: NonZero<ActorId>,
+        account: NonZero<K>,
         value: NonZero<T>,
-    ) -> Result<(), BalancesError> {
-        let (idx, balance) = self.store.get_mut(&account).ok_or(UnderflowError)?;
+    ) -> Result<Option<T>, BalancesError> {
+        self.preserve(account);
+
+        let (idx, balance) = self.store.get(&account).ok_or(UnderflowError)?;
 
-        match balance.clone().try_sub(value.clone()) {
+        let reaped = match balance.clone().try_sub(value.clone()) {
+            Ok(remaining) if self.is_dust(account, &remaining) => {
+                self.check_dust(&remaining)?;
+
+                self.store.remove_at(idx, &account);
+                self.put_dust(remaining.clone());
+
+                Some(remaining.into_inner())
+            }
             Ok(remaining) => {
+                let balance = unwrap_infallible!(
+                    self.store
+                        .get_mut_at(idx, &account)
+                        .ok_or_else(|| unreachable!())
+                );
+
                 *balance = remaining;
+
+                None
             }
             Err(MathError::Zero(_)) => {
                 self.store.remove_at(idx, &account);
+
+                Some(Zero::ZERO)
             }
             Err(MathError::Overflow(e)) => Err(e)?,
             Err(MathError::Underflow(e)) => Err(e)?,
@@ -164,14 +523,39 @@ where
                 .ok_or_else(|| unreachable!())
         );
 
-        Ok(())
+        Ok(reaped)
+    }
+
+    /// Checks that [`Self::burn`] succeeds, without mutating the store.
+    ///
+    /// Returns the dust of the account if the burn would remove it.
+    pub fn check_burn(
+        &self,
+        account: NonZero<K>,
+        value: NonZero<T>,
+    ) -> Result<Option<T>, BalancesError> {
+        let (_, balance) = self.store.get(&account).ok_or(UnderflowError)?;
+
+        match balance.clone().try_sub(value) {
+            Ok(remaining) if self.is_dust(account, &remaining) => {
+                self.check_dust(&remaining)?;
+
+                Ok(Some(remaining.into_inner()))
+            }
+            Ok(_) => Ok(None),
+            Err(MathError::Zero(_)) => Ok(Some(Zero::ZERO)),
+            Err(MathError::Overflow(e)) => Err(e)?,
+            Err(MathError::Underflow(e)) => Err(e)?,
+        }
     }
 
     /// Burns all value from the balance of a given account,
     /// reducing the total supply.
     ///
     /// Returns the amount of the burned value.
-    pub fn burn_all(&mut self, account: NonZero<ActorId>) -> T {
+    pub fn burn_all(&mut self, account: NonZero<K>) -> T {
+        self.preserve(account);
+
         let Some(value) = self.store.remove(&account).map(|(_, v)| v.into_inner()) else {
             return Zero::ZERO;
         };
@@ -199,29 +583,39 @@ where
     /// Mints a specified amount of value for a given account, increasing the total supply.
     ///
     /// Fails if:
+    /// - mints are halted by the circuit breaker;
     /// - new account balance is below the minimum;
     /// - new account balance overflows;
     /// - total supply overflows;
     /// - map capacity exceed.
-    pub fn mint(
-        &mut self,
-        account: NonZero<ActorId>,
-        value: NonZero<T>,
-    ) -> Result<(), BalancesError> {
-        let new_total = self.total.checked_add_err(value.clone().cast())?;
+    pub fn mint(&mut self, account: NonZero<K>, value: NonZero<T>) -> Result<(), BalancesError> {
+        ensure!(!self.circuit_breaker.is_tripped(), BalancesError::Halted);
+
+        self.preserve(account);
+
+        let minted: U256 = value.clone().cast();
+        let new_total = self.total.checked_add_err(minted)?;
+        let minimum = self.minimum;
 
         match self.store.get_mut(&account) {
             Some((_, balance)) => {
                 let new_balance = balance.clone().try_add(value)?;
 
+                ensure_minimum(&new_balance, minimum)?;
+
                 *balance = new_balance;
             }
             None => unsafe {
-                self.store.try_insert_new(account, value)?;
+                ensure_minimum(&value, minimum)?;
+
+                self.store
+                    .try_insert_new(account, value)
+                    .map_err(|err| self.shards_full(err))?;
             },
         }
 
         self.total = new_total;
+        self.minted = self.minted.saturating_add(minted);
 
         Ok(())
     }
@@ -230,49 +624,125 @@ where
     ///
     /// If `to` is zero, it's equivalent to [`Self::burn`].
     ///
+    /// Returns the dust of `from` if it was removed.
+    ///
     /// Fails if:
+    /// - transfers are halted by the circuit breaker, unless burning;
     /// - `from` balance is insufficient;
+    /// - `from` dust is left, while rejected by the dust policy;
     /// - new `to` balance is below the minimum;
-    /// - new `to` balance overflows;
+    /// - new `to` or treasury balance overflows;
     /// - total supply overflows;
-    /// - unused balance overflows;
     /// - map capacity exceed.
     pub fn transfer(
         &mut self,
-        from: NonZero<ActorId>,
-        to: ActorId,
+        from: NonZero<K>,
+        to: K,
         value: NonZero<T>,
-    ) -> Result<(), BalancesError> {
-        let Ok(to) = NonZero::try_from(to) else {
+    ) -> Result<Option<T>, BalancesError> {
+        let Ok(to) = NonZero::try_new(to) else {
             return self.burn(from, value);
         };
 
-        ok_if!(from == to);
+        self.transfer_exempting(from, to, value, None)
+    }
+
+    /// Transfers a specified amount of value from one account to another,
+    /// one of them being the `escrow` account holding value on behalf of others,
+    /// e.g. the program.
+    ///
+    /// Unlike [`Self::transfer`], the escrow account is exempt from the minimum
+    /// balance, so it's neither rejected as the receiver, nor removed as dust,
+    /// so value escrowed for others can't be lost.
+    ///
+    /// Returns the dust of `from` if it was removed, being zero if `from` was emptied.
+    ///
+    /// Fails under the same conditions as [`Self::transfer`], except for the minimum
+    /// balance of the escrow account.
+    pub fn transfer_escrowed(
+        &mut self,
+        from: NonZero<K>,
+        to: NonZero<K>,
+        value: NonZero<T>,
+        escrow: NonZero<K>,
+    ) -> Result<Option<T>, BalancesError> {
+        self.transfer_exempting(from, to, value, Some(escrow))
+    }
+
+    /// Transfers value between non-zero accounts, with the `exempt` account
+    /// not subject to the minimum balance.
+    fn transfer_exempting(
+        &mut self,
+        from: NonZero<K>,
+        to: NonZero<K>,
+        value: NonZero<T>,
+        exempt: Option<NonZero<K>>,
+    ) -> Result<Option<T>, BalancesError> {
+        ok_if!(from == to, None);
+        ensure!(!self.circuit_breaker.is_tripped(), BalancesError::Halted);
+
+        self.preserve(from);
+        self.preserve(to);
 
+        let minimum = self.minimum;
         let (idx_from, balance_from) = self.store.get(&from).ok_or(UnderflowError)?;
 
+        let mut value = value;
         let mut new_balance_from = None;
+        let mut dust = None;
+
+        let is_dust =
+            |remaining: &NonZero<T>| self.is_dust(from, remaining) && exempt != Some(from);
 
-        match balance_from.clone().try_sub(value.clone()) {
+        let reaped = match balance_from.clone().try_sub(value.clone()) {
+            // Dust credited to the receiver is transferred along with the value.
+            Ok(remaining_from) if is_dust(&remaining_from) && self.treasury() == Some(to) => {
+                value = balance_from.clone();
+
+                Some(remaining_from.into_inner())
+            }
+            Ok(remaining_from) if is_dust(&remaining_from) => {
+                self.check_dust(&remaining_from)?;
+
+                dust = Some(remaining_from.clone());
+
+                Some(remaining_from.into_inner())
+            }
             Ok(remaining_from) => {
                 new_balance_from = Some(remaining_from);
+
+                None
             }
-            Err(MathError::Zero(_)) => { /* `from` to be removed */ }
+            Err(MathError::Zero(_)) => Some(Zero::ZERO),
             Err(MathError::Overflow(e)) => Err(e)?,
             Err(MathError::Underflow(e)) => Err(e)?,
         };
 
+        let volume: U256 = value.clone().cast();
         let mut insert_balance_to = None;
 
+        // The exempt receiver isn't subject to the minimum.
+        let minimum = if exempt == Some(to) {
+            U256::zero()
+        } else {
+            minimum
+        };
+
         match self.store.get_mut(&to) {
             Some((_, balance_to)) => {
                 let new_balance_to = balance_to.clone().try_add(value)?;
 
+                ensure_minimum(&new_balance_to, minimum)?;
+
                 *balance_to = new_balance_to;
             }
             None => {
+                ensure_minimum(&value, minimum)?;
+
                 if new_balance_from.is_some() {
-                    self.store.has_space_err()?;
+                    self.store
+                        .has_space_err()
+                        .map_err(|err| self.shards_full(err))?;
                 }
 
                 insert_balance_to = Some(value);
@@ -311,7 +781,13 @@ where
             }
         };
 
-        Ok(())
+        if let Some(dust) = dust {
+            self.put_dust(dust);
+        }
+
+        self.transferred = self.transferred.saturating_add(volume);
+
+        Ok(reaped)
     }
 
     /// Transfers all value from one account to another, returning the amount
@@ -320,27 +796,33 @@ where
     /// If `to` is zero, it's equivalent to [`Self::burn_all`].
     ///
     /// Fails if:
+    /// - transfers are halted by the circuit breaker;
     /// - new `to` balance is below the minimum;
     /// - new `to` balance overflows.
-    pub fn transfer_all(
-        &mut self,
-        from: NonZero<ActorId>,
-        to: NonZero<ActorId>,
-    ) -> Result<T, BalancesError> {
+    pub fn transfer_all(&mut self, from: NonZero<K>, to: NonZero<K>) -> Result<T, BalancesError> {
         let Some((idx_from, balance_from)) = self.store.get(&from).map(|(i, b)| (i, b.clone()))
         else {
             return Ok(Zero::ZERO);
         };
 
         ok_if!(from == to, balance_from);
+        ensure!(!self.circuit_breaker.is_tripped(), BalancesError::Halted);
 
+        self.preserve(from);
+        self.preserve(to);
+
+        let minimum = self.minimum;
         let mut insert_balance_to = None;
 
         if let Some((_, balance_to)) = self.store.get_mut(&to) {
             let new_balance_to = balance_to.clone().try_add(balance_from)?;
 
+            ensure_minimum(&new_balance_to, minimum)?;
+
             *balance_to = new_balance_to;
         } else {
+            ensure_minimum(&balance_from, minimum)?;
+
             insert_balance_to = Some(balance_from);
         }
 
@@ -360,20 +842,120 @@ where
             }
         }
 
-        Ok(balance_from.into())
+        let balance_from: T = balance_from.into();
+
+        self.transferred = self.transferred.saturating_add(balance_from.clone().into());
+
+        Ok(balance_from)
+    }
+
+    /// Maps capacity overflow of the underlying sharded map into [`ShardsFull`].
+    fn shards_full(&self, err: ShardedMapError) -> BalancesError {
+        match err {
+            ShardedMapError::CapacityOverflow => {
+                ShardsFull::new(StorageMap::Balances, &self.store).into()
+            }
+            err => err.into(),
+        }
+    }
+
+    /// Checks if the remaining balance of the account is dust to be removed.
+    fn is_dust(&self, account: NonZero<K>, remaining: &NonZero<T>) -> bool {
+        remaining.clone().cast::<U256>() < self.minimum && self.treasury() != Some(account)
+    }
+
+    /// Returns the key of the treasury account dust is credited to, if any.
+    fn treasury(&self) -> Option<NonZero<K>> {
+        self.dust_policy
+            .treasury()
+            .and_then(|treasury| K::from_actor_id(treasury.into_inner()))
+            .and_then(|treasury| NonZero::try_new(treasury).ok())
+    }
+
+    /// Checks that dust can be handled by the dust policy.
+    fn check_dust(&self, dust: &NonZero<T>) -> Result<(), BalancesError> {
+        ensure!(
+            self.dust_policy != DustPolicy::Reject,
+            BalancesError::BelowMinimum
+        );
+
+        if let Some(treasury) = self.treasury() {
+            match self.store.get(&treasury) {
+                Some((_, balance)) => {
+                    balance.clone().try_add(dust.clone())?;
+                }
+                None => self
+                    .store
+                    .has_space_err()
+                    .map_err(|err| self.shards_full(err))?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles dust of a removed account by the dust policy.
+    ///
+    /// Must be preceded by [`Self::check_dust`].
+    fn put_dust(&mut self, dust: NonZero<T>) {
+        let Some(treasury) = self.treasury() else {
+            // Unused value is a part of the total supply, so it can't overflow.
+            self.unused = unwrap_infallible!(
+                self.unused
+                    .checked_add(dust.cast())
+                    .ok_or_else(|| unreachable!())
+            );
+
+            return;
+        };
+
+        self.preserve(treasury);
+
+        match self.store.get_mut(&treasury) {
+            Some((_, balance)) => {
+                *balance =
+                    unwrap_infallible!(balance.clone().try_add(dust).map_err(|_| unreachable!()));
+            }
+            None => unsafe {
+                unwrap_infallible!(
+                    self.store
+                        .try_insert_new(treasury, dust)
+                        .map_err(|_| unreachable!())
+                );
+            },
+        }
     }
 }
 
+/// Fails if the balance is below the minimum one.
+fn ensure_minimum<T: Clone>(balance: &NonZero<T>, minimum: U256) -> Result<(), BalancesError>
+where
+    U256: From<T>,
+{
+    ensure!(
+        balance.clone().cast::<U256>() >= minimum,
+        BalancesError::BelowMinimum
+    );
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum BalancesError {
+    #[error("balance is below the minimum")]
+    BelowMinimum,
+    #[error("mints and transfers are halted by the circuit breaker")]
+    Halted,
     #[error("insufficient balance")]
     Insufficient(#[from] UnderflowError),
     #[error("sharded map error: {0}")]
     Map(#[from] ShardedMapError),
     #[error("balance or supply overflow")]
     Overflow(#[from] OverflowError),
+    #[error(transparent)]
+    ShardsFull(#[from] ShardsFull),
     /// Addition ended up in zero.
     ///
     /// Should never happen with proper (unsigned) balance type used.
@@ -381,6 +963,20 @@ pub enum BalancesError {
     Zero(#[from] ZeroError),
 }
 
+impl ErrorCode for BalancesError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::BelowMinimum => 113,
+            Self::Halted => 114,
+            Self::Insufficient(_) => 110,
+            Self::Map(err) => err.code(),
+            Self::Overflow(_) => 111,
+            Self::ShardsFull(err) => err.code(),
+            Self::Zero(_) => 112,
+        }
+    }
+}
+
 impl From<MathError> for BalancesError {
     fn from(err: MathError) -> Self {
         match err {
@@ -0,0 +1,148 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Module for the circuit breaker of VFT balances.
+
+use sails_rs::{Decode, Encode, TypeInfo, U256, collections::VecDeque};
+
+/// Amount of buckets the rolling window of the circuit breaker is measured by.
+pub const WINDOW_BUCKETS: u32 = 16;
+
+/// Thresholds of the circuit breaker, halting mints and transfers if volume
+/// minted or transferred within a rolling window of blocks exceeds them.
+///
+/// Zero thresholds are disabled, and so is the breaker with zero `window_blocks`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CircuitBreakerConfig {
+    pub window_blocks: u32,
+    pub max_minted: U256,
+    pub max_transferred: U256,
+}
+
+/// Volume within the window which tripped the circuit breaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tripped {
+    pub minted: U256,
+    pub transferred: U256,
+}
+
+/// Anomaly monitor of [`Balances`](crate::Balances), measuring volume minted
+/// and transferred within the rolling window of `window_blocks` blocks, at
+/// the resolution of its [`WINDOW_BUCKETS`]-th part.
+///
+/// Volume is observed on checks by cumulative volumes of balances, so volume
+/// moved since the last check is accounted to the block of the next one.
+/// Once tripped, mints and transfers of balances fail until it's reset.
+#[derive(Clone, Debug, Default)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    /// Volumes minted and transferred by buckets of the window, oldest first.
+    buckets: VecDeque<(u32, U256, U256)>,
+    /// Cumulative volumes minted and transferred as of the last check.
+    observed: (U256, U256),
+    tripped: bool,
+}
+
+impl CircuitBreaker {
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.window_blocks != 0
+    }
+
+    /// Returns `true` if mints and transfers are halted.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped
+    }
+
+    /// Volumes minted and transferred within the window as of the last check.
+    pub fn volume(&self) -> (U256, U256) {
+        self.buckets.iter().fold(
+            (U256::zero(), U256::zero()),
+            |(minted, transferred), &(_, m, t)| {
+                (minted.saturating_add(m), transferred.saturating_add(t))
+            },
+        )
+    }
+
+    /// Replaces thresholds, resetting the breaker.
+    pub(crate) fn configure(&mut self, config: CircuitBreakerConfig, observed: (U256, U256)) {
+        self.config = config;
+        self.reset(observed);
+    }
+
+    /// Starts a new window, forgetting the volume measured so far
+    /// and resuming mints and transfers.
+    ///
+    /// Returns `true` if the breaker was tripped.
+    pub(crate) fn reset(&mut self, observed: (U256, U256)) -> bool {
+        self.buckets.clear();
+        self.observed = observed;
+
+        core::mem::take(&mut self.tripped)
+    }
+
+    /// Accounts volumes moved since the last check, given cumulative volumes
+    /// minted and transferred by balances.
+    ///
+    /// Returns volume within the window if it exceeds any threshold, tripping the breaker.
+    pub(crate) fn check(&mut self, observed: (U256, U256), current_bn: u32) -> Option<Tripped> {
+        let minted = observed.0.saturating_sub(self.observed.0);
+        let transferred = observed.1.saturating_sub(self.observed.1);
+
+        self.observed = observed;
+
+        if !self.is_enabled() || self.tripped {
+            return None;
+        }
+
+        let bucket = current_bn / self.config.window_blocks.div_ceil(WINDOW_BUCKETS);
+
+        // Buckets which left the window are forgotten.
+        while self
+            .buckets
+            .front()
+            .is_some_and(|&(start, _, _)| start + WINDOW_BUCKETS <= bucket)
+        {
+            self.buckets.pop_front();
+        }
+
+        match self.buckets.back_mut() {
+            Some((start, m, t)) if *start == bucket => {
+                *m = m.saturating_add(minted);
+                *t = t.saturating_add(transferred);
+            }
+            _ if minted.is_zero() && transferred.is_zero() => {}
+            _ => self.buckets.push_back((bucket, minted, transferred)),
+        }
+
+        let (minted, transferred) = self.volume();
+        let exceeds = |max: U256, volume: U256| !max.is_zero() && volume > max;
+
+        self.tripped = exceeds(self.config.max_minted, minted)
+            || exceeds(self.config.max_transferred, transferred);
+
+        self.tripped.then_some(Tripped {
+            minted,
+            transferred,
+        })
+    }
+}
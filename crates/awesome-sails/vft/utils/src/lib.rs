@@ -20,15 +20,48 @@
 
 use awesome_sails_utils::{
     impl_math_wrapper,
-    math::{LeBytes, Max},
+    map::{Routing, ShardedMap},
+    math::{LeBytes, Max, Zero},
 };
-use sails_rs::{Decode, Encode, TypeInfo};
+use core::{fmt, hash::Hash};
+use sails_rs::{ActorId, Decode, Encode, H160, TypeInfo};
 
 mod allowances;
 mod balances;
+mod circuit_breaker;
+mod snapshot;
 
-pub use allowances::{Allowances, AllowancesError, AllowancesKey, AllowancesValue};
-pub use balances::{Balances, BalancesError};
+pub use allowances::{Allowances, AllowancesError, AllowancesKey, AllowancesValue, SpendingCap};
+pub use balances::{
+    Balances, BalancesError, BalancesKey, BalancesValue, Claim, Claims, DustPolicy,
+};
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, Tripped, WINDOW_BUCKETS};
+pub use snapshot::{Snapshot, SnapshotRoot};
+
+// --- ACCOUNT KEY ---
+
+/// Key identifying accounts in [`Balances`] and [`Allowances`].
+///
+/// Accounts are keyed by `ActorId` by default, while `H160` keys allow
+/// programs to store holders by their Ethereum addresses.
+pub trait AccountKey: Copy + Ord + Hash + Zero {
+    /// Returns the key of the given actor, if it's representable.
+    fn from_actor_id(actor_id: ActorId) -> Option<Self>;
+}
+
+impl AccountKey for ActorId {
+    fn from_actor_id(actor_id: ActorId) -> Option<Self> {
+        Some(actor_id)
+    }
+}
+
+impl AccountKey for H160 {
+    fn from_actor_id(actor_id: ActorId) -> Option<Self> {
+        let address = actor_id.to_address_lossy();
+
+        (ActorId::from(address) == actor_id).then_some(address)
+    }
+}
 
 // --- ALLOWANCE ---
 
@@ -62,3 +95,127 @@ impl From<u64> for Balance {
         Self(LeBytes::<10>::try_from(u128::from(value)).unwrap())
     }
 }
+
+// --- STORAGE MAP ---
+
+/// Sharded map of the VFT storage.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum StorageMap {
+    Allowances,
+    Balances,
+}
+
+impl fmt::Display for StorageMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Allowances => f.write_str("allowances"),
+            Self::Balances => f.write_str("balances"),
+        }
+    }
+}
+
+/// Error type for inserts into the map with its shards full.
+///
+/// Carries occupancy of the map, so operators know to append shards
+/// to it or allocate its next ones.
+#[derive(
+    Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, thiserror::Error,
+)]
+#[codec(crate = sails_rs::scale_codec)]
+#[error("{map} shards are full: {capacity} entries in {shards} shards, append or allocate shards")]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ShardsFull {
+    pub map: StorageMap,
+    /// Amount of shards of the map, allocated or not.
+    pub shards: u32,
+    /// Amount of entries allocated shards of the map hold.
+    pub capacity: u32,
+}
+
+awesome_sails_utils::impl_error_code!(ShardsFull => 130);
+
+impl ShardsFull {
+    /// Returns the error for the map, given its underlying sharded map.
+    pub fn new<K, V, R: Routing>(map: StorageMap, store: &ShardedMap<K, V, R>) -> Self {
+        let metrics = store.metrics();
+
+        Self {
+            map,
+            shards: metrics.shards,
+            capacity: metrics.capacity,
+        }
+    }
+}
+
+/// Alert of operators about occupancy of the map.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CapacityAlert {
+    /// Occupancy of allocated shards alerting once crossed by inserts, in basis points.
+    pub threshold_bps: u16,
+    /// Account notified about the crossing, besides the event, if any.
+    pub operator: Option<ActorId>,
+}
+
+/// Occupancy of the map, which crossed the threshold of its [`CapacityAlert`].
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CapacityWarning {
+    pub map: StorageMap,
+    /// Amount of entries in the map.
+    pub entries: u32,
+    /// Amount of entries allocated shards of the map hold.
+    pub capacity: u32,
+}
+
+/// Outcome of inserts into the map, to be reported to operators.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CapacityCheck {
+    /// Occupancy of the map, if inserts filled its shards up.
+    pub saturation: Option<ShardsFull>,
+    /// Occupancy of the map, if inserts crossed the threshold of its alert,
+    /// along with the operator to notify.
+    pub warning: Option<(CapacityWarning, Option<ActorId>)>,
+}
+
+impl CapacityCheck {
+    /// Checks the map, given its underlying sharded map holding `len` entries before inserts.
+    fn new<K, V, R: Routing>(
+        map: StorageMap,
+        store: &ShardedMap<K, V, R>,
+        alert: Option<CapacityAlert>,
+        len: usize,
+    ) -> Self {
+        let entries = store.len();
+        let capacity = store.capacity();
+
+        if entries <= len {
+            return Self::default();
+        }
+
+        let crossed = |len: usize, alert: &CapacityAlert| {
+            len as u64 * 10_000 >= capacity as u64 * alert.threshold_bps as u64
+        };
+
+        let warning = alert
+            .filter(|alert| !crossed(len, alert) && crossed(entries, alert))
+            .map(|alert| {
+                let warning = CapacityWarning {
+                    map,
+                    entries: entries as u32,
+                    capacity: capacity as u32,
+                };
+
+                (warning, alert.operator)
+            });
+
+        Self {
+            saturation: (!store.has_space()).then(|| ShardsFull::new(map, store)),
+            warning,
+        }
+    }
+}
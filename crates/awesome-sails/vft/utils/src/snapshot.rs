@@ -0,0 +1,166 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Module for snapshots of VFT holders, committed by merkle roots.
+
+use awesome_sails_utils::{
+    map::MapCursor,
+    merkle::{self, Keccak256, MerkleBuilder},
+};
+use core::mem;
+use sails_rs::{ActorId, Decode, Encode, TypeInfo, U256, collections::BTreeMap};
+
+/// Commitment to balances of all holders as of the block, e.g. for airdrops
+/// or governance snapshots built off-chain to be verified against it.
+///
+/// `root` is the keccak-256 root of the [merkle](awesome_sails_utils::merkle) tree
+/// of `(account, balance).encode()` leaves, with `balance` as `U256`, in order
+/// of enumeration by `balances_from` while computed, followed by holders changed
+/// before visited in order of their keys.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SnapshotRoot {
+    pub block: u32,
+    pub root: [u8; 32],
+    pub holders: u32,
+}
+
+/// Snapshot of holders computed in chunks across messages,
+/// see [`Balances::continue_snapshot`](crate::Balances::continue_snapshot).
+///
+/// Balances changed while computing are copied on write: holders not visited
+/// yet keep their balances as of the block, see [`Self::preserve`].
+#[derive(Clone, Debug)]
+pub struct Snapshot<K = ActorId> {
+    pending: Option<PendingSnapshot<K>>,
+    latest: Option<SnapshotRoot>,
+}
+
+impl<K> Default for Snapshot<K> {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            latest: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct PendingSnapshot<K> {
+    block: u32,
+    /// Layout of the balances map the cursor is valid for.
+    layout: u64,
+    pub cursor: MapCursor,
+    tree: MerkleBuilder<Keccak256>,
+    /// Balances as of the block of accounts changed since, if still to be hashed.
+    preserved: BTreeMap<K, Option<U256>>,
+}
+
+impl<K: Ord> Snapshot<K> {
+    /// Returns the latest snapshot computed.
+    pub fn latest(&self) -> Option<SnapshotRoot> {
+        self.latest
+    }
+
+    /// Returns block the snapshot being computed is taken at, if any.
+    pub fn pending_block(&self) -> Option<u32> {
+        self.pending.as_ref().map(|pending| pending.block)
+    }
+
+    /// Keeps the balance of the account as of the block of the pending snapshot,
+    /// before it's changed, unless the account is visited already or preserved.
+    ///
+    /// `position` is the cursor of the account in the map, if it's a holder.
+    pub(crate) fn preserve(&mut self, account: K, position: Option<MapCursor>, balance: U256) {
+        let Some(pending) = self.pending.as_mut() else {
+            return;
+        };
+
+        let visited = position.is_none_or(|position| position < pending.cursor);
+
+        pending
+            .preserved
+            .entry(account)
+            .or_insert((!visited).then_some(balance));
+    }
+
+    /// Returns the pending snapshot of the given map layout,
+    /// starting a new one at the block if there's none.
+    pub(crate) fn resume(&mut self, layout: u64, current_bn: u32) -> &mut PendingSnapshot<K> {
+        if self
+            .pending
+            .as_ref()
+            .is_some_and(|pending| pending.layout != layout)
+        {
+            self.pending = None;
+        }
+
+        self.pending.get_or_insert_with(|| PendingSnapshot {
+            block: current_bn,
+            layout,
+            cursor: MapCursor::default(),
+            tree: MerkleBuilder::default(),
+            preserved: BTreeMap::new(),
+        })
+    }
+
+    /// Completes the pending snapshot, keeping it as the latest one.
+    pub(crate) fn finish(&mut self) -> Option<SnapshotRoot>
+    where
+        K: Encode,
+    {
+        let mut pending = self.pending.take()?;
+
+        for (account, balance) in mem::take(&mut pending.preserved) {
+            if let Some(balance) = balance {
+                pending.push((account, balance));
+            }
+        }
+
+        let snapshot = SnapshotRoot {
+            block: pending.block,
+            root: pending.tree.root(),
+            holders: pending.tree.len(),
+        };
+
+        self.latest = Some(snapshot);
+
+        Some(snapshot)
+    }
+}
+
+impl<K: Ord + Encode> PendingSnapshot<K> {
+    /// Appends the leaf of the holder visited to the tree, as of the block
+    /// of the snapshot if it's preserved.
+    pub fn visit(&mut self, account: K, balance: U256) {
+        match self.preserved.get_mut(&account) {
+            Some(preserved) => {
+                if let Some(balance) = preserved.take() {
+                    self.push((account, balance));
+                }
+            }
+            None => self.push((account, balance)),
+        }
+    }
+
+    /// Appends the leaf of the holder to the tree.
+    fn push(&mut self, holder: impl Encode) {
+        self.tree.push(merkle::leaf::<Keccak256>(&holder.encode()));
+    }
+}
@@ -0,0 +1,323 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Procedural macros for Awesome Sails services.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Error, Expr, FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl, Meta, Pat,
+    PathArguments, ReturnType, Token, Type,
+    parse::{Parse, ParseStream, Parser},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+};
+
+/// Requires the message source to have the given role before running the method.
+///
+/// Injects `access_control.require_role(role, Syscall::message_source())?` as the first
+/// statement of the method, so the method must return a `Result` whose error converts from
+/// the access-control error.
+///
+/// The access-control service defaults to `self.access_control` and may be overridden via
+/// `access_control = <expr>`:
+///
+/// ```ignore
+/// #[export(unwrap_result)]
+/// #[require_role(MINTER_ROLE)]
+/// pub fn mint(&mut self, to: ActorId, value: U256) -> Result<(), Error> { .. }
+///
+/// #[export(unwrap_result)]
+/// #[require_role(DEFAULT_ADMIN_ROLE, access_control = self)]
+/// pub fn set_role_grant_delay(&mut self, role_id: RoleId, delay: u32) -> Result<(), Error> { .. }
+/// ```
+#[proc_macro_attribute]
+pub fn require_role(args: TokenStream, item: TokenStream) -> TokenStream {
+    let RequireRoleArgs {
+        role,
+        access_control,
+    } = parse_macro_input!(args as RequireRoleArgs);
+    let mut method = parse_macro_input!(item as ImplItemFn);
+
+    if method.sig.receiver().is_none() {
+        return Error::new_spanned(
+            &method.sig,
+            "`require_role` can only be applied to methods taking `self`",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    method.block.stmts.insert(
+        0,
+        parse_quote! {
+            #access_control.require_role(
+                #role,
+                ::sails_rs::prelude::Syscall::message_source(),
+            )?;
+        },
+    );
+
+    quote!(#method).into()
+}
+
+struct RequireRoleArgs {
+    role: Expr,
+    access_control: Expr,
+}
+
+impl Parse for RequireRoleArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let role = input.parse()?;
+        let mut access_control = parse_quote!(self.access_control);
+
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: syn::Ident = input.parse()?;
+
+            if key != "access_control" {
+                return Err(Error::new_spanned(key, "expected `access_control`"));
+            }
+
+            input.parse::<Token![=]>()?;
+            access_control = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens"));
+        }
+
+        Ok(Self {
+            role,
+            access_control,
+        })
+    }
+}
+
+/// Makes failing methods of a service reply with the encoded error instead of its message.
+///
+/// Strips `unwrap_result` from every `#[export(..)]` of the impl, so `E` of methods returning
+/// `Result<T, E>` appears in the IDL. Must be placed above `#[service]`, usually behind
+/// a feature:
+///
+/// ```ignore
+/// #[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors)]
+/// #[service(events = Event)]
+/// impl<'a> Vft<'a> { .. }
+/// ```
+///
+/// Queries reply with `Result<T, E>`. Commands still fail on `Err`, so state changes they made
+/// before failing are reverted, but with the encoded `E` as the panic message, see
+/// `awesome_sails_utils::error::revert`. To do so, the body of a command is moved to a private
+/// method, wrapped by the exported one, so the crate must depend on `awesome-sails-utils`.
+///
+/// Methods returning `Result<CommandReply<T>, E>` keep panicking, as their reply carries
+/// value. So do methods listed in `skip(..)`, e.g. ones whose error reply must stay as is:
+///
+/// ```ignore
+/// #[cfg_attr(feature = "typed-errors", awesome_sails_macros::typed_errors(skip(batch)))]
+/// ```
+#[proc_macro_attribute]
+pub fn typed_errors(args: TokenStream, item: TokenStream) -> TokenStream {
+    let TypedErrorsArgs { skip } = parse_macro_input!(args as TypedErrorsArgs);
+    let item_impl = parse_macro_input!(item as ItemImpl);
+
+    match expand_typed_errors(item_impl, &skip) {
+        Ok(item_impl) => quote!(#item_impl).into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+struct TypedErrorsArgs {
+    skip: Vec<syn::Ident>,
+}
+
+impl Parse for TypedErrorsArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut skip = Vec::new();
+
+        if !input.is_empty() {
+            let key: syn::Ident = input.parse()?;
+
+            if key != "skip" {
+                return Err(Error::new_spanned(key, "expected `skip`"));
+            }
+
+            let content;
+            syn::parenthesized!(content in input);
+            skip = Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens"));
+        }
+
+        Ok(Self { skip })
+    }
+}
+
+fn expand_typed_errors(mut item_impl: ItemImpl, skip: &[syn::Ident]) -> syn::Result<ItemImpl> {
+    let mut inner_methods = Vec::new();
+
+    for item in &mut item_impl.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+
+        if returns_command_reply(method) || skip.contains(&method.sig.ident) {
+            continue;
+        }
+
+        let mut stripped = false;
+
+        for attr in &mut method.attrs {
+            stripped |= strip_unwrap_result(attr)?;
+        }
+
+        let is_command = method
+            .sig
+            .receiver()
+            .is_some_and(|receiver| receiver.mutability.is_some());
+
+        if stripped && is_command {
+            inner_methods.push(revert_on_err(method)?);
+        }
+    }
+
+    item_impl
+        .items
+        .extend(inner_methods.into_iter().map(ImplItem::Fn));
+
+    Ok(item_impl)
+}
+
+/// Strips `unwrap_result` from `#[export(..)]`, returning whether it was there.
+fn strip_unwrap_result(attr: &mut Attribute) -> syn::Result<bool> {
+    let Meta::List(list) = &attr.meta else {
+        return Ok(false);
+    };
+
+    if !list.path.is_ident("export") {
+        return Ok(false);
+    }
+
+    let all_args = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(list.tokens.clone())?;
+    let len = all_args.len();
+
+    let args = all_args
+        .into_iter()
+        .filter(|meta| !meta.path().is_ident("unwrap_result"))
+        .collect::<Punctuated<_, Token![,]>>();
+
+    if args.len() == len {
+        return Ok(false);
+    }
+
+    *attr = if args.is_empty() {
+        parse_quote!(#[export])
+    } else {
+        parse_quote!(#[export(#args)])
+    };
+
+    Ok(true)
+}
+
+/// Moves the body of `method` to the returned private method, making `method` call it
+/// and fail with the encoded error on `Err`.
+///
+/// The private method keeps attributes other than `export` and docs, e.g. `require_role`,
+/// so its checks fail the same way.
+fn revert_on_err(method: &mut ImplItemFn) -> syn::Result<ImplItemFn> {
+    let mut inner = method.clone();
+
+    inner.vis = syn::Visibility::Inherited;
+    inner.sig.ident = format_ident!("__{}", method.sig.ident);
+    inner
+        .attrs
+        .retain(|attr| !attr.path().is_ident("export") && !attr.path().is_ident("doc"));
+
+    method.attrs.retain(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_none_or(|segment| segment.ident != "require_role")
+    });
+
+    let mut args = Vec::new();
+
+    for arg in &mut method.sig.inputs {
+        let FnArg::Typed(arg) = arg else {
+            continue;
+        };
+
+        let Pat::Ident(pat) = arg.pat.as_mut() else {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "`typed_errors` requires command arguments to be identifiers",
+            ));
+        };
+
+        pat.mutability = None;
+        pat.by_ref = None;
+        args.push(pat.ident.clone());
+    }
+
+    let inner_ident = &inner.sig.ident;
+    let call = if method.sig.asyncness.is_some() {
+        quote!(self.#inner_ident(#(#args),*).await)
+    } else {
+        quote!(self.#inner_ident(#(#args),*))
+    };
+
+    method.block = parse_quote!({
+        match #call {
+            Err(err) => ::awesome_sails_utils::error::revert(err),
+            ok => ok,
+        }
+    });
+
+    Ok(inner)
+}
+
+fn returns_command_reply(method: &ImplItemFn) -> bool {
+    let ReturnType::Type(_, ty) = &method.sig.output else {
+        return false;
+    };
+
+    let Type::Path(path) = ty.as_ref() else {
+        return false;
+    };
+
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+
+    matches!(
+        args.args.first(),
+        Some(GenericArgument::Type(Type::Path(ok)))
+            if ok.path.segments.last().is_some_and(|s| s.ident == "CommandReply")
+    )
+}
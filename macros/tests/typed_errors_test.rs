@@ -0,0 +1,82 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Failing commands revert the message, which only happens on chain, so these tests
+//! cover replies of commands and queries. See the `typed_errors` gtests.
+
+use awesome_sails_macros::typed_errors;
+use awesome_sails_utils::error::{BadInput, Error, ErrorCode, decode_error};
+use sails_rs::export;
+
+#[derive(Default)]
+struct Counter {
+    value: u32,
+}
+
+#[typed_errors]
+impl Counter {
+    #[export(unwrap_result)]
+    pub fn add(&mut self, mut amount: u32) -> Result<u32, Error> {
+        amount = amount.checked_mul(2).ok_or(BadInput)?;
+        self.value = self.value.checked_add(amount).ok_or(BadInput)?;
+
+        Ok(self.value)
+    }
+
+    #[export(unwrap_result)]
+    pub fn checked_add(&self, amount: u32) -> Result<u32, Error> {
+        Ok(self.value.checked_add(amount).ok_or(BadInput)?)
+    }
+
+    #[export]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn commands_reply_with_ok() {
+    let mut counter = Counter::default();
+
+    assert_eq!(counter.add(2), Ok(4));
+    assert_eq!(counter.add(1), Ok(6));
+    assert_eq!(counter.value(), 6);
+}
+
+#[test]
+fn commands_panic_with_encoded_err() {
+    let mut counter = Counter::default();
+
+    let panic = std::panic::catch_unwind(move || counter.add(u32::MAX)).unwrap_err();
+    let message = panic.downcast::<String>().unwrap();
+
+    let err = decode_error(format!("panicked with '{message}'").as_bytes()).unwrap();
+    assert_eq!(err.code(), 1);
+    assert_eq!(err.message(), "incorrect input argument");
+}
+
+#[test]
+fn queries_reply_with_err() {
+    let counter = Counter { value: u32::MAX };
+
+    let err = counter.checked_add(1).unwrap_err();
+    assert_eq!(err.code(), 1);
+    assert_eq!(err.message(), "incorrect input argument");
+
+    assert_eq!(counter.checked_add(0), Ok(u32::MAX));
+}
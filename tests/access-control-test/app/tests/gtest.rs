@@ -19,11 +19,13 @@
 mod common;
 
 use access_control_test_client::{
-    AccessControlTestClient, Pagination,
+    AccessControlTestClient, Pagination, PendingGrant, Permissions, RecoveryConfig,
     access_control::{AccessControl, events::AccessControlEvents},
 };
-use awesome_sails::access_control::{DEFAULT_ADMIN_ROLE, RoleId};
-use awesome_sails_utils::assert_ok;
+use awesome_sails::access_control::{
+    DEFAULT_ADMIN_RENOUNCE_DELAY, DEFAULT_ADMIN_ROLE, RoleId, VIEWER_ROLE,
+};
+use awesome_sails_utils::{assert_ok, audit::AuditEntry};
 use common::{ALICE, BOB, CHARLIE, DAVE, assert_str_panic, deploy_program};
 use futures::StreamExt;
 use sails_rs::prelude::*;
@@ -50,6 +52,40 @@ async fn initial_admin_role_granted() {
     assert_ok!(has_role, false);
 }
 
+#[tokio::test]
+async fn initial_admin_grant_announced() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    // Anyone announces the grant made at deployment
+    let res = access_control_service
+        .init_access_control()
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: ALICE,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    // The grant is announced once
+    let res = access_control_service
+        .init_access_control()
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, false);
+}
+
 #[tokio::test]
 async fn grant_and_revoke_role_success() {
     let (program, _env, pid) = deploy_program().await;
@@ -72,6 +108,7 @@ async fn grant_and_revoke_role_success() {
             role_id: MINTER_ROLE,
             target_account: BOB,
             sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
         }
     );
 
@@ -94,6 +131,7 @@ async fn grant_and_revoke_role_success() {
             role_id: MINTER_ROLE,
             target_account: BOB,
             sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
         }
     );
 
@@ -102,6 +140,243 @@ async fn grant_and_revoke_role_success() {
     assert_ok!(has_role, false);
 }
 
+#[tokio::test]
+async fn replace_role_member_success() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGranted event
+
+    // Only role admin may replace members
+    let res = access_control_service
+        .replace_role_member(MINTER_ROLE, BOB, CHARLIE)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Replaced account must be a member other than the new one
+    let res = access_control_service
+        .replace_role_member(MINTER_ROLE, DAVE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    let res = access_control_service
+        .replace_role_member(MINTER_ROLE, BOB, BOB)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    access_control_service
+        .replace_role_member(MINTER_ROLE, BOB, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to replace Bob with Charlie");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: MINTER_ROLE,
+            target_account: CHARLIE,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleRevoked {
+            role_id: MINTER_ROLE,
+            target_account: BOB,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    assert_ok!(
+        access_control_service.has_role(MINTER_ROLE, BOB).await,
+        false
+    );
+    assert_ok!(
+        access_control_service.has_role(MINTER_ROLE, CHARLIE).await,
+        true
+    );
+    assert_ok!(
+        access_control_service
+            .get_role_member_count(MINTER_ROLE)
+            .await,
+        1
+    );
+
+    // Roles with a grant delay can't be replaced at once
+    access_control_service
+        .set_role_grant_delay(MINTER_ROLE, 10)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let res = access_control_service
+        .replace_role_member(MINTER_ROLE, CHARLIE, DAVE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+}
+
+#[tokio::test]
+async fn lock_role_success() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGranted event
+
+    // Only role admin may lock the role
+    let res = access_control_service
+        .lock_role(MINTER_ROLE)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Default admin role stays recoverable
+    let res = access_control_service
+        .lock_role(DEFAULT_ADMIN_ROLE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    assert_ok!(
+        access_control_service
+            .lock_role(MINTER_ROLE)
+            .with_actor_id(ALICE)
+            .await,
+        true
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleLocked {
+            role_id: MINTER_ROLE,
+            sender: ALICE,
+        }
+    );
+
+    assert_ok!(
+        access_control_service.is_role_locked(MINTER_ROLE).await,
+        true
+    );
+    assert_ok!(
+        access_control_service.is_role_locked(MODERATOR_ROLE).await,
+        false
+    );
+
+    // Locking twice is a no-op
+    assert_ok!(
+        access_control_service
+            .lock_role(MINTER_ROLE)
+            .with_actor_id(ALICE)
+            .await,
+        false
+    );
+
+    // No new members, no admin changes
+    let locked = "Role locked: role [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1] admits no new members";
+
+    let res = access_control_service
+        .grant_role(MINTER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), locked);
+
+    let res = access_control_service
+        .set_role_admin(MINTER_ROLE, MODERATOR_ROLE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), locked);
+
+    // Existing members may still be revoked
+    access_control_service
+        .revoke_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to revoke MINTER_ROLE from Bob");
+
+    assert_ok!(
+        access_control_service.has_role(MINTER_ROLE, BOB).await,
+        false
+    );
+    assert_ok!(
+        access_control_service.is_role_locked(MINTER_ROLE).await,
+        true
+    );
+}
+
+#[tokio::test]
+async fn lock_role_fail_with_parent() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    access_control_service
+        .set_role_parent(MINTER_ROLE, Some(MODERATOR_ROLE))
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // Holders of MODERATOR_ROLE would hold locked MINTER_ROLE
+    let res = access_control_service
+        .lock_role(MINTER_ROLE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    assert_ok!(
+        access_control_service.is_role_locked(MINTER_ROLE).await,
+        false
+    );
+
+    // Removing the parent allows locking, after which no parent may be set
+    access_control_service
+        .set_role_parent(MINTER_ROLE, None)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    assert_ok!(
+        access_control_service
+            .lock_role(MINTER_ROLE)
+            .with_actor_id(ALICE)
+            .await,
+        true
+    );
+
+    let res = access_control_service
+        .set_role_parent(MINTER_ROLE, Some(MODERATOR_ROLE))
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(
+        res.unwrap_err(),
+        "Role locked: role [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1] admits no new members",
+    );
+}
+
 #[tokio::test]
 async fn grant_role_fail_unauthorized() {
     let (program, _env, _pid) = deploy_program().await;
@@ -179,6 +454,7 @@ async fn renounce_role_success() {
             role_id: PAUSER_ROLE,
             target_account: CHARLIE,
             sender: CHARLIE,
+            via_role: PAUSER_ROLE,
         }
     );
 
@@ -262,7 +538,18 @@ async fn set_role_admin_success() {
         .with_actor_id(DAVE)
         .await
         .expect("Failed for Dave to grant MINTER_ROLE to Bob");
-    events.next().await.unwrap(); // Consume RoleGranted event
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: MINTER_ROLE,
+            target_account: BOB,
+            sender: DAVE,
+            via_role: MODERATOR_ROLE,
+        }
+    );
 
     // Bob should have MINTER_ROLE
     let has_role = access_control_service.has_role(MINTER_ROLE, BOB).await;
@@ -274,7 +561,29 @@ async fn set_role_admin_success() {
         .with_actor_id(ALICE)
         .await
         .expect("Alice (super admin) should still be able to grant roles");
-    events.next().await.unwrap(); // Consume RoleGranted event
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::SuperAdminOverrideUsed {
+            role_id: MINTER_ROLE,
+            admin_role_id: MODERATOR_ROLE,
+            sender: ALICE,
+        }
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: MINTER_ROLE,
+            target_account: CHARLIE,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
 
     let has_role = access_control_service.has_role(MINTER_ROLE, CHARLIE).await;
     assert_ok!(has_role, true);
@@ -389,29 +698,175 @@ async fn self_admin_role_success() {
 }
 
 #[tokio::test]
-async fn batch_grant_success() {
+async fn role_parent_success() {
     let (program, _env, pid) = deploy_program().await;
     let mut access_control_service = program.access_control();
     let listener = access_control_service.listener();
     let mut events = listener.listen().await.unwrap();
 
-    let roles = vec![MINTER_ROLE, MODERATOR_ROLE, PAUSER_ROLE];
-
+    // MODERATOR_ROLE ⊃ MINTER_ROLE ⊃ PAUSER_ROLE
     access_control_service
-        .grant_roles_batch(roles.clone(), BOB)
+        .set_role_parent(MINTER_ROLE, Some(MODERATOR_ROLE))
         .with_actor_id(ALICE)
         .await
-        .expect("Batch grant failed");
+        .expect("Failed to set MODERATOR_ROLE as parent of MINTER_ROLE");
 
-    for role_id in roles.clone() {
-        let (actor, event) = events.next().await.unwrap();
-        assert_eq!(actor, pid);
-        assert_eq!(
-            event,
-            AccessControlEvents::RoleGranted {
-                role_id,
-                target_account: BOB,
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleParentChanged {
+            role_id: MINTER_ROLE,
+            previous_parent_role_id: None,
+            new_parent_role_id: Some(MODERATOR_ROLE),
+            sender: ALICE,
+        }
+    );
+
+    access_control_service
+        .set_role_parent(PAUSER_ROLE, Some(MINTER_ROLE))
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let parent = access_control_service.get_role_parent(PAUSER_ROLE).await;
+    assert_ok!(parent, Some(MINTER_ROLE));
+
+    // Cycles are rejected
+    let res = access_control_service
+        .set_role_parent(MODERATOR_ROLE, Some(PAUSER_ROLE))
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    // Bob holding MODERATOR_ROLE inherits both child roles
+    access_control_service
+        .grant_role(MODERATOR_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    assert_ok!(
+        access_control_service.has_role(MINTER_ROLE, BOB).await,
+        true
+    );
+    assert_ok!(
+        access_control_service.has_role(PAUSER_ROLE, BOB).await,
+        true
+    );
+
+    // Charlie holding MINTER_ROLE doesn't inherit its parent
+    access_control_service
+        .grant_role(MINTER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    assert_ok!(
+        access_control_service
+            .has_role(MODERATOR_ROLE, CHARLIE)
+            .await,
+        false
+    );
+    assert_ok!(
+        access_control_service.has_role(PAUSER_ROLE, CHARLIE).await,
+        true
+    );
+
+    // Inherited roles aren't enumerated
+    assert_ok!(access_control_service.get_member_role_count(BOB).await, 1);
+
+    // Removing the parent stops inheritance
+    access_control_service
+        .set_role_parent(MINTER_ROLE, None)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    assert_ok!(
+        access_control_service.has_role(MINTER_ROLE, BOB).await,
+        false
+    );
+    assert_ok!(
+        access_control_service.has_role(PAUSER_ROLE, BOB).await,
+        false
+    );
+}
+
+#[tokio::test]
+async fn set_role_parent_fail_unauthorized() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    // Dave is MINTER_ROLE admin, but not super admin
+    access_control_service
+        .grant_role(MODERATOR_ROLE, DAVE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    access_control_service
+        .set_role_admin(MINTER_ROLE, MODERATOR_ROLE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let res = access_control_service
+        .set_role_parent(MINTER_ROLE, Some(PAUSER_ROLE))
+        .with_actor_id(DAVE)
+        .await;
+    assert!(res.is_err());
+
+    assert_ok!(
+        access_control_service.get_role_parent(MINTER_ROLE).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn set_role_parent_fail_default_admin_role() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    // Holders of MODERATOR_ROLE would become super admins
+    let res = access_control_service
+        .set_role_parent(DEFAULT_ADMIN_ROLE, Some(MODERATOR_ROLE))
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    assert_ok!(
+        access_control_service
+            .get_role_parent(DEFAULT_ADMIN_ROLE)
+            .await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn batch_grant_success() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    let roles = vec![MINTER_ROLE, MODERATOR_ROLE, PAUSER_ROLE];
+
+    access_control_service
+        .grant_roles_batch(roles.clone(), BOB)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Batch grant failed");
+
+    for role_id in roles.clone() {
+        let (actor, event) = events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            AccessControlEvents::RoleGranted {
+                role_id,
+                target_account: BOB,
                 sender: ALICE,
+                via_role: DEFAULT_ADMIN_ROLE,
             }
         );
     }
@@ -520,6 +975,91 @@ async fn enumeration_member_roles_success() {
     for r in &roles {
         assert!(bob_roles.contains(r));
     }
+
+    // Revoked role disappears from Bob's roles
+    access_control_service
+        .revoke_role(MODERATOR_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    assert_ok!(access_control_service.get_member_role_count(BOB).await, 2);
+    assert_ok!(
+        access_control_service
+            .get_member_roles(
+                BOB,
+                Some(Pagination {
+                    offset: 1,
+                    limit: 10,
+                })
+            )
+            .await,
+        vec![PAUSER_ROLE]
+    );
+}
+
+#[tokio::test]
+async fn enumeration_all_members_success() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    access_control_service
+        .grant_roles_batch(vec![MINTER_ROLE, PAUSER_ROLE], BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    access_control_service
+        .grant_role(MINTER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // Alice (initial admin), Bob and Charlie, each counted once
+    assert_ok!(access_control_service.get_all_member_count().await, 3);
+
+    let all_members = access_control_service.get_all_members(None).await.unwrap();
+    assert_eq!(all_members.len(), 3);
+    for m in [ALICE, BOB, CHARLIE] {
+        assert!(all_members.contains(&m));
+    }
+
+    let memberships = access_control_service.get_memberships(None).await.unwrap();
+    assert_eq!(
+        memberships,
+        vec![
+            (DEFAULT_ADMIN_ROLE, ALICE),
+            (MINTER_ROLE, BOB),
+            (MINTER_ROLE, CHARLIE),
+            (PAUSER_ROLE, BOB),
+        ]
+    );
+
+    let page = access_control_service
+        .get_memberships(Some(Pagination {
+            offset: 1,
+            limit: 2,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(page, memberships[1..3]);
+
+    // Bob leaves the index only once all his roles are revoked
+    access_control_service
+        .revoke_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    assert_ok!(access_control_service.get_all_member_count().await, 3);
+
+    access_control_service
+        .revoke_role(PAUSER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    assert_ok!(access_control_service.get_all_member_count().await, 2);
+
+    let all_members = access_control_service.get_all_members(None).await.unwrap();
+    assert!(!all_members.contains(&BOB));
 }
 
 #[tokio::test]
@@ -630,3 +1170,998 @@ async fn enumeration_pagination_logic() {
         .unwrap();
     assert!(empty.is_empty());
 }
+
+#[tokio::test]
+async fn max_page_size() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    assert_ok!(access_control_service.get_max_page_size().await, 1000);
+
+    access_control_service
+        .grant_roles_batch(vec![MINTER_ROLE, MODERATOR_ROLE, PAUSER_ROLE], BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    for _ in 0..3 {
+        events.next().await.unwrap(); // Consume RoleGranted events
+    }
+
+    // Only super admin may set the max page size
+    let res = access_control_service
+        .set_max_page_size(3)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = access_control_service
+        .set_max_page_size(0)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    access_control_service
+        .set_max_page_size(3)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to set max page size");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::MaxPageSizeChanged {
+            previous_max_page_size: 1000,
+            new_max_page_size: 3,
+            sender: ALICE,
+        }
+    );
+
+    assert_ok!(access_control_service.get_max_page_size().await, 3);
+
+    // All 4 roles don't fit the page
+    let res = access_control_service.get_roles(None).await;
+    assert_str_panic(res.unwrap_err(), "page too large: at most 3 items allowed");
+
+    let res = access_control_service
+        .get_roles(Some(Pagination {
+            offset: 0,
+            limit: 4,
+        }))
+        .await;
+    assert_str_panic(res.unwrap_err(), "page too large: at most 3 items allowed");
+
+    let page = access_control_service
+        .get_roles(Some(Pagination {
+            offset: 1,
+            limit: 3,
+        }))
+        .await
+        .unwrap();
+    assert_eq!(page, vec![MINTER_ROLE, MODERATOR_ROLE, PAUSER_ROLE]);
+
+    // All 3 roles of Bob fit the page
+    let bob_roles = access_control_service
+        .get_member_roles(BOB, None)
+        .await
+        .unwrap();
+    assert_eq!(bob_roles.len(), 3);
+
+    let res = access_control_service.get_memberships(None).await;
+    assert_str_panic(res.unwrap_err(), "page too large: at most 3 items allowed");
+
+    let res = access_control_service.audit_log(0, 10).await;
+    assert_str_panic(res.unwrap_err(), "page too large: at most 3 items allowed");
+}
+
+#[tokio::test]
+async fn grant_delay_success() {
+    let (program, env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    // Only super admin may set a grant delay
+    let res = access_control_service
+        .set_role_grant_delay(MINTER_ROLE, 10)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .set_role_grant_delay(MINTER_ROLE, 10)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to set grant delay for MINTER_ROLE");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGrantDelayChanged {
+            role_id: MINTER_ROLE,
+            previous_delay: 0,
+            new_delay: 10,
+            sender: ALICE,
+        }
+    );
+
+    // Granting MINTER_ROLE only schedules it
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to schedule MINTER_ROLE grant to Bob");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    let AccessControlEvents::RoleGrantScheduled {
+        role_id,
+        target_account,
+        sender,
+        ready_at,
+    } = event
+    else {
+        panic!("unexpected event: {event:?}");
+    };
+    assert_eq!((role_id, target_account, sender), (MINTER_ROLE, BOB, ALICE));
+
+    let pending = access_control_service
+        .get_pending_grant(MINTER_ROLE, BOB)
+        .await;
+    assert_ok!(
+        pending,
+        Some(PendingGrant {
+            ready_at,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        })
+    );
+
+    let has_role = access_control_service.has_role(MINTER_ROLE, BOB).await;
+    assert_ok!(has_role, false);
+
+    // Grant can't be executed before the delay passes
+    let res = access_control_service
+        .execute_role_grant(MINTER_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    env.system().run_to_block(ready_at);
+
+    // Anyone may execute a ready grant
+    access_control_service
+        .execute_role_grant(MINTER_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await
+        .expect("Failed to execute MINTER_ROLE grant to Bob");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: MINTER_ROLE,
+            target_account: BOB,
+            sender: ALICE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    let has_role = access_control_service.has_role(MINTER_ROLE, BOB).await;
+    assert_ok!(has_role, true);
+
+    let pending = access_control_service
+        .get_pending_grant(MINTER_ROLE, BOB)
+        .await;
+    assert_ok!(pending, None);
+
+    // Roles without delay are still granted instantly
+    access_control_service
+        .grant_role(PAUSER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let has_role = access_control_service.has_role(PAUSER_ROLE, BOB).await;
+    assert_ok!(has_role, true);
+}
+
+#[tokio::test]
+async fn grant_delay_cancel() {
+    let (program, env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    access_control_service
+        .set_role_grant_delay(MINTER_ROLE, 10)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGrantDelayChanged event
+
+    access_control_service
+        .grant_role(MINTER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGrantScheduled event
+
+    // Charlie isn't MINTER_ROLE admin
+    let res = access_control_service
+        .cancel_role_grant(MINTER_ROLE, CHARLIE)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .cancel_role_grant(MINTER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to cancel MINTER_ROLE grant to Charlie");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGrantCancelled {
+            role_id: MINTER_ROLE,
+            target_account: CHARLIE,
+            sender: ALICE,
+        }
+    );
+
+    // Cancelled grant can't be executed anymore
+    env.system().run_to_block(env.system().block_height() + 10);
+
+    let res = access_control_service
+        .execute_role_grant(MINTER_ROLE, CHARLIE)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    let has_role = access_control_service.has_role(MINTER_ROLE, CHARLIE).await;
+    assert_ok!(has_role, false);
+}
+
+#[tokio::test]
+async fn admin_renounce_delayed() {
+    let (program, env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    access_control_service
+        .grant_role(DEFAULT_ADMIN_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGranted event
+
+    // Only super admin may set the renounce delay
+    let res = access_control_service
+        .set_admin_renounce_delay(10)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .set_admin_renounce_delay(10)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to set admin renounce delay");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::AdminRenounceDelayChanged {
+            previous_delay: DEFAULT_ADMIN_RENOUNCE_DELAY,
+            new_delay: 10,
+            sender: ALICE,
+        }
+    );
+
+    // Renouncing DEFAULT_ADMIN_ROLE only schedules it
+    access_control_service
+        .renounce_role(DEFAULT_ADMIN_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await
+        .expect("Failed to schedule renounce of DEFAULT_ADMIN_ROLE by Bob");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    let AccessControlEvents::AdminRenounceScheduled {
+        account_id,
+        ready_at,
+    } = event
+    else {
+        panic!("unexpected event: {event:?}");
+    };
+    assert_eq!(account_id, BOB);
+
+    assert_ok!(
+        access_control_service.get_pending_admin_renounce(BOB).await,
+        Some(ready_at)
+    );
+    assert_ok!(
+        access_control_service
+            .has_role(DEFAULT_ADMIN_ROLE, BOB)
+            .await,
+        true
+    );
+
+    // Renounce can't be executed before the delay passes, nor by others
+    let res = access_control_service
+        .execute_admin_renounce(BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    env.system().run_to_block(ready_at);
+
+    let res = access_control_service
+        .execute_admin_renounce(BOB)
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .execute_admin_renounce(BOB)
+        .with_actor_id(BOB)
+        .await
+        .expect("Failed to execute renounce of DEFAULT_ADMIN_ROLE by Bob");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleRevoked {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: BOB,
+            sender: BOB,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    assert_ok!(
+        access_control_service
+            .has_role(DEFAULT_ADMIN_ROLE, BOB)
+            .await,
+        false
+    );
+    assert_ok!(
+        access_control_service.get_pending_admin_renounce(BOB).await,
+        None
+    );
+
+    // Pending renounce may be cancelled
+    access_control_service
+        .grant_role(DEFAULT_ADMIN_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGranted event
+
+    access_control_service
+        .renounce_role(DEFAULT_ADMIN_ROLE, CHARLIE)
+        .with_actor_id(CHARLIE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume AdminRenounceScheduled event
+
+    access_control_service
+        .cancel_admin_renounce(CHARLIE)
+        .with_actor_id(CHARLIE)
+        .await
+        .expect("Failed to cancel renounce of DEFAULT_ADMIN_ROLE by Charlie");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::AdminRenounceCancelled {
+            account_id: CHARLIE,
+        }
+    );
+
+    env.system().run_to_block(env.system().block_height() + 10);
+
+    let res = access_control_service
+        .execute_admin_renounce(CHARLIE)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_str_panic(
+        res.unwrap_err(),
+        "Renounce not found: account 0x0000000000000000000000002c00000000000000000000000000000000000000",
+    );
+
+    assert_ok!(
+        access_control_service
+            .has_role(DEFAULT_ADMIN_ROLE, CHARLIE)
+            .await,
+        true
+    );
+}
+
+#[tokio::test]
+async fn admin_renounce_last_admin() {
+    let (program, env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    access_control_service
+        .set_admin_renounce_delay(0)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume AdminRenounceDelayChanged event
+
+    // The last super admin can't renounce
+    let res = access_control_service
+        .renounce_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    assert_ok!(access_control_service.get_allow_admin_less().await, false);
+
+    // Only super admin may allow leaving the program admin-less
+    let res = access_control_service
+        .set_allow_admin_less(true)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    assert_ok!(
+        access_control_service
+            .set_allow_admin_less(true)
+            .with_actor_id(ALICE)
+            .await,
+        true
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::AllowAdminLessChanged {
+            allowed: true,
+            sender: ALICE,
+        }
+    );
+
+    access_control_service
+        .renounce_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to schedule renounce of DEFAULT_ADMIN_ROLE by Alice");
+    events.next().await.unwrap(); // Consume AdminRenounceScheduled event
+
+    env.system().run_next_block();
+
+    access_control_service
+        .execute_admin_renounce(ALICE)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to execute renounce of DEFAULT_ADMIN_ROLE by Alice");
+
+    assert_ok!(
+        access_control_service
+            .get_role_member_count(DEFAULT_ADMIN_ROLE)
+            .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn revoke_last_admin() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    let last_admin = "Last admin protection: account 0x0000000000000000000000002a00000000000000000000000000000000000000 is the last super admin";
+
+    // The last super admin can't be revoked
+    let res = access_control_service
+        .revoke_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), last_admin);
+
+    let res = access_control_service
+        .revoke_roles_batch(vec![MINTER_ROLE, DEFAULT_ADMIN_ROLE], ALICE)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), last_admin);
+
+    // Unless there is another one
+    access_control_service
+        .grant_role(DEFAULT_ADMIN_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RoleGranted event
+
+    access_control_service
+        .revoke_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .with_actor_id(BOB)
+        .await
+        .expect("Failed to revoke DEFAULT_ADMIN_ROLE from Alice");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleRevoked {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: ALICE,
+            sender: BOB,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    let res = access_control_service
+        .revoke_role(DEFAULT_ADMIN_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Or admin-less programs are allowed
+    access_control_service
+        .set_allow_admin_less(true)
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume AllowAdminLessChanged event
+
+    access_control_service
+        .revoke_role(DEFAULT_ADMIN_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await
+        .expect("Failed to revoke DEFAULT_ADMIN_ROLE from Bob");
+
+    assert_ok!(
+        access_control_service
+            .get_role_member_count(DEFAULT_ADMIN_ROLE)
+            .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn recovery_success() {
+    let (program, env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    // Threshold can't exceed the number of guardians
+    let res = access_control_service
+        .set_recovery_guardians(vec![BOB, CHARLIE], 3, 10)
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .set_recovery_guardians(vec![CHARLIE, BOB, BOB], 2, 10)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to set recovery guardians");
+    events.next().await.unwrap(); // Consume RecoveryConfigChanged event
+
+    let config = access_control_service.get_recovery_config().await;
+    assert_ok!(
+        config,
+        RecoveryConfig {
+            guardians: vec![BOB, CHARLIE],
+            threshold: 2,
+            delay: 10,
+        }
+    );
+
+    // Dave isn't a guardian
+    let res = access_control_service
+        .propose_recovery(DAVE)
+        .with_actor_id(DAVE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .propose_recovery(DAVE)
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RecoveryApproved {
+            new_admin: DAVE,
+            guardian: BOB,
+            approvals: 1,
+        }
+    );
+
+    // Not enough approvals yet
+    let res = access_control_service
+        .execute_recovery(DAVE)
+        .with_actor_id(DAVE)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .propose_recovery(DAVE)
+        .with_actor_id(CHARLIE)
+        .await
+        .unwrap();
+    events.next().await.unwrap(); // Consume RecoveryApproved event
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    let AccessControlEvents::RecoveryScheduled {
+        new_admin,
+        ready_at,
+    } = event
+    else {
+        panic!("unexpected event: {event:?}");
+    };
+    assert_eq!(new_admin, DAVE);
+
+    // Delay hasn't passed yet
+    let res = access_control_service
+        .execute_recovery(DAVE)
+        .with_actor_id(DAVE)
+        .await;
+    assert!(res.is_err());
+
+    env.system().run_to_block(ready_at);
+
+    access_control_service
+        .execute_recovery(DAVE)
+        .with_actor_id(DAVE)
+        .await
+        .expect("Failed to execute recovery");
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleRevoked {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: ALICE,
+            sender: DAVE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RoleGranted {
+            role_id: DEFAULT_ADMIN_ROLE,
+            target_account: DAVE,
+            sender: DAVE,
+            via_role: DEFAULT_ADMIN_ROLE,
+        }
+    );
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::RecoveryExecuted { new_admin: DAVE }
+    );
+
+    assert_ok!(
+        access_control_service
+            .has_role(DEFAULT_ADMIN_ROLE, ALICE)
+            .await,
+        false
+    );
+    assert_ok!(
+        access_control_service
+            .has_role(DEFAULT_ADMIN_ROLE, DAVE)
+            .await,
+        true
+    );
+    assert_ok!(
+        access_control_service.get_recovery_proposal(DAVE).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn recovery_cancelled_by_admin() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    access_control_service
+        .set_recovery_guardians(vec![BOB], 1, 10)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .propose_recovery(BOB)
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let proposal = access_control_service
+        .get_recovery_proposal(BOB)
+        .await
+        .unwrap()
+        .expect("proposal must exist");
+    assert_eq!(proposal.approvals, vec![BOB]);
+    assert!(proposal.ready_at.is_some());
+
+    // Guardian can't cancel recovery
+    let res = access_control_service
+        .cancel_recovery(BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    access_control_service
+        .cancel_recovery(BOB)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to cancel recovery");
+
+    assert_ok!(
+        access_control_service.get_recovery_proposal(BOB).await,
+        None
+    );
+
+    let res = access_control_service
+        .execute_recovery(BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn audit_log_records_privileged_calls() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .set_role_admin(MINTER_ROLE, MODERATOR_ROLE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // Renouncing isn't privileged, so it's not recorded
+    access_control_service
+        .renounce_role(MINTER_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let log = access_control_service.audit_log(0, 10).await.unwrap();
+    assert_eq!(log.len(), 2);
+
+    let (index, entry) = &log[0];
+    assert_eq!(*index, 0);
+    assert_eq!(entry.actor, ALICE);
+    assert_eq!(entry.action, "grant_role");
+    assert_eq!(
+        entry.params_hash,
+        AuditEntry::new(entry.block, ALICE, "grant_role", &(MINTER_ROLE, BOB)).params_hash
+    );
+
+    let (index, entry) = &log[1];
+    assert_eq!(*index, 1);
+    assert_eq!(entry.action, "set_role_admin");
+    assert!(entry.block >= log[0].1.block);
+
+    // Cursor skips older entries
+    let log = access_control_service.audit_log(1, 10).await.unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].0, 1);
+}
+
+#[tokio::test]
+async fn permissions_report() {
+    let (program, _env, _pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+
+    access_control_service
+        .set_role_admin(MINTER_ROLE, MODERATOR_ROLE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .grant_role(MODERATOR_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .set_role_grant_delay(PAUSER_ROLE, 10)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .grant_role(PAUSER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let pending = access_control_service
+        .get_pending_grant(PAUSER_ROLE, BOB)
+        .await
+        .unwrap()
+        .expect("grant of PAUSER_ROLE is scheduled");
+
+    // Bob holds MODERATOR_ROLE, administers MINTER_ROLE by it and awaits PAUSER_ROLE
+    let permissions = access_control_service.permissions_of(BOB).await;
+    assert_ok!(
+        permissions,
+        Permissions {
+            roles: vec![MODERATOR_ROLE],
+            administered: vec![(MINTER_ROLE, 0)],
+            pending_grants: vec![(PAUSER_ROLE, pending)],
+            is_super_admin: false,
+        }
+    );
+
+    // Super admin administers every known role
+    let permissions = access_control_service.permissions_of(ALICE).await;
+    assert_ok!(
+        permissions,
+        Permissions {
+            roles: vec![DEFAULT_ADMIN_ROLE],
+            administered: vec![
+                (DEFAULT_ADMIN_ROLE, 0),
+                (MINTER_ROLE, 0),
+                (MODERATOR_ROLE, 0),
+                (PAUSER_ROLE, 10),
+            ],
+            pending_grants: vec![],
+            is_super_admin: true,
+        }
+    );
+
+    let permissions = access_control_service.permissions_of(CHARLIE).await;
+    assert_ok!(
+        permissions,
+        Permissions {
+            roles: vec![],
+            administered: vec![],
+            pending_grants: vec![],
+            is_super_admin: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn viewer_gate() {
+    let (program, _env, pid) = deploy_program().await;
+    let mut access_control_service = program.access_control();
+    let listener = access_control_service.listener();
+    let mut events = listener.listen().await.unwrap();
+
+    // Exports are public by default
+    assert_ok!(access_control_service.get_viewer_gate().await, false);
+
+    let res = access_control_service
+        .audit_log(0, 10)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_ok());
+
+    // Only super admin may enable the gate
+    let res = access_control_service
+        .set_viewer_gate(true)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = access_control_service
+        .set_viewer_gate(true)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let (actor, event) = events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        AccessControlEvents::ViewerGateChanged {
+            enabled: true,
+            sender: ALICE,
+        }
+    );
+
+    let res = access_control_service
+        .set_viewer_gate(true)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, false);
+
+    assert_ok!(access_control_service.get_viewer_gate().await, true);
+
+    // Heavy exports require VIEWER_ROLE, others stay public
+    let res = access_control_service
+        .get_all_members(None)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    let res = access_control_service
+        .get_memberships(None)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    let res = access_control_service
+        .audit_log(0, 10)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    let res = access_control_service
+        .get_role_members(DEFAULT_ADMIN_ROLE, None)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_ok!(res, vec![ALICE]);
+
+    // Super admin passes the gate
+    let res = access_control_service
+        .get_all_members(None)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, vec![ALICE]);
+
+    access_control_service
+        .grant_role(VIEWER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .expect("Failed to grant VIEWER_ROLE to Charlie");
+
+    let res = access_control_service
+        .get_memberships(None)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_ok!(
+        res,
+        vec![(DEFAULT_ADMIN_ROLE, ALICE), (VIEWER_ROLE, CHARLIE)]
+    );
+
+    let res = access_control_service
+        .audit_log(0, 10)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_ok());
+
+    // Disabling the gate makes exports public again
+    access_control_service
+        .set_viewer_gate(false)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let res = access_control_service
+        .get_all_members(None)
+        .with_actor_id(DAVE)
+        .await;
+    assert!(res.is_ok());
+}
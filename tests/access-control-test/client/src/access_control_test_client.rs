@@ -47,14 +47,93 @@ pub mod access_control {
     use super::*;
     pub trait AccessControl {
         type Env: sails_rs::client::GearEnv;
+        /// Cancels the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+        ///
+        /// Emits an `AdminRenounceCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be `account_id`.
+        /// - the renounce must be pending.
+        fn cancel_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelAdminRenounce, Self::Env>;
+        /// Discards the recovery proposal of `new_admin`.
+        ///
+        /// Emits a `RecoveryCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - the proposal must exist.
+        fn cancel_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env>;
+        /// Cancels a scheduled grant of `role_id` to `target_account`.
+        ///
+        /// Emits a `RoleGrantCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - the grant must be pending.
+        fn cancel_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRoleGrant, Self::Env>;
+        /// Completes the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+        ///
+        /// Emits a `RoleRevoked` event. The renounce is discarded once `account_id` loses
+        /// `DEFAULT_ADMIN_ROLE` otherwise, e.g. on recovery.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be `account_id`.
+        /// - the renounce must be pending and its delay must have passed.
+        /// - the caller must not be the last super admin, unless admin-less programs are allowed.
+        fn execute_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteAdminRenounce, Self::Env>;
+        /// Transfers `DEFAULT_ADMIN_ROLE` to `new_admin`, revoking it from all other holders
+        /// and discarding all recovery proposals.
+        ///
+        /// Emits a `RoleRevoked` event for each previous super admin, a `RoleGranted` event
+        /// and a `RecoveryExecuted` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the proposal must have reached the threshold and its delay must have passed.
+        fn execute_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRecovery, Self::Env>;
+        /// Activates a scheduled grant of `role_id` to `target_account`.
+        ///
+        /// Emits a `RoleGranted` event on behalf of the account that scheduled it.
+        ///
+        /// Requirements:
+        ///
+        /// - the grant must be pending and its delay must have passed.
+        /// - `role_id` must not be locked.
+        fn execute_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRoleGrant, Self::Env>;
         /// Grants `role_id` to `target_account`.
         ///
         /// If `target_account` had not been already granted `role_id`, emits a `RoleGranted`
-        /// event.
+        /// event. If `role_id` has a grant delay, the grant is scheduled instead and a
+        /// `RoleGrantScheduled` event is emitted.
         ///
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be locked.
         fn grant_role(
             &mut self,
             role_id: [u8; 32],
@@ -63,16 +142,52 @@ pub mod access_control {
         /// Grants `role_ids` to `target_account`.
         ///
         /// If `target_account` had not been already granted any of the `role_ids`,
-        /// emits a `RoleGranted` event for each newly granted role.
+        /// emits a `RoleGranted` event for each newly granted role. Roles with a grant
+        /// delay are scheduled as in `grant_role`.
         ///
         /// Requirements:
         ///
         /// - the caller must have the admin role for all specified `role_ids`.
+        /// - none of the `role_ids` may be locked.
         fn grant_roles_batch(
             &mut self,
             role_ids: Vec<[u8; 32]>,
             target_account: ActorId,
         ) -> sails_rs::client::PendingCall<io::GrantRolesBatch, Self::Env>;
+        /// Announces the grant of `DEFAULT_ADMIN_ROLE` made by `grant_initial_admin`,
+        /// emitting its `RoleGranted` event with the initial admin as the sender.
+        ///
+        /// Returns `false` if there is no grant to announce, e.g. it's already announced.
+        /// Anyone may call it, as it only reports the grant made.
+        fn init_access_control(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::InitAccessControl, Self::Env>;
+        /// Locks `role_id` irreversibly: no new members may be granted, and its admin role
+        /// and parent role may no longer change.
+        ///
+        /// Emits a `RoleLocked` event, unless the role is already locked.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so it stays recoverable.
+        /// - `role_id` must have no parent role, holders of which would hold it as well.
+        fn lock_role(
+            &mut self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::LockRole, Self::Env>;
+        /// Approves `new_admin` as the recovered super admin on behalf of the calling guardian.
+        ///
+        /// Emits a `RecoveryApproved` event, followed by a `RecoveryScheduled` event once the
+        /// approvals reach the threshold.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be a guardian.
+        fn propose_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ProposeRecovery, Self::Env>;
         /// Revokes `role_id` from the calling account.
         ///
         /// Roles are often managed via `grant_role` and `revoke_role`: this function's
@@ -80,16 +195,36 @@ pub mod access_control {
         /// if they are compromised (such as when a trusted device is misplaced).
         ///
         /// If the calling account had been granted `role_id`, emits a `RoleRevoked`
-        /// event.
+        /// event. Renouncing `DEFAULT_ADMIN_ROLE` is scheduled instead and an
+        /// `AdminRenounceScheduled` event is emitted, see `execute_admin_renounce`.
         ///
         /// Requirements:
         ///
         /// - the caller must be `account_id`.
+        /// - the caller must not be the last super admin, unless admin-less programs are allowed.
         fn renounce_role(
             &mut self,
             role_id: [u8; 32],
             account_id: ActorId,
         ) -> sails_rs::client::PendingCall<io::RenounceRole, Self::Env>;
+        /// Replaces `old_account` with `new_account` among members of `role_id` at once,
+        /// e.g. to rotate a key without a window of the role having no members.
+        ///
+        /// Emits a `RoleGranted` event for `new_account`, unless it's already a member,
+        /// followed by a `RoleRevoked` event for `old_account`.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - `old_account` must be a member of `role_id`, other than `new_account`.
+        /// - `role_id` must have no grant delay, as the replacement would bypass it.
+        /// - `role_id` must not be locked.
+        fn replace_role_member(
+            &mut self,
+            role_id: [u8; 32],
+            old_account: ActorId,
+            new_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ReplaceRoleMember, Self::Env>;
         /// Revokes `role_id` from `target_account`.
         ///
         /// If `target_account` had been granted `role_id`, emits a `RoleRevoked` event.
@@ -97,6 +232,8 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `target_account` must not be the last super admin if `role_id` is
+        ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
         fn revoke_role(
             &mut self,
             role_id: [u8; 32],
@@ -110,11 +247,69 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have the admin role for all specified `role_ids`.
+        /// - `target_account` must not be the last super admin if `role_ids` contain
+        ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
         fn revoke_roles_batch(
             &mut self,
             role_ids: Vec<[u8; 32]>,
             target_account: ActorId,
         ) -> sails_rs::client::PendingCall<io::RevokeRolesBatch, Self::Env>;
+        /// Sets the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+        ///
+        /// Renounces already scheduled keep their original delay.
+        ///
+        /// Emits an `AdminRenounceDelayChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_admin_renounce_delay(
+            &mut self,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetAdminRenounceDelay, Self::Env>;
+        /// Allows or forbids the last super admin to renounce or be revoked `DEFAULT_ADMIN_ROLE`,
+        /// leaving the program without super admins.
+        ///
+        /// Emits an `AllowAdminLessChanged` event if the setting changed.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_allow_admin_less(
+            &mut self,
+            allowed: bool,
+        ) -> sails_rs::client::PendingCall<io::SetAllowAdminLess, Self::Env>;
+        /// Sets the max amount of items enumeration queries reply with at once.
+        ///
+        /// Queries requesting more items, or all items when there are more of them,
+        /// fail with `PageTooLarge` instead of running out of gas.
+        ///
+        /// Emits a `MaxPageSizeChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `max_page_size` must be non-zero.
+        fn set_max_page_size(
+            &mut self,
+            max_page_size: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env>;
+        /// Replaces the recovery guardians, discarding all recovery proposals.
+        ///
+        /// Passing no guardians disables recovery.
+        ///
+        /// Emits a `RecoveryConfigChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `threshold` must be within `1..=guardians.len()`, or zero if there are no guardians.
+        fn set_recovery_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRecoveryGuardians, Self::Env>;
         /// Sets `new_admin_role_id` as the admin role for `role_id`.
         ///
         /// Emits a `RoleAdminChanged` event.
@@ -122,11 +317,86 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be locked.
         fn set_role_admin(
             &mut self,
             role_id: [u8; 32],
             new_admin_role_id: [u8; 32],
         ) -> sails_rs::client::PendingCall<io::SetRoleAdmin, Self::Env>;
+        /// Sets the number of blocks grants of `role_id` are delayed for.
+        ///
+        /// Grants already scheduled keep their original delay.
+        ///
+        /// Emits a `RoleGrantDelayChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_role_grant_delay(
+            &mut self,
+            role_id: [u8; 32],
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRoleGrantDelay, Self::Env>;
+        /// Sets `parent_role_id` as the parent of `role_id`, or removes the parent if `None`.
+        ///
+        /// Holders of the parent role are considered to hold `role_id` as well.
+        ///
+        /// Emits a `RoleParentChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so only accounts granted it are
+        ///   super admins.
+        /// - the new parent must not make `role_id` its own ancestor.
+        /// - `role_id` must not be locked.
+        fn set_role_parent(
+            &mut self,
+            role_id: [u8; 32],
+            parent_role_id: Option<[u8; 32]>,
+        ) -> sails_rs::client::PendingCall<io::SetRoleParent, Self::Env>;
+        /// Restricts heavy enumeration exports to `VIEWER_ROLE` holders, or lifts the restriction.
+        ///
+        /// Emits a `ViewerGateChanged` event if the gate changed.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_viewer_gate(
+            &mut self,
+            enabled: bool,
+        ) -> sails_rs::client::PendingCall<io::SetViewerGate, Self::Env>;
+        /// Returns up to `len` audit log entries of privileged calls, starting from `cursor`
+        /// or the oldest entry kept.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env>;
+        /// Returns the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+        fn get_admin_renounce_delay(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAdminRenounceDelay, Self::Env>;
+        /// Returns the number of distinct accounts holding any role.
+        fn get_all_member_count(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllMemberCount, Self::Env>;
+        /// Returns a list of distinct accounts holding any role with pagination.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn get_all_members(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetAllMembers, Self::Env>;
+        /// Returns whether the last super admin may give up `DEFAULT_ADMIN_ROLE`.
+        fn get_allow_admin_less(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllowAdminLess, Self::Env>;
+        /// Returns the max amount of items enumeration queries reply with at once.
+        fn get_max_page_size(&self)
+        -> sails_rs::client::PendingCall<io::GetMaxPageSize, Self::Env>;
         /// Returns the number of roles assigned to the specified member.
         fn get_member_role_count(
             &self,
@@ -138,6 +408,34 @@ pub mod access_control {
             member_id: ActorId,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetMemberRoles, Self::Env>;
+        /// Returns a list of `(role_id, member_id)` pairs across all roles with pagination.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn get_memberships(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetMemberships, Self::Env>;
+        /// Returns the block the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`
+        /// is ready at, if any.
+        fn get_pending_admin_renounce(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingAdminRenounce, Self::Env>;
+        /// Returns the pending grant of `role_id` to `account_id`, if any.
+        fn get_pending_grant(
+            &self,
+            role_id: [u8; 32],
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingGrant, Self::Env>;
+        /// Returns the guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+        fn get_recovery_config(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryConfig, Self::Env>;
+        /// Returns guardians' approvals of `new_admin` as the recovered super admin, if any.
+        fn get_recovery_proposal(
+            &self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryProposal, Self::Env>;
         /// Returns the admin role ID that controls `role_id`.
         fn get_role_admin(
             &self,
@@ -145,6 +443,11 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetRoleAdmin, Self::Env>;
         /// Returns the number of roles in the system.
         fn get_role_count(&self) -> sails_rs::client::PendingCall<io::GetRoleCount, Self::Env>;
+        /// Returns the number of blocks a grant of `role_id` is delayed for.
+        fn get_role_grant_delay(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleGrantDelay, Self::Env>;
         /// Returns the number of members in the specified role.
         fn get_role_member_count(
             &self,
@@ -156,23 +459,87 @@ pub mod access_control {
             role_id: [u8; 32],
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoleMembers, Self::Env>;
+        /// Returns the parent role `role_id` inherits from, if any.
+        fn get_role_parent(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleParent, Self::Env>;
         /// Returns a list of role IDs with pagination.
         fn get_roles(
             &self,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoles, Self::Env>;
-        /// Returns `true` if `account_id` has been granted `role_id`.
+        /// Returns whether heavy enumeration exports are restricted to `VIEWER_ROLE` holders.
+        fn get_viewer_gate(&self) -> sails_rs::client::PendingCall<io::GetViewerGate, Self::Env>;
+        /// Returns `true` if `account_id` has been granted `role_id` or inherits it
+        /// from a parent role.
         fn has_role(
             &self,
             role_id: [u8; 32],
             account_id: ActorId,
         ) -> sails_rs::client::PendingCall<io::HasRole, Self::Env>;
+        /// Returns `true` if `role_id` is locked, admitting no new members.
+        fn is_role_locked(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::IsRoleLocked, Self::Env>;
+        /// Returns roles held and administered by `account_id`, and grants pending to it,
+        /// in a single query.
+        fn permissions_of(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::PermissionsOf, Self::Env>;
+        /// Previews `grant_role` by the caller without mutating storage, e.g. for signers
+        /// of a multisig to review its effects, failing as `grant_role` would.
+        fn simulate_grant_role(
+            &self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SimulateGrantRole, Self::Env>;
     }
     pub struct AccessControlImpl;
     impl<E: sails_rs::client::GearEnv> AccessControl
         for sails_rs::client::Service<AccessControlImpl, E>
     {
         type Env = E;
+        fn cancel_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn cancel_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
+        fn cancel_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRoleGrant, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
+        fn execute_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn execute_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
+        fn execute_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRoleGrant, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
         fn grant_role(
             &mut self,
             role_id: [u8; 32],
@@ -187,6 +554,23 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GrantRolesBatch, Self::Env> {
             self.pending_call((role_ids, target_account))
         }
+        fn init_access_control(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::InitAccessControl, Self::Env> {
+            self.pending_call(())
+        }
+        fn lock_role(
+            &mut self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::LockRole, Self::Env> {
+            self.pending_call((role_id,))
+        }
+        fn propose_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ProposeRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
         fn renounce_role(
             &mut self,
             role_id: [u8; 32],
@@ -194,6 +578,14 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::RenounceRole, Self::Env> {
             self.pending_call((role_id, account_id))
         }
+        fn replace_role_member(
+            &mut self,
+            role_id: [u8; 32],
+            old_account: ActorId,
+            new_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ReplaceRoleMember, Self::Env> {
+            self.pending_call((role_id, old_account, new_account))
+        }
         fn revoke_role(
             &mut self,
             role_id: [u8; 32],
@@ -208,6 +600,32 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::RevokeRolesBatch, Self::Env> {
             self.pending_call((role_ids, target_account))
         }
+        fn set_admin_renounce_delay(
+            &mut self,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetAdminRenounceDelay, Self::Env> {
+            self.pending_call((delay,))
+        }
+        fn set_allow_admin_less(
+            &mut self,
+            allowed: bool,
+        ) -> sails_rs::client::PendingCall<io::SetAllowAdminLess, Self::Env> {
+            self.pending_call((allowed,))
+        }
+        fn set_max_page_size(
+            &mut self,
+            max_page_size: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env> {
+            self.pending_call((max_page_size,))
+        }
+        fn set_recovery_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRecoveryGuardians, Self::Env> {
+            self.pending_call((guardians, threshold, delay))
+        }
         fn set_role_admin(
             &mut self,
             role_id: [u8; 32],
@@ -215,6 +633,59 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::SetRoleAdmin, Self::Env> {
             self.pending_call((role_id, new_admin_role_id))
         }
+        fn set_role_grant_delay(
+            &mut self,
+            role_id: [u8; 32],
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRoleGrantDelay, Self::Env> {
+            self.pending_call((role_id, delay))
+        }
+        fn set_role_parent(
+            &mut self,
+            role_id: [u8; 32],
+            parent_role_id: Option<[u8; 32]>,
+        ) -> sails_rs::client::PendingCall<io::SetRoleParent, Self::Env> {
+            self.pending_call((role_id, parent_role_id))
+        }
+        fn set_viewer_gate(
+            &mut self,
+            enabled: bool,
+        ) -> sails_rs::client::PendingCall<io::SetViewerGate, Self::Env> {
+            self.pending_call((enabled,))
+        }
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn get_admin_renounce_delay(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAdminRenounceDelay, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_all_member_count(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllMemberCount, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_all_members(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetAllMembers, Self::Env> {
+            self.pending_call((query,))
+        }
+        fn get_allow_admin_less(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllowAdminLess, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_max_page_size(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetMaxPageSize, Self::Env> {
+            self.pending_call(())
+        }
         fn get_member_role_count(
             &self,
             member_id: ActorId,
@@ -228,6 +699,36 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetMemberRoles, Self::Env> {
             self.pending_call((member_id, query))
         }
+        fn get_memberships(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetMemberships, Self::Env> {
+            self.pending_call((query,))
+        }
+        fn get_pending_admin_renounce(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn get_pending_grant(
+            &self,
+            role_id: [u8; 32],
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingGrant, Self::Env> {
+            self.pending_call((role_id, account_id))
+        }
+        fn get_recovery_config(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryConfig, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_recovery_proposal(
+            &self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryProposal, Self::Env> {
+            self.pending_call((new_admin,))
+        }
         fn get_role_admin(
             &self,
             role_id: [u8; 32],
@@ -237,6 +738,12 @@ pub mod access_control {
         fn get_role_count(&self) -> sails_rs::client::PendingCall<io::GetRoleCount, Self::Env> {
             self.pending_call(())
         }
+        fn get_role_grant_delay(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleGrantDelay, Self::Env> {
+            self.pending_call((role_id,))
+        }
         fn get_role_member_count(
             &self,
             role_id: [u8; 32],
@@ -250,12 +757,21 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetRoleMembers, Self::Env> {
             self.pending_call((role_id, query))
         }
+        fn get_role_parent(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleParent, Self::Env> {
+            self.pending_call((role_id,))
+        }
         fn get_roles(
             &self,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoles, Self::Env> {
             self.pending_call((query,))
         }
+        fn get_viewer_gate(&self) -> sails_rs::client::PendingCall<io::GetViewerGate, Self::Env> {
+            self.pending_call(())
+        }
         fn has_role(
             &self,
             role_id: [u8; 32],
@@ -263,24 +779,77 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::HasRole, Self::Env> {
             self.pending_call((role_id, account_id))
         }
+        fn is_role_locked(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::IsRoleLocked, Self::Env> {
+            self.pending_call((role_id,))
+        }
+        fn permissions_of(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::PermissionsOf, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn simulate_grant_role(
+            &self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SimulateGrantRole, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
     }
 
     pub mod io {
         use super::*;
+        sails_rs::io_struct_impl!(CancelAdminRenounce (account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(CancelRecovery (new_admin: ActorId) -> ());
+        sails_rs::io_struct_impl!(CancelRoleGrant (role_id: [u8; 32], target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteAdminRenounce (account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteRecovery (new_admin: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteRoleGrant (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(GrantRole (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(GrantRolesBatch (role_ids: Vec<[u8; 32]>, target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(InitAccessControl () -> bool);
+        sails_rs::io_struct_impl!(LockRole (role_id: [u8; 32]) -> bool);
+        sails_rs::io_struct_impl!(ProposeRecovery (new_admin: ActorId) -> ());
         sails_rs::io_struct_impl!(RenounceRole (role_id: [u8; 32], account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(ReplaceRoleMember (role_id: [u8; 32], old_account: ActorId, new_account: ActorId) -> ());
         sails_rs::io_struct_impl!(RevokeRole (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(RevokeRolesBatch (role_ids: Vec<[u8; 32]>, target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(SetAdminRenounceDelay (delay: u32) -> ());
+        sails_rs::io_struct_impl!(SetAllowAdminLess (allowed: bool) -> bool);
+        sails_rs::io_struct_impl!(SetMaxPageSize (max_page_size: u32) -> ());
+        sails_rs::io_struct_impl!(SetRecoveryGuardians (guardians: Vec<ActorId>, threshold: u32, delay: u32) -> ());
         sails_rs::io_struct_impl!(SetRoleAdmin (role_id: [u8; 32], new_admin_role_id: [u8; 32]) -> ());
+        sails_rs::io_struct_impl!(SetRoleGrantDelay (role_id: [u8; 32], delay: u32) -> ());
+        sails_rs::io_struct_impl!(SetRoleParent (role_id: [u8; 32], parent_role_id: Option<[u8; 32]>) -> ());
+        sails_rs::io_struct_impl!(SetViewerGate (enabled: bool) -> bool);
+        sails_rs::io_struct_impl!(AuditLog (cursor: u32, len: u32) -> Vec<(u32,super::AuditEntry,)>);
+        sails_rs::io_struct_impl!(GetAdminRenounceDelay () -> u32);
+        sails_rs::io_struct_impl!(GetAllMemberCount () -> u32);
+        sails_rs::io_struct_impl!(GetAllMembers (query: Option<super::Pagination>) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(GetAllowAdminLess () -> bool);
+        sails_rs::io_struct_impl!(GetMaxPageSize () -> u32);
         sails_rs::io_struct_impl!(GetMemberRoleCount (member_id: ActorId) -> u32);
         sails_rs::io_struct_impl!(GetMemberRoles (member_id: ActorId, query: Option<super::Pagination>) -> Vec<[u8; 32]>);
+        sails_rs::io_struct_impl!(GetMemberships (query: Option<super::Pagination>) -> Vec<([u8; 32],ActorId,)>);
+        sails_rs::io_struct_impl!(GetPendingAdminRenounce (account_id: ActorId) -> Option<u32>);
+        sails_rs::io_struct_impl!(GetPendingGrant (role_id: [u8; 32], account_id: ActorId) -> Option<super::PendingGrant>);
+        sails_rs::io_struct_impl!(GetRecoveryConfig () -> super::RecoveryConfig);
+        sails_rs::io_struct_impl!(GetRecoveryProposal (new_admin: ActorId) -> Option<super::RecoveryProposal>);
         sails_rs::io_struct_impl!(GetRoleAdmin (role_id: [u8; 32]) -> [u8; 32]);
         sails_rs::io_struct_impl!(GetRoleCount () -> u32);
+        sails_rs::io_struct_impl!(GetRoleGrantDelay (role_id: [u8; 32]) -> u32);
         sails_rs::io_struct_impl!(GetRoleMemberCount (role_id: [u8; 32]) -> u32);
         sails_rs::io_struct_impl!(GetRoleMembers (role_id: [u8; 32], query: Option<super::Pagination>) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(GetRoleParent (role_id: [u8; 32]) -> Option<[u8; 32]>);
         sails_rs::io_struct_impl!(GetRoles (query: Option<super::Pagination>) -> Vec<[u8; 32]>);
+        sails_rs::io_struct_impl!(GetViewerGate () -> bool);
         sails_rs::io_struct_impl!(HasRole (role_id: [u8; 32], account_id: ActorId) -> bool);
+        sails_rs::io_struct_impl!(IsRoleLocked (role_id: [u8; 32]) -> bool);
+        sails_rs::io_struct_impl!(PermissionsOf (account_id: ActorId) -> super::Permissions);
+        sails_rs::io_struct_impl!(SimulateGrantRole (role_id: [u8; 32], target_account: ActorId) -> super::GrantPreview);
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -289,15 +858,22 @@ pub mod access_control {
         #[derive(PartialEq, Debug, Encode, Decode)]
         #[codec(crate = sails_rs::scale_codec)]
         pub enum AccessControlEvents {
+            /// `via_role` is the role that authorized the grant: the admin role of `role_id`,
+            /// or `DEFAULT_ADMIN_ROLE` on super admin override and recovery.
             RoleGranted {
                 role_id: [u8; 32],
                 target_account: ActorId,
                 sender: ActorId,
+                via_role: [u8; 32],
             },
+            /// `via_role` is the role that authorized the revocation: the admin role of `role_id`,
+            /// `DEFAULT_ADMIN_ROLE` on super admin override and recovery, or `role_id` itself
+            /// when renounced.
             RoleRevoked {
                 role_id: [u8; 32],
                 target_account: ActorId,
                 sender: ActorId,
+                via_role: [u8; 32],
             },
             RoleAdminChanged {
                 role_id: [u8; 32],
@@ -305,10 +881,109 @@ pub mod access_control {
                 new_admin_role_id: [u8; 32],
                 sender: ActorId,
             },
+            RoleParentChanged {
+                role_id: [u8; 32],
+                previous_parent_role_id: Option<[u8; 32]>,
+                new_parent_role_id: Option<[u8; 32]>,
+                sender: ActorId,
+            },
+            RecoveryConfigChanged {
+                threshold: u32,
+                delay: u32,
+                sender: ActorId,
+            },
+            RecoveryApproved {
+                new_admin: ActorId,
+                guardian: ActorId,
+                approvals: u32,
+            },
+            RecoveryScheduled {
+                new_admin: ActorId,
+                ready_at: u32,
+            },
+            RecoveryCancelled {
+                new_admin: ActorId,
+                sender: ActorId,
+            },
+            RecoveryExecuted {
+                new_admin: ActorId,
+            },
+            RoleGrantScheduled {
+                role_id: [u8; 32],
+                target_account: ActorId,
+                sender: ActorId,
+                ready_at: u32,
+            },
+            RoleGrantCancelled {
+                role_id: [u8; 32],
+                target_account: ActorId,
+                sender: ActorId,
+            },
+            RoleGrantDelayChanged {
+                role_id: [u8; 32],
+                previous_delay: u32,
+                new_delay: u32,
+                sender: ActorId,
+            },
+            /// `sender` managed `role_id` as a super admin without holding its `admin_role_id`.
+            SuperAdminOverrideUsed {
+                role_id: [u8; 32],
+                admin_role_id: [u8; 32],
+                sender: ActorId,
+            },
+            MaxPageSizeChanged {
+                previous_max_page_size: u32,
+                new_max_page_size: u32,
+                sender: ActorId,
+            },
+            ViewerGateChanged {
+                enabled: bool,
+                sender: ActorId,
+            },
+            RoleLocked {
+                role_id: [u8; 32],
+                sender: ActorId,
+            },
+            AdminRenounceScheduled {
+                account_id: ActorId,
+                ready_at: u32,
+            },
+            AdminRenounceCancelled {
+                account_id: ActorId,
+            },
+            AdminRenounceDelayChanged {
+                previous_delay: u32,
+                new_delay: u32,
+                sender: ActorId,
+            },
+            AllowAdminLessChanged {
+                allowed: bool,
+                sender: ActorId,
+            },
         }
         impl sails_rs::client::Event for AccessControlEvents {
-            const EVENT_NAMES: &'static [Route] =
-                &["RoleGranted", "RoleRevoked", "RoleAdminChanged"];
+            const EVENT_NAMES: &'static [Route] = &[
+                "RoleGranted",
+                "RoleRevoked",
+                "RoleAdminChanged",
+                "RoleParentChanged",
+                "RecoveryConfigChanged",
+                "RecoveryApproved",
+                "RecoveryScheduled",
+                "RecoveryCancelled",
+                "RecoveryExecuted",
+                "RoleGrantScheduled",
+                "RoleGrantCancelled",
+                "RoleGrantDelayChanged",
+                "SuperAdminOverrideUsed",
+                "MaxPageSizeChanged",
+                "ViewerGateChanged",
+                "RoleLocked",
+                "AdminRenounceScheduled",
+                "AdminRenounceCancelled",
+                "AdminRenounceDelayChanged",
+                "AllowAdminLessChanged",
+            ];
         }
         impl sails_rs::client::ServiceWithEvents for AccessControlImpl {
             type Event = AccessControlEvents;
@@ -322,3 +997,73 @@ pub struct Pagination {
     pub offset: u32,
     pub limit: u32,
 }
+/// Grant of a role awaiting its delay to pass.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PendingGrant {
+    pub ready_at: u32,
+    pub sender: ActorId,
+    pub via_role: [u8; 32],
+}
+/// Guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+///
+/// Recovery is disabled while there are no guardians.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<ActorId>,
+    pub threshold: u32,
+    pub delay: u32,
+}
+/// Guardians' approvals of a candidate for `DEFAULT_ADMIN_ROLE`.
+///
+/// `ready_at` is set once the approvals reach the threshold.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryProposal {
+    pub approvals: Vec<ActorId>,
+    pub ready_at: Option<u32>,
+}
+/// Record of a privileged call.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct AuditEntry {
+    pub block: u32,
+    pub actor: ActorId,
+    pub action: String,
+    pub params_hash: [u8; 32],
+}
+/// Effects of `grant_role` previewed by `simulate_grant_role`.
+///
+/// The grant emits `SuperAdminOverrideUsed` event if `via_role` isn't the admin role
+/// of the role, and, if `changed`, `RoleGrantScheduled` event if `ready_at` is set
+/// or `RoleGranted` one otherwise.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GrantPreview {
+    /// Role authorizing the grant.
+    pub via_role: [u8; 32],
+    /// Block the grant is scheduled to, if the role has a grant delay.
+    pub ready_at: Option<u32>,
+    /// Whether the role isn't granted or pending yet.
+    pub changed: bool,
+}
+/// Permissions of an account, reported by `permissions_of`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Permissions {
+    /// Roles granted to the account explicitly.
+    pub roles: Vec<[u8; 32]>,
+    /// Known roles the account may grant and revoke, with their grant delays.
+    pub administered: Vec<([u8; 32], u32)>,
+    /// Grants to the account awaiting their delay.
+    pub pending_grants: Vec<([u8; 32], PendingGrant)>,
+    /// Whether the account holds `DEFAULT_ADMIN_ROLE`, passing any role check.
+    pub is_super_admin: bool,
+}
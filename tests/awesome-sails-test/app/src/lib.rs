@@ -20,15 +20,43 @@
 
 use awesome_sails::{
     access_control::{AccessControl, RolesStorage},
+    amm::{self, Pool},
+    counter::{self, Counters},
+    dutch_auction::{self, AuctionStorage},
+    eth_binding::{self, Bindings},
+    fee_pool::{self, FeePoolStorage},
+    htlc::{self, Locks},
+    lending::{self, Claims, Market},
+    metrics,
+    name_registry::{self, Names},
+    order_book::{self, OrderBookStorage},
+    proxy::{self, ProxyStorage},
+    raffle::{self, RaffleStorage},
+    scheduler::{self, Schedule},
+    session_keys::{self, Sessions},
+    smart_account::{self, AccountStorage},
+    token_registry::{self, Registry},
+    treasury::{self, TreasuryStorage},
     vft,
     vft::utils::{Allowance, Allowances, Balance, Balances},
-    vft_admin, vft_extension, vft_metadata,
+    vft_admin,
+    vft_admin::AdminStorage,
+    vft_batch,
+    vft_bonding_curve::{self, BondingCurve},
+    vft_bridge_adapter::{self, BridgeStorage},
+    vft_extension,
+    vft_inflation::{self, Inflation},
+    vft_metadata,
     vft_metadata::Metadata,
-    vft_native_exchange, vft_native_exchange_admin,
+    vft_native_exchange::{self, ExchangeStorage},
+    vft_native_exchange_admin,
 };
 use awesome_sails_utils::{
-    error::Error,
+    ensure,
+    error::{BadConfig, Error},
     pause::{PausableRef, Pause},
+    rate_limit::RateLimiter,
+    reply::ReplyRouter,
     storage::{StorageMut, StorageRefCell},
 };
 use core::{cell::RefCell, ops::DerefMut};
@@ -64,6 +92,8 @@ impl TestService<'_> {
                     )?;
                 }
             }
+
+            a.rebuild_index();
         }
 
         let mut b = self.balances.get_mut()?;
@@ -85,18 +115,85 @@ impl TestService<'_> {
         }
 
         b.set_total_supply(total_supply);
+        b.checkpoint_supply(Syscall::block_height());
 
         Ok(())
     }
 }
 
+/// Maximum decimals of the token.
+pub const MAX_DECIMALS: u8 = 30;
+
+/// Configuration of the program, validated on deployment by [`init_guard`].
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitConfig {
+    /// Account granted `DEFAULT_ADMIN_ROLE`.
+    pub admin: ActorId,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Capacities of balances shards.
+    pub balances_shards: Vec<u32>,
+    /// Capacities of allowances shards.
+    pub allowances_shards: Vec<u32>,
+    /// Period allowances expire after, in blocks.
+    pub expiry_period: u32,
+}
+
+/// Checks the configuration, so misconfigured instances fail to deploy.
+pub fn init_guard(config: &InitConfig) -> Result<(), BadConfig> {
+    ensure!(!config.admin.is_zero(), BadConfig::ZeroAdmin);
+    ensure!(config.decimals <= MAX_DECIMALS, BadConfig::Decimals);
+    ensure!(
+        !config.balances_shards.is_empty()
+            && !config.allowances_shards.is_empty()
+            && config
+                .balances_shards
+                .iter()
+                .chain(&config.allowances_shards)
+                .all(|&capacity| capacity != 0),
+        BadConfig::ShardCapacity
+    );
+    ensure!(config.expiry_period != 0, BadConfig::ExpiryPeriod);
+
+    Ok(())
+}
+
 #[derive(Default)]
 pub struct Program {
     access_control_roles: RefCell<RolesStorage>, // New field for access control
+    account: RefCell<AccountStorage>,
+    admin: RefCell<AdminStorage>,
     allowances: RefCell<Allowances>,
+    amm: RefCell<Pool>,
+    auction: RefCell<AuctionStorage>,
     balances: RefCell<Balances>,
+    bindings: RefCell<Bindings>,
+    bridge: RefCell<BridgeStorage>,
+    bonding_curve: RefCell<BondingCurve>,
+    claims: RefCell<Claims>,
+    counters: RefCell<Counters>,
+    exchange: RefCell<ExchangeStorage>,
+    fee_pool: RefCell<FeePoolStorage>,
+    htlc: RefCell<Locks>,
+    inflation: RefCell<Inflation>,
+    lp_allowances: RefCell<Allowances>,
+    lp_balances: RefCell<Balances>,
+    market: RefCell<Market>,
     metadata: Metadata,
+    mint_limiter: RefCell<RateLimiter<ActorId>>,
+    names: RefCell<Names>,
+    orders: RefCell<OrderBookStorage>,
     pause: Pause,
+    proxy: RefCell<ProxyStorage>,
+    raffle: RefCell<RaffleStorage>,
+    registry: RefCell<Registry>,
+    replies: RefCell<ReplyRouter>,
+    schedule: RefCell<Schedule>,
+    sessions: RefCell<Sessions>,
+    treasury: RefCell<TreasuryStorage>,
 }
 
 impl Program {
@@ -108,9 +205,109 @@ impl Program {
         PausableRef::new(&self.pause, StorageRefCell::new(&self.balances))
     }
 
+    pub fn account_storage(&self) -> StorageRefCell<'_, AccountStorage> {
+        StorageRefCell::new(&self.account)
+    }
+
     pub fn access_control_storage(&self) -> StorageRefCell<'_, RolesStorage> {
         StorageRefCell::new(&self.access_control_roles)
     }
+
+    pub fn amm_storage(&self) -> StorageRefCell<'_, Pool> {
+        StorageRefCell::new(&self.amm)
+    }
+
+    pub fn auction_storage(&self) -> StorageRefCell<'_, AuctionStorage> {
+        StorageRefCell::new(&self.auction)
+    }
+
+    pub fn admin_storage(&self) -> StorageRefCell<'_, AdminStorage> {
+        StorageRefCell::new(&self.admin)
+    }
+
+    pub fn bindings_storage(&self) -> StorageRefCell<'_, Bindings> {
+        StorageRefCell::new(&self.bindings)
+    }
+
+    pub fn bridge_storage(&self) -> StorageRefCell<'_, BridgeStorage> {
+        StorageRefCell::new(&self.bridge)
+    }
+
+    pub fn bonding_curve_storage(&self) -> StorageRefCell<'_, BondingCurve> {
+        StorageRefCell::new(&self.bonding_curve)
+    }
+
+    pub fn claims_storage(&self) -> StorageRefCell<'_, Claims> {
+        StorageRefCell::new(&self.claims)
+    }
+
+    pub fn counters_storage(&self) -> StorageRefCell<'_, Counters> {
+        StorageRefCell::new(&self.counters)
+    }
+
+    pub fn exchange_storage(&self) -> StorageRefCell<'_, ExchangeStorage> {
+        StorageRefCell::new(&self.exchange)
+    }
+
+    pub fn fee_pool_storage(&self) -> StorageRefCell<'_, FeePoolStorage> {
+        StorageRefCell::new(&self.fee_pool)
+    }
+
+    pub fn htlc_storage(&self) -> StorageRefCell<'_, Locks> {
+        StorageRefCell::new(&self.htlc)
+    }
+
+    pub fn inflation_storage(&self) -> StorageRefCell<'_, Inflation> {
+        StorageRefCell::new(&self.inflation)
+    }
+
+    pub fn lp_allowances(&self) -> PausableRef<'_, Allowances> {
+        PausableRef::new(&self.pause, StorageRefCell::new(&self.lp_allowances))
+    }
+
+    pub fn lp_balances(&self) -> PausableRef<'_, Balances> {
+        PausableRef::new(&self.pause, StorageRefCell::new(&self.lp_balances))
+    }
+
+    pub fn market_storage(&self) -> PausableRef<'_, Market> {
+        PausableRef::new(&self.pause, StorageRefCell::new(&self.market))
+    }
+
+    pub fn names_storage(&self) -> StorageRefCell<'_, Names> {
+        StorageRefCell::new(&self.names)
+    }
+
+    pub fn orders_storage(&self) -> StorageRefCell<'_, OrderBookStorage> {
+        StorageRefCell::new(&self.orders)
+    }
+
+    pub fn proxy_storage(&self) -> StorageRefCell<'_, ProxyStorage> {
+        StorageRefCell::new(&self.proxy)
+    }
+
+    pub fn raffle_storage(&self) -> StorageRefCell<'_, RaffleStorage> {
+        StorageRefCell::new(&self.raffle)
+    }
+
+    pub fn registry_storage(&self) -> StorageRefCell<'_, Registry> {
+        StorageRefCell::new(&self.registry)
+    }
+
+    pub fn replies_storage(&self) -> StorageRefCell<'_, ReplyRouter> {
+        StorageRefCell::new(&self.replies)
+    }
+
+    pub fn schedule_storage(&self) -> StorageRefCell<'_, Schedule> {
+        StorageRefCell::new(&self.schedule)
+    }
+
+    pub fn sessions_storage(&self) -> StorageRefCell<'_, Sessions> {
+        StorageRefCell::new(&self.sessions)
+    }
+
+    pub fn treasury_storage(&self) -> StorageRefCell<'_, TreasuryStorage> {
+        StorageRefCell::new(&self.treasury)
+    }
 }
 
 #[program]
@@ -124,15 +321,76 @@ impl Program {
 
         Self {
             access_control_roles: RefCell::new(access_control_roles),
+            account: RefCell::new(AccountStorage::new(deployer)),
+            admin: Default::default(),
             allowances: Default::default(),
+            amm: Default::default(),
+            auction: Default::default(),
             balances: Default::default(),
+            bindings: Default::default(),
+            bridge: Default::default(),
+            bonding_curve: Default::default(),
+            claims: Default::default(),
+            counters: Default::default(),
+            exchange: Default::default(),
+            fee_pool: Default::default(),
+            htlc: Default::default(),
+            inflation: Default::default(),
+            lp_allowances: Default::default(),
+            lp_balances: Default::default(),
+            market: Default::default(),
             metadata: Metadata::default(),
+            mint_limiter: Default::default(),
+            names: Default::default(),
+            orders: Default::default(),
             pause,
+            proxy: Default::default(),
+            raffle: Default::default(),
+            registry: Default::default(),
+            replies: Default::default(),
+            schedule: Default::default(),
+            sessions: Default::default(),
+            treasury: Default::default(),
+        }
+    }
+
+    /// Deploys the program with the configuration, failing if it's invalid.
+    pub fn with_config(config: InitConfig) -> Self {
+        init_guard(&config).map_err(Error::from).unwrap();
+
+        let capacities =
+            |shards: &[u32]| shards.iter().map(|&capacity| capacity as usize).collect();
+
+        let balances = Balances::try_new(capacities(&config.balances_shards))
+            .map_err(|_| Error::from(BadConfig::ShardCapacity))
+            .unwrap();
+        let allowances =
+            Allowances::try_new(capacities(&config.allowances_shards), config.expiry_period)
+                .map_err(|_| Error::from(BadConfig::ShardCapacity))
+                .unwrap();
+
+        let mut access_control_roles = RolesStorage::default();
+        access_control_roles.grant_initial_admin(config.admin);
+
+        Self {
+            access_control_roles: RefCell::new(access_control_roles),
+            allowances: RefCell::new(allowances),
+            balances: RefCell::new(balances),
+            metadata: Metadata::new(config.name, config.symbol, config.decimals),
+            ..Default::default()
         }
     }
 
     pub fn handle_reply(&mut self) {
-        self.vft_native_exchange_admin().handle_reply();
+        let Ok(reply_to) = Syscall::reply_to() else {
+            return;
+        };
+
+        let tag = self.replies.borrow_mut().route(reply_to);
+
+        if tag == Some(vft_native_exchange_admin::REPLY_TAG) {
+            self.vft_native_exchange_admin().handle_reply();
+        }
     }
 
     pub fn test(&self) -> TestService<'_> {
@@ -146,6 +404,155 @@ impl Program {
         AccessControl::new(self.access_control_storage())
     }
 
+    pub fn amm(
+        &self,
+    ) -> amm::Amm<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        amm::Amm::new(
+            self.access_control(),
+            self.lp_balances(),
+            self.amm_storage(),
+            self.lp_vft(),
+        )
+    }
+
+    pub fn counter(&self) -> counter::Counter<'_> {
+        counter::Counter::new(self.access_control(), self.counters_storage())
+    }
+
+    pub fn dutch_auction(
+        &self,
+    ) -> dutch_auction::DutchAuction<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        dutch_auction::DutchAuction::new(
+            self.access_control(),
+            self.balances(),
+            self.auction_storage(),
+            self.vft(),
+        )
+    }
+
+    pub fn eth_binding(
+        &self,
+    ) -> eth_binding::EthBinding<'_, PausableRef<'_, Allowances>, PausableRef<'_, Balances>> {
+        eth_binding::EthBinding::new(self.allowances(), self.bindings_storage(), self.vft())
+    }
+
+    pub fn fee_pool(
+        &self,
+    ) -> fee_pool::FeePool<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        fee_pool::FeePool::new(
+            self.access_control(),
+            self.eth_binding(),
+            self.fee_pool_storage(),
+            self.vft(),
+        )
+    }
+
+    pub fn htlc(&self) -> htlc::Htlc<'_, PausableRef<'_, Allowances>, PausableRef<'_, Balances>> {
+        htlc::Htlc::new(self.htlc_storage(), self.vft())
+    }
+
+    pub fn lending(
+        &self,
+    ) -> lending::Lending<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        StorageRefCell<'_, Claims>,
+        PausableRef<'_, Market>,
+    > {
+        lending::Lending::new(
+            self.access_control(),
+            self.claims_storage(),
+            self.market_storage(),
+        )
+    }
+
+    /// LP token of the `Amm` pool, kept apart from the VFT of the program.
+    pub fn lp_vft(&self) -> vft::Vft<'_> {
+        vft::Vft::new(self.lp_allowances(), self.lp_balances())
+    }
+
+    pub fn metrics(&self) -> metrics::Metrics<'_> {
+        metrics::Metrics::new(
+            self.access_control_storage(),
+            self.allowances(),
+            self.balances(),
+        )
+    }
+
+    pub fn name_registry(&self) -> name_registry::NameRegistry<'_> {
+        name_registry::NameRegistry::new(self.names_storage())
+    }
+
+    pub fn order_book(
+        &self,
+    ) -> order_book::OrderBook<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        order_book::OrderBook::new(self.access_control(), self.orders_storage(), self.vft())
+    }
+
+    pub fn proxy(&self) -> proxy::Proxy<'_> {
+        proxy::Proxy::new(self.proxy_storage())
+    }
+
+    pub fn raffle(
+        &self,
+    ) -> raffle::Raffle<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        raffle::Raffle::new(self.access_control(), self.raffle_storage(), self.vft())
+    }
+
+    pub fn scheduler(&self) -> scheduler::Scheduler<'_> {
+        scheduler::Scheduler::new(self.access_control(), self.schedule_storage())
+    }
+
+    pub fn session_keys(
+        &self,
+    ) -> session_keys::SessionKeys<'_, PausableRef<'_, Allowances>, PausableRef<'_, Balances>> {
+        session_keys::SessionKeys::new(self.sessions_storage(), self.vft())
+    }
+
+    pub fn smart_account(&self) -> smart_account::SmartAccount<'_> {
+        smart_account::SmartAccount::new(self.sessions_storage(), self.account_storage())
+    }
+
+    pub fn token_registry(&self) -> token_registry::TokenRegistry<'_> {
+        token_registry::TokenRegistry::new(self.access_control(), self.registry_storage())
+    }
+
+    pub fn treasury(
+        &self,
+    ) -> treasury::Treasury<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        treasury::Treasury::new(self.access_control(), self.treasury_storage(), self.vft())
+    }
+
     pub fn vft(&self) -> vft::Vft<'_> {
         vft::Vft::new(self.allowances(), self.balances())
     }
@@ -157,18 +564,87 @@ impl Program {
         StorageRefCell<'_, RolesStorage>, // ACS generic
         PausableRef<'_, Allowances>,
         PausableRef<'_, Balances>,
+        StorageRefCell<'_, AdminStorage>,
     > {
         vft_admin::VftAdmin::new(
             self.access_control(), // Pass AccessControl (it's already an exposure implicitly)
             self.allowances(),
             self.balances(),
             &self.pause,
+            self.admin_storage(),
+            self.vft(),
+        )
+    }
+
+    pub fn vft_batch(
+        &self,
+    ) -> vft_batch::VftBatch<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+        StorageRefCell<'_, AdminStorage>,
+    > {
+        vft_batch::VftBatch::new(self.vft(), self.vft_admin())
+    }
+
+    pub fn vft_bonding_curve(
+        &self,
+    ) -> vft_bonding_curve::VftBondingCurve<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+    > {
+        vft_bonding_curve::VftBondingCurve::new(
+            self.access_control(),
+            self.balances(),
+            self.bonding_curve_storage(),
+            self.vft(),
+        )
+    }
+
+    pub fn vft_bridge_adapter(
+        &self,
+    ) -> vft_bridge_adapter::VftBridgeAdapter<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+        StorageRefCell<'_, AdminStorage>,
+    > {
+        vft_bridge_adapter::VftBridgeAdapter::new(
+            self.access_control(),
+            &self.pause,
+            self.bridge_storage(),
             self.vft(),
+            self.vft_admin(),
         )
     }
 
     pub fn vft_extension(&self) -> vft_extension::VftExtension<'_> {
-        vft_extension::VftExtension::new(self.allowances(), self.balances(), self.vft())
+        vft_extension::VftExtension::new(
+            self.access_control(),
+            self.allowances(),
+            self.balances(),
+            self.vft(),
+        )
+    }
+
+    pub fn vft_inflation(
+        &self,
+    ) -> vft_inflation::VftInflation<
+        '_,
+        StorageRefCell<'_, RolesStorage>,
+        PausableRef<'_, Allowances>,
+        PausableRef<'_, Balances>,
+        StorageRefCell<'_, AdminStorage>,
+    > {
+        vft_inflation::VftInflation::new(
+            self.access_control(),
+            self.inflation_storage(),
+            self.vft_admin(),
+        )
     }
 
     pub fn vft_metadata(&self) -> vft_metadata::VftMetadata<&Metadata> {
@@ -181,8 +657,14 @@ impl Program {
         '_,
         PausableRef<'_, Allowances>,
         PausableRef<'_, Balances>,
+        StorageRefCell<'_, RateLimiter<ActorId>>,
     > {
-        vft_native_exchange::VftNativeExchange::new(self.balances(), self.vft())
+        vft_native_exchange::VftNativeExchange::new(
+            self.balances(),
+            StorageRefCell::new(&self.mint_limiter),
+            self.exchange_storage(),
+            self.vft(),
+        )
     }
 
     pub fn vft_native_exchange_admin(
@@ -192,8 +674,14 @@ impl Program {
         StorageRefCell<'_, RolesStorage>, // ACS generic for vft-admin
         PausableRef<'_, Allowances>,
         PausableRef<'_, Balances>,
+        StorageRefCell<'_, AdminStorage>,
     > {
         // vft_native_exchange_admin now takes a vft_admin VftAdmin, so we pass that
-        vft_native_exchange_admin::VftNativeExchangeAdmin::new(self.vft_admin())
+        vft_native_exchange_admin::VftNativeExchangeAdmin::new(
+            self.access_control(),
+            self.replies_storage(),
+            self.exchange_storage(),
+            self.vft_admin(),
+        )
     }
 }
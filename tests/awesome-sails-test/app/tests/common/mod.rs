@@ -16,6 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod snapshot;
+
 use awesome_sails_test_client::{
     AwesomeSailsTestClient, // Import AwesomeSailsTestClient trait
     AwesomeSailsTestClientCtors,
@@ -87,14 +89,27 @@ pub async fn deploy_with_data(
 ) {
     let (env, code_id, _gas_limit) = deploy_env();
 
+    let program = deploy_program(&env, code_id, b"salt", allowances, balances, expiry_period).await;
+    let program_id = program.id();
+
+    (program, env, program_id)
+}
+
+/// Deploys another program from the code in the given environment, e.g. a remote VFT.
+pub async fn deploy_program(
+    env: &GtestEnv,
+    code_id: CodeId,
+    salt: &[u8],
+    allowances: Vec<(ActorId, ActorId, U256, u32)>,
+    balances: Vec<(ActorId, U256)>,
+    expiry_period: u32,
+) -> Actor<AwesomeSailsTestClientProgram, GtestEnv> {
     let program = env
-        .deploy::<AwesomeSailsTestClientProgram>(code_id, b"salt".to_vec())
+        .deploy::<AwesomeSailsTestClientProgram>(code_id, salt.to_vec())
         .new()
         .await
         .expect("failed to deploy program");
 
-    let program_id = program.id();
-
     let mut vft_extension = program.vft_extension();
 
     while vft_extension
@@ -115,7 +130,7 @@ pub async fn deploy_with_data(
         .await
         .expect("failed to set data");
 
-    (program, env, program_id)
+    program
 }
 
 #[track_caller]
@@ -0,0 +1,145 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Snapshots of the program state, diffed so tests assert that only expected
+//! entries changed instead of checking every account.
+
+use awesome_sails_test_client::{
+    AwesomeSailsTestClient, AwesomeSailsTestClientProgram, Pagination,
+    access_control::AccessControl, vft_extension::VftExtension,
+};
+use sails_rs::{
+    ActorId, U256,
+    client::{Actor, GtestEnv},
+    collections::{BTreeMap, BTreeSet},
+};
+
+/// Entries fetched per query.
+const PAGE: u32 = 64;
+
+/// Balances, allowances and role memberships of the program, captured
+/// by enumeration queries.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StateSnapshot {
+    pub balances: BTreeMap<ActorId, U256>,
+    pub allowances: BTreeMap<(ActorId, ActorId), (U256, u32)>,
+    pub roles: BTreeSet<([u8; 32], ActorId)>,
+}
+
+/// Entry differing between snapshots, `None` if absent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    pub before: Option<T>,
+    pub after: Option<T>,
+}
+
+/// Entries differing between snapshots.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StateDiff {
+    pub balances: BTreeMap<ActorId, Change<U256>>,
+    pub allowances: BTreeMap<(ActorId, ActorId), Change<(U256, u32)>>,
+    pub granted: BTreeSet<([u8; 32], ActorId)>,
+    pub revoked: BTreeSet<([u8; 32], ActorId)>,
+}
+
+impl StateSnapshot {
+    pub async fn capture(program: &Actor<AwesomeSailsTestClientProgram, GtestEnv>) -> Self {
+        let vft_extension = program.vft_extension();
+        let access_control = program.access_control();
+
+        let mut snapshot = Self::default();
+
+        let mut cursor = 0;
+        loop {
+            let page = vft_extension
+                .balances(cursor, PAGE)
+                .await
+                .expect("failed to query balances");
+            let len = page.len() as u32;
+
+            snapshot.balances.extend(page);
+
+            if len < PAGE {
+                break;
+            }
+            cursor += len;
+        }
+
+        let mut cursor = 0;
+        loop {
+            let page = vft_extension
+                .allowances(cursor, PAGE)
+                .await
+                .expect("failed to query allowances");
+            let len = page.len() as u32;
+
+            snapshot.allowances.extend(page);
+
+            if len < PAGE {
+                break;
+            }
+            cursor += len;
+        }
+
+        let mut offset = 0;
+        loop {
+            let page = access_control
+                .get_memberships(Some(Pagination {
+                    offset,
+                    limit: PAGE,
+                }))
+                .await
+                .expect("failed to query memberships");
+            let len = page.len() as u32;
+
+            snapshot.roles.extend(page);
+
+            if len < PAGE {
+                break;
+            }
+            offset += len;
+        }
+
+        snapshot
+    }
+
+    /// Returns entries changed from `self` to `after`.
+    pub fn diff(&self, after: &Self) -> StateDiff {
+        StateDiff {
+            balances: diff_maps(&self.balances, &after.balances),
+            allowances: diff_maps(&self.allowances, &after.allowances),
+            granted: after.roles.difference(&self.roles).copied().collect(),
+            revoked: self.roles.difference(&after.roles).copied().collect(),
+        }
+    }
+}
+
+fn diff_maps<K: Ord + Copy, V: PartialEq + Copy>(
+    before: &BTreeMap<K, V>,
+    after: &BTreeMap<K, V>,
+) -> BTreeMap<K, Change<V>> {
+    before
+        .keys()
+        .chain(after.keys())
+        .filter_map(|key| {
+            let (before, after) = (before.get(key).copied(), after.get(key).copied());
+
+            (before != after).then_some((*key, Change { before, after }))
+        })
+        .collect()
+}
@@ -18,19 +18,77 @@
 
 mod common;
 
+use awesome_sails::counter::COUNTER_ADMIN_ROLE;
+use awesome_sails::dutch_auction::PRICE_SCALE as AUCTION_PRICE_SCALE;
+use awesome_sails::fee_pool::{RATE_SCALE, RELAYER_ROLE};
+use awesome_sails::htlc::hash;
+use awesome_sails::lending::{ORACLE_ROLE, SCALE};
+use awesome_sails::order_book::{PRICE_SCALE as ORDER_PRICE_SCALE, SETTLER_ROLE};
+use awesome_sails::raffle::{OPERATOR_ROLE, REVEAL_BLOCKS};
+use awesome_sails::scheduler::{DEFAULT_MIN_SELF_DELAY, SCHEDULER_ROLE};
+use awesome_sails::session_keys::{APPROVE, TRANSFER};
+use awesome_sails::smart_account::EXECUTE;
+use awesome_sails::token_registry::CURATOR_ROLE;
+use awesome_sails::treasury::{SPENDER_ROLE, TREASURER_ROLE};
 use awesome_sails::vft::utils::{Allowance, Balance};
-use awesome_sails::vft_admin::PAUSER_ROLE;
+use awesome_sails::vft_admin::{BURNER_ROLE, MINTER_ROLE, PAUSER_ROLE};
+use awesome_sails::vft_bonding_curve::PRICE_SCALE;
+use awesome_sails::vft_extension::approval_notification;
 use awesome_sails_test_client::{
-    AwesomeSailsTestClient,
+    Asset, Auction, AuctionParams, AwesomeSailsTestClient, AwesomeSailsTestClientCtors,
+    AwesomeSailsTestClientProgram, BurnPreview, Call, CapacityAlert, CircuitBreakerConfig, Claim,
+    Curve, DustPolicy, ExchangeStats, FeeConfig, FeeRate, Fill, GrantPreview, InitConfig, Lock,
+    MapCursor, MarketParams, MintBounds, MintRule, NameRecord, Order, Pair, PauseReason, Position,
+    ProposalStatus, Reconciliation, Recovery, RelayerStats, RoundParams, RoundStatus, Session,
+    Side, SignedFill, SnapshotRoot, StorageMap, TokenInfo, TokenMetadata, UnsoldPolicy,
     access_control::AccessControl,
+    amm::{Amm, events::AmmEvents},
+    counter::{Counter, events::CounterEvents},
+    dutch_auction::{DutchAuction, events::DutchAuctionEvents},
+    eth_binding::{EthBinding, events::EthBindingEvents},
+    fee_pool::{FeePool, events::FeePoolEvents},
+    htlc::{Htlc, events::HtlcEvents},
+    lending::{Lending, events::LendingEvents},
+    lp_vft::LpVft,
+    metrics::Metrics,
+    name_registry::{NameRegistry, events::NameRegistryEvents},
+    order_book::{OrderBook, events::OrderBookEvents},
+    proxy::{Proxy, events::ProxyEvents},
+    raffle::{Raffle, events::RaffleEvents},
+    scheduler::{Scheduler, events::SchedulerEvents},
+    session_keys::{SessionKeys, events::SessionKeysEvents},
+    smart_account::{SmartAccount, events::SmartAccountEvents},
+    token_registry::{TokenRegistry, events::TokenRegistryEvents},
+    treasury::{Treasury, events::TreasuryEvents},
     vft::{Vft, events::VftEvents},
-    vft_admin::VftAdmin,
+    vft_admin::{VftAdmin, events::VftAdminEvents},
+    vft_batch::VftBatch,
+    vft_bonding_curve::{VftBondingCurve, events::VftBondingCurveEvents},
+    vft_bridge_adapter::{VftBridgeAdapter, events::VftBridgeAdapterEvents},
     vft_extension::VftExtension,
+    vft_inflation::{VftInflation, events::VftInflationEvents},
+    vft_metadata::VftMetadata,
+    vft_native_exchange::VftNativeExchange,
+    vft_native_exchange_admin::{VftNativeExchangeAdmin, events::VftNativeExchangeAdminEvents},
+};
+use awesome_sails_utils::{
+    assert_ok, commit_reveal, eth,
+    math::Max,
+    merkle::{self, Keccak256},
+};
+use common::{
+    ALICE, BALANCE, BOB, CHARLIE, DAVE, assert_str_panic, deploy_env, deploy_program,
+    deploy_with_data,
+    snapshot::{Change, StateDiff, StateSnapshot},
 };
-use awesome_sails_utils::{assert_ok, math::Max};
-use common::{ALICE, BOB, CHARLIE, DAVE, assert_str_panic, deploy_with_data};
 use futures::StreamExt;
-use sails_rs::{U256, prelude::*};
+use gtest::Log;
+use k256::ecdsa::SigningKey;
+use sails_rs::{
+    U256,
+    collections::{BTreeMap, BTreeSet},
+    prelude::*,
+};
 
 const MAGIC: usize = 21;
 const BN: u32 = 137;
@@ -192,8 +250,13 @@ async fn approve() {
 
 #[tokio::test]
 async fn balance_of() {
+    let address = H160::repeat_byte(0x42);
+
     let allowances = Default::default();
-    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+    let balances = vec![
+        (ALICE, U256::exp10(MAGIC)),
+        (ActorId::from(address), U256::exp10(MAGIC - 1)),
+    ];
 
     let (program, _env, _pid) = deploy_with_data(allowances, balances, 0).await;
 
@@ -219,6 +282,610 @@ async fn balance_of() {
         let res = vft_service.balance_of(BOB).await;
         assert_ok!(res, U256::zero());
     }
+
+    // # Test case #3.
+    // Balance of the address is held by the padded actor.
+    {
+        let res = vft_extension_service.balance_of_address(address).await;
+        assert_ok!(res, U256::exp10(MAGIC - 1));
+
+        let res = vft_extension_service
+            .balance_of_address(H160::repeat_byte(0x24))
+            .await;
+        assert_ok!(res, U256::zero());
+    }
+}
+
+#[tokio::test]
+async fn batch_queries() {
+    let allowances = vec![(ALICE, BOB, U256::exp10(MAGIC), BN)];
+    let balances = vec![(ALICE, U256::exp10(MAGIC)), (CHARLIE, U256::one())];
+
+    let (program, _env, _pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let vft_extension_service = program.vft_extension();
+
+    let res = vft_extension_service
+        .balances_of(vec![ALICE, BOB, CHARLIE])
+        .await;
+    assert_ok!(res, vec![U256::exp10(MAGIC), U256::zero(), U256::one()]);
+
+    let res = vft_extension_service
+        .allowances_of(vec![(ALICE, BOB), (BOB, ALICE)])
+        .await;
+    assert_ok!(res, vec![U256::exp10(MAGIC), U256::zero()]);
+
+    // Compact enumeration encodes the same values in their native widths
+    let balances = vft_extension_service.balances(0, 10).await.unwrap();
+    let res = vft_extension_service.balances_compact(0, 10).await.unwrap();
+    assert_eq!(
+        res.into_iter()
+            .map(|(account, v)| (account, U256::from_little_endian(&v.0)))
+            .collect::<Vec<_>>(),
+        balances
+    );
+
+    let allowances = vft_extension_service.allowances(0, 10).await.unwrap();
+    let res = vft_extension_service
+        .allowances_compact(0, 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        res.into_iter()
+            .map(|(key, (v, expiry))| (key, (U256::from_little_endian(&v.0), expiry)))
+            .collect::<Vec<_>>(),
+        allowances
+    );
+
+    // Pages resumed from cursors cover the same balances
+    let cursor = MapCursor {
+        shard: 0,
+        bucket: 0,
+    };
+    let (first, cursor) = vft_extension_service
+        .balances_from(cursor, 1)
+        .await
+        .unwrap();
+    let (rest, cursor) = vft_extension_service
+        .balances_from(cursor, 10)
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!([first, rest].concat(), balances);
+
+    let res = vft_extension_service.balances_from(cursor, 10).await;
+    assert_ok!(res.map(|(page, _)| page), vec![]);
+
+    let res = vft_extension_service
+        .allowances_from(
+            MapCursor {
+                shard: 0,
+                bucket: 0,
+            },
+            10,
+        )
+        .await;
+    assert_ok!(res.map(|(page, _)| page), allowances);
+
+    let res = vft_extension_service.balances_of(vec![]).await;
+    assert_ok!(res, vec![]);
+
+    // Oversized batches are rejected
+    let res = vft_extension_service.balances_of(vec![ALICE; 1001]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn approve_and_notify() {
+    let (program, env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut vft_extension_service = program.vft_extension();
+    let vft_service = program.vft();
+
+    let notification = |value: U256| {
+        Log::builder()
+            .source(pid)
+            .dest(BOB)
+            .payload_bytes(approval_notification(ALICE, value))
+    };
+
+    // Spenders are notified of new allowances.
+    let res = vft_extension_service
+        .approve_and_notify(BOB, U256::exp10(MAGIC))
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service.allowance(ALICE, BOB).await;
+    assert_ok!(res, U256::exp10(MAGIC));
+
+    assert!(
+        env.system()
+            .get_mailbox(BOB)
+            .contains(&notification(U256::exp10(MAGIC)))
+    );
+
+    // Unchanged allowances aren't notified.
+    let res = vft_extension_service
+        .approve_and_notify(BOB, U256::exp10(MAGIC))
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, false);
+
+    let res = vft_extension_service
+        .approve_and_notify(BOB, U256::MAX)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    assert!(
+        env.system()
+            .get_mailbox(BOB)
+            .contains(&notification(U256::MAX))
+    );
+}
+
+#[tokio::test]
+async fn operators() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_extension_service = program.vft_extension();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    // Operators move any value without allowances.
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 1.into())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_extension_service
+        .set_operator(BOB, true)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::OperatorSet {
+            owner: ALICE,
+            operator: BOB,
+            approved: true,
+        }
+    );
+
+    let res = vft_extension_service
+        .set_operator(BOB, true)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, false);
+
+    let res = vft_extension_service
+        .set_operator(DAVE, true)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, U256::exp10(10))
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service.balance_of(CHARLIE).await;
+    assert_ok!(res, U256::exp10(10));
+
+    let res = vft_service.allowance(ALICE, BOB).await;
+    assert_ok!(res, U256::zero());
+
+    // Operators are enumerated for either side.
+    let res = vft_extension_service.is_operator(ALICE, BOB).await;
+    assert_ok!(res, true);
+
+    let res = vft_extension_service.operators_of(ALICE, 0, 10).await;
+    assert_ok!(res, vec![BOB, DAVE]);
+
+    let res = vft_extension_service.operators_of(ALICE, 1, 10).await;
+    assert_ok!(res, vec![DAVE]);
+
+    let res = vft_extension_service.operated_by(BOB, 0, 10).await;
+    assert_ok!(res, vec![ALICE]);
+
+    // Revoked operators no longer transfer.
+    let res = vft_extension_service
+        .set_operator(BOB, false)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 1.into())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_extension_service.operated_by(BOB, 0, 10).await;
+    assert_ok!(res, vec![]);
+}
+
+#[tokio::test]
+async fn spending_caps() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_extension_service = program.vft_extension();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    // Caps are renewed every period.
+    let res = vft_extension_service
+        .approve_with_cap(BOB, 100.into(), 0)
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_extension_service
+        .approve_with_cap(BOB, 100.into(), 10)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::SpendingCapSet {
+            owner: ALICE,
+            spender: BOB,
+            amount_per_period: 100.into(),
+            period_blocks: 10,
+        }
+    );
+
+    let cap = vft_extension_service
+        .spending_cap(ALICE, BOB)
+        .await
+        .unwrap()
+        .expect("cap is set");
+    assert_eq!(cap.amount_per_period, U256::from(100));
+    assert_eq!(cap.period_blocks, 10);
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 60.into())
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 50.into())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Caps on the same terms aren't renewed.
+    let res = vft_extension_service
+        .approve_with_cap(BOB, 100.into(), 10)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, false);
+
+    let res = vft_extension_service
+        .spending_cap_remaining(ALICE, BOB)
+        .await;
+    assert_ok!(res, Some(40.into()));
+
+    env.system()
+        .run_to_block(cap.period_start + cap.period_blocks);
+
+    let res = vft_extension_service
+        .spending_cap_remaining(ALICE, BOB)
+        .await;
+    assert_ok!(res, Some(100.into()));
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 100.into())
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_service.balance_of(CHARLIE).await;
+    assert_ok!(res, U256::from(160));
+
+    // Removed caps are no longer spent.
+    let res = vft_extension_service
+        .approve_with_cap(BOB, U256::zero(), 0)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, true);
+
+    let res = vft_extension_service.spending_cap(ALICE, BOB).await;
+    assert_ok!(res, None);
+
+    env.system()
+        .run_to_block(cap.period_start + 2 * cap.period_blocks);
+
+    let res = vft_service
+        .transfer_from(ALICE, CHARLIE, 1.into())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn claimable_transfers() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_extension_service = program.vft_extension();
+    let vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    // Transfers are escrowed until claimed by recipients.
+    let res = vft_extension_service
+        .transfer_claimable(BOB, 1_000.into(), 0)
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+    let id = vft_extension_service
+        .transfer_claimable(BOB, 1_000.into(), 100)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::Transfer {
+            from: ALICE,
+            to: pid,
+            value: 1_000.into(),
+        }
+    );
+
+    let claim = vft_extension_service
+        .pending_claim(id)
+        .await
+        .unwrap()
+        .expect("claim is pending");
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::ClaimCreated {
+            id,
+            from: ALICE,
+            to: BOB,
+            value: 1_000.into(),
+            expires_at: claim.expires_at,
+        }
+    );
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, 1_000.into());
+
+    let res = vft_extension_service.pending_claims_to(BOB, 0, 10).await;
+    assert_ok!(res, vec![(id, claim.clone())]);
+
+    let res = vft_extension_service
+        .pending_claims_from(ALICE, 0, 10)
+        .await;
+    assert_ok!(res, vec![(id, claim)]);
+
+    let res = vft_extension_service.claim(id).with_actor_id(CHARLIE).await;
+    assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+    // Senders reclaim only once claims expire.
+    let res = vft_extension_service.reclaim(id).with_actor_id(ALICE).await;
+    assert_ok!(res, false);
+
+    let res = vft_extension_service.claim(id).with_actor_id(BOB).await;
+    assert_ok!(res, true);
+
+    let _ = vft_events.next().await.unwrap();
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::ClaimSettled {
+            id,
+            to: BOB,
+            value: 1_000.into(),
+        }
+    );
+
+    let res = vft_service.balance_of(BOB).await;
+    assert_ok!(res, 1_000.into());
+
+    let res = vft_extension_service.claim(id).with_actor_id(BOB).await;
+    assert_ok!(res, false);
+
+    // Expired claims are reclaimed by senders.
+    let id = vft_extension_service
+        .transfer_claimable(DAVE, 1_000.into(), 10)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let Claim { expires_at, .. } = vft_extension_service
+        .pending_claim(id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    env.system().run_to_block(expires_at);
+
+    let res = vft_extension_service.reclaim(id).with_actor_id(ALICE).await;
+    assert_ok!(res, true);
+
+    let res = vft_service.balance_of(ALICE).await;
+    assert_ok!(res, U256::exp10(MAGIC) - 1_000);
+
+    let res = vft_extension_service.pending_claims_to(DAVE, 0, 10).await;
+    assert_ok!(res, vec![]);
+}
+
+#[tokio::test]
+async fn claimable_transfers_below_minimum() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC)), (CHARLIE, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_extension_service = program.vft_extension();
+    let vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    vft_admin_service
+        .set_minimum_balance(1_000.into())
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // The program escrows value below the minimum.
+    let charlie_id = vft_extension_service
+        .transfer_claimable(CHARLIE, 500.into(), 100)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let bob_id = vft_extension_service
+        .transfer_claimable(BOB, 1_000.into(), 100)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    for _ in 0..4 {
+        let _ = vft_events.next().await.unwrap();
+    }
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, 1_500.into());
+
+    // Escrow of the other claim isn't removed as dust.
+    let res = vft_extension_service.claim(bob_id).with_actor_id(BOB).await;
+    assert_ok!(res, true);
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::Transfer {
+            from: pid,
+            to: BOB,
+            value: 1_000.into(),
+        }
+    );
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::ClaimSettled {
+            id: bob_id,
+            to: BOB,
+            value: 1_000.into(),
+        }
+    );
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, 500.into());
+
+    // The program is reaped once the escrow is paid out.
+    let res = vft_extension_service
+        .claim(charlie_id)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_ok!(res, true);
+
+    let _ = vft_events.next().await.unwrap();
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::AccountReaped {
+            account: pid,
+            dust: U256::zero(),
+        }
+    );
+
+    let res = vft_service.balance_of(CHARLIE).await;
+    assert_ok!(res, U256::exp10(MAGIC) + 500);
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, U256::zero());
+}
+
+#[tokio::test]
+async fn allowances_enumeration() {
+    let allowances = vec![
+        (ALICE, BOB, U256::exp10(MAGIC), BN),
+        (ALICE, CHARLIE, U256::one(), BN),
+        (DAVE, BOB, Allowance::MAX.into(), BN),
+    ];
+
+    let (program, _env, _pid) = deploy_with_data(allowances, Default::default(), 0).await;
+
+    let mut vft_service = program.vft();
+    let vft_extension_service = program.vft_extension();
+
+    let res = vft_extension_service
+        .allowances_of_owner(ALICE, 0, 10)
+        .await;
+    assert_ok!(
+        res,
+        vec![
+            (BOB, (U256::exp10(MAGIC), BN)),
+            (CHARLIE, (U256::one(), BN))
+        ]
+    );
+
+    let res = vft_extension_service
+        .allowances_to_spender(BOB, 0, 10)
+        .await;
+    assert_ok!(
+        res,
+        vec![(ALICE, (U256::exp10(MAGIC), BN)), (DAVE, (U256::MAX, BN))]
+    );
+
+    let res = vft_extension_service
+        .allowances_to_spender(BOB, 1, 10)
+        .await;
+    assert_ok!(res, vec![(DAVE, (U256::MAX, BN))]);
+
+    // Indexes follow approvals
+    vft_service
+        .approve(BOB, U256::zero())
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let res = vft_extension_service
+        .allowances_to_spender(BOB, 0, 10)
+        .await;
+    assert_ok!(res, vec![(DAVE, (U256::MAX, BN))]);
+
+    let res = vft_extension_service
+        .allowances_of_owner(ALICE, 0, 10)
+        .await;
+    assert_ok!(res, vec![(CHARLIE, (U256::one(), BN))]);
 }
 
 #[tokio::test]
@@ -354,6 +1021,16 @@ async fn transfer() {
             }
         );
 
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: BOB,
+                dust: U256::zero(),
+            }
+        );
+
         let res = vft_service.balance_of(ALICE).await;
         assert_ok!(res, U256::exp10(MAGIC));
 
@@ -382,6 +1059,16 @@ async fn transfer() {
             }
         );
 
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: ALICE,
+                dust: U256::zero(),
+            }
+        );
+
         let res = vft_service.balance_of(CHARLIE).await;
         assert_ok!(res, U256::exp10(MAGIC));
 
@@ -486,6 +1173,16 @@ async fn transfer_from() {
             }
         );
 
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: BOB,
+                dust: U256::zero(),
+            }
+        );
+
         let res = vft_service.balance_of(ALICE).await;
         assert_ok!(res, U256::exp10(MAGIC));
 
@@ -727,13 +1424,16 @@ async fn pause() {
     let allowances = vec![(ALICE, BOB, U256::exp10(MAGIC), BN)];
     let balances = Default::default();
 
-    let (program, _env, _pid) = deploy_with_data(allowances, balances, 0).await;
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
 
     let mut access_control_service = program.access_control(); // NEW: Access Control service
     let mut vft_service = program.vft();
     let mut vft_admin_service = program.vft_admin();
     let vft_extension_service = program.vft_extension();
 
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
     // Alice grants PAUSER_ROLE to herself (she already has DEFAULT_ADMIN_ROLE)
     access_control_service
         .grant_role(PAUSER_ROLE, ALICE)
@@ -750,16 +1450,31 @@ async fn pause() {
         assert_ok!(res, U256::exp10(MAGIC));
     }
 
+    let res = vft_admin_service.pause_info().await;
+    assert_ok!(res, None);
+
     // Pause (Alice has PAUSER_ROLE)
     {
         vft_admin_service
-            .pause()
+            .pause(Some(PauseReason::Message("exploit".into())), None)
             .with_actor_id(ALICE)
             .await
             .unwrap(); // Call from Alice
 
         let paused = vft_admin_service.is_paused().await.unwrap();
         assert!(paused);
+
+        let info = vft_admin_service
+            .pause_info()
+            .await
+            .unwrap()
+            .expect("pause is attributed");
+        assert_eq!(info.paused_by, ALICE);
+        assert_eq!(info.reason, Some(PauseReason::Message("exploit".into())));
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::Paused(info));
     }
 
     // Call paused.
@@ -769,3 +1484,5817 @@ async fn pause() {
         assert_str_panic(res.unwrap_err(), "storage is paused");
     }
 }
+
+#[tokio::test]
+async fn burn_batch() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC)), (CHARLIE, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Bob has no BURNER_ROLE.
+    {
+        let res = vft_admin_service
+            .burn_batch(vec![(CHARLIE, U256::one())])
+            .with_actor_id(BOB)
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    // # Test case #2.
+    // Whole batch fails if any of burns fails.
+    {
+        let res = vft_admin_service
+            .burn_batch(vec![
+                (BOB, U256::exp10(MAGIC - 1)),
+                (DAVE, U256::exp10(MAGIC - 1)),
+            ])
+            .with_actor_id(ALICE)
+            .await;
+
+        assert_str_panic(res.unwrap_err(), "insufficient balance");
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC));
+    }
+
+    // # Test case #3.
+    // Alice burns from Bob and Charlie.
+    {
+        let res = vft_admin_service
+            .burn_batch(vec![
+                (BOB, U256::exp10(MAGIC - 1)),
+                (CHARLIE, U256::exp10(MAGIC)),
+            ])
+            .with_actor_id(ALICE)
+            .await;
+
+        assert_ok!(res, ());
+
+        for (from, value) in [(BOB, U256::exp10(MAGIC - 1)), (CHARLIE, U256::exp10(MAGIC))] {
+            let (actor, event) = vft_events.next().await.unwrap();
+            assert_eq!(actor, pid);
+            assert_eq!(
+                event,
+                VftEvents::Transfer {
+                    from,
+                    to: ActorId::zero(),
+                    value,
+                }
+            );
+        }
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: CHARLIE,
+                dust: U256::zero(),
+            }
+        );
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::BatchBurnTookPlace);
+
+        for (from, value) in [(BOB, U256::exp10(MAGIC - 1)), (CHARLIE, U256::exp10(MAGIC))] {
+            let (actor, event) = vft_admin_events.next().await.unwrap();
+            assert_eq!(actor, pid);
+            assert_eq!(
+                event,
+                VftAdminEvents::Burned {
+                    by: ALICE,
+                    from,
+                    value,
+                }
+            );
+        }
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::exp10(MAGIC - 1));
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_service.total_supply().await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::exp10(MAGIC - 1));
+    }
+}
+
+#[tokio::test]
+async fn force_transfer() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut vft_admin_service = program.vft_admin();
+    let vft_service = program.vft();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Charlie has BURNER_ROLE, but it's not enough for force transfer.
+    {
+        access_control_service
+            .grant_role(BURNER_ROLE, CHARLIE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = vft_admin_service
+            .force_transfer(BOB, CHARLIE, U256::one())
+            .with_actor_id(CHARLIE)
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    // # Test case #2.
+    // Noop on zero value or self transfer.
+    {
+        let res = vft_admin_service
+            .force_transfer(BOB, CHARLIE, U256::zero())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = vft_admin_service
+            .force_transfer(BOB, BOB, U256::one())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, false);
+    }
+
+    // # Test case #3.
+    // Alice moves Bob's funds to Dave without allowance.
+    {
+        let res = vft_admin_service
+            .force_transfer(BOB, DAVE, U256::exp10(MAGIC))
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::Transfer {
+                from: BOB,
+                to: DAVE,
+                value: U256::exp10(MAGIC),
+            }
+        );
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: BOB,
+                dust: U256::zero(),
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::exp10(MAGIC));
+    }
+}
+
+#[tokio::test]
+async fn minter_cap() {
+    let (program, _env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut vft_admin_service = program.vft_admin();
+    let vft_service = program.vft();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // # Test case #1.
+    // Bob is unbounded until capped.
+    {
+        let res = vft_admin_service.minter_remaining(BOB).await;
+        assert_ok!(res, None);
+
+        let res = vft_admin_service
+            .mint(CHARLIE, U256::exp10(MAGIC))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::MinterTookPlace);
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftAdminEvents::Minted {
+                by: BOB,
+                to: CHARLIE,
+                value: U256::exp10(MAGIC),
+            }
+        );
+    }
+
+    // # Test case #2.
+    // Only super admin sets caps.
+    {
+        let res = vft_admin_service
+            .set_minter_cap(BOB, U256::exp10(MAGIC), 1_000)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .set_minter_cap(BOB, U256::exp10(MAGIC), 0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+    }
+
+    // # Test case #3.
+    // Bob mints within his cap, but not beyond it.
+    {
+        let res = vft_admin_service
+            .set_minter_cap(BOB, U256::exp10(MAGIC), 1_000)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftAdminEvents::MinterCapSet {
+                minter: BOB,
+                amount_per_period: U256::exp10(MAGIC),
+                period_blocks: 1_000,
+            }
+        );
+
+        let res = vft_admin_service
+            .mint(CHARLIE, U256::exp10(MAGIC - 1))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let remaining = U256::exp10(MAGIC) - U256::exp10(MAGIC - 1);
+
+        let res = vft_admin_service.minter_remaining(BOB).await;
+        assert_ok!(res, Some(remaining));
+
+        let res = vft_admin_service
+            .mint(CHARLIE, remaining + 1)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service.minter_remaining(BOB).await;
+        assert_ok!(res, Some(remaining));
+
+        let res = vft_service.total_supply().await;
+        assert_ok!(res, U256::exp10(MAGIC) + U256::exp10(MAGIC - 1));
+    }
+
+    // # Test case #4.
+    // Removing the cap makes Bob unbounded again.
+    {
+        let res = vft_admin_service
+            .remove_minter_cap(BOB)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_admin_service
+            .remove_minter_cap(BOB)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = vft_admin_service.minter_cap(BOB).await;
+        assert_ok!(res, None);
+
+        let res = vft_admin_service
+            .mint(CHARLIE, U256::exp10(MAGIC))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+    }
+}
+
+#[tokio::test]
+async fn recurring_mint() {
+    let (program, env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let vft_service = program.vft();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Only super admin adds rules, which must mint something.
+    {
+        let res = vft_admin_service
+            .add_mint_rule(DAVE, U256::exp10(3), 10, 3)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .add_mint_rule(DAVE, U256::exp10(3), 0, 3)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = vft_admin_service
+            .add_mint_rule(DAVE, U256::exp10(3), 10, 0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+    }
+
+    // # Test case #2.
+    // Rules mint an interval after being added, with their schedule queried.
+    let rule = {
+        let res = vft_admin_service
+            .add_mint_rule(DAVE, U256::exp10(3), 10, 3)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 0);
+
+        let rule = vft_admin_service.mint_rule(0).await.unwrap().unwrap();
+        assert_eq!(
+            rule,
+            MintRule {
+                recipient: DAVE,
+                amount: U256::exp10(3),
+                interval_blocks: 10,
+                remaining_count: 3,
+                next_at: rule.next_at,
+            }
+        );
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftAdminEvents::MintRuleAdded {
+                id: 0,
+                rule: rule.clone()
+            }
+        );
+
+        let res = vft_admin_service.mint_schedule(0, 10).await;
+        assert_ok!(
+            res,
+            Some((
+                vec![rule.next_at, rule.next_at + 10, rule.next_at + 20],
+                U256::from(3_000)
+            ))
+        );
+
+        let res = vft_admin_service.mint_schedule(0, 1).await;
+        assert_ok!(res, Some((vec![rule.next_at], U256::from(3_000))));
+
+        let res = vft_admin_service.mint_schedule(1, 10).await;
+        assert_ok!(res, None);
+
+        let res = vft_admin_service.mint_rules().await;
+        assert_ok!(res, vec![(0, rule.clone())]);
+
+        rule
+    };
+
+    // # Test case #3.
+    // Anyone executes due mints lazily, catching up with missed ones.
+    {
+        let res = vft_admin_service.tick().with_actor_id(BOB).await;
+        assert_ok!(res, 0);
+
+        env.system().run_to_block(rule.next_at + 10);
+
+        let res = vft_admin_service.tick().with_actor_id(BOB).await;
+        assert_ok!(res, 2);
+
+        for remaining_count in [2, 1] {
+            let (actor, event) = vft_admin_events.next().await.unwrap();
+            assert_eq!(actor, pid);
+            assert_eq!(
+                event,
+                VftAdminEvents::RecurringMint {
+                    id: 0,
+                    to: DAVE,
+                    value: U256::exp10(3),
+                    remaining_count,
+                }
+            );
+        }
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(2_000));
+
+        let res = vft_admin_service.mint_schedule(0, 10).await;
+        assert_ok!(res, Some((vec![rule.next_at + 20], U256::exp10(3))));
+    }
+
+    // # Test case #4.
+    // Exhausted rules are removed.
+    {
+        env.system().run_to_block(rule.next_at + 20);
+
+        let res = vft_admin_service.tick().with_actor_id(CHARLIE).await;
+        assert_ok!(res, 1);
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(3_000));
+
+        let res = vft_admin_service.mint_rule(0).await;
+        assert_ok!(res, None);
+
+        let res = vft_admin_service.mint_rules().await;
+        assert_ok!(res, vec![]);
+    }
+
+    // # Test case #5.
+    // Super admin removes rules before they're exhausted.
+    {
+        let res = vft_admin_service
+            .add_mint_rule(DAVE, U256::exp10(3), 1, 100)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 1);
+
+        let res = vft_admin_service
+            .remove_mint_rule(1)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .remove_mint_rule(1)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_admin_service
+            .remove_mint_rule(1)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = vft_admin_service.tick().with_actor_id(BOB).await;
+        assert_ok!(res, 0);
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(3_000));
+    }
+}
+
+#[tokio::test]
+async fn minimum_balance() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_service = program.vft();
+    let vft_extension_service = program.vft_extension();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Only super admin sets the minimum balance.
+    {
+        let res = vft_admin_service
+            .set_minimum_balance(U256::exp10(3))
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .set_minimum_balance(U256::exp10(3))
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::MinimumBalanceChanged(U256::exp10(3)));
+
+        let res = vft_extension_service.minimum_balance().await;
+        assert_ok!(res, U256::exp10(3));
+    }
+
+    // # Test case #2.
+    // New balances below the minimum are rejected.
+    {
+        let res = vft_service
+            .transfer(CHARLIE, U256::exp10(3) - 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "balance is below the minimum");
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, U256::zero());
+    }
+
+    // # Test case #3.
+    // Dust left below the minimum is moved to the unused value.
+    {
+        let res = vft_service
+            .transfer(CHARLIE, U256::exp10(MAGIC) - 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, true);
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::Transfer {
+                from: BOB,
+                to: CHARLIE,
+                value: U256::exp10(MAGIC) - 1,
+            }
+        );
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::AccountReaped {
+                account: BOB,
+                dust: U256::one(),
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_extension_service.unused_value().await;
+        assert_ok!(res, U256::one());
+
+        let res = vft_service.total_supply().await;
+        assert_ok!(res, U256::exp10(MAGIC));
+    }
+
+    // # Test case #4.
+    // Dust is credited to the treasury, exempt from the minimum.
+    {
+        let policy = DustPolicy::TransferToTreasury(DAVE);
+
+        let res = vft_admin_service
+            .set_dust_policy(policy)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::DustPolicyChanged(policy));
+
+        let res = vft_extension_service.dust_policy().await;
+        assert_ok!(res, policy);
+
+        let res = vft_service
+            .transfer(BOB, U256::exp10(MAGIC) - 500)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(499));
+    }
+
+    // # Test case #5.
+    // Operations leaving dust fail when it's rejected.
+    {
+        let res = vft_admin_service
+            .set_dust_policy(DustPolicy::Reject)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = vft_service
+            .transfer(CHARLIE, U256::exp10(MAGIC) - 1_000)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "balance is below the minimum");
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) - 500);
+    }
+}
+
+#[tokio::test]
+async fn snapshot() {
+    // Leaves of holders, as committed by snapshots.
+    fn leaves(holders: &[(ActorId, U256)]) -> Vec<[u8; 32]> {
+        holders
+            .iter()
+            .map(|holder| merkle::leaf::<Keccak256>(&holder.encode()))
+            .collect()
+    }
+
+    let balances = vec![
+        (ALICE, U256::exp10(MAGIC)),
+        (BOB, U256::one()),
+        (CHARLIE, U256::from(2)),
+    ];
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_service = program.vft();
+    let mut vft_extension_service = program.vft_extension();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    let cursor = MapCursor {
+        shard: 0,
+        bucket: 0,
+    };
+
+    let res = vft_extension_service.latest_snapshot().await;
+    assert_ok!(res, None);
+
+    // # Test case #1.
+    // Snapshot is computed across messages.
+    {
+        let res = vft_extension_service.compute_snapshot(2).await;
+        assert_ok!(res, None);
+
+        let block = vft_extension_service
+            .pending_snapshot()
+            .await
+            .unwrap()
+            .expect("snapshot is pending");
+
+        let snapshot = vft_extension_service
+            .compute_snapshot(2)
+            .await
+            .unwrap()
+            .expect("snapshot is computed");
+
+        let (holders, _) = vft_extension_service
+            .balances_from(cursor, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            snapshot,
+            SnapshotRoot {
+                block,
+                root: merkle::root::<Keccak256>(leaves(&holders)),
+                holders: 3,
+            }
+        );
+
+        // Holders prove their balances against the root
+        let leaves = leaves(&holders);
+        let proof = merkle::proof::<Keccak256>(&leaves, 2).unwrap();
+        assert!(merkle::verify::<Keccak256>(
+            snapshot.root,
+            leaves[2],
+            &proof
+        ));
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::SnapshotTaken {
+                block,
+                root: snapshot.root,
+                holders: 3,
+            }
+        );
+
+        let res = vft_extension_service.latest_snapshot().await;
+        assert_ok!(res, Some(snapshot));
+
+        let res = vft_extension_service.pending_snapshot().await;
+        assert_ok!(res, None);
+    }
+
+    // # Test case #2.
+    // Balances changed while computing are hashed as of the block of the snapshot.
+    {
+        let (mut holders, _) = vft_extension_service
+            .balances_from(cursor, 10)
+            .await
+            .unwrap();
+
+        let res = vft_extension_service.compute_snapshot(1).await;
+        assert_ok!(res, None);
+
+        let block = vft_extension_service
+            .pending_snapshot()
+            .await
+            .unwrap()
+            .expect("snapshot is pending");
+
+        vft_service
+            .transfer(DAVE, U256::one())
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+        vft_events.next().await.unwrap(); // Consume Transfer event
+
+        let snapshot = vft_extension_service
+            .compute_snapshot(10)
+            .await
+            .unwrap()
+            .expect("snapshot is computed");
+        assert_eq!(snapshot.block, block);
+        assert_eq!(snapshot.holders, 3);
+
+        // Holders changed before visited follow the rest.
+        if holders[0].0 != ALICE {
+            let alice = holders.iter().position(|(account, _)| *account == ALICE);
+            let alice = holders.remove(alice.unwrap());
+
+            holders.push(alice);
+        }
+
+        assert_eq!(snapshot.root, merkle::root::<Keccak256>(leaves(&holders)));
+    }
+}
+
+#[tokio::test]
+async fn max_page_size() {
+    let allowances = vec![(ALICE, BOB, U256::exp10(MAGIC), BN)];
+    let balances = vec![(ALICE, U256::exp10(MAGIC)), (BOB, U256::one())];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let vft_extension_service = program.vft_extension();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    let res = vft_extension_service.max_page_size().await;
+    assert_ok!(res, 1000);
+
+    // # Test case #1.
+    // Only super admin sets the non-zero max page size.
+    {
+        let res = vft_admin_service
+            .set_max_page_size(1)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .set_max_page_size(0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = vft_admin_service
+            .set_max_page_size(1)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftAdminEvents::MaxPageSizeChanged(1));
+
+        let res = vft_extension_service.max_page_size().await;
+        assert_ok!(res, 1);
+    }
+
+    // # Test case #2.
+    // Enumeration exports reject larger pages.
+    {
+        let res = vft_extension_service.balances(0, 2).await;
+        assert_str_panic(res.unwrap_err(), "page too large: at most 1 items allowed");
+
+        let res = vft_extension_service
+            .balances_from(
+                MapCursor {
+                    shard: 0,
+                    bucket: 0,
+                },
+                2,
+            )
+            .await;
+        assert_str_panic(res.unwrap_err(), "page too large: at most 1 items allowed");
+
+        let res = vft_extension_service.allowances_of_owner(ALICE, 0, 2).await;
+        assert_str_panic(res.unwrap_err(), "page too large: at most 1 items allowed");
+
+        let res = vft_extension_service.balances(1, 1).await;
+        assert_eq!(res.unwrap().len(), 1);
+
+        let res = vft_extension_service.allowances(0, 1).await;
+        assert_ok!(res, vec![((ALICE, BOB), (U256::exp10(MAGIC), BN))]);
+    }
+}
+
+#[tokio::test]
+async fn viewer_gate() {
+    use awesome_sails::access_control::VIEWER_ROLE;
+
+    let allowances = vec![(ALICE, BOB, U256::exp10(MAGIC), BN)];
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, _env, _pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let vft_extension_service = program.vft_extension();
+
+    // # Test case #1.
+    // Enumeration exports are public until the gate is enabled.
+    {
+        let res = vft_extension_service
+            .balances(0, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, vec![(ALICE, U256::exp10(MAGIC))]);
+
+        let res = access_control_service
+            .set_viewer_gate(true)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+    }
+
+    // # Test case #2.
+    // Gated exports require the viewer role, point queries don't.
+    {
+        let res = vft_extension_service
+            .balances(0, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_extension_service
+            .allowances(0, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_extension_service
+            .balance_of(ALICE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, Some(U256::exp10(MAGIC)));
+
+        access_control_service
+            .grant_role(VIEWER_ROLE, CHARLIE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = vft_extension_service
+            .balances(0, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, vec![(ALICE, U256::exp10(MAGIC))]);
+
+        let res = vft_extension_service
+            .allowances(0, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, vec![((ALICE, BOB), (U256::exp10(MAGIC), BN))]);
+    }
+}
+
+#[tokio::test]
+async fn total_supply_at() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, env, _pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let vft_extension_service = program.vft_extension();
+
+    let bn_set = env.system().block_height();
+
+    // # Test case #1.
+    // History starts with the initial supply.
+    {
+        let res = vft_extension_service.total_supply_at(0).await;
+        assert_ok!(res, None);
+
+        let res = vft_extension_service.total_supply_at(bn_set).await;
+        assert_ok!(res, Some(U256::exp10(MAGIC)));
+    }
+
+    // # Test case #2.
+    // Minting and burning are checkpointed, keeping previous supply.
+    {
+        let res = vft_admin_service
+            .mint(CHARLIE, U256::exp10(MAGIC))
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let bn_mint = env.system().block_height();
+
+        let res = vft_admin_service
+            .burn(BOB, U256::exp10(MAGIC - 1))
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let bn_burn = env.system().block_height();
+
+        let res = vft_extension_service.total_supply_at(bn_set).await;
+        assert_ok!(res, Some(U256::exp10(MAGIC)));
+
+        let res = vft_extension_service.total_supply_at(bn_mint).await;
+        assert_ok!(res, Some(U256::exp10(MAGIC) * 2));
+
+        let res = vft_extension_service.total_supply_at(bn_burn).await;
+        assert_ok!(res, Some(U256::exp10(MAGIC) * 2 - U256::exp10(MAGIC - 1)));
+    }
+}
+
+#[tokio::test]
+async fn audit_log() {
+    let (program, _env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+
+    let res = vft_admin_service
+        .mint(BOB, U256::exp10(MAGIC))
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    // Failed calls are reverted along with their records
+    let res = vft_admin_service
+        .burn(BOB, U256::exp10(MAGIC))
+        .with_actor_id(CHARLIE)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_admin_service
+        .set_expiry_period(BN)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let log = vft_admin_service.audit_log(0, 10).await.unwrap();
+    let actions: Vec<_> = log
+        .iter()
+        .map(|(_, entry)| (entry.actor, entry.action.as_str()))
+        .collect();
+    assert_eq!(actions, vec![(ALICE, "mint"), (ALICE, "set_expiry_period")]);
+
+    // Pages are clamped to the max page size
+    let res = vft_admin_service
+        .set_max_page_size(1)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let log = vft_admin_service.audit_log(0, 10).await.unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].1.action, "mint");
+}
+
+#[tokio::test]
+async fn known_roles() {
+    use awesome_sails::access_control::{DEFAULT_ADMIN_ROLE, VIEWER_ROLE};
+
+    let (program, _env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let vft_admin_service = program.vft_admin();
+
+    let res = vft_admin_service.known_roles().await;
+    assert_ok!(
+        res,
+        vec![
+            ("DEFAULT_ADMIN_ROLE".into(), DEFAULT_ADMIN_ROLE),
+            ("MINTER_ROLE".into(), MINTER_ROLE),
+            ("BURNER_ROLE".into(), BURNER_ROLE),
+            ("PAUSER_ROLE".into(), PAUSER_ROLE),
+        ]
+    );
+
+    // Roles are derived by name, including ones of other services
+    let res = vft_admin_service.role_id("MINTER_ROLE".into()).await;
+    assert_ok!(res, MINTER_ROLE);
+
+    let res = vft_admin_service.role_id("VIEWER_ROLE".into()).await;
+    assert_ok!(res, VIEWER_ROLE);
+}
+
+#[tokio::test]
+async fn batch() {
+    let (program, _env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut vft_batch_service = program.vft_batch();
+    let vft_service = program.vft();
+
+    // # Test case #1.
+    // All calls are executed in order.
+    {
+        let res = vft_batch_service
+            .batch(vec![
+                Call::Mint {
+                    to: ALICE,
+                    value: U256::exp10(MAGIC),
+                },
+                Call::Transfer {
+                    to: BOB,
+                    value: U256::one(),
+                },
+                Call::Approve {
+                    spender: CHARLIE,
+                    value: U256::one(),
+                },
+            ])
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 3);
+
+        let res = vft_service.balance_of(ALICE).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::one());
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::one());
+
+        let res = vft_service.allowance(ALICE, CHARLIE).await;
+        assert_ok!(res, U256::one());
+    }
+
+    // # Test case #2.
+    // Failing call reverts the whole batch.
+    {
+        let res = vft_batch_service
+            .batch(vec![
+                Call::Transfer {
+                    to: BOB,
+                    value: U256::one(),
+                },
+                Call::Transfer {
+                    to: CHARLIE,
+                    value: U256::exp10(MAGIC),
+                },
+            ])
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "insufficient balance");
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::one());
+    }
+
+    // # Test case #3.
+    // Admin calls still require roles.
+    {
+        let res = vft_batch_service
+            .batch(vec![Call::Mint {
+                to: BOB,
+                value: U256::one(),
+            }])
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+    }
+}
+
+#[tokio::test]
+async fn counter() {
+    let (program, _env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut counter_service = program.counter();
+
+    let listener_binding = program.counter().listener();
+    let mut counter_events = listener_binding.listen().await.unwrap();
+
+    let key = String::from("nonce");
+
+    // # Test case #1.
+    // Anyone increments, with overflow checked.
+    {
+        let res = counter_service
+            .increment(key.clone(), 2)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 2);
+
+        let (actor, event) = counter_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            CounterEvents::Incremented {
+                key: key.clone(),
+                value: 2
+            }
+        );
+
+        let res = counter_service
+            .increment(key.clone(), u64::MAX)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "mathematical overflow");
+    }
+
+    // # Test case #2.
+    // Decrement, set and reset require the counter admin role.
+    {
+        let res = counter_service
+            .decrement(key.clone(), 1)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        access_control_service
+            .grant_role(COUNTER_ADMIN_ROLE, BOB)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = counter_service
+            .decrement(key.clone(), 3)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "mathematical underflow");
+
+        let res = counter_service
+            .decrement(key.clone(), 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 1);
+
+        let res = counter_service
+            .set(key.clone(), 10)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, true);
+
+        let res = counter_service.value(key.clone()).await;
+        assert_ok!(res, 10);
+
+        let res = counter_service.reset(key.clone()).with_actor_id(BOB).await;
+        assert_ok!(res, true);
+
+        let res = counter_service.reset(key.clone()).with_actor_id(BOB).await;
+        assert_ok!(res, false);
+
+        let res = counter_service.value(key).await;
+        assert_ok!(res, 0);
+    }
+}
+
+#[tokio::test]
+async fn scheduler() {
+    const VALUE: u128 = 10_000_000_000_000;
+
+    let (program, env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut scheduler_service = program.scheduler();
+
+    let listener_binding = program.scheduler().listener();
+    let mut scheduler_events = listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Scheduling requires the scheduler role.
+    {
+        let res = scheduler_service
+            .schedule(0, DAVE, vec![], 0)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        access_control_service
+            .grant_role(SCHEDULER_ROLE, CHARLIE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        // Value must cover the task value.
+        let res = scheduler_service
+            .schedule(0, DAVE, vec![], VALUE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        // Tasks to the program itself are scheduled by super admins only.
+        let res = scheduler_service
+            .schedule(0, pid, vec![], 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = scheduler_service
+            .schedule_self(0, vec![])
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        // Tasks to the program itself are delayed at least by the minimal delay,
+        // which is changed by the program itself only.
+        let res = scheduler_service.min_self_delay().await;
+        assert_ok!(res, DEFAULT_MIN_SELF_DELAY);
+
+        let res = scheduler_service
+            .schedule_self(env.system().block_height() + 1, vec![])
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = scheduler_service
+            .set_min_self_delay(0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+    }
+
+    // # Test case #2.
+    // Due tasks are executed by anyone.
+    {
+        let res = scheduler_service
+            .schedule(0, DAVE, b"ping".to_vec(), VALUE)
+            .with_value(2 * VALUE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 0);
+
+        let (actor, event) = scheduler_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SchedulerEvents::Scheduled {
+                id: 0,
+                execute_at: 0,
+                target: DAVE
+            }
+        );
+
+        let res = scheduler_service.due_tasks(10).await;
+        assert_ok!(res, vec![0]);
+
+        let res = scheduler_service.execute_due(10).with_actor_id(BOB).await;
+        assert_ok!(res, 1);
+
+        let (actor, event) = scheduler_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SchedulerEvents::Executed {
+                id: 0,
+                target: DAVE
+            }
+        );
+
+        let res = scheduler_service.due_tasks(10).await;
+        assert_ok!(res, vec![]);
+    }
+
+    // # Test case #3.
+    // Future tasks aren't executed and are cancelled by their owner only.
+    {
+        let res = scheduler_service
+            .schedule(u32::MAX, DAVE, vec![], 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 1);
+
+        let res = scheduler_service.execute_due(10).with_actor_id(BOB).await;
+        assert_ok!(res, 0);
+
+        let res = scheduler_service.cancel(1).with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = scheduler_service.cancel(1).with_actor_id(CHARLIE).await;
+        assert_ok!(res, true);
+
+        let res = scheduler_service.task(1).await;
+        assert_ok!(res, None);
+    }
+
+    // # Test case #4.
+    // Keepers are paid the keeper bounty within its budget.
+    {
+        let res = scheduler_service
+            .set_keeper_bounty(VALUE, 1, VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = scheduler_service
+            .set_keeper_bounty(VALUE, 1, VALUE)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = scheduler_service
+            .fund_keeper_bounty()
+            .with_value(2 * VALUE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = scheduler_service
+            .schedule(0, DAVE, vec![], 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 2);
+
+        let res = scheduler_service.execute_due(10).with_actor_id(BOB).await;
+        assert_ok!(res, 1);
+
+        let bounty = scheduler_service.keeper_bounty().await.unwrap();
+        assert_eq!(bounty.budget, VALUE);
+    }
+}
+
+#[tokio::test]
+async fn inflation() {
+    let (program, _env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut inflation_service = program.vft_inflation();
+    let vft_service = program.vft();
+
+    let listener_binding = program.vft_inflation().listener();
+    let mut inflation_events = listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Emission is disabled by default and set by super admin only.
+    {
+        let res = inflation_service.next_emission().await;
+        assert_ok!(res, None);
+
+        let res = inflation_service.mint_emission().with_actor_id(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = inflation_service
+            .set_schedule(DAVE, U256::exp10(3), 3, 5_000, None)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = inflation_service
+            .set_schedule(DAVE, U256::exp10(3), 3, 10_001, None)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+    }
+
+    // # Test case #2.
+    // Anyone mints elapsed eras, with emission decaying every era.
+    {
+        let res = inflation_service
+            .set_schedule(DAVE, U256::exp10(3), 3, 5_000, None)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let schedule = inflation_service.schedule().await.unwrap();
+
+        let (actor, event) = inflation_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftInflationEvents::ScheduleSet(schedule.clone()));
+
+        let res = inflation_service.next_emission().await;
+        assert_ok!(res, Some((schedule.start_block + 3, U256::exp10(3))));
+
+        let minted = loop {
+            let minted = inflation_service
+                .mint_emission()
+                .with_actor_id(BOB)
+                .await
+                .unwrap();
+
+            if !minted.is_zero() {
+                break minted;
+            }
+        };
+        assert_eq!(minted, U256::exp10(3));
+
+        let (actor, event) = inflation_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftInflationEvents::EmissionMinted {
+                era: 0,
+                to: DAVE,
+                value: U256::exp10(3),
+            }
+        );
+
+        let res = inflation_service.next_emission().await;
+        assert_ok!(res, Some((schedule.start_block + 6, U256::from(500))));
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::exp10(3));
+    }
+
+    // # Test case #3.
+    // Emission stops at the end block.
+    {
+        let res = inflation_service
+            .set_schedule(DAVE, U256::exp10(3), 3, 0, Some(0))
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = inflation_service.next_emission().await;
+        assert_ok!(res, None);
+    }
+}
+
+#[tokio::test]
+async fn treasury() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut treasury_service = program.treasury();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.treasury().listener();
+    let mut treasury_events = listener_binding.listen().await.unwrap();
+
+    // Bob funds the treasury with VFTs.
+    treasury_service
+        .deposit_vft(U256::exp10(4))
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let (actor, event) = treasury_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        TreasuryEvents::Deposited {
+            from: BOB,
+            asset: Asset::Vft,
+            amount: U256::exp10(4),
+        }
+    );
+
+    // VFTs of the program not deposited to the treasury aren't its funds.
+    vft_service
+        .transfer(pid, U256::exp10(MAGIC - 1))
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let res = treasury_service.funds(Asset::Vft).await;
+    assert_ok!(res, U256::exp10(4));
+
+    for (role, account) in [(SPENDER_ROLE, BOB), (TREASURER_ROLE, CHARLIE)] {
+        access_control_service
+            .grant_role(role, account)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+    }
+
+    // # Test case #1.
+    // Only spenders propose.
+    {
+        let res = treasury_service
+            .propose_spend(DAVE, Asset::Vft, U256::exp10(3))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        let res = treasury_service
+            .propose_spend(DAVE, Asset::Vft, U256::exp10(3))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 0);
+
+        let (actor, event) = treasury_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            TreasuryEvents::SpendProposed {
+                id: 0,
+                proposer: BOB,
+                beneficiary: DAVE,
+                asset: Asset::Vft,
+                amount: U256::exp10(3),
+            }
+        );
+    }
+
+    // # Test case #2.
+    // Treasurer approves the spend, paying it out once.
+    {
+        let res = treasury_service.approve_spend(0).with_actor_id(BOB).await;
+        assert!(res.is_err());
+
+        let res = treasury_service
+            .approve_spend(0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = treasury_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            TreasuryEvents::SpendExecuted {
+                id: 0,
+                treasurer: CHARLIE,
+            }
+        );
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::exp10(3));
+
+        let res = treasury_service
+            .approve_spend(0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Proposal 0 is not pending");
+
+        let proposal = treasury_service.proposal(0).await.unwrap().unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+    }
+
+    // # Test case #3.
+    // Spends are capped by the budget of the asset, while rejected ones aren't paid.
+    {
+        let res = treasury_service
+            .set_budget(Asset::Vft, U256::exp10(3), 1_000)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        for _ in 0..2 {
+            treasury_service
+                .propose_spend(DAVE, Asset::Vft, U256::exp10(3) + 1)
+                .with_actor_id(BOB)
+                .await
+                .unwrap();
+        }
+
+        let res = treasury_service
+            .approve_spend(1)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        let res = treasury_service
+            .reject_spend(2)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = treasury_service.budget_remaining(Asset::Vft).await;
+        assert_ok!(res, Some(U256::exp10(3)));
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::exp10(3));
+
+        let res = treasury_service.proposals(1, 10).await.unwrap();
+        let statuses: Vec<_> = res.into_iter().map(|(id, p)| (id, p.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![(1, ProposalStatus::Pending), (2, ProposalStatus::Rejected)]
+        );
+
+        let res = treasury_service.proposals(0, 1_001).await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "page too large: at most 1000 items allowed",
+        );
+    }
+
+    // # Test case #4.
+    // Spends are capped by funds of the treasury.
+    {
+        let res = treasury_service
+            .remove_budget(Asset::Vft)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        treasury_service
+            .propose_spend(DAVE, Asset::Vft, U256::exp10(4))
+            .with_actor_id(BOB)
+            .await
+            .unwrap();
+
+        let res = treasury_service
+            .approve_spend(3)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            &format!(
+                "Insufficient funds: {} of Vft held by the treasury",
+                U256::exp10(4) - U256::exp10(3)
+            ),
+        );
+
+        let res = treasury_service.funds(Asset::Vft).await;
+        assert_ok!(res, U256::exp10(4) - U256::exp10(3));
+    }
+}
+
+#[tokio::test]
+async fn bonding_curve() {
+    const AMOUNT: u128 = 10_000_000_000_000;
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut curve_service = program.vft_bonding_curve();
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.vft_bonding_curve().listener();
+    let mut curve_events = listener_binding.listen().await.unwrap();
+
+    // Price of 1 growing by 2 every `PRICE_SCALE` tokens.
+    let curve = Curve::Linear {
+        base_price: PRICE_SCALE.into(),
+        slope: (2 * PRICE_SCALE).into(),
+    };
+    let cost = AMOUNT + AMOUNT * AMOUNT / PRICE_SCALE as u128;
+
+    // # Test case #1.
+    // Curve is set by the super admin.
+    {
+        let res = curve_service
+            .buy(AMOUNT.into())
+            .with_value(cost)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Curve is not set");
+
+        let res = curve_service.set_curve(curve).with_actor_id(BOB).await;
+        assert!(res.is_err());
+
+        let res = curve_service.set_curve(curve).with_actor_id(ALICE).await;
+        assert_ok!(res, ());
+
+        let (actor, event) = curve_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, VftBondingCurveEvents::CurveSet(curve));
+
+        let res = curve_service.spot_price().await;
+        assert_ok!(res, Some(PRICE_SCALE.into()));
+    }
+
+    // # Test case #2.
+    // Tokens are bought for their price along the curve.
+    {
+        let res = curve_service.quote_buy(AMOUNT.into()).await;
+        assert_ok!(res, Some(cost));
+
+        let res = curve_service
+            .buy(AMOUNT.into())
+            .with_value(cost - 1)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = curve_service
+            .buy(AMOUNT.into())
+            .with_value(cost + VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = curve_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftBondingCurveEvents::Bought {
+                buyer: BOB,
+                amount: AMOUNT.into(),
+                cost,
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(AMOUNT));
+
+        let res = curve_service.reserve().await;
+        assert_ok!(res, cost);
+
+        let res = curve_service.supply().await;
+        assert_ok!(res, U256::from(AMOUNT));
+
+        let res = curve_service.issued_to(BOB).await;
+        assert_ok!(res, U256::from(AMOUNT));
+
+        let res = curve_service.set_curve(curve).with_actor_id(ALICE).await;
+        assert_str_panic(res.unwrap_err(), "Curve is in use");
+    }
+
+    // # Test case #3.
+    // Only tokens bought through the curve are sold back.
+    {
+        let res = vft_admin_service
+            .mint(CHARLIE, AMOUNT.into())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = curve_service
+            .sell(AMOUNT.into(), 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Sell exceeds issued: at most 0 may be sold back",
+        );
+
+        let res = vft_admin_service
+            .mint(BOB, AMOUNT.into())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = curve_service
+            .sell((AMOUNT + 1).into(), 0)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            &format!("Sell exceeds issued: at most {AMOUNT} may be sold back"),
+        );
+    }
+
+    // # Test case #4.
+    // Tokens are sold back for a refund from the reserve.
+    {
+        let res = curve_service.quote_sell(AMOUNT.into()).await;
+        assert_ok!(res, Some(cost));
+
+        let res = curve_service
+            .sell(AMOUNT.into(), cost + 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            &format!("Slippage exceeded: refund is {cost}"),
+        );
+
+        let res = curve_service
+            .sell(AMOUNT.into(), cost)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, cost);
+
+        let (actor, event) = curve_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftBondingCurveEvents::Sold {
+                seller: BOB,
+                amount: AMOUNT.into(),
+                refund: cost,
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(AMOUNT));
+
+        let res = curve_service.issued_to(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = curve_service.reserve().await;
+        assert_ok!(res, 0);
+    }
+}
+
+#[tokio::test]
+async fn dutch_auction() {
+    const AMOUNT: u128 = 100;
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let allowances = Default::default();
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut auction_service = program.dutch_auction();
+    let vft_service = program.vft();
+
+    let listener_binding = program.dutch_auction().listener();
+    let mut auction_events = listener_binding.listen().await.unwrap();
+
+    let start_block = env.system().block_height() + 10;
+
+    // Price decreasing from 2 to 1 units of value per token.
+    let params = AuctionParams {
+        amount: AMOUNT.into(),
+        start_price: U256::from(2 * VALUE) * AUCTION_PRICE_SCALE,
+        end_price: U256::from(VALUE) * AUCTION_PRICE_SCALE,
+        start_block,
+        end_block: start_block + 100,
+        cap_per_account: 50.into(),
+        unsold: UnsoldPolicy::ReturnToSeller,
+    };
+
+    // # Test case #1.
+    // Auction is started by the super admin, escrowing the tokens for sale.
+    {
+        let res = auction_service
+            .start(params.clone())
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = auction_service
+            .start(AuctionParams {
+                end_price: params.start_price + 1,
+                ..params.clone()
+            })
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = auction_service
+            .start(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = auction_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            DutchAuctionEvents::Started {
+                seller: ALICE,
+                params: params.clone(),
+            }
+        );
+
+        let res = auction_service
+            .start(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Auction is active");
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(AMOUNT));
+
+        // Nothing is sold before the start block.
+        let res = auction_service.current_price().await;
+        assert_ok!(res, None);
+
+        let res = auction_service
+            .buy(10.into())
+            .with_value(20 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Auction is not active");
+    }
+
+    // # Test case #2.
+    // Tokens are delivered instantly at the decreasing price, within caps.
+    let proceeds = {
+        env.system().run_to_block(start_block + 10);
+
+        let price = auction_service.current_price().await.unwrap().unwrap();
+        assert!(price < params.start_price && price > params.end_price);
+
+        let res = auction_service
+            .buy(10.into())
+            .with_value(VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message value");
+
+        let bob_cost = auction_service
+            .buy(10.into())
+            .with_value(20 * VALUE)
+            .with_actor_id(BOB)
+            .await
+            .unwrap();
+        assert!(bob_cost <= 10 * VALUE * 2 && bob_cost > 10 * VALUE);
+
+        let (actor, event) = auction_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            DutchAuctionEvents::Bought {
+                buyer: BOB,
+                amount: 10.into(),
+                cost: bob_cost,
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(10));
+
+        let res = auction_service
+            .buy(41.into())
+            .with_value(100 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Purchase cap exceeded: at most 40 may be bought",
+        );
+
+        env.system().run_to_block(start_block + 50);
+
+        let charlie_cost = auction_service
+            .buy(50.into())
+            .with_value(100 * VALUE)
+            .with_actor_id(CHARLIE)
+            .await
+            .unwrap();
+
+        // Later purchases are cheaper.
+        assert!(charlie_cost < 5 * bob_cost);
+
+        let _ = auction_events.next().await.unwrap();
+
+        let res = auction_service.purchased(CHARLIE).await;
+        assert_ok!(res, U256::from(50));
+
+        let res = auction_service.auction().await;
+        assert_ok!(
+            res,
+            Some(Auction {
+                seller: ALICE,
+                params: params.clone(),
+                sold: 60.into(),
+                proceeds: bob_cost + charlie_cost,
+            })
+        );
+
+        bob_cost + charlie_cost
+    };
+
+    // # Test case #3.
+    // Once ended, anyone finalizes the auction, returning unsold tokens.
+    {
+        let res = auction_service.finalize().with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Auction is active");
+
+        env.system().run_to_block(params.end_block);
+
+        let res = auction_service
+            .buy(10.into())
+            .with_value(20 * VALUE)
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Auction is not active");
+
+        let res = auction_service.finalize().with_actor_id(DAVE).await;
+        assert_ok!(res, ());
+
+        let (actor, event) = auction_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            DutchAuctionEvents::Finalized {
+                sold: 60.into(),
+                unsold: 40.into(),
+                proceeds,
+            }
+        );
+
+        let res = auction_service.auction().await;
+        assert_ok!(res, None);
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_service.balance_of(ALICE).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(60));
+
+        let res = auction_service.finalize().with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Auction is not active");
+    }
+
+    // # Test case #4.
+    // Unsold tokens may be burnt instead.
+    {
+        let start_block = env.system().block_height() + 1;
+
+        let params = AuctionParams {
+            start_block,
+            end_block: start_block + 5,
+            unsold: UnsoldPolicy::Burn,
+            ..params
+        };
+
+        let res = auction_service
+            .start(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        env.system().run_to_block(params.end_block);
+
+        let res = auction_service.finalize().with_actor_id(DAVE).await;
+        assert_ok!(res, ());
+
+        let res = vft_service.total_supply().await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(AMOUNT));
+    }
+}
+
+#[tokio::test]
+async fn order_book() {
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let allowances = Default::default();
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut order_book_service = program.order_book();
+    let vft_service = program.vft();
+
+    let listener_binding = program.order_book().listener();
+    let mut order_book_events = listener_binding.listen().await.unwrap();
+
+    // One unit of value per token.
+    let price = U256::from(VALUE) * ORDER_PRICE_SCALE;
+
+    let program_balance = env.system().balance_of(pid);
+
+    // # Test case #1.
+    // Orders are placed once the super admin allocates storage for them.
+    {
+        let res = order_book_service
+            .place(Side::Sell, 100.into(), price, 50)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "capacity overflow");
+
+        let res = order_book_service
+            .allocate_next_shard()
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = order_book_service
+            .allocate_next_shard()
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = order_book_service
+            .set_fee_config(FeeConfig {
+                fee_bps: 100,
+                treasury: ActorId::zero(),
+            })
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let fee_config = FeeConfig {
+            fee_bps: 100,
+            treasury: DAVE,
+        };
+
+        let res = order_book_service
+            .set_fee_config(fee_config.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = order_book_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, OrderBookEvents::FeeConfigChanged(fee_config.clone()));
+
+        let res = order_book_service.fee_config().await;
+        assert_ok!(res, fee_config);
+    }
+
+    // # Test case #2.
+    // Sell orders escrow tokens, filled partially by takers paying value.
+    let expires_at = {
+        let res = order_book_service
+            .place(Side::Sell, 100.into(), price, 50)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 0);
+
+        let expires_at = env.system().block_height() + 50;
+
+        let order = Order {
+            maker: ALICE,
+            side: Side::Sell,
+            price,
+            remaining: 100.into(),
+            escrow: 0,
+            expires_at,
+        };
+
+        let (actor, event) = order_book_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            OrderBookEvents::Placed {
+                id: 0,
+                order: order.clone(),
+            }
+        );
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(100));
+
+        let res = order_book_service
+            .fill(0, 30.into())
+            .with_value(29 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message value");
+
+        let res = order_book_service
+            .fill(0, 101.into())
+            .with_value(200 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Fill exceeds order: at most 100 may be filled",
+        );
+
+        let res = order_book_service
+            .fill(0, 30.into())
+            .with_value(40 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 30 * VALUE);
+
+        let (actor, event) = order_book_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            OrderBookEvents::Filled {
+                id: 0,
+                taker: BOB,
+                amount: 30.into(),
+                value: 30 * VALUE,
+                fee: 30 * VALUE / 100,
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(30));
+
+        let res = order_book_service.order(0).await;
+        assert_ok!(
+            res,
+            Some(Order {
+                remaining: 70.into(),
+                ..order
+            })
+        );
+
+        // Value of the fill is paid out to the maker and the treasury.
+        assert_eq!(env.system().balance_of(pid), program_balance);
+
+        expires_at
+    };
+
+    // # Test case #3.
+    // Buy orders escrow value, filled by takers delivering tokens.
+    {
+        let half_price = price / 2;
+
+        let res = order_book_service
+            .place(Side::Buy, 50.into(), half_price, 100)
+            .with_value(25 * VALUE - 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message value");
+
+        let res = order_book_service
+            .place(Side::Buy, 50.into(), half_price, 100)
+            .with_value(30 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 1);
+
+        let _ = order_book_events.next().await.unwrap();
+
+        assert_eq!(env.system().balance_of(pid), program_balance + 25 * VALUE);
+
+        let res = order_book_service
+            .fill(1, 20.into())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 10 * VALUE);
+
+        let _ = order_book_events.next().await.unwrap();
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(50));
+
+        let res = order_book_service.order(1).await;
+        let order = res.unwrap().unwrap();
+        assert_eq!(order.remaining, U256::from(30));
+        assert_eq!(order.escrow, 15 * VALUE);
+
+        let res = order_book_service.orders_of(BOB, 0, 10).await;
+        assert_ok!(res, vec![(1, order)]);
+
+        let res = order_book_service.orders(0, 10).await;
+        assert_eq!(res.unwrap().len(), 2);
+
+        let res = order_book_service.orders(1, 10).await;
+        assert_eq!(res.unwrap().len(), 1);
+    }
+
+    // # Test case #4.
+    // Makers cancel their orders, while expired ones are cancelled by anyone.
+    {
+        let res = order_book_service.cancel(0).with_actor_id(CHARLIE).await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        env.system().run_to_block(expires_at);
+
+        let res = order_book_service
+            .fill(0, 10.into())
+            .with_value(10 * VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Order 0 is expired");
+
+        let res = order_book_service.cancel(0).with_actor_id(CHARLIE).await;
+        assert_ok!(res, ());
+
+        let (actor, event) = order_book_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, OrderBookEvents::Cancelled { id: 0, by: CHARLIE });
+
+        let res = vft_service.balance_of(ALICE).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(50));
+
+        let res = order_book_service.cancel(1).with_actor_id(BOB).await;
+        assert_ok!(res, ());
+
+        let _ = order_book_events.next().await.unwrap();
+
+        let res = order_book_service.cancel(1).with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Order 1 not found");
+
+        let res = order_book_service.orders(0, 10).await;
+        assert_ok!(res, vec![]);
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::zero());
+
+        assert_eq!(env.system().balance_of(pid), program_balance);
+    }
+}
+
+#[tokio::test]
+async fn order_book_escrow_below_minimum() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut order_book_service = program.order_book();
+    let mut vft_admin_service = program.vft_admin();
+    let vft_service = program.vft();
+
+    let price = U256::from(1_000_000_000_000u128) * ORDER_PRICE_SCALE;
+
+    order_book_service
+        .allocate_next_shard()
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    vft_admin_service
+        .set_minimum_balance(1_000.into())
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // The program escrows sells below the minimum.
+    for id in 0..2 {
+        let res = order_book_service
+            .place(Side::Sell, 100.into(), price, 50)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, id);
+    }
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, U256::from(200));
+
+    // Releasing escrow doesn't reap the rest of it.
+    let res = order_book_service.cancel(0).with_actor_id(ALICE).await;
+    assert!(res.is_ok());
+
+    let res = vft_service.balance_of(pid).await;
+    assert_ok!(res, U256::from(100));
+
+    let res = vft_service.balance_of(ALICE).await;
+    assert_ok!(res, U256::exp10(MAGIC) - 100);
+}
+
+#[tokio::test]
+async fn order_book_settlement() {
+    use awesome_sails::order_book::FILL_TAG;
+    use schnorrkel::{ExpansionMode, Keypair, MiniSecretKey};
+
+    const VALUE: u128 = 1_000_000_000_000;
+
+    // Makers sign fills by their sr25519 keys.
+    let keypair = |seed: u8| {
+        MiniSecretKey::from_bytes(&[seed; 32])
+            .unwrap()
+            .expand_to_keypair(ExpansionMode::Ed25519)
+    };
+
+    let seller_key = keypair(1);
+    let buyer_key = keypair(2);
+
+    let seller = ActorId::from(seller_key.public.to_bytes());
+    let buyer = ActorId::from(buyer_key.public.to_bytes());
+
+    let allowances = Default::default();
+    let balances = vec![(seller, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    env.system().mint_to(seller, BALANCE);
+    env.system().mint_to(buyer, BALANCE);
+
+    let mut access_control_service = program.access_control();
+    let mut order_book_service = program.order_book();
+    let vft_service = program.vft();
+
+    let listener_binding = program.order_book().listener();
+    let mut order_book_events = listener_binding.listen().await.unwrap();
+
+    let price = |units_x4: u128| U256::from(units_x4 * VALUE / 4) * ORDER_PRICE_SCALE;
+
+    let sign = |key: &Keypair, fill: &Fill, nonce: u64| {
+        let maker = ActorId::from(key.public.to_bytes());
+        let message = (FILL_TAG, pid, maker, fill, nonce).encode();
+
+        key.sign_simple(b"substrate", &message).to_bytes()
+    };
+
+    // Fills signed by both makers with nonces following the given ones.
+    let signed = |fills: &[Fill], sell_nonce: u64, buy_nonce: u64| {
+        fills
+            .iter()
+            .zip(0..)
+            .map(|(fill, i)| SignedFill {
+                fill: fill.clone(),
+                sell_signature: sign(&seller_key, fill, sell_nonce + i),
+                buy_signature: sign(&buyer_key, fill, buy_nonce + i),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    order_book_service
+        .allocate_next_shard()
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    order_book_service
+        .set_fee_config(FeeConfig {
+            fee_bps: 100,
+            treasury: DAVE,
+        })
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    access_control_service
+        .grant_role(SETTLER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    let program_balance = env.system().balance_of(pid);
+
+    // Sell orders at 1 and 2 units of value per token.
+    for (amount, price) in [(100, price(4)), (50, price(8))] {
+        order_book_service
+            .place(Side::Sell, amount.into(), price, 100)
+            .with_actor_id(seller)
+            .await
+            .unwrap();
+    }
+
+    // Buy order at 1.5 units of value per token.
+    let res = order_book_service
+        .place(Side::Buy, 80.into(), price(6), 100)
+        .with_value(120 * VALUE)
+        .with_actor_id(buyer)
+        .await;
+    assert_ok!(res, 2);
+
+    for _ in 0..3 {
+        let _ = order_book_events.next().await.unwrap();
+    }
+
+    // # Test case #1.
+    // Batches are settled by settlers only and fail as a whole.
+    {
+        let fills = vec![Fill {
+            sell_id: 0,
+            buy_id: 2,
+            amount: 60.into(),
+            price: price(5),
+        }];
+
+        let res = order_book_service
+            .settle_batch(signed(&fills, 0, 0))
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = order_book_service
+            .settle_batch(vec![])
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = order_book_service
+            .settle_batch(signed(
+                &[Fill {
+                    sell_id: 0,
+                    buy_id: 1,
+                    ..fills[0].clone()
+                }],
+                0,
+                0,
+            ))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = order_book_service
+            .settle_batch(signed(
+                &[
+                    fills.clone(),
+                    vec![Fill {
+                        sell_id: 1,
+                        buy_id: 2,
+                        amount: 20.into(),
+                        price: price(6),
+                    }],
+                ]
+                .concat(),
+                0,
+                0,
+            ))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Orders 1 and 2 don't cross");
+
+        let res = order_book_service
+            .settle_batch(signed(
+                &[
+                    fills.clone(),
+                    vec![Fill {
+                        amount: 41.into(),
+                        ..fills[0].clone()
+                    }],
+                ]
+                .concat(),
+                0,
+                0,
+            ))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Fill exceeds order: at most 40 may be filled",
+        );
+
+        let res = vft_service.balance_of(buyer).await;
+        assert_ok!(res, U256::zero());
+
+        let res = order_book_service.order(0).await;
+        assert_eq!(res.unwrap().unwrap().remaining, U256::from(100));
+    }
+
+    // # Test case #2.
+    // Fills must be signed by both makers with their next nonces.
+    {
+        let fill = Fill {
+            sell_id: 0,
+            buy_id: 2,
+            amount: 60.into(),
+            price: price(5),
+        };
+
+        let res = order_book_service.nonce(seller).await;
+        assert_ok!(res, 0);
+
+        let res = order_book_service.fill_message(seller, fill.clone()).await;
+        assert_ok!(res, (FILL_TAG, pid, seller, &fill, 0u64).encode());
+
+        // Signed on other terms.
+        let res = order_book_service
+            .settle_batch(vec![SignedFill {
+                fill: Fill {
+                    price: price(4),
+                    ..fill.clone()
+                },
+                ..signed(&[fill.clone()], 0, 0)[0].clone()
+            }])
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+
+        // Signed by the seller only.
+        let res = order_book_service
+            .settle_batch(vec![SignedFill {
+                buy_signature: sign(&seller_key, &fill, 0),
+                ..signed(&[fill.clone()], 0, 0)[0].clone()
+            }])
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+
+        // Signed with a future nonce.
+        let res = order_book_service
+            .settle_batch(signed(&[fill.clone()], 1, 0))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+    }
+
+    // # Test case #3.
+    // Fills trade escrowed tokens for escrowed value at their prices,
+    // returning the rest of escrow of filled orders.
+    {
+        let fills = vec![
+            Fill {
+                sell_id: 0,
+                buy_id: 2,
+                amount: 60.into(),
+                price: price(5),
+            },
+            Fill {
+                sell_id: 0,
+                buy_id: 2,
+                amount: 20.into(),
+                price: price(4),
+            },
+        ];
+
+        let res = order_book_service
+            .settle_batch(signed(&fills, 0, 0))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 2);
+
+        for (fill, value) in fills.into_iter().zip([75 * VALUE, 20 * VALUE]) {
+            let (actor, event) = order_book_events.next().await.unwrap();
+            assert_eq!(actor, pid);
+            assert_eq!(
+                event,
+                OrderBookEvents::Settled {
+                    fill,
+                    value,
+                    fee: value / 100,
+                }
+            );
+        }
+
+        let res = vft_service.balance_of(buyer).await;
+        assert_ok!(res, U256::from(80));
+
+        let res = order_book_service.order(0).await;
+        assert_eq!(res.unwrap().unwrap().remaining, U256::from(20));
+
+        let res = order_book_service.order(2).await;
+        assert_ok!(res, None);
+
+        // Signatures are used only once.
+        let res = order_book_service.nonce(seller).await;
+        assert_ok!(res, 2);
+
+        let res = order_book_service.nonce(buyer).await;
+        assert_ok!(res, 2);
+
+        // Value is paid out to the seller and the treasury,
+        // with the rest of the buy order escrow returned.
+        assert_eq!(env.system().balance_of(pid), program_balance);
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(70));
+    }
+}
+
+#[tokio::test]
+async fn raffle() {
+    const PRICE: u128 = 1_000;
+
+    let allowances = Default::default();
+    const BOND: u128 = 500;
+
+    let balances = vec![
+        (BOB, U256::exp10(MAGIC)),
+        (CHARLIE, U256::exp10(MAGIC)),
+        (DAVE, U256::exp10(MAGIC)),
+    ];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut raffle_service = program.raffle();
+    let vft_service = program.vft();
+
+    let listener_binding = program.raffle().listener();
+    let mut raffle_events = listener_binding.listen().await.unwrap();
+
+    let commitment = commit_reveal::hash(b"seed");
+
+    // Fee of 10% goes to Alice.
+    let params = RoundParams {
+        ticket_price: PRICE.into(),
+        fee_bps: 1_000,
+        fee_recipient: ALICE,
+        end_block: env.system().block_height() + 20,
+        bond: BOND.into(),
+    };
+
+    // # Test case #1.
+    // Rounds are opened by operators, committing to the seed and escrowing the bond.
+    {
+        let res = raffle_service
+            .open(params.clone(), commitment)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        access_control_service
+            .grant_role(OPERATOR_ROLE, CHARLIE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = raffle_service
+            .open(
+                RoundParams {
+                    fee_bps: 10_001,
+                    ..params.clone()
+                },
+                commitment,
+            )
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = raffle_service
+            .open(params.clone(), commitment)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 0);
+
+        let (actor, event) = raffle_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            RaffleEvents::RoundOpened {
+                round: 0,
+                params: params.clone(),
+                commitment,
+            }
+        );
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(BOND));
+
+        let res = raffle_service
+            .open(params.clone(), commitment)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Round is active");
+    }
+
+    // # Test case #2.
+    // Tickets are bought with VFTs until the end block.
+    {
+        let res = raffle_service.buy_tickets(3).with_actor_id(BOB).await;
+        assert_ok!(res, 3);
+
+        let (actor, event) = raffle_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            RaffleEvents::TicketsBought {
+                round: 0,
+                buyer: BOB,
+                count: 3,
+            }
+        );
+
+        let res = raffle_service.buy_tickets(1).with_actor_id(DAVE).await;
+        assert_ok!(res, 1);
+
+        let _ = raffle_events.next().await.unwrap();
+
+        let res = raffle_service.tickets_of(0, BOB).await;
+        assert_ok!(res, 3);
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(4 * PRICE + BOND));
+
+        let res = raffle_service.close().with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Randomness is not ready");
+
+        let res = raffle_service
+            .draw(b"seed".to_vec())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Round is not closed");
+    }
+
+    // # Test case #3.
+    // Once ended, anyone closes the round, and the operator reveals the seed,
+    // paying the pot to the winner.
+    {
+        env.system().run_to_block(params.end_block);
+
+        let res = raffle_service.buy_tickets(1).with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Round is not open");
+
+        let res = raffle_service.close().with_actor_id(DAVE).await;
+        assert_ok!(res, ());
+
+        let (actor, event) = raffle_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert!(matches!(event, RaffleEvents::Closed { round: 0, .. }));
+
+        let res = raffle_service.close().with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Round is not open");
+
+        let res = raffle_service
+            .draw(b"guess".to_vec())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "revealed value doesn't match the commitment",
+        );
+
+        let res = raffle_service
+            .draw(b"seed".to_vec())
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let winner = raffle_service
+            .draw(b"seed".to_vec())
+            .with_actor_id(CHARLIE)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(winner == BOB || winner == DAVE);
+
+        let (actor, event) = raffle_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            RaffleEvents::Drawn {
+                round: 0,
+                seed: b"seed".to_vec(),
+                winner: Some(winner),
+                prize: U256::from(3_600),
+                fee: U256::from(400),
+            }
+        );
+
+        let res = raffle_service.round().await.unwrap().unwrap();
+        assert_eq!(
+            res.status,
+            RoundStatus::Drawn {
+                winner: Some(winner),
+                prize: U256::from(3_600),
+                fee: U256::from(400),
+            }
+        );
+
+        let spent = if winner == BOB { 3 * PRICE } else { PRICE };
+
+        let res = vft_service.balance_of(winner).await;
+        assert_ok!(
+            res,
+            U256::exp10(MAGIC) - U256::from(spent) + U256::from(3_600)
+        );
+
+        let res = vft_service.balance_of(ALICE).await;
+        assert_ok!(res, U256::from(400));
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, U256::exp10(MAGIC));
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::zero());
+    }
+
+    // # Test case #4.
+    // Tickets of rounds not drawn in time are refunded with the bond,
+    // even after the next round opens.
+    {
+        let params = RoundParams {
+            end_block: env.system().block_height() + 10,
+            ..params
+        };
+
+        let res = raffle_service
+            .open(params.clone(), commit_reveal::hash(b"another seed"))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 1);
+
+        let res = raffle_service.buy_tickets(2).with_actor_id(BOB).await;
+        assert_ok!(res, 2);
+
+        let res = raffle_service.buy_tickets(2).with_actor_id(DAVE).await;
+        assert_ok!(res, 2);
+
+        let res = raffle_service.refund(1).with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Round is not expired");
+
+        env.system().run_to_block(params.end_block + REVEAL_BLOCKS);
+
+        let res = raffle_service
+            .draw(b"another seed".to_vec())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Round is not closed");
+
+        let balance = vft_service.balance_of(BOB).await.unwrap();
+
+        let res = raffle_service.refund(1).with_actor_id(BOB).await;
+        assert_ok!(res, U256::from(2 * PRICE + BOND / 2));
+
+        let res = raffle_service.refund(1).with_actor_id(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, balance + U256::from(2 * PRICE + BOND / 2));
+
+        // Unclaimed refunds don't block the next round.
+        let res = raffle_service
+            .open(
+                RoundParams {
+                    end_block: env.system().block_height() + 10,
+                    ..params
+                },
+                commitment,
+            )
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, 2);
+
+        let res = raffle_service.round().await.unwrap().unwrap();
+        assert_eq!((res.id, res.tickets), (2, 0));
+
+        let res = raffle_service.tickets_of(1, DAVE).await;
+        assert_ok!(res, 2);
+
+        let res = raffle_service.refund(1).with_actor_id(DAVE).await;
+        assert_ok!(res, U256::from(2 * PRICE + BOND / 2));
+
+        let res = raffle_service.refund(2).with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Round is not expired");
+    }
+}
+
+#[tokio::test]
+async fn amm() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let balances = vec![(BOB, U256::exp10(MAGIC)), (CHARLIE, U256::exp10(MAGIC))];
+
+    let token0 = deploy_program(&env, code_id, b"token0", vec![], balances.clone(), 0).await;
+    let token1 = deploy_program(&env, code_id, b"token1", vec![], balances, 0).await;
+    let program = deploy_program(&env, code_id, b"amm", vec![], vec![], 0).await;
+
+    let pid = program.id();
+    let (t0, t1) = (token0.id(), token1.id());
+
+    let mut amm_service = program.amm();
+    let lp_vft_service = program.lp_vft();
+    let vft_service = program.vft();
+
+    let listener_binding = program.amm().listener();
+    let mut amm_events = listener_binding.listen().await.unwrap();
+
+    for token in [&token0, &token1] {
+        for account in [BOB, CHARLIE] {
+            token
+                .vft()
+                .approve(pid, U256::exp10(MAGIC))
+                .with_actor_id(account)
+                .await
+                .unwrap();
+        }
+    }
+
+    // # Test case #1.
+    // Pair is set by the super admin.
+    {
+        let res = amm_service
+            .add_liquidity(1_000_000.into(), 4_000_000.into(), U256::zero())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Pair is not set");
+
+        let res = amm_service.set_pair(t0, t1, 30).with_actor_id(BOB).await;
+        assert!(res.is_err());
+
+        let res = amm_service.set_pair(t0, t1, 30).with_actor_id(ALICE).await;
+        assert_ok!(res, ());
+
+        let (actor, event) = amm_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            AmmEvents::PairSet(Pair {
+                token0: t0,
+                token1: t1,
+                fee_bps: 30,
+            })
+        );
+    }
+
+    // # Test case #2.
+    // Liquidity providers are issued LP tokens of the pool, apart from the VFT of the program.
+    {
+        let res = amm_service
+            .add_liquidity(1_000_000.into(), 4_000_000.into(), U256::zero())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, U256::from(2_000_000));
+
+        let (actor, event) = amm_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            AmmEvents::LiquidityAdded {
+                provider: BOB,
+                amount0: 1_000_000.into(),
+                amount1: 4_000_000.into(),
+                liquidity: 2_000_000.into(),
+            }
+        );
+
+        let res = lp_vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::from(2_000_000));
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = amm_service.reserves().await;
+        assert_ok!(res, (U256::from(1_000_000), U256::from(4_000_000)));
+
+        let res = token0.vft().balance_of(pid).await;
+        assert_ok!(res, U256::from(1_000_000));
+    }
+
+    // # Test case #3.
+    // Swaps are charged the fee and accumulate prices.
+    {
+        let res = amm_service
+            .swap(DAVE, 10_000.into(), U256::zero())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Token is not in the pair");
+
+        let res = amm_service.quote_swap(t0, 10_000.into()).await;
+        assert_ok!(res, Some((t1, U256::from(39_486))));
+
+        let res = amm_service
+            .swap(t0, 10_000.into(), 39_487.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Slippage exceeded");
+
+        let res = amm_service
+            .swap(t0, 10_000.into(), 39_486.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, U256::from(39_486));
+
+        let (actor, event) = amm_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            AmmEvents::Swapped {
+                trader: CHARLIE,
+                token_in: t0,
+                amount_in: 10_000.into(),
+                amount_out: 39_486.into(),
+            }
+        );
+
+        let res = token1.vft().balance_of(CHARLIE).await;
+        assert_ok!(res, U256::exp10(MAGIC) + U256::from(39_486));
+
+        let res = amm_service.reserves().await;
+        assert_ok!(res, (U256::from(1_010_000), U256::from(3_960_514)));
+
+        let (price0, price1) = amm_service.price_cumulatives().await.unwrap();
+        assert!(!price0.is_zero() && !price1.is_zero());
+    }
+
+    // # Test case #4.
+    // Liquidity is removed for the tokens of the pool.
+    {
+        let res = amm_service
+            .remove_liquidity(2_000_000.into(), U256::zero(), U256::zero())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, (U256::from(1_010_000), U256::from(3_960_514)));
+
+        let res = lp_vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::zero());
+
+        let res = amm_service.liquidity().await;
+        assert_ok!(res, U256::zero());
+
+        let res = token0.vft().balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) + U256::from(10_000));
+    }
+}
+
+#[tokio::test]
+async fn lending() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let collateral = deploy_program(
+        &env,
+        code_id,
+        b"collateral",
+        vec![],
+        vec![(BOB, U256::exp10(MAGIC))],
+        0,
+    )
+    .await;
+    let debt = deploy_program(
+        &env,
+        code_id,
+        b"debt",
+        vec![],
+        vec![(ALICE, U256::exp10(MAGIC)), (CHARLIE, U256::exp10(MAGIC))],
+        0,
+    )
+    .await;
+    let program = deploy_program(&env, code_id, b"lending", vec![], vec![], 0).await;
+
+    let pid = program.id();
+
+    let mut lending_service = program.lending();
+    let mut access_control_service = program.access_control();
+
+    let listener_binding = program.lending().listener();
+    let mut lending_events = listener_binding.listen().await.unwrap();
+
+    debt.vft()
+        .transfer(pid, U256::exp10(MAGIC))
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    for (token, account) in [(&collateral, BOB), (&debt, BOB), (&debt, CHARLIE)] {
+        token
+            .vft()
+            .approve(pid, U256::exp10(MAGIC))
+            .with_actor_id(account)
+            .await
+            .unwrap();
+    }
+
+    let mut params = MarketParams {
+        collateral: collateral.id(),
+        debt: debt.id(),
+        ltv_bps: 5_000,
+        liquidation_threshold_bps: 8_000,
+        liquidation_bonus_bps: 500,
+        interest_per_block: U256::zero(),
+        max_price_age: 1_000,
+        oracle: ActorId::zero(),
+    };
+
+    // # Test case #1.
+    // Market is set by the super admin and priced by the oracle.
+    {
+        let res = lending_service
+            .deposit_collateral(1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Market is not set");
+
+        let res = lending_service
+            .set_params(params.clone())
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = lending_service
+            .set_params(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = lending_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, LendingEvents::ParamsSet(params.clone()));
+
+        let res = lending_service
+            .set_price(U256::from(SCALE) * 2)
+            .with_actor_id(DAVE)
+            .await;
+        assert!(res.is_err());
+
+        let res = lending_service.refresh_price().with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        // Collateral program doesn't expose the `Oracle` service.
+        let res = lending_service
+            .set_params(MarketParams {
+                oracle: collateral.id(),
+                ..params.clone()
+            })
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = lending_service.refresh_price().with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Oracle query failed");
+
+        let res = lending_service
+            .set_params(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        for _ in 0..2 {
+            let (_, event) = lending_events.next().await.unwrap();
+            assert!(matches!(event, LendingEvents::ParamsSet(_)));
+        }
+
+        access_control_service
+            .grant_role(ORACLE_ROLE, DAVE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+    }
+
+    // # Test case #2.
+    // Borrowing is limited by the LTV of the collateral.
+    {
+        let res = lending_service
+            .deposit_collateral(1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, U256::from(1_000));
+
+        let (actor, event) = lending_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            LendingEvents::CollateralDeposited {
+                account: BOB,
+                amount: 1_000.into(),
+            }
+        );
+
+        let res = lending_service.borrow(100.into()).with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Price is stale");
+
+        let res = lending_service
+            .set_price(U256::from(SCALE) * 2)
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = lending_service
+            .borrow(1_001.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Position is undercollateralized");
+
+        let res = lending_service
+            .borrow(1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = debt.vft().balance_of(BOB).await;
+        assert_ok!(res, U256::from(1_000));
+
+        let res = lending_service.position(BOB).await;
+        assert_ok!(
+            res,
+            Position {
+                collateral: 1_000.into(),
+                debt: 1_000.into(),
+            }
+        );
+
+        let res = lending_service
+            .withdraw_collateral(1.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Position is undercollateralized");
+    }
+
+    // # Test case #3.
+    // Positions beyond the liquidation threshold are liquidated by anyone with the bonus.
+    {
+        let res = lending_service
+            .liquidate(BOB, 500.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Position is not liquidatable");
+
+        let res = lending_service
+            .set_price(SCALE.into())
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = lending_service
+            .liquidate(BOB, 500.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, U256::from(525));
+
+        let res = collateral.vft().balance_of(CHARLIE).await;
+        assert_ok!(res, U256::from(525));
+
+        let res = lending_service.position(BOB).await;
+        assert_ok!(
+            res,
+            Position {
+                collateral: 475.into(),
+                debt: 500.into(),
+            }
+        );
+    }
+
+    // # Test case #4.
+    // Debt is repaid up to its amount, releasing the collateral.
+    {
+        let res = lending_service
+            .repay(U256::exp10(MAGIC))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, U256::from(500));
+
+        let res = lending_service
+            .withdraw_collateral(475.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = lending_service.position(BOB).await;
+        assert_ok!(
+            res,
+            Position {
+                collateral: U256::zero(),
+                debt: U256::zero(),
+            }
+        );
+
+        let res = collateral.vft().balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(525));
+    }
+
+    // # Test case #5.
+    // Debt accrues interest every block.
+    {
+        params.interest_per_block = U256::from(SCALE / 100);
+
+        let res = lending_service
+            .set_params(params.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        lending_service
+            .deposit_collateral(1_000.into())
+            .with_actor_id(BOB)
+            .await
+            .unwrap();
+
+        let res = lending_service.borrow(100.into()).with_actor_id(BOB).await;
+        assert_ok!(res, ());
+
+        env.system().run_to_block(env.system().block_height() + 10);
+
+        let position = lending_service.position(BOB).await.unwrap();
+        assert!(position.debt > U256::from(100));
+    }
+}
+
+#[tokio::test]
+async fn htlc() {
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut htlc_service = program.htlc();
+    let vft_service = program.vft();
+
+    let listener_binding = program.htlc().listener();
+    let mut htlc_events = listener_binding.listen().await.unwrap();
+
+    let hashlock = hash(b"secret");
+    let timelock = env.system().block_height() + 10;
+
+    // # Test case #1.
+    // VFTs and native value are locked under the hashlock.
+    {
+        let res = htlc_service
+            .lock(hashlock, 0, CHARLIE, 1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = htlc_service
+            .lock(hashlock, timelock, CHARLIE, 1_000.into())
+            .with_value(VALUE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = htlc_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            HtlcEvents::Locked {
+                hashlock,
+                sender: BOB,
+                recipient: CHARLIE,
+                timelock,
+                value: 1_000.into(),
+                native: VALUE,
+            }
+        );
+
+        let res = htlc_service
+            .lock(hashlock, timelock, DAVE, 1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Lock already exists");
+
+        let res = htlc_service.lock_of(hashlock).await;
+        assert_ok!(
+            res,
+            Some(Lock {
+                sender: BOB,
+                recipient: CHARLIE,
+                timelock,
+                value: 1_000.into(),
+                native: VALUE,
+            })
+        );
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(1_000));
+    }
+
+    // # Test case #2.
+    // Anyone revealing the preimage pays the lock to its recipient.
+    {
+        let res = htlc_service
+            .claim(b"guess".to_vec())
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Lock not found");
+
+        let res = htlc_service
+            .claim(b"secret".to_vec())
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, hashlock);
+
+        let (actor, event) = htlc_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            HtlcEvents::Claimed {
+                hashlock,
+                preimage: b"secret".to_vec(),
+            }
+        );
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, U256::from(1_000));
+
+        let res = htlc_service.lock_of(hashlock).await;
+        assert_ok!(res, None);
+    }
+
+    // # Test case #3.
+    // Expired locks are refunded to the sender only.
+    {
+        let hashlock = hash(b"another secret");
+        let timelock = env.system().block_height() + 5;
+
+        let res = htlc_service
+            .lock(hashlock, timelock, CHARLIE, 1_000.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = htlc_service.refund(hashlock).with_actor_id(DAVE).await;
+        assert_str_panic(res.unwrap_err(), "Lock is not expired");
+
+        env.system().run_to_block(timelock);
+
+        let res = htlc_service
+            .claim(b"another secret".to_vec())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Lock is expired");
+
+        let res = htlc_service.refund(hashlock).with_actor_id(DAVE).await;
+        assert_ok!(res, ());
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(1_000));
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::zero());
+    }
+}
+
+#[tokio::test]
+async fn eth_binding() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut eth_binding_service = program.eth_binding();
+    let vft_service = program.vft();
+
+    let listener_binding = program.eth_binding().listener();
+    let mut eth_binding_events = listener_binding.listen().await.unwrap();
+
+    let key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+    let public_key = key.verifying_key().to_encoded_point(false);
+    let address = eth::address(public_key.as_bytes()[1..].try_into().unwrap());
+
+    let sign = |message: &[u8]| {
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&eth::message_hash(message))
+            .unwrap();
+
+        let mut bytes = [0; 65];
+        bytes[..64].copy_from_slice(&signature.to_bytes());
+        bytes[64] = recovery_id.to_byte() + 27;
+
+        bytes
+    };
+
+    // # Test case #1.
+    // Addresses are bound by signed binding messages.
+    {
+        let message = eth_binding_service
+            .bind_message(address, CHARLIE)
+            .await
+            .unwrap();
+
+        let res = eth_binding_service
+            .bind(address, sign(&message))
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+
+        let message = eth_binding_service
+            .bind_message(address, BOB)
+            .await
+            .unwrap();
+        let signature = sign(&message);
+
+        let res = eth_binding_service
+            .bind(address, signature)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = eth_binding_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            EthBindingEvents::Bound {
+                address,
+                account: BOB,
+            }
+        );
+
+        // Signatures are used once.
+        let res = eth_binding_service
+            .bind(address, signature)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+
+        let res = eth_binding_service.account_of(address).await;
+        assert_ok!(res, Some(BOB));
+
+        let res = eth_binding_service.address_of(BOB).await;
+        assert_ok!(res, Some(address));
+
+        let res = eth_binding_service.nonce(address).await;
+        assert_ok!(res, 1);
+    }
+
+    // # Test case #2.
+    // Bound addresses approve VFTs of their accounts by signed permits.
+    {
+        let deadline = env.system().block_height() + 100;
+
+        let message = eth_binding_service
+            .permit_message(address, CHARLIE, 1_000.into(), 0)
+            .await
+            .unwrap();
+
+        let res = eth_binding_service
+            .permit(address, CHARLIE, 1_000.into(), 0, sign(&message))
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Permit is expired");
+
+        let message = eth_binding_service
+            .permit_message(address, CHARLIE, 1_000.into(), deadline)
+            .await
+            .unwrap();
+        let signature = sign(&message);
+
+        let res = eth_binding_service
+            .permit(address, CHARLIE, 1_000.into(), deadline, signature)
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_service.allowance(BOB, CHARLIE).await;
+        assert_ok!(res, U256::from(1_000));
+
+        let res = eth_binding_service
+            .permit(address, CHARLIE, 1_000.into(), deadline, signature)
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "invalid signature");
+    }
+
+    // # Test case #3.
+    // Accounts unbind their addresses.
+    {
+        let res = eth_binding_service.unbind().with_actor_id(BOB).await;
+        assert_ok!(res, address);
+
+        let (actor, event) = eth_binding_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            EthBindingEvents::Unbound {
+                address,
+                account: BOB,
+            }
+        );
+
+        let res = eth_binding_service.account_of(address).await;
+        assert_ok!(res, None);
+
+        let res = eth_binding_service.unbind().with_actor_id(BOB).await;
+        assert_str_panic(res.unwrap_err(), "Address or account is not bound");
+    }
+}
+
+#[tokio::test]
+async fn fee_pool() {
+    const DEPOSIT: u128 = 1_000_000_000_000_000;
+    const BASE_GAS: u64 = 1_000_000;
+
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut eth_binding_service = program.eth_binding();
+    let mut fee_pool_service = program.fee_pool();
+    let vft_service = program.vft();
+
+    let listener_binding = program.fee_pool().listener();
+    let mut fee_pool_events = listener_binding.listen().await.unwrap();
+
+    let key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+    let public_key = key.verifying_key().to_encoded_point(false);
+    let address = eth::address(public_key.as_bytes()[1..].try_into().unwrap());
+
+    let sign = |message: &[u8]| {
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&eth::message_hash(message))
+            .unwrap();
+
+        let mut bytes = [0; 65];
+        bytes[..64].copy_from_slice(&signature.to_bytes());
+        bytes[64] = recovery_id.to_byte() + 27;
+
+        bytes
+    };
+
+    let message = eth_binding_service
+        .bind_message(address, BOB)
+        .await
+        .unwrap();
+
+    eth_binding_service
+        .bind(address, sign(&message))
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    access_control_service
+        .grant_role(RELAYER_ROLE, CHARLIE)
+        .with_actor_id(ALICE)
+        .await
+        .unwrap();
+
+    // # Test case #1.
+    // Admin sets the rate, while accounts deposit VFTs to pay fees.
+    {
+        // One token per unit of gas.
+        let rate = FeeRate {
+            per_gas: RATE_SCALE.into(),
+            base_gas: BASE_GAS,
+        };
+
+        let res = fee_pool_service
+            .set_fee_rate(rate.clone())
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        let res = fee_pool_service
+            .set_fee_rate(rate.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = fee_pool_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(event, FeePoolEvents::FeeRateChanged(rate));
+
+        let res = fee_pool_service.quote(0).await;
+        assert_ok!(res, Some(U256::from(BASE_GAS)));
+
+        let res = fee_pool_service
+            .deposit(DEPOSIT.into())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = fee_pool_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            FeePoolEvents::Deposited {
+                account: BOB,
+                amount: DEPOSIT.into(),
+            }
+        );
+
+        let res = vft_service.balance_of(pid).await;
+        assert_ok!(res, U256::from(DEPOSIT));
+    }
+
+    // # Test case #2.
+    // Relayers submit signed permits, reimbursed from deposits of the accounts.
+    let fee = {
+        let deadline = 1_000;
+
+        let message = eth_binding_service
+            .permit_message(address, DAVE, 100.into(), deadline)
+            .await
+            .unwrap();
+        let signature = sign(&message);
+
+        let res = fee_pool_service
+            .relay_permit(address, DAVE, 100.into(), deadline, signature)
+            .with_actor_id(DAVE)
+            .await;
+        assert!(res.is_err());
+
+        let fee = fee_pool_service
+            .relay_permit(address, DAVE, 100.into(), deadline, signature)
+            .with_actor_id(CHARLIE)
+            .await
+            .unwrap();
+        assert!(fee > U256::from(BASE_GAS));
+
+        let (actor, event) = fee_pool_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            FeePoolEvents::Reimbursed {
+                relayer: CHARLIE,
+                account: BOB,
+                gas: fee.low_u64(),
+                fee,
+            }
+        );
+
+        let res = vft_service.allowance(BOB, DAVE).await;
+        assert_ok!(res, U256::from(100));
+
+        let res = vft_service.balance_of(CHARLIE).await;
+        assert_ok!(res, fee);
+
+        let res = fee_pool_service.deposit_of(BOB).await;
+        assert_ok!(res, U256::from(DEPOSIT) - fee);
+
+        let res = fee_pool_service.relayer_stats(CHARLIE).await;
+        assert_ok!(
+            res,
+            RelayerStats {
+                relayed: 1,
+                gas: fee.low_u64(),
+                reimbursed: fee,
+            }
+        );
+
+        fee
+    };
+
+    // # Test case #3.
+    // Meta-transactions fail as a whole if the deposit doesn't cover the fee.
+    {
+        let res = fee_pool_service
+            .withdraw(U256::from(DEPOSIT))
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            &format!(
+                "Insufficient deposit: {} is available",
+                U256::from(DEPOSIT) - fee
+            ),
+        );
+
+        let res = fee_pool_service
+            .withdraw(U256::from(DEPOSIT) - fee - 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = fee_pool_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            FeePoolEvents::Withdrawn {
+                account: BOB,
+                amount: U256::from(DEPOSIT) - fee - 1,
+            }
+        );
+
+        let message = eth_binding_service
+            .permit_message(address, DAVE, 200.into(), 1_000)
+            .await
+            .unwrap();
+
+        let res = fee_pool_service
+            .relay_permit(address, DAVE, 200.into(), 1_000, sign(&message))
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Insufficient deposit: 1 is available");
+
+        let res = vft_service.allowance(BOB, DAVE).await;
+        assert_ok!(res, U256::from(100));
+
+        let res = eth_binding_service.nonce(address).await;
+        assert_ok!(res, 2);
+    }
+}
+
+#[tokio::test]
+async fn bridge_adapter() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut bridge_adapter_service = program.vft_bridge_adapter();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.vft_bridge_adapter().listener();
+    let mut bridge_adapter_events = listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Bridge mints and burns with the roles of VFT-Admin.
+    {
+        let res = bridge_adapter_service
+            .mint(DAVE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        for role in [MINTER_ROLE, BURNER_ROLE] {
+            access_control_service
+                .grant_role(role, CHARLIE)
+                .with_actor_id(ALICE)
+                .await
+                .unwrap();
+        }
+
+        let res = bridge_adapter_service
+            .mint(DAVE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = bridge_adapter_service
+            .mint(DAVE, U256::zero())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = bridge_adapter_service
+            .burn(BOB, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(1_000));
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, U256::exp10(MAGIC) - U256::from(1_000));
+    }
+
+    // # Test case #2.
+    // Remote events are minted once.
+    {
+        let event_id = H256::from([1; 32]);
+
+        let res = bridge_adapter_service.is_processed(event_id).await;
+        assert_ok!(res, false);
+
+        let res = bridge_adapter_service
+            .mint_remote(event_id, DAVE, 500.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = bridge_adapter_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftBridgeAdapterEvents::RemoteEventProcessed {
+                event_id,
+                to: DAVE,
+                value: 500.into(),
+            }
+        );
+
+        let res = bridge_adapter_service
+            .mint_remote(event_id, DAVE, 500.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Remote event is already processed");
+
+        let res = bridge_adapter_service.is_processed(event_id).await;
+        assert_ok!(res, true);
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, U256::from(1_500));
+    }
+
+    // # Test case #3.
+    // Divergence of the supply from the remote locked amount pauses the program.
+    {
+        let supply = U256::exp10(MAGIC) + U256::from(500);
+
+        let res = bridge_adapter_service
+            .reconcile(supply)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert!(res.is_err());
+
+        let reconciliation = Reconciliation {
+            threshold: 100.into(),
+            auto_pause: true,
+        };
+
+        let res = bridge_adapter_service
+            .set_reconciliation(reconciliation.clone())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = bridge_adapter_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftBridgeAdapterEvents::ReconciliationSet(reconciliation)
+        );
+
+        let report = bridge_adapter_service
+            .reconcile(supply - U256::from(100))
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+        assert_eq!(report.local_supply, supply);
+        assert_eq!(report.divergence, U256::from(100));
+
+        let res = bridge_adapter_service
+            .reconcile(supply - U256::from(500))
+            .with_actor_id(ALICE)
+            .await;
+        assert!(res.is_ok());
+
+        let (actor, event) = bridge_adapter_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftBridgeAdapterEvents::SupplyMismatch {
+                local_supply: supply,
+                remote_locked: supply - U256::from(500),
+                divergence: 500.into(),
+                paused: true,
+            }
+        );
+
+        let res = vft_service
+            .transfer(DAVE, 1.into())
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+    }
+}
+
+#[tokio::test]
+async fn client_sdk() {
+    use awesome_sails::access_control::DEFAULT_ADMIN_ROLE;
+    use awesome_sails_client::{
+        AwesomeSailsClient as _, AwesomeSailsClientProgram,
+        access_control::AccessControl as _,
+        listeners,
+        vft::{Vft as _, events::VftEvents},
+    };
+    use sails_rs::client::Actor;
+
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (_program, env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    // The SDK talks to the program without its own generated client.
+    let program = Actor::<AwesomeSailsClientProgram, _>::new(env.clone(), pid);
+
+    let mut vft_service = program.vft();
+
+    let listener = vft_service.listener();
+    let mut vft_events = Box::pin(listeners::resubscribing(&listener).await.unwrap());
+
+    let res = vft_service.transfer(BOB, U256::exp10(10)).await;
+    assert_ok!(res, true);
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::Transfer {
+            from: ALICE,
+            to: BOB,
+            value: U256::exp10(10),
+        }
+    );
+
+    let res = vft_service.balance_of(BOB).await;
+    assert_ok!(res, U256::exp10(10));
+
+    let res = program
+        .access_control()
+        .has_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .await;
+    assert_ok!(res, true);
+}
+
+#[tokio::test]
+async fn state_diff() {
+    let balances = vec![(ALICE, U256::exp10(MAGIC)), (BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, _pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let mut vft_service = program.vft();
+
+    let before = StateSnapshot::capture(&program).await;
+
+    // Nothing changed.
+    assert_eq!(before.diff(&before), StateDiff::default());
+
+    assert_ok!(vft_service.transfer(CHARLIE, U256::exp10(10)).await, true);
+    assert_ok!(vft_service.approve(DAVE, U256::exp10(5)).await, true);
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .await
+        .unwrap();
+
+    let diff = before.diff(&StateSnapshot::capture(&program).await);
+
+    // Only entries of the calls changed.
+    assert_eq!(
+        diff.balances,
+        BTreeMap::from([
+            (
+                ALICE,
+                Change {
+                    before: Some(U256::exp10(MAGIC)),
+                    after: Some(U256::exp10(MAGIC) - U256::exp10(10)),
+                }
+            ),
+            (
+                CHARLIE,
+                Change {
+                    before: None,
+                    after: Some(U256::exp10(10)),
+                }
+            ),
+        ])
+    );
+
+    assert_eq!(diff.allowances.len(), 1);
+
+    let allowance = &diff.allowances[&(ALICE, DAVE)];
+    assert_eq!(allowance.before, None);
+    assert_eq!(
+        allowance.after.map(|(value, _)| value),
+        Some(U256::exp10(5))
+    );
+
+    assert_eq!(diff.granted, BTreeSet::from([(MINTER_ROLE, BOB)]));
+    assert!(diff.revoked.is_empty());
+}
+
+#[tokio::test]
+async fn init_guard() {
+    use awesome_sails::access_control::DEFAULT_ADMIN_ROLE;
+
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let config = InitConfig {
+        admin: BOB,
+        name: "Token".into(),
+        symbol: "TKN".into(),
+        decimals: 12,
+        balances_shards: vec![64, 64],
+        allowances_shards: vec![64],
+        expiry_period: 100,
+    };
+
+    let deploy = |salt: &[u8], config: InitConfig| {
+        env.deploy::<AwesomeSailsTestClientProgram>(code_id, salt.to_vec())
+            .with_config(config)
+    };
+
+    // Misconfigured instances fail to deploy.
+    for (salt, config, err) in [
+        (
+            b"decimals".as_slice(),
+            InitConfig {
+                decimals: 31,
+                ..config.clone()
+            },
+            "decimals exceed the maximum",
+        ),
+        (
+            b"admin",
+            InitConfig {
+                admin: ActorId::zero(),
+                ..config.clone()
+            },
+            "admin is zero",
+        ),
+        (
+            b"shards",
+            InitConfig {
+                balances_shards: vec![64, 0],
+                ..config.clone()
+            },
+            "shard capacity is invalid",
+        ),
+        (
+            b"expiry",
+            InitConfig {
+                expiry_period: 0,
+                ..config.clone()
+            },
+            "expiry period is zero",
+        ),
+    ] {
+        let res = deploy(salt, config).await.err();
+        assert_str_panic(res.expect("deployment must fail"), err);
+    }
+
+    // Valid configuration is applied.
+    let program = deploy(b"valid", config)
+        .await
+        .expect("failed to deploy program");
+
+    assert_ok!(program.vft_metadata().decimals().await, 12);
+    assert_ok!(program.vft_metadata().symbol().await, "TKN");
+    assert_ok!(
+        program
+            .access_control()
+            .has_role(DEFAULT_ADMIN_ROLE, BOB)
+            .await,
+        true
+    );
+    assert_ok!(
+        program
+            .access_control()
+            .has_role(DEFAULT_ADMIN_ROLE, ALICE)
+            .await,
+        false
+    );
+}
+
+#[tokio::test]
+async fn empty_shards() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let program = env
+        .deploy::<AwesomeSailsTestClientProgram>(code_id, b"salt".to_vec())
+        .with_config(InitConfig {
+            admin: BOB,
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            decimals: 12,
+            balances_shards: vec![56, 56],
+            allowances_shards: vec![56],
+            expiry_period: 100,
+        })
+        .await
+        .expect("failed to deploy program");
+
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_extension_service = program.vft_extension();
+
+    let res = vft_extension_service.allocate_next_balances_shard().await;
+    assert_ok!(res, true);
+
+    let res = vft_admin_service.balances_unused_capacity().await;
+    assert_ok!(res, 56);
+
+    // # Test case #1.
+    // Only super admin removes empty shards, the last ones first.
+    {
+        let res = vft_admin_service
+            .remove_empty_balances_shard()
+            .with_actor_id(ALICE)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .remove_empty_balances_shard()
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 56);
+
+        // Allocated shard is kept until removed as well.
+        let res = vft_admin_service.balances_unused_capacity().await;
+        assert_ok!(res, 56);
+
+        let res = vft_admin_service
+            .remove_empty_balances_shard()
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, 56);
+
+        let res = vft_admin_service.balances_unused_capacity().await;
+        assert_ok!(res, 0);
+
+        let res = vft_admin_service
+            .remove_empty_balances_shard()
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "sharded map error: no empty shard");
+    }
+
+    // # Test case #2.
+    // Freed capacity is appended to another map.
+    {
+        let res = vft_admin_service
+            .append_allowances_shard(56)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = vft_admin_service.allowances_unused_capacity().await;
+        assert_ok!(res, 0);
+
+        let res = vft_extension_service.allocate_next_allowances_shard().await;
+        assert_ok!(res, true);
+
+        let res = vft_extension_service.allocate_next_allowances_shard().await;
+        assert_ok!(res, false);
+
+        let res = vft_admin_service.allowances_unused_capacity().await;
+        assert_ok!(res, 112);
+    }
+}
+
+#[tokio::test]
+async fn shards_full() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let program = env
+        .deploy::<AwesomeSailsTestClientProgram>(code_id, b"salt".to_vec())
+        .with_config(InitConfig {
+            admin: BOB,
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            decimals: 12,
+            balances_shards: vec![7],
+            allowances_shards: vec![3],
+            expiry_period: 100,
+        })
+        .await
+        .expect("failed to deploy program");
+
+    let pid = program.id();
+
+    let mut vft_service = program.vft();
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_extension_service = program.vft_extension();
+
+    let listener_binding = program.vft().listener();
+    let mut vft_events = listener_binding.listen().await.unwrap();
+
+    let res = vft_extension_service.allocate_next_allowances_shard().await;
+    assert_ok!(res, false);
+
+    // # Test case #1.
+    // Insert filling shards up is reported.
+    {
+        for spender in [BOB, CHARLIE, DAVE] {
+            let res = vft_service.approve(spender, U256::one()).await;
+            assert_ok!(res, true);
+
+            let (actor, event) = vft_events.next().await.unwrap();
+            assert_eq!(actor, pid);
+            assert_eq!(
+                event,
+                VftEvents::Approval {
+                    owner: ALICE,
+                    spender,
+                    value: U256::one(),
+                }
+            );
+        }
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::StorageSaturated {
+                map: StorageMap::Allowances,
+                shards: 1,
+                capacity: 3,
+            }
+        );
+    }
+
+    // # Test case #2.
+    // Inserts into full shards fail with their occupancy, until shards are appended.
+    {
+        let res = vft_service.approve(ActorId::from(42), U256::one()).await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "allowances shards are full: 3 entries in 1 shards, append or allocate shards",
+        );
+
+        // Updates of existing entries don't insert.
+        let res = vft_service.approve(BOB, U256::from(2)).await;
+        assert_ok!(res, true);
+
+        let res = vft_admin_service
+            .append_allowances_shard(3)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = vft_extension_service.allocate_next_allowances_shard().await;
+        assert_ok!(res, false);
+
+        let res = vft_service.approve(ActorId::from(42), U256::one()).await;
+        assert_ok!(res, true);
+    }
+}
+
+#[tokio::test]
+async fn capacity_alert() {
+    use awesome_sails::vft::{capacity_notification, utils as vft_utils};
+
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let program = env
+        .deploy::<AwesomeSailsTestClientProgram>(code_id, b"salt".to_vec())
+        .with_config(InitConfig {
+            admin: BOB,
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            decimals: 12,
+            balances_shards: vec![7],
+            allowances_shards: vec![7],
+            expiry_period: 100,
+        })
+        .await
+        .expect("failed to deploy program");
+
+    let pid = program.id();
+
+    let mut access_control_service = program.access_control();
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_extension_service = program.vft_extension();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    let res = vft_extension_service.allocate_next_balances_shard().await;
+    assert_ok!(res, false);
+
+    access_control_service
+        .grant_role(MINTER_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await
+        .unwrap();
+
+    let alert = CapacityAlert {
+        threshold_bps: 5_000,
+        operator: Some(DAVE),
+    };
+
+    // # Test case #1.
+    // Only super admin sets alerts of valid thresholds.
+    {
+        let res = vft_admin_service
+            .set_capacity_alert(StorageMap::Balances, Some(alert))
+            .with_actor_id(ALICE)
+            .await;
+        assert!(res.is_err());
+
+        let res = vft_admin_service
+            .set_capacity_alert(
+                StorageMap::Balances,
+                Some(CapacityAlert {
+                    threshold_bps: 10_001,
+                    ..alert
+                }),
+            )
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = vft_admin_service
+            .set_capacity_alert(StorageMap::Balances, Some(alert))
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = vft_admin_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftAdminEvents::CapacityAlertChanged {
+                map: StorageMap::Balances,
+                alert: Some(alert),
+            }
+        );
+
+        let res = vft_admin_service.capacity_alert(StorageMap::Balances).await;
+        assert_ok!(res, Some(alert));
+
+        let res = vft_admin_service
+            .capacity_alert(StorageMap::Allowances)
+            .await;
+        assert_ok!(res, None);
+    }
+
+    // # Test case #2.
+    // Insert crossing the threshold warns and notifies the operator once.
+    {
+        for to in [ALICE, CHARLIE, DAVE] {
+            let res = vft_admin_service
+                .mint(to, U256::one())
+                .with_actor_id(BOB)
+                .await;
+            assert_ok!(res, ());
+        }
+
+        let listener_binding = program.vft().listener();
+        let mut vft_events = listener_binding.listen().await.unwrap();
+
+        // 4 of 7 entries cross 50%.
+        let res = vft_admin_service
+            .mint(ActorId::from(42), U256::one())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (_, event) = vft_events.next().await.unwrap();
+        assert!(matches!(event, VftEvents::Transfer { .. }));
+
+        let (actor, event) = vft_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            VftEvents::CapacityWarning {
+                map: StorageMap::Balances,
+                entries: 4,
+                capacity: 7,
+            }
+        );
+
+        let notification =
+            Log::builder()
+                .source(pid)
+                .dest(DAVE)
+                .payload_bytes(capacity_notification(vft_utils::CapacityWarning {
+                    map: vft_utils::StorageMap::Balances,
+                    entries: 4,
+                    capacity: 7,
+                }));
+
+        assert!(env.system().get_mailbox(DAVE).contains(&notification));
+
+        // Inserts past the threshold don't warn again.
+        let res = vft_admin_service
+            .mint(ActorId::from(43), U256::one())
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (_, event) = vft_events.next().await.unwrap();
+        assert!(matches!(event, VftEvents::Transfer { .. }));
+    }
+}
+
+#[tokio::test]
+async fn simulate() {
+    use awesome_sails::access_control::DEFAULT_ADMIN_ROLE;
+
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, _env, _pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let vft_admin = program.vft_admin();
+    let vft = program.vft();
+
+    // Burns are previewed without burning.
+    let res = vft_admin.simulate_burn(ALICE, U256::exp10(10)).await;
+    assert_ok!(
+        res,
+        BurnPreview {
+            balance: U256::exp10(MAGIC) - U256::exp10(10),
+            reaped: None,
+            total_supply: U256::exp10(MAGIC) - U256::exp10(10),
+        }
+    );
+
+    let res = vft_admin.simulate_burn(ALICE, U256::exp10(MAGIC)).await;
+    assert_ok!(
+        res,
+        BurnPreview {
+            balance: U256::zero(),
+            reaped: Some(U256::zero()),
+            total_supply: U256::zero(),
+        }
+    );
+
+    assert_ok!(vft.balance_of(ALICE).await, U256::exp10(MAGIC));
+    assert_ok!(vft.total_supply().await, U256::exp10(MAGIC));
+
+    // Previews fail as the calls would.
+    let res = vft_admin
+        .simulate_burn(ALICE, U256::exp10(MAGIC) + U256::one())
+        .await;
+    assert_str_panic(res.unwrap_err(), "insufficient balance");
+
+    let res = vft_admin
+        .simulate_burn(ALICE, U256::exp10(10))
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Grants are previewed without granting.
+    let mut access_control = program.access_control();
+
+    let res = access_control.simulate_grant_role(BURNER_ROLE, BOB).await;
+    assert_ok!(
+        res,
+        GrantPreview {
+            via_role: DEFAULT_ADMIN_ROLE,
+            ready_at: None,
+            changed: true,
+        }
+    );
+
+    assert_ok!(access_control.has_role(BURNER_ROLE, BOB).await, false);
+
+    let res = access_control
+        .simulate_grant_role(BURNER_ROLE, BOB)
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    // Delayed grants are previewed as scheduled.
+    let res = access_control.set_role_grant_delay(MINTER_ROLE, 10).await;
+    assert_ok!(res, ());
+
+    let res = access_control.simulate_grant_role(MINTER_ROLE, BOB).await;
+    let preview = res.expect("Ran into `Err` value");
+    assert!(preview.ready_at.is_some() && preview.changed);
+
+    // Granted roles are previewed as unchanged.
+    let res = access_control
+        .simulate_grant_role(DEFAULT_ADMIN_ROLE, ALICE)
+        .await;
+    assert_ok!(
+        res,
+        GrantPreview {
+            via_role: DEFAULT_ADMIN_ROLE,
+            ready_at: None,
+            changed: false,
+        }
+    );
+
+    // Grants of locked roles fail as `grant_role` would.
+    let res = access_control.lock_role(BURNER_ROLE).await;
+    assert_ok!(res, true);
+
+    let res = access_control.simulate_grant_role(BURNER_ROLE, BOB).await;
+    assert_str_panic(
+        res.unwrap_err(),
+        &format!("Role locked: role {BURNER_ROLE:?} admits no new members"),
+    );
+}
+
+#[tokio::test]
+async fn circuit_breaker() {
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, _env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+    let mut vft_service = program.vft();
+
+    let admin_listener_binding = program.vft_admin().listener();
+    let mut vft_admin_events = admin_listener_binding.listen().await.unwrap();
+
+    let vft_listener_binding = program.vft().listener();
+    let mut vft_events = vft_listener_binding.listen().await.unwrap();
+
+    let config = CircuitBreakerConfig {
+        window_blocks: 100,
+        max_minted: 1000.into(),
+        max_transferred: 500.into(),
+    };
+
+    // Only admins set thresholds.
+    let res = vft_admin_service
+        .set_circuit_breaker(config.clone())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_admin_service
+        .set_circuit_breaker(config.clone())
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let (actor, event) = vft_admin_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(event, VftAdminEvents::CircuitBreakerSet(config.clone()));
+
+    let res = vft_admin_service.circuit_breaker().await;
+    assert_ok!(res, config);
+
+    // Mints within thresholds pass.
+    let res = vft_admin_service
+        .mint(CHARLIE, 600.into())
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, false);
+
+    let (_, event) = vft_admin_events.next().await.unwrap();
+    assert_eq!(event, VftAdminEvents::MinterTookPlace);
+
+    let (_, event) = vft_admin_events.next().await.unwrap();
+    assert_eq!(
+        event,
+        VftAdminEvents::Minted {
+            by: ALICE,
+            to: CHARLIE,
+            value: 600.into(),
+        }
+    );
+
+    // Mint exceeding the threshold trips the breaker.
+    let res = vft_admin_service
+        .mint(CHARLIE, 500.into())
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, true);
+
+    let (_, event) = vft_admin_events.next().await.unwrap();
+    assert_eq!(event, VftAdminEvents::MinterTookPlace);
+
+    let (_, event) = vft_admin_events.next().await.unwrap();
+    assert_eq!(
+        event,
+        VftAdminEvents::Minted {
+            by: ALICE,
+            to: CHARLIE,
+            value: 500.into(),
+        }
+    );
+
+    let (actor, event) = vft_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftEvents::CircuitBreakerTripped {
+            minted: 1100.into(),
+            transferred: U256::zero(),
+        }
+    );
+
+    // Mints and transfers are halted, burns aren't.
+    let res = vft_service
+        .transfer(DAVE, 1.into())
+        .with_actor_id(BOB)
+        .await;
+    assert_str_panic(
+        res.unwrap_err(),
+        "mints and transfers are halted by the circuit breaker",
+    );
+
+    let res = vft_admin_service
+        .mint(CHARLIE, 1.into())
+        .with_actor_id(ALICE)
+        .await;
+    assert_str_panic(
+        res.unwrap_err(),
+        "mints and transfers are halted by the circuit breaker",
+    );
+
+    // Only pausers resume, resetting the breaker.
+    let res = vft_admin_service.resume().with_actor_id(BOB).await;
+    assert!(res.is_err());
+
+    let res = vft_admin_service.resume().with_actor_id(ALICE).await;
+    assert_ok!(res, ());
+
+    let res = vft_admin_service
+        .check_circuit_breaker()
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_ok!(res, false);
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, false);
+
+    // Transfer exceeding the threshold trips the breaker itself.
+    let res = vft_service
+        .transfer(DAVE, 600.into())
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let (_, event) = vft_events.next().await.unwrap();
+    assert_eq!(
+        event,
+        VftEvents::Transfer {
+            from: BOB,
+            to: DAVE,
+            value: 600.into(),
+        }
+    );
+
+    let (_, event) = vft_events.next().await.unwrap();
+    assert_eq!(
+        event,
+        VftEvents::CircuitBreakerTripped {
+            minted: U256::zero(),
+            transferred: 600.into(),
+        }
+    );
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, true);
+}
+
+#[tokio::test]
+async fn native_exchange_bounds() {
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let (program, env, pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut exchange_service = program.vft_native_exchange();
+    let mut exchange_admin_service = program.vft_native_exchange_admin();
+    let mut vft_service = program.vft();
+
+    let listener_binding = program.vft_native_exchange_admin().listener();
+    let mut exchange_admin_events = listener_binding.listen().await.unwrap();
+
+    let bounds = MintBounds {
+        min_mint_value: VALUE.into(),
+        max_mint_value: (10 * VALUE).into(),
+    };
+
+    // Only admins set bounds, the minimum not exceeding the maximum.
+    let res = exchange_admin_service
+        .set_mint_bounds(bounds.clone())
+        .with_actor_id(BOB)
+        .await;
+    assert!(res.is_err());
+
+    let res = exchange_admin_service
+        .set_mint_bounds(MintBounds {
+            min_mint_value: (10 * VALUE + 1).into(),
+            max_mint_value: (10 * VALUE).into(),
+        })
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    let res = exchange_admin_service
+        .set_mint_bounds(bounds.clone())
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let (actor, event) = exchange_admin_events.next().await.unwrap();
+    assert_eq!(actor, pid);
+    assert_eq!(
+        event,
+        VftNativeExchangeAdminEvents::MintBoundsSet(bounds.clone())
+    );
+
+    let res = exchange_service.mint_bounds().await;
+    assert_ok!(res, bounds);
+
+    // Out-of-range deposits are refunded.
+    let program_balance = env.system().balance_of(pid);
+
+    for value in [VALUE - 1, 10 * VALUE + 1] {
+        let res = exchange_service
+            .mint()
+            .with_value(value)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, false);
+    }
+
+    assert_eq!(env.system().balance_of(pid), program_balance);
+
+    let res = vft_service.balance_of(BOB).await;
+    assert_ok!(res, U256::zero());
+
+    // Deposits within bounds are minted.
+    let res = exchange_service
+        .mint()
+        .with_value(10 * VALUE)
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    assert_eq!(env.system().balance_of(pid), program_balance + 10 * VALUE);
+
+    let res = vft_service.balance_of(BOB).await;
+    assert_ok!(res, U256::from(10 * VALUE));
+}
+
+#[tokio::test]
+async fn native_exchange_stats() {
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let (program, _env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut exchange_service = program.vft_native_exchange();
+
+    let stats = |minted: u128, burned: u128| ExchangeStats {
+        minted: minted.into(),
+        burned: burned.into(),
+    };
+
+    // Mints and burns of accounts are accumulated.
+    let res = exchange_service
+        .mint()
+        .with_value(3 * VALUE)
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    let res = exchange_service
+        .mint()
+        .with_value(VALUE)
+        .with_actor_id(CHARLIE)
+        .await;
+    assert_ok!(res, true);
+
+    let res = exchange_service.burn(VALUE.into()).with_actor_id(BOB).await;
+    assert_ok!(res, ());
+
+    let res = exchange_service.burn_all().with_actor_id(CHARLIE).await;
+    assert_ok!(res, ());
+
+    let res = exchange_service.exchange_stats(BOB).await;
+    assert_ok!(res, stats(3 * VALUE, VALUE));
+
+    let res = exchange_service.exchange_stats(CHARLIE).await;
+    assert_ok!(res, stats(VALUE, VALUE));
+
+    let res = exchange_service.exchange_stats(DAVE).await;
+    assert_ok!(res, stats(0, 0));
+
+    let res = exchange_service.exchange_totals().await;
+    assert_ok!(res, stats(4 * VALUE, 2 * VALUE));
+}
+
+#[tokio::test]
+async fn native_exchange_payouts() {
+    const VALUE: u128 = 1_000_000_000_000;
+
+    let (program, env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut exchange_service = program.vft_native_exchange();
+    let mut exchange_admin_service = program.vft_native_exchange_admin();
+
+    let res = exchange_service
+        .mint()
+        .with_value(10 * VALUE)
+        .with_actor_id(BOB)
+        .await;
+    assert_ok!(res, true);
+
+    // Payouts are tracked until replied.
+    let res = exchange_admin_service
+        .burn_from(BOB, VALUE.into())
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let sent_at = env.system().block_height();
+
+    let res = exchange_admin_service.stale_payouts(10).await;
+    assert_ok!(res, vec![]);
+
+    env.system().run_to_block(sent_at + 10);
+
+    let payouts = exchange_admin_service.stale_payouts(10).await.unwrap();
+    assert_eq!(payouts.len(), 1);
+
+    let (_, payout) = &payouts[0];
+    assert_eq!(payout.destination, BOB);
+    assert_eq!(payout.purpose, "payout");
+    assert!(payout.sent_at <= sent_at);
+}
+
+#[tokio::test]
+async fn auto_resume() {
+    let (program, env, _pid) = deploy_with_data(Default::default(), Default::default(), 0).await;
+
+    let mut vft_admin_service = program.vft_admin();
+
+    // Pauses resume after the scheduled number of blocks.
+    let res = vft_admin_service
+        .pause(None, Some(0))
+        .with_actor_id(ALICE)
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_admin_service
+        .pause(None, Some(10))
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let resume_at = vft_admin_service
+        .auto_resume_at()
+        .await
+        .unwrap()
+        .expect("resume is scheduled");
+
+    // Only the program resumes itself.
+    let res = vft_admin_service.auto_resume().with_actor_id(ALICE).await;
+    assert!(res.is_err());
+
+    env.system().run_to_block(resume_at - 1);
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, true);
+
+    env.system().run_to_block(resume_at + 1);
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, false);
+
+    let res = vft_admin_service.auto_resume_at().await;
+    assert_ok!(res, None);
+
+    // Resumes scheduled by earlier pauses don't resume later ones.
+    let res = vft_admin_service
+        .pause(None, Some(10))
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let res = vft_admin_service.resume().with_actor_id(ALICE).await;
+    assert_ok!(res, ());
+
+    let res = vft_admin_service
+        .pause(None, None)
+        .with_actor_id(ALICE)
+        .await;
+    assert_ok!(res, ());
+
+    let bn = env.system().block_height();
+    env.system().run_to_block(bn + 20);
+
+    let res = vft_admin_service.is_paused().await;
+    assert_ok!(res, true);
+}
+
+#[tokio::test]
+async fn session_keys() {
+    let allowances = Default::default();
+    let balances = vec![(BOB, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut session_keys_service = program.session_keys();
+    let vft_service = program.vft();
+
+    let listener_binding = program.session_keys().listener();
+    let mut session_keys_events = listener_binding.listen().await.unwrap();
+
+    let valid_from = env.system().block_height();
+    let valid_until = valid_from + 100;
+
+    // # Test case #1.
+    // Keys are registered for a block range and a scope.
+    {
+        let res = session_keys_service
+            .register(BOB, valid_from, valid_until, vec![TRANSFER.into()])
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = session_keys_service
+            .register(CHARLIE, valid_until, valid_from, vec![TRANSFER.into()])
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = session_keys_service
+            .register(CHARLIE, valid_from, valid_until, vec![TRANSFER.into()])
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = session_keys_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SessionKeysEvents::SessionProposed {
+                account: BOB,
+                key: CHARLIE,
+                valid_from,
+                valid_until,
+            }
+        );
+
+        // Proposed sessions take effect once accepted by keys.
+        let res = session_keys_service.session(CHARLIE).await;
+        assert_ok!(res, None);
+
+        let res = session_keys_service.accept(BOB).with_actor_id(DAVE).await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Session wasn't proposed to the key by the account",
+        );
+
+        let res = session_keys_service
+            .accept(BOB)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = session_keys_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SessionKeysEvents::SessionRegistered {
+                account: BOB,
+                key: CHARLIE,
+                valid_from,
+                valid_until,
+            }
+        );
+
+        let res = session_keys_service.proposal(CHARLIE, BOB).await;
+        assert_ok!(res, None);
+
+        let res = session_keys_service
+            .register(CHARLIE, valid_from, valid_until, vec![TRANSFER.into()])
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Key is registered by another account");
+
+        let res = session_keys_service.session(CHARLIE).await;
+        assert_ok!(
+            res,
+            Some(Session {
+                account: BOB,
+                valid_from,
+                valid_until,
+                scope: vec![TRANSFER.into()],
+            })
+        );
+
+        let res = session_keys_service.keys_of(BOB).await;
+        assert_ok!(res, vec![CHARLIE]);
+    }
+
+    // # Test case #2.
+    // Keys act on behalf of accounts within their scope only.
+    {
+        let res = session_keys_service
+            .transfer_as(BOB, DAVE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_service.balance_of(DAVE).await;
+        assert_ok!(res, 1_000.into());
+
+        let res = session_keys_service
+            .transfer_as(BOB, DAVE, 1_000.into())
+            .with_actor_id(DAVE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Message source is not a key allowed to act on behalf of the account",
+        );
+
+        let res = session_keys_service
+            .approve_as(BOB, CHARLIE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Message source is not a key allowed to act on behalf of the account",
+        );
+
+        // Accepting the session again replaces it.
+        let res = session_keys_service
+            .register(
+                CHARLIE,
+                valid_from,
+                valid_until,
+                vec![TRANSFER.into(), APPROVE.into()],
+            )
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = session_keys_service
+            .accept(BOB)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = session_keys_service
+            .approve_as(BOB, DAVE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = vft_service.allowance(BOB, DAVE).await;
+        assert_ok!(res, 1_000.into());
+    }
+
+    // # Test case #3.
+    // Keys expire after their block range and are pruned by anyone.
+    {
+        env.system().run_to_block(valid_until + 1);
+
+        let res = session_keys_service
+            .transfer_as(BOB, DAVE, 1_000.into())
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Message source is not a key allowed to act on behalf of the account",
+        );
+
+        let res = session_keys_service
+            .revoke(CHARLIE)
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = session_keys_service
+            .prune(vec![CHARLIE, DAVE])
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, 1);
+
+        let _ = session_keys_events.next().await.unwrap();
+        let _ = session_keys_events.next().await.unwrap();
+        let (actor, event) = session_keys_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SessionKeysEvents::SessionRevoked {
+                account: BOB,
+                key: CHARLIE,
+            }
+        );
+
+        let res = session_keys_service.session(CHARLIE).await;
+        assert_ok!(res, None);
+
+        let res = session_keys_service.keys_of(BOB).await;
+        assert_ok!(res, vec![]);
+    }
+
+    // # Test case #4.
+    // Keys are revoked by accounts.
+    {
+        let bn = env.system().block_height();
+
+        let res = session_keys_service
+            .register(CHARLIE, bn, bn + 100, vec![TRANSFER.into()])
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = session_keys_service
+            .accept(BOB)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = session_keys_service
+            .revoke(CHARLIE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, true);
+
+        let _ = session_keys_events.next().await.unwrap();
+        let _ = session_keys_events.next().await.unwrap();
+        let (actor, event) = session_keys_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            SessionKeysEvents::SessionRevoked {
+                account: BOB,
+                key: CHARLIE,
+            }
+        );
+
+        let res = session_keys_service.keys_of(BOB).await;
+        assert_ok!(res, vec![]);
+    }
+}
+
+#[tokio::test]
+async fn smart_account() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let account = deploy_program(&env, code_id, b"account", vec![], vec![], 0).await;
+    let aid = account.id();
+
+    let token = deploy_program(
+        &env,
+        code_id,
+        b"token",
+        vec![],
+        vec![(aid, 1_000.into())],
+        0,
+    )
+    .await;
+    let tid = token.id();
+
+    let mut smart_account_service = account.smart_account();
+    let mut session_keys_service = account.session_keys();
+    let vft_service = token.vft();
+
+    let listener_binding = account.smart_account().listener();
+    let mut smart_account_events = listener_binding.listen().await.unwrap();
+
+    let transfer = |to: ActorId, value: U256| ("Vft", "Transfer", (to, value)).encode();
+
+    // # Test case #1.
+    // Calls are executed by the owner and its session keys.
+    {
+        let res = smart_account_service.owner().await;
+        assert_ok!(res, ALICE);
+
+        let res = smart_account_service
+            .execute(tid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = smart_account_service
+            .execute(tid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ("Vft", "Transfer", true).encode());
+
+        let (actor, event) = smart_account_events.next().await.unwrap();
+        assert_eq!(actor, aid);
+        assert_eq!(
+            event,
+            SmartAccountEvents::Executed {
+                executor: ALICE,
+                destination: tid,
+                value: 0,
+            }
+        );
+
+        let res = vft_service.balance_of(BOB).await;
+        assert_ok!(res, 100.into());
+
+        let bn = env.system().block_height();
+
+        let res = session_keys_service
+            .register(CHARLIE, bn, bn + 100, vec![EXECUTE.into()])
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = session_keys_service
+            .accept(ALICE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = smart_account_service
+            .execute(tid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, ("Vft", "Transfer", true).encode());
+
+        let res = vft_service.balance_of(aid).await;
+        assert_ok!(res, 800.into());
+
+        // The program itself isn't executed on behalf of the owner.
+        let res = smart_account_service
+            .execute(aid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+    }
+
+    // # Test case #2.
+    // Guardians rotate the owner once the threshold is reached.
+    {
+        let res = smart_account_service
+            .set_guardians(vec![BOB, DAVE], 3)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let res = smart_account_service
+            .set_guardians(vec![BOB, DAVE], 2)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = smart_account_service
+            .set_guardians(vec![BOB, DAVE], 2)
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, ());
+
+        let res = smart_account_service
+            .approve_recovery(DAVE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Message source is not a guardian");
+
+        let res = smart_account_service
+            .approve_recovery(DAVE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, false);
+
+        let res = smart_account_service.recovery(DAVE).await.unwrap().unwrap();
+        assert_eq!(res.new_owner, DAVE);
+        assert_eq!(res.approvals, vec![BOB]);
+
+        // Proposing another owner keeps approvals of the others.
+        let res = smart_account_service
+            .approve_recovery(CHARLIE)
+            .with_actor_id(DAVE)
+            .await;
+        assert_ok!(res, false);
+
+        let res = smart_account_service.recoveries().await.unwrap();
+        assert_eq!(res.len(), 2);
+
+        let res = smart_account_service.recovery(DAVE).await.unwrap().unwrap();
+        assert_eq!(res.approvals, vec![BOB]);
+
+        // Owner cancels pending recoveries.
+        let res = smart_account_service
+            .cancel_recovery()
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = smart_account_service.recoveries().await;
+        assert_ok!(res, Vec::<Recovery>::new());
+
+        for guardian in [BOB, DAVE] {
+            let res = smart_account_service
+                .approve_recovery(DAVE)
+                .with_actor_id(guardian)
+                .await;
+            assert_ok!(res, guardian == DAVE);
+        }
+
+        let res = smart_account_service.owner().await;
+        assert_ok!(res, DAVE);
+
+        // Session keys of the previous owner are no longer valid.
+        let res = smart_account_service
+            .execute(tid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = smart_account_service
+            .execute(tid, transfer(BOB, 100.into()), 0)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+    }
+}
+
+#[tokio::test]
+async fn proxy() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let program = deploy_program(&env, code_id, b"proxy", vec![], vec![], 0).await;
+    let pid = program.id();
+
+    let implementation = deploy_program(
+        &env,
+        code_id,
+        b"implementation",
+        vec![],
+        vec![(BOB, 1_000.into())],
+        0,
+    )
+    .await;
+    let iid = implementation.id();
+
+    let mut proxy_service = program.proxy();
+    let mut scheduler_service = program.scheduler();
+
+    let listener_binding = program.proxy().listener();
+    let mut proxy_events = listener_binding.listen().await.unwrap();
+
+    let balance_of = ("Vft", "BalanceOf", BOB).encode();
+
+    // # Test case #1.
+    // Implementation is set only through the scheduler.
+    {
+        let res = proxy_service.forward(balance_of.clone()).await;
+        assert_str_panic(res.unwrap_err(), "Implementation is not set");
+
+        let res = proxy_service
+            .set_implementation(iid)
+            .with_actor_id(ALICE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let execute_at = env.system().block_height() + DEFAULT_MIN_SELF_DELAY + 1;
+
+        let res = scheduler_service
+            .schedule_self(execute_at, ("Proxy", "SetImplementation", iid).encode())
+            .with_actor_id(ALICE)
+            .await;
+        assert_ok!(res, 0);
+
+        let res = proxy_service.implementation().await;
+        assert_ok!(res, None);
+
+        env.system().run_to_block(execute_at);
+
+        let res = scheduler_service.execute_due(10).with_actor_id(BOB).await;
+        assert_ok!(res, 1);
+
+        let (actor, event) = proxy_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            ProxyEvents::Upgraded {
+                previous: ActorId::zero(),
+                implementation: iid,
+            }
+        );
+
+        let res = proxy_service.implementation().await;
+        assert_ok!(res, Some(iid));
+    }
+
+    // # Test case #2.
+    // Calls are forwarded along with the caller, so implementations
+    // not handling forwarded calls reject them.
+    {
+        let res = proxy_service.forward(balance_of).await;
+        assert_str_panic(res.unwrap_err(), "Forwarded call failed");
+    }
+}
+
+#[tokio::test]
+async fn token_registry() {
+    let (env, code_id, _gas_limit) = deploy_env();
+
+    let program = deploy_program(&env, code_id, b"registry", vec![], vec![], 0).await;
+    let pid = program.id();
+
+    let token = deploy_program(&env, code_id, b"token", vec![], vec![], 0).await;
+    let tid = token.id();
+
+    let mut access_control_service = program.access_control();
+    let mut token_registry_service = program.token_registry();
+
+    let listener_binding = program.token_registry().listener();
+    let mut token_registry_events = listener_binding.listen().await.unwrap();
+
+    let metadata = TokenMetadata {
+        name: "Token".into(),
+        symbol: "TKN".into(),
+        decimals: 12,
+    };
+
+    // # Test case #1.
+    // Deployers register tokens and their newer versions.
+    {
+        let res = token_registry_service
+            .register(tid, metadata.clone(), code_id, 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let (actor, event) = token_registry_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            TokenRegistryEvents::Registered {
+                program_id: tid,
+                deployer: BOB,
+                version: 1,
+            }
+        );
+
+        let res = token_registry_service
+            .register(tid, metadata.clone(), code_id, 2)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = token_registry_service
+            .register(tid, metadata.clone(), code_id, 1)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(
+            res.unwrap_err(),
+            "Version is not newer than the registered one",
+        );
+
+        let res = token_registry_service.token(tid).await.unwrap().unwrap();
+        assert_eq!(
+            res,
+            TokenInfo {
+                deployer: BOB,
+                metadata: metadata.clone(),
+                code_id,
+                version: 1,
+                registered_at: res.registered_at,
+                verified: false,
+            }
+        );
+
+        let res = token_registry_service.tokens_of(BOB, 0, 10).await;
+        assert_ok!(res, vec![tid]);
+    }
+
+    // # Test case #2.
+    // Curators verify and remove tokens.
+    {
+        let res = token_registry_service
+            .verify(tid, true)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_err());
+
+        access_control_service
+            .grant_role(CURATOR_ROLE, CHARLIE)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = token_registry_service.tokens(0, 10, true).await;
+        assert_ok!(res, vec![]);
+
+        let res = token_registry_service
+            .verify(tid, true)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = token_registry_service
+            .verify(DAVE, true)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Token is not registered");
+
+        let res = token_registry_service.tokens(0, 10, true).await.unwrap();
+        assert_eq!(
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![tid]
+        );
+
+        // Updates drop the verification.
+        let res = token_registry_service
+            .register(tid, metadata, code_id, 2)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = token_registry_service.tokens(0, 10, true).await;
+        assert_ok!(res, vec![]);
+
+        let res = token_registry_service
+            .remove(tid)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_ok!(res, true);
+
+        let res = token_registry_service.token(tid).await;
+        assert_ok!(res, None);
+
+        let res = token_registry_service.tokens_of(BOB, 0, 10).await;
+        assert_ok!(res, vec![]);
+    }
+}
+
+#[tokio::test]
+async fn name_registry() {
+    use awesome_sails_client::{
+        AwesomeSailsClient as _, AwesomeSailsClientProgram, name_registry::NameRegistry as _,
+        vft::Vft as _,
+    };
+    use sails_rs::client::Actor;
+
+    let balances = vec![(ALICE, U256::exp10(MAGIC))];
+
+    let (program, env, pid) = deploy_with_data(Default::default(), balances, 0).await;
+
+    let mut name_registry_service = program.name_registry();
+
+    let listener_binding = program.name_registry().listener();
+    let mut name_registry_events = listener_binding.listen().await.unwrap();
+
+    // # Test case #1.
+    // Names are registered by anyone, until they expire.
+    {
+        let res = name_registry_service
+            .register("Bob".into(), BOB, 10)
+            .with_actor_id(BOB)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect input argument");
+
+        let expires_at = name_registry_service
+            .register("bob".into(), BOB, 100)
+            .with_actor_id(BOB)
+            .await
+            .unwrap();
+
+        let (actor, event) = name_registry_events.next().await.unwrap();
+        assert_eq!(actor, pid);
+        assert_eq!(
+            event,
+            NameRegistryEvents::Registered {
+                name: "bob".into(),
+                owner: BOB,
+                target: BOB,
+                expires_at,
+            }
+        );
+
+        let res = name_registry_service
+            .register("bob".into(), CHARLIE, 10)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Name is taken");
+
+        let res = name_registry_service.resolve("bob".into()).await;
+        assert_ok!(res, Some(BOB));
+    }
+
+    // # Test case #2.
+    // Names are resolved by the SDK, e.g. for transfers to names.
+    {
+        let sdk = Actor::<AwesomeSailsClientProgram, _>::new(env.clone(), pid);
+
+        let to = sdk
+            .name_registry()
+            .resolve("bob".into())
+            .await
+            .unwrap()
+            .expect("name is registered");
+
+        let res = sdk.vft().transfer(to, 1_000.into()).await;
+        assert_ok!(res, true);
+
+        let res = sdk.vft().balance_of(BOB).await;
+        assert_ok!(res, 1_000.into());
+    }
+
+    // # Test case #3.
+    // Owners point, renew and transfer their names.
+    {
+        let res = name_registry_service
+            .set_target("bob".into(), DAVE)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "incorrect message origin");
+
+        let res = name_registry_service
+            .set_target("bob".into(), DAVE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = name_registry_service
+            .renew("bob".into(), 50)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, expires_at + 50);
+
+        let res = name_registry_service
+            .transfer("bob".into(), CHARLIE)
+            .with_actor_id(BOB)
+            .await;
+        assert_ok!(res, ());
+
+        let res = name_registry_service.record("bob".into()).await;
+        assert_ok!(
+            res,
+            Some(NameRecord {
+                owner: CHARLIE,
+                target: DAVE,
+                expires_at: expires_at + 50,
+            })
+        );
+
+        let res = name_registry_service.names_of(BOB, 0, 10).await;
+        assert_ok!(res, Vec::<String>::new());
+
+        let res = name_registry_service.names_of(CHARLIE, 0, 10).await;
+        assert_ok!(res, vec!["bob".to_string()]);
+    }
+
+    // # Test case #4.
+    // Expired names resolve to nothing and are registered again.
+    {
+        env.system().run_to_block(expires_at + 50);
+
+        let res = name_registry_service.resolve("bob".into()).await;
+        assert_ok!(res, None);
+
+        let res = name_registry_service
+            .renew("bob".into(), 5)
+            .with_actor_id(CHARLIE)
+            .await;
+        assert_str_panic(res.unwrap_err(), "Name is not registered or expired");
+
+        let res = name_registry_service
+            .register("bob".into(), BOB, 10)
+            .with_actor_id(BOB)
+            .await;
+        assert!(res.is_ok());
+
+        let res = name_registry_service.names_of(CHARLIE, 0, 10).await;
+        assert_ok!(res, Vec::<String>::new());
+    }
+}
+
+#[tokio::test]
+async fn metrics() {
+    let allowances = vec![(ALICE, BOB, U256::exp10(MAGIC), BN)];
+    let balances = vec![(ALICE, U256::exp10(MAGIC)), (BOB, U256::one())];
+
+    let (program, _env, _pid) = deploy_with_data(allowances, balances, 0).await;
+
+    let mut access_control_service = program.access_control();
+    let metrics_service = program.metrics();
+
+    // # Test case #1.
+    // Maps report their entries within allocated capacity.
+    {
+        let res = metrics_service.balances_metrics().await.unwrap();
+        assert_eq!(res.entries, 2);
+        assert!(res.allocated_shards >= 1 && res.allocated_shards <= res.shards);
+        assert!(res.capacity >= res.entries && res.capacity <= res.max_capacity);
+        assert!(res.heap_bytes > 0);
+
+        let res = metrics_service.allowances_metrics().await.unwrap();
+        assert_eq!(res.entries, 1);
+    }
+
+    // # Test case #2.
+    // Roles and privileged calls are counted.
+    {
+        let before = metrics_service.metrics().await.unwrap();
+
+        let res = access_control_service.get_role_count().await;
+        assert_ok!(res, before.roles);
+
+        let res = access_control_service.get_all_member_count().await;
+        assert_ok!(res, before.members);
+
+        access_control_service
+            .grant_role(PAUSER_ROLE, BOB)
+            .with_actor_id(ALICE)
+            .await
+            .unwrap();
+
+        let res = metrics_service.metrics().await.unwrap();
+        assert_eq!(res.audit_seq, before.audit_seq + 1);
+        assert_eq!(res.members, before.members + 1);
+        assert_eq!(res.balances, before.balances);
+    }
+}
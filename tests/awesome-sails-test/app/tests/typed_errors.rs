@@ -0,0 +1,192 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tests of the program built with the `typed-errors` feature.
+//!
+//! The generated client expects untyped replies, so methods are called with raw messages.
+
+#![cfg(feature = "typed-errors")]
+
+use awesome_sails::vft_batch::Call;
+use awesome_sails_utils::error::{Error, decode_error, decode_result};
+use sails_rs::{
+    ActorId, U256,
+    gtest::{Program, System},
+    scale_codec::{Decode, Encode},
+};
+
+#[cfg(debug_assertions)]
+const WASM_PATH: &str = "../../../target/wasm32-gear/debug/awesome_sails_test_app.opt.wasm";
+#[cfg(not(debug_assertions))]
+const WASM_PATH: &str = "../../../target/wasm32-gear/release/awesome_sails_test_app.opt.wasm";
+
+const ALICE: ActorId = ActorId::new([42; 32]);
+const BOB: ActorId = ActorId::new([43; 32]);
+const CHARLIE: ActorId = ActorId::new([44; 32]);
+
+/// Sends a raw message to the method of the service, returning whether it succeeded
+/// and its reply payload without the route prefix.
+fn call(
+    system: &System,
+    program: &Program<'_>,
+    service: &str,
+    method: &str,
+    args: impl Encode,
+) -> (bool, Vec<u8>) {
+    let prefix = (service, method).encode();
+    let message_id = program.send_bytes(ALICE, [prefix.clone(), args.encode()].concat());
+    let result = system.run_next_block();
+
+    let payload = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(message_id))
+        .expect("no reply")
+        .payload();
+
+    if result.succeed.contains(&message_id) {
+        let payload = payload.strip_prefix(prefix.as_slice()).expect("bad prefix");
+
+        (true, payload.to_vec())
+    } else {
+        (false, payload.to_vec())
+    }
+}
+
+/// Calls the method, expecting it to succeed.
+fn call_ok<R: Decode>(
+    system: &System,
+    program: &Program<'_>,
+    service: &str,
+    method: &str,
+    args: impl Encode,
+) -> R {
+    let (succeed, payload) = call(system, program, service, method, args);
+    assert!(succeed, "{service}/{method} failed");
+
+    decode_result::<R>(&payload)
+        .expect("failed to decode reply")
+        .expect("method replied with error")
+}
+
+fn deploy(system: &System, balances: Vec<(ActorId, U256)>) -> Program<'_> {
+    system.mint_to(ALICE, 100_000_000_000_000_000);
+
+    let code = std::fs::read(WASM_PATH).unwrap();
+    let program = Program::from_binary_with_id(system, ActorId::from(1), &code);
+
+    program.send_bytes(ALICE, "New".encode());
+    system.run_next_block();
+
+    for method in ["AllocateNextBalancesShard", "AllocateNextAllowancesShard"] {
+        while call_ok::<bool>(system, &program, "VftExtension", method, ()) {}
+    }
+
+    // The test service isn't built with typed errors.
+    let (succeed, _) = call(
+        system,
+        &program,
+        "Test",
+        "Set",
+        (Vec::<(ActorId, ActorId, U256, u32)>::new(), balances, 0u32),
+    );
+    assert!(succeed, "failed to set data");
+
+    program
+}
+
+fn balance_of(system: &System, program: &Program<'_>, account: ActorId) -> U256 {
+    call_ok(system, program, "Vft", "BalanceOf", account)
+}
+
+#[test]
+fn failed_command_is_reverted_with_typed_error() {
+    let system = System::new();
+    let program = deploy(
+        &system,
+        vec![(BOB, U256::from(1_000)), (CHARLIE, U256::from(1_000))],
+    );
+
+    // The first burn succeeds, the second one fails: the whole batch is reverted.
+    let (succeed, payload) = call(
+        &system,
+        &program,
+        "VftAdmin",
+        "BurnBatch",
+        vec![(BOB, U256::from(500)), (CHARLIE, U256::from(1_001))],
+    );
+
+    assert!(!succeed);
+    assert_eq!(
+        decode_error(&payload).expect("failed to decode error"),
+        Error::with_code(110, "insufficient balance")
+    );
+    assert_eq!(balance_of(&system, &program, BOB), U256::from(1_000));
+    assert_eq!(balance_of(&system, &program, CHARLIE), U256::from(1_000));
+
+    // Successful command replies with `Ok`.
+    call_ok::<()>(
+        &system,
+        &program,
+        "VftAdmin",
+        "BurnBatch",
+        vec![(BOB, U256::from(500)), (CHARLIE, U256::from(1_000))],
+    );
+
+    assert_eq!(balance_of(&system, &program, BOB), U256::from(500));
+    assert_eq!(balance_of(&system, &program, CHARLIE), U256::zero());
+}
+
+#[test]
+fn failed_batch_is_reverted() {
+    let system = System::new();
+    let program = deploy(&system, Vec::new());
+
+    // The last call fails after the previous ones succeeded: the whole batch is reverted.
+    let (succeed, payload) = call(
+        &system,
+        &program,
+        "VftBatch",
+        "Batch",
+        vec![
+            Call::Mint {
+                to: ALICE,
+                value: U256::from(1_000),
+            },
+            Call::Transfer {
+                to: BOB,
+                value: U256::from(500),
+            },
+            Call::Transfer {
+                to: CHARLIE,
+                value: U256::from(1_000),
+            },
+        ],
+    );
+
+    // The failing call panics with its typed error.
+    assert!(!succeed);
+    assert_eq!(
+        decode_error(&payload).expect("failed to decode error"),
+        Error::with_code(110, "insufficient balance")
+    );
+
+    for account in [ALICE, BOB, CHARLIE] {
+        assert_eq!(balance_of(&system, &program, account), U256::zero());
+    }
+}
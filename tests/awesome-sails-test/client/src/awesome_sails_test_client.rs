@@ -9,11 +9,33 @@ pub trait AwesomeSailsTestClient {
     fn access_control(
         &self,
     ) -> sails_rs::client::Service<access_control::AccessControlImpl, Self::Env>;
+    fn amm(&self) -> sails_rs::client::Service<amm::AmmImpl, Self::Env>;
+    fn counter(&self) -> sails_rs::client::Service<counter::CounterImpl, Self::Env>;
+    fn dutch_auction(&self) -> sails_rs::client::Service<dutch_auction::DutchAuctionImpl, Self::Env>;
+    fn eth_binding(&self) -> sails_rs::client::Service<eth_binding::EthBindingImpl, Self::Env>;
+    fn fee_pool(&self) -> sails_rs::client::Service<fee_pool::FeePoolImpl, Self::Env>;
+    fn htlc(&self) -> sails_rs::client::Service<htlc::HtlcImpl, Self::Env>;
+    fn lending(&self) -> sails_rs::client::Service<lending::LendingImpl, Self::Env>;
+    fn lp_vft(&self) -> sails_rs::client::Service<lp_vft::LpVftImpl, Self::Env>;
+    fn metrics(&self) -> sails_rs::client::Service<metrics::MetricsImpl, Self::Env>;
+    fn name_registry(&self) -> sails_rs::client::Service<name_registry::NameRegistryImpl, Self::Env>;
+    fn order_book(&self) -> sails_rs::client::Service<order_book::OrderBookImpl, Self::Env>;
+    fn proxy(&self) -> sails_rs::client::Service<proxy::ProxyImpl, Self::Env>;
+    fn raffle(&self) -> sails_rs::client::Service<raffle::RaffleImpl, Self::Env>;
+    fn scheduler(&self) -> sails_rs::client::Service<scheduler::SchedulerImpl, Self::Env>;
+    fn session_keys(&self) -> sails_rs::client::Service<session_keys::SessionKeysImpl, Self::Env>;
+    fn smart_account(&self) -> sails_rs::client::Service<smart_account::SmartAccountImpl, Self::Env>;
+    fn token_registry(&self) -> sails_rs::client::Service<token_registry::TokenRegistryImpl, Self::Env>;
+    fn treasury(&self) -> sails_rs::client::Service<treasury::TreasuryImpl, Self::Env>;
     fn vft(&self) -> sails_rs::client::Service<vft::VftImpl, Self::Env>;
     fn vft_admin(&self) -> sails_rs::client::Service<vft_admin::VftAdminImpl, Self::Env>;
+    fn vft_batch(&self) -> sails_rs::client::Service<vft_batch::VftBatchImpl, Self::Env>;
+    fn vft_bonding_curve(&self) -> sails_rs::client::Service<vft_bonding_curve::VftBondingCurveImpl, Self::Env>;
+    fn vft_bridge_adapter(&self) -> sails_rs::client::Service<vft_bridge_adapter::VftBridgeAdapterImpl, Self::Env>;
     fn vft_extension(
         &self,
     ) -> sails_rs::client::Service<vft_extension::VftExtensionImpl, Self::Env>;
+    fn vft_inflation(&self) -> sails_rs::client::Service<vft_inflation::VftInflationImpl, Self::Env>;
     fn vft_metadata(&self) -> sails_rs::client::Service<vft_metadata::VftMetadataImpl, Self::Env>;
     fn vft_native_exchange(
         &self,
@@ -34,17 +56,83 @@ impl<E: sails_rs::client::GearEnv> AwesomeSailsTestClient
     ) -> sails_rs::client::Service<access_control::AccessControlImpl, Self::Env> {
         self.service(stringify!(AccessControl))
     }
+    fn amm(&self) -> sails_rs::client::Service<amm::AmmImpl, Self::Env> {
+        self.service(stringify!(Amm))
+    }
+    fn counter(&self) -> sails_rs::client::Service<counter::CounterImpl, Self::Env> {
+        self.service(stringify!(Counter))
+    }
+    fn dutch_auction(&self) -> sails_rs::client::Service<dutch_auction::DutchAuctionImpl, Self::Env> {
+        self.service(stringify!(DutchAuction))
+    }
+    fn eth_binding(&self) -> sails_rs::client::Service<eth_binding::EthBindingImpl, Self::Env> {
+        self.service(stringify!(EthBinding))
+    }
+    fn fee_pool(&self) -> sails_rs::client::Service<fee_pool::FeePoolImpl, Self::Env> {
+        self.service(stringify!(FeePool))
+    }
+    fn htlc(&self) -> sails_rs::client::Service<htlc::HtlcImpl, Self::Env> {
+        self.service(stringify!(Htlc))
+    }
+    fn lending(&self) -> sails_rs::client::Service<lending::LendingImpl, Self::Env> {
+        self.service(stringify!(Lending))
+    }
+    fn lp_vft(&self) -> sails_rs::client::Service<lp_vft::LpVftImpl, Self::Env> {
+        self.service(stringify!(LpVft))
+    }
+    fn metrics(&self) -> sails_rs::client::Service<metrics::MetricsImpl, Self::Env> {
+        self.service(stringify!(Metrics))
+    }
+    fn name_registry(&self) -> sails_rs::client::Service<name_registry::NameRegistryImpl, Self::Env> {
+        self.service(stringify!(NameRegistry))
+    }
+    fn order_book(&self) -> sails_rs::client::Service<order_book::OrderBookImpl, Self::Env> {
+        self.service(stringify!(OrderBook))
+    }
+    fn proxy(&self) -> sails_rs::client::Service<proxy::ProxyImpl, Self::Env> {
+        self.service(stringify!(Proxy))
+    }
+    fn raffle(&self) -> sails_rs::client::Service<raffle::RaffleImpl, Self::Env> {
+        self.service(stringify!(Raffle))
+    }
+    fn scheduler(&self) -> sails_rs::client::Service<scheduler::SchedulerImpl, Self::Env> {
+        self.service(stringify!(Scheduler))
+    }
+    fn session_keys(&self) -> sails_rs::client::Service<session_keys::SessionKeysImpl, Self::Env> {
+        self.service(stringify!(SessionKeys))
+    }
+    fn smart_account(&self) -> sails_rs::client::Service<smart_account::SmartAccountImpl, Self::Env> {
+        self.service(stringify!(SmartAccount))
+    }
+    fn token_registry(&self) -> sails_rs::client::Service<token_registry::TokenRegistryImpl, Self::Env> {
+        self.service(stringify!(TokenRegistry))
+    }
+    fn treasury(&self) -> sails_rs::client::Service<treasury::TreasuryImpl, Self::Env> {
+        self.service(stringify!(Treasury))
+    }
     fn vft(&self) -> sails_rs::client::Service<vft::VftImpl, Self::Env> {
         self.service(stringify!(Vft))
     }
     fn vft_admin(&self) -> sails_rs::client::Service<vft_admin::VftAdminImpl, Self::Env> {
         self.service(stringify!(VftAdmin))
     }
+    fn vft_batch(&self) -> sails_rs::client::Service<vft_batch::VftBatchImpl, Self::Env> {
+        self.service(stringify!(VftBatch))
+    }
+    fn vft_bonding_curve(&self) -> sails_rs::client::Service<vft_bonding_curve::VftBondingCurveImpl, Self::Env> {
+        self.service(stringify!(VftBondingCurve))
+    }
+    fn vft_bridge_adapter(&self) -> sails_rs::client::Service<vft_bridge_adapter::VftBridgeAdapterImpl, Self::Env> {
+        self.service(stringify!(VftBridgeAdapter))
+    }
     fn vft_extension(
         &self,
     ) -> sails_rs::client::Service<vft_extension::VftExtensionImpl, Self::Env> {
         self.service(stringify!(VftExtension))
     }
+    fn vft_inflation(&self) -> sails_rs::client::Service<vft_inflation::VftInflationImpl, Self::Env> {
+        self.service(stringify!(VftInflation))
+    }
     fn vft_metadata(&self) -> sails_rs::client::Service<vft_metadata::VftMetadataImpl, Self::Env> {
         self.service(stringify!(VftMetadata))
     }
@@ -67,6 +155,13 @@ pub trait AwesomeSailsTestClientCtors {
     fn new(
         self,
     ) -> sails_rs::client::PendingCtor<AwesomeSailsTestClientProgram, io::New, Self::Env>;
+    /// Deploys the program with the configuration, failing if it's invalid.
+    #[allow(clippy::new_ret_no_self)]
+    #[allow(clippy::wrong_self_convention)]
+    fn with_config(
+        self,
+        config: InitConfig,
+    ) -> sails_rs::client::PendingCtor<AwesomeSailsTestClientProgram, io::WithConfig, Self::Env>;
 }
 impl<E: sails_rs::client::GearEnv> AwesomeSailsTestClientCtors
     for sails_rs::client::Deployment<AwesomeSailsTestClientProgram, E>
@@ -77,11 +172,19 @@ impl<E: sails_rs::client::GearEnv> AwesomeSailsTestClientCtors
     ) -> sails_rs::client::PendingCtor<AwesomeSailsTestClientProgram, io::New, Self::Env> {
         self.pending_ctor(())
     }
+    fn with_config(
+        self,
+        config: InitConfig,
+    ) -> sails_rs::client::PendingCtor<AwesomeSailsTestClientProgram, io::WithConfig, Self::Env>
+    {
+        self.pending_ctor((config,))
+    }
 }
 
 pub mod io {
     use super::*;
     sails_rs::io_struct_impl!(New () -> ());
+    sails_rs::io_struct_impl!(WithConfig (config: super::InitConfig) -> ());
 }
 
 pub mod test {
@@ -118,14 +221,93 @@ pub mod access_control {
     use super::*;
     pub trait AccessControl {
         type Env: sails_rs::client::GearEnv;
+        /// Cancels the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+        ///
+        /// Emits an `AdminRenounceCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be `account_id`.
+        /// - the renounce must be pending.
+        fn cancel_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelAdminRenounce, Self::Env>;
+        /// Discards the recovery proposal of `new_admin`.
+        ///
+        /// Emits a `RecoveryCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - the proposal must exist.
+        fn cancel_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env>;
+        /// Cancels a scheduled grant of `role_id` to `target_account`.
+        ///
+        /// Emits a `RoleGrantCancelled` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - the grant must be pending.
+        fn cancel_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRoleGrant, Self::Env>;
+        /// Completes the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`.
+        ///
+        /// Emits a `RoleRevoked` event. The renounce is discarded once `account_id` loses
+        /// `DEFAULT_ADMIN_ROLE` otherwise, e.g. on recovery.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be `account_id`.
+        /// - the renounce must be pending and its delay must have passed.
+        /// - the caller must not be the last super admin, unless admin-less programs are allowed.
+        fn execute_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteAdminRenounce, Self::Env>;
+        /// Transfers `DEFAULT_ADMIN_ROLE` to `new_admin`, revoking it from all other holders
+        /// and discarding all recovery proposals.
+        ///
+        /// Emits a `RoleRevoked` event for each previous super admin, a `RoleGranted` event
+        /// and a `RecoveryExecuted` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the proposal must have reached the threshold and its delay must have passed.
+        fn execute_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRecovery, Self::Env>;
+        /// Activates a scheduled grant of `role_id` to `target_account`.
+        ///
+        /// Emits a `RoleGranted` event on behalf of the account that scheduled it.
+        ///
+        /// Requirements:
+        ///
+        /// - the grant must be pending and its delay must have passed.
+        /// - `role_id` must not be locked.
+        fn execute_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRoleGrant, Self::Env>;
         /// Grants `role_id` to `target_account`.
         ///
         /// If `target_account` had not been already granted `role_id`, emits a `RoleGranted`
-        /// event.
+        /// event. If `role_id` has a grant delay, the grant is scheduled instead and a
+        /// `RoleGrantScheduled` event is emitted.
         ///
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be locked.
         fn grant_role(
             &mut self,
             role_id: [u8; 32],
@@ -134,16 +316,52 @@ pub mod access_control {
         /// Grants `role_ids` to `target_account`.
         ///
         /// If `target_account` had not been already granted any of the `role_ids`,
-        /// emits a `RoleGranted` event for each newly granted role.
+        /// emits a `RoleGranted` event for each newly granted role. Roles with a grant
+        /// delay are scheduled as in `grant_role`.
         ///
         /// Requirements:
         ///
         /// - the caller must have the admin role for all specified `role_ids`.
+        /// - none of the `role_ids` may be locked.
         fn grant_roles_batch(
             &mut self,
             role_ids: Vec<[u8; 32]>,
             target_account: ActorId,
         ) -> sails_rs::client::PendingCall<io::GrantRolesBatch, Self::Env>;
+        /// Announces the grant of `DEFAULT_ADMIN_ROLE` made by `grant_initial_admin`,
+        /// emitting its `RoleGranted` event with the initial admin as the sender.
+        ///
+        /// Returns `false` if there is no grant to announce, e.g. it's already announced.
+        /// Anyone may call it, as it only reports the grant made.
+        fn init_access_control(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::InitAccessControl, Self::Env>;
+        /// Locks `role_id` irreversibly: no new members may be granted, and its admin role
+        /// and parent role may no longer change.
+        ///
+        /// Emits a `RoleLocked` event, unless the role is already locked.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so it stays recoverable.
+        /// - `role_id` must have no parent role, holders of which would hold it as well.
+        fn lock_role(
+            &mut self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::LockRole, Self::Env>;
+        /// Approves `new_admin` as the recovered super admin on behalf of the calling guardian.
+        ///
+        /// Emits a `RecoveryApproved` event, followed by a `RecoveryScheduled` event once the
+        /// approvals reach the threshold.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be a guardian.
+        fn propose_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ProposeRecovery, Self::Env>;
         /// Revokes `role_id` from the calling account.
         ///
         /// Roles are often managed via `grant_role` and `revoke_role`: this function's
@@ -151,16 +369,36 @@ pub mod access_control {
         /// if they are compromised (such as when a trusted device is misplaced).
         ///
         /// If the calling account had been granted `role_id`, emits a `RoleRevoked`
-        /// event.
+        /// event. Renouncing `DEFAULT_ADMIN_ROLE` is scheduled instead and an
+        /// `AdminRenounceScheduled` event is emitted, see `execute_admin_renounce`.
         ///
         /// Requirements:
         ///
         /// - the caller must be `account_id`.
+        /// - the caller must not be the last super admin, unless admin-less programs are allowed.
         fn renounce_role(
             &mut self,
             role_id: [u8; 32],
             account_id: ActorId,
         ) -> sails_rs::client::PendingCall<io::RenounceRole, Self::Env>;
+        /// Replaces `old_account` with `new_account` among members of `role_id` at once,
+        /// e.g. to rotate a key without a window of the role having no members.
+        ///
+        /// Emits a `RoleGranted` event for `new_account`, unless it's already a member,
+        /// followed by a `RoleRevoked` event for `old_account`.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `role_id`'s admin role.
+        /// - `old_account` must be a member of `role_id`, other than `new_account`.
+        /// - `role_id` must have no grant delay, as the replacement would bypass it.
+        /// - `role_id` must not be locked.
+        fn replace_role_member(
+            &mut self,
+            role_id: [u8; 32],
+            old_account: ActorId,
+            new_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ReplaceRoleMember, Self::Env>;
         /// Revokes `role_id` from `target_account`.
         ///
         /// If `target_account` had been granted `role_id`, emits a `RoleRevoked` event.
@@ -168,6 +406,8 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `target_account` must not be the last super admin if `role_id` is
+        ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
         fn revoke_role(
             &mut self,
             role_id: [u8; 32],
@@ -181,11 +421,69 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have the admin role for all specified `role_ids`.
+        /// - `target_account` must not be the last super admin if `role_ids` contain
+        ///   `DEFAULT_ADMIN_ROLE`, unless admin-less programs are allowed.
         fn revoke_roles_batch(
             &mut self,
             role_ids: Vec<[u8; 32]>,
             target_account: ActorId,
         ) -> sails_rs::client::PendingCall<io::RevokeRolesBatch, Self::Env>;
+        /// Sets the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+        ///
+        /// Renounces already scheduled keep their original delay.
+        ///
+        /// Emits an `AdminRenounceDelayChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_admin_renounce_delay(
+            &mut self,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetAdminRenounceDelay, Self::Env>;
+        /// Allows or forbids the last super admin to renounce or be revoked `DEFAULT_ADMIN_ROLE`,
+        /// leaving the program without super admins.
+        ///
+        /// Emits an `AllowAdminLessChanged` event if the setting changed.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_allow_admin_less(
+            &mut self,
+            allowed: bool,
+        ) -> sails_rs::client::PendingCall<io::SetAllowAdminLess, Self::Env>;
+        /// Sets the max amount of items enumeration queries reply with at once.
+        ///
+        /// Queries requesting more items, or all items when there are more of them,
+        /// fail with `PageTooLarge` instead of running out of gas.
+        ///
+        /// Emits a `MaxPageSizeChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `max_page_size` must be non-zero.
+        fn set_max_page_size(
+            &mut self,
+            max_page_size: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env>;
+        /// Replaces the recovery guardians, discarding all recovery proposals.
+        ///
+        /// Passing no guardians disables recovery.
+        ///
+        /// Emits a `RecoveryConfigChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `threshold` must be within `1..=guardians.len()`, or zero if there are no guardians.
+        fn set_recovery_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRecoveryGuardians, Self::Env>;
         /// Sets `new_admin_role_id` as the admin role for `role_id`.
         ///
         /// Emits a `RoleAdminChanged` event.
@@ -193,11 +491,86 @@ pub mod access_control {
         /// Requirements:
         ///
         /// - the caller must have `role_id`'s admin role.
+        /// - `role_id` must not be locked.
         fn set_role_admin(
             &mut self,
             role_id: [u8; 32],
             new_admin_role_id: [u8; 32],
         ) -> sails_rs::client::PendingCall<io::SetRoleAdmin, Self::Env>;
+        /// Sets the number of blocks grants of `role_id` are delayed for.
+        ///
+        /// Grants already scheduled keep their original delay.
+        ///
+        /// Emits a `RoleGrantDelayChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_role_grant_delay(
+            &mut self,
+            role_id: [u8; 32],
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRoleGrantDelay, Self::Env>;
+        /// Sets `parent_role_id` as the parent of `role_id`, or removes the parent if `None`.
+        ///
+        /// Holders of the parent role are considered to hold `role_id` as well.
+        ///
+        /// Emits a `RoleParentChanged` event.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `role_id` must not be `DEFAULT_ADMIN_ROLE`, so only accounts granted it are
+        ///   super admins.
+        /// - the new parent must not make `role_id` its own ancestor.
+        /// - `role_id` must not be locked.
+        fn set_role_parent(
+            &mut self,
+            role_id: [u8; 32],
+            parent_role_id: Option<[u8; 32]>,
+        ) -> sails_rs::client::PendingCall<io::SetRoleParent, Self::Env>;
+        /// Restricts heavy enumeration exports to `VIEWER_ROLE` holders, or lifts the restriction.
+        ///
+        /// Emits a `ViewerGateChanged` event if the gate changed.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        fn set_viewer_gate(
+            &mut self,
+            enabled: bool,
+        ) -> sails_rs::client::PendingCall<io::SetViewerGate, Self::Env>;
+        /// Returns up to `len` audit log entries of privileged calls, starting from `cursor`
+        /// or the oldest entry kept.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env>;
+        /// Returns the number of blocks renouncing `DEFAULT_ADMIN_ROLE` is delayed for.
+        fn get_admin_renounce_delay(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAdminRenounceDelay, Self::Env>;
+        /// Returns the number of distinct accounts holding any role.
+        fn get_all_member_count(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllMemberCount, Self::Env>;
+        /// Returns a list of distinct accounts holding any role with pagination.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn get_all_members(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetAllMembers, Self::Env>;
+        /// Returns whether the last super admin may give up `DEFAULT_ADMIN_ROLE`.
+        fn get_allow_admin_less(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllowAdminLess, Self::Env>;
+        /// Returns the max amount of items enumeration queries reply with at once.
+        fn get_max_page_size(&self)
+        -> sails_rs::client::PendingCall<io::GetMaxPageSize, Self::Env>;
         /// Returns the number of roles assigned to the specified member.
         fn get_member_role_count(
             &self,
@@ -209,6 +582,34 @@ pub mod access_control {
             member_id: ActorId,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetMemberRoles, Self::Env>;
+        /// Returns a list of `(role_id, member_id)` pairs across all roles with pagination.
+        ///
+        /// Requires `VIEWER_ROLE` once the viewer gate is enabled.
+        fn get_memberships(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetMemberships, Self::Env>;
+        /// Returns the block the pending renounce of `DEFAULT_ADMIN_ROLE` by `account_id`
+        /// is ready at, if any.
+        fn get_pending_admin_renounce(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingAdminRenounce, Self::Env>;
+        /// Returns the pending grant of `role_id` to `account_id`, if any.
+        fn get_pending_grant(
+            &self,
+            role_id: [u8; 32],
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingGrant, Self::Env>;
+        /// Returns the guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+        fn get_recovery_config(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryConfig, Self::Env>;
+        /// Returns guardians' approvals of `new_admin` as the recovered super admin, if any.
+        fn get_recovery_proposal(
+            &self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryProposal, Self::Env>;
         /// Returns the admin role ID that controls `role_id`.
         fn get_role_admin(
             &self,
@@ -216,6 +617,11 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetRoleAdmin, Self::Env>;
         /// Returns the number of roles in the system.
         fn get_role_count(&self) -> sails_rs::client::PendingCall<io::GetRoleCount, Self::Env>;
+        /// Returns the number of blocks a grant of `role_id` is delayed for.
+        fn get_role_grant_delay(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleGrantDelay, Self::Env>;
         /// Returns the number of members in the specified role.
         fn get_role_member_count(
             &self,
@@ -227,23 +633,87 @@ pub mod access_control {
             role_id: [u8; 32],
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoleMembers, Self::Env>;
+        /// Returns the parent role `role_id` inherits from, if any.
+        fn get_role_parent(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleParent, Self::Env>;
         /// Returns a list of role IDs with pagination.
         fn get_roles(
             &self,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoles, Self::Env>;
-        /// Returns `true` if `account_id` has been granted `role_id`.
+        /// Returns whether heavy enumeration exports are restricted to `VIEWER_ROLE` holders.
+        fn get_viewer_gate(&self) -> sails_rs::client::PendingCall<io::GetViewerGate, Self::Env>;
+        /// Returns `true` if `account_id` has been granted `role_id` or inherits it
+        /// from a parent role.
         fn has_role(
             &self,
             role_id: [u8; 32],
             account_id: ActorId,
         ) -> sails_rs::client::PendingCall<io::HasRole, Self::Env>;
+        /// Returns `true` if `role_id` is locked, admitting no new members.
+        fn is_role_locked(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::IsRoleLocked, Self::Env>;
+        /// Returns roles held and administered by `account_id`, and grants pending to it,
+        /// in a single query.
+        fn permissions_of(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::PermissionsOf, Self::Env>;
+        /// Previews `grant_role` by the caller without mutating storage, e.g. for signers
+        /// of a multisig to review its effects, failing as `grant_role` would.
+        fn simulate_grant_role(
+            &self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SimulateGrantRole, Self::Env>;
     }
     pub struct AccessControlImpl;
     impl<E: sails_rs::client::GearEnv> AccessControl
         for sails_rs::client::Service<AccessControlImpl, E>
     {
         type Env = E;
+        fn cancel_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn cancel_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
+        fn cancel_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::CancelRoleGrant, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
+        fn execute_admin_renounce(
+            &mut self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn execute_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
+        fn execute_role_grant(
+            &mut self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExecuteRoleGrant, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
         fn grant_role(
             &mut self,
             role_id: [u8; 32],
@@ -258,6 +728,23 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GrantRolesBatch, Self::Env> {
             self.pending_call((role_ids, target_account))
         }
+        fn init_access_control(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::InitAccessControl, Self::Env> {
+            self.pending_call(())
+        }
+        fn lock_role(
+            &mut self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::LockRole, Self::Env> {
+            self.pending_call((role_id,))
+        }
+        fn propose_recovery(
+            &mut self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ProposeRecovery, Self::Env> {
+            self.pending_call((new_admin,))
+        }
         fn renounce_role(
             &mut self,
             role_id: [u8; 32],
@@ -265,6 +752,14 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::RenounceRole, Self::Env> {
             self.pending_call((role_id, account_id))
         }
+        fn replace_role_member(
+            &mut self,
+            role_id: [u8; 32],
+            old_account: ActorId,
+            new_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ReplaceRoleMember, Self::Env> {
+            self.pending_call((role_id, old_account, new_account))
+        }
         fn revoke_role(
             &mut self,
             role_id: [u8; 32],
@@ -279,6 +774,32 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::RevokeRolesBatch, Self::Env> {
             self.pending_call((role_ids, target_account))
         }
+        fn set_admin_renounce_delay(
+            &mut self,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetAdminRenounceDelay, Self::Env> {
+            self.pending_call((delay,))
+        }
+        fn set_allow_admin_less(
+            &mut self,
+            allowed: bool,
+        ) -> sails_rs::client::PendingCall<io::SetAllowAdminLess, Self::Env> {
+            self.pending_call((allowed,))
+        }
+        fn set_max_page_size(
+            &mut self,
+            max_page_size: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env> {
+            self.pending_call((max_page_size,))
+        }
+        fn set_recovery_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRecoveryGuardians, Self::Env> {
+            self.pending_call((guardians, threshold, delay))
+        }
         fn set_role_admin(
             &mut self,
             role_id: [u8; 32],
@@ -286,6 +807,59 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::SetRoleAdmin, Self::Env> {
             self.pending_call((role_id, new_admin_role_id))
         }
+        fn set_role_grant_delay(
+            &mut self,
+            role_id: [u8; 32],
+            delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetRoleGrantDelay, Self::Env> {
+            self.pending_call((role_id, delay))
+        }
+        fn set_role_parent(
+            &mut self,
+            role_id: [u8; 32],
+            parent_role_id: Option<[u8; 32]>,
+        ) -> sails_rs::client::PendingCall<io::SetRoleParent, Self::Env> {
+            self.pending_call((role_id, parent_role_id))
+        }
+        fn set_viewer_gate(
+            &mut self,
+            enabled: bool,
+        ) -> sails_rs::client::PendingCall<io::SetViewerGate, Self::Env> {
+            self.pending_call((enabled,))
+        }
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn get_admin_renounce_delay(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAdminRenounceDelay, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_all_member_count(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllMemberCount, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_all_members(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetAllMembers, Self::Env> {
+            self.pending_call((query,))
+        }
+        fn get_allow_admin_less(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetAllowAdminLess, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_max_page_size(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetMaxPageSize, Self::Env> {
+            self.pending_call(())
+        }
         fn get_member_role_count(
             &self,
             member_id: ActorId,
@@ -299,6 +873,36 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetMemberRoles, Self::Env> {
             self.pending_call((member_id, query))
         }
+        fn get_memberships(
+            &self,
+            query: Option<Pagination>,
+        ) -> sails_rs::client::PendingCall<io::GetMemberships, Self::Env> {
+            self.pending_call((query,))
+        }
+        fn get_pending_admin_renounce(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingAdminRenounce, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn get_pending_grant(
+            &self,
+            role_id: [u8; 32],
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetPendingGrant, Self::Env> {
+            self.pending_call((role_id, account_id))
+        }
+        fn get_recovery_config(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryConfig, Self::Env> {
+            self.pending_call(())
+        }
+        fn get_recovery_proposal(
+            &self,
+            new_admin: ActorId,
+        ) -> sails_rs::client::PendingCall<io::GetRecoveryProposal, Self::Env> {
+            self.pending_call((new_admin,))
+        }
         fn get_role_admin(
             &self,
             role_id: [u8; 32],
@@ -308,6 +912,12 @@ pub mod access_control {
         fn get_role_count(&self) -> sails_rs::client::PendingCall<io::GetRoleCount, Self::Env> {
             self.pending_call(())
         }
+        fn get_role_grant_delay(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleGrantDelay, Self::Env> {
+            self.pending_call((role_id,))
+        }
         fn get_role_member_count(
             &self,
             role_id: [u8; 32],
@@ -321,37 +931,99 @@ pub mod access_control {
         ) -> sails_rs::client::PendingCall<io::GetRoleMembers, Self::Env> {
             self.pending_call((role_id, query))
         }
+        fn get_role_parent(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::GetRoleParent, Self::Env> {
+            self.pending_call((role_id,))
+        }
         fn get_roles(
             &self,
             query: Option<Pagination>,
         ) -> sails_rs::client::PendingCall<io::GetRoles, Self::Env> {
             self.pending_call((query,))
         }
-        fn has_role(
+        fn get_viewer_gate(&self) -> sails_rs::client::PendingCall<io::GetViewerGate, Self::Env> {
+            self.pending_call(())
+        }
+        fn has_role(
             &self,
             role_id: [u8; 32],
             account_id: ActorId,
         ) -> sails_rs::client::PendingCall<io::HasRole, Self::Env> {
             self.pending_call((role_id, account_id))
         }
+        fn is_role_locked(
+            &self,
+            role_id: [u8; 32],
+        ) -> sails_rs::client::PendingCall<io::IsRoleLocked, Self::Env> {
+            self.pending_call((role_id,))
+        }
+        fn permissions_of(
+            &self,
+            account_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::PermissionsOf, Self::Env> {
+            self.pending_call((account_id,))
+        }
+        fn simulate_grant_role(
+            &self,
+            role_id: [u8; 32],
+            target_account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SimulateGrantRole, Self::Env> {
+            self.pending_call((role_id, target_account))
+        }
     }
 
     pub mod io {
         use super::*;
+        sails_rs::io_struct_impl!(CancelAdminRenounce (account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(CancelRecovery (new_admin: ActorId) -> ());
+        sails_rs::io_struct_impl!(CancelRoleGrant (role_id: [u8; 32], target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteAdminRenounce (account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteRecovery (new_admin: ActorId) -> ());
+        sails_rs::io_struct_impl!(ExecuteRoleGrant (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(GrantRole (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(GrantRolesBatch (role_ids: Vec<[u8; 32]>, target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(InitAccessControl () -> bool);
+        sails_rs::io_struct_impl!(LockRole (role_id: [u8; 32]) -> bool);
+        sails_rs::io_struct_impl!(ProposeRecovery (new_admin: ActorId) -> ());
         sails_rs::io_struct_impl!(RenounceRole (role_id: [u8; 32], account_id: ActorId) -> ());
+        sails_rs::io_struct_impl!(ReplaceRoleMember (role_id: [u8; 32], old_account: ActorId, new_account: ActorId) -> ());
         sails_rs::io_struct_impl!(RevokeRole (role_id: [u8; 32], target_account: ActorId) -> ());
         sails_rs::io_struct_impl!(RevokeRolesBatch (role_ids: Vec<[u8; 32]>, target_account: ActorId) -> ());
+        sails_rs::io_struct_impl!(SetAdminRenounceDelay (delay: u32) -> ());
+        sails_rs::io_struct_impl!(SetAllowAdminLess (allowed: bool) -> bool);
+        sails_rs::io_struct_impl!(SetMaxPageSize (max_page_size: u32) -> ());
+        sails_rs::io_struct_impl!(SetRecoveryGuardians (guardians: Vec<ActorId>, threshold: u32, delay: u32) -> ());
         sails_rs::io_struct_impl!(SetRoleAdmin (role_id: [u8; 32], new_admin_role_id: [u8; 32]) -> ());
+        sails_rs::io_struct_impl!(SetRoleGrantDelay (role_id: [u8; 32], delay: u32) -> ());
+        sails_rs::io_struct_impl!(SetRoleParent (role_id: [u8; 32], parent_role_id: Option<[u8; 32]>) -> ());
+        sails_rs::io_struct_impl!(SetViewerGate (enabled: bool) -> bool);
+        sails_rs::io_struct_impl!(AuditLog (cursor: u32, len: u32) -> Vec<(u32,super::AuditEntry,)>);
+        sails_rs::io_struct_impl!(GetAdminRenounceDelay () -> u32);
+        sails_rs::io_struct_impl!(GetAllMemberCount () -> u32);
+        sails_rs::io_struct_impl!(GetAllMembers (query: Option<super::Pagination>) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(GetAllowAdminLess () -> bool);
+        sails_rs::io_struct_impl!(GetMaxPageSize () -> u32);
         sails_rs::io_struct_impl!(GetMemberRoleCount (member_id: ActorId) -> u32);
         sails_rs::io_struct_impl!(GetMemberRoles (member_id: ActorId, query: Option<super::Pagination>) -> Vec<[u8; 32]>);
+        sails_rs::io_struct_impl!(GetMemberships (query: Option<super::Pagination>) -> Vec<([u8; 32],ActorId,)>);
+        sails_rs::io_struct_impl!(GetPendingAdminRenounce (account_id: ActorId) -> Option<u32>);
+        sails_rs::io_struct_impl!(GetPendingGrant (role_id: [u8; 32], account_id: ActorId) -> Option<super::PendingGrant>);
+        sails_rs::io_struct_impl!(GetRecoveryConfig () -> super::RecoveryConfig);
+        sails_rs::io_struct_impl!(GetRecoveryProposal (new_admin: ActorId) -> Option<super::RecoveryProposal>);
         sails_rs::io_struct_impl!(GetRoleAdmin (role_id: [u8; 32]) -> [u8; 32]);
         sails_rs::io_struct_impl!(GetRoleCount () -> u32);
+        sails_rs::io_struct_impl!(GetRoleGrantDelay (role_id: [u8; 32]) -> u32);
         sails_rs::io_struct_impl!(GetRoleMemberCount (role_id: [u8; 32]) -> u32);
         sails_rs::io_struct_impl!(GetRoleMembers (role_id: [u8; 32], query: Option<super::Pagination>) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(GetRoleParent (role_id: [u8; 32]) -> Option<[u8; 32]>);
         sails_rs::io_struct_impl!(GetRoles (query: Option<super::Pagination>) -> Vec<[u8; 32]>);
+        sails_rs::io_struct_impl!(GetViewerGate () -> bool);
         sails_rs::io_struct_impl!(HasRole (role_id: [u8; 32], account_id: ActorId) -> bool);
+        sails_rs::io_struct_impl!(IsRoleLocked (role_id: [u8; 32]) -> bool);
+        sails_rs::io_struct_impl!(PermissionsOf (account_id: ActorId) -> super::Permissions);
+        sails_rs::io_struct_impl!(SimulateGrantRole (role_id: [u8; 32], target_account: ActorId) -> super::GrantPreview);
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -360,15 +1032,22 @@ pub mod access_control {
         #[derive(PartialEq, Debug, Encode, Decode)]
         #[codec(crate = sails_rs::scale_codec)]
         pub enum AccessControlEvents {
+            /// `via_role` is the role that authorized the grant: the admin role of `role_id`,
+            /// or `DEFAULT_ADMIN_ROLE` on super admin override and recovery.
             RoleGranted {
                 role_id: [u8; 32],
                 target_account: ActorId,
                 sender: ActorId,
+                via_role: [u8; 32],
             },
+            /// `via_role` is the role that authorized the revocation: the admin role of `role_id`,
+            /// `DEFAULT_ADMIN_ROLE` on super admin override and recovery, or `role_id` itself
+            /// when renounced.
             RoleRevoked {
                 role_id: [u8; 32],
                 target_account: ActorId,
                 sender: ActorId,
+                via_role: [u8; 32],
             },
             RoleAdminChanged {
                 role_id: [u8; 32],
@@ -376,10 +1055,109 @@ pub mod access_control {
                 new_admin_role_id: [u8; 32],
                 sender: ActorId,
             },
+            RoleParentChanged {
+                role_id: [u8; 32],
+                previous_parent_role_id: Option<[u8; 32]>,
+                new_parent_role_id: Option<[u8; 32]>,
+                sender: ActorId,
+            },
+            RecoveryConfigChanged {
+                threshold: u32,
+                delay: u32,
+                sender: ActorId,
+            },
+            RecoveryApproved {
+                new_admin: ActorId,
+                guardian: ActorId,
+                approvals: u32,
+            },
+            RecoveryScheduled {
+                new_admin: ActorId,
+                ready_at: u32,
+            },
+            RecoveryCancelled {
+                new_admin: ActorId,
+                sender: ActorId,
+            },
+            RecoveryExecuted {
+                new_admin: ActorId,
+            },
+            RoleGrantScheduled {
+                role_id: [u8; 32],
+                target_account: ActorId,
+                sender: ActorId,
+                ready_at: u32,
+            },
+            RoleGrantCancelled {
+                role_id: [u8; 32],
+                target_account: ActorId,
+                sender: ActorId,
+            },
+            RoleGrantDelayChanged {
+                role_id: [u8; 32],
+                previous_delay: u32,
+                new_delay: u32,
+                sender: ActorId,
+            },
+            /// `sender` managed `role_id` as a super admin without holding its `admin_role_id`.
+            SuperAdminOverrideUsed {
+                role_id: [u8; 32],
+                admin_role_id: [u8; 32],
+                sender: ActorId,
+            },
+            MaxPageSizeChanged {
+                previous_max_page_size: u32,
+                new_max_page_size: u32,
+                sender: ActorId,
+            },
+            ViewerGateChanged {
+                enabled: bool,
+                sender: ActorId,
+            },
+            RoleLocked {
+                role_id: [u8; 32],
+                sender: ActorId,
+            },
+            AdminRenounceScheduled {
+                account_id: ActorId,
+                ready_at: u32,
+            },
+            AdminRenounceCancelled {
+                account_id: ActorId,
+            },
+            AdminRenounceDelayChanged {
+                previous_delay: u32,
+                new_delay: u32,
+                sender: ActorId,
+            },
+            AllowAdminLessChanged {
+                allowed: bool,
+                sender: ActorId,
+            },
         }
         impl sails_rs::client::Event for AccessControlEvents {
-            const EVENT_NAMES: &'static [Route] =
-                &["RoleGranted", "RoleRevoked", "RoleAdminChanged"];
+            const EVENT_NAMES: &'static [Route] = &[
+                "RoleGranted",
+                "RoleRevoked",
+                "RoleAdminChanged",
+                "RoleParentChanged",
+                "RecoveryConfigChanged",
+                "RecoveryApproved",
+                "RecoveryScheduled",
+                "RecoveryCancelled",
+                "RecoveryExecuted",
+                "RoleGrantScheduled",
+                "RoleGrantCancelled",
+                "RoleGrantDelayChanged",
+                "SuperAdminOverrideUsed",
+                "MaxPageSizeChanged",
+                "ViewerGateChanged",
+                "RoleLocked",
+                "AdminRenounceScheduled",
+                "AdminRenounceCancelled",
+                "AdminRenounceDelayChanged",
+                "AllowAdminLessChanged",
+            ];
         }
         impl sails_rs::client::ServiceWithEvents for AccessControlImpl {
             type Event = AccessControlEvents;
@@ -387,88 +1165,155 @@ pub mod access_control {
     }
 }
 
-pub mod vft {
+pub mod amm {
     use super::*;
-    pub trait Vft {
+    pub trait Amm {
         type Env: sails_rs::client::GearEnv;
-        fn approve(
+        /// Deposits the tokens at the current price, up to the desired amounts,
+        /// issuing LP tokens to the message source.
+        ///
+        /// Tokens are pulled from the message source, which must approve them to the program.
+        /// If the liquidity can't be issued once the tokens are pulled, e.g. as the price
+        /// moved meanwhile, the tokens are credited to be claimed and zero is returned.
+        fn add_liquidity(
             &mut self,
-            spender: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env>;
-        fn transfer(
+            amount0_desired: U256,
+            amount1_desired: U256,
+            min_liquidity: U256,
+        ) -> sails_rs::client::PendingCall<io::AddLiquidity, Self::Env>;
+        /// Transfers the amount of the token credited to the message source.
+        ///
+        /// Returns the transferred amount.
+        fn claim(&mut self, token: ActorId) -> sails_rs::client::PendingCall<io::Claim, Self::Env>;
+        /// Burns LP tokens of the message source, transferring it the withdrawn tokens.
+        ///
+        /// Returns withdrawn amounts of `token0` and `token1`.
+        fn remove_liquidity(
             &mut self,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env>;
-        fn transfer_from(
+            liquidity: U256,
+            min_amount0: U256,
+            min_amount1: U256,
+        ) -> sails_rs::client::PendingCall<io::RemoveLiquidity, Self::Env>;
+        /// Sets the pair of the pool, while no liquidity is issued for the current one.
+        fn set_pair(
             &mut self,
-            from: ActorId,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env>;
-        fn allowance(
+            token0: ActorId,
+            token1: ActorId,
+            fee_bps: u16,
+        ) -> sails_rs::client::PendingCall<io::SetPair, Self::Env>;
+        /// Swaps `amount_in` of `token_in` for the other token of the pair.
+        ///
+        /// Input is pulled from the message source, which must approve it to the program.
+        /// If the output drops below `min_amount_out` once the input is pulled, the input
+        /// is credited to be claimed and zero is returned.
+        ///
+        /// Returns the output amount.
+        fn swap(
+            &mut self,
+            token_in: ActorId,
+            amount_in: U256,
+            min_amount_out: U256,
+        ) -> sails_rs::client::PendingCall<io::Swap, Self::Env>;
+        fn liquidity(&self) -> sails_rs::client::PendingCall<io::Liquidity, Self::Env>;
+        fn pair(&self) -> sails_rs::client::PendingCall<io::Pair, Self::Env>;
+        /// Returns accumulated prices of `token0` and `token1`, scaled by [`PRICE_SCALE`].
+        fn price_cumulatives(
             &self,
-            owner: ActorId,
-            spender: ActorId,
-        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env>;
-        fn balance_of(
+        ) -> sails_rs::client::PendingCall<io::PriceCumulatives, Self::Env>;
+        /// Returns the token paid out for `amount_in` of `token_in` with its amount.
+        fn quote_swap(
+            &self,
+            token_in: ActorId,
+            amount_in: U256,
+        ) -> sails_rs::client::PendingCall<io::QuoteSwap, Self::Env>;
+        fn reserves(&self) -> sails_rs::client::PendingCall<io::Reserves, Self::Env>;
+        fn unclaimed(
             &self,
+            token: ActorId,
             account: ActorId,
-        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env>;
-        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env>;
+        ) -> sails_rs::client::PendingCall<io::Unclaimed, Self::Env>;
     }
-    pub struct VftImpl;
-    impl<E: sails_rs::client::GearEnv> Vft for sails_rs::client::Service<VftImpl, E> {
+    pub struct AmmImpl;
+    impl<E: sails_rs::client::GearEnv> Amm for sails_rs::client::Service<AmmImpl, E> {
         type Env = E;
-        fn approve(
+        fn add_liquidity(
             &mut self,
-            spender: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env> {
-            self.pending_call((spender, value))
+            amount0_desired: U256,
+            amount1_desired: U256,
+            min_liquidity: U256,
+        ) -> sails_rs::client::PendingCall<io::AddLiquidity, Self::Env> {
+            self.pending_call((amount0_desired, amount1_desired, min_liquidity))
         }
-        fn transfer(
+        fn claim(&mut self, token: ActorId) -> sails_rs::client::PendingCall<io::Claim, Self::Env> {
+            self.pending_call((token,))
+        }
+        fn remove_liquidity(
             &mut self,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env> {
-            self.pending_call((to, value))
+            liquidity: U256,
+            min_amount0: U256,
+            min_amount1: U256,
+        ) -> sails_rs::client::PendingCall<io::RemoveLiquidity, Self::Env> {
+            self.pending_call((liquidity, min_amount0, min_amount1))
         }
-        fn transfer_from(
+        fn set_pair(
             &mut self,
-            from: ActorId,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env> {
-            self.pending_call((from, to, value))
+            token0: ActorId,
+            token1: ActorId,
+            fee_bps: u16,
+        ) -> sails_rs::client::PendingCall<io::SetPair, Self::Env> {
+            self.pending_call((token0, token1, fee_bps))
         }
-        fn allowance(
+        fn swap(
+            &mut self,
+            token_in: ActorId,
+            amount_in: U256,
+            min_amount_out: U256,
+        ) -> sails_rs::client::PendingCall<io::Swap, Self::Env> {
+            self.pending_call((token_in, amount_in, min_amount_out))
+        }
+        fn liquidity(&self) -> sails_rs::client::PendingCall<io::Liquidity, Self::Env> {
+            self.pending_call(())
+        }
+        fn pair(&self) -> sails_rs::client::PendingCall<io::Pair, Self::Env> {
+            self.pending_call(())
+        }
+        fn price_cumulatives(
             &self,
-            owner: ActorId,
-            spender: ActorId,
-        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env> {
-            self.pending_call((owner, spender))
+        ) -> sails_rs::client::PendingCall<io::PriceCumulatives, Self::Env> {
+            self.pending_call(())
         }
-        fn balance_of(
+        fn quote_swap(
             &self,
-            account: ActorId,
-        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env> {
-            self.pending_call((account,))
+            token_in: ActorId,
+            amount_in: U256,
+        ) -> sails_rs::client::PendingCall<io::QuoteSwap, Self::Env> {
+            self.pending_call((token_in, amount_in))
         }
-        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env> {
+        fn reserves(&self) -> sails_rs::client::PendingCall<io::Reserves, Self::Env> {
             self.pending_call(())
         }
+        fn unclaimed(
+            &self,
+            token: ActorId,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Unclaimed, Self::Env> {
+            self.pending_call((token, account))
+        }
     }
 
     pub mod io {
         use super::*;
-        sails_rs::io_struct_impl!(Approve (spender: ActorId, value: U256) -> bool);
-        sails_rs::io_struct_impl!(Transfer (to: ActorId, value: U256) -> bool);
-        sails_rs::io_struct_impl!(TransferFrom (from: ActorId, to: ActorId, value: U256) -> bool);
-        sails_rs::io_struct_impl!(Allowance (owner: ActorId, spender: ActorId) -> U256);
-        sails_rs::io_struct_impl!(BalanceOf (account: ActorId) -> U256);
-        sails_rs::io_struct_impl!(TotalSupply () -> U256);
+        sails_rs::io_struct_impl!(AddLiquidity (amount0_desired: U256, amount1_desired: U256, min_liquidity: U256) -> U256);
+        sails_rs::io_struct_impl!(Claim (token: ActorId) -> U256);
+        sails_rs::io_struct_impl!(RemoveLiquidity (liquidity: U256, min_amount0: U256, min_amount1: U256) -> (U256,U256,));
+        sails_rs::io_struct_impl!(SetPair (token0: ActorId, token1: ActorId, fee_bps: u16) -> ());
+        sails_rs::io_struct_impl!(Swap (token_in: ActorId, amount_in: U256, min_amount_out: U256) -> U256);
+        sails_rs::io_struct_impl!(Liquidity () -> U256);
+        sails_rs::io_struct_impl!(Pair () -> Option<super::Pair>);
+        sails_rs::io_struct_impl!(PriceCumulatives () -> (U256,U256,));
+        sails_rs::io_struct_impl!(QuoteSwap (token_in: ActorId, amount_in: U256) -> Option<(ActorId,U256,)>);
+        sails_rs::io_struct_impl!(Reserves () -> (U256,U256,));
+        sails_rs::io_struct_impl!(Unclaimed (token: ActorId, account: ActorId) -> U256);
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -476,139 +1321,114 @@ pub mod vft {
         use super::*;
         #[derive(PartialEq, Debug, Encode, Decode)]
         #[codec(crate = sails_rs::scale_codec)]
-        pub enum VftEvents {
-            Approval {
-                owner: ActorId,
-                spender: ActorId,
-                value: U256,
+        pub enum AmmEvents {
+            LiquidityAdded {
+                provider: ActorId,
+                amount0: U256,
+                amount1: U256,
+                liquidity: U256,
             },
-            Transfer {
-                from: ActorId,
-                to: ActorId,
-                value: U256,
+            LiquidityRemoved {
+                provider: ActorId,
+                amount0: U256,
+                amount1: U256,
+                liquidity: U256,
+            },
+            Swapped {
+                trader: ActorId,
+                token_in: ActorId,
+                amount_in: U256,
+                amount_out: U256,
+            },
+            Credited {
+                token: ActorId,
+                account: ActorId,
+                amount: U256,
             },
+            Claimed {
+                token: ActorId,
+                account: ActorId,
+                amount: U256,
+            },
+            PairSet(Pair),
         }
-        impl sails_rs::client::Event for VftEvents {
-            const EVENT_NAMES: &'static [Route] = &["Approval", "Transfer"];
+        impl sails_rs::client::Event for AmmEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "LiquidityAdded",
+                "LiquidityRemoved",
+                "Swapped",
+                "Credited",
+                "Claimed",
+                "PairSet",
+            ];
         }
-        impl sails_rs::client::ServiceWithEvents for VftImpl {
-            type Event = VftEvents;
+        impl sails_rs::client::ServiceWithEvents for AmmImpl {
+            type Event = AmmEvents;
         }
     }
 }
 
-pub mod vft_admin {
+pub mod counter {
     use super::*;
-    pub trait VftAdmin {
+    pub trait Counter {
         type Env: sails_rs::client::GearEnv;
-        fn append_allowances_shard(
-            &mut self,
-            capacity: u32,
-        ) -> sails_rs::client::PendingCall<io::AppendAllowancesShard, Self::Env>;
-        fn append_balances_shard(
-            &mut self,
-            capacity: u32,
-        ) -> sails_rs::client::PendingCall<io::AppendBalancesShard, Self::Env>;
-        fn approve_from(
-            &mut self,
-            owner: ActorId,
-            spender: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::ApproveFrom, Self::Env>;
-        fn burn(
-            &mut self,
-            from: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env>;
-        fn exit(
+        fn decrement(
             &mut self,
-            inheritor: ActorId,
-        ) -> sails_rs::client::PendingCall<io::Exit, Self::Env>;
-        fn mint(
+            key: String,
+            by: u64,
+        ) -> sails_rs::client::PendingCall<io::Decrement, Self::Env>;
+        fn increment(
             &mut self,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env>;
-        fn pause(&mut self) -> sails_rs::client::PendingCall<io::Pause, Self::Env>;
-        fn resume(&mut self) -> sails_rs::client::PendingCall<io::Resume, Self::Env>;
-        fn set_expiry_period(
+            key: String,
+            by: u64,
+        ) -> sails_rs::client::PendingCall<io::Increment, Self::Env>;
+        fn reset(&mut self, key: String) -> sails_rs::client::PendingCall<io::Reset, Self::Env>;
+        fn set(
             &mut self,
-            period: u32,
-        ) -> sails_rs::client::PendingCall<io::SetExpiryPeriod, Self::Env>;
-        fn is_paused(&self) -> sails_rs::client::PendingCall<io::IsPaused, Self::Env>;
+            key: String,
+            value: u64,
+        ) -> sails_rs::client::PendingCall<io::Set, Self::Env>;
+        fn value(&self, key: String) -> sails_rs::client::PendingCall<io::Value, Self::Env>;
     }
-    pub struct VftAdminImpl;
-    impl<E: sails_rs::client::GearEnv> VftAdmin for sails_rs::client::Service<VftAdminImpl, E> {
+    pub struct CounterImpl;
+    impl<E: sails_rs::client::GearEnv> Counter for sails_rs::client::Service<CounterImpl, E> {
         type Env = E;
-        fn append_allowances_shard(
-            &mut self,
-            capacity: u32,
-        ) -> sails_rs::client::PendingCall<io::AppendAllowancesShard, Self::Env> {
-            self.pending_call((capacity,))
-        }
-        fn append_balances_shard(
-            &mut self,
-            capacity: u32,
-        ) -> sails_rs::client::PendingCall<io::AppendBalancesShard, Self::Env> {
-            self.pending_call((capacity,))
-        }
-        fn approve_from(
-            &mut self,
-            owner: ActorId,
-            spender: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::ApproveFrom, Self::Env> {
-            self.pending_call((owner, spender, value))
-        }
-        fn burn(
-            &mut self,
-            from: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env> {
-            self.pending_call((from, value))
-        }
-        fn exit(
+        fn decrement(
             &mut self,
-            inheritor: ActorId,
-        ) -> sails_rs::client::PendingCall<io::Exit, Self::Env> {
-            self.pending_call((inheritor,))
+            key: String,
+            by: u64,
+        ) -> sails_rs::client::PendingCall<io::Decrement, Self::Env> {
+            self.pending_call((key, by))
         }
-        fn mint(
+        fn increment(
             &mut self,
-            to: ActorId,
-            value: U256,
-        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env> {
-            self.pending_call((to, value))
-        }
-        fn pause(&mut self) -> sails_rs::client::PendingCall<io::Pause, Self::Env> {
-            self.pending_call(())
+            key: String,
+            by: u64,
+        ) -> sails_rs::client::PendingCall<io::Increment, Self::Env> {
+            self.pending_call((key, by))
         }
-        fn resume(&mut self) -> sails_rs::client::PendingCall<io::Resume, Self::Env> {
-            self.pending_call(())
+        fn reset(&mut self, key: String) -> sails_rs::client::PendingCall<io::Reset, Self::Env> {
+            self.pending_call((key,))
         }
-        fn set_expiry_period(
+        fn set(
             &mut self,
-            period: u32,
-        ) -> sails_rs::client::PendingCall<io::SetExpiryPeriod, Self::Env> {
-            self.pending_call((period,))
+            key: String,
+            value: u64,
+        ) -> sails_rs::client::PendingCall<io::Set, Self::Env> {
+            self.pending_call((key, value))
         }
-        fn is_paused(&self) -> sails_rs::client::PendingCall<io::IsPaused, Self::Env> {
-            self.pending_call(())
+        fn value(&self, key: String) -> sails_rs::client::PendingCall<io::Value, Self::Env> {
+            self.pending_call((key,))
         }
     }
 
     pub mod io {
         use super::*;
-        sails_rs::io_struct_impl!(AppendAllowancesShard (capacity: u32) -> ());
-        sails_rs::io_struct_impl!(AppendBalancesShard (capacity: u32) -> ());
-        sails_rs::io_struct_impl!(ApproveFrom (owner: ActorId, spender: ActorId, value: U256) -> bool);
-        sails_rs::io_struct_impl!(Burn (from: ActorId, value: U256) -> ());
-        sails_rs::io_struct_impl!(Exit (inheritor: ActorId) -> ());
-        sails_rs::io_struct_impl!(Mint (to: ActorId, value: U256) -> ());
-        sails_rs::io_struct_impl!(Pause () -> ());
-        sails_rs::io_struct_impl!(Resume () -> ());
-        sails_rs::io_struct_impl!(SetExpiryPeriod (period: u32) -> ());
-        sails_rs::io_struct_impl!(IsPaused () -> bool);
+        sails_rs::io_struct_impl!(Decrement (key: String, by: u64) -> u64);
+        sails_rs::io_struct_impl!(Increment (key: String, by: u64) -> u64);
+        sails_rs::io_struct_impl!(Reset (key: String) -> bool);
+        sails_rs::io_struct_impl!(Set (key: String, value: u64) -> bool);
+        sails_rs::io_struct_impl!(Value (key: String) -> u64);
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -616,30 +1436,3404 @@ pub mod vft_admin {
         use super::*;
         #[derive(PartialEq, Debug, Encode, Decode)]
         #[codec(crate = sails_rs::scale_codec)]
-        pub enum VftAdminEvents {
-            BurnerTookPlace,
-            MinterTookPlace,
-            ExpiryPeriodChanged(u32),
-            Exited(ActorId),
-            Paused,
-            Resumed,
+        pub enum CounterEvents {
+            Incremented { key: String, value: u64 },
+            Decremented { key: String, value: u64 },
+            Set { key: String, value: u64 },
+            Reset(String),
         }
-        impl sails_rs::client::Event for VftAdminEvents {
-            const EVENT_NAMES: &'static [Route] = &[
-                "BurnerTookPlace",
-                "MinterTookPlace",
-                "ExpiryPeriodChanged",
-                "Exited",
-                "Paused",
-                "Resumed",
-            ];
+        impl sails_rs::client::Event for CounterEvents {
+            const EVENT_NAMES: &'static [Route] = &["Incremented", "Decremented", "Set", "Reset"];
         }
-        impl sails_rs::client::ServiceWithEvents for VftAdminImpl {
+        impl sails_rs::client::ServiceWithEvents for CounterImpl {
+            type Event = CounterEvents;
+        }
+    }
+}
+
+pub mod dutch_auction {
+    use super::*;
+    pub trait DutchAuction {
+        type Env: sails_rs::client::GearEnv;
+        /// Sells `amount` tokens to the message source at the current price.
+        ///
+        /// Message value must cover the cost, with the rest attached to the reply.
+        /// Returns the cost.
+        fn buy(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Buy, Self::Env>;
+        /// Finalizes the auction once it ended or sold out, sending the proceeds
+        /// to the seller and handling unsold tokens by the [`UnsoldPolicy`].
+        ///
+        /// May be called by anyone.
+        fn finalize(&mut self) -> sails_rs::client::PendingCall<io::Finalize, Self::Env>;
+        /// Starts the auction, escrowing its tokens from the message source,
+        /// who receives the proceeds.
+        fn start(
+            &mut self,
+            params: AuctionParams,
+        ) -> sails_rs::client::PendingCall<io::Start, Self::Env>;
+        fn auction(&self) -> sails_rs::client::PendingCall<io::Auction, Self::Env>;
+        /// Returns the current price, scaled by [`PRICE_SCALE`], while the auction is active.
+        fn current_price(&self) -> sails_rs::client::PendingCall<io::CurrentPrice, Self::Env>;
+        fn purchased(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Purchased, Self::Env>;
+        /// Returns native value to be paid for `amount` tokens at the current price.
+        fn quote(&self, amount: U256) -> sails_rs::client::PendingCall<io::Quote, Self::Env>;
+    }
+    pub struct DutchAuctionImpl;
+    impl<E: sails_rs::client::GearEnv> DutchAuction for sails_rs::client::Service<DutchAuctionImpl, E> {
+        type Env = E;
+        fn buy(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Buy, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn finalize(&mut self) -> sails_rs::client::PendingCall<io::Finalize, Self::Env> {
+            self.pending_call(())
+        }
+        fn start(
+            &mut self,
+            params: AuctionParams,
+        ) -> sails_rs::client::PendingCall<io::Start, Self::Env> {
+            self.pending_call((params,))
+        }
+        fn auction(&self) -> sails_rs::client::PendingCall<io::Auction, Self::Env> {
+            self.pending_call(())
+        }
+        fn current_price(&self) -> sails_rs::client::PendingCall<io::CurrentPrice, Self::Env> {
+            self.pending_call(())
+        }
+        fn purchased(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Purchased, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn quote(&self, amount: U256) -> sails_rs::client::PendingCall<io::Quote, Self::Env> {
+            self.pending_call((amount,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Buy (amount: U256) -> u128);
+        sails_rs::io_struct_impl!(Finalize () -> ());
+        sails_rs::io_struct_impl!(Start (params: super::AuctionParams) -> ());
+        sails_rs::io_struct_impl!(Auction () -> Option<super::Auction>);
+        sails_rs::io_struct_impl!(CurrentPrice () -> Option<U256>);
+        sails_rs::io_struct_impl!(Purchased (account: ActorId) -> U256);
+        sails_rs::io_struct_impl!(Quote (amount: U256) -> Option<u128>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum DutchAuctionEvents {
+            Started {
+                seller: ActorId,
+                params: AuctionParams,
+            },
+            Bought {
+                buyer: ActorId,
+                amount: U256,
+                cost: u128,
+            },
+            Finalized {
+                sold: U256,
+                unsold: U256,
+                proceeds: u128,
+            },
+        }
+        impl sails_rs::client::Event for DutchAuctionEvents {
+            const EVENT_NAMES: &'static [Route] = &["Started", "Bought", "Finalized"];
+        }
+        impl sails_rs::client::ServiceWithEvents for DutchAuctionImpl {
+            type Event = DutchAuctionEvents;
+        }
+    }
+}
+
+pub mod eth_binding {
+    use super::*;
+    pub trait EthBinding {
+        type Env: sails_rs::client::GearEnv;
+        /// Binds the address to the message source by the signature
+        /// of the binding message made by the address.
+        fn bind(
+            &mut self,
+            address: H160,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::Bind, Self::Env>;
+        /// Approves `value` of VFTs of the account bound to `owner` to the spender
+        /// by the signature of the permit message made by `owner`.
+        ///
+        /// Returns `true` if the allowance changed.
+        fn permit(
+            &mut self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::Permit, Self::Env>;
+        /// Unbinds the address of the message source.
+        ///
+        /// Returns the unbound address.
+        fn unbind(&mut self) -> sails_rs::client::PendingCall<io::Unbind, Self::Env>;
+        fn account_of(
+            &self,
+            address: H160,
+        ) -> sails_rs::client::PendingCall<io::AccountOf, Self::Env>;
+        fn address_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::AddressOf, Self::Env>;
+        /// Returns the message to be signed by the address to bind to the account.
+        fn bind_message(
+            &self,
+            address: H160,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BindMessage, Self::Env>;
+        fn nonce(&self, address: H160) -> sails_rs::client::PendingCall<io::Nonce, Self::Env>;
+        /// Returns the message to be signed by the owner to permit the approval.
+        fn permit_message(
+            &self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+        ) -> sails_rs::client::PendingCall<io::PermitMessage, Self::Env>;
+    }
+    pub struct EthBindingImpl;
+    impl<E: sails_rs::client::GearEnv> EthBinding for sails_rs::client::Service<EthBindingImpl, E> {
+        type Env = E;
+        fn bind(
+            &mut self,
+            address: H160,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::Bind, Self::Env> {
+            self.pending_call((address, signature))
+        }
+        fn permit(
+            &mut self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::Permit, Self::Env> {
+            self.pending_call((owner, spender, value, deadline, signature))
+        }
+        fn unbind(&mut self) -> sails_rs::client::PendingCall<io::Unbind, Self::Env> {
+            self.pending_call(())
+        }
+        fn account_of(
+            &self,
+            address: H160,
+        ) -> sails_rs::client::PendingCall<io::AccountOf, Self::Env> {
+            self.pending_call((address,))
+        }
+        fn address_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::AddressOf, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn bind_message(
+            &self,
+            address: H160,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BindMessage, Self::Env> {
+            self.pending_call((address, account))
+        }
+        fn nonce(&self, address: H160) -> sails_rs::client::PendingCall<io::Nonce, Self::Env> {
+            self.pending_call((address,))
+        }
+        fn permit_message(
+            &self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+        ) -> sails_rs::client::PendingCall<io::PermitMessage, Self::Env> {
+            self.pending_call((owner, spender, value, deadline))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Bind (address: H160, signature: [u8; 65]) -> ());
+        sails_rs::io_struct_impl!(Permit (owner: H160, spender: ActorId, value: U256, deadline: u32, signature: [u8; 65]) -> bool);
+        sails_rs::io_struct_impl!(Unbind () -> H160);
+        sails_rs::io_struct_impl!(AccountOf (address: H160) -> Option<ActorId>);
+        sails_rs::io_struct_impl!(AddressOf (account: ActorId) -> Option<H160>);
+        sails_rs::io_struct_impl!(BindMessage (address: H160, account: ActorId) -> Vec<u8>);
+        sails_rs::io_struct_impl!(Nonce (address: H160) -> u64);
+        sails_rs::io_struct_impl!(PermitMessage (owner: H160, spender: ActorId, value: U256, deadline: u32) -> Vec<u8>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum EthBindingEvents {
+            Bound { address: H160, account: ActorId },
+            Unbound { address: H160, account: ActorId },
+        }
+        impl sails_rs::client::Event for EthBindingEvents {
+            const EVENT_NAMES: &'static [Route] = &["Bound", "Unbound"];
+        }
+        impl sails_rs::client::ServiceWithEvents for EthBindingImpl {
+            type Event = EthBindingEvents;
+        }
+    }
+}
+
+pub mod fee_pool {
+    use super::*;
+    pub trait FeePool {
+        type Env: sails_rs::client::GearEnv;
+        /// Deposits `amount` VFTs of the message source to pay for its meta-transactions.
+        fn deposit(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Deposit, Self::Env>;
+        /// Submits the permit signed by `owner` as `EthBinding::permit` does,
+        /// reimbursing the message source from the deposit of the account bound to `owner`.
+        ///
+        /// Returns the fee reimbursed.
+        fn relay_permit(
+            &mut self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::RelayPermit, Self::Env>;
+        fn set_fee_rate(
+            &mut self,
+            rate: FeeRate,
+        ) -> sails_rs::client::PendingCall<io::SetFeeRate, Self::Env>;
+        /// Withdraws `amount` VFTs from the deposit of the message source.
+        fn withdraw(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Withdraw, Self::Env>;
+        fn deposit_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::DepositOf, Self::Env>;
+        fn fee_rate(&self) -> sails_rs::client::PendingCall<io::FeeRate, Self::Env>;
+        /// Returns tokens reimbursed for the gas by the current rate, the base gas added.
+        fn quote(&self, gas: u64) -> sails_rs::client::PendingCall<io::Quote, Self::Env>;
+        fn relayer_stats(
+            &self,
+            relayer: ActorId,
+        ) -> sails_rs::client::PendingCall<io::RelayerStats, Self::Env>;
+    }
+    pub struct FeePoolImpl;
+    impl<E: sails_rs::client::GearEnv> FeePool for sails_rs::client::Service<FeePoolImpl, E> {
+        type Env = E;
+        fn deposit(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Deposit, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn relay_permit(
+            &mut self,
+            owner: H160,
+            spender: ActorId,
+            value: U256,
+            deadline: u32,
+            signature: [u8; 65],
+        ) -> sails_rs::client::PendingCall<io::RelayPermit, Self::Env> {
+            self.pending_call((owner, spender, value, deadline, signature))
+        }
+        fn set_fee_rate(
+            &mut self,
+            rate: FeeRate,
+        ) -> sails_rs::client::PendingCall<io::SetFeeRate, Self::Env> {
+            self.pending_call((rate,))
+        }
+        fn withdraw(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Withdraw, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn deposit_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::DepositOf, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn fee_rate(&self) -> sails_rs::client::PendingCall<io::FeeRate, Self::Env> {
+            self.pending_call(())
+        }
+        fn quote(&self, gas: u64) -> sails_rs::client::PendingCall<io::Quote, Self::Env> {
+            self.pending_call((gas,))
+        }
+        fn relayer_stats(
+            &self,
+            relayer: ActorId,
+        ) -> sails_rs::client::PendingCall<io::RelayerStats, Self::Env> {
+            self.pending_call((relayer,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Deposit (amount: U256) -> ());
+        sails_rs::io_struct_impl!(RelayPermit (owner: H160, spender: ActorId, value: U256, deadline: u32, signature: [u8; 65]) -> U256);
+        sails_rs::io_struct_impl!(SetFeeRate (rate: super::FeeRate) -> ());
+        sails_rs::io_struct_impl!(Withdraw (amount: U256) -> ());
+        sails_rs::io_struct_impl!(DepositOf (account: ActorId) -> U256);
+        sails_rs::io_struct_impl!(FeeRate () -> super::FeeRate);
+        sails_rs::io_struct_impl!(Quote (gas: u64) -> Option<U256>);
+        sails_rs::io_struct_impl!(RelayerStats (relayer: ActorId) -> super::RelayerStats);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum FeePoolEvents {
+            Deposited {
+                account: ActorId,
+                amount: U256,
+            },
+            Withdrawn {
+                account: ActorId,
+                amount: U256,
+            },
+            Reimbursed {
+                relayer: ActorId,
+                account: ActorId,
+                gas: u64,
+                fee: U256,
+            },
+            FeeRateChanged(FeeRate),
+        }
+        impl sails_rs::client::Event for FeePoolEvents {
+            const EVENT_NAMES: &'static [Route] =
+                &["Deposited", "Withdrawn", "Reimbursed", "FeeRateChanged"];
+        }
+        impl sails_rs::client::ServiceWithEvents for FeePoolImpl {
+            type Event = FeePoolEvents;
+        }
+    }
+}
+
+pub mod htlc {
+    use super::*;
+    pub trait Htlc {
+        type Env: sails_rs::client::GearEnv;
+        /// Pays the lock of the revealed preimage to its recipient.
+        ///
+        /// Returns the hashlock of the claimed lock.
+        fn claim(
+            &mut self,
+            preimage: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::Claim, Self::Env>;
+        /// Locks `value` of VFTs of the message source along with the message value
+        /// for `recipient` until block `timelock`, under the keccak-256 `hashlock`.
+        fn lock(
+            &mut self,
+            hashlock: H256,
+            timelock: u32,
+            recipient: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Lock, Self::Env>;
+        /// Refunds the expired lock to its sender.
+        fn refund(
+            &mut self,
+            hashlock: H256,
+        ) -> sails_rs::client::PendingCall<io::Refund, Self::Env>;
+        fn lock_of(&self, hashlock: H256) -> sails_rs::client::PendingCall<io::LockOf, Self::Env>;
+    }
+    pub struct HtlcImpl;
+    impl<E: sails_rs::client::GearEnv> Htlc for sails_rs::client::Service<HtlcImpl, E> {
+        type Env = E;
+        fn claim(
+            &mut self,
+            preimage: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::Claim, Self::Env> {
+            self.pending_call((preimage,))
+        }
+        fn lock(
+            &mut self,
+            hashlock: H256,
+            timelock: u32,
+            recipient: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Lock, Self::Env> {
+            self.pending_call((hashlock, timelock, recipient, value))
+        }
+        fn refund(
+            &mut self,
+            hashlock: H256,
+        ) -> sails_rs::client::PendingCall<io::Refund, Self::Env> {
+            self.pending_call((hashlock,))
+        }
+        fn lock_of(&self, hashlock: H256) -> sails_rs::client::PendingCall<io::LockOf, Self::Env> {
+            self.pending_call((hashlock,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Claim (preimage: Vec<u8>) -> H256);
+        sails_rs::io_struct_impl!(Lock (hashlock: H256, timelock: u32, recipient: ActorId, value: U256) -> ());
+        sails_rs::io_struct_impl!(Refund (hashlock: H256) -> ());
+        sails_rs::io_struct_impl!(LockOf (hashlock: H256) -> Option<super::Lock>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum HtlcEvents {
+            Locked {
+                hashlock: H256,
+                sender: ActorId,
+                recipient: ActorId,
+                timelock: u32,
+                value: U256,
+                native: u128,
+            },
+            Claimed {
+                hashlock: H256,
+                preimage: Vec<u8>,
+            },
+            Refunded(H256),
+        }
+        impl sails_rs::client::Event for HtlcEvents {
+            const EVENT_NAMES: &'static [Route] = &["Locked", "Claimed", "Refunded"];
+        }
+        impl sails_rs::client::ServiceWithEvents for HtlcImpl {
+            type Event = HtlcEvents;
+        }
+    }
+}
+
+pub mod lending {
+    use super::*;
+    pub trait Lending {
+        type Env: sails_rs::client::GearEnv;
+        /// Borrows `amount` of the debt token to the message source,
+        /// within the LTV of its collateral.
+        fn borrow(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Borrow, Self::Env>;
+        /// Transfers the amount of the token credited to the message source.
+        ///
+        /// Returns the transferred amount.
+        fn claim(&mut self, token: ActorId) -> sails_rs::client::PendingCall<io::Claim, Self::Env>;
+        /// Deposits `amount` of the collateral token of the message source,
+        /// which must approve it to the program.
+        ///
+        /// If the collateral can't be deposited once it's pulled, e.g. as the market
+        /// was paused meanwhile, it's credited to be claimed and zero is returned.
+        ///
+        /// Returns the deposited amount.
+        fn deposit_collateral(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::DepositCollateral, Self::Env>;
+        /// Repays up to `amount` of the debt of the liquidatable `borrower` from
+        /// the message source, which must approve it to the program, for the collateral
+        /// of the borrower with the bonus.
+        ///
+        /// If the borrower can't be liquidated once the debt token is pulled, e.g. as the
+        /// price changed or the market was paused meanwhile, the debt token is credited
+        /// to be claimed and zero is returned.
+        ///
+        /// Returns the seized collateral.
+        fn liquidate(
+            &mut self,
+            borrower: ActorId,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Liquidate, Self::Env>;
+        /// Queries the price of the collateral from the oracle of the market.
+        ///
+        /// Returns the price with the block it was reported at.
+        fn refresh_price(&mut self) -> sails_rs::client::PendingCall<io::RefreshPrice, Self::Env>;
+        /// Repays up to `amount` of the debt of the message source,
+        /// which must approve it to the program.
+        ///
+        /// If the debt can't be repaid once the debt token is pulled, e.g. as the market
+        /// was paused meanwhile, the debt token is credited to be claimed and zero
+        /// is returned.
+        ///
+        /// Returns the repaid amount.
+        fn repay(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Repay, Self::Env>;
+        fn set_params(
+            &mut self,
+            params: MarketParams,
+        ) -> sails_rs::client::PendingCall<io::SetParams, Self::Env>;
+        /// Sets the price of the collateral in the debt token, scaled by [`SCALE`].
+        fn set_price(
+            &mut self,
+            price: U256,
+        ) -> sails_rs::client::PendingCall<io::SetPrice, Self::Env>;
+        /// Withdraws `amount` of the collateral of the message source,
+        /// keeping its debt within the LTV.
+        fn withdraw_collateral(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::WithdrawCollateral, Self::Env>;
+        fn params(&self) -> sails_rs::client::PendingCall<io::Params, Self::Env>;
+        fn position(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Position, Self::Env>;
+        /// Returns the price of the collateral with the block it was set at.
+        fn price(&self) -> sails_rs::client::PendingCall<io::Price, Self::Env>;
+        fn unclaimed(
+            &self,
+            token: ActorId,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Unclaimed, Self::Env>;
+    }
+    pub struct LendingImpl;
+    impl<E: sails_rs::client::GearEnv> Lending for sails_rs::client::Service<LendingImpl, E> {
+        type Env = E;
+        fn borrow(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Borrow, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn claim(&mut self, token: ActorId) -> sails_rs::client::PendingCall<io::Claim, Self::Env> {
+            self.pending_call((token,))
+        }
+        fn deposit_collateral(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::DepositCollateral, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn liquidate(
+            &mut self,
+            borrower: ActorId,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Liquidate, Self::Env> {
+            self.pending_call((borrower, amount))
+        }
+        fn refresh_price(&mut self) -> sails_rs::client::PendingCall<io::RefreshPrice, Self::Env> {
+            self.pending_call(())
+        }
+        fn repay(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Repay, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn set_params(
+            &mut self,
+            params: MarketParams,
+        ) -> sails_rs::client::PendingCall<io::SetParams, Self::Env> {
+            self.pending_call((params,))
+        }
+        fn set_price(
+            &mut self,
+            price: U256,
+        ) -> sails_rs::client::PendingCall<io::SetPrice, Self::Env> {
+            self.pending_call((price,))
+        }
+        fn withdraw_collateral(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::WithdrawCollateral, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn params(&self) -> sails_rs::client::PendingCall<io::Params, Self::Env> {
+            self.pending_call(())
+        }
+        fn position(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Position, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn price(&self) -> sails_rs::client::PendingCall<io::Price, Self::Env> {
+            self.pending_call(())
+        }
+        fn unclaimed(
+            &self,
+            token: ActorId,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Unclaimed, Self::Env> {
+            self.pending_call((token, account))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Borrow (amount: U256) -> ());
+        sails_rs::io_struct_impl!(Claim (token: ActorId) -> U256);
+        sails_rs::io_struct_impl!(DepositCollateral (amount: U256) -> U256);
+        sails_rs::io_struct_impl!(Liquidate (borrower: ActorId, amount: U256) -> U256);
+        sails_rs::io_struct_impl!(RefreshPrice () -> (U256,u32,));
+        sails_rs::io_struct_impl!(Repay (amount: U256) -> U256);
+        sails_rs::io_struct_impl!(SetParams (params: super::MarketParams) -> ());
+        sails_rs::io_struct_impl!(SetPrice (price: U256) -> ());
+        sails_rs::io_struct_impl!(WithdrawCollateral (amount: U256) -> ());
+        sails_rs::io_struct_impl!(Params () -> Option<super::MarketParams>);
+        sails_rs::io_struct_impl!(Position (account: ActorId) -> super::Position);
+        sails_rs::io_struct_impl!(Price () -> (U256,u32,));
+        sails_rs::io_struct_impl!(Unclaimed (token: ActorId, account: ActorId) -> U256);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum LendingEvents {
+            CollateralDeposited {
+                account: ActorId,
+                amount: U256,
+            },
+            CollateralWithdrawn {
+                account: ActorId,
+                amount: U256,
+            },
+            Borrowed {
+                account: ActorId,
+                amount: U256,
+            },
+            Repaid {
+                account: ActorId,
+                amount: U256,
+            },
+            Liquidated {
+                borrower: ActorId,
+                liquidator: ActorId,
+                repaid: U256,
+                seized: U256,
+            },
+            Credited {
+                token: ActorId,
+                account: ActorId,
+                amount: U256,
+            },
+            Claimed {
+                token: ActorId,
+                account: ActorId,
+                amount: U256,
+            },
+            ParamsSet(MarketParams),
+            PriceSet(U256),
+        }
+        impl sails_rs::client::Event for LendingEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "CollateralDeposited",
+                "CollateralWithdrawn",
+                "Borrowed",
+                "Repaid",
+                "Liquidated",
+                "Credited",
+                "Claimed",
+                "ParamsSet",
+                "PriceSet",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for LendingImpl {
+            type Event = LendingEvents;
+        }
+    }
+}
+
+pub mod lp_vft {
+    use super::*;
+    pub trait LpVft {
+        type Env: sails_rs::client::GearEnv;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env>;
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env>;
+        fn transfer_from(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env>;
+        fn allowance(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env>;
+        fn balance_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env>;
+        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env>;
+    }
+    pub struct LpVftImpl;
+    impl<E: sails_rs::client::GearEnv> LpVft for sails_rs::client::Service<LpVftImpl, E> {
+        type Env = E;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env> {
+            self.pending_call((spender, value))
+        }
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env> {
+            self.pending_call((to, value))
+        }
+        fn transfer_from(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env> {
+            self.pending_call((from, to, value))
+        }
+        fn allowance(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env> {
+            self.pending_call((owner, spender))
+        }
+        fn balance_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Approve (spender: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Transfer (to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(TransferFrom (from: ActorId, to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Allowance (owner: ActorId, spender: ActorId) -> U256);
+        sails_rs::io_struct_impl!(BalanceOf (account: ActorId) -> U256);
+        sails_rs::io_struct_impl!(TotalSupply () -> U256);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum LpVftEvents {
+            Approval {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
+            Transfer {
+                from: ActorId,
+                to: ActorId,
+                value: U256,
+            },
+            /// Account was removed from balances, as its balance hit zero
+            /// or fell below the minimum, leaving `dust`.
+            AccountReaped { account: ActorId, dust: U256 },
+            /// Spending cap of the spender was set, or removed if `amount_per_period` is zero.
+            SpendingCapSet {
+                owner: ActorId,
+                spender: ActorId,
+                amount_per_period: U256,
+                period_blocks: u32,
+            },
+            /// Operator of the owner was approved or revoked.
+            OperatorSet {
+                owner: ActorId,
+                operator: ActorId,
+                approved: bool,
+            },
+            /// Value was escrowed until claimed by the recipient, see `VftExtension::transfer_claimable`.
+            ClaimCreated {
+                id: u64,
+                from: ActorId,
+                to: ActorId,
+                value: U256,
+                expires_at: u32,
+            },
+            /// Escrowed value was paid out, to the recipient if claimed or to the sender if reclaimed.
+            ClaimSettled { id: u64, to: ActorId, value: U256 },
+            /// Snapshot of holders was computed, see `VftExtension::compute_snapshot`.
+            SnapshotTaken {
+                block: u32,
+                root: [u8; 32],
+                holders: u32,
+            },
+            /// Insert filled shards of the map up, so next inserts fail until shards
+            /// are appended or allocated, see `VftAdmin::append_balances_shard`.
+            StorageSaturated {
+                map: StorageMap,
+                shards: u32,
+                capacity: u32,
+            },
+            /// Insert made occupancy of the map cross the threshold of its alert,
+            /// see `VftAdmin::set_capacity_alert`.
+            CapacityWarning {
+                map: StorageMap,
+                entries: u32,
+                capacity: u32,
+            },
+        }
+        impl sails_rs::client::Event for LpVftEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Approval",
+                "Transfer",
+                "AccountReaped",
+                "SpendingCapSet",
+                "OperatorSet",
+                "ClaimCreated",
+                "ClaimSettled",
+                "SnapshotTaken",
+                "StorageSaturated",
+                "CapacityWarning",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for LpVftImpl {
+            type Event = LpVftEvents;
+        }
+    }
+}
+
+pub mod metrics {
+    use super::*;
+    pub trait Metrics {
+        type Env: sails_rs::client::GearEnv;
+        /// Returns metrics of the allowances map.
+        fn allowances_metrics(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::AllowancesMetrics, Self::Env>;
+        /// Returns metrics of the balances map.
+        fn balances_metrics(&self)
+        -> sails_rs::client::PendingCall<io::BalancesMetrics, Self::Env>;
+        /// Returns metrics of the whole program storage.
+        fn metrics(&self) -> sails_rs::client::PendingCall<io::Metrics, Self::Env>;
+    }
+    pub struct MetricsImpl;
+    impl<E: sails_rs::client::GearEnv> Metrics for sails_rs::client::Service<MetricsImpl, E> {
+        type Env = E;
+        fn allowances_metrics(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::AllowancesMetrics, Self::Env> {
+            self.pending_call(())
+        }
+        fn balances_metrics(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::BalancesMetrics, Self::Env> {
+            self.pending_call(())
+        }
+        fn metrics(&self) -> sails_rs::client::PendingCall<io::Metrics, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(AllowancesMetrics () -> super::MapMetrics);
+        sails_rs::io_struct_impl!(BalancesMetrics () -> super::MapMetrics);
+        sails_rs::io_struct_impl!(Metrics () -> super::ProgramMetrics);
+    }
+}
+
+pub mod name_registry {
+    use super::*;
+    pub trait NameRegistry {
+        type Env: sails_rs::client::GearEnv;
+        /// Registers the name for `duration` blocks, owned by the message source
+        /// and resolving to the target.
+        ///
+        /// Returns the block the name expires at.
+        fn register(
+            &mut self,
+            name: String,
+            target: ActorId,
+            duration: u32,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env>;
+        /// Extends the name of the message source by `duration` blocks.
+        ///
+        /// Returns the block the name expires at.
+        fn renew(
+            &mut self,
+            name: String,
+            duration: u32,
+        ) -> sails_rs::client::PendingCall<io::Renew, Self::Env>;
+        /// Points the name of the message source to the target.
+        fn set_target(
+            &mut self,
+            name: String,
+            target: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SetTarget, Self::Env>;
+        /// Transfers the name of the message source to the new owner.
+        fn transfer(
+            &mut self,
+            name: String,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env>;
+        /// Returns up to `len` names of the owner starting from `cursor`,
+        /// including expired ones, in ascending order.
+        fn names_of(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::NamesOf, Self::Env>;
+        fn record(&self, name: String) -> sails_rs::client::PendingCall<io::Record, Self::Env>;
+        /// Returns the actor the name resolves to, unless it's expired.
+        fn resolve(&self, name: String) -> sails_rs::client::PendingCall<io::Resolve, Self::Env>;
+    }
+    pub struct NameRegistryImpl;
+    impl<E: sails_rs::client::GearEnv> NameRegistry for sails_rs::client::Service<NameRegistryImpl, E> {
+        type Env = E;
+        fn register(
+            &mut self,
+            name: String,
+            target: ActorId,
+            duration: u32,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env> {
+            self.pending_call((name, target, duration))
+        }
+        fn renew(
+            &mut self,
+            name: String,
+            duration: u32,
+        ) -> sails_rs::client::PendingCall<io::Renew, Self::Env> {
+            self.pending_call((name, duration))
+        }
+        fn set_target(
+            &mut self,
+            name: String,
+            target: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SetTarget, Self::Env> {
+            self.pending_call((name, target))
+        }
+        fn transfer(
+            &mut self,
+            name: String,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env> {
+            self.pending_call((name, new_owner))
+        }
+        fn names_of(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::NamesOf, Self::Env> {
+            self.pending_call((owner, cursor, len))
+        }
+        fn record(&self, name: String) -> sails_rs::client::PendingCall<io::Record, Self::Env> {
+            self.pending_call((name,))
+        }
+        fn resolve(&self, name: String) -> sails_rs::client::PendingCall<io::Resolve, Self::Env> {
+            self.pending_call((name,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Register (name: String, target: ActorId, duration: u32) -> u32);
+        sails_rs::io_struct_impl!(Renew (name: String, duration: u32) -> u32);
+        sails_rs::io_struct_impl!(SetTarget (name: String, target: ActorId) -> ());
+        sails_rs::io_struct_impl!(Transfer (name: String, new_owner: ActorId) -> ());
+        sails_rs::io_struct_impl!(NamesOf (owner: ActorId, cursor: u32, len: u32) -> Vec<String>);
+        sails_rs::io_struct_impl!(Record (name: String) -> Option<super::NameRecord>);
+        sails_rs::io_struct_impl!(Resolve (name: String) -> Option<ActorId>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum NameRegistryEvents {
+            Registered {
+                name: String,
+                owner: ActorId,
+                target: ActorId,
+                expires_at: u32,
+            },
+            Renewed {
+                name: String,
+                expires_at: u32,
+            },
+            TargetSet {
+                name: String,
+                target: ActorId,
+            },
+            Transferred {
+                name: String,
+                from: ActorId,
+                to: ActorId,
+            },
+        }
+        impl sails_rs::client::Event for NameRegistryEvents {
+            const EVENT_NAMES: &'static [Route] =
+                &["Registered", "Renewed", "TargetSet", "Transferred"];
+        }
+        impl sails_rs::client::ServiceWithEvents for NameRegistryImpl {
+            type Event = NameRegistryEvents;
+        }
+    }
+}
+
+pub mod order_book {
+    use super::*;
+    pub trait OrderBook {
+        type Env: sails_rs::client::GearEnv;
+        fn allocate_next_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::AllocateNextShard, Self::Env>;
+        /// Cancels the order, returning the rest of its escrow to the maker.
+        ///
+        /// Makers cancel their orders at any time, while expired orders
+        /// may be cancelled by anyone.
+        fn cancel(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Cancel, Self::Env>;
+        /// Fills `amount` tokens of the order by the message source.
+        ///
+        /// Takers of sell orders pay the native value by the message value, with the
+        /// rest attached to the reply. Takers of buy orders deliver the tokens and get
+        /// the native value attached to the reply. Returns the native value of the fill,
+        /// the fee included.
+        fn fill(
+            &mut self,
+            id: u64,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Fill, Self::Env>;
+        /// Places the order of the message source for `amount` tokens at the price,
+        /// expiring in `ttl_blocks` blocks. Returns its id.
+        ///
+        /// Sell orders escrow the tokens. Buy orders escrow their native value by the
+        /// message value, with the rest attached to the reply.
+        fn place(
+            &mut self,
+            side: Side,
+            amount: U256,
+            price: U256,
+            ttl_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::Place, Self::Env>;
+        fn set_fee_config(
+            &mut self,
+            fee_config: FeeConfig,
+        ) -> sails_rs::client::PendingCall<io::SetFeeConfig, Self::Env>;
+        /// Settles the fills matched off-chain, returning their amount.
+        ///
+        /// Each fill trades tokens escrowed by the sell order for native value escrowed
+        /// by the buy order at the price of the fill, with the fee taken from the value
+        /// paid to the seller. The batch fails as a whole if any of the fills does.
+        ///
+        /// Requirements:
+        ///
+        /// - there must be at most `MAX_BATCH_LEN` fills;
+        /// - makers of the sell and buy orders must sign each fill, see `fill_message`,
+        ///   with their next nonces (the sell maker's first if they're the same).
+        fn settle_batch(
+            &mut self,
+            fills: Vec<SignedFill>,
+        ) -> sails_rs::client::PendingCall<io::SettleBatch, Self::Env>;
+        fn fee_config(&self) -> sails_rs::client::PendingCall<io::FeeConfig, Self::Env>;
+        /// Returns the message to be signed by the maker to agree to the fill.
+        fn fill_message(
+            &self,
+            maker: ActorId,
+            fill: Fill,
+        ) -> sails_rs::client::PendingCall<io::FillMessage, Self::Env>;
+        fn nonce(&self, maker: ActorId) -> sails_rs::client::PendingCall<io::Nonce, Self::Env>;
+        fn order(&self, id: u64) -> sails_rs::client::PendingCall<io::Order, Self::Env>;
+        /// Returns up to `len` orders starting from `cursor`, in no particular order.
+        fn orders(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::Orders, Self::Env>;
+        /// Returns up to `len` orders of the maker starting from `cursor`,
+        /// in ascending order of ids.
+        fn orders_of(
+            &self,
+            maker: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OrdersOf, Self::Env>;
+    }
+    pub struct OrderBookImpl;
+    impl<E: sails_rs::client::GearEnv> OrderBook for sails_rs::client::Service<OrderBookImpl, E> {
+        type Env = E;
+        fn allocate_next_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::AllocateNextShard, Self::Env> {
+            self.pending_call(())
+        }
+        fn cancel(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Cancel, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn fill(
+            &mut self,
+            id: u64,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::Fill, Self::Env> {
+            self.pending_call((id, amount))
+        }
+        fn place(
+            &mut self,
+            side: Side,
+            amount: U256,
+            price: U256,
+            ttl_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::Place, Self::Env> {
+            self.pending_call((side, amount, price, ttl_blocks))
+        }
+        fn set_fee_config(
+            &mut self,
+            fee_config: FeeConfig,
+        ) -> sails_rs::client::PendingCall<io::SetFeeConfig, Self::Env> {
+            self.pending_call((fee_config,))
+        }
+        fn settle_batch(
+            &mut self,
+            fills: Vec<SignedFill>,
+        ) -> sails_rs::client::PendingCall<io::SettleBatch, Self::Env> {
+            self.pending_call((fills,))
+        }
+        fn fee_config(&self) -> sails_rs::client::PendingCall<io::FeeConfig, Self::Env> {
+            self.pending_call(())
+        }
+        fn fill_message(
+            &self,
+            maker: ActorId,
+            fill: Fill,
+        ) -> sails_rs::client::PendingCall<io::FillMessage, Self::Env> {
+            self.pending_call((maker, fill))
+        }
+        fn nonce(&self, maker: ActorId) -> sails_rs::client::PendingCall<io::Nonce, Self::Env> {
+            self.pending_call((maker,))
+        }
+        fn order(&self, id: u64) -> sails_rs::client::PendingCall<io::Order, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn orders(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::Orders, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn orders_of(
+            &self,
+            maker: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OrdersOf, Self::Env> {
+            self.pending_call((maker, cursor, len))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(AllocateNextShard () -> bool);
+        sails_rs::io_struct_impl!(Cancel (id: u64) -> ());
+        sails_rs::io_struct_impl!(Fill (id: u64, amount: U256) -> u128);
+        sails_rs::io_struct_impl!(Place (side: super::Side, amount: U256, price: U256, ttl_blocks: u32) -> u64);
+        sails_rs::io_struct_impl!(SetFeeConfig (fee_config: super::FeeConfig) -> ());
+        sails_rs::io_struct_impl!(SettleBatch (fills: Vec<super::SignedFill>) -> u32);
+        sails_rs::io_struct_impl!(FeeConfig () -> super::FeeConfig);
+        sails_rs::io_struct_impl!(FillMessage (maker: ActorId, fill: super::Fill) -> Vec<u8>);
+        sails_rs::io_struct_impl!(Nonce (maker: ActorId) -> u64);
+        sails_rs::io_struct_impl!(Order (id: u64) -> Option<super::Order>);
+        sails_rs::io_struct_impl!(Orders (cursor: u32, len: u32) -> Vec<(u64,super::Order,)>);
+        sails_rs::io_struct_impl!(OrdersOf (maker: ActorId, cursor: u32, len: u32) -> Vec<(u64,super::Order,)>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum OrderBookEvents {
+            Placed {
+                id: u64,
+                order: Order,
+            },
+            Filled {
+                id: u64,
+                taker: ActorId,
+                amount: U256,
+                value: u128,
+                fee: u128,
+            },
+            Cancelled {
+                id: u64,
+                by: ActorId,
+            },
+            Settled {
+                fill: Fill,
+                value: u128,
+                fee: u128,
+            },
+            FeeConfigChanged(FeeConfig),
+        }
+        impl sails_rs::client::Event for OrderBookEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Placed",
+                "Filled",
+                "Cancelled",
+                "Settled",
+                "FeeConfigChanged",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for OrderBookImpl {
+            type Event = OrderBookEvents;
+        }
+    }
+}
+
+pub mod proxy {
+    use super::*;
+    pub trait Proxy {
+        type Env: sails_rs::client::GearEnv;
+        /// Forwards the payload with the message value to the implementation
+        /// as [`Forwarded`] by the message source, returning its reply.
+        fn forward(
+            &mut self,
+            payload: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::Forward, Self::Env>;
+        /// Sets the implementation calls are forwarded to.
+        ///
+        /// Called only by the program itself, e.g. by a scheduled task.
+        fn set_implementation(
+            &mut self,
+            implementation: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SetImplementation, Self::Env>;
+        fn implementation(&self) -> sails_rs::client::PendingCall<io::Implementation, Self::Env>;
+        fn upgraded_at(&self) -> sails_rs::client::PendingCall<io::UpgradedAt, Self::Env>;
+    }
+    pub struct ProxyImpl;
+    impl<E: sails_rs::client::GearEnv> Proxy for sails_rs::client::Service<ProxyImpl, E> {
+        type Env = E;
+        fn forward(
+            &mut self,
+            payload: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::Forward, Self::Env> {
+            self.pending_call((payload,))
+        }
+        fn set_implementation(
+            &mut self,
+            implementation: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SetImplementation, Self::Env> {
+            self.pending_call((implementation,))
+        }
+        fn implementation(&self) -> sails_rs::client::PendingCall<io::Implementation, Self::Env> {
+            self.pending_call(())
+        }
+        fn upgraded_at(&self) -> sails_rs::client::PendingCall<io::UpgradedAt, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Forward (payload: Vec<u8>) -> Vec<u8>);
+        sails_rs::io_struct_impl!(SetImplementation (implementation: ActorId) -> ());
+        sails_rs::io_struct_impl!(Implementation () -> Option<ActorId>);
+        sails_rs::io_struct_impl!(UpgradedAt () -> u32);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ProxyEvents {
+            Upgraded {
+                previous: ActorId,
+                implementation: ActorId,
+            },
+        }
+        impl sails_rs::client::Event for ProxyEvents {
+            const EVENT_NAMES: &'static [Route] = &["Upgraded"];
+        }
+        impl sails_rs::client::ServiceWithEvents for ProxyImpl {
+            type Event = ProxyEvents;
+        }
+    }
+}
+
+pub mod raffle {
+    use super::*;
+    pub trait Raffle {
+        type Env: sails_rs::client::GearEnv;
+        /// Buys `count` tickets of the open round for VFTs of the message source.
+        ///
+        /// Returns the amount of tickets the message source holds.
+        fn buy_tickets(
+            &mut self,
+            count: u32,
+        ) -> sails_rs::client::PendingCall<io::BuyTickets, Self::Env>;
+        /// Closes the ended round, mixing the randomness of the chain into its entropy,
+        /// so the seed can be revealed by `draw`.
+        ///
+        /// Called by anyone, e.g. ticket holders, right at the end of the round.
+        fn close(&mut self) -> sails_rs::client::PendingCall<io::Close, Self::Env>;
+        /// Draws the winner of the closed round by revealing the seed committed to,
+        /// transferring the prize to the winner, the fee to its recipient and
+        /// the bond back to the operator of the round.
+        ///
+        /// Returns the winner, if any tickets were sold.
+        fn draw(&mut self, seed: Vec<u8>) -> sails_rs::client::PendingCall<io::Draw, Self::Env>;
+        /// Opens the round, committing to the keccak-256 hash of the seed
+        /// revealed by `draw` once the round ends, and escrowing the bond
+        /// of the message source.
+        ///
+        /// Returns the id of the round.
+        fn open(
+            &mut self,
+            params: RoundParams,
+            commitment: H256,
+        ) -> sails_rs::client::PendingCall<io::Open, Self::Env>;
+        /// Refunds tickets of the message source in the round whose seed
+        /// wasn't revealed in time, along with their share of the bond.
+        ///
+        /// Returns the refunded value.
+        fn refund(&mut self, round: u32) -> sails_rs::client::PendingCall<io::Refund, Self::Env>;
+        fn round(&self) -> sails_rs::client::PendingCall<io::Round, Self::Env>;
+        /// Returns the amount of tickets the account holds in the round.
+        fn tickets_of(
+            &self,
+            round: u32,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::TicketsOf, Self::Env>;
+    }
+    pub struct RaffleImpl;
+    impl<E: sails_rs::client::GearEnv> Raffle for sails_rs::client::Service<RaffleImpl, E> {
+        type Env = E;
+        fn buy_tickets(
+            &mut self,
+            count: u32,
+        ) -> sails_rs::client::PendingCall<io::BuyTickets, Self::Env> {
+            self.pending_call((count,))
+        }
+        fn close(&mut self) -> sails_rs::client::PendingCall<io::Close, Self::Env> {
+            self.pending_call(())
+        }
+        fn draw(&mut self, seed: Vec<u8>) -> sails_rs::client::PendingCall<io::Draw, Self::Env> {
+            self.pending_call((seed,))
+        }
+        fn open(
+            &mut self,
+            params: RoundParams,
+            commitment: H256,
+        ) -> sails_rs::client::PendingCall<io::Open, Self::Env> {
+            self.pending_call((params, commitment))
+        }
+        fn refund(&mut self, round: u32) -> sails_rs::client::PendingCall<io::Refund, Self::Env> {
+            self.pending_call((round,))
+        }
+        fn round(&self) -> sails_rs::client::PendingCall<io::Round, Self::Env> {
+            self.pending_call(())
+        }
+        fn tickets_of(
+            &self,
+            round: u32,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::TicketsOf, Self::Env> {
+            self.pending_call((round, account))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(BuyTickets (count: u32) -> u32);
+        sails_rs::io_struct_impl!(Close () -> ());
+        sails_rs::io_struct_impl!(Draw (seed: Vec<u8>) -> Option<ActorId>);
+        sails_rs::io_struct_impl!(Open (params: super::RoundParams, commitment: H256) -> u32);
+        sails_rs::io_struct_impl!(Refund (round: u32) -> U256);
+        sails_rs::io_struct_impl!(Round () -> Option<super::Round>);
+        sails_rs::io_struct_impl!(TicketsOf (round: u32, account: ActorId) -> u32);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum RaffleEvents {
+            RoundOpened {
+                round: u32,
+                params: RoundParams,
+                commitment: H256,
+            },
+            TicketsBought {
+                round: u32,
+                buyer: ActorId,
+                count: u32,
+            },
+            Closed {
+                round: u32,
+                entropy: H256,
+            },
+            Drawn {
+                round: u32,
+                seed: Vec<u8>,
+                winner: Option<ActorId>,
+                prize: U256,
+                fee: U256,
+            },
+            Refunded {
+                round: u32,
+                account: ActorId,
+                value: U256,
+            },
+        }
+        impl sails_rs::client::Event for RaffleEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "RoundOpened",
+                "TicketsBought",
+                "Closed",
+                "Drawn",
+                "Refunded",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for RaffleImpl {
+            type Event = RaffleEvents;
+        }
+    }
+}
+
+pub mod scheduler {
+    use super::*;
+    pub trait Scheduler {
+        type Env: sails_rs::client::GearEnv;
+        /// Cancels the task of the message source, refunding its value and bounty.
+        fn cancel(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Cancel, Self::Env>;
+        /// Executes up to `limit` due tasks, paying their bounties to the message source.
+        ///
+        /// Tasks which can't be sent are marked failed and skipped, with their value
+        /// and bounty kept to be refunded on cancellation.
+        ///
+        /// Returns the number of executed tasks.
+        fn execute_due(
+            &mut self,
+            limit: u32,
+        ) -> sails_rs::client::PendingCall<io::ExecuteDue, Self::Env>;
+        /// Funds the keeper bounty with the message value.
+        fn fund_keeper_bounty(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::FundKeeperBounty, Self::Env>;
+        /// Schedules a message to `target` at block `execute_at`.
+        ///
+        /// Message value must cover `value`, with the rest paid as a bounty
+        /// to the account executing the task.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `SCHEDULER_ROLE`.
+        /// - `target` must not be the program itself, see `schedule_self`.
+        fn schedule(
+            &mut self,
+            execute_at: u32,
+            target: ActorId,
+            payload: Vec<u8>,
+            value: u128,
+        ) -> sails_rs::client::PendingCall<io::Schedule, Self::Env>;
+        /// Schedules a message to the program itself at block `execute_at`, e.g. a call
+        /// allowed to the program only, with the message value paid as a bounty
+        /// to the account executing the task.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must have `DEFAULT_ADMIN_ROLE`.
+        /// - `execute_at` must be at least `min_self_delay` blocks ahead.
+        fn schedule_self(
+            &mut self,
+            execute_at: u32,
+            payload: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::ScheduleSelf, Self::Env>;
+        /// Sets parameters of the keeper bounty, keeping its budget.
+        fn set_keeper_bounty(
+            &mut self,
+            reward_per_task: u128,
+            max_tasks_per_call: u32,
+            max_payout_per_block: u128,
+        ) -> sails_rs::client::PendingCall<io::SetKeeperBounty, Self::Env>;
+        /// Sets the minimal delay of tasks to the program itself.
+        ///
+        /// Called only by the program itself, i.e. by a task scheduled
+        /// the current minimal delay ahead.
+        fn set_min_self_delay(
+            &mut self,
+            min_self_delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMinSelfDelay, Self::Env>;
+        /// Returns ids of up to `len` tasks due at the current block, earliest first.
+        fn due_tasks(&self, len: u32) -> sails_rs::client::PendingCall<io::DueTasks, Self::Env>;
+        /// Returns `true` if the task failed to be executed.
+        fn is_failed(&self, id: u64) -> sails_rs::client::PendingCall<io::IsFailed, Self::Env>;
+        fn keeper_bounty(&self) -> sails_rs::client::PendingCall<io::KeeperBounty, Self::Env>;
+        fn min_self_delay(&self) -> sails_rs::client::PendingCall<io::MinSelfDelay, Self::Env>;
+        fn task(&self, id: u64) -> sails_rs::client::PendingCall<io::Task, Self::Env>;
+    }
+    pub struct SchedulerImpl;
+    impl<E: sails_rs::client::GearEnv> Scheduler for sails_rs::client::Service<SchedulerImpl, E> {
+        type Env = E;
+        fn cancel(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Cancel, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn execute_due(
+            &mut self,
+            limit: u32,
+        ) -> sails_rs::client::PendingCall<io::ExecuteDue, Self::Env> {
+            self.pending_call((limit,))
+        }
+        fn fund_keeper_bounty(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::FundKeeperBounty, Self::Env> {
+            self.pending_call(())
+        }
+        fn schedule(
+            &mut self,
+            execute_at: u32,
+            target: ActorId,
+            payload: Vec<u8>,
+            value: u128,
+        ) -> sails_rs::client::PendingCall<io::Schedule, Self::Env> {
+            self.pending_call((execute_at, target, payload, value))
+        }
+        fn schedule_self(
+            &mut self,
+            execute_at: u32,
+            payload: Vec<u8>,
+        ) -> sails_rs::client::PendingCall<io::ScheduleSelf, Self::Env> {
+            self.pending_call((execute_at, payload))
+        }
+        fn set_keeper_bounty(
+            &mut self,
+            reward_per_task: u128,
+            max_tasks_per_call: u32,
+            max_payout_per_block: u128,
+        ) -> sails_rs::client::PendingCall<io::SetKeeperBounty, Self::Env> {
+            self.pending_call((reward_per_task, max_tasks_per_call, max_payout_per_block))
+        }
+        fn set_min_self_delay(
+            &mut self,
+            min_self_delay: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMinSelfDelay, Self::Env> {
+            self.pending_call((min_self_delay,))
+        }
+        fn due_tasks(&self, len: u32) -> sails_rs::client::PendingCall<io::DueTasks, Self::Env> {
+            self.pending_call((len,))
+        }
+        fn is_failed(&self, id: u64) -> sails_rs::client::PendingCall<io::IsFailed, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn keeper_bounty(&self) -> sails_rs::client::PendingCall<io::KeeperBounty, Self::Env> {
+            self.pending_call(())
+        }
+        fn min_self_delay(&self) -> sails_rs::client::PendingCall<io::MinSelfDelay, Self::Env> {
+            self.pending_call(())
+        }
+        fn task(&self, id: u64) -> sails_rs::client::PendingCall<io::Task, Self::Env> {
+            self.pending_call((id,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Cancel (id: u64) -> bool);
+        sails_rs::io_struct_impl!(ExecuteDue (limit: u32) -> u32);
+        sails_rs::io_struct_impl!(FundKeeperBounty () -> ());
+        sails_rs::io_struct_impl!(Schedule (execute_at: u32, target: ActorId, payload: Vec<u8>, value: u128) -> u64);
+        sails_rs::io_struct_impl!(ScheduleSelf (execute_at: u32, payload: Vec<u8>) -> u64);
+        sails_rs::io_struct_impl!(SetKeeperBounty (reward_per_task: u128, max_tasks_per_call: u32, max_payout_per_block: u128) -> ());
+        sails_rs::io_struct_impl!(SetMinSelfDelay (min_self_delay: u32) -> ());
+        sails_rs::io_struct_impl!(DueTasks (len: u32) -> Vec<u64>);
+        sails_rs::io_struct_impl!(IsFailed (id: u64) -> bool);
+        sails_rs::io_struct_impl!(KeeperBounty () -> super::Bounty);
+        sails_rs::io_struct_impl!(MinSelfDelay () -> u32);
+        sails_rs::io_struct_impl!(Task (id: u64) -> Option<super::Task>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum SchedulerEvents {
+            Scheduled {
+                id: u64,
+                execute_at: u32,
+                target: ActorId,
+            },
+            Executed {
+                id: u64,
+                target: ActorId,
+            },
+            Failed {
+                id: u64,
+                target: ActorId,
+            },
+            Cancelled(u64),
+            KeeperBountySet {
+                reward_per_task: u128,
+                max_tasks_per_call: u32,
+                max_payout_per_block: u128,
+            },
+            KeeperBountyFunded(u128),
+            MinSelfDelaySet(u32),
+        }
+        impl sails_rs::client::Event for SchedulerEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Scheduled",
+                "Executed",
+                "Failed",
+                "Cancelled",
+                "KeeperBountySet",
+                "KeeperBountyFunded",
+                "MinSelfDelaySet",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for SchedulerImpl {
+            type Event = SchedulerEvents;
+        }
+    }
+}
+
+pub mod session_keys {
+    use super::*;
+    pub trait SessionKeys {
+        type Env: sails_rs::client::GearEnv;
+        /// Accepts the session the account proposed to the message source as its key.
+        fn accept(
+            &mut self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Accept, Self::Env>;
+        /// Approves `value` of VFTs of the account to the spender by its key
+        /// allowed to [`APPROVE`], see `Vft::approve`.
+        ///
+        /// Returns `true` if the allowance changed.
+        fn approve_as(
+            &mut self,
+            account: ActorId,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveAs, Self::Env>;
+        /// Removes expired sessions of the keys, along with expired sessions
+        /// proposed to them, freeing the storage. Callable by anyone.
+        ///
+        /// Returns amount of removed sessions, emitting `SessionRevoked` for each.
+        fn prune(
+            &mut self,
+            keys: Vec<ActorId>,
+        ) -> sails_rs::client::PendingCall<io::Prune, Self::Env>;
+        /// Proposes the key to act on behalf of the message source
+        /// within blocks `valid_from..=valid_until` and the scope of methods.
+        ///
+        /// The session takes effect once accepted by the key, see [`Self::accept`].
+        /// Proposing the key again replaces its proposal, while accepting replaces its session.
+        fn register(
+            &mut self,
+            key: ActorId,
+            valid_from: u32,
+            valid_until: u32,
+            scope: Vec<String>,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env>;
+        /// Revokes the key of the message source.
+        ///
+        /// Returns `true` if the key was registered.
+        fn revoke(&mut self, key: ActorId) -> sails_rs::client::PendingCall<io::Revoke, Self::Env>;
+        /// Transfers `value` of VFTs of the account to `to` by its key
+        /// allowed to [`TRANSFER`], see `Vft::transfer`.
+        fn transfer_as(
+            &mut self,
+            account: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferAs, Self::Env>;
+        fn keys_of(&self, account: ActorId)
+        -> sails_rs::client::PendingCall<io::KeysOf, Self::Env>;
+        fn proposal(
+            &self,
+            key: ActorId,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Proposal, Self::Env>;
+        fn session(&self, key: ActorId) -> sails_rs::client::PendingCall<io::Session, Self::Env>;
+    }
+    pub struct SessionKeysImpl;
+    impl<E: sails_rs::client::GearEnv> SessionKeys for sails_rs::client::Service<SessionKeysImpl, E> {
+        type Env = E;
+        fn accept(
+            &mut self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Accept, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn approve_as(
+            &mut self,
+            account: ActorId,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveAs, Self::Env> {
+            self.pending_call((account, spender, value))
+        }
+        fn prune(
+            &mut self,
+            keys: Vec<ActorId>,
+        ) -> sails_rs::client::PendingCall<io::Prune, Self::Env> {
+            self.pending_call((keys,))
+        }
+        fn register(
+            &mut self,
+            key: ActorId,
+            valid_from: u32,
+            valid_until: u32,
+            scope: Vec<String>,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env> {
+            self.pending_call((key, valid_from, valid_until, scope))
+        }
+        fn revoke(&mut self, key: ActorId) -> sails_rs::client::PendingCall<io::Revoke, Self::Env> {
+            self.pending_call((key,))
+        }
+        fn transfer_as(
+            &mut self,
+            account: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferAs, Self::Env> {
+            self.pending_call((account, to, value))
+        }
+        fn keys_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::KeysOf, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn proposal(
+            &self,
+            key: ActorId,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Proposal, Self::Env> {
+            self.pending_call((key, account))
+        }
+        fn session(&self, key: ActorId) -> sails_rs::client::PendingCall<io::Session, Self::Env> {
+            self.pending_call((key,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Accept (account: ActorId) -> ());
+        sails_rs::io_struct_impl!(ApproveAs (account: ActorId, spender: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Prune (keys: Vec<ActorId>) -> u32);
+        sails_rs::io_struct_impl!(Register (key: ActorId, valid_from: u32, valid_until: u32, scope: Vec<String>) -> ());
+        sails_rs::io_struct_impl!(Revoke (key: ActorId) -> bool);
+        sails_rs::io_struct_impl!(TransferAs (account: ActorId, to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(KeysOf (account: ActorId) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(Proposal (key: ActorId, account: ActorId) -> Option<super::Session>);
+        sails_rs::io_struct_impl!(Session (key: ActorId) -> Option<super::Session>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum SessionKeysEvents {
+            /// Session was proposed to the key, taking effect once accepted, see `SessionKeys::accept`.
+            SessionProposed {
+                account: ActorId,
+                key: ActorId,
+                valid_from: u32,
+                valid_until: u32,
+            },
+            SessionRegistered {
+                account: ActorId,
+                key: ActorId,
+                valid_from: u32,
+                valid_until: u32,
+            },
+            SessionRevoked {
+                account: ActorId,
+                key: ActorId,
+            },
+        }
+        impl sails_rs::client::Event for SessionKeysEvents {
+            const EVENT_NAMES: &'static [Route] =
+                &["SessionProposed", "SessionRegistered", "SessionRevoked"];
+        }
+        impl sails_rs::client::ServiceWithEvents for SessionKeysImpl {
+            type Event = SessionKeysEvents;
+        }
+    }
+}
+
+pub mod smart_account {
+    use super::*;
+    pub trait SmartAccount {
+        type Env: sails_rs::client::GearEnv;
+        /// Approves rotation of the owner to `new_owner` by the guardian.
+        ///
+        /// Returns `true` if the owner is rotated.
+        fn approve_recovery(
+            &mut self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ApproveRecovery, Self::Env>;
+        /// Cancels pending recoveries.
+        ///
+        /// Returns `true` if any recovery was pending.
+        fn cancel_recovery(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env>;
+        /// Sends the payload with `value` of the account to the destination,
+        /// returning the reply.
+        ///
+        /// Requirements:
+        ///
+        /// - the caller must be the owner or its session key allowed to [`EXECUTE`].
+        /// - `destination` must not be the program itself, as it acts on behalf
+        ///   of other services composed in it, e.g. holding their escrow.
+        fn execute(
+            &mut self,
+            destination: ActorId,
+            payload: Vec<u8>,
+            value: u128,
+        ) -> sails_rs::client::PendingCall<io::Execute, Self::Env>;
+        /// Appoints guardians, `threshold` of which rotate the owner.
+        ///
+        /// Empty guardians with zero threshold disable recovery.
+        fn set_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+        ) -> sails_rs::client::PendingCall<io::SetGuardians, Self::Env>;
+        /// Transfers the account to the new owner.
+        fn transfer_ownership(
+            &mut self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::TransferOwnership, Self::Env>;
+        fn guardians(&self) -> sails_rs::client::PendingCall<io::Guardians, Self::Env>;
+        fn owner(&self) -> sails_rs::client::PendingCall<io::Owner, Self::Env>;
+        /// Returns pending recoveries, in ascending order of new owners.
+        fn recoveries(&self) -> sails_rs::client::PendingCall<io::Recoveries, Self::Env>;
+        /// Returns the pending recovery rotating the owner to `new_owner`, if any.
+        fn recovery(
+            &self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Recovery, Self::Env>;
+        fn threshold(&self) -> sails_rs::client::PendingCall<io::Threshold, Self::Env>;
+    }
+    pub struct SmartAccountImpl;
+    impl<E: sails_rs::client::GearEnv> SmartAccount for sails_rs::client::Service<SmartAccountImpl, E> {
+        type Env = E;
+        fn approve_recovery(
+            &mut self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ApproveRecovery, Self::Env> {
+            self.pending_call((new_owner,))
+        }
+        fn cancel_recovery(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::CancelRecovery, Self::Env> {
+            self.pending_call(())
+        }
+        fn execute(
+            &mut self,
+            destination: ActorId,
+            payload: Vec<u8>,
+            value: u128,
+        ) -> sails_rs::client::PendingCall<io::Execute, Self::Env> {
+            self.pending_call((destination, payload, value))
+        }
+        fn set_guardians(
+            &mut self,
+            guardians: Vec<ActorId>,
+            threshold: u32,
+        ) -> sails_rs::client::PendingCall<io::SetGuardians, Self::Env> {
+            self.pending_call((guardians, threshold))
+        }
+        fn transfer_ownership(
+            &mut self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::TransferOwnership, Self::Env> {
+            self.pending_call((new_owner,))
+        }
+        fn guardians(&self) -> sails_rs::client::PendingCall<io::Guardians, Self::Env> {
+            self.pending_call(())
+        }
+        fn owner(&self) -> sails_rs::client::PendingCall<io::Owner, Self::Env> {
+            self.pending_call(())
+        }
+        fn recoveries(&self) -> sails_rs::client::PendingCall<io::Recoveries, Self::Env> {
+            self.pending_call(())
+        }
+        fn recovery(
+            &self,
+            new_owner: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Recovery, Self::Env> {
+            self.pending_call((new_owner,))
+        }
+        fn threshold(&self) -> sails_rs::client::PendingCall<io::Threshold, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(ApproveRecovery (new_owner: ActorId) -> bool);
+        sails_rs::io_struct_impl!(CancelRecovery () -> bool);
+        sails_rs::io_struct_impl!(Execute (destination: ActorId, payload: Vec<u8>, value: u128) -> Vec<u8>);
+        sails_rs::io_struct_impl!(SetGuardians (guardians: Vec<ActorId>, threshold: u32) -> ());
+        sails_rs::io_struct_impl!(TransferOwnership (new_owner: ActorId) -> ());
+        sails_rs::io_struct_impl!(Guardians () -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(Owner () -> ActorId);
+        sails_rs::io_struct_impl!(Recoveries () -> Vec<super::Recovery>);
+        sails_rs::io_struct_impl!(Recovery (new_owner: ActorId) -> Option<super::Recovery>);
+        sails_rs::io_struct_impl!(Threshold () -> u32);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum SmartAccountEvents {
+            Executed {
+                executor: ActorId,
+                destination: ActorId,
+                value: u128,
+            },
+            OwnerChanged {
+                previous: ActorId,
+                new: ActorId,
+            },
+            GuardiansSet {
+                guardians: Vec<ActorId>,
+                threshold: u32,
+            },
+            RecoveryApproved {
+                guardian: ActorId,
+                new_owner: ActorId,
+            },
+            RecoveryCancelled,
+        }
+        impl sails_rs::client::Event for SmartAccountEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Executed",
+                "OwnerChanged",
+                "GuardiansSet",
+                "RecoveryApproved",
+                "RecoveryCancelled",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for SmartAccountImpl {
+            type Event = SmartAccountEvents;
+        }
+    }
+}
+
+pub mod token_registry {
+    use super::*;
+    pub trait TokenRegistry {
+        type Env: sails_rs::client::GearEnv;
+        /// Registers the token program deployed by the message source,
+        /// or its newer version.
+        fn register(
+            &mut self,
+            program_id: ActorId,
+            metadata: TokenMetadata,
+            code_id: CodeId,
+            version: u32,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env>;
+        /// Removes the token from the registry.
+        ///
+        /// Returns `true` if the token was registered.
+        fn remove(
+            &mut self,
+            program_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Remove, Self::Env>;
+        /// Sets whether the token is verified.
+        ///
+        /// Returns `true` if the verification changed.
+        fn verify(
+            &mut self,
+            program_id: ActorId,
+            verified: bool,
+        ) -> sails_rs::client::PendingCall<io::Verify, Self::Env>;
+        fn token(&self, program_id: ActorId)
+        -> sails_rs::client::PendingCall<io::Token, Self::Env>;
+        /// Returns up to `len` registered tokens starting from `cursor`,
+        /// only verified ones if `verified_only`, in ascending order of program ids.
+        fn tokens(
+            &self,
+            cursor: u32,
+            len: u32,
+            verified_only: bool,
+        ) -> sails_rs::client::PendingCall<io::Tokens, Self::Env>;
+        /// Returns up to `len` programs registered by the deployer starting from `cursor`,
+        /// in ascending order.
+        fn tokens_of(
+            &self,
+            deployer: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::TokensOf, Self::Env>;
+    }
+    pub struct TokenRegistryImpl;
+    impl<E: sails_rs::client::GearEnv> TokenRegistry
+        for sails_rs::client::Service<TokenRegistryImpl, E>
+    {
+        type Env = E;
+        fn register(
+            &mut self,
+            program_id: ActorId,
+            metadata: TokenMetadata,
+            code_id: CodeId,
+            version: u32,
+        ) -> sails_rs::client::PendingCall<io::Register, Self::Env> {
+            self.pending_call((program_id, metadata, code_id, version))
+        }
+        fn remove(
+            &mut self,
+            program_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Remove, Self::Env> {
+            self.pending_call((program_id,))
+        }
+        fn verify(
+            &mut self,
+            program_id: ActorId,
+            verified: bool,
+        ) -> sails_rs::client::PendingCall<io::Verify, Self::Env> {
+            self.pending_call((program_id, verified))
+        }
+        fn token(
+            &self,
+            program_id: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Token, Self::Env> {
+            self.pending_call((program_id,))
+        }
+        fn tokens(
+            &self,
+            cursor: u32,
+            len: u32,
+            verified_only: bool,
+        ) -> sails_rs::client::PendingCall<io::Tokens, Self::Env> {
+            self.pending_call((cursor, len, verified_only))
+        }
+        fn tokens_of(
+            &self,
+            deployer: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::TokensOf, Self::Env> {
+            self.pending_call((deployer, cursor, len))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Register (program_id: ActorId, metadata: super::TokenMetadata, code_id: CodeId, version: u32) -> ());
+        sails_rs::io_struct_impl!(Remove (program_id: ActorId) -> bool);
+        sails_rs::io_struct_impl!(Verify (program_id: ActorId, verified: bool) -> bool);
+        sails_rs::io_struct_impl!(Token (program_id: ActorId) -> Option<super::TokenInfo>);
+        sails_rs::io_struct_impl!(Tokens (cursor: u32, len: u32, verified_only: bool) -> Vec<(ActorId,super::TokenInfo,)>);
+        sails_rs::io_struct_impl!(TokensOf (deployer: ActorId, cursor: u32, len: u32) -> Vec<ActorId>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum TokenRegistryEvents {
+            Registered {
+                program_id: ActorId,
+                deployer: ActorId,
+                version: u32,
+            },
+            Verified {
+                program_id: ActorId,
+                verified: bool,
+            },
+            Removed(ActorId),
+        }
+        impl sails_rs::client::Event for TokenRegistryEvents {
+            const EVENT_NAMES: &'static [Route] = &["Registered", "Verified", "Removed"];
+        }
+        impl sails_rs::client::ServiceWithEvents for TokenRegistryImpl {
+            type Event = TokenRegistryEvents;
+        }
+    }
+}
+
+pub mod treasury {
+    use super::*;
+    pub trait Treasury {
+        type Env: sails_rs::client::GearEnv;
+        /// Approves the pending proposal, paying it out within the budget of its asset.
+        fn approve_spend(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::ApproveSpend, Self::Env>;
+        /// Deposits the message value to the treasury.
+        fn deposit(&mut self) -> sails_rs::client::PendingCall<io::Deposit, Self::Env>;
+        /// Deposits `amount` of VFTs of the message source to the treasury.
+        fn deposit_vft(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::DepositVft, Self::Env>;
+        /// Proposes to spend the amount of the asset to the beneficiary.
+        fn propose_spend(
+            &mut self,
+            beneficiary: ActorId,
+            asset: Asset,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::ProposeSpend, Self::Env>;
+        fn reject_spend(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::RejectSpend, Self::Env>;
+        fn remove_budget(
+            &mut self,
+            asset: Asset,
+        ) -> sails_rs::client::PendingCall<io::RemoveBudget, Self::Env>;
+        /// Limits the amount of the asset spent per `period_blocks` blocks.
+        ///
+        /// Replaces any existing budget, starting a fresh period.
+        fn set_budget(
+            &mut self,
+            asset: Asset,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::SetBudget, Self::Env>;
+        fn budget(&self, asset: Asset) -> sails_rs::client::PendingCall<io::Budget, Self::Env>;
+        /// Returns the amount of the asset still available to spend in the current period,
+        /// or `None` if the asset is not budgeted.
+        fn budget_remaining(
+            &self,
+            asset: Asset,
+        ) -> sails_rs::client::PendingCall<io::BudgetRemaining, Self::Env>;
+        /// Returns the amount of the asset deposited to the treasury and not yet spent.
+        fn funds(&self, asset: Asset) -> sails_rs::client::PendingCall<io::Funds, Self::Env>;
+        fn proposal(&self, id: u64) -> sails_rs::client::PendingCall<io::Proposal, Self::Env>;
+        /// Returns up to `len` proposals, starting from the `cursor` id.
+        ///
+        /// Fails with `PageTooLarge` if `len` exceeds the max page size of the program.
+        fn proposals(
+            &self,
+            cursor: u64,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::Proposals, Self::Env>;
+    }
+    pub struct TreasuryImpl;
+    impl<E: sails_rs::client::GearEnv> Treasury for sails_rs::client::Service<TreasuryImpl, E> {
+        type Env = E;
+        fn approve_spend(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::ApproveSpend, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn deposit(&mut self) -> sails_rs::client::PendingCall<io::Deposit, Self::Env> {
+            self.pending_call(())
+        }
+        fn deposit_vft(
+            &mut self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::DepositVft, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn propose_spend(
+            &mut self,
+            beneficiary: ActorId,
+            asset: Asset,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::ProposeSpend, Self::Env> {
+            self.pending_call((beneficiary, asset, amount))
+        }
+        fn reject_spend(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::RejectSpend, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn remove_budget(
+            &mut self,
+            asset: Asset,
+        ) -> sails_rs::client::PendingCall<io::RemoveBudget, Self::Env> {
+            self.pending_call((asset,))
+        }
+        fn set_budget(
+            &mut self,
+            asset: Asset,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::SetBudget, Self::Env> {
+            self.pending_call((asset, amount_per_period, period_blocks))
+        }
+        fn budget(&self, asset: Asset) -> sails_rs::client::PendingCall<io::Budget, Self::Env> {
+            self.pending_call((asset,))
+        }
+        fn budget_remaining(
+            &self,
+            asset: Asset,
+        ) -> sails_rs::client::PendingCall<io::BudgetRemaining, Self::Env> {
+            self.pending_call((asset,))
+        }
+        fn funds(&self, asset: Asset) -> sails_rs::client::PendingCall<io::Funds, Self::Env> {
+            self.pending_call((asset,))
+        }
+        fn proposal(&self, id: u64) -> sails_rs::client::PendingCall<io::Proposal, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn proposals(
+            &self,
+            cursor: u64,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::Proposals, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(ApproveSpend (id: u64) -> ());
+        sails_rs::io_struct_impl!(Deposit () -> ());
+        sails_rs::io_struct_impl!(DepositVft (amount: U256) -> ());
+        sails_rs::io_struct_impl!(ProposeSpend (beneficiary: ActorId, asset: super::Asset, amount: U256) -> u64);
+        sails_rs::io_struct_impl!(RejectSpend (id: u64) -> ());
+        sails_rs::io_struct_impl!(RemoveBudget (asset: super::Asset) -> bool);
+        sails_rs::io_struct_impl!(SetBudget (asset: super::Asset, amount_per_period: U256, period_blocks: u32) -> ());
+        sails_rs::io_struct_impl!(Budget (asset: super::Asset) -> Option<super::PeriodBudget>);
+        sails_rs::io_struct_impl!(BudgetRemaining (asset: super::Asset) -> Option<U256>);
+        sails_rs::io_struct_impl!(Funds (asset: super::Asset) -> U256);
+        sails_rs::io_struct_impl!(Proposal (id: u64) -> Option<super::Proposal>);
+        sails_rs::io_struct_impl!(Proposals (cursor: u64, len: u32) -> Vec<(u64,super::Proposal,)>);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum TreasuryEvents {
+            Deposited {
+                from: ActorId,
+                asset: Asset,
+                amount: U256,
+            },
+            SpendProposed {
+                id: u64,
+                proposer: ActorId,
+                beneficiary: ActorId,
+                asset: Asset,
+                amount: U256,
+            },
+            SpendExecuted {
+                id: u64,
+                treasurer: ActorId,
+            },
+            SpendRejected {
+                id: u64,
+                treasurer: ActorId,
+            },
+            BudgetSet {
+                asset: Asset,
+                amount_per_period: U256,
+                period_blocks: u32,
+            },
+            BudgetRemoved(Asset),
+        }
+        impl sails_rs::client::Event for TreasuryEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Deposited",
+                "SpendProposed",
+                "SpendExecuted",
+                "SpendRejected",
+                "BudgetSet",
+                "BudgetRemoved",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for TreasuryImpl {
+            type Event = TreasuryEvents;
+        }
+    }
+}
+
+pub mod vft {
+    use super::*;
+    pub trait Vft {
+        type Env: sails_rs::client::GearEnv;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env>;
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env>;
+        fn transfer_from(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env>;
+        fn allowance(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env>;
+        fn balance_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env>;
+        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env>;
+    }
+    pub struct VftImpl;
+    impl<E: sails_rs::client::GearEnv> Vft for sails_rs::client::Service<VftImpl, E> {
+        type Env = E;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Approve, Self::Env> {
+            self.pending_call((spender, value))
+        }
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Transfer, Self::Env> {
+            self.pending_call((to, value))
+        }
+        fn transfer_from(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::TransferFrom, Self::Env> {
+            self.pending_call((from, to, value))
+        }
+        fn allowance(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Allowance, Self::Env> {
+            self.pending_call((owner, spender))
+        }
+        fn balance_of(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn total_supply(&self) -> sails_rs::client::PendingCall<io::TotalSupply, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Approve (spender: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Transfer (to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(TransferFrom (from: ActorId, to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Allowance (owner: ActorId, spender: ActorId) -> U256);
+        sails_rs::io_struct_impl!(BalanceOf (account: ActorId) -> U256);
+        sails_rs::io_struct_impl!(TotalSupply () -> U256);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum VftEvents {
+            Approval {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
+            Transfer {
+                from: ActorId,
+                to: ActorId,
+                value: U256,
+            },
+            /// Account was removed from balances, as its balance hit zero
+            /// or fell below the minimum, leaving `dust`.
+            AccountReaped { account: ActorId, dust: U256 },
+            /// Spending cap of the spender was set, or removed if `amount_per_period` is zero.
+            SpendingCapSet {
+                owner: ActorId,
+                spender: ActorId,
+                amount_per_period: U256,
+                period_blocks: u32,
+            },
+            /// Operator of the owner was approved or revoked.
+            OperatorSet {
+                owner: ActorId,
+                operator: ActorId,
+                approved: bool,
+            },
+            /// Value was escrowed until claimed by the recipient, see `VftExtension::transfer_claimable`.
+            ClaimCreated {
+                id: u64,
+                from: ActorId,
+                to: ActorId,
+                value: U256,
+                expires_at: u32,
+            },
+            /// Escrowed value was paid out, to the recipient if claimed or to the sender if reclaimed.
+            ClaimSettled { id: u64, to: ActorId, value: U256 },
+            /// Snapshot of holders was computed, see `VftExtension::compute_snapshot`.
+            SnapshotTaken {
+                block: u32,
+                root: [u8; 32],
+                holders: u32,
+            },
+            /// Insert filled shards of the map up, so next inserts fail until shards
+            /// are appended or allocated, see `VftAdmin::append_balances_shard`.
+            StorageSaturated {
+                map: StorageMap,
+                shards: u32,
+                capacity: u32,
+            },
+            /// Insert made occupancy of the map cross the threshold of its alert,
+            /// see `VftAdmin::set_capacity_alert`.
+            CapacityWarning {
+                map: StorageMap,
+                entries: u32,
+                capacity: u32,
+            },
+            /// Volume within the rolling window exceeded thresholds of the circuit breaker,
+            /// so mints and transfers were halted until resumed, see `VftAdmin::resume`.
+            CircuitBreakerTripped { minted: U256, transferred: U256 },
+        }
+        impl sails_rs::client::Event for VftEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "Approval",
+                "Transfer",
+                "AccountReaped",
+                "SpendingCapSet",
+                "OperatorSet",
+                "ClaimCreated",
+                "ClaimSettled",
+                "SnapshotTaken",
+                "StorageSaturated",
+                "CapacityWarning",
+                "CircuitBreakerTripped",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for VftImpl {
+            type Event = VftEvents;
+        }
+    }
+}
+
+pub mod vft_admin {
+    use super::*;
+    pub trait VftAdmin {
+        type Env: sails_rs::client::GearEnv;
+        /// Adds the rule minting `amount` to `recipient` every `interval_blocks` blocks,
+        /// `count` times, starting an interval after the current block.
+        ///
+        /// Returns the id of the rule.
+        fn add_mint_rule(
+            &mut self,
+            recipient: ActorId,
+            amount: U256,
+            interval_blocks: u32,
+            count: u32,
+        ) -> sails_rs::client::PendingCall<io::AddMintRule, Self::Env>;
+        fn append_allowances_shard(
+            &mut self,
+            capacity: u32,
+        ) -> sails_rs::client::PendingCall<io::AppendAllowancesShard, Self::Env>;
+        fn append_balances_shard(
+            &mut self,
+            capacity: u32,
+        ) -> sails_rs::client::PendingCall<io::AppendBalancesShard, Self::Env>;
+        fn approve_from(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveFrom, Self::Env>;
+        /// Resumes the program once the block scheduled by `pause` comes.
+        ///
+        /// Only the program itself calls it, by the delayed message sent by `pause`.
+        /// Returns `false` if the resume is no longer scheduled for this block,
+        /// e.g. the program was resumed and paused again in between.
+        fn auto_resume(&mut self) -> sails_rs::client::PendingCall<io::AutoResume, Self::Env>;
+        fn burn(
+            &mut self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env>;
+        /// Burns VFTs from each of the specified addresses.
+        ///
+        /// Either all burns succeed or the whole batch fails.
+        fn burn_batch(
+            &mut self,
+            burns: Vec<(ActorId, U256)>,
+        ) -> sails_rs::client::PendingCall<io::BurnBatch, Self::Env>;
+        /// Checks volume minted and transferred within the rolling window against
+        /// the circuit breaker, halting mints and transfers if it exceeds thresholds.
+        ///
+        /// Mints and transfers check it themselves, so it's only needed for volume
+        /// moved by services not checking it. May be called by anyone.
+        ///
+        /// Returns `true` if the breaker tripped.
+        fn check_circuit_breaker(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::CheckCircuitBreaker, Self::Env>;
+        fn exit(
+            &mut self,
+            inheritor: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Exit, Self::Env>;
+        /// Transfers VFTs between arbitrary accounts, bypassing allowances.
+        ///
+        /// Intended for incident response only.
+        fn force_transfer(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ForceTransfer, Self::Env>;
+        fn mint(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env>;
+        /// Pauses the program, recording the caller, the block and the reason,
+        /// so wallets can show users why their calls fail.
+        ///
+        /// If `resume_after` is set, the program resumes after that many blocks,
+        /// see [`AUTO_RESUME_ROUTE`]; otherwise the scheduled resume, if any, is cancelled.
+        fn pause(
+            &mut self,
+            reason: Option<PauseReason>,
+            resume_after: Option<u32>,
+        ) -> sails_rs::client::PendingCall<io::Pause, Self::Env>;
+        /// Removes the last allowances shard holding no entries, freeing its memory.
+        ///
+        /// Returns the max capacity of the removed shard, e.g. to append it
+        /// to balances by `append_balances_shard`.
+        fn remove_empty_allowances_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::RemoveEmptyAllowancesShard, Self::Env>;
+        /// Removes the last balances shard holding no entries, freeing its memory.
+        ///
+        /// Returns the max capacity of the removed shard, e.g. to append it
+        /// to allowances by `append_allowances_shard`.
+        fn remove_empty_balances_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::RemoveEmptyBalancesShard, Self::Env>;
+        fn remove_mint_rule(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::RemoveMintRule, Self::Env>;
+        fn remove_minter_cap(
+            &mut self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::RemoveMinterCap, Self::Env>;
+        fn resume(&mut self) -> sails_rs::client::PendingCall<io::Resume, Self::Env>;
+        /// Sets the alert of operators about occupancy of the map, or removes it.
+        ///
+        /// Once inserts make occupancy of allocated shards of the map cross
+        /// the threshold, `Vft` emits `CapacityWarning` and notifies the operator.
+        fn set_capacity_alert(
+            &mut self,
+            map: StorageMap,
+            alert: Option<CapacityAlert>,
+        ) -> sails_rs::client::PendingCall<io::SetCapacityAlert, Self::Env>;
+        /// Sets thresholds of the circuit breaker, starting a new window.
+        fn set_circuit_breaker(
+            &mut self,
+            config: CircuitBreakerConfig,
+        ) -> sails_rs::client::PendingCall<io::SetCircuitBreaker, Self::Env>;
+        /// Sets the policy of handling dust left by balances falling below the minimum.
+        fn set_dust_policy(
+            &mut self,
+            policy: DustPolicy,
+        ) -> sails_rs::client::PendingCall<io::SetDustPolicy, Self::Env>;
+        fn set_expiry_period(
+            &mut self,
+            period: u32,
+        ) -> sails_rs::client::PendingCall<io::SetExpiryPeriod, Self::Env>;
+        /// Sets the max amount of items enumeration exports of the VFT-Extension
+        /// service reply with at once.
+        fn set_max_page_size(
+            &mut self,
+            value: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env>;
+        /// Sets the minimum balance of an account: balances falling below it are
+        /// removed, with the remaining dust handled by the dust policy.
+        fn set_minimum_balance(
+            &mut self,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::SetMinimumBalance, Self::Env>;
+        /// Limits the amount of VFTs the minter may mint per `period_blocks` blocks.
+        ///
+        /// Replaces any existing cap, starting a fresh period.
+        fn set_minter_cap(
+            &mut self,
+            minter: ActorId,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMinterCap, Self::Env>;
+        /// Executes up to [`MAX_MINTS_PER_TICK`] due mints of recurring mint rules,
+        /// each checking the circuit breaker.
+        ///
+        /// May be called by anyone, e.g. keepers or tasks of the scheduler.
+        ///
+        /// Returns the amount of executed mints.
+        fn tick(&mut self) -> sails_rs::client::PendingCall<io::Tick, Self::Env>;
+        /// Returns amount of entries allocated allowances shards may still hold.
+        fn allowances_unused_capacity(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::AllowancesUnusedCapacity, Self::Env>;
+        /// Returns up to `len` audit log entries of privileged calls, starting from `cursor`
+        /// or the oldest entry kept.
+        ///
+        /// `len` is clamped to the max page size.
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env>;
+        /// Returns the block the program is scheduled to resume at, if any.
+        fn auto_resume_at(&self) -> sails_rs::client::PendingCall<io::AutoResumeAt, Self::Env>;
+        /// Returns amount of entries allocated balances shards may still hold.
+        fn balances_unused_capacity(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::BalancesUnusedCapacity, Self::Env>;
+        /// Returns the alert of operators about occupancy of the map, if any.
+        fn capacity_alert(
+            &self,
+            map: StorageMap,
+        ) -> sails_rs::client::PendingCall<io::CapacityAlert, Self::Env>;
+        fn circuit_breaker(&self) -> sails_rs::client::PendingCall<io::CircuitBreaker, Self::Env>;
+        /// Returns `true` if the program is paused, or its mints and transfers
+        /// are halted by the circuit breaker.
+        fn is_paused(&self) -> sails_rs::client::PendingCall<io::IsPaused, Self::Env>;
+        /// Returns names and IDs of the roles the service checks, so clients don't
+        /// need to derive them.
+        fn known_roles(&self) -> sails_rs::client::PendingCall<io::KnownRoles, Self::Env>;
+        fn mint_rule(&self, id: u64) -> sails_rs::client::PendingCall<io::MintRule, Self::Env>;
+        /// Returns all recurring mint rules with their ids.
+        fn mint_rules(&self) -> sails_rs::client::PendingCall<io::MintRules, Self::Env>;
+        /// Returns blocks of up to `len` remaining mints of the rule, with the total
+        /// amount still to be minted by it, or `None` if there's no such rule.
+        fn mint_schedule(
+            &self,
+            id: u64,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::MintSchedule, Self::Env>;
+        fn minter_cap(
+            &self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::MinterCap, Self::Env>;
+        /// Returns the amount the minter may still mint in the current period,
+        /// or `None` if the minter is not capped.
+        fn minter_remaining(
+            &self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::MinterRemaining, Self::Env>;
+        /// Returns who paused the program, when and why, if it's paused.
+        fn pause_info(&self) -> sails_rs::client::PendingCall<io::PauseInfo, Self::Env>;
+        /// Derives the ID of the role named `name`, as `MINTER_ROLE` and others are derived.
+        fn role_id(&self, name: String) -> sails_rs::client::PendingCall<io::RoleId, Self::Env>;
+        /// Previews `burn` by the caller without mutating storage, e.g. for signers of
+        /// a multisig to review its effects, failing as `burn` would.
+        fn simulate_burn(
+            &self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::SimulateBurn, Self::Env>;
+    }
+    pub struct VftAdminImpl;
+    impl<E: sails_rs::client::GearEnv> VftAdmin for sails_rs::client::Service<VftAdminImpl, E> {
+        type Env = E;
+        fn add_mint_rule(
+            &mut self,
+            recipient: ActorId,
+            amount: U256,
+            interval_blocks: u32,
+            count: u32,
+        ) -> sails_rs::client::PendingCall<io::AddMintRule, Self::Env> {
+            self.pending_call((recipient, amount, interval_blocks, count))
+        }
+        fn append_allowances_shard(
+            &mut self,
+            capacity: u32,
+        ) -> sails_rs::client::PendingCall<io::AppendAllowancesShard, Self::Env> {
+            self.pending_call((capacity,))
+        }
+        fn append_balances_shard(
+            &mut self,
+            capacity: u32,
+        ) -> sails_rs::client::PendingCall<io::AppendBalancesShard, Self::Env> {
+            self.pending_call((capacity,))
+        }
+        fn approve_from(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveFrom, Self::Env> {
+            self.pending_call((owner, spender, value))
+        }
+        fn auto_resume(&mut self) -> sails_rs::client::PendingCall<io::AutoResume, Self::Env> {
+            self.pending_call(())
+        }
+        fn burn(
+            &mut self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env> {
+            self.pending_call((from, value))
+        }
+        fn burn_batch(
+            &mut self,
+            burns: Vec<(ActorId, U256)>,
+        ) -> sails_rs::client::PendingCall<io::BurnBatch, Self::Env> {
+            self.pending_call((burns,))
+        }
+        fn check_circuit_breaker(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::CheckCircuitBreaker, Self::Env> {
+            self.pending_call(())
+        }
+        fn exit(
+            &mut self,
+            inheritor: ActorId,
+        ) -> sails_rs::client::PendingCall<io::Exit, Self::Env> {
+            self.pending_call((inheritor,))
+        }
+        fn force_transfer(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ForceTransfer, Self::Env> {
+            self.pending_call((from, to, value))
+        }
+        fn mint(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env> {
+            self.pending_call((to, value))
+        }
+        fn pause(
+            &mut self,
+            reason: Option<PauseReason>,
+            resume_after: Option<u32>,
+        ) -> sails_rs::client::PendingCall<io::Pause, Self::Env> {
+            self.pending_call((reason, resume_after))
+        }
+        fn remove_empty_allowances_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::RemoveEmptyAllowancesShard, Self::Env> {
+            self.pending_call(())
+        }
+        fn remove_empty_balances_shard(
+            &mut self,
+        ) -> sails_rs::client::PendingCall<io::RemoveEmptyBalancesShard, Self::Env> {
+            self.pending_call(())
+        }
+        fn remove_mint_rule(
+            &mut self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::RemoveMintRule, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn remove_minter_cap(
+            &mut self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::RemoveMinterCap, Self::Env> {
+            self.pending_call((minter,))
+        }
+        fn resume(&mut self) -> sails_rs::client::PendingCall<io::Resume, Self::Env> {
+            self.pending_call(())
+        }
+        fn set_capacity_alert(
+            &mut self,
+            map: StorageMap,
+            alert: Option<CapacityAlert>,
+        ) -> sails_rs::client::PendingCall<io::SetCapacityAlert, Self::Env> {
+            self.pending_call((map, alert))
+        }
+        fn set_circuit_breaker(
+            &mut self,
+            config: CircuitBreakerConfig,
+        ) -> sails_rs::client::PendingCall<io::SetCircuitBreaker, Self::Env> {
+            self.pending_call((config,))
+        }
+        fn set_dust_policy(
+            &mut self,
+            policy: DustPolicy,
+        ) -> sails_rs::client::PendingCall<io::SetDustPolicy, Self::Env> {
+            self.pending_call((policy,))
+        }
+        fn set_expiry_period(
+            &mut self,
+            period: u32,
+        ) -> sails_rs::client::PendingCall<io::SetExpiryPeriod, Self::Env> {
+            self.pending_call((period,))
+        }
+        fn set_max_page_size(
+            &mut self,
+            value: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMaxPageSize, Self::Env> {
+            self.pending_call((value,))
+        }
+        fn set_minimum_balance(
+            &mut self,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::SetMinimumBalance, Self::Env> {
+            self.pending_call((value,))
+        }
+        fn set_minter_cap(
+            &mut self,
+            minter: ActorId,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::SetMinterCap, Self::Env> {
+            self.pending_call((minter, amount_per_period, period_blocks))
+        }
+        fn tick(&mut self) -> sails_rs::client::PendingCall<io::Tick, Self::Env> {
+            self.pending_call(())
+        }
+        fn allowances_unused_capacity(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::AllowancesUnusedCapacity, Self::Env> {
+            self.pending_call(())
+        }
+        fn audit_log(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AuditLog, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn auto_resume_at(&self) -> sails_rs::client::PendingCall<io::AutoResumeAt, Self::Env> {
+            self.pending_call(())
+        }
+        fn balances_unused_capacity(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::BalancesUnusedCapacity, Self::Env> {
+            self.pending_call(())
+        }
+        fn capacity_alert(
+            &self,
+            map: StorageMap,
+        ) -> sails_rs::client::PendingCall<io::CapacityAlert, Self::Env> {
+            self.pending_call((map,))
+        }
+        fn circuit_breaker(&self) -> sails_rs::client::PendingCall<io::CircuitBreaker, Self::Env> {
+            self.pending_call(())
+        }
+        fn is_paused(&self) -> sails_rs::client::PendingCall<io::IsPaused, Self::Env> {
+            self.pending_call(())
+        }
+        fn known_roles(&self) -> sails_rs::client::PendingCall<io::KnownRoles, Self::Env> {
+            self.pending_call(())
+        }
+        fn mint_rule(&self, id: u64) -> sails_rs::client::PendingCall<io::MintRule, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn mint_rules(&self) -> sails_rs::client::PendingCall<io::MintRules, Self::Env> {
+            self.pending_call(())
+        }
+        fn mint_schedule(
+            &self,
+            id: u64,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::MintSchedule, Self::Env> {
+            self.pending_call((id, len))
+        }
+        fn minter_cap(
+            &self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::MinterCap, Self::Env> {
+            self.pending_call((minter,))
+        }
+        fn minter_remaining(
+            &self,
+            minter: ActorId,
+        ) -> sails_rs::client::PendingCall<io::MinterRemaining, Self::Env> {
+            self.pending_call((minter,))
+        }
+        fn pause_info(&self) -> sails_rs::client::PendingCall<io::PauseInfo, Self::Env> {
+            self.pending_call(())
+        }
+        fn role_id(&self, name: String) -> sails_rs::client::PendingCall<io::RoleId, Self::Env> {
+            self.pending_call((name,))
+        }
+        fn simulate_burn(
+            &self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::SimulateBurn, Self::Env> {
+            self.pending_call((from, value))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(AddMintRule (recipient: ActorId, amount: U256, interval_blocks: u32, count: u32) -> u64);
+        sails_rs::io_struct_impl!(AppendAllowancesShard (capacity: u32) -> ());
+        sails_rs::io_struct_impl!(AppendBalancesShard (capacity: u32) -> ());
+        sails_rs::io_struct_impl!(ApproveFrom (owner: ActorId, spender: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(AutoResume () -> bool);
+        sails_rs::io_struct_impl!(Burn (from: ActorId, value: U256) -> ());
+        sails_rs::io_struct_impl!(BurnBatch (burns: Vec<(ActorId,U256,)>) -> ());
+        sails_rs::io_struct_impl!(CheckCircuitBreaker () -> bool);
+        sails_rs::io_struct_impl!(Exit (inheritor: ActorId) -> ());
+        sails_rs::io_struct_impl!(ForceTransfer (from: ActorId, to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Mint (to: ActorId, value: U256) -> ());
+        sails_rs::io_struct_impl!(Pause (reason: Option<super::PauseReason>, resume_after: Option<u32>) -> ());
+        sails_rs::io_struct_impl!(RemoveEmptyAllowancesShard () -> u32);
+        sails_rs::io_struct_impl!(RemoveEmptyBalancesShard () -> u32);
+        sails_rs::io_struct_impl!(RemoveMintRule (id: u64) -> bool);
+        sails_rs::io_struct_impl!(RemoveMinterCap (minter: ActorId) -> bool);
+        sails_rs::io_struct_impl!(Resume () -> ());
+        sails_rs::io_struct_impl!(SetCapacityAlert (map: super::StorageMap, alert: Option<super::CapacityAlert>) -> ());
+        sails_rs::io_struct_impl!(SetCircuitBreaker (config: super::CircuitBreakerConfig) -> ());
+        sails_rs::io_struct_impl!(SetDustPolicy (policy: super::DustPolicy) -> ());
+        sails_rs::io_struct_impl!(SetExpiryPeriod (period: u32) -> ());
+        sails_rs::io_struct_impl!(SetMaxPageSize (value: u32) -> ());
+        sails_rs::io_struct_impl!(SetMinimumBalance (value: U256) -> ());
+        sails_rs::io_struct_impl!(SetMinterCap (minter: ActorId, amount_per_period: U256, period_blocks: u32) -> ());
+        sails_rs::io_struct_impl!(Tick () -> u32);
+        sails_rs::io_struct_impl!(AllowancesUnusedCapacity () -> u32);
+        sails_rs::io_struct_impl!(AuditLog (cursor: u32, len: u32) -> Vec<(u32,super::AuditEntry,)>);
+        sails_rs::io_struct_impl!(AutoResumeAt () -> Option<u32>);
+        sails_rs::io_struct_impl!(BalancesUnusedCapacity () -> u32);
+        sails_rs::io_struct_impl!(CapacityAlert (map: super::StorageMap) -> Option<super::CapacityAlert>);
+        sails_rs::io_struct_impl!(CircuitBreaker () -> super::CircuitBreakerConfig);
+        sails_rs::io_struct_impl!(IsPaused () -> bool);
+        sails_rs::io_struct_impl!(KnownRoles () -> Vec<(String,[u8; 32],)>);
+        sails_rs::io_struct_impl!(MintRule (id: u64) -> Option<super::MintRule>);
+        sails_rs::io_struct_impl!(MintRules () -> Vec<(u64,super::MintRule,)>);
+        sails_rs::io_struct_impl!(MintSchedule (id: u64, len: u32) -> Option<(Vec<u32>,U256,)>);
+        sails_rs::io_struct_impl!(MinterCap (minter: ActorId) -> Option<super::MinterCap>);
+        sails_rs::io_struct_impl!(MinterRemaining (minter: ActorId) -> Option<U256>);
+        sails_rs::io_struct_impl!(PauseInfo () -> Option<super::PauseInfo>);
+        sails_rs::io_struct_impl!(RoleId (name: String) -> [u8; 32]);
+        sails_rs::io_struct_impl!(SimulateBurn (from: ActorId, value: U256) -> super::BurnPreview);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum VftAdminEvents {
+            /// Kept for compatibility, followed by `Burned` carrying the details.
+            BurnerTookPlace,
+            /// Kept for compatibility, followed by `Minted` carrying the details.
+            MinterTookPlace,
+            /// Kept for compatibility, followed by `Burned` for each burn of the batch.
+            BatchBurnTookPlace,
+            ForceTransferTookPlace,
+            MinterCapSet {
+                minter: ActorId,
+                amount_per_period: U256,
+                period_blocks: u32,
+            },
+            MinterCapRemoved(ActorId),
+            MintRuleAdded {
+                id: u64,
+                rule: MintRule,
+            },
+            MintRuleRemoved(u64),
+            /// Due mint of the recurring mint rule was executed.
+            RecurringMint {
+                id: u64,
+                to: ActorId,
+                value: U256,
+                remaining_count: u32,
+            },
+            CircuitBreakerSet(CircuitBreakerConfig),
+            ExpiryPeriodChanged(u32),
+            MinimumBalanceChanged(U256),
+            DustPolicyChanged(DustPolicy),
+            Exited(ActorId),
+            Paused(PauseInfo),
+            Resumed,
+            MaxPageSizeChanged(u32),
+            CapacityAlertChanged {
+                map: StorageMap,
+                alert: Option<CapacityAlert>,
+            },
+            /// `by` minted `value` to `to` via `mint`.
+            Minted {
+                by: ActorId,
+                to: ActorId,
+                value: U256,
+            },
+            /// `by` burned `value` from `from` via `burn` or `burn_batch`.
+            Burned {
+                by: ActorId,
+                from: ActorId,
+                value: U256,
+            },
+        }
+        impl sails_rs::client::Event for VftAdminEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "BurnerTookPlace",
+                "MinterTookPlace",
+                "BatchBurnTookPlace",
+                "ForceTransferTookPlace",
+                "MinterCapSet",
+                "MinterCapRemoved",
+                "MintRuleAdded",
+                "MintRuleRemoved",
+                "RecurringMint",
+                "CircuitBreakerSet",
+                "ExpiryPeriodChanged",
+                "MinimumBalanceChanged",
+                "DustPolicyChanged",
+                "Exited",
+                "Paused",
+                "Resumed",
+                "MaxPageSizeChanged",
+                "CapacityAlertChanged",
+                "Minted",
+                "Burned",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for VftAdminImpl {
             type Event = VftAdminEvents;
         }
     }
 }
 
+pub mod vft_batch {
+    use super::*;
+    pub trait VftBatch {
+        type Env: sails_rs::client::GearEnv;
+        /// Executes `calls` in order, failing on the first failing call.
+        ///
+        /// Returns the number of executed calls.
+        ///
+        /// Requirements:
+        ///
+        /// - there must be at most `MAX_BATCH_LEN` calls.
+        fn batch(
+            &mut self,
+            calls: Vec<Call>,
+        ) -> sails_rs::client::PendingCall<io::Batch, Self::Env>;
+    }
+    pub struct VftBatchImpl;
+    impl<E: sails_rs::client::GearEnv> VftBatch for sails_rs::client::Service<VftBatchImpl, E> {
+        type Env = E;
+        fn batch(
+            &mut self,
+            calls: Vec<Call>,
+        ) -> sails_rs::client::PendingCall<io::Batch, Self::Env> {
+            self.pending_call((calls,))
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Batch (calls: Vec<super::Call>) -> u32);
+    }
+}
+
+pub mod vft_bonding_curve {
+    use super::*;
+    pub trait VftBondingCurve {
+        type Env: sails_rs::client::GearEnv;
+        /// Mints `amount` tokens to the message source for their price along the curve.
+        ///
+        /// Message value must cover the price, with the rest attached to the reply.
+        fn buy(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Buy, Self::Env>;
+        /// Burns `amount` tokens of the message source, attaching their refund to the reply.
+        ///
+        /// Fails if the refund is below `min_refund`, or if the message source bought
+        /// less than `amount` through the curve.
+        fn sell(
+            &mut self,
+            amount: U256,
+            min_refund: u128,
+        ) -> sails_rs::client::PendingCall<io::Sell, Self::Env>;
+        /// Sets the curve, while no tokens are issued through the current one.
+        fn set_curve(
+            &mut self,
+            curve: Curve,
+        ) -> sails_rs::client::PendingCall<io::SetCurve, Self::Env>;
+        fn curve(&self) -> sails_rs::client::PendingCall<io::Curve, Self::Env>;
+        /// Returns amount bought through the curve by the account,
+        /// which it may sell back.
+        fn issued_to(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::IssuedTo, Self::Env>;
+        /// Returns native value to be paid for `amount` tokens.
+        fn quote_buy(&self, amount: U256)
+        -> sails_rs::client::PendingCall<io::QuoteBuy, Self::Env>;
+        /// Returns native value to be refunded for `amount` tokens.
+        fn quote_sell(
+            &self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::QuoteSell, Self::Env>;
+        fn reserve(&self) -> sails_rs::client::PendingCall<io::Reserve, Self::Env>;
+        /// Returns the price of the next token, scaled by [`PRICE_SCALE`].
+        fn spot_price(&self) -> sails_rs::client::PendingCall<io::SpotPrice, Self::Env>;
+        fn supply(&self) -> sails_rs::client::PendingCall<io::Supply, Self::Env>;
+    }
+    pub struct VftBondingCurveImpl;
+    impl<E: sails_rs::client::GearEnv> VftBondingCurve
+        for sails_rs::client::Service<VftBondingCurveImpl, E>
+    {
+        type Env = E;
+        fn buy(&mut self, amount: U256) -> sails_rs::client::PendingCall<io::Buy, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn sell(
+            &mut self,
+            amount: U256,
+            min_refund: u128,
+        ) -> sails_rs::client::PendingCall<io::Sell, Self::Env> {
+            self.pending_call((amount, min_refund))
+        }
+        fn set_curve(
+            &mut self,
+            curve: Curve,
+        ) -> sails_rs::client::PendingCall<io::SetCurve, Self::Env> {
+            self.pending_call((curve,))
+        }
+        fn curve(&self) -> sails_rs::client::PendingCall<io::Curve, Self::Env> {
+            self.pending_call(())
+        }
+        fn issued_to(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::IssuedTo, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn quote_buy(
+            &self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::QuoteBuy, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn quote_sell(
+            &self,
+            amount: U256,
+        ) -> sails_rs::client::PendingCall<io::QuoteSell, Self::Env> {
+            self.pending_call((amount,))
+        }
+        fn reserve(&self) -> sails_rs::client::PendingCall<io::Reserve, Self::Env> {
+            self.pending_call(())
+        }
+        fn spot_price(&self) -> sails_rs::client::PendingCall<io::SpotPrice, Self::Env> {
+            self.pending_call(())
+        }
+        fn supply(&self) -> sails_rs::client::PendingCall<io::Supply, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Buy (amount: U256) -> ());
+        sails_rs::io_struct_impl!(Sell (amount: U256, min_refund: u128) -> u128);
+        sails_rs::io_struct_impl!(SetCurve (curve: super::Curve) -> ());
+        sails_rs::io_struct_impl!(Curve () -> Option<super::Curve>);
+        sails_rs::io_struct_impl!(IssuedTo (account: ActorId) -> U256);
+        sails_rs::io_struct_impl!(QuoteBuy (amount: U256) -> Option<u128>);
+        sails_rs::io_struct_impl!(QuoteSell (amount: U256) -> Option<u128>);
+        sails_rs::io_struct_impl!(Reserve () -> u128);
+        sails_rs::io_struct_impl!(SpotPrice () -> Option<U256>);
+        sails_rs::io_struct_impl!(Supply () -> U256);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum VftBondingCurveEvents {
+            Bought {
+                buyer: ActorId,
+                amount: U256,
+                cost: u128,
+            },
+            Sold {
+                seller: ActorId,
+                amount: U256,
+                refund: u128,
+            },
+            CurveSet(Curve),
+        }
+        impl sails_rs::client::Event for VftBondingCurveEvents {
+            const EVENT_NAMES: &'static [Route] = &["Bought", "Sold", "CurveSet"];
+        }
+        impl sails_rs::client::ServiceWithEvents for VftBondingCurveImpl {
+            type Event = VftBondingCurveEvents;
+        }
+    }
+}
+
+pub mod vft_bridge_adapter {
+    use super::*;
+    pub trait VftBridgeAdapter {
+        type Env: sails_rs::client::GearEnv;
+        /// Burns VFTs locked for the remote chain.
+        ///
+        /// Requires [`BURNER_ROLE`](vft_admin::BURNER_ROLE).
+        fn burn(
+            &mut self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env>;
+        /// Mints VFTs whose lock on the remote chain is proven.
+        ///
+        /// Requires [`MINTER_ROLE`](vft_admin::MINTER_ROLE).
+        fn mint(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env>;
+        /// Mints VFTs for the remote event, failing if it was already processed.
+        ///
+        /// Requires [`MINTER_ROLE`](vft_admin::MINTER_ROLE).
+        fn mint_remote(
+            &mut self,
+            event_id: H256,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::MintRemote, Self::Env>;
+        /// Compares the local total supply against the amount locked on the remote chain,
+        /// reporting divergence beyond the threshold and pausing the program if configured.
+        fn reconcile(
+            &mut self,
+            remote_locked: U256,
+        ) -> sails_rs::client::PendingCall<io::Reconcile, Self::Env>;
+        fn set_reconciliation(
+            &mut self,
+            reconciliation: Reconciliation,
+        ) -> sails_rs::client::PendingCall<io::SetReconciliation, Self::Env>;
+        fn is_processed(
+            &self,
+            event_id: H256,
+        ) -> sails_rs::client::PendingCall<io::IsProcessed, Self::Env>;
+        fn last_report(&self) -> sails_rs::client::PendingCall<io::LastReport, Self::Env>;
+        fn reconciliation(&self) -> sails_rs::client::PendingCall<io::Reconciliation, Self::Env>;
+    }
+    pub struct VftBridgeAdapterImpl;
+    impl<E: sails_rs::client::GearEnv> VftBridgeAdapter
+        for sails_rs::client::Service<VftBridgeAdapterImpl, E>
+    {
+        type Env = E;
+        fn burn(
+            &mut self,
+            from: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Burn, Self::Env> {
+            self.pending_call((from, value))
+        }
+        fn mint(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::Mint, Self::Env> {
+            self.pending_call((to, value))
+        }
+        fn mint_remote(
+            &mut self,
+            event_id: H256,
+            to: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::MintRemote, Self::Env> {
+            self.pending_call((event_id, to, value))
+        }
+        fn reconcile(
+            &mut self,
+            remote_locked: U256,
+        ) -> sails_rs::client::PendingCall<io::Reconcile, Self::Env> {
+            self.pending_call((remote_locked,))
+        }
+        fn set_reconciliation(
+            &mut self,
+            reconciliation: Reconciliation,
+        ) -> sails_rs::client::PendingCall<io::SetReconciliation, Self::Env> {
+            self.pending_call((reconciliation,))
+        }
+        fn is_processed(
+            &self,
+            event_id: H256,
+        ) -> sails_rs::client::PendingCall<io::IsProcessed, Self::Env> {
+            self.pending_call((event_id,))
+        }
+        fn last_report(&self) -> sails_rs::client::PendingCall<io::LastReport, Self::Env> {
+            self.pending_call(())
+        }
+        fn reconciliation(&self) -> sails_rs::client::PendingCall<io::Reconciliation, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(Burn (from: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(Mint (to: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(MintRemote (event_id: H256, to: ActorId, value: U256) -> ());
+        sails_rs::io_struct_impl!(Reconcile (remote_locked: U256) -> super::SupplyReport);
+        sails_rs::io_struct_impl!(SetReconciliation (reconciliation: super::Reconciliation) -> ());
+        sails_rs::io_struct_impl!(IsProcessed (event_id: H256) -> bool);
+        sails_rs::io_struct_impl!(LastReport () -> Option<super::SupplyReport>);
+        sails_rs::io_struct_impl!(Reconciliation () -> super::Reconciliation);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum VftBridgeAdapterEvents {
+            RemoteEventProcessed {
+                event_id: H256,
+                to: ActorId,
+                value: U256,
+            },
+            /// Local supply diverged from the remote locked amount beyond the threshold.
+            SupplyMismatch {
+                local_supply: U256,
+                remote_locked: U256,
+                divergence: U256,
+                paused: bool,
+            },
+            ReconciliationSet(Reconciliation),
+        }
+        impl sails_rs::client::Event for VftBridgeAdapterEvents {
+            const EVENT_NAMES: &'static [Route] = &[
+                "RemoteEventProcessed",
+                "SupplyMismatch",
+                "ReconciliationSet",
+            ];
+        }
+        impl sails_rs::client::ServiceWithEvents for VftBridgeAdapterImpl {
+            type Event = VftBridgeAdapterEvents;
+        }
+    }
+}
+
 pub mod vft_extension {
     use super::*;
     pub trait VftExtension {
@@ -650,11 +4844,57 @@ pub mod vft_extension {
         fn allocate_next_balances_shard(
             &mut self,
         ) -> sails_rs::client::PendingCall<io::AllocateNextBalancesShard, Self::Env>;
+        /// Approves `value` to the spender as `Vft::approve` does, notifying the spender
+        /// by the [`approval_notification`] message if the allowance changed.
+        fn approve_and_notify(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveAndNotify, Self::Env>;
+        /// Gives the spender a cap of `amount_per_period` renewed every `period_blocks`,
+        /// spent by its transfers from the message source instead of the allowance.
+        /// Zero `amount_per_period` removes the cap.
+        ///
+        /// Returns `true` if the cap changed.
+        fn approve_with_cap(
+            &mut self,
+            spender: ActorId,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::ApproveWithCap, Self::Env>;
+        /// Claims the escrowed transfer sent to the message source.
+        ///
+        /// Returns `false` if the claim isn't pending.
+        fn claim(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Claim, Self::Env>;
+        /// Hashes up to `len` more holders into the merkle root of balances being
+        /// snapshotted, starting the snapshot at the current block if there's none,
+        /// or if balances changed since it started.
+        ///
+        /// Anyone may continue the snapshot, until it's done in as many messages
+        /// as needed. Once all holders are hashed, emits a `SnapshotTaken` event
+        /// and returns the snapshot, kept as the latest one.
+        fn compute_snapshot(
+            &mut self,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::ComputeSnapshot, Self::Env>;
+        /// Reclaims the expired escrowed transfer sent by the message source.
+        ///
+        /// Returns `false` if the claim isn't pending or expired yet.
+        fn reclaim(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Reclaim, Self::Env>;
         fn remove_expired_allowance(
             &mut self,
             owner: ActorId,
             spender: ActorId,
         ) -> sails_rs::client::PendingCall<io::RemoveExpiredAllowance, Self::Env>;
+        /// Approves or revokes the operator of the message source, transferring
+        /// any value of it without allowances.
+        ///
+        /// Returns `true` if the operator changed.
+        fn set_operator(
+            &mut self,
+            operator: ActorId,
+            approved: bool,
+        ) -> sails_rs::client::PendingCall<io::SetOperator, Self::Env>;
         fn transfer_all(
             &mut self,
             to: ActorId,
@@ -664,6 +4904,16 @@ pub mod vft_extension {
             from: ActorId,
             to: ActorId,
         ) -> sails_rs::client::PendingCall<io::TransferAllFrom, Self::Env>;
+        /// Escrows `value` of the message source for the recipient to claim,
+        /// reclaimable by the message source after `timeout_blocks`.
+        ///
+        /// Returns the id of the claim.
+        fn transfer_claimable(
+            &mut self,
+            to: ActorId,
+            value: U256,
+            timeout_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::TransferClaimable, Self::Env>;
         fn allowance_of(
             &self,
             owner: ActorId,
@@ -674,16 +4924,143 @@ pub mod vft_extension {
             cursor: u32,
             len: u32,
         ) -> sails_rs::client::PendingCall<io::Allowances, Self::Env>;
+        /// Returns up to `len` allowances as `allowances` does, encoding values
+        /// as 9-byte [`Allowance`]s instead of 32-byte `U256`s.
+        fn allowances_compact(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesCompact, Self::Env>;
+        /// Returns up to `len` allowances starting from the `cursor`, with the cursor
+        /// to resume from, so deep pages cost as much as the first one.
+        fn allowances_from(
+            &self,
+            cursor: MapCursor,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesFrom, Self::Env>;
+        /// Returns allowances of each of the `(owner, spender)` pairs, as `Vft::allowance` does.
+        fn allowances_of(
+            &self,
+            pairs: Vec<(ActorId, ActorId)>,
+        ) -> sails_rs::client::PendingCall<io::AllowancesOf, Self::Env>;
+        /// Returns up to `len` allowances given by the owner as `(spender, (value, expiry))`,
+        /// starting from `cursor`, in ascending order of spenders.
+        fn allowances_of_owner(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesOfOwner, Self::Env>;
+        /// Returns up to `len` allowances given to the spender as `(owner, (value, expiry))`,
+        /// starting from `cursor`, in ascending order of owners.
+        fn allowances_to_spender(
+            &self,
+            spender: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesToSpender, Self::Env>;
         fn balance_of(
             &self,
             account: ActorId,
         ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env>;
+        /// Returns balance of the actor the 20-byte `address` maps to,
+        /// as `ActorId::from(H160)` pads it, being an alias of `Vft::balance_of`.
+        ///
+        /// Holders keyed by Ethereum addresses themselves are kept by storages
+        /// instantiated with `H160` keys, e.g. `Balances<Balance, H160>`.
+        fn balance_of_address(
+            &self,
+            address: H160,
+        ) -> sails_rs::client::PendingCall<io::BalanceOfAddress, Self::Env>;
         fn balances(
             &self,
             cursor: u32,
             len: u32,
         ) -> sails_rs::client::PendingCall<io::Balances, Self::Env>;
+        /// Returns up to `len` balances as `balances` does, encoding values
+        /// as 10-byte [`Balance`]s instead of 32-byte `U256`s.
+        fn balances_compact(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::BalancesCompact, Self::Env>;
+        /// Returns up to `len` balances starting from the `cursor`, with the cursor
+        /// to resume from, so deep pages cost as much as the first one.
+        fn balances_from(
+            &self,
+            cursor: MapCursor,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::BalancesFrom, Self::Env>;
+        /// Returns balances of each of the `accounts`, as `Vft::balance_of` does.
+        fn balances_of(
+            &self,
+            accounts: Vec<ActorId>,
+        ) -> sails_rs::client::PendingCall<io::BalancesOf, Self::Env>;
+        fn dust_policy(&self) -> sails_rs::client::PendingCall<io::DustPolicy, Self::Env>;
         fn expiry_period(&self) -> sails_rs::client::PendingCall<io::ExpiryPeriod, Self::Env>;
+        fn is_operator(
+            &self,
+            owner: ActorId,
+            operator: ActorId,
+        ) -> sails_rs::client::PendingCall<io::IsOperator, Self::Env>;
+        /// Returns the latest snapshot of holders computed, see `compute_snapshot`.
+        fn latest_snapshot(&self) -> sails_rs::client::PendingCall<io::LatestSnapshot, Self::Env>;
+        /// Returns the max amount of items enumeration exports reply with at once.
+        fn max_page_size(&self) -> sails_rs::client::PendingCall<io::MaxPageSize, Self::Env>;
+        fn minimum_balance(&self) -> sails_rs::client::PendingCall<io::MinimumBalance, Self::Env>;
+        /// Returns up to `len` owners that approved the operator, starting from `cursor`.
+        fn operated_by(
+            &self,
+            operator: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OperatedBy, Self::Env>;
+        /// Returns up to `len` operators approved by the owner, starting from `cursor`.
+        fn operators_of(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OperatorsOf, Self::Env>;
+        fn pending_claim(
+            &self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::PendingClaim, Self::Env>;
+        /// Returns up to `len` pending claims sent by the sender, starting from `cursor`.
+        fn pending_claims_from(
+            &self,
+            sender: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::PendingClaimsFrom, Self::Env>;
+        /// Returns up to `len` pending claims sent to the recipient, starting from `cursor`.
+        fn pending_claims_to(
+            &self,
+            recipient: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::PendingClaimsTo, Self::Env>;
+        /// Returns block the snapshot being computed is taken at, if any.
+        fn pending_snapshot(&self)
+        -> sails_rs::client::PendingCall<io::PendingSnapshot, Self::Env>;
+        /// Returns the spending cap given by the owner to the spender, if any.
+        fn spending_cap(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SpendingCap, Self::Env>;
+        /// Returns value the spender may still spend from its cap within the current period.
+        fn spending_cap_remaining(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SpendingCapRemaining, Self::Env>;
+        /// Returns the total supply as of the end of the given block,
+        /// or `None` if the block precedes the kept supply history.
+        fn total_supply_at(
+            &self,
+            block: u32,
+        ) -> sails_rs::client::PendingCall<io::TotalSupplyAt, Self::Env>;
         fn unused_value(&self) -> sails_rs::client::PendingCall<io::UnusedValue, Self::Env>;
     }
     pub struct VftExtensionImpl;
@@ -699,6 +5076,33 @@ pub mod vft_extension {
         ) -> sails_rs::client::PendingCall<io::AllocateNextBalancesShard, Self::Env> {
             self.pending_call(())
         }
+        fn approve_and_notify(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> sails_rs::client::PendingCall<io::ApproveAndNotify, Self::Env> {
+            self.pending_call((spender, value))
+        }
+        fn approve_with_cap(
+            &mut self,
+            spender: ActorId,
+            amount_per_period: U256,
+            period_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::ApproveWithCap, Self::Env> {
+            self.pending_call((spender, amount_per_period, period_blocks))
+        }
+        fn claim(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Claim, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn compute_snapshot(
+            &mut self,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::ComputeSnapshot, Self::Env> {
+            self.pending_call((len,))
+        }
+        fn reclaim(&mut self, id: u64) -> sails_rs::client::PendingCall<io::Reclaim, Self::Env> {
+            self.pending_call((id,))
+        }
         fn remove_expired_allowance(
             &mut self,
             owner: ActorId,
@@ -706,6 +5110,13 @@ pub mod vft_extension {
         ) -> sails_rs::client::PendingCall<io::RemoveExpiredAllowance, Self::Env> {
             self.pending_call((owner, spender))
         }
+        fn set_operator(
+            &mut self,
+            operator: ActorId,
+            approved: bool,
+        ) -> sails_rs::client::PendingCall<io::SetOperator, Self::Env> {
+            self.pending_call((operator, approved))
+        }
         fn transfer_all(
             &mut self,
             to: ActorId,
@@ -719,6 +5130,14 @@ pub mod vft_extension {
         ) -> sails_rs::client::PendingCall<io::TransferAllFrom, Self::Env> {
             self.pending_call((from, to))
         }
+        fn transfer_claimable(
+            &mut self,
+            to: ActorId,
+            value: U256,
+            timeout_blocks: u32,
+        ) -> sails_rs::client::PendingCall<io::TransferClaimable, Self::Env> {
+            self.pending_call((to, value, timeout_blocks))
+        }
         fn allowance_of(
             &self,
             owner: ActorId,
@@ -733,12 +5152,54 @@ pub mod vft_extension {
         ) -> sails_rs::client::PendingCall<io::Allowances, Self::Env> {
             self.pending_call((cursor, len))
         }
+        fn allowances_compact(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesCompact, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn allowances_from(
+            &self,
+            cursor: MapCursor,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesFrom, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn allowances_of(
+            &self,
+            pairs: Vec<(ActorId, ActorId)>,
+        ) -> sails_rs::client::PendingCall<io::AllowancesOf, Self::Env> {
+            self.pending_call((pairs,))
+        }
+        fn allowances_of_owner(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesOfOwner, Self::Env> {
+            self.pending_call((owner, cursor, len))
+        }
+        fn allowances_to_spender(
+            &self,
+            spender: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::AllowancesToSpender, Self::Env> {
+            self.pending_call((spender, cursor, len))
+        }
         fn balance_of(
             &self,
             account: ActorId,
         ) -> sails_rs::client::PendingCall<io::BalanceOf, Self::Env> {
             self.pending_call((account,))
         }
+        fn balance_of_address(
+            &self,
+            address: H160,
+        ) -> sails_rs::client::PendingCall<io::BalanceOfAddress, Self::Env> {
+            self.pending_call((address,))
+        }
         fn balances(
             &self,
             cursor: u32,
@@ -746,9 +5207,111 @@ pub mod vft_extension {
         ) -> sails_rs::client::PendingCall<io::Balances, Self::Env> {
             self.pending_call((cursor, len))
         }
+        fn balances_compact(
+            &self,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::BalancesCompact, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn balances_from(
+            &self,
+            cursor: MapCursor,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::BalancesFrom, Self::Env> {
+            self.pending_call((cursor, len))
+        }
+        fn balances_of(
+            &self,
+            accounts: Vec<ActorId>,
+        ) -> sails_rs::client::PendingCall<io::BalancesOf, Self::Env> {
+            self.pending_call((accounts,))
+        }
+        fn dust_policy(&self) -> sails_rs::client::PendingCall<io::DustPolicy, Self::Env> {
+            self.pending_call(())
+        }
         fn expiry_period(&self) -> sails_rs::client::PendingCall<io::ExpiryPeriod, Self::Env> {
             self.pending_call(())
         }
+        fn is_operator(
+            &self,
+            owner: ActorId,
+            operator: ActorId,
+        ) -> sails_rs::client::PendingCall<io::IsOperator, Self::Env> {
+            self.pending_call((owner, operator))
+        }
+        fn latest_snapshot(&self) -> sails_rs::client::PendingCall<io::LatestSnapshot, Self::Env> {
+            self.pending_call(())
+        }
+        fn max_page_size(&self) -> sails_rs::client::PendingCall<io::MaxPageSize, Self::Env> {
+            self.pending_call(())
+        }
+        fn minimum_balance(&self) -> sails_rs::client::PendingCall<io::MinimumBalance, Self::Env> {
+            self.pending_call(())
+        }
+        fn operated_by(
+            &self,
+            operator: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OperatedBy, Self::Env> {
+            self.pending_call((operator, cursor, len))
+        }
+        fn operators_of(
+            &self,
+            owner: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::OperatorsOf, Self::Env> {
+            self.pending_call((owner, cursor, len))
+        }
+        fn pending_claim(
+            &self,
+            id: u64,
+        ) -> sails_rs::client::PendingCall<io::PendingClaim, Self::Env> {
+            self.pending_call((id,))
+        }
+        fn pending_claims_from(
+            &self,
+            sender: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::PendingClaimsFrom, Self::Env> {
+            self.pending_call((sender, cursor, len))
+        }
+        fn pending_claims_to(
+            &self,
+            recipient: ActorId,
+            cursor: u32,
+            len: u32,
+        ) -> sails_rs::client::PendingCall<io::PendingClaimsTo, Self::Env> {
+            self.pending_call((recipient, cursor, len))
+        }
+        fn pending_snapshot(
+            &self,
+        ) -> sails_rs::client::PendingCall<io::PendingSnapshot, Self::Env> {
+            self.pending_call(())
+        }
+        fn spending_cap(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SpendingCap, Self::Env> {
+            self.pending_call((owner, spender))
+        }
+        fn spending_cap_remaining(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> sails_rs::client::PendingCall<io::SpendingCapRemaining, Self::Env> {
+            self.pending_call((owner, spender))
+        }
+        fn total_supply_at(
+            &self,
+            block: u32,
+        ) -> sails_rs::client::PendingCall<io::TotalSupplyAt, Self::Env> {
+            self.pending_call((block,))
+        }
         fn unused_value(&self) -> sails_rs::client::PendingCall<io::UnusedValue, Self::Env> {
             self.pending_call(())
         }
@@ -758,18 +5321,126 @@ pub mod vft_extension {
         use super::*;
         sails_rs::io_struct_impl!(AllocateNextAllowancesShard () -> bool);
         sails_rs::io_struct_impl!(AllocateNextBalancesShard () -> bool);
+        sails_rs::io_struct_impl!(ApproveAndNotify (spender: ActorId, value: U256) -> bool);
+        sails_rs::io_struct_impl!(ApproveWithCap (spender: ActorId, amount_per_period: U256, period_blocks: u32) -> bool);
+        sails_rs::io_struct_impl!(Claim (id: u64) -> bool);
+        sails_rs::io_struct_impl!(ComputeSnapshot (len: u32) -> Option<super::SnapshotRoot>);
+        sails_rs::io_struct_impl!(Reclaim (id: u64) -> bool);
         sails_rs::io_struct_impl!(RemoveExpiredAllowance (owner: ActorId, spender: ActorId) -> bool);
+        sails_rs::io_struct_impl!(SetOperator (operator: ActorId, approved: bool) -> bool);
         sails_rs::io_struct_impl!(TransferAll (to: ActorId) -> bool);
         sails_rs::io_struct_impl!(TransferAllFrom (from: ActorId, to: ActorId) -> bool);
+        sails_rs::io_struct_impl!(TransferClaimable (to: ActorId, value: U256, timeout_blocks: u32) -> u64);
         sails_rs::io_struct_impl!(AllowanceOf (owner: ActorId, spender: ActorId) -> Option<(U256,u32,)>);
         sails_rs::io_struct_impl!(Allowances (cursor: u32, len: u32) -> Vec<((ActorId,ActorId,),(U256,u32,),)>);
+        sails_rs::io_struct_impl!(AllowancesCompact (cursor: u32, len: u32) -> Vec<((ActorId,ActorId,),(super::Allowance,u32,),)>);
+        sails_rs::io_struct_impl!(AllowancesFrom (cursor: super::MapCursor, len: u32) -> (Vec<((ActorId,ActorId,),(U256,u32,),)>,super::MapCursor,));
+        sails_rs::io_struct_impl!(AllowancesOf (pairs: Vec<(ActorId,ActorId,)>) -> Vec<U256>);
+        sails_rs::io_struct_impl!(AllowancesOfOwner (owner: ActorId, cursor: u32, len: u32) -> Vec<(ActorId,(U256,u32,),)>);
+        sails_rs::io_struct_impl!(AllowancesToSpender (spender: ActorId, cursor: u32, len: u32) -> Vec<(ActorId,(U256,u32,),)>);
         sails_rs::io_struct_impl!(BalanceOf (account: ActorId) -> Option<U256>);
+        sails_rs::io_struct_impl!(BalanceOfAddress (address: H160) -> U256);
         sails_rs::io_struct_impl!(Balances (cursor: u32, len: u32) -> Vec<(ActorId,U256,)>);
+        sails_rs::io_struct_impl!(BalancesCompact (cursor: u32, len: u32) -> Vec<(ActorId,super::Balance,)>);
+        sails_rs::io_struct_impl!(BalancesFrom (cursor: super::MapCursor, len: u32) -> (Vec<(ActorId,U256,)>,super::MapCursor,));
+        sails_rs::io_struct_impl!(BalancesOf (accounts: Vec<ActorId>) -> Vec<U256>);
+        sails_rs::io_struct_impl!(DustPolicy () -> super::DustPolicy);
         sails_rs::io_struct_impl!(ExpiryPeriod () -> u32);
+        sails_rs::io_struct_impl!(IsOperator (owner: ActorId, operator: ActorId) -> bool);
+        sails_rs::io_struct_impl!(LatestSnapshot () -> Option<super::SnapshotRoot>);
+        sails_rs::io_struct_impl!(MaxPageSize () -> u32);
+        sails_rs::io_struct_impl!(MinimumBalance () -> U256);
+        sails_rs::io_struct_impl!(OperatedBy (operator: ActorId, cursor: u32, len: u32) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(OperatorsOf (owner: ActorId, cursor: u32, len: u32) -> Vec<ActorId>);
+        sails_rs::io_struct_impl!(PendingClaim (id: u64) -> Option<super::Claim>);
+        sails_rs::io_struct_impl!(PendingClaimsFrom (sender: ActorId, cursor: u32, len: u32) -> Vec<(u64,super::Claim,)>);
+        sails_rs::io_struct_impl!(PendingClaimsTo (recipient: ActorId, cursor: u32, len: u32) -> Vec<(u64,super::Claim,)>);
+        sails_rs::io_struct_impl!(PendingSnapshot () -> Option<u32>);
+        sails_rs::io_struct_impl!(SpendingCap (owner: ActorId, spender: ActorId) -> Option<super::PeriodBudget>);
+        sails_rs::io_struct_impl!(SpendingCapRemaining (owner: ActorId, spender: ActorId) -> Option<U256>);
+        sails_rs::io_struct_impl!(TotalSupplyAt (block: u32) -> Option<U256>);
         sails_rs::io_struct_impl!(UnusedValue () -> U256);
     }
 }
 
+pub mod vft_inflation {
+    use super::*;
+    pub trait VftInflation {
+        type Env: sails_rs::client::GearEnv;
+        /// Mints emission of up to [`MAX_ERAS_PER_CALL`] elapsed eras to the recipient.
+        ///
+        /// Returns the minted amount.
+        fn mint_emission(&mut self) -> sails_rs::client::PendingCall<io::MintEmission, Self::Env>;
+        /// Sets the emission schedule, starting its eras from the current block.
+        fn set_schedule(
+            &mut self,
+            recipient: ActorId,
+            initial_emission: U256,
+            era_blocks: u32,
+            decay_bps: u16,
+            end_block: Option<u32>,
+        ) -> sails_rs::client::PendingCall<io::SetSchedule, Self::Env>;
+        /// Returns the block the next era ends at, with its emission,
+        /// or `None` if the emission is disabled or has ended.
+        fn next_emission(&self) -> sails_rs::client::PendingCall<io::NextEmission, Self::Env>;
+        fn schedule(&self) -> sails_rs::client::PendingCall<io::Schedule, Self::Env>;
+    }
+    pub struct VftInflationImpl;
+    impl<E: sails_rs::client::GearEnv> VftInflation for sails_rs::client::Service<VftInflationImpl, E> {
+        type Env = E;
+        fn mint_emission(&mut self) -> sails_rs::client::PendingCall<io::MintEmission, Self::Env> {
+            self.pending_call(())
+        }
+        fn set_schedule(
+            &mut self,
+            recipient: ActorId,
+            initial_emission: U256,
+            era_blocks: u32,
+            decay_bps: u16,
+            end_block: Option<u32>,
+        ) -> sails_rs::client::PendingCall<io::SetSchedule, Self::Env> {
+            self.pending_call((
+                recipient,
+                initial_emission,
+                era_blocks,
+                decay_bps,
+                end_block,
+            ))
+        }
+        fn next_emission(&self) -> sails_rs::client::PendingCall<io::NextEmission, Self::Env> {
+            self.pending_call(())
+        }
+        fn schedule(&self) -> sails_rs::client::PendingCall<io::Schedule, Self::Env> {
+            self.pending_call(())
+        }
+    }
+
+    pub mod io {
+        use super::*;
+        sails_rs::io_struct_impl!(MintEmission () -> U256);
+        sails_rs::io_struct_impl!(SetSchedule (recipient: ActorId, initial_emission: U256, era_blocks: u32, decay_bps: u16, end_block: Option<u32>) -> ());
+        sails_rs::io_struct_impl!(NextEmission () -> Option<(u32,U256,)>);
+        sails_rs::io_struct_impl!(Schedule () -> super::InflationSchedule);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum VftInflationEvents {
+            EmissionMinted { era: u32, to: ActorId, value: U256 },
+            ScheduleSet(InflationSchedule),
+        }
+        impl sails_rs::client::Event for VftInflationEvents {
+            const EVENT_NAMES: &'static [Route] = &["EmissionMinted", "ScheduleSet"];
+        }
+        impl sails_rs::client::ServiceWithEvents for VftInflationImpl {
+            type Event = VftInflationEvents;
+        }
+    }
+}
+
 pub mod vft_metadata {
     use super::*;
     pub trait VftMetadata {
@@ -809,7 +5480,18 @@ pub mod vft_native_exchange {
         type Env: sails_rs::client::GearEnv;
         fn burn(&mut self, value: U256) -> sails_rs::client::PendingCall<io::Burn, Self::Env>;
         fn burn_all(&mut self) -> sails_rs::client::PendingCall<io::BurnAll, Self::Env>;
+        /// Mints VFTs for the attached value.
+        ///
+        /// Returns `false` if the value is out of the [`MintBounds`], refunding it.
         fn mint(&mut self) -> sails_rs::client::PendingCall<io::Mint, Self::Env>;
+        /// Returns values exchanged by the account.
+        fn exchange_stats(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExchangeStats, Self::Env>;
+        /// Returns values exchanged by all accounts.
+        fn exchange_totals(&self) -> sails_rs::client::PendingCall<io::ExchangeTotals, Self::Env>;
+        fn mint_bounds(&self) -> sails_rs::client::PendingCall<io::MintBounds, Self::Env>;
     }
     pub struct VftNativeExchangeImpl;
     impl<E: sails_rs::client::GearEnv> VftNativeExchange
@@ -825,13 +5507,28 @@ pub mod vft_native_exchange {
         fn mint(&mut self) -> sails_rs::client::PendingCall<io::Mint, Self::Env> {
             self.pending_call(())
         }
+        fn exchange_stats(
+            &self,
+            account: ActorId,
+        ) -> sails_rs::client::PendingCall<io::ExchangeStats, Self::Env> {
+            self.pending_call((account,))
+        }
+        fn exchange_totals(&self) -> sails_rs::client::PendingCall<io::ExchangeTotals, Self::Env> {
+            self.pending_call(())
+        }
+        fn mint_bounds(&self) -> sails_rs::client::PendingCall<io::MintBounds, Self::Env> {
+            self.pending_call(())
+        }
     }
 
     pub mod io {
         use super::*;
         sails_rs::io_struct_impl!(Burn (value: U256) -> ());
         sails_rs::io_struct_impl!(BurnAll () -> ());
-        sails_rs::io_struct_impl!(Mint () -> ());
+        sails_rs::io_struct_impl!(Mint () -> bool);
+        sails_rs::io_struct_impl!(ExchangeStats (account: ActorId) -> super::ExchangeStats);
+        sails_rs::io_struct_impl!(ExchangeTotals () -> super::ExchangeStats);
+        sails_rs::io_struct_impl!(MintBounds () -> super::MintBounds);
     }
 }
 
@@ -844,6 +5541,17 @@ pub mod vft_native_exchange_admin {
             from: ActorId,
             value: U256,
         ) -> sails_rs::client::PendingCall<io::BurnFrom, Self::Env>;
+        /// Sets bounds of values deposited to mint, failing if the minimum
+        /// exceeds the bounded maximum.
+        fn set_mint_bounds(
+            &mut self,
+            bounds: MintBounds,
+        ) -> sails_rs::client::PendingCall<io::SetMintBounds, Self::Env>;
+        /// Returns payouts left unreplied for at least `older_than` blocks.
+        fn stale_payouts(
+            &self,
+            older_than: u32,
+        ) -> sails_rs::client::PendingCall<io::StalePayouts, Self::Env>;
     }
     pub struct VftNativeExchangeAdminImpl;
     impl<E: sails_rs::client::GearEnv> VftNativeExchangeAdmin
@@ -857,11 +5565,25 @@ pub mod vft_native_exchange_admin {
         ) -> sails_rs::client::PendingCall<io::BurnFrom, Self::Env> {
             self.pending_call((from, value))
         }
+        fn set_mint_bounds(
+            &mut self,
+            bounds: MintBounds,
+        ) -> sails_rs::client::PendingCall<io::SetMintBounds, Self::Env> {
+            self.pending_call((bounds,))
+        }
+        fn stale_payouts(
+            &self,
+            older_than: u32,
+        ) -> sails_rs::client::PendingCall<io::StalePayouts, Self::Env> {
+            self.pending_call((older_than,))
+        }
     }
 
     pub mod io {
         use super::*;
         sails_rs::io_struct_impl!(BurnFrom (from: ActorId, value: U256) -> ());
+        sails_rs::io_struct_impl!(SetMintBounds (bounds: super::MintBounds) -> ());
+        sails_rs::io_struct_impl!(StalePayouts (older_than: u32) -> Vec<(MessageId,super::OutboundMessage,)>);
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -871,15 +5593,33 @@ pub mod vft_native_exchange_admin {
         #[codec(crate = sails_rs::scale_codec)]
         pub enum VftNativeExchangeAdminEvents {
             FailedMint { to: ActorId, value: U256 },
+            MintBoundsSet(MintBounds),
         }
         impl sails_rs::client::Event for VftNativeExchangeAdminEvents {
-            const EVENT_NAMES: &'static [Route] = &["FailedMint"];
+            const EVENT_NAMES: &'static [Route] = &["FailedMint", "MintBoundsSet"];
         }
         impl sails_rs::client::ServiceWithEvents for VftNativeExchangeAdminImpl {
             type Event = VftNativeExchangeAdminEvents;
         }
     }
 }
+/// Configuration of the program, validated on deployment by [`init_guard`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitConfig {
+    /// Account granted `DEFAULT_ADMIN_ROLE`.
+    pub admin: ActorId,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Capacities of balances shards.
+    pub balances_shards: Vec<u32>,
+    /// Capacities of allowances shards.
+    pub allowances_shards: Vec<u32>,
+    /// Period allowances expire after, in blocks.
+    pub expiry_period: u32,
+}
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
@@ -887,3 +5627,717 @@ pub struct Pagination {
     pub offset: u32,
     pub limit: u32,
 }
+/// Amount which may be spent within a period of blocks, renewed every period,
+/// e.g. spending caps of allowances, caps of minters or budgets of treasuries.
+///
+/// Periods are aligned to the block the budget was created at, so unlike
+/// rolling windows (see the circuit breaker of VFT balances) the whole amount
+/// may be spent at the end of a period and again at the start of the next one.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PeriodBudget {
+    pub amount_per_period: U256,
+    pub period_blocks: u32,
+    pub period_start: u32,
+    pub spent: U256,
+}
+/// Grant of a role awaiting its delay to pass.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PendingGrant {
+    pub ready_at: u32,
+    pub sender: ActorId,
+    pub via_role: [u8; 32],
+}
+/// Guardians allowed to recover `DEFAULT_ADMIN_ROLE`.
+///
+/// Recovery is disabled while there are no guardians.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<ActorId>,
+    pub threshold: u32,
+    pub delay: u32,
+}
+/// Guardians' approvals of a candidate for `DEFAULT_ADMIN_ROLE`.
+///
+/// `ready_at` is set once the approvals reach the threshold.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RecoveryProposal {
+    pub approvals: Vec<ActorId>,
+    pub ready_at: Option<u32>,
+}
+/// Record of a privileged call.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct AuditEntry {
+    pub block: u32,
+    pub actor: ActorId,
+    pub action: String,
+    pub params_hash: [u8; 32],
+}
+/// Call of a VFT or VFT-Admin command.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Call {
+    Approve { spender: ActorId, value: U256 },
+    Transfer { to: ActorId, value: U256 },
+    TransferFrom { from: ActorId, to: ActorId, value: U256 },
+    Mint { to: ActorId, value: U256 },
+    Burn { from: ActorId, value: U256 },
+}
+/// Message to be sent by the program at the given block.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Task {
+    /// Account refunded on cancellation.
+    pub owner: ActorId,
+    pub execute_at: u32,
+    pub target: ActorId,
+    pub payload: Vec<u8>,
+    pub value: u128,
+    /// Value paid to the account executing the task.
+    pub bounty: u128,
+}
+/// Asset a [`Bounty`] is paid in.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RewardAsset {
+    /// Native value, e.g. attached to the reply.
+    Native,
+    /// VFTs, transferred from the program account.
+    Vft,
+}
+/// Reward paid to accounts performing maintenance work of a service,
+/// e.g. executing due tasks or removing expired entries.
+///
+/// Payouts are proportional to the units of work done and capped per call, per block
+/// and by the funded budget, so keepers can't drain the bounty by splitting or
+/// repeating their calls. Bounty with zero reward (the default) pays nothing.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Bounty {
+    pub asset: RewardAsset,
+    pub reward_per_unit: u128,
+    pub max_units_per_call: u32,
+    pub max_payout_per_block: u128,
+    /// Amount left to be paid out.
+    pub budget: u128,
+    /// Block of the last payout.
+    pub paid_at: u32,
+    /// Amount paid out within `paid_at` block.
+    pub paid_in_block: u128,
+}
+/// Policy of handling dust: the remaining balance of an account
+/// falling below the minimum balance.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum DustPolicy {
+    /// Removes the account, moving dust to the unused value.
+    BurnToUnused,
+    /// Removes the account, crediting dust to the treasury account.
+    ///
+    /// The treasury itself is exempt from the dust removal.
+    TransferToTreasury(ActorId),
+    /// Fails operations leaving dust.
+    Reject,
+}
+/// Parameters of the emission.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InflationSchedule {
+    /// Account the emission is minted to.
+    pub recipient: ActorId,
+    /// Emission of the first era.
+    pub initial_emission: U256,
+    /// Length of an era in blocks: zero disables the emission.
+    pub era_blocks: u32,
+    /// Decrease of the emission every era, in basis points.
+    pub decay_bps: u16,
+    pub start_block: u32,
+    /// Block the emission stops at: eras ending after it aren't minted.
+    pub end_block: Option<u32>,
+}
+/// Asset held by the treasury.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Asset {
+    Native,
+    Vft,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Rejected,
+}
+/// Proposal to spend treasury funds.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Proposal {
+    pub proposer: ActorId,
+    pub beneficiary: ActorId,
+    pub asset: Asset,
+    pub amount: U256,
+    pub created_at: u32,
+    pub status: ProposalStatus,
+}
+/// Price of a token depending on the supply issued through the curve.
+///
+/// Prices are given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Curve {
+    /// Price increasing by `slope` every [`PRICE_SCALE`] tokens of supply.
+    Linear { base_price: U256, slope: U256 },
+    /// Price increasing by `growth_bps` every `step` tokens of supply, compounded.
+    Exponential { base_price: U256, growth_bps: u16, step: U256 },
+}
+/// Tokens of the pool with the swap fee.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Pair {
+    pub token0: ActorId,
+    pub token1: ActorId,
+    /// Fee charged of swapped amounts, in basis points.
+    pub fee_bps: u16,
+}
+/// Parameters of the market.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MarketParams {
+    pub collateral: ActorId,
+    pub debt: ActorId,
+    /// Maximal debt of the position per its collateral value, in basis points.
+    pub ltv_bps: u16,
+    /// Debt per collateral value the position is liquidated at, in basis points.
+    pub liquidation_threshold_bps: u16,
+    /// Collateral paid to liquidators atop of the repaid value, in basis points.
+    pub liquidation_bonus_bps: u16,
+    /// Interest accrued by debt every block, scaled by [`SCALE`].
+    pub interest_per_block: U256,
+    /// Blocks the collateral price stays valid for.
+    pub max_price_age: u32,
+    /// Program with the `Oracle` service reporting the collateral price,
+    /// or zero if the price is only pushed by [`ORACLE_ROLE`].
+    pub oracle: ActorId,
+}
+/// Collateral and debt of an account, with accrued interest.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Position {
+    pub collateral: U256,
+    pub debt: U256,
+}
+/// Funds locked until the secret of the hashlock is revealed or the timelock expires.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Lock {
+    /// Account refunded once the lock expires.
+    pub sender: ActorId,
+    pub recipient: ActorId,
+    /// Block the lock expires at.
+    pub timelock: u32,
+    /// Locked VFTs.
+    pub value: U256,
+    /// Locked native value.
+    pub native: u128,
+}
+/// Parameters of supply reconciliation.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Reconciliation {
+    /// Divergence of the local supply from the remote locked amount tolerated.
+    pub threshold: U256,
+    /// Whether divergence beyond the threshold pauses the program.
+    pub auto_pause: bool,
+}
+/// Local total supply compared against the remote locked amount.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SupplyReport {
+    pub local_supply: U256,
+    pub remote_locked: U256,
+    pub divergence: U256,
+    pub reported_at: u32,
+}
+/// Effects of `burn` previewed by `simulate_burn`.
+///
+/// The burn emits VFT `Transfer` event and, if `reaped` is set, `AccountReaped` one.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct BurnPreview {
+    /// Balance of the account after the burn.
+    pub balance: U256,
+    /// Dust of the account if the burn removes it.
+    pub reaped: Option<U256>,
+    /// Total supply after the burn.
+    pub total_supply: U256,
+}
+/// Effects of `grant_role` previewed by `simulate_grant_role`.
+///
+/// The grant emits `SuperAdminOverrideUsed` event if `via_role` isn't the admin role
+/// of the role, and, if `changed`, `RoleGrantScheduled` event if `ready_at` is set
+/// or `RoleGranted` one otherwise.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GrantPreview {
+    /// Role authorizing the grant.
+    pub via_role: [u8; 32],
+    /// Block the grant is scheduled to, if the role has a grant delay.
+    pub ready_at: Option<u32>,
+    /// Whether the role isn't granted or pending yet.
+    pub changed: bool,
+}
+/// Permissions of an account, reported by `permissions_of`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Permissions {
+    /// Roles granted to the account explicitly.
+    pub roles: Vec<[u8; 32]>,
+    /// Known roles the account may grant and revoke, with their grant delays.
+    pub administered: Vec<([u8; 32], u32)>,
+    /// Grants to the account awaiting their delay.
+    pub pending_grants: Vec<([u8; 32], PendingGrant)>,
+    /// Whether the account holds `DEFAULT_ADMIN_ROLE`, passing any role check.
+    pub is_super_admin: bool,
+}
+/// Reason of a pause, shown to users whose calls fail.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PauseReason {
+    /// Machine-readable code, e.g. an error code.
+    Code(u16),
+    Message(String),
+}
+/// Attribution of a pause.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PauseInfo {
+    pub paused_by: ActorId,
+    pub paused_at: u32,
+    pub reason: Option<PauseReason>,
+}
+/// Thresholds of the circuit breaker, pausing the program if volume minted
+/// or transferred within a window of blocks exceeds them.
+///
+/// Zero thresholds are disabled, and so is the breaker with zero `window_blocks`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CircuitBreakerConfig {
+    pub window_blocks: u32,
+    pub max_minted: U256,
+    pub max_transferred: U256,
+}
+/// Bounds of values deposited to [`VftNativeExchange::mint`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MintBounds {
+    /// Minimal value minted, e.g. against dust spam.
+    pub min_mint_value: U256,
+    /// Maximal value minted, zero for unbounded.
+    pub max_mint_value: U256,
+}
+/// Cumulative values exchanged.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ExchangeStats {
+    /// Value deposited for minted VFTs.
+    pub minted: U256,
+    /// Value paid out for burnt VFTs.
+    pub burned: U256,
+}
+/// Record of a message sent to another program.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct OutboundMessage {
+    pub destination: ActorId,
+    /// What the message is sent for, e.g. `"payout"`.
+    pub purpose: String,
+    pub sent_at: u32,
+}
+/// Session of a key acting on behalf of the account.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Session {
+    pub account: ActorId,
+    /// First block the key is valid at.
+    pub valid_from: u32,
+    /// Last block the key is valid at.
+    pub valid_until: u32,
+    /// Methods the key is allowed to call, e.g. [`TRANSFER`].
+    pub scope: Vec<String>,
+}
+/// Pending rotation of the owner key by guardians.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Recovery {
+    pub new_owner: ActorId,
+    pub approvals: Vec<ActorId>,
+    pub started_at: u32,
+}
+/// Metadata of the token, as returned by `VftMetadata`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+/// Registered token program.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TokenInfo {
+    pub deployer: ActorId,
+    pub metadata: TokenMetadata,
+    pub code_id: CodeId,
+    pub version: u32,
+    pub registered_at: u32,
+    /// Whether a curator verified the token.
+    pub verified: bool,
+}
+/// Registered name.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct NameRecord {
+    pub owner: ActorId,
+    /// Actor the name resolves to.
+    pub target: ActorId,
+    /// Block the name expires at.
+    pub expires_at: u32,
+}
+/// Transfer escrowed until claimed by the recipient,
+/// or reclaimed by the sender once expired.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Claim {
+    pub sender: ActorId,
+    pub recipient: ActorId,
+    pub value: U256,
+    /// Block the sender may reclaim the value from.
+    pub expires_at: u32,
+}
+/// Recurring mint of an amount to a recipient every interval of blocks.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MintRule {
+    pub recipient: ActorId,
+    pub amount: U256,
+    pub interval_blocks: u32,
+    /// Mints left, the rule is removed once it reaches zero.
+    pub remaining_count: u32,
+    /// Block the next mint is due at.
+    pub next_at: u32,
+}
+/// Handling of tokens left unsold once the auction ends.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UnsoldPolicy {
+    /// Unsold tokens are returned to the seller.
+    ReturnToSeller,
+    /// Unsold tokens are burnt.
+    Burn,
+}
+/// Parameters of the auction.
+///
+/// Prices are given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct AuctionParams {
+    /// Amount of tokens for sale.
+    pub amount: U256,
+    /// Price at the start block.
+    pub start_price: U256,
+    /// Price at the end block, not above the start price.
+    pub end_price: U256,
+    pub start_block: u32,
+    /// Block the auction ends at: no purchases are made from it on.
+    pub end_block: u32,
+    /// Amount of tokens an account may buy, zero for unbounded.
+    pub cap_per_account: U256,
+    pub unsold: UnsoldPolicy,
+}
+/// Auction along with its progress.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Auction {
+    /// Account the tokens are escrowed from and the proceeds are sent to.
+    pub seller: ActorId,
+    pub params: AuctionParams,
+    /// Amount of tokens sold.
+    pub sold: U256,
+    /// Native value paid for the sold tokens.
+    pub proceeds: u128,
+}
+/// Parameters of the round.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RoundParams {
+    /// Price of a ticket in VFTs.
+    pub ticket_price: U256,
+    /// Share of the pot taken as the fee, in basis points.
+    pub fee_bps: u16,
+    pub fee_recipient: ActorId,
+    /// Block the round ends at: no tickets are sold from it on.
+    pub end_block: u32,
+    /// VFTs escrowed by the operator, returned on `draw` or slashed
+    /// to ticket holders if the round expires.
+    pub bond: U256,
+}
+/// Status of the round.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RoundStatus {
+    /// Tickets are sold until the end block, then the round is closed.
+    Open,
+    /// Randomness of the chain is mixed into the entropy, the seed is awaited.
+    Closed,
+    /// Winner is drawn, if any tickets were sold.
+    Drawn { winner: Option<ActorId>, prize: U256, fee: U256 },
+}
+/// Hash of a value to be revealed within the window of blocks.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Commitment {
+    pub hash: H256,
+    /// First block the value may be revealed at.
+    pub reveal_from: u32,
+    /// Block the value may no longer be revealed from.
+    pub reveal_until: u32,
+}
+/// Round of the raffle.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Round {
+    pub id: u32,
+    /// Operator who opened the round, escrowing the bond.
+    pub operator: ActorId,
+    pub params: RoundParams,
+    /// Commitment to the seed revealed by `draw` within [`REVEAL_BLOCKS`] after the end.
+    pub commitment: Commitment,
+    /// Amount of sold tickets.
+    pub tickets: u32,
+    /// Entropy accumulated from block data of purchases.
+    pub entropy: H256,
+    pub status: RoundStatus,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Side {
+    /// Maker buys tokens, escrowing native value.
+    Buy,
+    /// Maker sells tokens, escrowing them.
+    Sell,
+}
+/// Limit order along with its unfilled part.
+///
+/// The price is given in native value per token (the smallest VFT unit),
+/// scaled by [`PRICE_SCALE`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Order {
+    pub maker: ActorId,
+    pub side: Side,
+    pub price: U256,
+    /// Amount of tokens left to fill.
+    pub remaining: U256,
+    /// Native value left in escrow, zero for sell orders.
+    pub escrow: u128,
+    /// Block the order expires at.
+    pub expires_at: u32,
+}
+/// Fee charged on fills.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeConfig {
+    /// Fee in basis points of the native value of the fill.
+    pub fee_bps: u16,
+    /// Account fees are sent to.
+    pub treasury: ActorId,
+}
+/// Fill along with signatures of makers of its orders, see [`fill_message`].
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SignedFill {
+    pub fill: Fill,
+    pub sell_signature: [u8; 64],
+    pub buy_signature: [u8; 64],
+}
+/// Sell order matched against a buy order off-chain.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Fill {
+    pub sell_id: u64,
+    pub buy_id: u64,
+    /// Amount of tokens traded.
+    pub amount: U256,
+    /// Price of the trade, between the prices of the orders.
+    pub price: U256,
+}
+/// Conversion of gas spent by relayers into VFTs.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeRate {
+    /// Tokens per unit of gas, scaled by [`RATE_SCALE`].
+    pub per_gas: U256,
+    /// Gas added to the measured one, covering the rest of the message.
+    pub base_gas: u64,
+}
+/// Accounting of a relayer.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RelayerStats {
+    /// Amount of meta-transactions relayed.
+    pub relayed: u64,
+    /// Gas reimbursed, the base gas included.
+    pub gas: u64,
+    /// Tokens reimbursed.
+    pub reimbursed: U256,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Allowance(pub [u8; 9]);
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Balance(pub [u8; 10]);
+/// Position in the sharded map to resume iteration from.
+///
+/// Default cursor points to the start of the map. Pairs keep their positions
+/// unless the shard gets rehashed, reclaiming space of removed pairs, so pairs
+/// inserted or removed between pages may be missed, as they're by skipping.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MapCursor {
+    /// Index of the shard.
+    pub shard: u32,
+    /// Index of the bucket in the shard.
+    pub bucket: u32,
+}
+/// Metrics of the sharded map.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MapMetrics {
+    /// Amount of shards, allocated or not.
+    pub shards: u32,
+    /// Amount of allocated shards.
+    pub allocated_shards: u32,
+    /// Amount of entries in the map.
+    pub entries: u32,
+    /// Amount of entries allocated shards may hold.
+    pub capacity: u32,
+    /// Amount of entries all shards may hold, once allocated.
+    pub max_capacity: u32,
+    /// Estimated heap bytes of allocated shards and their filters.
+    pub heap_bytes: u64,
+}
+/// Metrics of the program storage.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ProgramMetrics {
+    /// Metrics of the balances map.
+    pub balances: MapMetrics,
+    /// Metrics of the allowances map.
+    pub allowances: MapMetrics,
+    /// Amount of roles known.
+    pub roles: u32,
+    /// Amount of distinct accounts holding any role.
+    pub members: u32,
+    /// Amount of privileged calls made, recorded by the audit log.
+    pub audit_seq: u32,
+}
+/// Commitment to balances of all holders as of the block, e.g. for airdrops
+/// or governance snapshots built off-chain to be verified against it.
+///
+/// `root` is the root of the merkle tree of keccak-256 hashes:
+///
+/// - leaves are hashes of `0x00 ++ (account, balance).encode()`, with `balance`
+///   as `U256`, in order of enumeration by `balances_from`;
+/// - nodes are hashes of `0x01 ++ min(left, right) ++ max(left, right)`,
+///   so proofs are lists of siblings, with the last node of odd levels promoted;
+/// - root of no holders is zero.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SnapshotRoot {
+    pub block: u32,
+    pub root: [u8; 32],
+    pub holders: u32,
+}
+/// Sharded map of the VFT storage.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum StorageMap {
+    Allowances,
+    Balances,
+}
+/// Alert of operators about occupancy of the map.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CapacityAlert {
+    /// Occupancy of allocated shards alerting once crossed by inserts, in basis points.
+    pub threshold_bps: u16,
+    /// Account notified about the crossing, besides the event, if any.
+    pub operator: Option<ActorId>,
+}
@@ -0,0 +1,101 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Golden IDL tests.
+//!
+//! The build script regenerates the IDL of the test program on every build;
+//! these tests compare it, split per service, against the golden files in `idl/`,
+//! so any change of exported signatures, events or types fails until reviewed.
+//!
+//! Run with `UPDATE_GOLDEN_IDL=1` to accept the changes, then commit `idl/`.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+const IDL: &str = "awesome_sails_test_client.idl";
+
+/// Splits the IDL into golden files: `program.idl` with types and the constructor,
+/// and a file per service.
+fn split(idl: &str) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::<String, String>::new();
+    let mut chunk = String::new();
+
+    for line in idl.lines() {
+        chunk.push_str(line);
+        chunk.push('\n');
+
+        if line != "};" {
+            continue;
+        }
+
+        let name = chunk
+            .lines()
+            .find_map(|line| line.strip_prefix("service "))
+            .and_then(|line| line.split_whitespace().next())
+            .unwrap_or("program");
+
+        let file = files.entry(format!("{name}.idl")).or_default();
+
+        if !file.is_empty() {
+            file.push('\n');
+        }
+
+        file.push_str(chunk.trim_start_matches('\n'));
+        chunk.clear();
+    }
+
+    files
+}
+
+#[test]
+fn idl_matches_golden() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let golden = root.join("idl");
+
+    let idl = fs::read_to_string(root.join(IDL)).expect("IDL is generated by the build script");
+    let files = split(&idl);
+
+    if std::env::var_os("UPDATE_GOLDEN_IDL").is_some() {
+        let _ = fs::remove_dir_all(&golden);
+        fs::create_dir_all(&golden).unwrap();
+
+        for (name, content) in &files {
+            fs::write(golden.join(name), content).unwrap();
+        }
+
+        return;
+    }
+
+    let mut stale: Vec<_> = fs::read_dir(&golden)
+        .expect("golden IDL is committed")
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| !files.contains_key(name))
+        .collect();
+    stale.sort();
+
+    assert!(stale.is_empty(), "services removed from the IDL: {stale:?}");
+
+    for (name, content) in &files {
+        let expected = fs::read_to_string(golden.join(name)).unwrap_or_default();
+
+        assert!(
+            *content == expected,
+            "IDL of `{name}` drifted from golden; review the change and rerun with `UPDATE_GOLDEN_IDL=1`\n\
+             --- golden\n{expected}\n+++ generated\n{content}"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! End-to-end tests of the test program against a live node.
+//!
+//! Unlike gtest, a node executes value transfers and replies for real, so these
+//! tests cover paths gtest only simulates. They run with the `e2e` feature against
+//! a dev node, e.g. `gear --dev`, listening on `ws://127.0.0.1:9944`:
+//!
+//! ```sh
+//! cargo test -p awesome-sails-e2e --features e2e
+//! ```
+
+#![cfg(feature = "e2e")]
+
+use awesome_sails_test_client::{
+    AwesomeSailsTestClient, AwesomeSailsTestClientCtors, AwesomeSailsTestClientProgram,
+    vft_extension::VftExtension,
+};
+use sails_rs::{
+    ActorId, CodeId,
+    client::{Actor, GclientEnv},
+    gclient::GearApi,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(debug_assertions)]
+pub const WASM_PATH: &str = "../../target/wasm32-gear/debug/awesome_sails_test_app.opt.wasm";
+#[cfg(not(debug_assertions))]
+pub const WASM_PATH: &str = "../../target/wasm32-gear/release/awesome_sails_test_app.opt.wasm";
+
+/// Connects to the dev node as Alice, returning the API, the environment and Alice's account.
+pub async fn connect() -> (GearApi, GclientEnv, ActorId) {
+    let api = GearApi::dev()
+        .await
+        .expect("failed to connect to the dev node");
+    let account = ActorId::new(*api.account_id().as_ref());
+
+    (api.clone(), GclientEnv::new(api), account)
+}
+
+/// Deploys a new instance of the test program on behalf of the connected account,
+/// which becomes its admin.
+pub async fn deploy(
+    api: &GearApi,
+    env: &GclientEnv,
+) -> Actor<AwesomeSailsTestClientProgram, GclientEnv> {
+    let code = std::fs::read(WASM_PATH).expect("failed to read program code");
+    let code_id = CodeId::generate(&code);
+
+    // Code stays on the node between runs, failing repeated uploads.
+    let _ = api.upload_code(&code).await;
+
+    let salt = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos()
+        .to_le_bytes();
+
+    let program = env
+        .deploy::<AwesomeSailsTestClientProgram>(code_id, salt.to_vec())
+        .new()
+        .await
+        .expect("failed to deploy program");
+
+    let mut vft_extension = program.vft_extension();
+
+    while vft_extension
+        .allocate_next_balances_shard()
+        .await
+        .expect("failed to allocate next balances shard")
+    {}
+
+    program
+}
@@ -0,0 +1,88 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "e2e")]
+
+use awesome_sails_e2e::{connect, deploy};
+use awesome_sails_test_client::{
+    AwesomeSailsTestClient, vft::Vft, vft_native_exchange::VftNativeExchange,
+};
+use awesome_sails_utils::assert_ok;
+use sails_rs::{ActorId, U256};
+
+/// Value exchanged, 10 * 10**12, well above the existential deposit.
+const VALUE: u128 = 10_000_000_000_000;
+
+#[tokio::test]
+async fn native_exchange() {
+    let (api, env, alice) = connect().await;
+
+    let program = deploy(&api, &env).await;
+    let program_id = program.id();
+
+    let mut native_exchange = program.vft_native_exchange();
+    let vft_service = program.vft();
+
+    // Minting exchanges the attached value to VFTs.
+    native_exchange.mint().with_value(VALUE).await.unwrap();
+
+    let res = vft_service.balance_of(alice).await;
+    assert_ok!(res, U256::from(VALUE));
+
+    let balance = api.free_balance(program_id).await.unwrap();
+
+    // Burning replies with the value of burnt VFTs.
+    native_exchange.burn(U256::from(VALUE / 2)).await.unwrap();
+
+    let res = vft_service.balance_of(alice).await;
+    assert_ok!(res, U256::from(VALUE / 2));
+
+    assert_eq!(
+        api.free_balance(program_id).await.unwrap(),
+        balance - VALUE / 2
+    );
+
+    // Burning all VFTs replies with the value of the rest.
+    native_exchange.burn_all().await.unwrap();
+
+    let res = vft_service.balance_of(alice).await;
+    assert_ok!(res, U256::zero());
+
+    assert_eq!(api.free_balance(program_id).await.unwrap(), balance - VALUE);
+}
+
+#[tokio::test]
+async fn error_reply() {
+    let (api, env, alice) = connect().await;
+
+    let program = deploy(&api, &env).await;
+
+    let mut native_exchange = program.vft_native_exchange();
+    let mut vft_service = program.vft();
+
+    native_exchange.mint().with_value(VALUE).await.unwrap();
+
+    // Failing commands reply with an error, leaving state untouched.
+    let res = vft_service
+        .transfer(ActorId::new([43; 32]), U256::from(VALUE + 1))
+        .await;
+    assert!(res.is_err());
+
+    let res = vft_service.balance_of(alice).await;
+    assert_ok!(res, U256::from(VALUE));
+}
@@ -0,0 +1,255 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Deterministic scenario replay.
+//!
+//! A [`Scenario`] deploys a program and sends it a sequence of service calls on behalf
+//! of given actors, querying the state after each call. Running it in gtest gives the
+//! same replies and snapshots every time, so bug reports of deployed programs are
+//! reproduced from a scenario file alone.
+//!
+//! Scenarios are read from JSON, with byte strings hex-encoded, or SCALE-encoded
+//! files with the `.scale` extension. Arguments of calls are SCALE-encoded, as in
+//! messages, so any service is replayed without its client.
+
+use sails_rs::{
+    ActorId,
+    gtest::{Program, System},
+    scale_codec::{Decode, Encode},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fs, path::Path};
+
+/// Balance accounts are funded with unless a scenario sets it, 100_000 * 10**12.
+pub const DEFAULT_BALANCE: u128 = 100_000_000_000_000_000;
+
+/// Sequence of calls replayed against a program.
+#[derive(Clone, Debug, Decode, Encode, Deserialize)]
+#[codec(crate = sails_rs::scale_codec)]
+pub struct Scenario {
+    /// Accounts funded before the program is deployed.
+    #[serde(default)]
+    pub accounts: Vec<Account>,
+    /// Constructor call deploying the program, its `method` being the constructor.
+    pub init: Call,
+    pub steps: Vec<Call>,
+    /// Queries answered after every step, on behalf of the init actor.
+    #[serde(default)]
+    pub snapshot: Vec<Query>,
+}
+
+#[derive(Clone, Debug, Decode, Encode, Deserialize)]
+#[codec(crate = sails_rs::scale_codec)]
+pub struct Account {
+    #[serde(with = "hex_actor")]
+    pub id: ActorId,
+    #[serde(default = "default_balance")]
+    pub balance: u128,
+}
+
+/// Message sent to the program.
+#[derive(Clone, Debug, Decode, Encode, Deserialize)]
+#[codec(crate = sails_rs::scale_codec)]
+pub struct Call {
+    #[serde(with = "hex_actor")]
+    pub actor: ActorId,
+    /// Route of the service; empty for constructors.
+    #[serde(default)]
+    pub service: String,
+    pub method: String,
+    /// SCALE-encoded arguments.
+    #[serde(default, with = "hex_bytes")]
+    pub args: Vec<u8>,
+    #[serde(default)]
+    pub value: u128,
+    /// Blocks to run before sending the message.
+    #[serde(default)]
+    pub skip_blocks: u32,
+}
+
+#[derive(Clone, Debug, Decode, Encode, Deserialize)]
+#[codec(crate = sails_rs::scale_codec)]
+pub struct Query {
+    pub service: String,
+    pub method: String,
+    #[serde(default, with = "hex_bytes")]
+    pub args: Vec<u8>,
+}
+
+/// Reply of the program to a message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Reply {
+    pub ok: bool,
+    /// Reply payload: the encoded result on success, the error otherwise.
+    #[serde(serialize_with = "hex_bytes::serialize")]
+    pub payload: Vec<u8>,
+}
+
+/// Outcome of a step: the reply and the snapshot taken afterwards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StepResult {
+    pub block: u32,
+    pub reply: Reply,
+    pub snapshot: Vec<Reply>,
+}
+
+impl Scenario {
+    /// Reads the scenario, SCALE-encoded if the file has the `.scale` extension
+    /// and JSON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let data = fs::read(path).map_err(|e| format!("failed to read scenario: {e}"))?;
+
+        if path.extension().is_some_and(|ext| ext == "scale") {
+            Self::decode(&mut data.as_slice()).map_err(|e| format!("invalid scenario: {e}"))
+        } else {
+            serde_json::from_slice(&data).map_err(|e| format!("invalid scenario: {e}"))
+        }
+    }
+
+    /// Replays the scenario against the program code, returning the init reply
+    /// and results of steps.
+    pub fn replay(&self, code: &[u8]) -> (Reply, Vec<StepResult>) {
+        let system = System::new();
+
+        for account in &self.accounts {
+            system.mint_to(account.id, account.balance);
+        }
+
+        let program = Program::from_binary_with_id(&system, ActorId::from(1), code);
+
+        let init = send(&system, &program, &self.init);
+
+        let results = self
+            .steps
+            .iter()
+            .map(|call| {
+                let reply = send(&system, &program, call);
+
+                let snapshot = self
+                    .snapshot
+                    .iter()
+                    .map(|query| {
+                        let call = Call {
+                            actor: self.init.actor,
+                            service: query.service.clone(),
+                            method: query.method.clone(),
+                            args: query.args.clone(),
+                            value: 0,
+                            skip_blocks: 0,
+                        };
+
+                        send(&system, &program, &call)
+                    })
+                    .collect();
+
+                StepResult {
+                    block: system.block_height(),
+                    reply,
+                    snapshot,
+                }
+            })
+            .collect();
+
+        (init, results)
+    }
+}
+
+impl Call {
+    /// Returns the message payload: encoded routes followed by the arguments.
+    pub fn payload(&self) -> Vec<u8> {
+        let mut payload = Vec::new();
+
+        if !self.service.is_empty() {
+            self.service.encode_to(&mut payload);
+        }
+
+        self.method.encode_to(&mut payload);
+        payload.extend_from_slice(&self.args);
+
+        payload
+    }
+}
+
+fn send(system: &System, program: &Program<'_>, call: &Call) -> Reply {
+    if call.skip_blocks != 0 {
+        system.run_to_block(system.block_height() + call.skip_blocks);
+    }
+
+    let message_id = program.send_bytes_with_value(call.actor, call.payload(), call.value);
+    let result = system.run_next_block();
+
+    let payload = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(message_id))
+        .map(|log| log.payload().to_vec())
+        .unwrap_or_default();
+
+    Reply {
+        ok: result.succeed.contains(&message_id),
+        payload,
+    }
+}
+
+fn default_balance() -> u128 {
+    DEFAULT_BALANCE
+}
+
+/// Hex encoding of byte strings, with an optional `0x` prefix.
+mod hex_bytes {
+    use super::*;
+
+    pub fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn decode(s: &str) -> Result<Vec<u8>, String> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+
+        if !s.len().is_multiple_of(2) {
+            return Err(format!("odd length of hex string `{s}`"));
+        }
+
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        decode(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Hex encoding of actor ids.
+mod hex_actor {
+    use super::*;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ActorId, D::Error> {
+        let bytes = hex_bytes::deserialize(deserializer)?;
+
+        <[u8; 32]>::try_from(bytes)
+            .map(ActorId::new)
+            .map_err(|_| serde::de::Error::custom("actor id must be 32 bytes"))
+    }
+}
@@ -0,0 +1,60 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Replays a scenario against the program code, printing replies and
+//! snapshots of every step as JSON.
+//!
+//! ```sh
+//! cargo run -p awesome-sails-replay -- <program.opt.wasm> <scenario.json|scenario.scale>
+//! ```
+
+use awesome_sails_replay::Scenario;
+use std::{env, fs, process::ExitCode};
+
+fn main() -> ExitCode {
+    let args: Vec<_> = env::args().skip(1).collect();
+
+    let [code, scenario] = args.as_slice() else {
+        eprintln!("usage: awesome-sails-replay <program.opt.wasm> <scenario.json|scenario.scale>");
+        return ExitCode::FAILURE;
+    };
+
+    let code = match fs::read(code) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("failed to read program code: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let scenario = match Scenario::load(scenario) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (init, steps) = scenario.replay(&code);
+
+    let output = serde_json::json!({ "init": init, "steps": steps });
+
+    println!("{output:#}");
+
+    ExitCode::SUCCESS
+}
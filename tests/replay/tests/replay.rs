@@ -0,0 +1,73 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_replay::{Reply, Scenario};
+use sails_rs::{U256, scale_codec::Encode};
+
+#[cfg(debug_assertions)]
+const WASM_PATH: &str = "../../target/wasm32-gear/debug/awesome_sails_test_app.opt.wasm";
+#[cfg(not(debug_assertions))]
+const WASM_PATH: &str = "../../target/wasm32-gear/release/awesome_sails_test_app.opt.wasm";
+
+fn balance(method: &str, value: u128) -> Reply {
+    Reply {
+        ok: true,
+        payload: [("Vft", method).encode(), U256::from(value).encode()].concat(),
+    }
+}
+
+#[test]
+fn replays_transfers() {
+    let code = std::fs::read(WASM_PATH).unwrap();
+    let scenario = Scenario::load("scenarios/transfer.json").unwrap();
+
+    let (init, steps) = scenario.replay(&code);
+
+    assert!(init.ok);
+    assert_eq!(steps.len(), 4);
+
+    // Transfer is made after skipped blocks.
+    assert!(steps[2].reply.ok);
+    assert_eq!(steps[2].block, steps[1].block + 11);
+    assert_eq!(
+        steps[2].snapshot,
+        [
+            balance("BalanceOf", 6_000_000_000_000),
+            balance("BalanceOf", 4_000_000_000_000),
+            balance("TotalSupply", 10_000_000_000_000),
+        ]
+    );
+
+    // Transfer above the balance fails, leaving state untouched.
+    assert!(!steps[3].reply.ok);
+    assert_eq!(steps[3].snapshot, steps[2].snapshot);
+
+    // Replays are deterministic.
+    assert_eq!(scenario.replay(&code), (init, steps));
+}
+
+#[test]
+fn loads_scale_scenarios() {
+    let scenario = Scenario::load("scenarios/transfer.json").unwrap();
+
+    let path = std::env::temp_dir().join("awesome-sails-replay-transfer.scale");
+    std::fs::write(&path, scenario.encode()).unwrap();
+
+    let decoded = Scenario::load(&path).unwrap();
+    assert_eq!(decoded.encode(), scenario.encode());
+}
@@ -0,0 +1,160 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome bounded audit log primitive.
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+#[cfg(feature = "gprimitives")]
+pub use entry::AuditEntry;
+
+/// Bounded log keeping the most recent entries.
+///
+/// Each entry is assigned a sequential index, so entries remain addressable
+/// after older ones are evicted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditLog<T> {
+    entries: VecDeque<T>,
+    capacity: usize,
+    next_index: u32,
+}
+
+impl<T> Default for AuditLog<T> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<T> AuditLog<T> {
+    /// Capacity used by [`Default`] implementation.
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    /// Creates a new [`Self`] keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+            next_index: 0,
+        }
+    }
+
+    /// Returns the maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries kept.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns bool indicating if no entries are kept.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the index of the oldest entry kept.
+    pub fn first_index(&self) -> u32 {
+        self.next_index - self.entries.len() as u32
+    }
+
+    /// Returns the index the next entry will be assigned.
+    pub fn next_index(&self) -> u32 {
+        self.next_index
+    }
+
+    /// Appends an entry, evicting the oldest one if the log is full.
+    ///
+    /// Returns the index assigned to the entry.
+    pub fn push(&mut self, entry: T) -> u32 {
+        let index = self.next_index;
+
+        if self.capacity != 0 {
+            if self.entries.len() >= self.capacity {
+                self.entries.pop_front();
+            }
+
+            self.entries.push_back(entry);
+        }
+
+        self.next_index = self.next_index.saturating_add(1);
+
+        index
+    }
+
+    /// Iterates over entries kept, starting from the given index or the oldest one.
+    pub fn iter_from(&self, cursor: u32) -> impl Iterator<Item = (u32, &T)> {
+        let first = self.first_index();
+        let skip = cursor.saturating_sub(first);
+
+        self.entries
+            .iter()
+            .enumerate()
+            .skip(skip as usize)
+            .map(move |(i, entry)| (first + i as u32, entry))
+    }
+
+    /// Returns up to `len` entries kept, starting from the given index or the oldest one.
+    pub fn entries(&self, cursor: u32, len: u32) -> Vec<(u32, T)>
+    where
+        T: Clone,
+    {
+        self.iter_from(cursor)
+            .take(len as usize)
+            .map(|(i, entry)| (i, entry.clone()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "gprimitives")]
+mod entry {
+    use alloc::string::String;
+    use gprimitives::ActorId;
+    use parity_scale_codec::{Decode, Encode};
+    use scale_info::TypeInfo;
+
+    /// Record of a privileged call.
+    #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+    #[codec(crate = parity_scale_codec)]
+    #[scale_info(crate = scale_info)]
+    pub struct AuditEntry {
+        pub block: u32,
+        pub actor: ActorId,
+        pub action: String,
+        pub params_hash: [u8; 32],
+    }
+
+    impl AuditEntry {
+        /// Creates a new [`Self`], hashing SCALE-encoded `params` with Keccak-256.
+        pub fn new(
+            block: u32,
+            actor: ActorId,
+            action: impl Into<String>,
+            params: &impl Encode,
+        ) -> Self {
+            Self {
+                block,
+                actor,
+                action: action.into(),
+                params_hash: keccak_const::Keccak256::new()
+                    .update(&params.encode())
+                    .finalize(),
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome bounded checkpoints primitive.
+
+use alloc::collections::VecDeque;
+
+/// Bounded history of a value, recorded at blocks it changed in.
+///
+/// Keeps the most recent checkpoints, so the value is known for blocks
+/// since the oldest checkpoint kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoints<V> {
+    entries: VecDeque<(u32, V)>,
+    capacity: usize,
+}
+
+impl<V> Default for Checkpoints<V> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<V> Checkpoints<V> {
+    /// Capacity used by [`Default`] implementation.
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    /// Creates a new [`Self`] keeping at most `capacity` checkpoints.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of checkpoints kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of checkpoints kept.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns bool indicating if no checkpoints are kept.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the block of the oldest checkpoint kept.
+    pub fn first_block(&self) -> Option<u32> {
+        self.entries.front().map(|(block, _)| *block)
+    }
+
+    /// Returns the latest checkpoint.
+    pub fn latest(&self) -> Option<(u32, &V)> {
+        self.entries.back().map(|(block, value)| (*block, value))
+    }
+
+    /// Returns the value as of the end of the given block,
+    /// or `None` if the block precedes the oldest checkpoint kept.
+    pub fn at(&self, block: u32) -> Option<&V> {
+        let idx = self.entries.partition_point(|(b, _)| *b <= block);
+
+        idx.checked_sub(1).map(|idx| &self.entries[idx].1)
+    }
+}
+
+impl<V: PartialEq> Checkpoints<V> {
+    /// Records the value at the given block, evicting the oldest checkpoint
+    /// if the history is full.
+    ///
+    /// Blocks are expected to be non-decreasing: value recorded at the block
+    /// of the latest checkpoint (or before it) replaces its value.
+    /// Unchanged values aren't recorded.
+    pub fn push(&mut self, block: u32, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        match self.entries.back_mut() {
+            Some((latest, latest_value)) if *latest >= block => *latest_value = value,
+            Some((_, latest_value)) if *latest_value == value => {}
+            _ => {
+                if self.entries.len() >= self.capacity {
+                    self.entries.pop_front();
+                }
+
+                self.entries.push_back((block, value));
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome commit-reveal primitive.
+//!
+//! Parties commit to the keccak-256 hash of a secret value, e.g. a seed or a move,
+//! and reveal the value within a window of blocks, so nobody chooses it after
+//! seeing values of others. Values worth guessing should be salted.
+
+use crate::ensure;
+use alloc::{collections::BTreeMap, vec::Vec};
+use parity_scale_codec::{Decode, Encode};
+use primitive_types::H256;
+use scale_info::TypeInfo;
+
+/// Returns the keccak-256 hash the value is committed to.
+pub fn hash(value: &[u8]) -> H256 {
+    keccak_const::Keccak256::new()
+        .update(value)
+        .finalize()
+        .into()
+}
+
+/// Hash of a value to be revealed within the window of blocks.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct Commitment {
+    pub hash: H256,
+    /// First block the value may be revealed at.
+    pub reveal_from: u32,
+    /// Block the value may no longer be revealed from.
+    pub reveal_until: u32,
+}
+
+impl Commitment {
+    /// Creates a new `Commitment` to the hash, revealed within `reveal_from..reveal_until`.
+    pub fn new(hash: H256, reveal_from: u32, reveal_until: u32) -> Self {
+        Self {
+            hash,
+            reveal_from,
+            reveal_until,
+        }
+    }
+
+    /// Returns `true` if the reveal window has closed.
+    pub fn is_expired(&self, current_bn: u32) -> bool {
+        current_bn >= self.reveal_until
+    }
+
+    /// Checks that the value is revealed within the window and matches the hash.
+    pub fn verify(&self, value: &[u8], current_bn: u32) -> Result<(), RevealError> {
+        ensure!(current_bn >= self.reveal_from, RevealError::TooEarly);
+        ensure!(!self.is_expired(current_bn), RevealError::Expired);
+        ensure!(hash(value) == self.hash, RevealError::Mismatch);
+
+        Ok(())
+    }
+}
+
+/// Commitments keyed by e.g. `ActorId`, each revealed once.
+#[derive(Clone, Debug, Default)]
+pub struct Commitments<K>(BTreeMap<K, Commitment>);
+
+impl<K: Ord> Commitments<K> {
+    pub fn get(&self, key: &K) -> Option<&Commitment> {
+        self.0.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds the commitment of the key, failing if it has one already.
+    pub fn commit(&mut self, key: K, commitment: Commitment) -> Result<(), AlreadyCommitted> {
+        ensure!(!self.0.contains_key(&key), AlreadyCommitted);
+
+        self.0.insert(key, commitment);
+
+        Ok(())
+    }
+
+    /// Removes the commitment of the key once its value is revealed.
+    pub fn reveal(
+        &mut self,
+        key: &K,
+        value: &[u8],
+        current_bn: u32,
+    ) -> Result<Commitment, RevealError> {
+        let commitment = self.0.get(key).ok_or(RevealError::NotCommitted)?;
+
+        commitment.verify(value, current_bn)?;
+
+        Ok(self.0.remove(key).expect("checked above"))
+    }
+
+    /// Removes commitments whose reveal window has closed, returning their keys.
+    pub fn remove_expired(&mut self, current_bn: u32) -> Vec<K>
+    where
+        K: Clone,
+    {
+        let expired: Vec<K> = self
+            .0
+            .iter()
+            .filter(|(_, commitment)| commitment.is_expired(current_bn))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &expired {
+            self.0.remove(key);
+        }
+
+        expired
+    }
+}
+
+/// Error type for failed reveals.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub enum RevealError {
+    #[error("nothing is committed")]
+    NotCommitted,
+    #[error("reveal window is not open")]
+    TooEarly,
+    #[error("reveal window is closed")]
+    Expired,
+    #[error("revealed value doesn't match the commitment")]
+    Mismatch,
+}
+
+/// Error type for repeated commitments.
+#[derive(Clone, Debug, Decode, Default, Encode, PartialEq, Eq, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[error("already committed")]
+#[scale_info(crate = scale_info)]
+pub struct AlreadyCommitted;
+
+crate::impl_error_code!(RevealError => 60, AlreadyCommitted => 61);
@@ -18,34 +18,161 @@
 
 //! Awesome errors definition module.
 
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 /// Error type for the `awesome-sails` library.
-#[derive(Clone, Decode, Encode, TypeInfo, derive_more::Display)]
+///
+/// Carries the [`ErrorCode`] of the error it was converted from, so clients can branch
+/// on failures without matching messages.
+///
+/// Services built with the `typed-errors` feature reply with it encoded instead of its
+/// message, see [`decode_result`] and [`decode_error`].
+#[derive(Clone, Decode, Encode, TypeInfo, PartialEq, Eq, derive_more::Display)]
 #[codec(crate = parity_scale_codec)]
 #[scale_info(crate = scale_info)]
-#[display("{}", _0)]
-pub struct Error(String);
+#[display("{}", message)]
+pub struct Error {
+    code: u16,
+    message: String,
+}
 
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.message)
     }
 }
 
 impl Error {
-    /// Creates a new [`Self`] instance with the given message.
+    /// Code of errors created from a message only.
+    pub const UNSPECIFIED: u16 = 0;
+
+    /// Creates a new [`Self`] instance with the given message and [`Self::UNSPECIFIED`] code.
     pub fn new(message: impl ToString) -> Self {
-        Self(message.to_string())
+        Self::with_code(Self::UNSPECIFIED, message)
+    }
+
+    /// Creates a new [`Self`] instance with the given code and message.
+    pub fn with_code(code: u16, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
     }
 }
 
-impl<E: core::error::Error> From<E> for Error {
+impl<E: core::error::Error + ErrorCode> From<E> for Error {
     fn from(err: E) -> Self {
-        Self::new(err)
+        Self::with_code(err.code(), err)
+    }
+}
+
+/// Decodes the reply of a method of a service built with the `typed-errors` feature.
+///
+/// `payload` is the reply payload without the route prefix. Failed queries reply with `Err`,
+/// while failed commands are reverted, replying with an error reply, see [`decode_error`].
+pub fn decode_result<T: Decode>(
+    mut payload: &[u8],
+) -> Result<Result<T, Error>, parity_scale_codec::Error> {
+    Decode::decode(&mut payload)
+}
+
+/// Decodes the error reply payload of a failed command of a service built with
+/// the `typed-errors` feature, i.e. the panic message of [`revert`].
+pub fn decode_error(payload: &[u8]) -> Result<Error, parity_scale_codec::Error> {
+    let hex = core::str::from_utf8(payload)
+        .ok()
+        .and_then(|reply| reply.strip_prefix(REVERT_PREFIX))
+        .and_then(|reply| reply.split('\'').next())
+        .ok_or("error reply isn't a reverted command")?;
+
+    let encoded = (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or("error reply isn't hex")?;
+
+    Decode::decode(&mut encoded.as_slice())
+}
+
+/// Prefix of the panic message of [`revert`] in the error reply payload, as added by
+/// the panic handler of `gstd`.
+const REVERT_PREFIX: &str = "panicked with '0x";
+
+/// Fails a command of a service built with the `typed-errors` feature with `err`,
+/// reverting state changes made by the command.
+///
+/// As services can't set the error reply payload, `err` is encoded as hex in the panic
+/// message, see [`decode_error`].
+pub fn revert(err: impl Encode) -> ! {
+    struct Hex(Vec<u8>);
+
+    impl fmt::Display for Hex {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("0x")?;
+            self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+        }
+    }
+
+    panic!("{}", Hex(err.encode()))
+}
+
+/// Stable numeric code of an error.
+///
+/// Codes are allocated in ranges per crate:
+///
+/// - `0`: unspecified, see [`Error::new`].
+/// - `1..=99`: `awesome-sails-utils`.
+/// - `100..=199`: `awesome-sails-vft` and its utils.
+/// - `200..=299`: `awesome-sails-vft-extension`.
+/// - `300..=399`: `awesome-sails-vft-admin`.
+/// - `400..=499`: `awesome-sails-vft-native-exchange` and its admin.
+/// - `500..=599`: `awesome-sails-access-control`.
+/// - `600..=699`: `awesome-sails-treasury`.
+/// - `700..=799`: `awesome-sails-vft-bonding-curve`.
+/// - `800..=899`: `awesome-sails-amm`.
+/// - `900..=999`: `awesome-sails-lending`.
+/// - `1000..=1099`: `awesome-sails-htlc`.
+/// - `1100..=1199`: `awesome-sails-eth-binding`.
+/// - `1200..=1299`: `awesome-sails-vft-bridge-adapter`.
+/// - `1300..=1399`: `awesome-sails-session-keys`.
+/// - `1400..=1499`: `awesome-sails-smart-account`.
+/// - `1500..=1599`: `awesome-sails-proxy`.
+/// - `1600..=1699`: `awesome-sails-token-registry`.
+/// - `1700..=1799`: `awesome-sails-name-registry`.
+/// - `1800..=1899`: `awesome-sails-dutch-auction`.
+/// - `1900..=1999`: `awesome-sails-raffle`.
+/// - `2000..=2099`: `awesome-sails-order-book`.
+/// - `2100..=2199`: `awesome-sails-fee-pool`.
+///
+/// Wrapping errors forward the code of the error they wrap.
+pub trait ErrorCode {
+    /// Returns the code of the error.
+    fn code(&self) -> u16;
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u16 {
+        self.code
+    }
+}
+
+impl ErrorCode for core::convert::Infallible {
+    fn code(&self) -> u16 {
+        match *self {}
     }
 }
 
@@ -76,3 +203,68 @@ pub struct BadValue;
 #[error("emit event error")]
 #[scale_info(crate = scale_info)]
 pub struct EmitError;
+
+/// Error type for invalid configuration of a program being deployed.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub enum BadConfig {
+    #[error("admin is zero")]
+    ZeroAdmin,
+    #[error("decimals exceed the maximum")]
+    Decimals,
+    #[error("shard capacity is invalid")]
+    ShardCapacity,
+    #[error("expiry period is zero")]
+    ExpiryPeriod,
+}
+
+/// Error type for inability to send a message, e.g. with value, or to pay for its gas.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub enum SendError {
+    #[error("failed to send value")]
+    Value,
+    #[error("failed to send message")]
+    Message,
+    #[error("failed to send delayed message")]
+    DelayedMessage,
+    #[error("failed to notify spender")]
+    Notify,
+    #[error("failed to reserve gas")]
+    ReserveGas,
+    #[error("failed to deposit gas for reply")]
+    ReplyDeposit,
+}
+
+crate::impl_error_code!(
+    BadInput => 1,
+    BadOrigin => 2,
+    BadValue => 3,
+    EmitError => 4,
+    BadConfig => 5,
+    SendError => 6
+);
+
+/// Machine-readable description of a failure, for services to emit as an event.
+///
+/// Note that events emitted by a message that panics are discarded along with its
+/// state changes, so emitting it is only meaningful for failures replied as `Err`.
+#[derive(Clone, Debug, Decode, Encode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct ErrorEvent {
+    pub code: u16,
+    pub message: String,
+}
+
+impl ErrorEvent {
+    /// Creates a new [`Self`] instance describing the given error.
+    pub fn new(err: &(impl fmt::Display + ErrorCode)) -> Self {
+        Self {
+            code: err.code(),
+            message: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome Ethereum primitives: keccak-256 hashing and recovery of
+//! addresses from secp256k1 ECDSA signatures.
+
+pub use crate::signature::InvalidSignature;
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use primitive_types::H160;
+
+/// Ethereum signature: `r`, `s` and the recovery id `v`, either `0`/`1` or `27`/`28`.
+pub type EthSignature = [u8; 65];
+
+/// Returns the keccak-256 hash of the data.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    keccak_const::Keccak256::new().update(data).finalize()
+}
+
+/// Returns the hash of the message signed with `personal_sign` (EIP-191),
+/// i.e. prefixed with `"\x19Ethereum Signed Message:\n"` and its length.
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let mut len = [0; 20];
+    let mut pos = len.len();
+    let mut n = message.len();
+
+    loop {
+        pos -= 1;
+        len[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    keccak_const::Keccak256::new()
+        .update(b"\x19Ethereum Signed Message:\n")
+        .update(&len[pos..])
+        .update(message)
+        .finalize()
+}
+
+/// Returns the address of the uncompressed public key, without its `0x04` prefix.
+pub fn address(public_key: &[u8; 64]) -> H160 {
+    H160::from_slice(&keccak256(public_key)[12..])
+}
+
+/// Recovers the address which signed the hash.
+///
+/// Signatures with high `s` are rejected, as Ethereum does since Homestead.
+pub fn recover(hash: &[u8; 32], signature: &EthSignature) -> Result<H160, InvalidSignature> {
+    let v = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return Err(InvalidSignature),
+    };
+
+    let signature = Signature::from_slice(&signature[..64]).map_err(|_| InvalidSignature)?;
+
+    if signature.normalize_s().is_some() {
+        return Err(InvalidSignature);
+    }
+
+    let recovery_id = RecoveryId::from_byte(v).ok_or(InvalidSignature)?;
+
+    let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+        .map_err(|_| InvalidSignature)?;
+
+    let point = key.to_encoded_point(false);
+    let public_key = point.as_bytes()[1..]
+        .try_into()
+        .map_err(|_| InvalidSignature)?;
+
+    Ok(address(public_key))
+}
+
+/// Recovers the address which signed the message with `personal_sign` (EIP-191).
+pub fn recover_message(message: &[u8], signature: &EthSignature) -> Result<H160, InvalidSignature> {
+    recover(&message_hash(message), signature)
+}
@@ -0,0 +1,109 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome keeper incentives primitive.
+
+use crate::math::OverflowError;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Asset a [`Bounty`] is paid in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub enum RewardAsset {
+    /// Native value, e.g. attached to the reply.
+    #[default]
+    Native,
+    /// VFTs, transferred from the program account.
+    Vft,
+}
+
+/// Reward paid to accounts performing maintenance work of a service,
+/// e.g. executing due tasks or removing expired entries.
+///
+/// Payouts are proportional to the units of work done and capped per call, per block
+/// and by the funded budget, so keepers can't drain the bounty by splitting or
+/// repeating their calls. Bounty with zero reward (the default) pays nothing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct Bounty {
+    pub asset: RewardAsset,
+    pub reward_per_unit: u128,
+    pub max_units_per_call: u32,
+    pub max_payout_per_block: u128,
+    /// Amount left to be paid out.
+    pub budget: u128,
+    /// Block of the last payout.
+    pub paid_at: u32,
+    /// Amount paid out within `paid_at` block.
+    pub paid_in_block: u128,
+}
+
+impl Bounty {
+    pub fn new(
+        asset: RewardAsset,
+        reward_per_unit: u128,
+        max_units_per_call: u32,
+        max_payout_per_block: u128,
+    ) -> Self {
+        Self {
+            asset,
+            reward_per_unit,
+            max_units_per_call,
+            max_payout_per_block,
+            ..Default::default()
+        }
+    }
+
+    /// Replaces parameters of the bounty, keeping its budget and payouts.
+    pub fn reconfigure(&mut self, params: Bounty) {
+        *self = Self {
+            budget: self.budget,
+            paid_at: self.paid_at,
+            paid_in_block: self.paid_in_block,
+            ..params
+        };
+    }
+
+    /// Increases the budget by `amount`.
+    pub fn fund(&mut self, amount: u128) -> Result<(), OverflowError> {
+        self.budget = self.budget.checked_add(amount).ok_or(OverflowError)?;
+
+        Ok(())
+    }
+
+    /// Accounts `units` of work done at the given block, returning the amount to pay.
+    pub fn claim(&mut self, units: u32, current_bn: u32) -> u128 {
+        if self.paid_at != current_bn {
+            self.paid_at = current_bn;
+            self.paid_in_block = 0;
+        }
+
+        let payout = u128::from(units.min(self.max_units_per_call))
+            .saturating_mul(self.reward_per_unit)
+            .min(self.max_payout_per_block.saturating_sub(self.paid_in_block))
+            .min(self.budget);
+
+        self.budget -= payout;
+        self.paid_in_block += payout;
+
+        payout
+    }
+}
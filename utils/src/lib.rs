@@ -20,9 +20,24 @@
 
 extern crate alloc;
 
+pub mod audit;
+pub mod checkpoint;
+pub mod commit_reveal;
 pub mod error;
+#[cfg(feature = "eth")]
+pub mod eth;
+pub mod keeper;
 pub mod macros;
 pub mod map;
 pub mod math;
+pub mod merkle;
+pub mod multicall;
+pub mod page;
 pub mod pause;
+pub mod period;
+pub mod rate_limit;
+#[cfg(feature = "gprimitives")]
+pub mod reply;
+pub mod signature;
 pub mod storage;
+pub mod typed_data;
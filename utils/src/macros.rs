@@ -64,6 +64,22 @@ macro_rules! ok_if {
     };
 }
 
+/// Implements [`ErrorCode`](crate::error::ErrorCode) returning a constant code.
+///
+/// Usage: `impl_error_code!(FirstError => 1, SecondError => 2);`
+#[macro_export]
+macro_rules! impl_error_code {
+    ($($ty: ty => $code: literal),* $(,)?) => {
+        $(
+            impl $crate::error::ErrorCode for $ty {
+                fn code(&self) -> u16 {
+                    $code
+                }
+            }
+        )*
+    };
+}
+
 #[macro_export]
 macro_rules! unwrap_infallible {
     ($res: expr) => {
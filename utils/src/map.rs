@@ -18,10 +18,14 @@
 
 //! Awesome ShardedMap module.
 
-use crate::ensure;
-use alloc::vec::Vec;
-use core::{hash::Hash, mem};
-use hashbrown::HashMap;
+use crate::{ensure, error::ErrorCode};
+use alloc::{vec, vec::Vec};
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    mem,
+};
+use hashbrown::{HashMap, hash_map::DefaultHashBuilder};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
@@ -29,15 +33,36 @@ use scale_info::TypeInfo;
 /// resulting capacity isn't that much restricted by the HashMap impl.
 ///
 /// Useful for optimal filling of limited storage space.
-pub struct ShardedMap<K, V> {
+///
+/// Shards may be given bloom filters of their keys (see [`Self::set_filters`]),
+/// so lookups skip shards not holding the key instead of probing each of them.
+///
+/// Shards keys are kept in are picked by the [`Routing`] strategy: filled in
+/// order by default, or picked by hashes of keys, see [`Hashed`].
+pub struct ShardedMap<K, V, R = Sequential> {
     shards: Vec<(HashMap<K, V>, usize)>,
+    filters: Option<Filters>,
+    version: u64,
+    layout: u64,
+    _routing: PhantomData<R>,
 }
 
 impl<K, V> ShardedMap<K, V> {
-    /// Creates new sharded map with given capacities for underlying shards.
+    /// Creates new sharded map with given capacities for underlying shards,
+    /// filling shards in order.
     ///
     /// Capacities must be [0b1] (1), [0b11] (3) or [0b111] (7) with any amount of trailing zeroes (14, 28, 56 ...).
-    pub fn try_new(mut capacities: Vec<usize>) -> Result<Self, ShardedMapError> {
+    pub fn try_new(capacities: Vec<usize>) -> Result<Self, ShardedMapError> {
+        Self::try_new_routed(capacities)
+    }
+}
+
+impl<K, V, R: Routing> ShardedMap<K, V, R> {
+    /// Creates new sharded map with given capacities for underlying shards,
+    /// picked for keys by the routing strategy `R`.
+    ///
+    /// Capacities are restricted as by [`ShardedMap::try_new`].
+    pub fn try_new_routed(mut capacities: Vec<usize>) -> Result<Self, ShardedMapError> {
         ensure!(
             capacities.iter().all(|&c| Self::is_valid_capacity(c)),
             ShardedMapError::InvalidCapacity
@@ -51,7 +76,13 @@ impl<K, V> ShardedMap<K, V> {
             .map(|c| (HashMap::new(), c))
             .collect();
 
-        Ok(Self { shards })
+        Ok(Self {
+            shards,
+            filters: None,
+            version: 0,
+            layout: 0,
+            _routing: PhantomData,
+        })
     }
 
     /// Returns currently allocated capacity of the map.
@@ -82,6 +113,49 @@ impl<K, V> ShardedMap<K, V> {
             .sum()
     }
 
+    /// Returns version of the map's entries, changed by every mutable access to them.
+    ///
+    /// Lets computations over the map resumed across messages, e.g. hashing
+    /// its entries in chunks, detect that the map changed in between.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns version of positions of the map's entries, changed only once
+    /// entries move between cursors, i.e. shards are cleared or removed.
+    ///
+    /// Lets computations over the map resumed across messages keep going
+    /// while entries are changed in place, see [`Self::cursor_of`].
+    pub fn layout(&self) -> u64 {
+        self.layout
+    }
+
+    /// Returns metrics of the map, e.g. for operators to plan shard appends.
+    pub fn metrics(&self) -> MapMetrics {
+        let filters_bytes = self.filters.as_ref().map_or(0, |filters| {
+            filters.shards.iter().map(|bits| bits.len() * 8).sum()
+        });
+
+        let tables_bytes: usize = self
+            .shards
+            .iter()
+            .map(|(map, _)| map.raw_table().allocation_info().1.size())
+            .sum();
+
+        MapMetrics {
+            shards: self.shards.len() as u32,
+            allocated_shards: self
+                .shards
+                .iter()
+                .filter(|(map, _)| map.capacity() > 0)
+                .count() as u32,
+            entries: self.len() as u32,
+            capacity: self.capacity() as u32,
+            max_capacity: self.max_capacity() as u32,
+            heap_bytes: (tables_bytes + filters_bytes) as u64,
+        }
+    }
+
     /// Returns bool indicating if the map has free space.
     pub fn has_space(&self) -> bool {
         self.shards
@@ -101,8 +175,43 @@ impl<K, V> ShardedMap<K, V> {
         self.shards.iter().flat_map(|(map, _)| map.iter())
     }
 
+    /// Returns iterator over key-value pairs in the map starting from the cursor,
+    /// each paired with the cursor pointing right after it.
+    ///
+    /// Unlike skipping pairs of [`Self::iter`], resuming from the cursor of
+    /// the last pair costs nothing, so deep pagination stays proportional to
+    /// the page size (and free buckets of the shard scanned on the way).
+    pub fn iter_from(&self, cursor: MapCursor) -> impl Iterator<Item = (MapCursor, &K, &V)> {
+        self.shards
+            .iter()
+            .enumerate()
+            .skip(cursor.shard as usize)
+            .flat_map(move |(shard, (map, _))| {
+                let table = map.raw_table();
+
+                let start = if shard == cursor.shard as usize {
+                    cursor.bucket as usize
+                } else {
+                    0
+                };
+
+                (start..table.buckets()).filter_map(move |bucket| {
+                    // SAFETY: bucket is in bounds and checked to be full before accessed.
+                    unsafe {
+                        table.is_bucket_full(bucket).then(|| {
+                            let (key, value) = table.bucket(bucket).as_ref();
+
+                            (MapCursor::new(shard, bucket + 1), key, value)
+                        })
+                    }
+                })
+            })
+    }
+
     /// Returns mutable iterator over all key-value pairs in the map.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.touch();
+
         self.shards.iter_mut().flat_map(|(map, _)| map.iter_mut())
     }
 
@@ -121,18 +230,32 @@ impl<K, V> ShardedMap<K, V> {
                 })
             });
 
+        if let (Some(i), Some(filters)) = (idx, &mut self.filters) {
+            filters.shards[i] = filters.empty(self.shards[i].0.capacity());
+        }
+
         idx.is_some_and(|i| i != self.shards.len() - 1)
     }
 
     /// Clears all shards in the map.
     pub fn clear_shards(&mut self) {
+        self.touch();
+        self.layout = self.layout.wrapping_add(1);
+
         self.shards.iter_mut().for_each(|(map, _)| map.clear());
+
+        if let Some(filters) = &mut self.filters {
+            filters.shards.iter_mut().for_each(|bits| bits.fill(0));
+        }
     }
 
     /// Tries to appends a new shard to the map.
     ///
     /// It will require upcoming allocation (see [`Self::alloc_next_shard`]).
+    ///
+    /// Shards of routed maps are fixed, as appending them would reroute keys.
     pub fn try_append_shard(&mut self, capacity: usize) -> Result<(), ShardedMapError> {
+        ensure!(!R::ROUTED, ShardedMapError::Misrouted);
         ensure!(
             Self::is_valid_capacity(capacity),
             ShardedMapError::InvalidCapacity
@@ -140,28 +263,63 @@ impl<K, V> ShardedMap<K, V> {
 
         self.shards.push((HashMap::new(), capacity));
 
+        if let Some(filters) = &mut self.filters {
+            filters.shards.push(Vec::new());
+        }
+
         Ok(())
     }
 
-    /// Helper function to `find_map` shards.
-    fn find_map<'a, T: 'a, F>(&'a self, f: F) -> Option<(ShardIdx, T)>
+    /// Tries to remove the last shard holding no entries, freeing its memory.
+    ///
+    /// Returns the max capacity of the removed shard, e.g. for operators
+    /// to append it to another map. Cursors past the shard are shifted.
+    ///
+    /// Shards of routed maps are fixed, as removing them would reroute keys.
+    pub fn try_remove_empty_shard(&mut self) -> Result<usize, ShardedMapError> {
+        ensure!(!R::ROUTED, ShardedMapError::Misrouted);
+
+        let idx = self
+            .shards
+            .iter()
+            .rposition(|(map, _)| map.is_empty())
+            .ok_or(ShardedMapError::NoEmptyShard)?;
+
+        self.touch();
+        self.layout = self.layout.wrapping_add(1);
+
+        if let Some(filters) = &mut self.filters {
+            filters.shards.remove(idx);
+        }
+
+        Ok(self.shards.remove(idx).1)
+    }
+
+    /// Helper function to `find_map` shards, which may hold the key looked up.
+    fn find_map<'a, T: 'a, F>(&'a self, lookup: Lookup, f: F) -> Option<(ShardIdx, T)>
     where
         F: Fn((usize, &'a HashMap<K, V>)) -> Option<T>,
     {
+        let filters = self.filters.as_ref();
+
         self.shards
             .iter()
             .enumerate()
+            .filter(|&(idx, _)| lookup.may_hold(filters, idx))
             .find_map(|(idx, (map, _))| f((idx, map)).map(|v| (ShardIdx(idx), v)))
     }
 
-    /// Helper function to `find_map` shards mutably.
-    fn find_map_mut<'a, T: 'a, F>(&'a mut self, mut f: F) -> Option<(ShardIdx, T)>
+    /// Helper function to `find_map` shards mutably, which may hold the key looked up.
+    fn find_map_mut<'a, T: 'a, F>(&'a mut self, lookup: Lookup, mut f: F) -> Option<(ShardIdx, T)>
     where
         F: FnMut((usize, &'a mut HashMap<K, V>)) -> Option<T>,
     {
+        let filters = self.filters.as_ref();
+
         self.shards
             .iter_mut()
             .enumerate()
+            .filter(|(idx, _)| lookup.may_hold(filters, *idx))
             .find_map(|(idx, (map, _))| f((idx, map)).map(|v| (ShardIdx(idx), v)))
     }
 
@@ -181,13 +339,62 @@ impl<K, V> ShardedMap<K, V> {
 
         shift_for == 0 || n.trailing_ones() >= 3
     }
+
+    /// Helper function to change the version on mutable access to entries.
+    fn touch(&mut self) {
+        self.version = self.version.wrapping_add(1);
+    }
 }
 
-impl<K: Eq + Hash, V> ShardedMap<K, V> {
+impl<K: Eq + Hash, V, R: Routing> ShardedMap<K, V, R> {
+    /// Sets bloom filters of keys of each shard, sized `bits_per_key` per
+    /// element of its capacity, rebuilding them from the current keys.
+    ///
+    /// Removed keys are kept by filters until rebuilt, only costing lookups
+    /// extra probes. Zero `bits_per_key` disables filters.
+    pub fn set_filters(&mut self, bits_per_key: usize) {
+        if bits_per_key == 0 {
+            self.filters = None;
+            return;
+        }
+
+        let mut filters = Filters {
+            bits_per_key,
+            shards: Vec::with_capacity(self.shards.len()),
+        };
+
+        for (map, _) in &self.shards {
+            let mut bits = filters.empty(map.capacity());
+
+            for key in map.keys() {
+                Filters::insert(&mut bits, hash_of(key));
+            }
+
+            filters.shards.push(bits);
+        }
+
+        self.filters = Some(filters);
+    }
+
     /// Returns a reference to the value under the given key with its shard index.
     pub fn get(&self, key: &K) -> Option<(ShardIdx, &V)> {
-        self.find_map(|(_, map)| map.get(key))
+        self.find_map(self.lookup(key), |(_, map)| map.get(key))
+    }
+    /// Returns the position of the key, i.e. the cursor [`Self::iter_from`]
+    /// visits it from, kept until the [layout](Self::layout) changes.
+    pub fn cursor_of(&self, key: &K) -> Option<MapCursor> {
+        let (idx, _) = self.get(key)?;
+        let map = &self.shards[idx.0].0;
+        let table = map.raw_table();
+
+        let bucket = table.find(map.hasher().hash_one(key), |(k, _)| k == key)?;
+
+        // SAFETY: the bucket is found in the table.
+        let bucket = unsafe { table.bucket_index(&bucket) };
+
+        Some(MapCursor::new(idx.0, bucket))
     }
+
     /// Returns a reference to the value under the given key at specific shard.
     pub fn get_at(&self, idx: ShardIdx, key: &K) -> Option<&V> {
         self.shards[idx.0].0.get(key)
@@ -195,21 +402,29 @@ impl<K: Eq + Hash, V> ShardedMap<K, V> {
 
     /// Returns a mut reference to the value under the given key with its shard index.
     pub fn get_mut(&mut self, key: &K) -> Option<(ShardIdx, &mut V)> {
-        self.find_map_mut(|(_, map)| map.get_mut(key))
+        self.touch();
+
+        self.find_map_mut(self.lookup(key), |(_, map)| map.get_mut(key))
     }
 
     /// Returns a mut reference to the value under the given key at specific shard.
     pub fn get_mut_at(&mut self, idx: ShardIdx, key: &K) -> Option<&mut V> {
+        self.touch();
+
         self.shards[idx.0].0.get_mut(key)
     }
 
     /// Removes the value under the given key, returning it with its shard index.
     pub fn remove(&mut self, key: &K) -> Option<(ShardIdx, V)> {
-        self.find_map_mut(|(_, map)| map.remove(key))
+        self.touch();
+
+        self.find_map_mut(self.lookup(key), |(_, map)| map.remove(key))
     }
 
     /// Removes the value under the given key at given shard index, returning it.
     pub fn remove_at(&mut self, idx: ShardIdx, key: &K) -> Option<V> {
+        self.touch();
+
         self.shards[idx.0].0.remove(key)
     }
 
@@ -219,21 +434,17 @@ impl<K: Eq + Hash, V> ShardedMap<K, V> {
         key: K,
         value: V,
     ) -> Result<(ShardIdx, Option<V>), ShardedMapError> {
-        let mut available_map = None;
+        self.touch();
 
-        if let Some((idx, prev_value_mut)) = self.find_map_mut(|(idx, map)| {
-            if available_map.is_none() && map.len() < map.capacity() {
-                available_map = Some(idx);
-            };
+        let lookup = self.lookup(&key);
 
-            map.get_mut(&key)
-        }) {
+        if let Some((idx, prev_value_mut)) = self.find_map_mut(lookup, |(_, map)| map.get_mut(&key))
+        {
             return Ok((idx, Some(mem::replace(prev_value_mut, value))));
         };
 
-        available_map
-            .map(|idx| (ShardIdx(idx), self.shards[idx].0.insert(key, value)))
-            .ok_or(ShardedMapError::CapacityOverflow)
+        // SAFETY: the key is checked to not exist in any shard above.
+        unsafe { self.try_insert_new(key, value) }.map(|idx| (idx, None))
     }
 
     /// Tries to insert a new key-value pair with guarantee that
@@ -242,17 +453,27 @@ impl<K: Eq + Hash, V> ShardedMap<K, V> {
     /// # Safety
     /// The caller must ensure that the key does not exist in any shard.
     pub unsafe fn try_insert_new(&mut self, key: K, value: V) -> Result<ShardIdx, ShardedMapError> {
-        self.find_map_mut(|(_, map)| (map.len() < map.capacity()).then_some(map))
-            .map(|(idx, map)| {
-                map.insert(key, value);
-                idx
+        let route = self.route(&key);
+
+        let idx = self
+            .shards
+            .iter()
+            .enumerate()
+            .position(|(idx, (map, _))| {
+                route.is_none_or(|route| route == idx) && map.len() < map.capacity()
             })
-            .ok_or(ShardedMapError::CapacityOverflow)
+            .ok_or(ShardedMapError::CapacityOverflow)?;
+
+        self.insert_at(idx, key, value);
+
+        Ok(ShardIdx(idx))
     }
 
     /// Tries to insert a new key-value pair at specific shard with guarantee
     /// that the key does not exist in any shard yet.
     ///
+    /// Keys of routed maps may only be inserted at shards they're routed to.
+    ///
     /// # Safety
     /// The caller must ensure that the key does not exist in any shard.
     pub unsafe fn try_insert_new_at(
@@ -261,23 +482,206 @@ impl<K: Eq + Hash, V> ShardedMap<K, V> {
         key: K,
         value: V,
     ) -> Result<(), ShardedMapError> {
-        let map = &mut self.shards[idx.0].0;
+        ensure!(
+            self.route(&key).is_none_or(|route| route == idx.0),
+            ShardedMapError::Misrouted
+        );
+
+        let map = &self.shards[idx.0].0;
 
         ensure!(
             map.len() < map.capacity(),
             ShardedMapError::CapacityOverflow
         );
 
-        map.insert(key, value);
+        self.insert_at(idx.0, key, value);
 
         Ok(())
     }
+
+    /// Helper function to insert the pair into the shard, adding the key to its filter.
+    fn insert_at(&mut self, idx: usize, key: K, value: V) {
+        self.touch();
+
+        if let Some(filters) = &mut self.filters {
+            Filters::insert(&mut filters.shards[idx], hash_of(&key));
+        }
+
+        self.shards[idx].0.insert(key, value);
+    }
+
+    /// Helper function to get the shard the key is routed to, if routed.
+    fn route(&self, key: &K) -> Option<usize> {
+        R::ROUTED.then(|| R::route(hash_of(key), self.shards.iter().map(|(_, c)| *c)))
+    }
+
+    /// Helper function to prepare the lookup of the key.
+    fn lookup(&self, key: &K) -> Lookup {
+        Lookup {
+            route: self.route(key),
+            hash: self.filters.as_ref().map(|_| hash_of(key)),
+        }
+    }
+}
+
+/// Strategy of picking shards keys are kept in.
+pub trait Routing {
+    /// Whether keys are routed to shards, only kept in and looked up at them.
+    ///
+    /// Otherwise keys are kept in any shard, filling shards in order.
+    const ROUTED: bool;
+
+    /// Returns index of the shard the key of given hash is routed to,
+    /// given max capacities of shards.
+    fn route(hash: u64, capacities: impl Iterator<Item = usize> + Clone) -> usize;
+}
+
+/// Keys are kept in any shard, filling shards in order, and looked up
+/// at each of them in turn.
+///
+/// Keeps storage filled densely, e.g. for append-heavy genesis loads.
+pub struct Sequential;
+
+impl Routing for Sequential {
+    const ROUTED: bool = false;
+
+    fn route(_hash: u64, _capacities: impl Iterator<Item = usize> + Clone) -> usize {
+        0
+    }
+}
+
+/// Keys are routed to shards by their hashes, in proportion to capacities
+/// of shards, so lookups touch a single shard.
+///
+/// Suits random keys, while inserts fail once the routed shard is full,
+/// however much space other shards have. Shards can't be appended.
+pub struct Hashed;
+
+impl Routing for Hashed {
+    const ROUTED: bool = true;
+
+    fn route(hash: u64, capacities: impl Iterator<Item = usize> + Clone) -> usize {
+        let total = capacities.clone().map(|c| c as u64).sum::<u64>().max(1);
+        let mut point = hash % total;
+
+        capacities
+            .map(|c| c as u64)
+            .position(|capacity| match point.checked_sub(capacity) {
+                Some(rest) => {
+                    point = rest;
+                    false
+                }
+                None => true,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Shards the key may be held by.
+#[derive(Clone, Copy)]
+struct Lookup {
+    route: Option<usize>,
+    hash: Option<u64>,
+}
+
+impl Lookup {
+    /// Checks if the shard may hold the key.
+    fn may_hold(self, filters: Option<&Filters>, idx: usize) -> bool {
+        self.route.is_none_or(|route| route == idx)
+            && match (filters, self.hash) {
+                (Some(filters), Some(hash)) => Filters::contains(&filters.shards[idx], hash),
+                _ => true,
+            }
+    }
+}
+
+/// Returns the hash of the key, shards and bits of filters are picked by.
+fn hash_of<K: Hash>(key: &K) -> u64 {
+    DefaultHashBuilder::default().hash_one(key)
+}
+
+/// Bloom filters of keys of each shard.
+struct Filters {
+    bits_per_key: usize,
+    shards: Vec<Vec<u64>>,
+}
+
+impl Filters {
+    /// Amount of bits set per key.
+    const HASHES: u64 = 3;
+
+    /// Returns empty filter for the shard of given capacity.
+    fn empty(&self, capacity: usize) -> Vec<u64> {
+        vec![0; capacity.saturating_mul(self.bits_per_key).div_ceil(64)]
+    }
+
+    /// Returns positions of bits of the hash in the filter of given length.
+    fn positions(len: usize, hash: u64) -> impl Iterator<Item = usize> {
+        let bits = (len as u64) * 64;
+        let (h1, h2) = (hash & u64::from(u32::MAX), (hash >> 32) | 1);
+
+        (0..Self::HASHES).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bits) as usize)
+    }
+
+    fn insert(bits: &mut [u64], hash: u64) {
+        for pos in Self::positions(bits.len(), hash) {
+            bits[pos / 64] |= 1 << (pos % 64);
+        }
+    }
+
+    fn contains(bits: &[u64], hash: u64) -> bool {
+        !bits.is_empty()
+            && Self::positions(bits.len(), hash).all(|pos| bits[pos / 64] & (1 << (pos % 64)) != 0)
+    }
+}
+
+/// Metrics of the sharded map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct MapMetrics {
+    /// Amount of shards, allocated or not.
+    pub shards: u32,
+    /// Amount of allocated shards.
+    pub allocated_shards: u32,
+    /// Amount of entries in the map.
+    pub entries: u32,
+    /// Amount of entries allocated shards may hold.
+    pub capacity: u32,
+    /// Amount of entries all shards may hold, once allocated.
+    pub max_capacity: u32,
+    /// Estimated heap bytes of allocated shards and their filters.
+    pub heap_bytes: u64,
+}
+
+/// Position in the sharded map to resume iteration from.
+///
+/// Default cursor points to the start of the map. Pairs keep their positions
+/// unless the shard gets rehashed, reclaiming space of removed pairs, so pairs
+/// inserted or removed between pages may be missed, as they're by skipping.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct MapCursor {
+    /// Index of the shard.
+    pub shard: u32,
+    /// Index of the bucket in the shard.
+    pub bucket: u32,
+}
+
+impl MapCursor {
+    fn new(shard: usize, bucket: usize) -> Self {
+        Self {
+            shard: shard as u32,
+            bucket: bucket as u32,
+        }
+    }
 }
 
 /// Shard index type.
 ///
 /// Used to identify a shard in the sharded map.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct ShardIdx(usize);
 
 impl ShardIdx {
@@ -301,4 +705,19 @@ pub enum ShardedMapError {
     CapacityOverflow,
     #[error("invalid capacity")]
     InvalidCapacity,
+    #[error("shard mismatches routing")]
+    Misrouted,
+    #[error("no empty shard")]
+    NoEmptyShard,
+}
+
+impl ErrorCode for ShardedMapError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::CapacityOverflow => 30,
+            Self::InvalidCapacity => 31,
+            Self::Misrouted => 32,
+            Self::NoEmptyShard => 33,
+        }
+    }
 }
@@ -16,12 +16,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::error::ErrorCode;
 use alloc::vec;
 use bnum::BUintD8;
 use core::cmp::Ordering;
 use derive_more::Deref;
 use parity_scale_codec::{Decode, Encode};
-use scale_info::{Path, Type, TypeInfo, build::Fields};
+use scale_info::{Type, TypeInfo};
 
 pub use primitive_types::{H160, H256, U256};
 
@@ -350,13 +351,11 @@ impl<const N: usize> Decode for LeBytes<N> {
     }
 }
 
+// Described as the fixed-size array of bytes it's encoded as.
 impl<const N: usize> TypeInfo for LeBytes<N> {
-    type Identity = Self;
+    type Identity = [u8; N];
     fn type_info() -> Type {
-        Type::builder()
-            .path(Path::new("LeBytes", module_path!()))
-            .type_params(vec::Vec::new())
-            .composite(Fields::unnamed().field(|f| f.ty::<[u8]>()))
+        <[u8; N]>::type_info()
     }
 }
 
@@ -625,3 +624,15 @@ pub struct UnderflowError;
 #[error("zero error")]
 #[scale_info(crate = scale_info)]
 pub struct ZeroError;
+
+crate::impl_error_code!(OverflowError => 10, UnderflowError => 11, ZeroError => 12);
+
+impl ErrorCode for MathError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::Overflow(err) => err.code(),
+            Self::Underflow(err) => err.code(),
+            Self::Zero(err) => err.code(),
+        }
+    }
+}
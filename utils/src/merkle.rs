@@ -0,0 +1,195 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome merkle trees module.
+//!
+//! Trees commit to lists of leaves, e.g. of holders for airdrops or snapshots,
+//! so a leaf is proven to be listed by the root and a few hashes, its proof:
+//!
+//! - leaves are hashes of `0x00 ++ data`, so they're never confused with nodes;
+//! - nodes are hashes of `0x01 ++ min(left, right) ++ max(left, right)`, so proofs
+//!   are lists of siblings without their sides;
+//! - the last node of odd levels is promoted to the next level as is;
+//! - root of no leaves is zero.
+//!
+//! Trees are hashed by keccak-256 (see [`Keccak256`]), e.g. for proofs verified
+//! by Ethereum contracts as well, or by blake2b-256 with the `blake2` feature.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// Hash function of the tree.
+pub trait Hasher {
+    /// Returns the hash of the concatenation of the data.
+    fn hash(data: &[&[u8]]) -> [u8; 32];
+}
+
+/// Keccak-256 hasher.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Keccak256;
+
+impl Hasher for Keccak256 {
+    fn hash(data: &[&[u8]]) -> [u8; 32] {
+        data.iter()
+            .fold(keccak_const::Keccak256::new(), |hasher, data| {
+                hasher.update(data)
+            })
+            .finalize()
+    }
+}
+
+/// Blake2b-256 hasher, as hashes of Gear are.
+#[cfg(feature = "blake2")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Blake2b256;
+
+#[cfg(feature = "blake2")]
+impl Hasher for Blake2b256 {
+    fn hash(data: &[&[u8]]) -> [u8; 32] {
+        use blake2::{Blake2b, Digest, digest::consts::U32};
+
+        data.iter()
+            .fold(Blake2b::<U32>::new(), |hasher, data| {
+                hasher.chain_update(data)
+            })
+            .finalize()
+            .into()
+    }
+}
+
+/// Returns the hash of the leaf of the data.
+pub fn leaf<H: Hasher>(data: &[u8]) -> [u8; 32] {
+    H::hash(&[&[0x00], data])
+}
+
+/// Returns the hash of the node of the siblings, regardless of their order.
+pub fn node<H: Hasher>(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (min, max) = if a <= b { (a, b) } else { (b, a) };
+
+    H::hash(&[&[0x01], &min, &max])
+}
+
+/// Returns the root of the tree of the leaves.
+pub fn root<H: Hasher>(leaves: impl IntoIterator<Item = [u8; 32]>) -> [u8; 32] {
+    let mut builder = MerkleBuilder::<H>::default();
+
+    leaves.into_iter().for_each(|leaf| builder.push(leaf));
+
+    builder.root()
+}
+
+/// Returns the proof of the leaf at the index, or `None` if it's out of bounds.
+pub fn proof<H: Hasher>(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut level = leaves.to_vec();
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        if let Some(&sibling) = level.get(index ^ 1) {
+            proof.push(sibling);
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match *pair {
+                [left, right] => node::<H>(left, right),
+                [left] => left,
+                _ => unreachable!("chunks are non-empty"),
+            })
+            .collect();
+
+        index /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Returns `true` if the proof proves the leaf to be listed by the root.
+pub fn verify<H: Hasher>(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |hash, &sibling| node::<H>(hash, sibling))
+        == root
+}
+
+/// Builder of the root of the tree, taking leaves one by one.
+///
+/// Keeps a node per level awaiting its sibling only, so the root of the list
+/// of any length is built in chunks, e.g. across messages.
+#[derive(Clone, Debug)]
+pub struct MerkleBuilder<H> {
+    len: u32,
+    /// Left nodes awaiting their siblings, per level of the tree.
+    frontier: Vec<Option<[u8; 32]>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H> Default for MerkleBuilder<H> {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            frontier: Vec::new(),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<H: Hasher> MerkleBuilder<H> {
+    /// Returns amount of leaves pushed.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns bool indicating if no leaves were pushed.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Pushes the leaf, hashing nodes completed by it.
+    pub fn push(&mut self, leaf: [u8; 32]) {
+        let mut hash = leaf;
+
+        self.len += 1;
+
+        for left in self.frontier.iter_mut() {
+            match left.take() {
+                Some(left) => hash = node::<H>(left, hash),
+                None => {
+                    *left = Some(hash);
+                    return;
+                }
+            }
+        }
+
+        self.frontier.push(Some(hash));
+    }
+
+    /// Returns the root of the tree of leaves pushed.
+    pub fn root(&self) -> [u8; 32] {
+        self.frontier
+            .iter()
+            .fold(None, |carry, &left| match (left, carry) {
+                (Some(left), Some(right)) => Some(node::<H>(left, right)),
+                (left, carry) => left.or(carry),
+            })
+            .unwrap_or_default()
+    }
+}
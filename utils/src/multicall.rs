@@ -0,0 +1,40 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome multicall module.
+//!
+//! Helpers for answering many read-only queries within a single message.
+
+use crate::{ensure, error::BadInput};
+use alloc::vec::Vec;
+
+/// Upper bound on the number of queries answered in one batch, keeping replies bounded.
+pub const MAX_BATCH_LEN: usize = 1000;
+
+/// Answers each of `queries` with `f`, preserving their order.
+///
+/// Fails as a whole on the first failing query, or with [`BadInput`] if there are
+/// more than [`MAX_BATCH_LEN`] queries.
+pub fn multicall<Q, T, E: From<BadInput>>(
+    queries: Vec<Q>,
+    f: impl FnMut(Q) -> Result<T, E>,
+) -> Result<Vec<T>, E> {
+    ensure!(queries.len() <= MAX_BATCH_LEN, BadInput);
+
+    queries.into_iter().map(f).collect()
+}
@@ -0,0 +1,118 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome pagination module.
+
+use crate::ensure;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use parity_scale_codec::{Compact, Decode, Encode, EncodeLike, Output};
+use scale_info::{Type, TypeInfo};
+
+/// Default max amount of items replied by an enumeration export at once.
+pub const DEFAULT_MAX_PAGE_SIZE: u32 = 1000;
+
+/// Ensures that a page of `len` items doesn't exceed `max_page_size`.
+pub fn ensure_page_size(len: u32, max_page_size: u32) -> Result<(), PageTooLarge> {
+    ensure!(len <= max_page_size, PageTooLarge { max: max_page_size });
+
+    Ok(())
+}
+
+/// Page of items encoded as `Vec<T>`, while collected.
+///
+/// Items are encoded into the buffer of the page as they're visited, e.g.
+/// entries of the sharded map, so enumeration exports reply without cloning
+/// entries into the `Vec` encoded afterwards. Collecting into the page is
+/// a drop-in replacement for collecting into `Vec<T>`.
+pub struct Page<T> {
+    len: u32,
+    buf: Vec<u8>,
+    _items: PhantomData<T>,
+}
+
+impl<T: Encode> Page<T> {
+    /// Returns amount of items in the page.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns bool indicating if the page is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Encodes the item into the page.
+    pub fn push(&mut self, item: impl EncodeLike<T>) {
+        item.encode_to(&mut self.buf);
+        self.len += 1;
+    }
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            buf: Vec::new(),
+            _items: PhantomData,
+        }
+    }
+}
+
+impl<T: Encode> FromIterator<T> for Page<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut page = Self::default();
+
+        iter.into_iter().for_each(|item| page.push(&item));
+
+        page
+    }
+}
+
+impl<T> Encode for Page<T> {
+    fn size_hint(&self) -> usize {
+        Compact(self.len).size_hint() + self.buf.len()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        Compact(self.len).encode_to(dest);
+        dest.write(&self.buf);
+    }
+}
+
+impl<T: Encode> EncodeLike<Vec<T>> for Page<T> {}
+
+// Described as the `Vec<T>` it's encoded as.
+impl<T: TypeInfo + 'static> TypeInfo for Page<T> {
+    type Identity = Vec<T>;
+
+    fn type_info() -> Type {
+        Vec::<T>::type_info()
+    }
+}
+
+/// Error type for requested page exceeding the max page size.
+#[derive(Clone, Debug, Decode, Encode, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[error("page too large: at most {max} items allowed")]
+#[scale_info(crate = scale_info)]
+pub struct PageTooLarge {
+    pub max: u32,
+}
+
+crate::impl_error_code!(PageTooLarge => 70);
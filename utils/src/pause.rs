@@ -20,10 +20,12 @@
 
 use crate::{
     ensure,
+    error::ErrorCode,
     storage::{InfallibleStorage, Storage, StorageMut, StorageRefCell},
 };
+use alloc::{collections::BTreeMap, string::String};
 use core::{
-    cell::Cell,
+    cell::{Cell, RefCell},
     error,
     ops::{Deref, DerefMut},
 };
@@ -126,37 +128,164 @@ where
     }
 }
 
+#[cfg(feature = "gprimitives")]
+pub use info::{PauseInfo, PauseReason};
+
 /// Struct representing a pause switch.
 ///
 /// This struct is used to create a pausable storage instance.
 #[derive(Debug, Default, PartialEq, Clone)]
-pub struct Pause(Cell<bool>);
+pub struct Pause {
+    paused: Cell<bool>,
+    #[cfg(feature = "gprimitives")]
+    info: RefCell<Option<PauseInfo>>,
+}
 
 pub type PauseRef<'a> = &'a Pause;
 
 impl Pause {
     /// Creates a new `Pause` instance.
     pub fn new(paused: bool) -> Self {
-        Self(Cell::new(paused))
+        Self {
+            paused: Cell::new(paused),
+            #[cfg(feature = "gprimitives")]
+            info: RefCell::new(None),
+        }
     }
 
     /// Switches pause on.
     ///
     /// Returns bool indicating if state was changed.
     pub fn pause(&self) -> bool {
-        !self.0.replace(true)
+        !self.paused.replace(true)
     }
 
-    /// Switches pause off.
+    /// Switches pause off, dropping attribution of the pause.
     ///
     /// Returns bool indicating if state was changed.
     pub fn resume(&self) -> bool {
-        self.0.replace(false)
+        #[cfg(feature = "gprimitives")]
+        self.info.take();
+
+        self.paused.replace(false)
     }
 
     /// Returns bool indicating if pause is on.
     pub fn is_paused(&self) -> bool {
-        self.0.get()
+        self.paused.get()
+    }
+}
+
+#[cfg(feature = "gprimitives")]
+impl Pause {
+    /// Switches pause on, recording who paused, when and why.
+    ///
+    /// Returns bool indicating if state was changed: attribution of
+    /// the pause already on is kept.
+    pub fn pause_with(&self, info: PauseInfo) -> bool {
+        let changed = self.pause();
+
+        if changed {
+            self.info.replace(Some(info));
+        }
+
+        changed
+    }
+
+    /// Returns attribution of the pause, if it's on and was attributed.
+    pub fn info(&self) -> Option<PauseInfo> {
+        self.info.borrow().clone()
+    }
+}
+
+/// Pause switches of services composed in a program, keyed by service name,
+/// so an exploited service may be halted without freezing the others.
+///
+/// Services are registered on construction, so switches stay at fixed addresses
+/// while [`PausableRef`]s of the services borrow them.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PauseRegistry {
+    switches: BTreeMap<String, Pause>,
+}
+
+impl PauseRegistry {
+    /// Creates a new `PauseRegistry` with a switch, initially off, per service.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(services: I) -> Self {
+        Self {
+            switches: services
+                .into_iter()
+                .map(|service| (service.into(), Pause::default()))
+                .collect(),
+        }
+    }
+
+    /// Returns the pause switch of the service.
+    pub fn get(&self, service: &str) -> Result<&Pause, UnknownService> {
+        self.switches.get(service).ok_or(UnknownService)
+    }
+
+    /// Returns the storage of the service, pausable by the service's switch.
+    pub fn pausable<'a, T>(
+        &'a self,
+        service: &str,
+        cell: &'a RefCell<T>,
+    ) -> Result<PausableRef<'a, T>, UnknownService> {
+        Ok(Pausable::new(self.get(service)?, StorageRefCell::new(cell)))
+    }
+
+    /// Switches pause of the service on.
+    ///
+    /// Returns bool indicating if state was changed.
+    pub fn pause(&self, service: &str) -> Result<bool, UnknownService> {
+        self.get(service).map(Pause::pause)
+    }
+
+    /// Switches pause of the service off.
+    ///
+    /// Returns bool indicating if state was changed.
+    pub fn resume(&self, service: &str) -> Result<bool, UnknownService> {
+        self.get(service).map(Pause::resume)
+    }
+
+    /// Returns bool indicating if pause of the service is on.
+    pub fn is_paused(&self, service: &str) -> Result<bool, UnknownService> {
+        self.get(service).map(Pause::is_paused)
+    }
+
+    /// Iterates over names of the paused services.
+    pub fn paused(&self) -> impl Iterator<Item = &str> {
+        self.switches
+            .iter()
+            .filter(|(_, pause)| pause.is_paused())
+            .map(|(service, _)| service.as_str())
+    }
+}
+
+#[cfg(feature = "gprimitives")]
+mod info {
+    use alloc::string::String;
+    use gprimitives::ActorId;
+    use parity_scale_codec::{Decode, Encode};
+    use scale_info::TypeInfo;
+
+    /// Reason of a pause, shown to users whose calls fail.
+    #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+    #[codec(crate = parity_scale_codec)]
+    #[scale_info(crate = scale_info)]
+    pub enum PauseReason {
+        /// Machine-readable code, e.g. an error code.
+        Code(u16),
+        Message(String),
+    }
+
+    /// Attribution of a pause.
+    #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+    #[codec(crate = parity_scale_codec)]
+    #[scale_info(crate = scale_info)]
+    pub struct PauseInfo {
+        pub paused_by: ActorId,
+        pub paused_at: u32,
+        pub reason: Option<PauseReason>,
     }
 }
 
@@ -190,3 +319,22 @@ pub struct PausedError;
 #[error("disabled pause error")]
 #[scale_info(crate = scale_info)]
 pub struct UnpausedError;
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, TypeInfo, thiserror::Error,
+)]
+#[codec(crate = parity_scale_codec)]
+#[error("unknown service")]
+#[scale_info(crate = scale_info)]
+pub struct UnknownService;
+
+crate::impl_error_code!(PausedError => 20, UnpausedError => 21, UnknownService => 22);
+
+impl<E: error::Error + ErrorCode> ErrorCode for PausableError<E> {
+    fn code(&self) -> u16 {
+        match self {
+            Self::Paused => PausedError.code(),
+            Self::Storage(err) => err.code(),
+        }
+    }
+}
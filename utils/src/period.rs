@@ -0,0 +1,88 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome rate limiting primitive.
+//! Awesome period budget primitive.
+
+use crate::ensure;
+use parity_scale_codec::{Decode, Encode};
+use primitive_types::U256;
+use scale_info::TypeInfo;
+
+/// Amount which may be spent within a period of blocks, renewed every period,
+/// e.g. spending caps of allowances, caps of minters or budgets of treasuries.
+///
+/// Periods are aligned to the block the budget was created at, so unlike
+/// rolling windows (see the circuit breaker of VFT balances) the whole amount
+/// may be spent at the end of a period and again at the start of the next one.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct PeriodBudget {
+    pub amount_per_period: U256,
+    pub period_blocks: u32,
+    pub period_start: u32,
+    pub spent: U256,
+}
+
+impl PeriodBudget {
+    /// Creates the budget, starting its first period at the given block.
+    ///
+    /// Returns `None` if the period is empty.
+    pub fn new(amount_per_period: U256, period_blocks: u32, current_bn: u32) -> Option<Self> {
+        (period_blocks != 0).then_some(Self {
+            amount_per_period,
+            period_blocks,
+            period_start: current_bn,
+            spent: U256::zero(),
+        })
+    }
+
+    /// Amount still available to spend at the given block.
+    pub fn remaining(&self, current_bn: u32) -> U256 {
+        let mut budget = *self;
+        budget.roll_period(current_bn);
+        budget.amount_per_period.saturating_sub(budget.spent)
+    }
+
+    /// Spends `value` within the period of the given block.
+    ///
+    /// Fails with the amount still available if `value` exceeds it.
+    pub fn try_spend(&mut self, value: U256, current_bn: u32) -> Result<(), U256> {
+        self.roll_period(current_bn);
+
+        let remaining = self.amount_per_period.saturating_sub(self.spent);
+
+        ensure!(value <= remaining, remaining);
+
+        self.spent += value;
+
+        Ok(())
+    }
+
+    /// Starts a new period if the current one has elapsed,
+    /// keeping period boundaries aligned to the initial start.
+    fn roll_period(&mut self, current_bn: u32) {
+        let elapsed = current_bn.saturating_sub(self.period_start);
+
+        if elapsed >= self.period_blocks {
+            self.period_start += elapsed - elapsed % self.period_blocks;
+            self.spent = U256::zero();
+        }
+    }
+}
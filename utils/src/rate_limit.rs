@@ -0,0 +1,128 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome rate limiting primitive.
+
+use crate::ensure;
+use alloc::collections::BTreeMap;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Token-bucket rate limiter keyed by e.g. `ActorId`.
+///
+/// Every key has a bucket of up to `capacity` tokens, refilled by one token
+/// every `refill_period` blocks. Keys with full buckets aren't stored.
+///
+/// Limiter with zero `refill_period` (the default) is disabled and never limits.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter<K> {
+    capacity: u32,
+    refill_period: u32,
+    buckets: BTreeMap<K, Bucket>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    tokens: u32,
+    updated_at: u32,
+}
+
+impl<K: Ord> RateLimiter<K> {
+    /// Creates a new `RateLimiter` allowing bursts of `capacity` calls and
+    /// refilling one token per `refill_period` blocks.
+    pub fn new(capacity: u32, refill_period: u32) -> Self {
+        Self {
+            capacity,
+            refill_period,
+            buckets: BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if the limiter never limits.
+    pub fn is_disabled(&self) -> bool {
+        self.refill_period == 0
+    }
+
+    /// Returns the amount of tokens available to the key at the given block.
+    pub fn available(&self, key: &K, current_bn: u32) -> u32 {
+        if self.is_disabled() {
+            return u32::MAX;
+        }
+
+        self.buckets
+            .get(key)
+            .map(|bucket| self.refilled(*bucket, current_bn).tokens)
+            .unwrap_or(self.capacity)
+    }
+
+    /// Consumes `cost` tokens of the key, failing if there are not enough.
+    pub fn try_consume(&mut self, key: K, cost: u32, current_bn: u32) -> Result<(), RateLimited> {
+        if self.is_disabled() {
+            return Ok(());
+        }
+
+        let bucket = self
+            .buckets
+            .get(&key)
+            .map(|bucket| self.refilled(*bucket, current_bn))
+            .unwrap_or(Bucket {
+                tokens: self.capacity,
+                updated_at: current_bn,
+            });
+
+        ensure!(bucket.tokens >= cost, RateLimited);
+
+        let bucket = Bucket {
+            tokens: bucket.tokens - cost,
+            ..bucket
+        };
+
+        if bucket.tokens >= self.capacity {
+            self.buckets.remove(&key);
+        } else {
+            self.buckets.insert(key, bucket);
+        }
+
+        Ok(())
+    }
+
+    /// Refills the bucket up to the given block,
+    /// keeping refill boundaries aligned to the last update.
+    fn refilled(&self, bucket: Bucket, current_bn: u32) -> Bucket {
+        let elapsed = current_bn.saturating_sub(bucket.updated_at);
+        let refills = elapsed / self.refill_period;
+
+        let tokens = bucket.tokens.saturating_add(refills).min(self.capacity);
+        let updated_at = if tokens == self.capacity {
+            current_bn
+        } else {
+            bucket.updated_at + refills * self.refill_period
+        };
+
+        Bucket { tokens, updated_at }
+    }
+}
+
+/// Error type for exceeded rate limit.
+#[derive(Clone, Debug, Decode, Default, Encode, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[error("rate limit exceeded")]
+#[scale_info(crate = scale_info)]
+pub struct RateLimited;
+
+crate::impl_error_code!(RateLimited => 40);
@@ -0,0 +1,141 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome reply routing and outbound message tracking primitives.
+
+use alloc::{collections::BTreeMap, string::String};
+use gprimitives::{ActorId, MessageId};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Router of replies to the services which sent the messages replied to.
+///
+/// Programs have a single `handle_reply`, so services sending messages register
+/// ids of the messages they expect replies to along with the tag of their handler,
+/// e.g. the route of the service, and `handle_reply` dispatches replies by the
+/// tag routed. Replies to unregistered messages aren't routed anywhere.
+#[derive(Clone, Debug)]
+pub struct ReplyRouter<T = &'static str> {
+    expected: BTreeMap<MessageId, T>,
+}
+
+impl<T> Default for ReplyRouter<T> {
+    fn default() -> Self {
+        Self {
+            expected: Default::default(),
+        }
+    }
+}
+
+impl<T> ReplyRouter<T> {
+    /// Registers the message as expecting a reply handled by the tagged handler.
+    pub fn expect(&mut self, message_id: MessageId, tag: T) {
+        self.expected.insert(message_id, tag);
+    }
+
+    /// Returns the tag of the handler expecting the reply to the message.
+    pub fn tag_of(&self, message_id: &MessageId) -> Option<&T> {
+        self.expected.get(message_id)
+    }
+
+    /// Routes the reply to the message, returning the tag of its handler.
+    ///
+    /// Messages are replied once, so the message is unregistered.
+    pub fn route(&mut self, reply_to: MessageId) -> Option<T> {
+        self.expected.remove(&reply_to)
+    }
+
+    /// Returns the number of messages awaiting replies.
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+}
+
+/// Record of a message sent to another program.
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct OutboundMessage {
+    pub destination: ActorId,
+    /// What the message is sent for, e.g. `"payout"`.
+    pub purpose: String,
+    pub sent_at: u32,
+}
+
+/// Tracker of messages sent to other programs until their replies arrive.
+///
+/// Messages are recorded once sent and resolved in `handle_reply`, so messages
+/// left unresolved for long, e.g. whose replies never arrive, are found by
+/// [`stale_messages`](Self::stale_messages) for watchdog logic to act upon.
+#[derive(Clone, Debug, Default)]
+pub struct OutboundTracker {
+    messages: BTreeMap<MessageId, OutboundMessage>,
+}
+
+impl OutboundTracker {
+    /// Records the message sent at the given block.
+    pub fn record(
+        &mut self,
+        message_id: MessageId,
+        destination: ActorId,
+        purpose: impl Into<String>,
+        sent_at: u32,
+    ) {
+        self.messages.insert(
+            message_id,
+            OutboundMessage {
+                destination,
+                purpose: purpose.into(),
+                sent_at,
+            },
+        );
+    }
+
+    pub fn get(&self, message_id: &MessageId) -> Option<&OutboundMessage> {
+        self.messages.get(message_id)
+    }
+
+    /// Marks the message replied to as resolved, returning its record if tracked.
+    pub fn resolve(&mut self, reply_to: MessageId) -> Option<OutboundMessage> {
+        self.messages.remove(&reply_to)
+    }
+
+    /// Returns messages unresolved for at least `older_than` blocks.
+    pub fn stale_messages(
+        &self,
+        older_than: u32,
+        current_bn: u32,
+    ) -> impl Iterator<Item = (&MessageId, &OutboundMessage)> {
+        self.messages
+            .iter()
+            .filter(move |(_, message)| current_bn.saturating_sub(message.sent_at) >= older_than)
+    }
+
+    /// Returns the number of unresolved messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
@@ -0,0 +1,108 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome signature verification module.
+//!
+//! Services verify signatures of users or validators, e.g. of permits,
+//! meta-transactions or attestations, by helpers of the scheme enabled:
+//!
+//! - [`sr25519`]: Schnorr signatures of Substrate accounts, with the `sr25519` feature;
+//! - [`ed25519`]: Edwards signatures, with the `ed25519` feature;
+//! - [`eth`](crate::eth): secp256k1 ECDSA signatures of Ethereum, with the `eth` feature.
+//!
+//! Schemes fail with the same [`InvalidSignature`] error.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Error type for malformed signatures or ones not matching the signer.
+#[derive(Clone, Debug, Decode, Default, Encode, PartialEq, Eq, TypeInfo, thiserror::Error)]
+#[codec(crate = parity_scale_codec)]
+#[error("invalid signature")]
+#[scale_info(crate = scale_info)]
+pub struct InvalidSignature;
+
+crate::impl_error_code!(InvalidSignature => 50);
+
+/// Schnorr signatures over Ristretto25519, signed by Substrate accounts.
+#[cfg(feature = "sr25519")]
+pub mod sr25519 {
+    use super::InvalidSignature;
+    use alloc::vec::Vec;
+    use schnorrkel::{PublicKey, Signature};
+
+    /// Sr25519 signature.
+    pub type Sr25519Signature = [u8; 64];
+
+    /// Signing context of Substrate, signatures of accounts are made in.
+    pub const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+    /// Verifies the signature of the message by the public key, e.g. bytes
+    /// of the `ActorId` of the account, made in the Substrate context.
+    pub fn verify(
+        public_key: &[u8; 32],
+        message: &[u8],
+        signature: &Sr25519Signature,
+    ) -> Result<(), InvalidSignature> {
+        let public_key = PublicKey::from_bytes(public_key).map_err(|_| InvalidSignature)?;
+        let signature = Signature::from_bytes(signature).map_err(|_| InvalidSignature)?;
+
+        public_key
+            .verify_simple(SIGNING_CONTEXT, message, &signature)
+            .map_err(|_| InvalidSignature)
+    }
+
+    /// Verifies the signature of the message as signed by wallets' `signRaw`,
+    /// wrapping the message into `<Bytes>` tags.
+    pub fn verify_wrapped(
+        public_key: &[u8; 32],
+        message: &[u8],
+        signature: &Sr25519Signature,
+    ) -> Result<(), InvalidSignature> {
+        let wrapped: Vec<u8> = [&b"<Bytes>"[..], message, b"</Bytes>"].concat();
+
+        verify(public_key, &wrapped, signature)
+    }
+}
+
+/// Edwards-curve signatures over Curve25519.
+#[cfg(feature = "ed25519")]
+pub mod ed25519 {
+    use super::InvalidSignature;
+    use ed25519_dalek::{Signature, VerifyingKey};
+
+    /// Ed25519 signature.
+    pub type Ed25519Signature = [u8; 64];
+
+    /// Verifies the signature of the message by the public key.
+    ///
+    /// Verification is strict: malleable signatures and weak keys are rejected,
+    /// so every message has a single valid signature of the key.
+    pub fn verify(
+        public_key: &[u8; 32],
+        message: &[u8],
+        signature: &Ed25519Signature,
+    ) -> Result<(), InvalidSignature> {
+        let public_key = VerifyingKey::from_bytes(public_key).map_err(|_| InvalidSignature)?;
+        let signature = Signature::from_bytes(signature);
+
+        public_key
+            .verify_strict(message, &signature)
+            .map_err(|_| InvalidSignature)
+    }
+}
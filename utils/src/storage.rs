@@ -18,13 +18,14 @@
 
 //! Awesome storage primitives module.
 
+use crate::error::ErrorCode;
 use core::{
-    cell::{Ref, RefCell, RefMut},
+    cell::{OnceCell, Ref, RefCell, RefMut},
     convert::Infallible,
     ops::{Deref, DerefMut},
 };
 
-use alloc::rc::Rc;
+use alloc::{boxed::Box, rc::Rc};
 
 /// A trait representing a read-only storage mechanism inside a program.
 pub trait Storage {
@@ -32,7 +33,7 @@ pub trait Storage {
     type Item: ?Sized;
 
     /// The type of error that can occur when accessing the storage.
-    type Error: core::error::Error;
+    type Error: core::error::Error + ErrorCode;
 
     /// Retrieves a reference to the item stored in the storage.
     fn get(&self) -> Result<impl Deref<Target = Self::Item>, Self::Error>;
@@ -235,6 +236,206 @@ impl<'a, T> InfallibleStorageMut for StorageRefCell<'a, T> {
     }
 }
 
+/// A lazily initialized cell suitable for program-wide `static` state.
+///
+/// The item is constructed by the given function on first access, so large
+/// state doesn't have to be built up front in the program constructor.
+///
+/// ```ignore
+/// static BALANCES: StaticCell<Balances> = StaticCell::new(Balances::default);
+///
+/// let storage = StorageStatic::new(&BALANCES);
+/// ```
+pub struct StaticCell<T> {
+    cell: OnceCell<RefCell<T>>,
+    init: fn() -> T,
+}
+
+impl<T> StaticCell<T> {
+    /// Creates a new `StaticCell` initialized on first access by `init`.
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init,
+        }
+    }
+
+    fn cell(&self) -> &RefCell<T> {
+        self.cell.get_or_init(|| RefCell::new((self.init)()))
+    }
+}
+
+// SAFETY: wasm programs are executed in a single thread.
+#[cfg(target_arch = "wasm32")]
+unsafe impl<T> Sync for StaticCell<T> {}
+
+/// A storage over a `'static` [`StaticCell<T>`].
+///
+/// Unlike [`StorageRefCell`], it carries no lifetime and may be freely copied
+/// between service instances.
+pub struct StorageStatic<T: 'static>(&'static StaticCell<T>);
+
+impl<T> StorageStatic<T> {
+    /// Creates a new `StorageStatic` from a reference to a `StaticCell<T>`.
+    pub const fn new(cell: &'static StaticCell<T>) -> Self {
+        Self(cell)
+    }
+}
+
+impl<T> Clone for StorageStatic<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StorageStatic<T> {}
+
+impl<T> From<&'static StaticCell<T>> for StorageStatic<T> {
+    fn from(value: &'static StaticCell<T>) -> Self {
+        StorageStatic::new(value)
+    }
+}
+
+// Storage trait implementations for StorageStatic<T>
+impl<T> InfallibleStorage for StorageStatic<T> {
+    type Item = T;
+
+    fn get(&self) -> impl Deref<Target = Self::Item> {
+        self.0.cell().borrow()
+    }
+}
+
+impl<T> InfallibleStorageMut for StorageStatic<T> {
+    fn get_mut(&mut self) -> impl DerefMut<Target = Self::Item> {
+        self.0.cell().borrow_mut()
+    }
+
+    fn replace(&mut self, value: Self::Item) -> Self::Item {
+        self.0.cell().replace(value)
+    }
+
+    fn replace_with(&mut self, f: impl FnOnce(&mut Self::Item) -> Self::Item) -> Self::Item {
+        self.0.cell().replace_with(f)
+    }
+}
+
+/// A compile-time key of a storage cell, namespaced by the service owning it.
+///
+/// Keys are hashes of the namespace and the name of the cell, so services
+/// sharing a program never collide as long as their namespaces differ, and
+/// cells keep their keys across upgrades as long as they aren't renamed.
+///
+/// ```ignore
+/// const BALANCES: StorageKey = StorageKey::new("Vft", "balances");
+/// const ALLOWANCES: StorageKey = StorageKey::new("Vft", "allowances");
+///
+/// const _: () = StorageKey::assert_unique(&[BALANCES, ALLOWANCES]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StorageKey {
+    namespace: &'static str,
+    name: &'static str,
+    hash: [u8; 32],
+}
+
+impl StorageKey {
+    /// Creates the key of the cell `name` within `namespace`, e.g. the service name.
+    pub const fn new(namespace: &'static str, name: &'static str) -> Self {
+        // Length prefix keeps ("ab", "c") and ("a", "bc") apart.
+        let hash = keccak_const::Keccak256::new()
+            .update(&(namespace.len() as u32).to_le_bytes())
+            .update(namespace.as_bytes())
+            .update(name.as_bytes())
+            .finalize();
+
+        Self {
+            namespace,
+            name,
+            hash,
+        }
+    }
+
+    /// Returns the namespace of the key.
+    pub const fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+
+    /// Returns the name of the cell within its namespace.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the hash of the key, the one storage backends address cells by.
+    pub const fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    /// Panics if any two of the keys are equal, failing compilation
+    /// if evaluated in a constant.
+    pub const fn assert_unique(keys: &[StorageKey]) {
+        let mut i = 0;
+
+        while i < keys.len() {
+            let mut j = i + 1;
+
+            while j < keys.len() {
+                let (a, b) = (&keys[i].hash, &keys[j].hash);
+                let mut k = 0;
+
+                while k < a.len() && a[k] == b[k] {
+                    k += 1;
+                }
+
+                assert!(k < a.len(), "storage key collision");
+
+                j += 1;
+            }
+
+            i += 1;
+        }
+    }
+}
+
+/// A [`StaticCell`] under a [`StorageKey`], for program-wide `static` state
+/// of services sharing a program.
+///
+/// ```ignore
+/// static BALANCES: NamespacedCell<Balances> =
+///     NamespacedCell::new(StorageKey::new("Vft", "balances"), Balances::default);
+///
+/// let storage = BALANCES.storage();
+/// ```
+pub struct NamespacedCell<T> {
+    key: StorageKey,
+    cell: StaticCell<T>,
+}
+
+impl<T> NamespacedCell<T> {
+    /// Creates a new `NamespacedCell` under the key, initialized on first access by `init`.
+    pub const fn new(key: StorageKey, init: fn() -> T) -> Self {
+        Self {
+            key,
+            cell: StaticCell::new(init),
+        }
+    }
+
+    /// Returns the key of the cell.
+    pub const fn key(&self) -> StorageKey {
+        self.key
+    }
+
+    /// Returns a storage over the cell.
+    pub const fn storage(&'static self) -> StorageStatic<T> {
+        StorageStatic::new(&self.cell)
+    }
+}
+
+impl<T> From<&'static NamespacedCell<T>> for StorageStatic<T> {
+    fn from(value: &'static NamespacedCell<T>) -> Self {
+        value.storage()
+    }
+}
+
 // Storage trait implementations for Ref<T>, RefMut<T>
 impl<'a, T> InfallibleStorage for Ref<'a, T> {
     type Item = T;
@@ -261,3 +462,207 @@ impl<'a, T> InfallibleStorageMut for RefMut<'a, T> {
         core::mem::replace(self, value)
     }
 }
+
+/// A dyn-compatible counterpart of [`StorageMut`].
+///
+/// Implemented for every [`StorageMut`], so services may hold storages as
+/// trait objects where generics are unwieldy.
+pub trait StorageAccessor<T, E> {
+    /// Retrieves a boxed reference to the item stored in the storage.
+    fn access(&self) -> Result<Box<dyn Deref<Target = T> + '_>, E>;
+
+    /// Retrieves a boxed mutable reference to the item stored in the storage.
+    fn access_mut(&mut self) -> Result<Box<dyn DerefMut<Target = T> + '_>, E>;
+
+    /// Replaces the item in the storage with a new value and returns the old value.
+    fn swap_item(&mut self, value: T) -> Result<T, E>;
+}
+
+impl<S: StorageMut> StorageAccessor<S::Item, S::Error> for S
+where
+    S::Item: Sized,
+{
+    fn access(&self) -> Result<Box<dyn Deref<Target = S::Item> + '_>, S::Error> {
+        Ok(Box::new(self.get()?))
+    }
+
+    fn access_mut(&mut self) -> Result<Box<dyn DerefMut<Target = S::Item> + '_>, S::Error> {
+        Ok(Box::new(self.get_mut()?))
+    }
+
+    fn swap_item(&mut self, value: S::Item) -> Result<S::Item, S::Error> {
+        self.replace(value)
+    }
+}
+
+/// A type-erased storage over a boxed [`StorageAccessor`].
+pub struct BoxedStorage<'a, T, E = Infallible>(Box<dyn StorageAccessor<T, E> + 'a>);
+
+impl<'a, T, E> BoxedStorage<'a, T, E> {
+    /// Creates a new `BoxedStorage` erasing the type of the given storage.
+    pub fn new<S: StorageMut<Item = T, Error = E> + 'a>(storage: S) -> Self {
+        Self(Box::new(storage))
+    }
+}
+
+impl<T, E: core::error::Error + ErrorCode> Storage for BoxedStorage<'_, T, E> {
+    type Item = T;
+    type Error = E;
+
+    fn get(&self) -> Result<impl Deref<Target = Self::Item>, Self::Error> {
+        self.0.access().map(Unboxed)
+    }
+}
+
+impl<T, E: core::error::Error + ErrorCode> StorageMut for BoxedStorage<'_, T, E> {
+    fn get_mut(&mut self) -> Result<impl DerefMut<Target = Self::Item>, Self::Error> {
+        self.0.access_mut().map(Unboxed)
+    }
+
+    fn replace(&mut self, value: Self::Item) -> Result<Self::Item, Self::Error> {
+        self.0.swap_item(value)
+    }
+}
+
+// Dereferences through a boxed reference to its target.
+struct Unboxed<D: ?Sized>(Box<D>);
+
+impl<D: ?Sized + Deref> Deref for Unboxed<D> {
+    type Target = D::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<D: ?Sized + DerefMut> DerefMut for Unboxed<D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A storage adapter buffering mutations of the inner storage until committed.
+///
+/// The inner item is cloned into the overlay on first mutable access and all
+/// subsequent reads and writes go to the overlay. Changes are written back to
+/// the inner storage only on [`commit`](Self::commit) and are discarded on
+/// [`revert`](Self::revert) or drop.
+pub struct TransactionalStorage<S: StorageMut>
+where
+    S::Item: Clone + Sized,
+{
+    inner: S,
+    pending: Option<S::Item>,
+}
+
+impl<S: StorageMut> TransactionalStorage<S>
+where
+    S::Item: Clone + Sized,
+{
+    /// Creates a new `TransactionalStorage` over the given storage.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            pending: None,
+        }
+    }
+
+    /// Returns `true` if there are uncommitted changes.
+    pub fn is_dirty(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Writes buffered changes to the inner storage.
+    pub fn commit(&mut self) -> Result<(), S::Error> {
+        if let Some(item) = self.pending.take() {
+            self.inner.replace(item)?;
+        }
+
+        Ok(())
+    }
+
+    /// Discards buffered changes.
+    pub fn revert(&mut self) {
+        self.pending = None;
+    }
+
+    /// Runs `f` over the storage, committing its changes on success and
+    /// reverting them on failure.
+    pub fn transact<R, E: From<S::Error>>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<R, E>,
+    ) -> Result<R, E> {
+        match f(self) {
+            Ok(res) => {
+                self.commit()?;
+                Ok(res)
+            }
+            Err(e) => {
+                self.revert();
+                Err(e)
+            }
+        }
+    }
+
+    /// Discards buffered changes and returns the inner storage.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: StorageMut> Storage for TransactionalStorage<S>
+where
+    S::Item: Clone + Sized,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn get(&self) -> Result<impl Deref<Target = Self::Item>, Self::Error> {
+        Ok(match &self.pending {
+            Some(item) => Either::Left(item),
+            None => Either::Right(self.inner.get()?),
+        })
+    }
+}
+
+impl<S: StorageMut> StorageMut for TransactionalStorage<S>
+where
+    S::Item: Clone + Sized,
+{
+    fn get_mut(&mut self) -> Result<impl DerefMut<Target = Self::Item>, Self::Error> {
+        let item = match self.pending.take() {
+            Some(item) => item,
+            None => self.inner.get()?.clone(),
+        };
+
+        Ok(self.pending.insert(item))
+    }
+
+    fn replace(&mut self, value: Self::Item) -> Result<Self::Item, Self::Error> {
+        let old = match self.pending.take() {
+            Some(item) => item,
+            None => self.inner.get()?.clone(),
+        };
+
+        self.pending = Some(value);
+
+        Ok(old)
+    }
+}
+
+// Dereferences to whichever of two same-target references is held.
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<T: ?Sized, L: Deref<Target = T>, R: Deref<Target = T>> Deref for Either<L, R> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => r,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Awesome typed data module: domain-separated hashing of structured messages,
+//! an analog of EIP-712.
+//!
+//! Signers sign the [`digest`] of a message instead of its bytes. The digest
+//! commits to the [`Domain`] of the message, i.e. the program, the network and
+//! the service verifying it, and to the type of the message, so a signature made
+//! for one program, network, service or kind of message is never valid for another.
+//!
+//! Services define their messages by implementing [`TypedData`], or use the
+//! [`Permit`] and [`MetaTx`] payloads.
+
+use crate::merkle::Hasher;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use primitive_types::U256;
+use scale_info::TypeInfo;
+
+/// Prefix of digests, the EIP-191 version byte `0x01` of EIP-712.
+pub const DIGEST_PREFIX: &[u8] = b"\x19\x01";
+
+/// Tag of domain separators.
+pub const DOMAIN_TAG: &[u8] = b"awesome-sails/domain";
+
+/// Domain messages are signed within.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Domain {
+    /// Bytes of the id of the program verifying signatures.
+    pub program: [u8; 32],
+    /// Identifier of the network, e.g. its genesis hash.
+    pub network: [u8; 32],
+    /// Name of the service verifying signatures.
+    pub service: &'static str,
+    /// Version of the service, bumped to invalidate signatures made for former ones.
+    pub version: u32,
+}
+
+impl Domain {
+    /// Returns the separator of the domain, i.e. the hash of its fields.
+    pub fn separator<H: Hasher>(&self) -> [u8; 32] {
+        let fields = (self.program, self.network, self.service, self.version).encode();
+
+        H::hash(&[DOMAIN_TAG, &fields])
+    }
+}
+
+/// Structured message signed within a [`Domain`].
+pub trait TypedData: Encode {
+    /// Name of the type, so messages of the same encoding aren't confused.
+    const TYPE: &'static str;
+
+    /// Returns the hash of the message along with its type.
+    fn struct_hash<H: Hasher>(&self) -> [u8; 32] {
+        H::hash(&[&Self::TYPE.encode(), &self.encode()])
+    }
+}
+
+/// Returns the digest of the message within the domain, the one to sign.
+pub fn digest<H: Hasher, T: TypedData>(domain: &Domain, data: &T) -> [u8; 32] {
+    H::hash(&[
+        DIGEST_PREFIX,
+        &domain.separator::<H>(),
+        &data.struct_hash::<H>(),
+    ])
+}
+
+/// Approval of `value` of the owner to the spender, valid until block `deadline`.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct Permit<O, S = O> {
+    pub owner: O,
+    pub spender: S,
+    pub value: U256,
+    pub nonce: u64,
+    pub deadline: u32,
+}
+
+impl<O: Encode, S: Encode> TypedData for Permit<O, S> {
+    const TYPE: &'static str = "Permit";
+}
+
+/// Call relayed on behalf of the signer, valid until block `deadline`.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+#[codec(crate = parity_scale_codec)]
+#[scale_info(crate = scale_info)]
+pub struct MetaTx<A> {
+    pub signer: A,
+    /// Encoded call, e.g. its service and method routes followed by arguments.
+    pub payload: Vec<u8>,
+    pub nonce: u64,
+    pub deadline: u32,
+}
+
+impl<A: Encode> TypedData for MetaTx<A> {
+    const TYPE: &'static str = "MetaTx";
+}
@@ -0,0 +1,80 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::audit::AuditLog;
+
+#[test]
+fn push_assigns_sequential_indices() {
+    let mut log = AuditLog::new(4);
+
+    assert!(log.is_empty());
+    assert_eq!(log.push('a'), 0);
+    assert_eq!(log.push('b'), 1);
+    assert_eq!(log.push('c'), 2);
+
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.first_index(), 0);
+    assert_eq!(log.next_index(), 3);
+    assert_eq!(log.entries(0, 10), vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn push_evicts_oldest_when_full() {
+    let mut log = AuditLog::new(2);
+
+    for c in ['a', 'b', 'c', 'd'] {
+        log.push(c);
+    }
+
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.first_index(), 2);
+    assert_eq!(log.entries(0, 10), vec![(2, 'c'), (3, 'd')]);
+}
+
+#[test]
+fn entries_paginate_by_cursor() {
+    let mut log = AuditLog::new(8);
+
+    for c in 'a'..='f' {
+        log.push(c);
+    }
+
+    assert_eq!(log.entries(2, 2), vec![(2, 'c'), (3, 'd')]);
+    assert_eq!(log.entries(5, 10), vec![(5, 'f')]);
+    assert!(log.entries(6, 10).is_empty());
+    assert!(log.entries(0, 0).is_empty());
+}
+
+#[test]
+fn zero_capacity_keeps_nothing() {
+    let mut log = AuditLog::new(0);
+
+    assert_eq!(log.push('a'), 0);
+    assert_eq!(log.push('b'), 1);
+
+    assert!(log.is_empty());
+    assert_eq!(log.first_index(), 2);
+    assert!(log.entries(0, 10).is_empty());
+}
+
+#[test]
+fn default_capacity() {
+    let log = AuditLog::<u8>::default();
+
+    assert_eq!(log.capacity(), AuditLog::<u8>::DEFAULT_CAPACITY);
+}
@@ -0,0 +1,65 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::checkpoint::Checkpoints;
+
+#[test]
+fn returns_value_at_block() {
+    let mut checkpoints = Checkpoints::default();
+
+    assert_eq!(checkpoints.at(100), None);
+
+    checkpoints.push(10, 1);
+    checkpoints.push(20, 2);
+    checkpoints.push(30, 3);
+
+    assert_eq!(checkpoints.at(9), None);
+    assert_eq!(checkpoints.at(10), Some(&1));
+    assert_eq!(checkpoints.at(25), Some(&2));
+    assert_eq!(checkpoints.at(1_000), Some(&3));
+}
+
+#[test]
+fn merges_same_block_and_unchanged_values() {
+    let mut checkpoints = Checkpoints::default();
+
+    checkpoints.push(10, 1);
+    checkpoints.push(10, 2);
+    checkpoints.push(20, 2);
+
+    assert_eq!(checkpoints.len(), 1);
+    assert_eq!(checkpoints.latest(), Some((10, &2)));
+}
+
+#[test]
+fn evicts_oldest_checkpoints() {
+    let mut checkpoints = Checkpoints::new(2);
+
+    for block in 1..=3 {
+        checkpoints.push(block, block);
+    }
+
+    assert_eq!(checkpoints.first_block(), Some(2));
+    assert_eq!(checkpoints.at(1), None);
+    assert_eq!(checkpoints.at(2), Some(&2));
+
+    // Zero capacity keeps nothing
+    let mut checkpoints = Checkpoints::new(0);
+    checkpoints.push(1, 1);
+    assert!(checkpoints.is_empty());
+}
@@ -0,0 +1,80 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::commit_reveal::{
+    AlreadyCommitted, Commitment, Commitments, RevealError, hash,
+};
+
+#[test]
+fn verifies_reveals_within_window() {
+    let commitment = Commitment::new(hash(b"seed"), 10, 20);
+
+    assert_eq!(commitment.verify(b"seed", 9), Err(RevealError::TooEarly));
+    assert_eq!(commitment.verify(b"seed", 10), Ok(()));
+    assert_eq!(commitment.verify(b"seed", 19), Ok(()));
+    assert_eq!(commitment.verify(b"seed", 20), Err(RevealError::Expired));
+
+    assert_eq!(commitment.verify(b"guess", 15), Err(RevealError::Mismatch));
+
+    assert!(!commitment.is_expired(19));
+    assert!(commitment.is_expired(20));
+}
+
+#[test]
+fn reveals_once_per_key() {
+    let mut commitments = Commitments::default();
+
+    assert_eq!(
+        commitments.commit(1, Commitment::new(hash(b"one"), 0, 10)),
+        Ok(())
+    );
+    assert_eq!(
+        commitments.commit(1, Commitment::new(hash(b"other"), 0, 10)),
+        Err(AlreadyCommitted)
+    );
+
+    assert_eq!(
+        commitments.reveal(&1, b"other", 5),
+        Err(RevealError::Mismatch)
+    );
+    assert_eq!(
+        commitments.reveal(&1, b"one", 5).map(|c| c.hash),
+        Ok(hash(b"one"))
+    );
+    assert_eq!(
+        commitments.reveal(&1, b"one", 5),
+        Err(RevealError::NotCommitted)
+    );
+}
+
+#[test]
+fn removes_expired() {
+    let mut commitments = Commitments::default();
+
+    commitments
+        .commit(1, Commitment::new(hash(b"one"), 0, 10))
+        .unwrap();
+    commitments
+        .commit(2, Commitment::new(hash(b"two"), 0, 20))
+        .unwrap();
+
+    assert_eq!(commitments.remove_expired(9), Vec::<u32>::new());
+    assert_eq!(commitments.remove_expired(15), vec![1]);
+    assert_eq!(commitments.len(), 1);
+    assert!(commitments.get(&2).is_some());
+}
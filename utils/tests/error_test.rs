@@ -0,0 +1,135 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    error::{BadConfig, BadInput, EmitError, Error, ErrorCode, ErrorEvent},
+    map::ShardedMapError,
+    math::{MathError, OverflowError, ZeroError},
+    page::PageTooLarge,
+    pause::{PausableError, PausedError},
+};
+use core::convert::Infallible;
+
+#[test]
+fn conversion_keeps_code() {
+    assert_eq!(Error::from(BadInput).code(), 1);
+    assert_eq!(Error::from(EmitError).code(), 4);
+    assert_eq!(Error::from(BadConfig::Decimals).code(), 5);
+    assert_eq!(Error::from(ShardedMapError::InvalidCapacity).code(), 31);
+    assert_eq!(Error::from(ShardedMapError::Misrouted).code(), 32);
+    assert_eq!(Error::from(ShardedMapError::NoEmptyShard).code(), 33);
+    assert_eq!(Error::from(PageTooLarge { max: 1 }).code(), 70);
+}
+
+#[test]
+fn wrapping_errors_forward_code() {
+    assert_eq!(MathError::from(ZeroError).code(), ZeroError.code());
+    assert_eq!(
+        Error::from(MathError::from(OverflowError)).code(),
+        OverflowError.code()
+    );
+
+    assert_eq!(
+        PausableError::<Infallible>::Paused.code(),
+        PausedError.code()
+    );
+    assert_eq!(
+        PausableError::Storage(ShardedMapError::CapacityOverflow).code(),
+        30
+    );
+}
+
+#[test]
+fn message_errors_are_unspecified() {
+    let err = Error::new("custom failure");
+
+    assert_eq!(err.code(), Error::UNSPECIFIED);
+    assert_eq!(err.message(), "custom failure");
+    assert_eq!(format!("{err:?}"), "custom failure");
+}
+
+#[test]
+fn error_events_describe_errors() {
+    assert_eq!(
+        ErrorEvent::new(&BadInput),
+        ErrorEvent {
+            code: 1,
+            message: "incorrect input argument".into(),
+        }
+    );
+    assert_eq!(
+        ErrorEvent::new(&Error::new("value is too big")),
+        ErrorEvent {
+            code: Error::UNSPECIFIED,
+            message: "value is too big".into(),
+        }
+    );
+}
+
+#[test]
+fn decode_result_roundtrip() {
+    use awesome_sails_utils::error::decode_result;
+    use parity_scale_codec::Encode;
+
+    let ok: Result<u32, Error> = Ok(42);
+    assert_eq!(decode_result::<u32>(&ok.encode()).unwrap(), ok);
+
+    let err: Result<u32, Error> = Err(BadInput.into());
+    let decoded = decode_result::<u32>(&err.encode()).unwrap().unwrap_err();
+    assert_eq!(decoded.code(), 1);
+    assert_eq!(decoded.message(), "incorrect input argument");
+
+    assert!(decode_result::<u32>(&[1]).is_err());
+}
+
+#[test]
+fn decode_error_roundtrip() {
+    use awesome_sails_utils::error::decode_error;
+    use parity_scale_codec::Encode;
+
+    let err: Error = BadInput.into();
+    let payload = format!("panicked with '0x{}'", hex(&err.encode()));
+    let decoded = decode_error(payload.as_bytes()).unwrap();
+    assert_eq!(decoded, err);
+    assert_eq!(decoded.code(), 1);
+
+    // Panic location may follow the message.
+    let payload = format!("{}' at 'src/lib.rs:1:1'", payload.trim_end_matches('\''));
+    assert_eq!(decode_error(payload.as_bytes()).unwrap(), err);
+
+    assert!(decode_error(&[]).is_err());
+    assert!(decode_error(&err.encode()).is_err());
+    assert!(decode_error(b"panicked with '0xzz'").is_err());
+}
+
+#[test]
+fn revert_panics_with_encoded_error() {
+    use awesome_sails_utils::error::{decode_error, revert};
+
+    let err: Error = BadInput.into();
+
+    let panic = std::panic::catch_unwind(|| revert(err.clone())).unwrap_err();
+    let message = panic.downcast::<String>().unwrap();
+
+    let payload = format!("panicked with '{message}'");
+    assert_eq!(decode_error(payload.as_bytes()).unwrap(), err);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,94 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "eth")]
+
+use awesome_sails_utils::eth::{self, EthSignature};
+use k256::ecdsa::SigningKey;
+use primitive_types::H160;
+
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+
+    bytes
+}
+
+fn sign(key: &SigningKey, hash: &[u8; 32]) -> EthSignature {
+    let (signature, recovery_id) = key.sign_prehash_recoverable(hash).unwrap();
+
+    let mut bytes = [0; 65];
+    bytes[..64].copy_from_slice(&signature.to_bytes());
+    bytes[64] = recovery_id.to_byte() + 27;
+
+    bytes
+}
+
+#[test]
+fn hashes_personal_messages() {
+    // `web3.eth.accounts.hashMessage("Hello World")`.
+    assert_eq!(
+        eth::message_hash(b"Hello World"),
+        hex::<32>("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2")
+    );
+}
+
+#[test]
+fn recovers_signers() {
+    let key = SigningKey::from_bytes(
+        &hex::<32>("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318").into(),
+    )
+    .unwrap();
+
+    let expected = H160(hex::<20>("2c7536e3605d9c16a7a3d7b1898e529396a65c23"));
+
+    let public_key = key.verifying_key().to_encoded_point(false);
+    assert_eq!(
+        eth::address(public_key.as_bytes()[1..].try_into().unwrap()),
+        expected
+    );
+
+    let signature = sign(&key, &eth::message_hash(b"Some data"));
+    assert_eq!(eth::recover_message(b"Some data", &signature), Ok(expected));
+
+    // Recovery ids `0`/`1` are accepted too.
+    let mut raw = signature;
+    raw[64] -= 27;
+    assert_eq!(eth::recover_message(b"Some data", &raw), Ok(expected));
+
+    // Other messages recover other addresses.
+    assert_ne!(
+        eth::recover_message(b"Other data", &signature),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn rejects_malformed_signatures() {
+    let key = SigningKey::from_bytes(&[1; 32].into()).unwrap();
+    let hash = eth::keccak256(b"data");
+
+    let mut signature = sign(&key, &hash);
+    signature[64] = 29;
+    assert!(eth::recover(&hash, &signature).is_err());
+
+    assert!(eth::recover(&hash, &[0; 65]).is_err());
+}
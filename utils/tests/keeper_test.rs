@@ -0,0 +1,61 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::keeper::{Bounty, RewardAsset};
+
+#[test]
+fn pays_per_unit_within_caps() {
+    let mut bounty = Bounty::new(RewardAsset::Native, 10, 3, 50);
+    bounty.fund(1_000).unwrap();
+
+    // Capped per call
+    assert_eq!(bounty.claim(5, 1), 30);
+    // Capped per block
+    assert_eq!(bounty.claim(3, 1), 20);
+    assert_eq!(bounty.claim(3, 1), 0);
+    // Cap is restored in the next block
+    assert_eq!(bounty.claim(1, 2), 10);
+
+    assert_eq!(bounty.budget, 940);
+}
+
+#[test]
+fn pays_within_budget() {
+    let mut bounty = Bounty::new(RewardAsset::Vft, 10, 10, u128::MAX);
+    bounty.fund(25).unwrap();
+
+    assert_eq!(bounty.claim(2, 1), 20);
+    assert_eq!(bounty.claim(2, 2), 5);
+    assert_eq!(bounty.claim(2, 3), 0);
+
+    bounty.fund(1).unwrap();
+    assert!(bounty.fund(u128::MAX).is_err());
+}
+
+#[test]
+fn reconfigure_keeps_budget() {
+    let mut bounty = Bounty::default();
+    bounty.fund(100).unwrap();
+
+    assert_eq!(bounty.claim(1, 1), 0);
+
+    bounty.reconfigure(Bounty::new(RewardAsset::Native, 1, 1, 1));
+
+    assert_eq!(bounty.claim(1, 1), 1);
+    assert_eq!(bounty.budget, 99);
+}
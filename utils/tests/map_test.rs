@@ -0,0 +1,349 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::map::{Hashed, MapCursor, ShardedMap, ShardedMapError};
+
+fn map(len: u32) -> ShardedMap<u32, u32> {
+    let mut map = ShardedMap::try_new(vec![0b111 << 4, 0b111 << 2, 0b111]).unwrap();
+
+    while map.alloc_next_shard() {}
+
+    for key in 0..len {
+        map.try_insert(key, key * 10).unwrap();
+    }
+
+    map
+}
+
+fn page(map: &ShardedMap<u32, u32>, cursor: MapCursor, len: usize) -> (Vec<u32>, MapCursor) {
+    let mut next = cursor;
+
+    let keys = map
+        .iter_from(cursor)
+        .take(len)
+        .map(|(cursor, &key, _)| {
+            next = cursor;
+            key
+        })
+        .collect();
+
+    (keys, next)
+}
+
+#[test]
+fn iterates_as_iter_from_start() {
+    let map = map(100);
+
+    let all: Vec<_> = map
+        .iter_from(Default::default())
+        .map(|(_, &k, &v)| (k, v))
+        .collect();
+    let expected: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn pages_cover_map_across_shards() {
+    let map = map(137);
+
+    let mut cursor = MapCursor::default();
+    let mut keys = vec![];
+
+    loop {
+        let (page, next) = page(&map, cursor, 10);
+
+        if page.is_empty() {
+            break;
+        }
+
+        keys.extend(page);
+        cursor = next;
+    }
+
+    let expected: Vec<_> = map.iter().map(|(&k, _)| k).collect();
+
+    assert_eq!(keys, expected);
+}
+
+#[test]
+fn cursor_survives_removal() {
+    let mut map = map(50);
+
+    let (first, cursor) = page(&map, Default::default(), 20);
+
+    // Removing already listed pairs doesn't shift the rest.
+    for key in &first {
+        map.remove(key);
+    }
+
+    let (rest, _) = page(&map, cursor, usize::MAX);
+
+    let expected: Vec<_> = map.iter().map(|(&k, _)| k).collect();
+
+    assert_eq!(rest, expected);
+}
+
+#[test]
+fn filters_keep_lookups_exact() {
+    let mut map = map(100);
+
+    map.set_filters(8);
+
+    for key in 0..100 {
+        assert_eq!(map.get(&key).map(|(_, &v)| v), Some(key * 10));
+    }
+
+    for key in 100..1000 {
+        assert!(map.get(&key).is_none());
+    }
+
+    // Filters follow inserts and removals.
+    for key in 0..50 {
+        assert_eq!(map.remove(&key).map(|(_, v)| v), Some(key * 10));
+    }
+
+    for key in 1000..1047 {
+        assert_eq!(map.try_insert(key, key).unwrap().1, None);
+    }
+
+    assert_eq!(map.try_insert(50, 0).unwrap().1, Some(500));
+
+    for key in (0..50).chain(100..1000) {
+        assert!(map.get(&key).is_none());
+    }
+
+    for key in (51..100).chain(1000..1047) {
+        assert!(map.get(&key).is_some());
+    }
+
+    map.clear_shards();
+
+    assert!(map.get(&50).is_none());
+    assert!(map.is_empty());
+}
+
+#[test]
+fn filters_follow_new_shards() {
+    let mut map = ShardedMap::try_new(vec![0b111]).unwrap();
+
+    map.set_filters(8);
+    map.alloc_next_shard();
+
+    for key in 0..7 {
+        map.try_insert(key, ()).unwrap();
+    }
+
+    map.try_append_shard(0b111).unwrap();
+    map.alloc_next_shard();
+
+    map.try_insert(7, ()).unwrap();
+
+    for key in 0..8 {
+        assert!(map.get(&key).is_some());
+    }
+
+    // Disabling filters keeps the map as is.
+    map.set_filters(0);
+
+    assert_eq!(map.len(), 8);
+    assert!(map.get(&7).is_some());
+}
+
+#[test]
+fn hashed_routing_keeps_keys_in_routed_shards() {
+    let mut map: ShardedMap<u32, u32, Hashed> =
+        ShardedMap::try_new_routed(vec![0b111 << 4, 0b111 << 4]).unwrap();
+
+    while map.alloc_next_shard() {}
+
+    let mut inserted = vec![];
+
+    for key in 0..1000 {
+        match map.try_insert(key, key * 10) {
+            Ok((_, None)) => inserted.push(key),
+            Err(err) => assert_eq!(err, ShardedMapError::CapacityOverflow),
+            Ok((_, Some(_))) => unreachable!(),
+        }
+    }
+
+    // Routed shards fill up before the whole map does.
+    assert_eq!(map.len(), inserted.len());
+    assert!(map.len() <= map.max_capacity());
+
+    for &key in &inserted {
+        assert_eq!(map.get(&key).map(|(_, &v)| v), Some(key * 10));
+    }
+
+    for &key in &inserted {
+        assert_eq!(map.remove(&key).map(|(_, v)| v), Some(key * 10));
+        assert!(map.get(&key).is_none());
+    }
+
+    assert!(map.is_empty());
+
+    // Shards can't be appended or removed, not to reroute keys.
+    assert_eq!(map.try_append_shard(0b111), Err(ShardedMapError::Misrouted));
+    assert_eq!(
+        map.try_remove_empty_shard(),
+        Err(ShardedMapError::Misrouted)
+    );
+}
+
+#[test]
+fn hashed_routing_rejects_misrouted_inserts() {
+    let mut map: ShardedMap<u32, (), Hashed> =
+        ShardedMap::try_new_routed(vec![0b111, 0b111]).unwrap();
+
+    while map.alloc_next_shard() {}
+
+    map.try_insert(0, ()).unwrap();
+
+    let (idx, _) = map.remove(&0).unwrap();
+
+    // Any key routed to the other shard is rejected by this one.
+    let other = (1..)
+        .find(|key| {
+            map.try_insert(*key, ()).unwrap();
+            let (other_idx, _) = map.remove(key).unwrap();
+
+            other_idx != idx
+        })
+        .unwrap();
+
+    let res = unsafe { map.try_insert_new_at(idx, other, ()) };
+    assert_eq!(res, Err(ShardedMapError::Misrouted));
+}
+
+#[test]
+fn removes_empty_shards() {
+    let mut map = map(10);
+    let version = map.version();
+
+    // Last empty shards are removed first.
+    assert_eq!(map.try_remove_empty_shard(), Ok(0b111));
+    assert_eq!(map.try_remove_empty_shard(), Ok(0b111 << 2));
+    assert_eq!(
+        map.try_remove_empty_shard(),
+        Err(ShardedMapError::NoEmptyShard)
+    );
+
+    assert_ne!(map.version(), version);
+    assert_eq!(map.max_capacity(), 0b111 << 4);
+    assert_eq!(map.len(), 10);
+
+    for key in 0..10 {
+        assert_eq!(map.get(&key).map(|(_, &v)| v), Some(key * 10));
+    }
+
+    // Freed capacity may be appended back.
+    map.try_append_shard(0b111 << 2).unwrap();
+    assert!(!map.alloc_next_shard());
+    assert_eq!(map.max_capacity(), (0b111 << 4) + (0b111 << 2));
+}
+
+#[test]
+fn metrics_follow_allocations() {
+    let mut map: ShardedMap<u32, u32> =
+        ShardedMap::try_new(vec![0b111 << 4, 0b111 << 2, 0b111]).unwrap();
+
+    let metrics = map.metrics();
+    assert_eq!(metrics.shards, 3);
+    assert_eq!(metrics.allocated_shards, 0);
+    assert_eq!(metrics.max_capacity, 147);
+    assert_eq!(metrics.capacity, 0);
+    assert_eq!(metrics.heap_bytes, 0);
+
+    map.alloc_next_shard();
+
+    for key in 0..10 {
+        map.try_insert(key, key).unwrap();
+    }
+
+    let metrics = map.metrics();
+    assert_eq!(metrics.allocated_shards, 1);
+    assert_eq!(metrics.entries, 10);
+    assert_eq!(metrics.capacity, 112);
+
+    // Buckets hold pairs of 8 bytes besides control bytes.
+    assert!(metrics.heap_bytes >= 112 * 9);
+
+    map.set_filters(8);
+
+    assert_eq!(map.metrics().heap_bytes, metrics.heap_bytes + 112);
+}
+
+#[test]
+fn version_follows_mutable_access() {
+    let mut map = map(10);
+
+    let version = map.version();
+
+    // Reads keep the version.
+    assert!(map.get(&3).is_some());
+    assert_eq!(map.iter_from(MapCursor::default()).count(), 10);
+    assert_eq!(map.version(), version);
+
+    *map.get_mut(&3).unwrap().1 += 1;
+    assert_ne!(map.version(), version);
+
+    let version = map.version();
+
+    map.remove(&3);
+    assert_ne!(map.version(), version);
+
+    let version = map.version();
+
+    map.try_insert(3, 30).unwrap();
+    assert_ne!(map.version(), version);
+}
+
+#[test]
+fn cursors_of_keys_follow_layout() {
+    let mut map = map(10);
+
+    let layout = map.layout();
+
+    // Keys are visited from their cursors.
+    for (cursor, key, _) in map.iter_from(MapCursor::default()).collect::<Vec<_>>() {
+        let position = map.cursor_of(key).unwrap();
+
+        assert!(position < cursor);
+        assert_eq!(map.iter_from(position).next().map(|(_, k, _)| k), Some(key));
+    }
+
+    assert_eq!(map.cursor_of(&10), None);
+
+    // Entries changed in place keep positions and the layout.
+    let position = map.cursor_of(&3).unwrap();
+
+    *map.get_mut(&3).unwrap().1 += 1;
+    map.remove(&4);
+    map.try_insert(10, 100).unwrap();
+
+    assert_eq!(map.cursor_of(&3), Some(position));
+    assert_eq!(map.layout(), layout);
+
+    map.try_remove_empty_shard().unwrap();
+    assert_ne!(map.layout(), layout);
+
+    let layout = map.layout();
+
+    map.clear_shards();
+    assert_ne!(map.layout(), layout);
+}
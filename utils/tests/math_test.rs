@@ -178,6 +178,17 @@ mod custom_uint {
             assert_eq!(Uint72::default().encode().len(), 9);
             assert_eq!(Uint80::default().encode().len(), 10);
         }
+
+        #[test]
+        fn test_type_info_matches_encoding() {
+            use scale_info::{TypeDef, TypeInfo};
+
+            let TypeDef::Array(array) = Uint72::type_info().type_def else {
+                panic!("LeBytes must be described as an array");
+            };
+            assert_eq!(array.len, 9);
+            assert_eq!(Uint80::type_info(), <[u8; 10]>::type_info());
+        }
     }
 
     mod conversions {
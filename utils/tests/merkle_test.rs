@@ -0,0 +1,125 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::merkle::{self, Hasher, Keccak256, MerkleBuilder};
+
+fn leaves<H: Hasher>(len: u32) -> Vec<[u8; 32]> {
+    (0..len)
+        .map(|i| merkle::leaf::<H>(&i.to_le_bytes()))
+        .collect()
+}
+
+#[test]
+fn builder_matches_levels() {
+    let leaves = leaves::<Keccak256>(5);
+
+    // Last nodes of odd levels are promoted.
+    let ab = merkle::node::<Keccak256>(leaves[0], leaves[1]);
+    let cd = merkle::node::<Keccak256>(leaves[2], leaves[3]);
+    let abcd = merkle::node::<Keccak256>(ab, cd);
+
+    assert_eq!(
+        merkle::root::<Keccak256>(leaves.clone()),
+        merkle::node::<Keccak256>(abcd, leaves[4])
+    );
+
+    for len in 0..40 {
+        let leaves = self::leaves::<Keccak256>(len);
+
+        let mut builder = MerkleBuilder::<Keccak256>::default();
+        leaves.iter().for_each(|&leaf| builder.push(leaf));
+
+        assert_eq!(builder.len(), len);
+        assert_eq!(builder.root(), merkle::root::<Keccak256>(leaves));
+    }
+
+    assert_eq!(merkle::root::<Keccak256>([]), [0; 32]);
+}
+
+#[test]
+fn nodes_are_commutative() {
+    let [a, b] = [
+        merkle::leaf::<Keccak256>(b"a"),
+        merkle::leaf::<Keccak256>(b"b"),
+    ];
+
+    assert_eq!(
+        merkle::node::<Keccak256>(a, b),
+        merkle::node::<Keccak256>(b, a)
+    );
+
+    // Leaves and nodes are domain separated.
+    assert_ne!(
+        merkle::leaf::<Keccak256>(&[a, b].concat()),
+        merkle::node::<Keccak256>(a, b)
+    );
+}
+
+#[test]
+fn proofs_verify() {
+    for len in 1..20 {
+        let leaves = leaves::<Keccak256>(len);
+        let root = merkle::root::<Keccak256>(leaves.clone());
+
+        for (index, &leaf) in leaves.iter().enumerate() {
+            let proof = merkle::proof::<Keccak256>(&leaves, index).unwrap();
+
+            assert!(merkle::verify::<Keccak256>(root, leaf, &proof));
+            assert!(!merkle::verify::<Keccak256>(
+                root,
+                merkle::leaf::<Keccak256>(b"forged"),
+                &proof
+            ));
+        }
+
+        assert!(merkle::proof::<Keccak256>(&leaves, len as usize).is_none());
+    }
+}
+
+#[cfg(feature = "blake2")]
+#[test]
+fn blake2b256_hashes() {
+    use awesome_sails_utils::merkle::Blake2b256;
+
+    assert_eq!(
+        Blake2b256::hash(&[]),
+        hex("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+    );
+    assert_eq!(
+        Blake2b256::hash(&[b"abc".repeat(50).as_slice(), b"abc".repeat(50).as_slice()]),
+        hex("a45fa4cf75d92e585cbe98fe16cfbbd1fb605ecff7a43dc3d3c75b8476aab10d")
+    );
+
+    let leaves = leaves::<Blake2b256>(7);
+    let root = merkle::root::<Blake2b256>(leaves.clone());
+    let proof = merkle::proof::<Blake2b256>(&leaves, 6).unwrap();
+
+    assert!(merkle::verify::<Blake2b256>(root, leaves[6], &proof));
+    assert_ne!(root, merkle::root::<Keccak256>(leaves));
+}
+
+#[cfg(feature = "blake2")]
+fn hex(s: &str) -> [u8; 32] {
+    let mut bytes = [0; 32];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+
+    bytes
+}
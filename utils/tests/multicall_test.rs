@@ -0,0 +1,51 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    error::{BadInput, Error},
+    multicall::{MAX_BATCH_LEN, multicall},
+};
+
+#[test]
+fn answers_in_order() {
+    let res: Result<_, Error> = multicall(vec![3, 1, 2], |q| Ok(q * 10));
+
+    assert_eq!(res.unwrap(), vec![30, 10, 20]);
+}
+
+#[test]
+fn fails_on_first_error() {
+    let mut answered = 0;
+
+    let res: Result<Vec<u32>, Error> = multicall(vec![1, 0, 2], |q| {
+        answered += 1;
+        (q != 0).then_some(q).ok_or_else(|| BadInput.into())
+    });
+
+    assert!(res.is_err());
+    assert_eq!(answered, 2);
+}
+
+#[test]
+fn rejects_oversized_batch() {
+    let res: Result<Vec<u8>, Error> = multicall(vec![0; MAX_BATCH_LEN + 1], Ok);
+    assert!(res.is_err());
+
+    let res: Result<Vec<u8>, Error> = multicall(vec![0; MAX_BATCH_LEN], Ok);
+    assert_eq!(res.unwrap().len(), MAX_BATCH_LEN);
+}
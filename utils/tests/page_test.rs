@@ -0,0 +1,72 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::page::{DEFAULT_MAX_PAGE_SIZE, Page, ensure_page_size};
+use parity_scale_codec::Encode;
+use scale_info::TypeInfo;
+
+#[test]
+fn encodes_as_vec() {
+    let items: Vec<(u64, [u8; 3])> = (0..100).map(|i| (i, [i as u8; 3])).collect();
+
+    let page: Page<(u64, [u8; 3])> = items.iter().cloned().collect();
+
+    assert_eq!(page.len(), 100);
+    assert_eq!(page.encode(), items.encode());
+    assert_eq!(page.size_hint(), items.encode().len());
+
+    let empty: Page<u32> = Default::default();
+
+    assert!(empty.is_empty());
+    assert_eq!(empty.encode(), Vec::<u32>::new().encode());
+}
+
+#[test]
+fn pushes_borrowed_items() {
+    let mut page = Page::<(u32, String)>::default();
+
+    page.push((&1, &String::from("one")));
+    page.push((2, "two"));
+
+    assert_eq!(
+        page.encode(),
+        vec![(1u32, String::from("one")), (2, String::from("two"))].encode()
+    );
+}
+
+#[test]
+fn described_as_vec() {
+    assert_eq!(
+        Page::<(u64, bool)>::type_info(),
+        Vec::<(u64, bool)>::type_info()
+    );
+}
+
+#[test]
+fn page_size_bounded() {
+    assert!(ensure_page_size(0, 0).is_ok());
+    assert!(ensure_page_size(DEFAULT_MAX_PAGE_SIZE, DEFAULT_MAX_PAGE_SIZE).is_ok());
+
+    let err = ensure_page_size(DEFAULT_MAX_PAGE_SIZE + 1, DEFAULT_MAX_PAGE_SIZE).unwrap_err();
+
+    assert_eq!(err.max, DEFAULT_MAX_PAGE_SIZE);
+    assert_eq!(
+        err.to_string(),
+        "page too large: at most 1000 items allowed"
+    );
+}
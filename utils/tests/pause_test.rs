@@ -0,0 +1,88 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    pause::{PausableError, Pause, PauseRegistry, UnknownService},
+    storage::{Storage, StorageMut},
+};
+use core::cell::RefCell;
+
+#[cfg(feature = "gprimitives")]
+use awesome_sails_utils::pause::{PauseInfo, PauseReason};
+
+#[cfg(feature = "gprimitives")]
+fn info(paused_by: u64, reason: PauseReason) -> PauseInfo {
+    PauseInfo {
+        paused_by: gprimitives::ActorId::from(paused_by),
+        paused_at: 10,
+        reason: Some(reason),
+    }
+}
+
+#[cfg(feature = "gprimitives")]
+#[test]
+fn records_attribution() {
+    let pause = Pause::default();
+
+    assert!(pause.pause_with(info(1, PauseReason::Code(7))));
+    assert!(pause.is_paused());
+    assert_eq!(pause.info(), Some(info(1, PauseReason::Code(7))));
+
+    // Attribution of the pause already on is kept.
+    assert!(!pause.pause_with(info(2, PauseReason::Message("exploit".into()))));
+    assert_eq!(pause.info(), Some(info(1, PauseReason::Code(7))));
+
+    assert!(pause.resume());
+    assert!(!pause.is_paused());
+    assert_eq!(pause.info(), None);
+
+    // Pauses aren't attributed unless requested.
+    assert!(pause.pause());
+    assert_eq!(pause.info(), None);
+}
+
+#[test]
+fn registry_pauses_services_apart() {
+    let registry = PauseRegistry::new(["vft", "staking"]);
+
+    let balances = RefCell::new(1);
+    let stakes = RefCell::new(2);
+
+    let mut vft = registry.pausable("vft", &balances).unwrap();
+    let mut staking = registry.pausable("staking", &stakes).unwrap();
+
+    assert_eq!(registry.pause("staking"), Ok(true));
+    assert_eq!(registry.pause("staking"), Ok(false));
+    assert_eq!(registry.paused().collect::<Vec<_>>(), ["staking"]);
+
+    // Paused service is read-only, while others are intact.
+    assert!(matches!(staking.get_mut(), Err(PausableError::Paused)));
+    assert_eq!(*staking.get().unwrap(), 2);
+
+    *vft.get_mut().unwrap() += 1;
+    assert_eq!(*balances.borrow(), 2);
+
+    assert_eq!(registry.resume("staking"), Ok(true));
+    assert_eq!(registry.is_paused("staking"), Ok(false));
+    assert!(staking.get_mut().is_ok());
+
+    // Unknown services aren't switched.
+    assert_eq!(registry.pause("sale"), Err(UnknownService));
+    assert!(registry.pausable("sale", &balances).is_err());
+    assert_eq!(registry.paused().count(), 0);
+}
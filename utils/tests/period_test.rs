@@ -0,0 +1,47 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::period::PeriodBudget;
+
+#[test]
+fn spends_within_period() {
+    assert!(PeriodBudget::new(100.into(), 0, 10).is_none());
+
+    let mut budget = PeriodBudget::new(100.into(), 10, 10).unwrap();
+
+    assert!(budget.try_spend(60.into(), 10).is_ok());
+    assert_eq!(budget.try_spend(50.into(), 19), Err(40.into()));
+    assert_eq!(budget.remaining(19), 40.into());
+    assert!(budget.try_spend(40.into(), 19).is_ok());
+    assert_eq!(budget.remaining(19), 0.into());
+}
+
+#[test]
+fn renews_on_aligned_periods() {
+    let mut budget = PeriodBudget::new(100.into(), 10, 10).unwrap();
+
+    assert!(budget.try_spend(100.into(), 15).is_ok());
+    assert_eq!(budget.remaining(19), 0.into());
+    assert_eq!(budget.remaining(20), 100.into());
+
+    // Skipped periods keep boundaries aligned to the initial start
+    assert!(budget.try_spend(30.into(), 47).is_ok());
+    assert_eq!(budget.period_start, 40);
+    assert_eq!(budget.remaining(49), 70.into());
+    assert_eq!(budget.remaining(50), 100.into());
+}
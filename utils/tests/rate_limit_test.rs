@@ -0,0 +1,60 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::rate_limit::RateLimiter;
+
+#[test]
+fn limits_bursts_per_key() {
+    let mut limiter = RateLimiter::new(2, 10);
+
+    assert!(limiter.try_consume(1, 1, 100).is_ok());
+    assert!(limiter.try_consume(1, 1, 100).is_ok());
+    assert!(limiter.try_consume(1, 1, 100).is_err());
+
+    // Other keys have their own buckets
+    assert!(limiter.try_consume(2, 2, 100).is_ok());
+    assert_eq!(limiter.available(&3, 100), 2);
+}
+
+#[test]
+fn refills_over_blocks() {
+    let mut limiter = RateLimiter::new(3, 10);
+
+    assert!(limiter.try_consume(1, 3, 100).is_ok());
+    assert_eq!(limiter.available(&1, 109), 0);
+    assert_eq!(limiter.available(&1, 125), 2);
+    assert_eq!(limiter.available(&1, 1_000), 3);
+
+    // Partial periods are kept for the next refill
+    assert!(limiter.try_consume(1, 1, 115).is_ok());
+    assert_eq!(limiter.available(&1, 119), 0);
+    assert_eq!(limiter.available(&1, 120), 1);
+
+    assert!(limiter.try_consume(1, 2, 115).is_err());
+}
+
+#[test]
+fn disabled_never_limits() {
+    let mut limiter = RateLimiter::default();
+
+    assert!(limiter.is_disabled());
+
+    for _ in 0..100 {
+        assert!(limiter.try_consume(1, u32::MAX, 0).is_ok());
+    }
+}
@@ -0,0 +1,75 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "gprimitives")]
+
+use awesome_sails_utils::reply::{OutboundTracker, ReplyRouter};
+use gprimitives::{ActorId, MessageId};
+
+fn id(n: u8) -> MessageId {
+    MessageId::new([n; 32])
+}
+
+#[test]
+fn routes_replies_once() {
+    let mut router = ReplyRouter::default();
+
+    router.expect(id(1), "exchange");
+    router.expect(id(2), "gateway");
+
+    assert_eq!(router.len(), 2);
+    assert_eq!(router.tag_of(&id(2)), Some(&"gateway"));
+
+    assert_eq!(router.route(id(1)), Some("exchange"));
+    assert_eq!(router.route(id(1)), None);
+
+    // Replies to unregistered messages aren't routed.
+    assert_eq!(router.route(id(3)), None);
+
+    assert_eq!(router.route(id(2)), Some("gateway"));
+    assert!(router.is_empty());
+}
+
+#[test]
+fn tracks_stale_messages() {
+    let mut tracker = OutboundTracker::default();
+
+    tracker.record(id(1), ActorId::from([1; 32]), "payout", 10);
+    tracker.record(id(2), ActorId::from([2; 32]), "payout", 15);
+
+    let stale = |tracker: &OutboundTracker, older_than, current_bn| {
+        tracker
+            .stale_messages(older_than, current_bn)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(stale(&tracker, 10, 19), vec![]);
+    assert_eq!(stale(&tracker, 10, 20), vec![id(1)]);
+    assert_eq!(stale(&tracker, 5, 20), vec![id(1), id(2)]);
+
+    // Resolved messages are no longer tracked.
+    let message = tracker.resolve(id(1)).unwrap();
+    assert_eq!(message.destination, ActorId::from([1; 32]));
+    assert_eq!(message.purpose, "payout");
+    assert_eq!(message.sent_at, 10);
+
+    assert!(tracker.resolve(id(1)).is_none());
+    assert_eq!(stale(&tracker, 5, 20), vec![id(2)]);
+    assert_eq!(tracker.len(), 1);
+}
@@ -0,0 +1,106 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(any(feature = "ed25519", feature = "sr25519"))]
+
+use awesome_sails_utils::signature::*;
+
+#[cfg(feature = "ed25519")]
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+
+    bytes
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn verifies_ed25519() {
+    // RFC 8032, section 7.1, test 1.
+    let public_key = hex::<32>("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let signature = hex::<64>(
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    );
+
+    assert_eq!(ed25519::verify(&public_key, b"", &signature), Ok(()));
+    assert_eq!(
+        ed25519::verify(&public_key, b"tampered", &signature),
+        Err(InvalidSignature)
+    );
+
+    let mut forged = signature;
+    forged[0] ^= 1;
+    assert_eq!(
+        ed25519::verify(&public_key, b"", &forged),
+        Err(InvalidSignature)
+    );
+}
+
+#[cfg(feature = "sr25519")]
+#[test]
+fn verifies_sr25519() {
+    use schnorrkel::{ExpansionMode, MiniSecretKey};
+
+    let keypair = MiniSecretKey::from_bytes(&[7; 32])
+        .unwrap()
+        .expand_to_keypair(ExpansionMode::Ed25519);
+    let public_key = keypair.public.to_bytes();
+
+    let signature = keypair
+        .sign_simple(sr25519::SIGNING_CONTEXT, b"message")
+        .to_bytes();
+
+    assert_eq!(sr25519::verify(&public_key, b"message", &signature), Ok(()));
+    assert_eq!(
+        sr25519::verify(&public_key, b"tampered", &signature),
+        Err(InvalidSignature)
+    );
+
+    // Wallets sign messages wrapped into `<Bytes>` tags.
+    let signature = keypair
+        .sign_simple(sr25519::SIGNING_CONTEXT, b"<Bytes>message</Bytes>")
+        .to_bytes();
+
+    assert_eq!(
+        sr25519::verify_wrapped(&public_key, b"message", &signature),
+        Ok(())
+    );
+    assert_eq!(
+        sr25519::verify(&public_key, b"message", &signature),
+        Err(InvalidSignature)
+    );
+}
+
+#[cfg(feature = "sr25519")]
+#[test]
+fn rejects_malformed_sr25519() {
+    let public_key = [0; 32];
+
+    // Schnorrkel signatures must have the high bit of the last byte set.
+    assert_eq!(
+        sr25519::verify(&public_key, b"message", &[0; 64]),
+        Err(InvalidSignature)
+    );
+    assert_eq!(
+        sr25519::verify_wrapped(&public_key, b"message", &[0; 64]),
+        Err(InvalidSignature)
+    );
+}
@@ -0,0 +1,163 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    error::{BadInput, Error},
+    storage::{
+        BoxedStorage, NamespacedCell, StaticCell, Storage, StorageKey, StorageMut, StorageRefCell,
+        StorageStatic, TransactionalStorage,
+    },
+};
+use core::cell::RefCell;
+
+#[test]
+fn transactional_commit() {
+    let cell = RefCell::new(vec![1]);
+    let mut storage = TransactionalStorage::new(StorageRefCell::new(&cell));
+
+    storage.get_mut().unwrap().push(2);
+
+    assert!(storage.is_dirty());
+    assert_eq!(*storage.get().unwrap(), vec![1, 2]);
+    assert_eq!(*cell.borrow(), vec![1]);
+
+    storage.commit().unwrap();
+
+    assert!(!storage.is_dirty());
+    assert_eq!(*cell.borrow(), vec![1, 2]);
+}
+
+#[test]
+fn transactional_revert() {
+    let cell = RefCell::new(vec![1]);
+    let mut storage = TransactionalStorage::new(StorageRefCell::new(&cell));
+
+    assert_eq!(storage.replace(vec![3]).unwrap(), vec![1]);
+    storage.revert();
+
+    assert_eq!(*storage.get().unwrap(), vec![1]);
+
+    storage.get_mut().unwrap().push(2);
+    drop(storage);
+
+    assert_eq!(*cell.borrow(), vec![1]);
+}
+
+#[test]
+fn transactional_transact() {
+    let cell = RefCell::new(0u32);
+    let mut storage = TransactionalStorage::new(StorageRefCell::new(&cell));
+
+    let res: Result<(), Error> = storage.transact(|s| {
+        *s.get_mut()? += 1;
+        Ok(())
+    });
+    assert!(res.is_ok());
+    assert_eq!(*cell.borrow(), 1);
+
+    let res: Result<(), Error> = storage.transact(|s| {
+        *s.get_mut()? += 1;
+        Err(BadInput.into())
+    });
+    assert!(res.is_err());
+    assert_eq!(*cell.borrow(), 1);
+}
+
+#[test]
+fn boxed_storage() {
+    let cell = RefCell::new(vec![1]);
+    let mut value = vec![1];
+
+    let mut storages: Vec<BoxedStorage<'_, Vec<u32>>> = vec![
+        BoxedStorage::new(StorageRefCell::new(&cell)),
+        BoxedStorage::new(&mut value),
+    ];
+
+    for storage in storages.iter_mut() {
+        storage.get_mut().unwrap().push(2);
+        assert_eq!(storage.replace(vec![3]).unwrap(), vec![1, 2]);
+        assert_eq!(*Storage::get(storage).unwrap(), vec![3]);
+    }
+
+    drop(storages);
+
+    assert_eq!(*cell.borrow(), vec![3]);
+    assert_eq!(value, vec![3]);
+}
+
+#[test]
+fn static_storage() {
+    fn init() -> Vec<u32> {
+        vec![1]
+    }
+
+    let cell: &'static StaticCell<Vec<u32>> = Box::leak(Box::new(StaticCell::new(init)));
+
+    let mut storage = StorageStatic::new(cell);
+    let copy = storage;
+
+    storage.get_mut().unwrap().push(2);
+
+    assert_eq!(*copy.get().unwrap(), vec![1, 2]);
+    assert_eq!(storage.take().unwrap(), vec![1, 2]);
+    assert!(copy.get().unwrap().is_empty());
+}
+
+const BALANCES: StorageKey = StorageKey::new("Vft", "balances");
+const ALLOWANCES: StorageKey = StorageKey::new("Vft", "allowances");
+const ADMIN_BALANCES: StorageKey = StorageKey::new("VftAdmin", "balances");
+
+const _: () = StorageKey::assert_unique(&[BALANCES, ALLOWANCES, ADMIN_BALANCES]);
+
+#[test]
+fn storage_keys() {
+    assert_eq!(BALANCES, StorageKey::new("Vft", "balances"));
+    assert_eq!(BALANCES.namespace(), "Vft");
+    assert_eq!(BALANCES.name(), "balances");
+
+    assert_ne!(BALANCES.hash(), ADMIN_BALANCES.hash());
+
+    // Splitting the same bytes differently yields another key.
+    assert_ne!(
+        StorageKey::new("Vft", "Admin").hash(),
+        StorageKey::new("VftA", "dmin").hash()
+    );
+}
+
+#[test]
+#[should_panic(expected = "storage key collision")]
+fn storage_keys_collide() {
+    StorageKey::assert_unique(&[BALANCES, ALLOWANCES, StorageKey::new("Vft", "balances")]);
+}
+
+#[test]
+fn namespaced_storage() {
+    fn init() -> Vec<u32> {
+        vec![1]
+    }
+
+    let cell: &'static NamespacedCell<Vec<u32>> =
+        Box::leak(Box::new(NamespacedCell::new(BALANCES, init)));
+
+    assert_eq!(cell.key(), BALANCES);
+
+    let mut storage = cell.storage();
+    storage.get_mut().unwrap().push(2);
+
+    assert_eq!(*StorageStatic::from(cell).get().unwrap(), vec![1, 2]);
+}
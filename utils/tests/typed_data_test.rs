@@ -0,0 +1,133 @@
+// This file is part of Gear.
+
+// Copyright (C) 2025 Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use awesome_sails_utils::{
+    merkle::{Hasher, Keccak256},
+    typed_data::{self, Domain, MetaTx, Permit, TypedData},
+};
+use parity_scale_codec::Encode;
+use primitive_types::U256;
+
+const DOMAIN: Domain = Domain {
+    program: [1; 32],
+    network: [2; 32],
+    service: "Vft",
+    version: 1,
+};
+
+fn permit() -> Permit<[u8; 32]> {
+    Permit {
+        owner: [3; 32],
+        spender: [4; 32],
+        value: U256::from(42),
+        nonce: 0,
+        deadline: 100,
+    }
+}
+
+#[test]
+fn digest_commits_to_domain_and_type() {
+    let permit = permit();
+
+    let expected = Keccak256::hash(&[
+        b"\x19\x01",
+        &Keccak256::hash(&[
+            b"awesome-sails/domain",
+            &([1u8; 32], [2u8; 32], "Vft", 1u32).encode(),
+        ]),
+        &Keccak256::hash(&["Permit".encode().as_slice(), &permit.encode()]),
+    ]);
+
+    assert_eq!(
+        typed_data::digest::<Keccak256, _>(&DOMAIN, &permit),
+        expected
+    );
+}
+
+#[test]
+fn digests_differ_across_domains() {
+    let permit = permit();
+    let digest = typed_data::digest::<Keccak256, _>(&DOMAIN, &permit);
+
+    let domains = [
+        Domain {
+            program: [0; 32],
+            ..DOMAIN
+        },
+        Domain {
+            network: [0; 32],
+            ..DOMAIN
+        },
+        Domain {
+            service: "VftAdmin",
+            ..DOMAIN
+        },
+        Domain {
+            version: 2,
+            ..DOMAIN
+        },
+    ];
+
+    for domain in domains {
+        assert_ne!(typed_data::digest::<Keccak256, _>(&domain, &permit), digest);
+    }
+}
+
+#[test]
+fn digests_differ_across_types() {
+    #[derive(Encode)]
+    struct Transfer([u8; 32], [u8; 32], U256, u64, u32);
+
+    impl TypedData for Transfer {
+        const TYPE: &'static str = "Transfer";
+    }
+
+    let permit = permit();
+    let transfer = Transfer(
+        permit.owner,
+        permit.spender,
+        permit.value,
+        permit.nonce,
+        permit.deadline,
+    );
+
+    // Same encoding, different types.
+    assert_eq!(permit.encode(), transfer.encode());
+    assert_ne!(
+        typed_data::digest::<Keccak256, _>(&DOMAIN, &permit),
+        typed_data::digest::<Keccak256, _>(&DOMAIN, &transfer)
+    );
+
+    let meta_tx = MetaTx {
+        signer: [3u8; 32],
+        payload: b"call".to_vec(),
+        nonce: 0,
+        deadline: 100,
+    };
+
+    assert_ne!(
+        typed_data::digest::<Keccak256, _>(&DOMAIN, &meta_tx),
+        typed_data::digest::<Keccak256, _>(
+            &DOMAIN,
+            &MetaTx {
+                nonce: 1,
+                ..meta_tx.clone()
+            }
+        )
+    );
+}